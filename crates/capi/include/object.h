@@ -0,0 +1,150 @@
+/*
+ * C API for reading object files using the `object` crate.
+ *
+ * Open a file with `object_file_open` or `object_file_parse`, and release it with
+ * `object_file_free`. All names and data returned for a file point into a copy of
+ * the file data that is owned by the handle, and remain valid until it is freed.
+ * Names are not null terminated.
+ *
+ * Functions that can fail return null, and the reason can be retrieved with
+ * `object_error_message`.
+ */
+
+#ifndef OBJECT_H
+#define OBJECT_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define OBJECT_FORMAT_UNKNOWN 0
+#define OBJECT_FORMAT_COFF 1
+#define OBJECT_FORMAT_ELF 2
+#define OBJECT_FORMAT_MACHO 3
+#define OBJECT_FORMAT_PE 4
+#define OBJECT_FORMAT_WASM 5
+#define OBJECT_FORMAT_XCOFF 6
+
+#define OBJECT_SECTION_KIND_UNKNOWN 0
+#define OBJECT_SECTION_KIND_TEXT 1
+#define OBJECT_SECTION_KIND_DATA 2
+#define OBJECT_SECTION_KIND_READ_ONLY_DATA 3
+#define OBJECT_SECTION_KIND_READ_ONLY_STRING 4
+#define OBJECT_SECTION_KIND_UNINITIALIZED_DATA 5
+#define OBJECT_SECTION_KIND_TLS 6
+#define OBJECT_SECTION_KIND_UNINITIALIZED_TLS 7
+#define OBJECT_SECTION_KIND_DEBUG 8
+#define OBJECT_SECTION_KIND_METADATA 9
+#define OBJECT_SECTION_KIND_OTHER 10
+
+#define OBJECT_SYMBOL_KIND_UNKNOWN 0
+#define OBJECT_SYMBOL_KIND_TEXT 1
+#define OBJECT_SYMBOL_KIND_DATA 2
+#define OBJECT_SYMBOL_KIND_SECTION 3
+#define OBJECT_SYMBOL_KIND_FILE 4
+#define OBJECT_SYMBOL_KIND_LABEL 5
+#define OBJECT_SYMBOL_KIND_TLS 6
+
+#define OBJECT_SYMBOL_SCOPE_UNKNOWN 0
+#define OBJECT_SYMBOL_SCOPE_COMPILATION 1
+#define OBJECT_SYMBOL_SCOPE_LINKAGE 2
+#define OBJECT_SYMBOL_SCOPE_DYNAMIC 3
+
+#define OBJECT_SYMBOL_FLAG_UNDEFINED (1u << 0)
+#define OBJECT_SYMBOL_FLAG_WEAK (1u << 1)
+#define OBJECT_SYMBOL_FLAG_GLOBAL (1u << 2)
+#define OBJECT_SYMBOL_FLAG_COMMON (1u << 3)
+
+#define OBJECT_NO_SECTION SIZE_MAX
+
+/* An opaque handle for a parsed object file. */
+typedef struct object_file object_file;
+
+/* A byte string, such as a name or section data. This is not null terminated. */
+typedef struct object_bytes {
+    const uint8_t *data;
+    size_t len;
+} object_bytes;
+
+typedef struct object_section {
+    size_t index;
+    object_bytes name;
+    uint64_t address;
+    uint64_t size;
+    uint64_t align;
+    /* The offset of the section data in the file, or 0 if there is none. */
+    uint64_t file_offset;
+    uint64_t file_size;
+    /* One of the OBJECT_SECTION_KIND_* constants. */
+    uint32_t kind;
+    /* The raw section data in the file. This is not decompressed. */
+    object_bytes data;
+} object_section;
+
+typedef struct object_symbol {
+    size_t index;
+    object_bytes name;
+    uint64_t address;
+    uint64_t size;
+    /* One of the OBJECT_SYMBOL_KIND_* constants. */
+    uint32_t kind;
+    /* One of the OBJECT_SYMBOL_SCOPE_* constants. */
+    uint32_t scope;
+    /* The index of the section containing the symbol, or OBJECT_NO_SECTION. */
+    size_t section_index;
+    /* A combination of the OBJECT_SYMBOL_FLAG_* constants. */
+    uint32_t flags;
+} object_symbol;
+
+typedef struct object_import {
+    /* Empty if the format does not record the library. */
+    object_bytes library;
+    object_bytes name;
+} object_import;
+
+typedef struct object_export {
+    object_bytes name;
+    uint64_t address;
+} object_export;
+
+/* Return the message for the last error on this thread, or null. */
+const char *object_error_message(void);
+
+/* Read and parse the object file at the given path. Returns null on failure. */
+object_file *object_file_open(const char *path);
+/* Parse an object file from memory. The data is copied. Returns null on failure. */
+object_file *object_file_parse(const uint8_t *data, size_t len);
+void object_file_free(object_file *file);
+
+/* Return one of the OBJECT_FORMAT_* constants. */
+uint32_t object_file_format(const object_file *file);
+/* Return the name of the architecture, such as "X86_64", or "Unknown". The name is static. */
+const char *object_file_architecture(const object_file *file);
+bool object_file_is_64(const object_file *file);
+bool object_file_is_little_endian(const object_file *file);
+uint64_t object_file_entry(const object_file *file);
+
+/*
+ * The getters return null if the position is out of range.
+ * The imports and exports are empty if their tables can't be parsed.
+ */
+size_t object_file_section_count(const object_file *file);
+const object_section *object_file_section(const object_file *file, size_t i);
+size_t object_file_symbol_count(const object_file *file);
+const object_symbol *object_file_symbol(const object_file *file, size_t i);
+size_t object_file_dynamic_symbol_count(const object_file *file);
+const object_symbol *object_file_dynamic_symbol(const object_file *file, size_t i);
+size_t object_file_import_count(const object_file *file);
+const object_import *object_file_import(const object_file *file, size_t i);
+size_t object_file_export_count(const object_file *file);
+const object_export *object_file_export(const object_file *file, size_t i);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* OBJECT_H */
@@ -0,0 +1,571 @@
+//! A C API for reading object files.
+//!
+//! This exposes the unified read API of the `object` crate through a stable C ABI.
+//! The declarations are in `include/object.h`.
+//!
+//! A file is opened with `object_file_open` or `object_file_parse`, which return
+//! an opaque handle that must be released with `object_file_free`. The handle owns
+//! a copy of the file data, and all names and data returned by the API point into
+//! this copy, so they remain valid until the handle is freed.
+//!
+//! Functions that can fail return null or false, and the reason can be retrieved
+//! with `object_error_message`.
+
+#![warn(rust_2018_idioms)]
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::{fs, ptr, slice};
+
+use object::read::{Object, ObjectSection, ObjectSymbol};
+use object::{Architecture, BinaryFormat, SectionKind, SymbolKind, SymbolScope, SymbolSection};
+
+/// A byte string, such as a name or section data.
+///
+/// This is not null terminated.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ObjectBytes {
+    /// A pointer to the bytes, or null if there are none.
+    pub data: *const u8,
+    /// The number of bytes.
+    pub len: usize,
+}
+
+impl ObjectBytes {
+    const EMPTY: ObjectBytes = ObjectBytes {
+        data: ptr::null(),
+        len: 0,
+    };
+
+    fn new(bytes: &[u8]) -> Self {
+        if bytes.is_empty() {
+            ObjectBytes::EMPTY
+        } else {
+            ObjectBytes {
+                data: bytes.as_ptr(),
+                len: bytes.len(),
+            }
+        }
+    }
+}
+
+/// A section in an object file.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ObjectSectionInfo {
+    /// The index of the section in the file.
+    pub index: usize,
+    /// The name of the section.
+    pub name: ObjectBytes,
+    /// The address of the section.
+    pub address: u64,
+    /// The size of the section in memory.
+    pub size: u64,
+    /// The alignment of the section in memory.
+    pub align: u64,
+    /// The offset of the section data in the file, or 0 if there is none.
+    pub file_offset: u64,
+    /// The size of the section data in the file.
+    pub file_size: u64,
+    /// One of the `OBJECT_SECTION_KIND_*` constants.
+    pub kind: u32,
+    /// The raw section data in the file.
+    ///
+    /// This is not decompressed.
+    pub data: ObjectBytes,
+}
+
+/// A symbol in an object file.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ObjectSymbolInfo {
+    /// The index of the symbol in its symbol table.
+    pub index: usize,
+    /// The name of the symbol.
+    pub name: ObjectBytes,
+    /// The address of the symbol.
+    pub address: u64,
+    /// The size of the symbol.
+    pub size: u64,
+    /// One of the `OBJECT_SYMBOL_KIND_*` constants.
+    pub kind: u32,
+    /// One of the `OBJECT_SYMBOL_SCOPE_*` constants.
+    pub scope: u32,
+    /// The index of the section containing the symbol, or `OBJECT_NO_SECTION`.
+    pub section_index: usize,
+    /// A combination of the `OBJECT_SYMBOL_FLAG_*` constants.
+    pub flags: u32,
+}
+
+/// A symbol imported from another module.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ObjectImportInfo {
+    /// The name of the library containing the symbol.
+    ///
+    /// This is empty if the format does not record the library.
+    pub library: ObjectBytes,
+    /// The name of the imported symbol.
+    pub name: ObjectBytes,
+}
+
+/// A symbol exported by this module.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ObjectExportInfo {
+    /// The name of the exported symbol.
+    pub name: ObjectBytes,
+    /// The virtual address of the exported symbol.
+    pub address: u64,
+}
+
+pub const OBJECT_FORMAT_UNKNOWN: u32 = 0;
+pub const OBJECT_FORMAT_COFF: u32 = 1;
+pub const OBJECT_FORMAT_ELF: u32 = 2;
+pub const OBJECT_FORMAT_MACHO: u32 = 3;
+pub const OBJECT_FORMAT_PE: u32 = 4;
+pub const OBJECT_FORMAT_WASM: u32 = 5;
+pub const OBJECT_FORMAT_XCOFF: u32 = 6;
+
+pub const OBJECT_SECTION_KIND_UNKNOWN: u32 = 0;
+pub const OBJECT_SECTION_KIND_TEXT: u32 = 1;
+pub const OBJECT_SECTION_KIND_DATA: u32 = 2;
+pub const OBJECT_SECTION_KIND_READ_ONLY_DATA: u32 = 3;
+pub const OBJECT_SECTION_KIND_READ_ONLY_STRING: u32 = 4;
+pub const OBJECT_SECTION_KIND_UNINITIALIZED_DATA: u32 = 5;
+pub const OBJECT_SECTION_KIND_TLS: u32 = 6;
+pub const OBJECT_SECTION_KIND_UNINITIALIZED_TLS: u32 = 7;
+pub const OBJECT_SECTION_KIND_DEBUG: u32 = 8;
+pub const OBJECT_SECTION_KIND_METADATA: u32 = 9;
+pub const OBJECT_SECTION_KIND_OTHER: u32 = 10;
+
+pub const OBJECT_SYMBOL_KIND_UNKNOWN: u32 = 0;
+pub const OBJECT_SYMBOL_KIND_TEXT: u32 = 1;
+pub const OBJECT_SYMBOL_KIND_DATA: u32 = 2;
+pub const OBJECT_SYMBOL_KIND_SECTION: u32 = 3;
+pub const OBJECT_SYMBOL_KIND_FILE: u32 = 4;
+pub const OBJECT_SYMBOL_KIND_LABEL: u32 = 5;
+pub const OBJECT_SYMBOL_KIND_TLS: u32 = 6;
+
+pub const OBJECT_SYMBOL_SCOPE_UNKNOWN: u32 = 0;
+pub const OBJECT_SYMBOL_SCOPE_COMPILATION: u32 = 1;
+pub const OBJECT_SYMBOL_SCOPE_LINKAGE: u32 = 2;
+pub const OBJECT_SYMBOL_SCOPE_DYNAMIC: u32 = 3;
+
+pub const OBJECT_SYMBOL_FLAG_UNDEFINED: u32 = 1 << 0;
+pub const OBJECT_SYMBOL_FLAG_WEAK: u32 = 1 << 1;
+pub const OBJECT_SYMBOL_FLAG_GLOBAL: u32 = 1 << 2;
+pub const OBJECT_SYMBOL_FLAG_COMMON: u32 = 1 << 3;
+
+pub const OBJECT_NO_SECTION: usize = usize::MAX;
+
+/// An opaque handle for a parsed object file.
+pub struct ObjectFile {
+    format: u32,
+    architecture: &'static CStr,
+    is_64: bool,
+    is_little_endian: bool,
+    entry: u64,
+    sections: Vec<ObjectSectionInfo>,
+    symbols: Vec<ObjectSymbolInfo>,
+    dynamic_symbols: Vec<ObjectSymbolInfo>,
+    imports: Vec<ObjectImportInfo>,
+    exports: Vec<ObjectExportInfo>,
+    // All of the pointers above point into this, so it must not be modified.
+    _data: Box<[u8]>,
+}
+
+impl ObjectFile {
+    fn parse(data: Box<[u8]>) -> object::Result<Self> {
+        let file = object::File::parse(&*data)?;
+        let sections = file
+            .sections()
+            .map(|section| ObjectSectionInfo {
+                index: section.index().0,
+                name: ObjectBytes::new(section.name_bytes().unwrap_or(&[])),
+                address: section.address(),
+                size: section.size(),
+                align: section.align(),
+                file_offset: section.file_range().map(|r| r.0).unwrap_or(0),
+                file_size: section.file_range().map(|r| r.1).unwrap_or(0),
+                kind: section_kind(section.kind()),
+                data: ObjectBytes::new(section.data().unwrap_or(&[])),
+            })
+            .collect();
+        let symbols = symbol_infos(file.symbols());
+        let dynamic_symbols = symbol_infos(file.dynamic_symbols());
+        // A malformed import or export table shouldn't prevent access to the rest
+        // of the file, so these are empty if they can't be parsed.
+        let imports = file
+            .imports()
+            .unwrap_or_default()
+            .iter()
+            .map(|import| ObjectImportInfo {
+                library: ObjectBytes::new(import.library()),
+                name: ObjectBytes::new(import.name()),
+            })
+            .collect();
+        let exports = file
+            .exports()
+            .unwrap_or_default()
+            .iter()
+            .map(|export| ObjectExportInfo {
+                name: ObjectBytes::new(export.name()),
+                address: export.address(),
+            })
+            .collect();
+        Ok(ObjectFile {
+            format: format(file.format()),
+            architecture: architecture(file.architecture()),
+            is_64: file.is_64(),
+            is_little_endian: file.is_little_endian(),
+            entry: file.entry(),
+            sections,
+            symbols,
+            dynamic_symbols,
+            imports,
+            exports,
+            _data: data,
+        })
+    }
+}
+
+fn symbol_infos<'data: 'file, 'file>(
+    symbols: impl Iterator<Item = object::Symbol<'data, 'file>>,
+) -> Vec<ObjectSymbolInfo> {
+    symbols
+        .map(|symbol| {
+            let mut flags = 0;
+            if symbol.is_undefined() {
+                flags |= OBJECT_SYMBOL_FLAG_UNDEFINED;
+            }
+            if symbol.is_weak() {
+                flags |= OBJECT_SYMBOL_FLAG_WEAK;
+            }
+            if symbol.is_global() {
+                flags |= OBJECT_SYMBOL_FLAG_GLOBAL;
+            }
+            if symbol.is_common() {
+                flags |= OBJECT_SYMBOL_FLAG_COMMON;
+            }
+            let section_index = match symbol.section() {
+                SymbolSection::Section(index) => index.0,
+                _ => OBJECT_NO_SECTION,
+            };
+            ObjectSymbolInfo {
+                index: symbol.index().0,
+                name: ObjectBytes::new(symbol.name_bytes().unwrap_or(&[])),
+                address: symbol.address(),
+                size: symbol.size(),
+                kind: symbol_kind(symbol.kind()),
+                scope: symbol_scope(symbol.scope()),
+                section_index,
+                flags,
+            }
+        })
+        .collect()
+}
+
+fn format(format: BinaryFormat) -> u32 {
+    match format {
+        BinaryFormat::Coff => OBJECT_FORMAT_COFF,
+        BinaryFormat::Elf => OBJECT_FORMAT_ELF,
+        BinaryFormat::MachO => OBJECT_FORMAT_MACHO,
+        BinaryFormat::Pe => OBJECT_FORMAT_PE,
+        BinaryFormat::Wasm => OBJECT_FORMAT_WASM,
+        BinaryFormat::Xcoff => OBJECT_FORMAT_XCOFF,
+        _ => OBJECT_FORMAT_UNKNOWN,
+    }
+}
+
+fn architecture(architecture: Architecture) -> &'static CStr {
+    // These names are part of the C API, so they must not change.
+    let name: &'static [u8] = match architecture {
+        Architecture::Aarch64 => b"Aarch64\0",
+        Architecture::Aarch64_Ilp32 => b"Aarch64_Ilp32\0",
+        Architecture::Arm => b"Arm\0",
+        Architecture::Avr => b"Avr\0",
+        Architecture::Bpf => b"Bpf\0",
+        Architecture::Csky => b"Csky\0",
+        Architecture::I386 => b"I386\0",
+        Architecture::X86_64 => b"X86_64\0",
+        Architecture::X86_64_X32 => b"X86_64_X32\0",
+        Architecture::Hexagon => b"Hexagon\0",
+        Architecture::LoongArch64 => b"LoongArch64\0",
+        Architecture::Mips => b"Mips\0",
+        Architecture::Mips64 => b"Mips64\0",
+        Architecture::Msp430 => b"Msp430\0",
+        Architecture::PowerPc => b"PowerPc\0",
+        Architecture::PowerPc64 => b"PowerPc64\0",
+        Architecture::Riscv32 => b"Riscv32\0",
+        Architecture::Riscv64 => b"Riscv64\0",
+        Architecture::S390x => b"S390x\0",
+        Architecture::Sbf => b"Sbf\0",
+        Architecture::Sparc64 => b"Sparc64\0",
+        Architecture::Wasm32 => b"Wasm32\0",
+        Architecture::Wasm64 => b"Wasm64\0",
+        Architecture::Xtensa => b"Xtensa\0",
+        _ => b"Unknown\0",
+    };
+    CStr::from_bytes_with_nul(name).unwrap()
+}
+
+fn section_kind(kind: SectionKind) -> u32 {
+    match kind {
+        SectionKind::Unknown => OBJECT_SECTION_KIND_UNKNOWN,
+        SectionKind::Text => OBJECT_SECTION_KIND_TEXT,
+        SectionKind::Data => OBJECT_SECTION_KIND_DATA,
+        SectionKind::ReadOnlyData | SectionKind::ReadOnlyDataWithRel => {
+            OBJECT_SECTION_KIND_READ_ONLY_DATA
+        }
+        SectionKind::ReadOnlyString => OBJECT_SECTION_KIND_READ_ONLY_STRING,
+        SectionKind::UninitializedData | SectionKind::Common => {
+            OBJECT_SECTION_KIND_UNINITIALIZED_DATA
+        }
+        SectionKind::Tls | SectionKind::TlsVariables => OBJECT_SECTION_KIND_TLS,
+        SectionKind::UninitializedTls => OBJECT_SECTION_KIND_UNINITIALIZED_TLS,
+        SectionKind::Debug => OBJECT_SECTION_KIND_DEBUG,
+        SectionKind::Metadata => OBJECT_SECTION_KIND_METADATA,
+        _ => OBJECT_SECTION_KIND_OTHER,
+    }
+}
+
+fn symbol_kind(kind: SymbolKind) -> u32 {
+    match kind {
+        SymbolKind::Text => OBJECT_SYMBOL_KIND_TEXT,
+        SymbolKind::Data => OBJECT_SYMBOL_KIND_DATA,
+        SymbolKind::Section => OBJECT_SYMBOL_KIND_SECTION,
+        SymbolKind::File => OBJECT_SYMBOL_KIND_FILE,
+        SymbolKind::Label => OBJECT_SYMBOL_KIND_LABEL,
+        SymbolKind::Tls => OBJECT_SYMBOL_KIND_TLS,
+        _ => OBJECT_SYMBOL_KIND_UNKNOWN,
+    }
+}
+
+fn symbol_scope(scope: SymbolScope) -> u32 {
+    match scope {
+        SymbolScope::Compilation => OBJECT_SYMBOL_SCOPE_COMPILATION,
+        SymbolScope::Linkage => OBJECT_SYMBOL_SCOPE_LINKAGE,
+        SymbolScope::Dynamic => OBJECT_SYMBOL_SCOPE_DYNAMIC,
+        _ => OBJECT_SYMBOL_SCOPE_UNKNOWN,
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_error(message: String) {
+    // Error messages never contain null bytes, but be safe.
+    let message = CString::new(message.replace('\0', "")).unwrap();
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(message));
+}
+
+fn into_handle(data: Box<[u8]>) -> *mut ObjectFile {
+    match ObjectFile::parse(data) {
+        Ok(file) => Box::into_raw(Box::new(file)),
+        Err(e) => {
+            set_error(e.to_string());
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Return the message for the last error that occurred on this thread.
+///
+/// Returns null if no error has occurred. The message remains valid until the
+/// next failing call on this thread.
+#[no_mangle]
+pub extern "C" fn object_error_message() -> *const c_char {
+    LAST_ERROR.with(|e| match &*e.borrow() {
+        Some(message) => message.as_ptr(),
+        None => ptr::null(),
+    })
+}
+
+/// Read and parse the object file at the given null terminated path.
+///
+/// Returns null on failure.
+///
+/// # Safety
+///
+/// `path` must be a valid null terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn object_file_open(path: *const c_char) -> *mut ObjectFile {
+    if path.is_null() {
+        set_error("Null path".into());
+        return ptr::null_mut();
+    }
+    let path = match CStr::from_ptr(path).to_str() {
+        Ok(path) => path,
+        Err(_) => {
+            set_error("Invalid UTF-8 in path".into());
+            return ptr::null_mut();
+        }
+    };
+    match fs::read(path) {
+        Ok(data) => into_handle(data.into_boxed_slice()),
+        Err(e) => {
+            set_error(format!("Failed to read '{}': {}", path, e));
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Parse an object file from memory.
+///
+/// The data is copied, so it does not need to remain valid after this returns.
+/// Returns null on failure.
+///
+/// # Safety
+///
+/// `data` must point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn object_file_parse(data: *const u8, len: usize) -> *mut ObjectFile {
+    let data = if len == 0 {
+        &[]
+    } else if data.is_null() {
+        set_error("Null data".into());
+        return ptr::null_mut();
+    } else {
+        slice::from_raw_parts(data, len)
+    };
+    into_handle(data.into())
+}
+
+/// Free a file returned by `object_file_open` or `object_file_parse`.
+///
+/// # Safety
+///
+/// `file` must be null or a handle that has not already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn object_file_free(file: *mut ObjectFile) {
+    if !file.is_null() {
+        drop(Box::from_raw(file));
+    }
+}
+
+/// Return the file format, as one of the `OBJECT_FORMAT_*` constants.
+///
+/// # Safety
+///
+/// `file` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn object_file_format(file: *const ObjectFile) -> u32 {
+    (*file).format
+}
+
+/// Return the name of the architecture, such as `X86_64`, or `Unknown`.
+///
+/// The name is a static string.
+///
+/// # Safety
+///
+/// `file` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn object_file_architecture(file: *const ObjectFile) -> *const c_char {
+    (&*file).architecture.as_ptr()
+}
+
+/// Return true if the file is a 64-bit file.
+///
+/// # Safety
+///
+/// `file` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn object_file_is_64(file: *const ObjectFile) -> bool {
+    (*file).is_64
+}
+
+/// Return true if the file is little endian.
+///
+/// # Safety
+///
+/// `file` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn object_file_is_little_endian(file: *const ObjectFile) -> bool {
+    (*file).is_little_endian
+}
+
+/// Return the address of the entry point, or 0 if there is none.
+///
+/// # Safety
+///
+/// `file` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn object_file_entry(file: *const ObjectFile) -> u64 {
+    (*file).entry
+}
+
+macro_rules! accessors {
+    ($count:ident, $get:ident, $field:ident, $ty:ty, $one:literal, $many:literal) => {
+        #[doc = concat!("Return the number of ", $many, ".")]
+        ///
+        /// # Safety
+        ///
+        /// `file` must be a valid handle.
+        #[no_mangle]
+        pub unsafe extern "C" fn $count(file: *const ObjectFile) -> usize {
+            (&*file).$field.len()
+        }
+
+        #[doc = concat!("Return the ", $one, " at the given position.")]
+        ///
+        /// Returns null if the position is out of range. The result is valid until
+        /// the file is freed.
+        ///
+        /// # Safety
+        ///
+        /// `file` must be a valid handle.
+        #[no_mangle]
+        pub unsafe extern "C" fn $get(file: *const ObjectFile, i: usize) -> *const $ty {
+            match (&*file).$field.get(i) {
+                Some(item) => item,
+                None => ptr::null(),
+            }
+        }
+    };
+}
+
+accessors!(
+    object_file_section_count,
+    object_file_section,
+    sections,
+    ObjectSectionInfo,
+    "section",
+    "sections"
+);
+accessors!(
+    object_file_symbol_count,
+    object_file_symbol,
+    symbols,
+    ObjectSymbolInfo,
+    "symbol in the symbol table",
+    "symbols in the symbol table"
+);
+accessors!(
+    object_file_dynamic_symbol_count,
+    object_file_dynamic_symbol,
+    dynamic_symbols,
+    ObjectSymbolInfo,
+    "symbol in the dynamic symbol table",
+    "symbols in the dynamic symbol table"
+);
+accessors!(
+    object_file_import_count,
+    object_file_import,
+    imports,
+    ObjectImportInfo,
+    "imported symbol",
+    "imported symbols"
+);
+accessors!(
+    object_file_export_count,
+    object_file_export,
+    exports,
+    ObjectExportInfo,
+    "exported symbol",
+    "exported symbols"
+);
@@ -0,0 +1,80 @@
+/*
+ * Check that `include/object.h` matches the library.
+ *
+ * This is built and run in CI against the static library:
+ *
+ *     cargo build -p object-capi
+ *     cc -Wall -Wextra -Werror -I crates/capi/include crates/capi/tests/capi.c \
+ *         target/debug/libobject_capi.a -lpthread -ldl -lm -o capi
+ *     ./capi ./capi
+ */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "object.h"
+
+#define CHECK(cond)                                                         \
+    do {                                                                    \
+        if (!(cond)) {                                                      \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, \
+                    #cond);                                                 \
+            return 1;                                                       \
+        }                                                                   \
+    } while (0)
+
+int main(int argc, char **argv) {
+    object_file *file;
+    size_t i, count;
+    int text = 0;
+
+    CHECK(argc == 2);
+
+    CHECK(object_file_open("/nonexistent/object/file") == NULL);
+    CHECK(object_error_message() != NULL);
+
+    file = object_file_open(argv[1]);
+    if (file == NULL) {
+        fprintf(stderr, "%s\n", object_error_message());
+        return 1;
+    }
+
+    CHECK(object_file_format(file) != OBJECT_FORMAT_UNKNOWN);
+    CHECK(strcmp(object_file_architecture(file), "Unknown") != 0);
+    CHECK(object_file_is_64(file) == (sizeof(void *) == 8));
+    CHECK(object_file_entry(file) != 0);
+
+    count = object_file_section_count(file);
+    for (i = 0; i < count; i++) {
+        const object_section *section = object_file_section(file, i);
+        CHECK(section != NULL);
+        CHECK(section->index != OBJECT_NO_SECTION);
+        if (section->kind == OBJECT_SECTION_KIND_TEXT) {
+            CHECK(section->name.len != 0);
+            CHECK(section->data.data != NULL);
+            text = 1;
+        }
+    }
+    CHECK(text);
+    CHECK(object_file_section(file, count) == NULL);
+
+    count = object_file_dynamic_symbol_count(file);
+    for (i = 0; i < count; i++) {
+        const object_symbol *symbol = object_file_dynamic_symbol(file, i);
+        CHECK(symbol != NULL);
+        if (symbol->flags & OBJECT_SYMBOL_FLAG_UNDEFINED) {
+            CHECK(symbol->section_index == OBJECT_NO_SECTION);
+        }
+    }
+
+    count = object_file_import_count(file);
+    CHECK(count != 0);
+    for (i = 0; i < count; i++) {
+        const object_import *import = object_file_import(file, i);
+        CHECK(import != NULL);
+        CHECK(import->name.len != 0);
+    }
+
+    object_file_free(file);
+    return 0;
+}
@@ -0,0 +1,118 @@
+use std::ffi::{CStr, CString};
+use std::slice;
+
+use object_capi::*;
+
+fn bytes(bytes: &ObjectBytes) -> &[u8] {
+    if bytes.data.is_null() {
+        &[]
+    } else {
+        unsafe { slice::from_raw_parts(bytes.data, bytes.len) }
+    }
+}
+
+#[test]
+fn open_current_exe() {
+    // The test executable is a convenient file in the host format.
+    let path = std::env::current_exe().unwrap();
+    let path = CString::new(path.to_str().unwrap()).unwrap();
+
+    unsafe {
+        let file = object_file_open(path.as_ptr());
+        assert!(!file.is_null());
+
+        let format = object_file_format(file);
+        if cfg!(target_os = "linux") {
+            assert_eq!(format, OBJECT_FORMAT_ELF);
+        } else if cfg!(target_os = "macos") {
+            assert_eq!(format, OBJECT_FORMAT_MACHO);
+        } else if cfg!(target_os = "windows") {
+            assert_eq!(format, OBJECT_FORMAT_PE);
+        }
+        assert_eq!(object_file_is_64(file), cfg!(target_pointer_width = "64"));
+        assert_eq!(
+            object_file_is_little_endian(file),
+            cfg!(target_endian = "little")
+        );
+        let architecture = CStr::from_ptr(object_file_architecture(file)).to_str();
+        if cfg!(target_arch = "x86_64") {
+            assert_eq!(architecture, Ok("X86_64"));
+        } else if cfg!(target_arch = "aarch64") {
+            assert_eq!(architecture, Ok("Aarch64"));
+        } else {
+            assert_ne!(architecture, Ok(""));
+        }
+        assert_ne!(object_file_entry(file), 0);
+
+        let count = object_file_section_count(file);
+        let mut text = None;
+        for i in 0..count {
+            let section = &*object_file_section(file, i);
+            if section.kind == OBJECT_SECTION_KIND_TEXT {
+                assert!(!bytes(&section.name).is_empty());
+                assert!(!bytes(&section.data).is_empty());
+                text = Some(section.index);
+            }
+        }
+        assert!(text.is_some());
+        assert!(object_file_section(file, count).is_null());
+
+        // The executable imports symbols from the C library.
+        let count = object_file_import_count(file);
+        assert_ne!(count, 0);
+        for i in 0..count {
+            let import = &*object_file_import(file, i);
+            assert!(!bytes(&import.name).is_empty());
+        }
+        assert!(object_file_import(file, count).is_null());
+
+        for i in 0..object_file_dynamic_symbol_count(file) {
+            let symbol = &*object_file_dynamic_symbol(file, i);
+            if symbol.flags & OBJECT_SYMBOL_FLAG_UNDEFINED != 0 {
+                assert_eq!(symbol.section_index, OBJECT_NO_SECTION);
+            }
+        }
+
+        object_file_free(file);
+    }
+}
+
+#[test]
+fn parse_copies_data() {
+    let path = std::env::current_exe().unwrap();
+    let data = std::fs::read(path).unwrap();
+
+    unsafe {
+        let file = object_file_parse(data.as_ptr(), data.len());
+        assert!(!file.is_null());
+        let sections = object_file_section_count(file);
+        drop(data);
+        assert_eq!(object_file_section_count(file), sections);
+        for i in 0..sections {
+            let section = &*object_file_section(file, i);
+            // Reading the data must not access the freed buffer.
+            let _ = bytes(&section.data).iter().fold(0u8, |a, b| a ^ b);
+        }
+        object_file_free(file);
+    }
+}
+
+#[test]
+fn open_error() {
+    let path = CString::new("/nonexistent/object/file").unwrap();
+    unsafe {
+        let file = object_file_open(path.as_ptr());
+        assert!(file.is_null());
+        assert!(!object_error_message().is_null());
+    }
+}
+
+#[test]
+fn parse_error() {
+    unsafe {
+        let file = object_file_parse([0xffu8; 32].as_ptr(), 32);
+        assert!(file.is_null());
+        let message = CStr::from_ptr(object_error_message());
+        assert_eq!(message.to_str(), Ok("Unknown file magic"));
+    }
+}
@@ -0,0 +1,91 @@
+use object::write;
+use object::{Architecture, BinaryFormat, Endianness, Object, SectionKind, SymbolFlags};
+use object::{SymbolKind, SymbolScope};
+use std::time::{Duration, Instant};
+use std::{env, fs, process};
+
+/// Time `File::parse`, followed by counting the sections.
+///
+/// The files to use may be given on the command line. Otherwise, relocatable
+/// object files with `SMALL_COUNT` and `LARGE_COUNT` symbols are generated
+/// for each format. `File::parse` only reads the headers and locates the
+/// tables, so the time taken should not depend on the number of symbols.
+fn main() {
+    let paths = env::args().skip(1).collect::<Vec<_>>();
+    let mut files = Vec::new();
+    if paths.is_empty() {
+        for format in [
+            BinaryFormat::Coff,
+            BinaryFormat::Elf,
+            BinaryFormat::MachO,
+            BinaryFormat::Xcoff,
+        ] {
+            for count in [SMALL_COUNT, LARGE_COUNT] {
+                let name = format!("<generated {:?}, {} symbols>", format, count);
+                files.push((name, generate(format, count)));
+            }
+        }
+    }
+    for path in paths {
+        match fs::read(&path) {
+            Ok(data) => files.push((path, data)),
+            Err(err) => {
+                eprintln!("Failed to read file '{}': {}", path, err);
+                process::exit(1);
+            }
+        }
+    }
+
+    for (path, data) in &files {
+        let mut parse = Duration::default();
+        let mut sections = 0;
+        for _ in 0..ITERATIONS {
+            let start = Instant::now();
+            match object::File::parse(&**data) {
+                Ok(file) => sections = file.sections().count(),
+                Err(err) => {
+                    eprintln!("Failed to parse file '{}': {}", path, err);
+                    process::exit(1);
+                }
+            }
+            parse += start.elapsed();
+        }
+        println!(
+            "{}: {} bytes, {} sections, {:?} per parse",
+            path,
+            data.len(),
+            sections,
+            parse / ITERATIONS
+        );
+    }
+}
+
+const SMALL_COUNT: usize = 10;
+const LARGE_COUNT: usize = 100_000;
+const ITERATIONS: u32 = 1000;
+
+/// Generate a relocatable object with `count` defined symbols.
+fn generate(format: BinaryFormat, count: usize) -> Vec<u8> {
+    let (architecture, endian) = match format {
+        BinaryFormat::Xcoff => (Architecture::PowerPc64, Endianness::Big),
+        _ => (Architecture::X86_64, Endianness::Little),
+    };
+    let mut object = write::Object::new(format, architecture, endian);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &vec![0xc3; count], 1);
+    let data = object.add_section(Vec::new(), b".data".to_vec(), SectionKind::Data);
+    object.append_section_data(data, &[0; 8], 8);
+    for i in 0..count {
+        object.add_symbol(write::Symbol {
+            name: format!("func{}", i).into_bytes(),
+            value: i as u64,
+            size: 1,
+            kind: SymbolKind::Text,
+            scope: SymbolScope::Linkage,
+            weak: false,
+            section: write::SymbolSection::Section(text),
+            flags: SymbolFlags::None,
+        });
+    }
+    object.write().unwrap()
+}
@@ -0,0 +1,233 @@
+use object::pe;
+use object::read::pe::{PeFile, PeFile64};
+use object::write::pe::{NtHeaders, Writer};
+use object::{pod, LittleEndian as LE, Object, U16, U32};
+use std::time::{Duration, Instant};
+use std::{env, fs, process};
+
+/// Time the PE import and export iteration, both when collecting into a `Vec`
+/// and when using the iterators directly.
+///
+/// The files to use may be given on the command line. Otherwise, a PE file
+/// with `DEFAULT_COUNT` imports and exports is generated.
+fn main() {
+    let paths = env::args().skip(1).collect::<Vec<_>>();
+    let mut files = Vec::new();
+    if paths.is_empty() {
+        files.push(("<generated>".to_string(), generate(DEFAULT_COUNT)));
+    }
+    for path in paths {
+        match fs::read(&path) {
+            Ok(data) => files.push((path, data)),
+            Err(err) => {
+                eprintln!("Failed to read file '{}': {}", path, err);
+                process::exit(1);
+            }
+        }
+    }
+
+    for (path, data) in &files {
+        let result = match object::FileKind::parse(&**data) {
+            Ok(object::FileKind::Pe32) => bench::<pe::ImageNtHeaders32>(data),
+            Ok(object::FileKind::Pe64) => bench::<pe::ImageNtHeaders64>(data),
+            Ok(_) => {
+                eprintln!("File '{}' is not a PE file", path);
+                process::exit(1);
+            }
+            Err(err) => Err(err),
+        };
+        if let Err(err) = result {
+            eprintln!("Failed to benchmark file '{}': {}", path, err);
+            process::exit(1);
+        }
+    }
+}
+
+const DEFAULT_COUNT: u32 = 50_000;
+const ITERATIONS: u32 = 10;
+
+fn bench<Pe: object::read::pe::ImageNtHeaders>(data: &[u8]) -> object::read::Result<()> {
+    let file = PeFile::<Pe>::parse(data)?;
+
+    let mut imports = Duration::default();
+    let mut exports = Duration::default();
+    let mut import_iter = Duration::default();
+    let mut export_iter = Duration::default();
+    let mut import_count = 0;
+    let mut export_count = 0;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        import_count = file.imports()?.len();
+        imports += start.elapsed();
+
+        let start = Instant::now();
+        export_count = file.exports()?.len();
+        exports += start.elapsed();
+
+        let start = Instant::now();
+        let count = file.import_iter()?.try_fold(0, |n, i| i.map(|_| n + 1))?;
+        import_iter += start.elapsed();
+        assert_eq!(count, import_count);
+
+        let start = Instant::now();
+        let count = file.export_iter()?.try_fold(0, |n, e| e.map(|_| n + 1))?;
+        export_iter += start.elapsed();
+        assert_eq!(count, export_count);
+    }
+
+    println!("{} imports, {} exports", import_count, export_count);
+    println!("imports: {:?} per iteration", imports / ITERATIONS);
+    println!("exports: {:?} per iteration", exports / ITERATIONS);
+    println!("import_iter: {:?} per iteration", import_iter / ITERATIONS);
+    println!("export_iter: {:?} per iteration", export_iter / ITERATIONS);
+    Ok(())
+}
+
+/// Generate a PE file with `count` imports and `count` exports.
+///
+/// The imports are spread across several libraries, with the hint/name table
+/// in a different section from the import descriptors.
+fn generate(count: u32) -> Vec<u8> {
+    const LIBRARIES: u32 = 16;
+    let names = (0..count)
+        .map(|i| format!("function_{:06}", i))
+        .collect::<Vec<_>>();
+
+    // Hint/name table.
+    let mut rdata = Vec::new();
+    let mut hint_names = Vec::new();
+    for (i, name) in names.iter().enumerate() {
+        hint_names.push(rdata.len() as u32);
+        rdata.extend_from_slice(&(i as u16).to_le_bytes());
+        rdata.extend_from_slice(name.as_bytes());
+        rdata.push(0);
+        rdata.resize((rdata.len() + 1) & !1, 0);
+    }
+
+    // Import descriptors, followed by the import lookup tables and library names.
+    let idata_len = {
+        let descs = (LIBRARIES + 1) * 20;
+        let thunks = (count + LIBRARIES) * 8;
+        let names = LIBRARIES * 16;
+        descs + thunks + names
+    };
+
+    // Export directory, followed by the address, name pointer and ordinal tables.
+    let edata_len = {
+        let names = names.iter().map(|name| name.len() as u32 + 1).sum::<u32>();
+        40 + count * 10 + 16 + names
+    };
+
+    let mut data = Vec::new();
+    let mut writer = Writer::new(true, 0x1000, 0x200, &mut data);
+    writer.reserve_dos_header_and_stub();
+    writer.reserve_nt_headers(16);
+    writer.reserve_section_headers(3);
+    let rdata_range = writer.reserve_rdata_section(rdata.len() as u32);
+    let idata_range = writer.reserve_idata_section(idata_len);
+    let edata_range = writer.reserve_edata_section(edata_len);
+
+    let idata_address = idata_range.virtual_address;
+    let mut idata = vec![0; ((LIBRARIES + 1) * 20) as usize];
+    let mut thunks = Vec::new();
+    let mut library_names = Vec::new();
+    let thunks_address = idata_address + idata.len() as u32;
+    let library_names_address = thunks_address + (count + LIBRARIES) * 8;
+    let per_library = (count + LIBRARIES - 1) / LIBRARIES;
+    for library in 0..LIBRARIES {
+        let desc = pe::ImageImportDescriptor {
+            original_first_thunk: U32::new(LE, thunks_address + thunks.len() as u32),
+            time_date_stamp: U32::new(LE, 0),
+            forwarder_chain: U32::new(LE, 0),
+            name: U32::new(LE, library_names_address + library_names.len() as u32),
+            first_thunk: U32::new(LE, thunks_address + thunks.len() as u32),
+        };
+        let offset = (library * 20) as usize;
+        idata[offset..][..20].copy_from_slice(pod::bytes_of(&desc));
+        let start = library * per_library;
+        let end = (start + per_library).min(count);
+        for i in start..end {
+            let address = rdata_range.virtual_address + hint_names[i as usize];
+            thunks.extend_from_slice(&u64::from(address).to_le_bytes());
+        }
+        thunks.extend_from_slice(&0u64.to_le_bytes());
+        let mut name = format!("library{:02}.dll", library).into_bytes();
+        name.resize(16, 0);
+        library_names.extend_from_slice(&name);
+    }
+    idata.extend_from_slice(&thunks);
+    idata.resize((library_names_address - idata_address) as usize, 0);
+    idata.extend_from_slice(&library_names);
+
+    let edata_address = edata_range.virtual_address;
+    let functions = edata_address + 40;
+    let name_pointers = functions + count * 4;
+    let ordinals = name_pointers + count * 4;
+    let library_name = ordinals + count * 2;
+    let dir = pe::ImageExportDirectory {
+        characteristics: U32::new(LE, 0),
+        time_date_stamp: U32::new(LE, 0),
+        major_version: U16::new(LE, 0),
+        minor_version: U16::new(LE, 0),
+        name: U32::new(LE, library_name),
+        base: U32::new(LE, 1),
+        number_of_functions: U32::new(LE, count),
+        number_of_names: U32::new(LE, count),
+        address_of_functions: U32::new(LE, functions),
+        address_of_names: U32::new(LE, name_pointers),
+        address_of_name_ordinals: U32::new(LE, ordinals),
+    };
+    let mut edata = pod::bytes_of(&dir).to_vec();
+    for i in 0..count {
+        edata.extend_from_slice(&(rdata_range.virtual_address + i).to_le_bytes());
+    }
+    let mut name_address = library_name + 16;
+    for name in &names {
+        edata.extend_from_slice(&name_address.to_le_bytes());
+        name_address += name.len() as u32 + 1;
+    }
+    for i in 0..count {
+        edata.extend_from_slice(&(i as u16).to_le_bytes());
+    }
+    let mut name = b"generated.dll".to_vec();
+    name.resize(16, 0);
+    edata.extend_from_slice(&name);
+    for name in &names {
+        edata.extend_from_slice(name.as_bytes());
+        edata.push(0);
+    }
+    assert_eq!(edata.len() as u32, edata_len);
+
+    writer.write_dos_header_and_stub().unwrap();
+    writer.write_nt_headers(NtHeaders {
+        machine: pe::IMAGE_FILE_MACHINE_AMD64,
+        time_date_stamp: 0,
+        characteristics: pe::IMAGE_FILE_EXECUTABLE_IMAGE | pe::IMAGE_FILE_DLL,
+        major_linker_version: 0,
+        minor_linker_version: 0,
+        address_of_entry_point: 0,
+        image_base: 0x1_8000_0000,
+        major_operating_system_version: 6,
+        minor_operating_system_version: 0,
+        major_image_version: 0,
+        minor_image_version: 0,
+        major_subsystem_version: 6,
+        minor_subsystem_version: 0,
+        subsystem: pe::IMAGE_SUBSYSTEM_WINDOWS_CUI,
+        dll_characteristics: 0,
+        size_of_stack_reserve: 0x10_0000,
+        size_of_stack_commit: 0x1000,
+        size_of_heap_reserve: 0x10_0000,
+        size_of_heap_commit: 0x1000,
+    });
+    writer.write_section_headers();
+    writer.write_section(rdata_range.file_offset, &rdata);
+    writer.write_section(idata_range.file_offset, &idata);
+    writer.write_section(edata_range.file_offset, &edata);
+
+    // Check that the generated file is valid.
+    let file = PeFile64::parse(&*data).unwrap();
+    assert_eq!(file.imports().unwrap().len(), count as usize);
+    assert_eq!(file.exports().unwrap().len(), count as usize);
+    data
+}
@@ -1,5 +1,6 @@
 use super::*;
 use object::macho::*;
+use object::read::dump::macho::*;
 use object::read::macho::*;
 use object::BigEndian;
 
@@ -577,7 +578,7 @@ fn print_section<S: Section>(
             p.field_enum("Flags", flags, FLAGS_S_TYPE);
         } else {
             p.field_hex("Flags", section.flags(endian));
-            p.flags(flags, SECTION_TYPE, FLAGS_S_TYPE);
+            p.flags(flags, SECTION_TYPE.into(), FLAGS_S_TYPE);
             p.flags(flags, 0, FLAGS_S_ATTR);
         }
         if let Some(relocations) = section.relocations(endian, data).print_err(p) {
@@ -650,14 +651,14 @@ fn print_symtab<Mach: MachHeader>(
                         p.field_enum("Type", n_type, FLAGS_N_TYPE);
                     } else {
                         p.field_hex("Type", n_type);
-                        p.flags(n_type, N_TYPE, FLAGS_N_TYPE);
+                        p.flags(n_type, N_TYPE.into(), FLAGS_N_TYPE);
                         p.flags(n_type, 0, FLAGS_N_EXT);
                     }
                     p.field("Section", nlist.n_sect());
                     let n_desc = nlist.n_desc(endian);
                     p.field_hex("Desc", n_desc);
                     if nlist.is_undefined() {
-                        p.flags(n_desc, REFERENCE_TYPE, FLAGS_REFERENCE);
+                        p.flags(n_desc, REFERENCE_TYPE.into(), FLAGS_REFERENCE);
                     }
                     if !nlist.is_stab() {
                         p.flags(n_desc, 0, FLAGS_N_DESC);
@@ -690,386 +691,6 @@ fn print_cputype(p: &mut Printer<'_>, cputype: u32, cpusubtype: u32) {
     };
     p.field_enum("CpuType", cputype, FLAGS_CPU_TYPE);
     p.field_hex("CpuSubtype", cpusubtype);
-    p.flags(cpusubtype, !CPU_SUBTYPE_MASK, proc);
+    p.flags(cpusubtype, (!CPU_SUBTYPE_MASK).into(), proc);
     p.flags(cpusubtype, 0, FLAGS_CPU_SUBTYPE);
 }
-
-const FLAGS_CPU_TYPE: &[Flag<u32>] = &flags!(
-    CPU_TYPE_ANY,
-    CPU_TYPE_VAX,
-    CPU_TYPE_MC680X0,
-    CPU_TYPE_X86,
-    CPU_TYPE_X86_64,
-    CPU_TYPE_MIPS,
-    CPU_TYPE_MC98000,
-    CPU_TYPE_HPPA,
-    CPU_TYPE_ARM,
-    CPU_TYPE_ARM64,
-    CPU_TYPE_ARM64_32,
-    CPU_TYPE_MC88000,
-    CPU_TYPE_SPARC,
-    CPU_TYPE_I860,
-    CPU_TYPE_ALPHA,
-    CPU_TYPE_POWERPC,
-    CPU_TYPE_POWERPC64,
-);
-const FLAGS_CPU_SUBTYPE: &[Flag<u32>] = &flags!(CPU_SUBTYPE_LIB64);
-const FLAGS_CPU_SUBTYPE_ANY: &[Flag<u32>] = &flags!(
-    CPU_SUBTYPE_MULTIPLE,
-    CPU_SUBTYPE_LITTLE_ENDIAN,
-    CPU_SUBTYPE_BIG_ENDIAN,
-);
-const FLAGS_CPU_SUBTYPE_VAX: &[Flag<u32>] = &flags!(
-    CPU_SUBTYPE_VAX_ALL,
-    CPU_SUBTYPE_VAX780,
-    CPU_SUBTYPE_VAX785,
-    CPU_SUBTYPE_VAX750,
-    CPU_SUBTYPE_VAX730,
-    CPU_SUBTYPE_UVAXI,
-    CPU_SUBTYPE_UVAXII,
-    CPU_SUBTYPE_VAX8200,
-    CPU_SUBTYPE_VAX8500,
-    CPU_SUBTYPE_VAX8600,
-    CPU_SUBTYPE_VAX8650,
-    CPU_SUBTYPE_VAX8800,
-    CPU_SUBTYPE_UVAXIII,
-);
-const FLAGS_CPU_SUBTYPE_MC680X0: &[Flag<u32>] = &flags!(
-    CPU_SUBTYPE_MC680X0_ALL,
-    CPU_SUBTYPE_MC68040,
-    CPU_SUBTYPE_MC68030_ONLY,
-);
-const FLAGS_CPU_SUBTYPE_X86: &[Flag<u32>] = &flags!(
-    CPU_SUBTYPE_I386_ALL,
-    CPU_SUBTYPE_386,
-    CPU_SUBTYPE_486,
-    CPU_SUBTYPE_486SX,
-    CPU_SUBTYPE_586,
-    CPU_SUBTYPE_PENT,
-    CPU_SUBTYPE_PENTPRO,
-    CPU_SUBTYPE_PENTII_M3,
-    CPU_SUBTYPE_PENTII_M5,
-    CPU_SUBTYPE_CELERON,
-    CPU_SUBTYPE_CELERON_MOBILE,
-    CPU_SUBTYPE_PENTIUM_3,
-    CPU_SUBTYPE_PENTIUM_3_M,
-    CPU_SUBTYPE_PENTIUM_3_XEON,
-    CPU_SUBTYPE_PENTIUM_M,
-    CPU_SUBTYPE_PENTIUM_4,
-    CPU_SUBTYPE_PENTIUM_4_M,
-    CPU_SUBTYPE_ITANIUM,
-    CPU_SUBTYPE_ITANIUM_2,
-    CPU_SUBTYPE_XEON,
-    CPU_SUBTYPE_XEON_MP,
-);
-const FLAGS_CPU_SUBTYPE_X86_64: &[Flag<u32>] = &flags!(
-    CPU_SUBTYPE_X86_64_ALL,
-    CPU_SUBTYPE_X86_ARCH1,
-    CPU_SUBTYPE_X86_64_H,
-);
-const FLAGS_CPU_SUBTYPE_MIPS: &[Flag<u32>] = &flags!(
-    CPU_SUBTYPE_MIPS_ALL,
-    CPU_SUBTYPE_MIPS_R2300,
-    CPU_SUBTYPE_MIPS_R2600,
-    CPU_SUBTYPE_MIPS_R2800,
-    CPU_SUBTYPE_MIPS_R2000A,
-    CPU_SUBTYPE_MIPS_R2000,
-    CPU_SUBTYPE_MIPS_R3000A,
-    CPU_SUBTYPE_MIPS_R3000,
-);
-const FLAGS_CPU_SUBTYPE_MC98000: &[Flag<u32>] =
-    &flags!(CPU_SUBTYPE_MC98000_ALL, CPU_SUBTYPE_MC98601);
-const FLAGS_CPU_SUBTYPE_HPPA: &[Flag<u32>] = &flags!(CPU_SUBTYPE_HPPA_ALL, CPU_SUBTYPE_HPPA_7100LC);
-const FLAGS_CPU_SUBTYPE_MC88000: &[Flag<u32>] = &flags!(
-    CPU_SUBTYPE_MC88000_ALL,
-    CPU_SUBTYPE_MC88100,
-    CPU_SUBTYPE_MC88110,
-);
-const FLAGS_CPU_SUBTYPE_SPARC: &[Flag<u32>] = &flags!(CPU_SUBTYPE_SPARC_ALL);
-const FLAGS_CPU_SUBTYPE_I860: &[Flag<u32>] = &flags!(CPU_SUBTYPE_I860_ALL, CPU_SUBTYPE_I860_860);
-const FLAGS_CPU_SUBTYPE_POWERPC: &[Flag<u32>] = &flags!(
-    CPU_SUBTYPE_POWERPC_ALL,
-    CPU_SUBTYPE_POWERPC_601,
-    CPU_SUBTYPE_POWERPC_602,
-    CPU_SUBTYPE_POWERPC_603,
-    CPU_SUBTYPE_POWERPC_603E,
-    CPU_SUBTYPE_POWERPC_603EV,
-    CPU_SUBTYPE_POWERPC_604,
-    CPU_SUBTYPE_POWERPC_604E,
-    CPU_SUBTYPE_POWERPC_620,
-    CPU_SUBTYPE_POWERPC_750,
-    CPU_SUBTYPE_POWERPC_7400,
-    CPU_SUBTYPE_POWERPC_7450,
-    CPU_SUBTYPE_POWERPC_970,
-);
-const FLAGS_CPU_SUBTYPE_ARM: &[Flag<u32>] = &flags!(
-    CPU_SUBTYPE_ARM_ALL,
-    CPU_SUBTYPE_ARM_V4T,
-    CPU_SUBTYPE_ARM_V6,
-    CPU_SUBTYPE_ARM_V5TEJ,
-    CPU_SUBTYPE_ARM_XSCALE,
-    CPU_SUBTYPE_ARM_V7,
-    CPU_SUBTYPE_ARM_V7F,
-    CPU_SUBTYPE_ARM_V7S,
-    CPU_SUBTYPE_ARM_V7K,
-    CPU_SUBTYPE_ARM_V8,
-    CPU_SUBTYPE_ARM_V6M,
-    CPU_SUBTYPE_ARM_V7M,
-    CPU_SUBTYPE_ARM_V7EM,
-    CPU_SUBTYPE_ARM_V8M,
-);
-const FLAGS_CPU_SUBTYPE_ARM64: &[Flag<u32>] = &flags!(
-    CPU_SUBTYPE_ARM64_ALL,
-    CPU_SUBTYPE_ARM64_V8,
-    CPU_SUBTYPE_ARM64E,
-);
-const FLAGS_CPU_SUBTYPE_ARM64_32: &[Flag<u32>] =
-    &flags!(CPU_SUBTYPE_ARM64_32_ALL, CPU_SUBTYPE_ARM64_32_V8);
-const FLAGS_MH_FILETYPE: &[Flag<u32>] = &flags!(
-    MH_OBJECT,
-    MH_EXECUTE,
-    MH_FVMLIB,
-    MH_CORE,
-    MH_PRELOAD,
-    MH_DYLIB,
-    MH_DYLINKER,
-    MH_BUNDLE,
-    MH_DYLIB_STUB,
-    MH_DSYM,
-    MH_KEXT_BUNDLE,
-    MH_FILESET,
-);
-const FLAGS_MH: &[Flag<u32>] = &flags!(
-    MH_NOUNDEFS,
-    MH_INCRLINK,
-    MH_DYLDLINK,
-    MH_BINDATLOAD,
-    MH_PREBOUND,
-    MH_SPLIT_SEGS,
-    MH_LAZY_INIT,
-    MH_TWOLEVEL,
-    MH_FORCE_FLAT,
-    MH_NOMULTIDEFS,
-    MH_NOFIXPREBINDING,
-    MH_PREBINDABLE,
-    MH_ALLMODSBOUND,
-    MH_SUBSECTIONS_VIA_SYMBOLS,
-    MH_CANONICAL,
-    MH_WEAK_DEFINES,
-    MH_BINDS_TO_WEAK,
-    MH_ALLOW_STACK_EXECUTION,
-    MH_ROOT_SAFE,
-    MH_SETUID_SAFE,
-    MH_NO_REEXPORTED_DYLIBS,
-    MH_PIE,
-    MH_DEAD_STRIPPABLE_DYLIB,
-    MH_HAS_TLV_DESCRIPTORS,
-    MH_NO_HEAP_EXECUTION,
-    MH_APP_EXTENSION_SAFE,
-    MH_NLIST_OUTOFSYNC_WITH_DYLDINFO,
-    MH_SIM_SUPPORT,
-    MH_DYLIB_IN_CACHE,
-);
-const FLAGS_LC: &[Flag<u32>] = &flags!(
-    LC_SEGMENT,
-    LC_SYMTAB,
-    LC_SYMSEG,
-    LC_THREAD,
-    LC_UNIXTHREAD,
-    LC_LOADFVMLIB,
-    LC_IDFVMLIB,
-    LC_IDENT,
-    LC_FVMFILE,
-    LC_PREPAGE,
-    LC_DYSYMTAB,
-    LC_LOAD_DYLIB,
-    LC_ID_DYLIB,
-    LC_LOAD_DYLINKER,
-    LC_ID_DYLINKER,
-    LC_PREBOUND_DYLIB,
-    LC_ROUTINES,
-    LC_SUB_FRAMEWORK,
-    LC_SUB_UMBRELLA,
-    LC_SUB_CLIENT,
-    LC_SUB_LIBRARY,
-    LC_TWOLEVEL_HINTS,
-    LC_PREBIND_CKSUM,
-    LC_LOAD_WEAK_DYLIB,
-    LC_SEGMENT_64,
-    LC_ROUTINES_64,
-    LC_UUID,
-    LC_RPATH,
-    LC_CODE_SIGNATURE,
-    LC_SEGMENT_SPLIT_INFO,
-    LC_REEXPORT_DYLIB,
-    LC_LAZY_LOAD_DYLIB,
-    LC_ENCRYPTION_INFO,
-    LC_DYLD_INFO,
-    LC_DYLD_INFO_ONLY,
-    LC_LOAD_UPWARD_DYLIB,
-    LC_VERSION_MIN_MACOSX,
-    LC_VERSION_MIN_IPHONEOS,
-    LC_FUNCTION_STARTS,
-    LC_DYLD_ENVIRONMENT,
-    LC_MAIN,
-    LC_DATA_IN_CODE,
-    LC_SOURCE_VERSION,
-    LC_DYLIB_CODE_SIGN_DRS,
-    LC_ENCRYPTION_INFO_64,
-    LC_LINKER_OPTION,
-    LC_LINKER_OPTIMIZATION_HINT,
-    LC_VERSION_MIN_TVOS,
-    LC_VERSION_MIN_WATCHOS,
-    LC_NOTE,
-    LC_BUILD_VERSION,
-    LC_DYLD_EXPORTS_TRIE,
-    LC_DYLD_CHAINED_FIXUPS,
-    LC_FILESET_ENTRY,
-);
-const FLAGS_VM: &[Flag<u32>] = &flags!(VM_PROT_READ, VM_PROT_WRITE, VM_PROT_EXECUTE);
-const FLAGS_SG: &[Flag<u32>] = &flags!(
-    SG_HIGHVM,
-    SG_FVMLIB,
-    SG_NORELOC,
-    SG_PROTECTED_VERSION_1,
-    SG_READ_ONLY,
-);
-const FLAGS_S_TYPE: &[Flag<u32>] = &flags!(
-    S_REGULAR,
-    S_ZEROFILL,
-    S_CSTRING_LITERALS,
-    S_4BYTE_LITERALS,
-    S_8BYTE_LITERALS,
-    S_LITERAL_POINTERS,
-    S_NON_LAZY_SYMBOL_POINTERS,
-    S_LAZY_SYMBOL_POINTERS,
-    S_SYMBOL_STUBS,
-    S_MOD_INIT_FUNC_POINTERS,
-    S_MOD_TERM_FUNC_POINTERS,
-    S_COALESCED,
-    S_GB_ZEROFILL,
-    S_INTERPOSING,
-    S_16BYTE_LITERALS,
-    S_DTRACE_DOF,
-    S_LAZY_DYLIB_SYMBOL_POINTERS,
-    S_THREAD_LOCAL_REGULAR,
-    S_THREAD_LOCAL_ZEROFILL,
-    S_THREAD_LOCAL_VARIABLES,
-    S_THREAD_LOCAL_VARIABLE_POINTERS,
-    S_THREAD_LOCAL_INIT_FUNCTION_POINTERS,
-    S_INIT_FUNC_OFFSETS,
-);
-const FLAGS_S_ATTR: &[Flag<u32>] = &flags!(
-    S_ATTR_PURE_INSTRUCTIONS,
-    S_ATTR_NO_TOC,
-    S_ATTR_STRIP_STATIC_SYMS,
-    S_ATTR_NO_DEAD_STRIP,
-    S_ATTR_LIVE_SUPPORT,
-    S_ATTR_SELF_MODIFYING_CODE,
-    S_ATTR_DEBUG,
-    S_ATTR_SOME_INSTRUCTIONS,
-    S_ATTR_EXT_RELOC,
-    S_ATTR_LOC_RELOC,
-);
-const FLAGS_PLATFORM: &[Flag<u32>] = &flags!(
-    PLATFORM_MACOS,
-    PLATFORM_IOS,
-    PLATFORM_TVOS,
-    PLATFORM_WATCHOS,
-    PLATFORM_BRIDGEOS,
-    PLATFORM_MACCATALYST,
-    PLATFORM_IOSSIMULATOR,
-    PLATFORM_TVOSSIMULATOR,
-    PLATFORM_WATCHOSSIMULATOR,
-    PLATFORM_DRIVERKIT,
-);
-const FLAGS_N_EXT: &[Flag<u8>] = &flags!(N_PEXT, N_EXT);
-const FLAGS_N_TYPE: &[Flag<u8>] = &flags!(N_UNDF, N_ABS, N_SECT, N_PBUD, N_INDR);
-const FLAGS_N_STAB: &[Flag<u8>] = &flags!(
-    N_GSYM, N_FNAME, N_FUN, N_STSYM, N_LCSYM, N_BNSYM, N_AST, N_OPT, N_RSYM, N_SLINE, N_ENSYM,
-    N_SSYM, N_SO, N_OSO, N_LSYM, N_BINCL, N_SOL, N_PARAMS, N_VERSION, N_OLEVEL, N_PSYM, N_EINCL,
-    N_ENTRY, N_LBRAC, N_EXCL, N_RBRAC, N_BCOMM, N_ECOMM, N_ECOML, N_LENG, N_PC,
-);
-const FLAGS_REFERENCE: &[Flag<u16>] = &flags!(
-    REFERENCE_FLAG_UNDEFINED_NON_LAZY,
-    REFERENCE_FLAG_UNDEFINED_LAZY,
-    REFERENCE_FLAG_DEFINED,
-    REFERENCE_FLAG_PRIVATE_DEFINED,
-    REFERENCE_FLAG_PRIVATE_UNDEFINED_NON_LAZY,
-    REFERENCE_FLAG_PRIVATE_UNDEFINED_LAZY,
-);
-const FLAGS_N_DESC: &[Flag<u16>] = &flags!(
-    REFERENCED_DYNAMICALLY,
-    N_NO_DEAD_STRIP,
-    N_DESC_DISCARDED,
-    N_WEAK_REF,
-    N_WEAK_DEF,
-    N_REF_TO_WEAK,
-    N_ARM_THUMB_DEF,
-    N_SYMBOL_RESOLVER,
-    N_ALT_ENTRY,
-);
-const FLAGS_GENERIC_RELOC: &[Flag<u8>] = &flags!(
-    GENERIC_RELOC_VANILLA,
-    GENERIC_RELOC_PAIR,
-    GENERIC_RELOC_SECTDIFF,
-    GENERIC_RELOC_PB_LA_PTR,
-    GENERIC_RELOC_LOCAL_SECTDIFF,
-    GENERIC_RELOC_TLV,
-);
-const FLAGS_ARM_RELOC: &[Flag<u8>] = &flags!(
-    ARM_RELOC_VANILLA,
-    ARM_RELOC_PAIR,
-    ARM_RELOC_SECTDIFF,
-    ARM_RELOC_LOCAL_SECTDIFF,
-    ARM_RELOC_PB_LA_PTR,
-    ARM_RELOC_BR24,
-    ARM_THUMB_RELOC_BR22,
-    ARM_THUMB_32BIT_BRANCH,
-    ARM_RELOC_HALF,
-    ARM_RELOC_HALF_SECTDIFF,
-);
-const FLAGS_ARM64_RELOC: &[Flag<u8>] = &flags!(
-    ARM64_RELOC_UNSIGNED,
-    ARM64_RELOC_SUBTRACTOR,
-    ARM64_RELOC_BRANCH26,
-    ARM64_RELOC_PAGE21,
-    ARM64_RELOC_PAGEOFF12,
-    ARM64_RELOC_GOT_LOAD_PAGE21,
-    ARM64_RELOC_GOT_LOAD_PAGEOFF12,
-    ARM64_RELOC_POINTER_TO_GOT,
-    ARM64_RELOC_TLVP_LOAD_PAGE21,
-    ARM64_RELOC_TLVP_LOAD_PAGEOFF12,
-    ARM64_RELOC_ADDEND,
-    ARM64_RELOC_AUTHENTICATED_POINTER,
-);
-const FLAGS_PPC_RELOC: &[Flag<u8>] = &flags!(
-    PPC_RELOC_VANILLA,
-    PPC_RELOC_PAIR,
-    PPC_RELOC_BR14,
-    PPC_RELOC_BR24,
-    PPC_RELOC_HI16,
-    PPC_RELOC_LO16,
-    PPC_RELOC_HA16,
-    PPC_RELOC_LO14,
-    PPC_RELOC_SECTDIFF,
-    PPC_RELOC_PB_LA_PTR,
-    PPC_RELOC_HI16_SECTDIFF,
-    PPC_RELOC_LO16_SECTDIFF,
-    PPC_RELOC_HA16_SECTDIFF,
-    PPC_RELOC_JBSR,
-    PPC_RELOC_LO14_SECTDIFF,
-    PPC_RELOC_LOCAL_SECTDIFF,
-);
-const FLAGS_X86_64_RELOC: &[Flag<u8>] = &flags!(
-    X86_64_RELOC_UNSIGNED,
-    X86_64_RELOC_SIGNED,
-    X86_64_RELOC_BRANCH,
-    X86_64_RELOC_GOT_LOAD,
-    X86_64_RELOC_GOT,
-    X86_64_RELOC_SUBTRACTOR,
-    X86_64_RELOC_SIGNED_1,
-    X86_64_RELOC_SIGNED_2,
-    X86_64_RELOC_SIGNED_4,
-    X86_64_RELOC_TLV,
-);
@@ -2,6 +2,7 @@ use std::io::Write;
 use std::{fmt, str};
 
 use object::read::archive::ArchiveFile;
+use object::read::dump::Names;
 use object::read::macho::{FatArch, FatHeader};
 use object::Endianness;
 
@@ -105,39 +106,49 @@ impl<'a> Printer<'a> {
         writeln!(self.w).unwrap();
     }
 
-    fn field_enum<T: Eq + fmt::UpperHex>(&mut self, name: &str, value: T, flags: &[Flag<T>]) {
-        for flag in flags {
-            if value == flag.value {
+    fn field_enum<T: Copy + Into<u64> + fmt::UpperHex>(
+        &mut self,
+        name: &str,
+        value: T,
+        flags: Names,
+    ) {
+        for &(flag, flag_name) in flags {
+            if value.into() == flag {
                 self.field_name(name);
-                writeln!(self.w, "{} (0x{:X})", flag.name, value).unwrap();
+                writeln!(self.w, "{} (0x{:X})", flag_name, value).unwrap();
                 return;
             }
         }
         self.field_hex(name, value);
     }
 
-    fn field_enum_display<T: Eq + fmt::Display>(
+    fn field_enum_display<T: Copy + Into<i64> + fmt::Display>(
         &mut self,
         name: &str,
         value: T,
-        flags: &[Flag<T>],
+        flags: Names,
     ) {
-        for flag in flags {
-            if value == flag.value {
+        for &(flag, flag_name) in flags {
+            if value.into() as u64 == flag {
                 self.field_name(name);
-                writeln!(self.w, "{} ({})", flag.name, value).unwrap();
+                writeln!(self.w, "{} ({})", flag_name, value).unwrap();
                 return;
             }
         }
         self.field(name, value);
     }
 
-    fn field_enums<T: Eq + fmt::UpperHex>(&mut self, name: &str, value: T, enums: &[&[Flag<T>]]) {
+    fn field_enums<T: Copy + Into<u64> + fmt::UpperHex>(
+        &mut self,
+        name: &str,
+        value: T,
+        enums: &[Names],
+    ) {
         for flags in enums {
-            for flag in *flags {
-                if value == flag.value {
+            for &(flag, flag_name) in *flags {
+                if value.into() == flag {
                     self.field_name(name);
-                    writeln!(self.w, "{} (0x{:X})", flag.name, value).unwrap();
+                    writeln!(self.w, "{} (0x{:X})", flag_name, value).unwrap();
                     return;
                 }
             }
@@ -145,25 +156,24 @@ impl<'a> Printer<'a> {
         self.field_hex(name, value);
     }
 
-    fn flags<T: Into<u64>, U: Copy + Into<u64>>(&mut self, value: T, mask: U, flags: &[Flag<U>]) {
+    fn flags<T: Into<u64>>(&mut self, value: T, mask: u64, flags: Names) {
         let value = value.into();
-        let mask = mask.into();
         self.indent(|p| {
             if mask != 0 {
-                for flag in flags {
-                    if value & mask == flag.value.into() {
+                for &(flag, flag_name) in flags {
+                    if value & mask == flag {
                         p.print_indent();
-                        writeln!(p.w, "{} (0x{:X})", flag.name, flag.value.into()).unwrap();
+                        writeln!(p.w, "{} (0x{:X})", flag_name, flag).unwrap();
                         return;
                     }
                 }
                 p.print_indent();
                 writeln!(p.w, "<unknown> (0x{:X})", value & mask).unwrap();
             } else {
-                for flag in flags {
-                    if value & flag.value.into() == flag.value.into() {
+                for &(flag, flag_name) in flags {
+                    if value & flag == flag {
                         p.print_indent();
-                        writeln!(p.w, "{} (0x{:X})", flag.name, flag.value.into()).unwrap();
+                        writeln!(p.w, "{} (0x{:X})", flag_name, flag).unwrap();
                     }
                 }
                 // TODO: display unknown flags (need to display all flags at once for this)
@@ -172,15 +182,6 @@ impl<'a> Printer<'a> {
     }
 }
 
-struct Flag<T> {
-    value: T,
-    name: &'static str,
-}
-
-macro_rules! flags {
-    ($($name:ident),+ $(,)?) => ( [ $(Flag { value: $name, name: stringify!($name), }),+ ] )
-}
-
 fn print_object(p: &mut Printer<'_>, data: &[u8]) {
     let kind = match object::FileKind::parse(data) {
         Ok(file) => file,
@@ -2,6 +2,7 @@ use super::*;
 use object::pe::*;
 use object::read::coff::ImageSymbol as _;
 use object::read::coff::*;
+use object::read::dump::coff::*;
 use object::read::pe::*;
 use object::LittleEndian as LE;
 use object::{Bytes, U32Bytes, U64Bytes};
@@ -120,7 +121,7 @@ fn print_pe<Pe: ImageNtHeaders>(p: &mut Printer<'_>, data: &[u8]) {
             print_optional(p, nt_headers.optional_header());
             for (index, dir) in data_directories.iter().enumerate() {
                 p.group("ImageDataDirectory", |p| {
-                    p.field_enum("Index", index, FLAGS_IMAGE_DIRECTORY_ENTRY);
+                    p.field_enum("Index", index as u64, FLAGS_IMAGE_DIRECTORY_ENTRY);
                     p.field_hex("VirtualAddress", dir.virtual_address.get(LE));
                     p.field_hex("Size", dir.size.get(LE));
                 });
@@ -560,7 +561,7 @@ fn print_sections<'data, Coff: CoffHeader>(
             if section.characteristics.get(LE) & IMAGE_SCN_ALIGN_MASK != 0 {
                 p.flags(
                     section.characteristics.get(LE),
-                    IMAGE_SCN_ALIGN_MASK,
+                    IMAGE_SCN_ALIGN_MASK.into(),
                     FLAGS_IMAGE_SCN_ALIGN,
                 );
             }
@@ -745,535 +746,3 @@ fn print_reloc_dir(
     }
     Some(())
 }
-
-const FLAGS_IMAGE_FILE: &[Flag<u16>] = &flags!(
-    IMAGE_FILE_RELOCS_STRIPPED,
-    IMAGE_FILE_EXECUTABLE_IMAGE,
-    IMAGE_FILE_LINE_NUMS_STRIPPED,
-    IMAGE_FILE_LOCAL_SYMS_STRIPPED,
-    IMAGE_FILE_AGGRESIVE_WS_TRIM,
-    IMAGE_FILE_LARGE_ADDRESS_AWARE,
-    IMAGE_FILE_BYTES_REVERSED_LO,
-    IMAGE_FILE_32BIT_MACHINE,
-    IMAGE_FILE_DEBUG_STRIPPED,
-    IMAGE_FILE_REMOVABLE_RUN_FROM_SWAP,
-    IMAGE_FILE_NET_RUN_FROM_SWAP,
-    IMAGE_FILE_SYSTEM,
-    IMAGE_FILE_DLL,
-    IMAGE_FILE_UP_SYSTEM_ONLY,
-    IMAGE_FILE_BYTES_REVERSED_HI,
-);
-const FLAGS_IMAGE_FILE_MACHINE: &[Flag<u16>] = &flags!(
-    IMAGE_FILE_MACHINE_UNKNOWN,
-    IMAGE_FILE_MACHINE_TARGET_HOST,
-    IMAGE_FILE_MACHINE_I386,
-    IMAGE_FILE_MACHINE_R3000,
-    IMAGE_FILE_MACHINE_R4000,
-    IMAGE_FILE_MACHINE_R10000,
-    IMAGE_FILE_MACHINE_WCEMIPSV2,
-    IMAGE_FILE_MACHINE_ALPHA,
-    IMAGE_FILE_MACHINE_SH3,
-    IMAGE_FILE_MACHINE_SH3DSP,
-    IMAGE_FILE_MACHINE_SH3E,
-    IMAGE_FILE_MACHINE_SH4,
-    IMAGE_FILE_MACHINE_SH5,
-    IMAGE_FILE_MACHINE_ARM,
-    IMAGE_FILE_MACHINE_THUMB,
-    IMAGE_FILE_MACHINE_ARMNT,
-    IMAGE_FILE_MACHINE_AM33,
-    IMAGE_FILE_MACHINE_POWERPC,
-    IMAGE_FILE_MACHINE_POWERPCFP,
-    IMAGE_FILE_MACHINE_IA64,
-    IMAGE_FILE_MACHINE_MIPS16,
-    IMAGE_FILE_MACHINE_ALPHA64,
-    IMAGE_FILE_MACHINE_MIPSFPU,
-    IMAGE_FILE_MACHINE_MIPSFPU16,
-    IMAGE_FILE_MACHINE_AXP64,
-    IMAGE_FILE_MACHINE_TRICORE,
-    IMAGE_FILE_MACHINE_CEF,
-    IMAGE_FILE_MACHINE_EBC,
-    IMAGE_FILE_MACHINE_AMD64,
-    IMAGE_FILE_MACHINE_M32R,
-    IMAGE_FILE_MACHINE_ARM64,
-    IMAGE_FILE_MACHINE_CEE,
-    IMAGE_FILE_MACHINE_RISCV32,
-    IMAGE_FILE_MACHINE_RISCV64,
-    IMAGE_FILE_MACHINE_RISCV128,
-);
-const FLAGS_IMAGE_SCN: &[Flag<u32>] = &flags!(
-    IMAGE_SCN_TYPE_NO_PAD,
-    IMAGE_SCN_CNT_CODE,
-    IMAGE_SCN_CNT_INITIALIZED_DATA,
-    IMAGE_SCN_CNT_UNINITIALIZED_DATA,
-    IMAGE_SCN_LNK_OTHER,
-    IMAGE_SCN_LNK_INFO,
-    IMAGE_SCN_LNK_REMOVE,
-    IMAGE_SCN_LNK_COMDAT,
-    IMAGE_SCN_NO_DEFER_SPEC_EXC,
-    IMAGE_SCN_GPREL,
-    IMAGE_SCN_MEM_FARDATA,
-    IMAGE_SCN_MEM_PURGEABLE,
-    IMAGE_SCN_MEM_16BIT,
-    IMAGE_SCN_MEM_LOCKED,
-    IMAGE_SCN_MEM_PRELOAD,
-    IMAGE_SCN_LNK_NRELOC_OVFL,
-    IMAGE_SCN_MEM_DISCARDABLE,
-    IMAGE_SCN_MEM_NOT_CACHED,
-    IMAGE_SCN_MEM_NOT_PAGED,
-    IMAGE_SCN_MEM_SHARED,
-    IMAGE_SCN_MEM_EXECUTE,
-    IMAGE_SCN_MEM_READ,
-    IMAGE_SCN_MEM_WRITE,
-);
-const FLAGS_IMAGE_SCN_ALIGN: &[Flag<u32>] = &flags!(
-    IMAGE_SCN_ALIGN_1BYTES,
-    IMAGE_SCN_ALIGN_2BYTES,
-    IMAGE_SCN_ALIGN_4BYTES,
-    IMAGE_SCN_ALIGN_8BYTES,
-    IMAGE_SCN_ALIGN_16BYTES,
-    IMAGE_SCN_ALIGN_32BYTES,
-    IMAGE_SCN_ALIGN_64BYTES,
-    IMAGE_SCN_ALIGN_128BYTES,
-    IMAGE_SCN_ALIGN_256BYTES,
-    IMAGE_SCN_ALIGN_512BYTES,
-    IMAGE_SCN_ALIGN_1024BYTES,
-    IMAGE_SCN_ALIGN_2048BYTES,
-    IMAGE_SCN_ALIGN_4096BYTES,
-    IMAGE_SCN_ALIGN_8192BYTES,
-);
-const FLAGS_IMAGE_REL_I386: &[Flag<u16>] = &flags!(
-    IMAGE_REL_I386_ABSOLUTE,
-    IMAGE_REL_I386_DIR16,
-    IMAGE_REL_I386_REL16,
-    IMAGE_REL_I386_DIR32,
-    IMAGE_REL_I386_DIR32NB,
-    IMAGE_REL_I386_SEG12,
-    IMAGE_REL_I386_SECTION,
-    IMAGE_REL_I386_SECREL,
-    IMAGE_REL_I386_TOKEN,
-    IMAGE_REL_I386_SECREL7,
-    IMAGE_REL_I386_REL32,
-);
-const FLAGS_IMAGE_REL_MIPS: &[Flag<u16>] = &flags!(
-    IMAGE_REL_MIPS_ABSOLUTE,
-    IMAGE_REL_MIPS_REFHALF,
-    IMAGE_REL_MIPS_REFWORD,
-    IMAGE_REL_MIPS_JMPADDR,
-    IMAGE_REL_MIPS_REFHI,
-    IMAGE_REL_MIPS_REFLO,
-    IMAGE_REL_MIPS_GPREL,
-    IMAGE_REL_MIPS_LITERAL,
-    IMAGE_REL_MIPS_SECTION,
-    IMAGE_REL_MIPS_SECREL,
-    IMAGE_REL_MIPS_SECRELLO,
-    IMAGE_REL_MIPS_SECRELHI,
-    IMAGE_REL_MIPS_TOKEN,
-    IMAGE_REL_MIPS_JMPADDR16,
-    IMAGE_REL_MIPS_REFWORDNB,
-    IMAGE_REL_MIPS_PAIR,
-);
-const FLAGS_IMAGE_REL_ALPHA: &[Flag<u16>] = &flags!(
-    IMAGE_REL_ALPHA_ABSOLUTE,
-    IMAGE_REL_ALPHA_REFLONG,
-    IMAGE_REL_ALPHA_REFQUAD,
-    IMAGE_REL_ALPHA_GPREL32,
-    IMAGE_REL_ALPHA_LITERAL,
-    IMAGE_REL_ALPHA_LITUSE,
-    IMAGE_REL_ALPHA_GPDISP,
-    IMAGE_REL_ALPHA_BRADDR,
-    IMAGE_REL_ALPHA_HINT,
-    IMAGE_REL_ALPHA_INLINE_REFLONG,
-    IMAGE_REL_ALPHA_REFHI,
-    IMAGE_REL_ALPHA_REFLO,
-    IMAGE_REL_ALPHA_PAIR,
-    IMAGE_REL_ALPHA_MATCH,
-    IMAGE_REL_ALPHA_SECTION,
-    IMAGE_REL_ALPHA_SECREL,
-    IMAGE_REL_ALPHA_REFLONGNB,
-    IMAGE_REL_ALPHA_SECRELLO,
-    IMAGE_REL_ALPHA_SECRELHI,
-    IMAGE_REL_ALPHA_REFQ3,
-    IMAGE_REL_ALPHA_REFQ2,
-    IMAGE_REL_ALPHA_REFQ1,
-    IMAGE_REL_ALPHA_GPRELLO,
-    IMAGE_REL_ALPHA_GPRELHI,
-);
-const FLAGS_IMAGE_REL_PPC: &[Flag<u16>] = &flags!(
-    IMAGE_REL_PPC_ABSOLUTE,
-    IMAGE_REL_PPC_ADDR64,
-    IMAGE_REL_PPC_ADDR32,
-    IMAGE_REL_PPC_ADDR24,
-    IMAGE_REL_PPC_ADDR16,
-    IMAGE_REL_PPC_ADDR14,
-    IMAGE_REL_PPC_REL24,
-    IMAGE_REL_PPC_REL14,
-    IMAGE_REL_PPC_TOCREL16,
-    IMAGE_REL_PPC_TOCREL14,
-    IMAGE_REL_PPC_ADDR32NB,
-    IMAGE_REL_PPC_SECREL,
-    IMAGE_REL_PPC_SECTION,
-    IMAGE_REL_PPC_IFGLUE,
-    IMAGE_REL_PPC_IMGLUE,
-    IMAGE_REL_PPC_SECREL16,
-    IMAGE_REL_PPC_REFHI,
-    IMAGE_REL_PPC_REFLO,
-    IMAGE_REL_PPC_PAIR,
-    IMAGE_REL_PPC_SECRELLO,
-    IMAGE_REL_PPC_SECRELHI,
-    IMAGE_REL_PPC_GPREL,
-    IMAGE_REL_PPC_TOKEN,
-);
-const FLAGS_IMAGE_REL_PPC_BITS: &[Flag<u16>] = &flags!(
-    IMAGE_REL_PPC_NEG,
-    IMAGE_REL_PPC_BRTAKEN,
-    IMAGE_REL_PPC_BRNTAKEN,
-    IMAGE_REL_PPC_TOCDEFN,
-);
-const FLAGS_IMAGE_REL_SH: &[Flag<u16>] = &flags!(
-    IMAGE_REL_SH3_ABSOLUTE,
-    IMAGE_REL_SH3_DIRECT16,
-    IMAGE_REL_SH3_DIRECT32,
-    IMAGE_REL_SH3_DIRECT8,
-    IMAGE_REL_SH3_DIRECT8_WORD,
-    IMAGE_REL_SH3_DIRECT8_LONG,
-    IMAGE_REL_SH3_DIRECT4,
-    IMAGE_REL_SH3_DIRECT4_WORD,
-    IMAGE_REL_SH3_DIRECT4_LONG,
-    IMAGE_REL_SH3_PCREL8_WORD,
-    IMAGE_REL_SH3_PCREL8_LONG,
-    IMAGE_REL_SH3_PCREL12_WORD,
-    IMAGE_REL_SH3_STARTOF_SECTION,
-    IMAGE_REL_SH3_SIZEOF_SECTION,
-    IMAGE_REL_SH3_SECTION,
-    IMAGE_REL_SH3_SECREL,
-    IMAGE_REL_SH3_DIRECT32_NB,
-    IMAGE_REL_SH3_GPREL4_LONG,
-    IMAGE_REL_SH3_TOKEN,
-    IMAGE_REL_SHM_PCRELPT,
-    IMAGE_REL_SHM_REFLO,
-    IMAGE_REL_SHM_REFHALF,
-    IMAGE_REL_SHM_RELLO,
-    IMAGE_REL_SHM_RELHALF,
-    IMAGE_REL_SHM_PAIR,
-);
-const FLAGS_IMAGE_REL_SH_BITS: &[Flag<u16>] = &flags!(IMAGE_REL_SH_NOMODE,);
-const FLAGS_IMAGE_REL_ARM: &[Flag<u16>] = &flags!(
-    IMAGE_REL_ARM_ABSOLUTE,
-    IMAGE_REL_ARM_ADDR32,
-    IMAGE_REL_ARM_ADDR32NB,
-    IMAGE_REL_ARM_BRANCH24,
-    IMAGE_REL_ARM_BRANCH11,
-    IMAGE_REL_ARM_TOKEN,
-    IMAGE_REL_ARM_GPREL12,
-    IMAGE_REL_ARM_GPREL7,
-    IMAGE_REL_ARM_BLX24,
-    IMAGE_REL_ARM_BLX11,
-    IMAGE_REL_ARM_SECTION,
-    IMAGE_REL_ARM_SECREL,
-    IMAGE_REL_ARM_MOV32A,
-    IMAGE_REL_ARM_MOV32T,
-    IMAGE_REL_ARM_BRANCH20T,
-    IMAGE_REL_ARM_BRANCH24T,
-    IMAGE_REL_ARM_BLX23T,
-);
-const FLAGS_IMAGE_REL_AM: &[Flag<u16>] = &flags!(
-    IMAGE_REL_AM_ABSOLUTE,
-    IMAGE_REL_AM_ADDR32,
-    IMAGE_REL_AM_ADDR32NB,
-    IMAGE_REL_AM_CALL32,
-    IMAGE_REL_AM_FUNCINFO,
-    IMAGE_REL_AM_REL32_1,
-    IMAGE_REL_AM_REL32_2,
-    IMAGE_REL_AM_SECREL,
-    IMAGE_REL_AM_SECTION,
-    IMAGE_REL_AM_TOKEN,
-);
-const FLAGS_IMAGE_REL_ARM64: &[Flag<u16>] = &flags!(
-    IMAGE_REL_ARM64_ABSOLUTE,
-    IMAGE_REL_ARM64_ADDR32,
-    IMAGE_REL_ARM64_ADDR32NB,
-    IMAGE_REL_ARM64_BRANCH26,
-    IMAGE_REL_ARM64_PAGEBASE_REL21,
-    IMAGE_REL_ARM64_REL21,
-    IMAGE_REL_ARM64_PAGEOFFSET_12A,
-    IMAGE_REL_ARM64_PAGEOFFSET_12L,
-    IMAGE_REL_ARM64_SECREL,
-    IMAGE_REL_ARM64_SECREL_LOW12A,
-    IMAGE_REL_ARM64_SECREL_HIGH12A,
-    IMAGE_REL_ARM64_SECREL_LOW12L,
-    IMAGE_REL_ARM64_TOKEN,
-    IMAGE_REL_ARM64_SECTION,
-    IMAGE_REL_ARM64_ADDR64,
-    IMAGE_REL_ARM64_BRANCH19,
-);
-const FLAGS_IMAGE_REL_AMD64: &[Flag<u16>] = &flags!(
-    IMAGE_REL_AMD64_ABSOLUTE,
-    IMAGE_REL_AMD64_ADDR64,
-    IMAGE_REL_AMD64_ADDR32,
-    IMAGE_REL_AMD64_ADDR32NB,
-    IMAGE_REL_AMD64_REL32,
-    IMAGE_REL_AMD64_REL32_1,
-    IMAGE_REL_AMD64_REL32_2,
-    IMAGE_REL_AMD64_REL32_3,
-    IMAGE_REL_AMD64_REL32_4,
-    IMAGE_REL_AMD64_REL32_5,
-    IMAGE_REL_AMD64_SECTION,
-    IMAGE_REL_AMD64_SECREL,
-    IMAGE_REL_AMD64_SECREL7,
-    IMAGE_REL_AMD64_TOKEN,
-    IMAGE_REL_AMD64_SREL32,
-    IMAGE_REL_AMD64_PAIR,
-    IMAGE_REL_AMD64_SSPAN32,
-    IMAGE_REL_AMD64_EHANDLER,
-    IMAGE_REL_AMD64_IMPORT_BR,
-    IMAGE_REL_AMD64_IMPORT_CALL,
-    IMAGE_REL_AMD64_CFG_BR,
-    IMAGE_REL_AMD64_CFG_BR_REX,
-    IMAGE_REL_AMD64_CFG_CALL,
-    IMAGE_REL_AMD64_INDIR_BR,
-    IMAGE_REL_AMD64_INDIR_BR_REX,
-    IMAGE_REL_AMD64_INDIR_CALL,
-    IMAGE_REL_AMD64_INDIR_BR_SWITCHTABLE_FIRST,
-    IMAGE_REL_AMD64_INDIR_BR_SWITCHTABLE_LAST,
-);
-const FLAGS_IMAGE_REL_IA64: &[Flag<u16>] = &flags!(
-    IMAGE_REL_IA64_ABSOLUTE,
-    IMAGE_REL_IA64_IMM14,
-    IMAGE_REL_IA64_IMM22,
-    IMAGE_REL_IA64_IMM64,
-    IMAGE_REL_IA64_DIR32,
-    IMAGE_REL_IA64_DIR64,
-    IMAGE_REL_IA64_PCREL21B,
-    IMAGE_REL_IA64_PCREL21M,
-    IMAGE_REL_IA64_PCREL21F,
-    IMAGE_REL_IA64_GPREL22,
-    IMAGE_REL_IA64_LTOFF22,
-    IMAGE_REL_IA64_SECTION,
-    IMAGE_REL_IA64_SECREL22,
-    IMAGE_REL_IA64_SECREL64I,
-    IMAGE_REL_IA64_SECREL32,
-    IMAGE_REL_IA64_DIR32NB,
-    IMAGE_REL_IA64_SREL14,
-    IMAGE_REL_IA64_SREL22,
-    IMAGE_REL_IA64_SREL32,
-    IMAGE_REL_IA64_UREL32,
-    IMAGE_REL_IA64_PCREL60X,
-    IMAGE_REL_IA64_PCREL60B,
-    IMAGE_REL_IA64_PCREL60F,
-    IMAGE_REL_IA64_PCREL60I,
-    IMAGE_REL_IA64_PCREL60M,
-    IMAGE_REL_IA64_IMMGPREL64,
-    IMAGE_REL_IA64_TOKEN,
-    IMAGE_REL_IA64_GPREL32,
-    IMAGE_REL_IA64_ADDEND,
-);
-const FLAGS_IMAGE_REL_CEF: &[Flag<u16>] = &flags!(
-    IMAGE_REL_CEF_ABSOLUTE,
-    IMAGE_REL_CEF_ADDR32,
-    IMAGE_REL_CEF_ADDR64,
-    IMAGE_REL_CEF_ADDR32NB,
-    IMAGE_REL_CEF_SECTION,
-    IMAGE_REL_CEF_SECREL,
-    IMAGE_REL_CEF_TOKEN,
-);
-const FLAGS_IMAGE_REL_CEE: &[Flag<u16>] = &flags!(
-    IMAGE_REL_CEE_ABSOLUTE,
-    IMAGE_REL_CEE_ADDR32,
-    IMAGE_REL_CEE_ADDR64,
-    IMAGE_REL_CEE_ADDR32NB,
-    IMAGE_REL_CEE_SECTION,
-    IMAGE_REL_CEE_SECREL,
-    IMAGE_REL_CEE_TOKEN,
-);
-const FLAGS_IMAGE_REL_M32R: &[Flag<u16>] = &flags!(
-    IMAGE_REL_M32R_ABSOLUTE,
-    IMAGE_REL_M32R_ADDR32,
-    IMAGE_REL_M32R_ADDR32NB,
-    IMAGE_REL_M32R_ADDR24,
-    IMAGE_REL_M32R_GPREL16,
-    IMAGE_REL_M32R_PCREL24,
-    IMAGE_REL_M32R_PCREL16,
-    IMAGE_REL_M32R_PCREL8,
-    IMAGE_REL_M32R_REFHALF,
-    IMAGE_REL_M32R_REFHI,
-    IMAGE_REL_M32R_REFLO,
-    IMAGE_REL_M32R_PAIR,
-    IMAGE_REL_M32R_SECTION,
-    IMAGE_REL_M32R_SECREL32,
-    IMAGE_REL_M32R_TOKEN,
-);
-const FLAGS_IMAGE_REL_EBC: &[Flag<u16>] = &flags!(
-    IMAGE_REL_EBC_ABSOLUTE,
-    IMAGE_REL_EBC_ADDR32NB,
-    IMAGE_REL_EBC_REL32,
-    IMAGE_REL_EBC_SECTION,
-    IMAGE_REL_EBC_SECREL,
-);
-const FLAGS_IMAGE_SYM: &[Flag<i32>] =
-    &flags!(IMAGE_SYM_UNDEFINED, IMAGE_SYM_ABSOLUTE, IMAGE_SYM_DEBUG,);
-const FLAGS_IMAGE_SYM_TYPE: &[Flag<u16>] = &flags!(
-    IMAGE_SYM_TYPE_NULL,
-    IMAGE_SYM_TYPE_VOID,
-    IMAGE_SYM_TYPE_CHAR,
-    IMAGE_SYM_TYPE_SHORT,
-    IMAGE_SYM_TYPE_INT,
-    IMAGE_SYM_TYPE_LONG,
-    IMAGE_SYM_TYPE_FLOAT,
-    IMAGE_SYM_TYPE_DOUBLE,
-    IMAGE_SYM_TYPE_STRUCT,
-    IMAGE_SYM_TYPE_UNION,
-    IMAGE_SYM_TYPE_ENUM,
-    IMAGE_SYM_TYPE_MOE,
-    IMAGE_SYM_TYPE_BYTE,
-    IMAGE_SYM_TYPE_WORD,
-    IMAGE_SYM_TYPE_UINT,
-    IMAGE_SYM_TYPE_DWORD,
-    IMAGE_SYM_TYPE_PCODE,
-);
-const FLAGS_IMAGE_SYM_DTYPE: &[Flag<u16>] = &flags!(
-    IMAGE_SYM_DTYPE_NULL,
-    IMAGE_SYM_DTYPE_POINTER,
-    IMAGE_SYM_DTYPE_FUNCTION,
-    IMAGE_SYM_DTYPE_ARRAY,
-);
-const FLAGS_IMAGE_SYM_CLASS: &[Flag<u8>] = &flags!(
-    IMAGE_SYM_CLASS_END_OF_FUNCTION,
-    IMAGE_SYM_CLASS_NULL,
-    IMAGE_SYM_CLASS_AUTOMATIC,
-    IMAGE_SYM_CLASS_EXTERNAL,
-    IMAGE_SYM_CLASS_STATIC,
-    IMAGE_SYM_CLASS_REGISTER,
-    IMAGE_SYM_CLASS_EXTERNAL_DEF,
-    IMAGE_SYM_CLASS_LABEL,
-    IMAGE_SYM_CLASS_UNDEFINED_LABEL,
-    IMAGE_SYM_CLASS_MEMBER_OF_STRUCT,
-    IMAGE_SYM_CLASS_ARGUMENT,
-    IMAGE_SYM_CLASS_STRUCT_TAG,
-    IMAGE_SYM_CLASS_MEMBER_OF_UNION,
-    IMAGE_SYM_CLASS_UNION_TAG,
-    IMAGE_SYM_CLASS_TYPE_DEFINITION,
-    IMAGE_SYM_CLASS_UNDEFINED_STATIC,
-    IMAGE_SYM_CLASS_ENUM_TAG,
-    IMAGE_SYM_CLASS_MEMBER_OF_ENUM,
-    IMAGE_SYM_CLASS_REGISTER_PARAM,
-    IMAGE_SYM_CLASS_BIT_FIELD,
-    IMAGE_SYM_CLASS_FAR_EXTERNAL,
-    IMAGE_SYM_CLASS_BLOCK,
-    IMAGE_SYM_CLASS_FUNCTION,
-    IMAGE_SYM_CLASS_END_OF_STRUCT,
-    IMAGE_SYM_CLASS_FILE,
-    IMAGE_SYM_CLASS_SECTION,
-    IMAGE_SYM_CLASS_WEAK_EXTERNAL,
-    IMAGE_SYM_CLASS_CLR_TOKEN,
-);
-const FLAGS_IMAGE_COMDAT_SELECT: &[Flag<u8>] = &flags!(
-    IMAGE_COMDAT_SELECT_NODUPLICATES,
-    IMAGE_COMDAT_SELECT_ANY,
-    IMAGE_COMDAT_SELECT_SAME_SIZE,
-    IMAGE_COMDAT_SELECT_EXACT_MATCH,
-    IMAGE_COMDAT_SELECT_ASSOCIATIVE,
-    IMAGE_COMDAT_SELECT_LARGEST,
-    IMAGE_COMDAT_SELECT_NEWEST,
-);
-const FLAGS_IMAGE_SUBSYSTEM: &[Flag<u16>] = &flags!(
-    IMAGE_SUBSYSTEM_UNKNOWN,
-    IMAGE_SUBSYSTEM_NATIVE,
-    IMAGE_SUBSYSTEM_WINDOWS_GUI,
-    IMAGE_SUBSYSTEM_WINDOWS_CUI,
-    IMAGE_SUBSYSTEM_OS2_CUI,
-    IMAGE_SUBSYSTEM_POSIX_CUI,
-    IMAGE_SUBSYSTEM_NATIVE_WINDOWS,
-    IMAGE_SUBSYSTEM_WINDOWS_CE_GUI,
-    IMAGE_SUBSYSTEM_EFI_APPLICATION,
-    IMAGE_SUBSYSTEM_EFI_BOOT_SERVICE_DRIVER,
-    IMAGE_SUBSYSTEM_EFI_RUNTIME_DRIVER,
-    IMAGE_SUBSYSTEM_EFI_ROM,
-    IMAGE_SUBSYSTEM_XBOX,
-    IMAGE_SUBSYSTEM_WINDOWS_BOOT_APPLICATION,
-    IMAGE_SUBSYSTEM_XBOX_CODE_CATALOG,
-);
-const FLAGS_IMAGE_DLLCHARACTERISTICS: &[Flag<u16>] = &flags!(
-    IMAGE_DLLCHARACTERISTICS_HIGH_ENTROPY_VA,
-    IMAGE_DLLCHARACTERISTICS_DYNAMIC_BASE,
-    IMAGE_DLLCHARACTERISTICS_FORCE_INTEGRITY,
-    IMAGE_DLLCHARACTERISTICS_NX_COMPAT,
-    IMAGE_DLLCHARACTERISTICS_NO_ISOLATION,
-    IMAGE_DLLCHARACTERISTICS_NO_SEH,
-    IMAGE_DLLCHARACTERISTICS_NO_BIND,
-    IMAGE_DLLCHARACTERISTICS_APPCONTAINER,
-    IMAGE_DLLCHARACTERISTICS_WDM_DRIVER,
-    IMAGE_DLLCHARACTERISTICS_GUARD_CF,
-    IMAGE_DLLCHARACTERISTICS_TERMINAL_SERVER_AWARE,
-);
-const FLAGS_IMAGE_DIRECTORY_ENTRY: &[Flag<usize>] = &flags!(
-    IMAGE_DIRECTORY_ENTRY_EXPORT,
-    IMAGE_DIRECTORY_ENTRY_IMPORT,
-    IMAGE_DIRECTORY_ENTRY_RESOURCE,
-    IMAGE_DIRECTORY_ENTRY_EXCEPTION,
-    IMAGE_DIRECTORY_ENTRY_SECURITY,
-    IMAGE_DIRECTORY_ENTRY_BASERELOC,
-    IMAGE_DIRECTORY_ENTRY_DEBUG,
-    IMAGE_DIRECTORY_ENTRY_ARCHITECTURE,
-    IMAGE_DIRECTORY_ENTRY_GLOBALPTR,
-    IMAGE_DIRECTORY_ENTRY_TLS,
-    IMAGE_DIRECTORY_ENTRY_LOAD_CONFIG,
-    IMAGE_DIRECTORY_ENTRY_BOUND_IMPORT,
-    IMAGE_DIRECTORY_ENTRY_IAT,
-    IMAGE_DIRECTORY_ENTRY_DELAY_IMPORT,
-    IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR,
-);
-const FLAGS_IMAGE_REL_BASED: &[Flag<u16>] = &flags!(
-    IMAGE_REL_BASED_ABSOLUTE,
-    IMAGE_REL_BASED_HIGH,
-    IMAGE_REL_BASED_LOW,
-    IMAGE_REL_BASED_HIGHLOW,
-    IMAGE_REL_BASED_HIGHADJ,
-    IMAGE_REL_BASED_MACHINE_SPECIFIC_5,
-    IMAGE_REL_BASED_RESERVED,
-    IMAGE_REL_BASED_MACHINE_SPECIFIC_7,
-    IMAGE_REL_BASED_MACHINE_SPECIFIC_8,
-    IMAGE_REL_BASED_MACHINE_SPECIFIC_9,
-    IMAGE_REL_BASED_DIR64,
-);
-const FLAGS_IMAGE_REL_IA64_BASED: &[Flag<u16>] = &flags!(IMAGE_REL_BASED_IA64_IMM64,);
-const FLAGS_IMAGE_REL_MIPS_BASED: &[Flag<u16>] =
-    &flags!(IMAGE_REL_BASED_MIPS_JMPADDR, IMAGE_REL_BASED_MIPS_JMPADDR16,);
-const FLAGS_IMAGE_REL_ARM_BASED: &[Flag<u16>] =
-    &flags!(IMAGE_REL_BASED_ARM_MOV32, IMAGE_REL_BASED_THUMB_MOV32,);
-const FLAGS_IMAGE_REL_RISCV_BASED: &[Flag<u16>] = &flags!(
-    IMAGE_REL_BASED_RISCV_HIGH20,
-    IMAGE_REL_BASED_RISCV_LOW12I,
-    IMAGE_REL_BASED_RISCV_LOW12S,
-);
-const FLAGS_RT: &[Flag<u16>] = &flags!(
-    RT_CURSOR,
-    RT_BITMAP,
-    RT_ICON,
-    RT_MENU,
-    RT_DIALOG,
-    RT_STRING,
-    RT_FONTDIR,
-    RT_FONT,
-    RT_ACCELERATOR,
-    RT_RCDATA,
-    RT_MESSAGETABLE,
-    RT_GROUP_CURSOR,
-    RT_GROUP_ICON,
-    RT_VERSION,
-    RT_DLGINCLUDE,
-    RT_PLUGPLAY,
-    RT_VXD,
-    RT_ANICURSOR,
-    RT_ANIICON,
-    RT_HTML,
-    RT_MANIFEST,
-);
-const FLAGS_IMAGE_OBJECT_TYPE: &[Flag<u16>] =
-    &flags!(IMPORT_OBJECT_CODE, IMPORT_OBJECT_DATA, IMPORT_OBJECT_CONST);
-const FLAGS_IMAGE_OBJECT_NAME: &[Flag<u16>] = &flags!(
-    IMPORT_OBJECT_ORDINAL,
-    IMPORT_OBJECT_NAME,
-    IMPORT_OBJECT_NAME_NO_PREFIX,
-    IMPORT_OBJECT_NAME_UNDECORATE,
-    IMPORT_OBJECT_NAME_EXPORTAS,
-);
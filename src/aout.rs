@@ -0,0 +1,204 @@
+//! a.out definitions.
+//!
+//! These definitions are independent of read/write support, although we do implement
+//! some traits useful for those.
+//!
+//! This module is based heavily on the Linux `a.out.h`. The same structures are used
+//! by the BSDs and SunOS, with differences in the meaning of some fields.
+
+#![allow(missing_docs)]
+
+use crate::endian::{Endian, U16, U32};
+use crate::pod::Pod;
+
+/// The header at the start of every a.out file.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Exec<E: Endian> {
+    /// The magic number in the low 16 bits, the machine type in the next 8 bits,
+    /// and flags in the high 8 bits.
+    ///
+    /// Use `N_MAGIC`, `N_MACHTYPE` and `N_FLAGS` to extract the fields.
+    pub a_info: U32<E>,
+    /// Length of text, in bytes.
+    pub a_text: U32<E>,
+    /// Length of data, in bytes.
+    pub a_data: U32<E>,
+    /// Length of uninitialized data area for file, in bytes.
+    pub a_bss: U32<E>,
+    /// Length of symbol table data in file, in bytes.
+    pub a_syms: U32<E>,
+    /// Start address.
+    pub a_entry: U32<E>,
+    /// Length of relocation info for text, in bytes.
+    pub a_trsize: U32<E>,
+    /// Length of relocation info for data, in bytes.
+    pub a_drsize: U32<E>,
+}
+
+/// Return the magic number from `Exec::a_info`.
+#[allow(non_snake_case)]
+#[inline]
+pub fn N_MAGIC(a_info: u32) -> u16 {
+    a_info as u16
+}
+
+/// Return the machine type from `Exec::a_info`.
+#[allow(non_snake_case)]
+#[inline]
+pub fn N_MACHTYPE(a_info: u32) -> u8 {
+    (a_info >> 16) as u8
+}
+
+/// Return the flags from `Exec::a_info`.
+#[allow(non_snake_case)]
+#[inline]
+pub fn N_FLAGS(a_info: u32) -> u8 {
+    (a_info >> 24) as u8
+}
+
+// Values for `N_MAGIC(a_info)`.
+/// Object file or impure executable.
+pub const OMAGIC: u16 = 0o407;
+/// Pure executable.
+pub const NMAGIC: u16 = 0o410;
+/// Demand-paged executable.
+pub const ZMAGIC: u16 = 0o413;
+/// Demand-paged executable with the header in the text segment.
+pub const QMAGIC: u16 = 0o314;
+/// Core file.
+pub const CMAGIC: u16 = 0o421;
+
+// Values for `N_MACHTYPE(a_info)`.
+/// A Sun-2 or old executable.
+pub const M_OLDSUN2: u8 = 0;
+/// Runs on either 68010 or 68020.
+pub const M_68010: u8 = 1;
+/// Runs only on 68020.
+pub const M_68020: u8 = 2;
+/// Runs only on SPARC.
+pub const M_SPARC: u8 = 3;
+/// Linux i386.
+pub const M_386: u8 = 100;
+/// MIPS R2000/R3000.
+pub const M_MIPS1: u8 = 151;
+/// MIPS R4000/R4400.
+pub const M_MIPS2: u8 = 152;
+
+/// The file offset of the text for `ZMAGIC` files.
+///
+/// The header is at the start of the file, and is padded to this size.
+pub const ZMAGIC_TEXT_OFFSET: u32 = 1024;
+/// The text address for `QMAGIC` files.
+pub const QMAGIC_TEXT_ADDRESS: u32 = 0x1000;
+/// The alignment of the data address for `NMAGIC`, `ZMAGIC` and `QMAGIC` files.
+pub const SEGMENT_SIZE: u32 = 1024;
+
+/// A symbol table entry.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Nlist<E: Endian> {
+    /// Index into the string table of the symbol name, or 0 for no name.
+    pub n_strx: U32<E>,
+    /// Type flag, see below.
+    pub n_type: u8,
+    pub n_other: u8,
+    /// Debugger information.
+    pub n_desc: U16<E>,
+    /// Value of this symbol, or the size for common symbols.
+    pub n_value: U32<E>,
+}
+
+// Values for `Nlist::n_type`.
+/// Undefined symbol, or common symbol if the value is non-zero.
+pub const N_UNDF: u8 = 0x00;
+/// Absolute symbol.
+pub const N_ABS: u8 = 0x02;
+/// Text symbol.
+pub const N_TEXT: u8 = 0x04;
+/// Data symbol.
+pub const N_DATA: u8 = 0x06;
+/// BSS symbol.
+pub const N_BSS: u8 = 0x08;
+/// Indirect symbol.
+///
+/// The value of the following symbol is the name of the symbol that this refers to.
+pub const N_INDR: u8 = 0x0a;
+/// Common symbol (internal to the linker).
+pub const N_COMM: u8 = 0x12;
+/// File name symbol.
+pub const N_FN: u8 = 0x1f;
+/// Mask for the type.
+pub const N_TYPE: u8 = 0x1e;
+/// External symbol.
+pub const N_EXT: u8 = 0x01;
+/// Mask for debugger symbols.
+///
+/// If any of these bits are set, the symbol is a debugger symbol.
+pub const N_STAB: u8 = 0xe0;
+
+/// A relocation entry.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct RelocationInfo<E: Endian> {
+    /// Address relative to the start of the section of the data to be relocated.
+    pub r_address: U32<E>,
+    /// The symbol number and flags.
+    ///
+    /// This is a bitfield, so the layout depends on the endianness of the file.
+    /// Use `RelocationInfo::r_info` to extract the fields.
+    pub r_info: U32<E>,
+}
+
+impl<E: Endian> RelocationInfo<E> {
+    /// Unpack the fields of `r_info`.
+    pub fn r_info(&self, endian: E) -> RelocationFields {
+        let r_info = self.r_info.get(endian);
+        if endian.is_little_endian() {
+            RelocationFields {
+                r_symbolnum: r_info & 0x00ff_ffff,
+                r_pcrel: (r_info >> 24) & 1 != 0,
+                r_length: ((r_info >> 25) & 3) as u8,
+                r_extern: (r_info >> 27) & 1 != 0,
+                r_baserel: (r_info >> 28) & 1 != 0,
+                r_jmptable: (r_info >> 29) & 1 != 0,
+                r_relative: (r_info >> 30) & 1 != 0,
+                r_copy: (r_info >> 31) & 1 != 0,
+            }
+        } else {
+            RelocationFields {
+                r_symbolnum: r_info >> 8,
+                r_pcrel: (r_info >> 7) & 1 != 0,
+                r_length: ((r_info >> 5) & 3) as u8,
+                r_extern: (r_info >> 4) & 1 != 0,
+                r_baserel: (r_info >> 3) & 1 != 0,
+                r_jmptable: (r_info >> 2) & 1 != 0,
+                r_relative: (r_info >> 1) & 1 != 0,
+                r_copy: r_info & 1 != 0,
+            }
+        }
+    }
+}
+
+/// The unpacked fields of `RelocationInfo::r_info`.
+#[derive(Debug, Clone, Copy)]
+pub struct RelocationFields {
+    /// The symbol index if `r_extern` is set, otherwise the `N_TYPE` of the section.
+    pub r_symbolnum: u32,
+    /// Set if the relocation is PC relative.
+    pub r_pcrel: bool,
+    /// The log base 2 of the size of the relocation in bytes.
+    pub r_length: u8,
+    /// Set if the relocation target is a symbol.
+    pub r_extern: bool,
+    /// Set if the relocation is relative to the global offset table.
+    pub r_baserel: bool,
+    /// Set if the relocation is relative to the procedure linkage table.
+    pub r_jmptable: bool,
+    /// Set if the relocation is relative to the load address.
+    pub r_relative: bool,
+    /// Set if the relocation is a copy relocation.
+    pub r_copy: bool,
+}
+
+unsafe_impl_endian_pod!(Exec, Nlist, RelocationInfo);
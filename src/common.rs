@@ -1,6 +1,7 @@
 /// A CPU architecture.
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum Architecture {
     Unknown,
@@ -72,6 +73,7 @@ impl Architecture {
 /// This may differ from the address size supported by the file format (such as for COFF).
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum AddressSize {
@@ -92,6 +94,7 @@ impl AddressSize {
 /// A binary file format.
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum BinaryFormat {
     Coff,
@@ -100,10 +103,12 @@ pub enum BinaryFormat {
     Pe,
     Wasm,
     Xcoff,
+    Omf,
 }
 
 /// The kind of a section.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum SectionKind {
     /// The section kind is unknown.
@@ -187,6 +192,43 @@ pub enum SectionKind {
     ///
     /// Example ELF sections: `.symtab`, `.strtab`, `.group`
     Metadata,
+    /// A global offset table, or other table of pointers to symbols that are
+    /// filled in by the dynamic linker.
+    ///
+    /// Example ELF sections: `.got`, `.got.plt`
+    ///
+    /// Example Mach-O sections: `__DATA_CONST/__got`, `__DATA/__la_symbol_ptr`
+    Got,
+    /// A procedure linkage table, or other stubs for calling imported functions.
+    ///
+    /// Example ELF sections: `.plt`, `.plt.sec`
+    ///
+    /// Example Mach-O sections: `__TEXT/__stubs`
+    Plt,
+    /// An array of pointers to initialization functions.
+    ///
+    /// Example ELF sections: `.init_array`
+    ///
+    /// Example Mach-O sections: `__DATA/__mod_init_func`
+    InitArray,
+    /// An array of pointers to termination functions.
+    ///
+    /// Example ELF sections: `.fini_array`
+    ///
+    /// Example Mach-O sections: `__DATA/__mod_term_func`
+    FiniArray,
+    /// Exception handling or stack unwinding information.
+    ///
+    /// Example ELF sections: `.eh_frame`, `.eh_frame_hdr`, `.gcc_except_table`
+    ///
+    /// Example Mach-O sections: `__TEXT/__eh_frame`, `__TEXT/__unwind_info`
+    ///
+    /// Example COFF sections: `.pdata`, `.xdata`
+    Unwind,
+    /// Tables used by the dynamic linker.
+    ///
+    /// Example ELF sections: `.dynamic`, `.dynsym`, `.dynstr`, `.gnu.hash`, `.rela.dyn`
+    Dynamic,
     /// Some other ELF section type.
     ///
     /// This is the `sh_type` field in the section header.
@@ -208,6 +250,7 @@ impl SectionKind {
 /// This determines the way in which the linker resolves multiple definitions of the COMDAT
 /// sections.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum ComdatKind {
     /// The selection kind is unknown.
@@ -240,6 +283,7 @@ pub enum ComdatKind {
 
 /// The kind of a symbol.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum SymbolKind {
     /// The symbol kind is unknown.
@@ -262,6 +306,7 @@ pub enum SymbolKind {
 
 /// A symbol scope.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum SymbolScope {
     /// Unknown scope.
     Unknown,
@@ -289,6 +334,7 @@ pub enum SymbolScope {
 ///
 /// 'XxxRelative' means 'Xxx + A - P'.  'XxxOffset' means 'S + A - Xxx'.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum RelocationKind {
     /// S + A
@@ -311,6 +357,12 @@ pub enum RelocationKind {
     SectionOffset,
     /// The index of the section containing the symbol.
     SectionIndex,
+    /// Image + A
+    ///
+    /// Adjusts the place for the load address of the image. This is used for
+    /// ELF `R_*_RELATIVE` and RELR relocations, PE base relocations, and Mach-O rebases.
+    /// For PE and Mach-O, the implicit addend is relative to the preferred image base.
+    Rebase,
     /// Some other ELF relocation. The value is dependent on the architecture.
     Elf(u32),
     /// Some other Mach-O relocation. The value is dependent on the architecture.
@@ -324,6 +376,10 @@ pub enum RelocationKind {
     Coff(u16),
     /// Some other XCOFF relocation.
     Xcoff(u8),
+    /// Some other a.out relocation. The value is the raw `r_info` field.
+    Aout(u32),
+    /// Some other OMF fixup. The value is the location type.
+    Omf(u8),
 }
 
 /// Information about how the result of the relocation operation is encoded in the place.
@@ -331,6 +387,7 @@ pub enum RelocationKind {
 /// This is usually architecture specific, such as specifying an addressing mode or
 /// a specific instruction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum RelocationEncoding {
     /// Generic encoding.
@@ -371,6 +428,7 @@ pub enum RelocationEncoding {
 
 /// File flags that are specific to each file format.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum FileFlags {
     /// No file flags.
@@ -401,8 +459,77 @@ pub enum FileFlags {
     },
 }
 
+/// The kind of a segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[non_exhaustive]
+pub enum SegmentKind {
+    /// The segment kind is unknown.
+    Unknown,
+    /// A segment that is loaded into memory.
+    ///
+    /// This is an ELF `PT_LOAD` segment, a Mach-O segment, or a PE section.
+    Load,
+    /// The dynamic linking information.
+    ///
+    /// This is an ELF `PT_DYNAMIC` segment.
+    Dynamic,
+    /// The TLS initialization image.
+    ///
+    /// This is an ELF `PT_TLS` segment.
+    Tls,
+    /// Auxiliary information.
+    ///
+    /// This is an ELF `PT_NOTE` segment.
+    Note,
+    /// Some other ELF segment type.
+    ///
+    /// This is the `p_type` field in the program header.
+    Elf(u32),
+}
+
+/// The memory access permissions of a segment.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Permissions {
+    read: bool,
+    write: bool,
+    execute: bool,
+}
+
+impl Permissions {
+    /// Construct a `Permissions`.
+    #[inline]
+    pub fn new(read: bool, write: bool, execute: bool) -> Self {
+        Permissions {
+            read,
+            write,
+            execute,
+        }
+    }
+
+    /// Return true if the memory is readable.
+    #[inline]
+    pub fn read(self) -> bool {
+        self.read
+    }
+
+    /// Return true if the memory is writable.
+    #[inline]
+    pub fn write(self) -> bool {
+        self.write
+    }
+
+    /// Return true if the memory is executable.
+    #[inline]
+    pub fn execute(self) -> bool {
+        self.execute
+    }
+}
+
 /// Segment flags that are specific to each file format.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum SegmentFlags {
     /// No segment flags.
@@ -430,6 +557,7 @@ pub enum SegmentFlags {
 
 /// Section flags that are specific to each file format.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum SectionFlags {
     /// No section flags.
@@ -458,6 +586,7 @@ pub enum SectionFlags {
 
 /// Symbol flags that are specific to each file format.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum SymbolFlags<Section, Symbol> {
     /// No symbol flags.
@@ -481,6 +610,21 @@ pub enum SymbolFlags<Section, Symbol> {
         /// `Number` field in the auxiliary symbol for the section.
         associative_section: Option<Section>,
     },
+    /// COFF flags for a symbol that is not a section symbol.
+    CoffSymbol {
+        /// `Type` field in the COFF symbol.
+        typ: u16,
+        /// `StorageClass` field in the COFF symbol.
+        storage_class: u8,
+        /// `TagIndex` field in the auxiliary symbol for a weak external.
+        ///
+        /// Only valid if `storage_class` is `IMAGE_SYM_CLASS_WEAK_EXTERNAL`.
+        weak_default_symbol: Option<Symbol>,
+        /// `Characteristics` field in the auxiliary symbol for a weak external.
+        ///
+        /// Only valid if `storage_class` is `IMAGE_SYM_CLASS_WEAK_EXTERNAL`.
+        weak_search_type: u32,
+    },
     /// XCOFF symbol flags.
     Xcoff {
         /// `n_sclass` field in the XCOFF symbol.
@@ -499,3 +643,117 @@ pub enum SymbolFlags<Section, Symbol> {
         containing_csect: Option<Symbol>,
     },
 }
+
+/// Create a table of the names of enumerated values.
+#[allow(unused_macros)]
+macro_rules! value_names {
+    ($($name:ident),+ $(,)?) => ( [ $(($name, stringify!($name))),+ ] )
+}
+
+/// Create a table of the names of single bit flags.
+#[allow(unused_macros)]
+macro_rules! flag_names {
+    ($($name:ident),+ $(,)?) => (
+        [ $($crate::common::FlagName::new($name as u64, $name as u64, stringify!($name))),+ ]
+    )
+}
+
+/// Create a table of the names of enumerated values within a flags field.
+#[allow(unused_macros)]
+macro_rules! masked_names {
+    ($mask:expr; $($name:ident),+ $(,)?) => (
+        [ $($crate::common::FlagName::new($mask as u64, $name as u64, stringify!($name))),+ ]
+    )
+}
+
+/// Find the name of an enumerated value in a table created by `value_names!`.
+#[allow(dead_code)]
+pub(crate) fn value_name<T: PartialEq>(
+    value: T,
+    names: &'static [(T, &'static str)],
+) -> Option<&'static str> {
+    names
+        .iter()
+        .find(|(x, _)| *x == value)
+        .map(|(_, name)| *name)
+}
+
+/// The name of a flag, or of an enumerated value within a flags field.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FlagName {
+    mask: u64,
+    value: u64,
+    name: &'static str,
+}
+
+impl FlagName {
+    #[allow(dead_code)]
+    pub(crate) const fn new(mask: u64, value: u64, name: &'static str) -> Self {
+        FlagName { mask, value, name }
+    }
+
+    #[inline]
+    fn matches(&self, value: u64) -> bool {
+        value & self.mask == self.value
+    }
+}
+
+/// An iterator over the names of the flags that are set in a flags field.
+///
+/// Enumerated values within the field, such as an alignment or a type, are
+/// included if they have a name.
+///
+/// This is returned by functions such as `elf::sh_flags_names`.
+#[derive(Debug, Clone)]
+pub struct FlagNames {
+    value: u64,
+    names: &'static [FlagName],
+    extra_names: &'static [FlagName],
+    index: usize,
+}
+
+impl FlagNames {
+    #[allow(dead_code)]
+    pub(crate) fn new(
+        value: u64,
+        names: &'static [FlagName],
+        extra_names: &'static [FlagName],
+    ) -> Self {
+        FlagNames {
+            value,
+            names,
+            extra_names,
+            index: 0,
+        }
+    }
+
+    /// Return the bits of the value that do not have a name.
+    ///
+    /// This includes the bits of enumerated values that do not have a name.
+    pub fn unknown(&self) -> u64 {
+        let mut known = 0;
+        for name in self.names.iter().chain(self.extra_names) {
+            if name.matches(self.value) {
+                known |= name.mask;
+            }
+        }
+        self.value & !known
+    }
+}
+
+impl Iterator for FlagNames {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<&'static str> {
+        loop {
+            let name = match self.names.get(self.index) {
+                Some(name) => name,
+                None => self.extra_names.get(self.index - self.names.len())?,
+            };
+            self.index += 1;
+            if name.matches(self.value) {
+                return Some(name.name);
+            }
+        }
+    }
+}
@@ -11,8 +11,12 @@
 use crate::endian::{Endian, U32Bytes, U64Bytes, I32, I64, U16, U32, U64};
 use crate::pod::Pod;
 
+mod names;
+pub use names::*;
+
 /// The header at the start of every 32-bit ELF file.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct FileHeader32<E: Endian> {
     /// Magic number and other information.
@@ -59,6 +63,7 @@ pub struct FileHeader32<E: Endian> {
 
 /// The header at the start of every 64-bit ELF file.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct FileHeader64<E: Endian> {
     /// Magic number and other information.
@@ -107,6 +112,7 @@ pub struct FileHeader64<E: Endian> {
 ///
 /// Contained in the file header.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct Ident {
     /// Magic number. Must be `ELFMAG`.
@@ -590,6 +596,7 @@ pub const EV_CURRENT: u8 = 1;
 
 /// Section header.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct SectionHeader32<E: Endian> {
     /// Section name.
@@ -622,6 +629,7 @@ pub struct SectionHeader32<E: Endian> {
 
 /// Section header.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct SectionHeader64<E: Endian> {
     /// Section name.
@@ -710,6 +718,8 @@ pub const SHT_PREINIT_ARRAY: u32 = 16;
 pub const SHT_GROUP: u32 = 17;
 /// Extended section indices for a symbol table.
 pub const SHT_SYMTAB_SHNDX: u32 = 18;
+/// Relative relocation entries in the compact RELR format.
+pub const SHT_RELR: u32 = 19;
 /// Start of OS-specific section types.
 pub const SHT_LOOS: u32 = 0x6000_0000;
 /// Object attributes.
@@ -1211,8 +1221,25 @@ impl<E: Endian> Rela64<E> {
     }
 }
 
+/// 32-bit relative relocation table entry.
+///
+/// An even entry is the address of a place to relocate. An odd entry is a
+/// bitmap of the following 31 words that need relocation.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Relr32<E: Endian>(pub U32<E>);
+
+/// 64-bit relative relocation table entry.
+///
+/// An even entry is the address of a place to relocate. An odd entry is a
+/// bitmap of the following 63 words that need relocation.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Relr64<E: Endian>(pub U64<E>);
+
 /// Program segment header.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct ProgramHeader32<E: Endian> {
     /// Segment type. One of the `PT_*` constants.
@@ -1235,6 +1262,7 @@ pub struct ProgramHeader32<E: Endian> {
 
 /// Program segment header.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct ProgramHeader64<E: Endian> {
     /// Segment type. One of the `PT_*` constants.
@@ -1288,6 +1316,14 @@ pub const PT_GNU_STACK: u32 = 0x6474_e551;
 pub const PT_GNU_RELRO: u32 = 0x6474_e552;
 /// Segment containing `.note.gnu.property` section.
 pub const PT_GNU_PROPERTY: u32 = 0x6474_e553;
+/// OpenBSD segment that is filled with random data.
+pub const PT_OPENBSD_RANDOMIZE: u32 = 0x65a3_dbe6;
+/// OpenBSD segment indicating that the program needs W^X violations.
+pub const PT_OPENBSD_WXNEEDED: u32 = 0x65a3_dbe7;
+/// OpenBSD segment indicating that the program does not use branch target CFI.
+pub const PT_OPENBSD_NOBTCFI: u32 = 0x65a3_dbe8;
+/// OpenBSD segment containing boot arguments.
+pub const PT_OPENBSD_BOOTDATA: u32 = 0x65a4_1be6;
 /// End of OS-specific segment types.
 pub const PT_HIOS: u32 = 0x6fff_ffff;
 /// Start of processor-specific segment types.
@@ -1543,6 +1579,12 @@ pub const DT_PREINIT_ARRAY: u32 = 32;
 pub const DT_PREINIT_ARRAYSZ: u32 = 33;
 /// Address of SYMTAB_SHNDX section
 pub const DT_SYMTAB_SHNDX: u32 = 34;
+/// Total size of RELR relative relocations
+pub const DT_RELRSZ: u32 = 35;
+/// Address of RELR relative relocations
+pub const DT_RELR: u32 = 36;
+/// Size of one RELR relative relocation
+pub const DT_RELRENT: u32 = 37;
 /// Start of OS-specific
 pub const DT_LOOS: u32 = 0x6000_000d;
 /// End of OS-specific
@@ -1832,6 +1874,86 @@ pub const ELF_NOTE_SOLARIS: &[u8] = b"SUNW Solaris";
 /// Desired pagesize for the binary.
 pub const NT_SOLARIS_PAGESIZE_HINT: u32 = 1;
 
+/// FreeBSD entries in the note section have this name.
+pub const ELF_NOTE_FREEBSD: &[u8] = b"FreeBSD";
+
+// Values for `n_type` when the name is `ELF_NOTE_FREEBSD`.
+/// ABI version.
+///
+/// The descriptor is a word containing the value of `__FreeBSD_version`.
+pub const NT_FREEBSD_ABI_TAG: u32 = 1;
+/// Indicates that the binary does not need `crt1.o` initialization.
+pub const NT_FREEBSD_NOINIT_TAG: u32 = 2;
+/// Architecture name.
+///
+/// The descriptor is a null-terminated string, such as `amd64`.
+pub const NT_FREEBSD_ARCH_TAG: u32 = 3;
+/// Feature control flags.
+///
+/// The descriptor is a word containing `NT_FREEBSD_FCTL_*` flags.
+pub const NT_FREEBSD_FEATURE_CTL: u32 = 4;
+
+// Values for the descriptor of `NT_FREEBSD_FEATURE_CTL`.
+/// Disable address space layout randomization.
+pub const NT_FREEBSD_FCTL_ASLR_DISABLE: u32 = 0x01;
+/// Disable implicit `PROT_MAX`.
+pub const NT_FREEBSD_FCTL_PROTMAX_DISABLE: u32 = 0x02;
+/// Disable the stack gap.
+pub const NT_FREEBSD_FCTL_STKGAP_DISABLE: u32 = 0x04;
+/// The program needs W^X violations.
+pub const NT_FREEBSD_FCTL_WXNEEDED: u32 = 0x08;
+/// Request a 48-bit virtual address space on amd64 with LA57.
+pub const NT_FREEBSD_FCTL_LA48: u32 = 0x10;
+/// Disable ASLR stack gap.
+pub const NT_FREEBSD_FCTL_ASG_DISABLE: u32 = 0x20;
+
+/// NetBSD entries in the note section have this name.
+pub const ELF_NOTE_NETBSD: &[u8] = b"NetBSD";
+
+// Values for `n_type` when the name is `ELF_NOTE_NETBSD`.
+/// OS version.
+///
+/// The descriptor is a word containing the value of `__NetBSD_Version__`.
+pub const NT_NETBSD_IDENT: u32 = 1;
+/// Machine architecture.
+///
+/// The descriptor is a null-terminated string, such as `earmv7hf`.
+pub const NT_NETBSD_MARCH: u32 = 5;
+
+/// PaX entries in the note section have this name.
+///
+/// These are used by NetBSD.
+pub const ELF_NOTE_PAX: &[u8] = b"PaX";
+
+// Values for `n_type` when the name is `ELF_NOTE_PAX`.
+/// PaX security feature flags.
+///
+/// The descriptor is a word containing `NT_NETBSD_PAX_*` flags.
+pub const NT_NETBSD_PAX: u32 = 3;
+
+// Values for the descriptor of `NT_NETBSD_PAX`.
+/// Force enable mprotect restrictions.
+pub const NT_NETBSD_PAX_MPROTECT: u32 = 0x01;
+/// Force disable mprotect restrictions.
+pub const NT_NETBSD_PAX_NOMPROTECT: u32 = 0x02;
+/// Force enable segvguard.
+pub const NT_NETBSD_PAX_GUARD: u32 = 0x04;
+/// Force disable segvguard.
+pub const NT_NETBSD_PAX_NOGUARD: u32 = 0x08;
+/// Force enable address space layout randomization.
+pub const NT_NETBSD_PAX_ASLR: u32 = 0x10;
+/// Force disable address space layout randomization.
+pub const NT_NETBSD_PAX_NOASLR: u32 = 0x20;
+
+/// OpenBSD entries in the note section have this name.
+pub const ELF_NOTE_OPENBSD: &[u8] = b"OpenBSD";
+
+// Values for `n_type` when the name is `ELF_NOTE_OPENBSD`.
+/// OS identification.
+///
+/// The descriptor is a word, which is usually 0.
+pub const NT_OPENBSD_IDENT: u32 = 1;
+
 /// GNU entries in the note section have this name.
 pub const ELF_NOTE_GNU: &[u8] = b"GNU";
 
@@ -6125,6 +6247,7 @@ pub const Tag_Section: u8 = 2;
 #[allow(non_upper_case_globals)]
 pub const Tag_Symbol: u8 = 3;
 
+unsafe_impl_pod!(Ident);
 unsafe_impl_endian_pod!(
     FileHeader32,
     FileHeader64,
@@ -6140,6 +6263,8 @@ unsafe_impl_endian_pod!(
     Rel64,
     Rela32,
     Rela64,
+    Relr32,
+    Relr64,
     ProgramHeader32,
     ProgramHeader64,
     Dyn32,
@@ -0,0 +1,1992 @@
+//! Functions for finding the names of ELF constants.
+
+use crate::common::{value_name, FlagName, FlagNames};
+use crate::elf::*;
+
+/// Return the name of a relocation type for the given machine.
+///
+/// For example, `r_type_name(EM_X86_64, 4)` returns `Some("R_X86_64_PLT32")`.
+pub fn r_type_name(e_machine: u16, r_type: u32) -> Option<&'static str> {
+    let names = match e_machine {
+        EM_68K => NAMES_R_68K,
+        EM_386 => NAMES_R_386,
+        EM_SPARC | EM_SPARC32PLUS | EM_SPARCV9 => NAMES_R_SPARC,
+        EM_MIPS => NAMES_R_MIPS,
+        EM_PARISC => NAMES_R_PARISC,
+        EM_ALPHA => NAMES_R_ALPHA,
+        EM_PPC => NAMES_R_PPC,
+        EM_PPC64 => NAMES_R_PPC64,
+        EM_AARCH64 => NAMES_R_AARCH64,
+        EM_ARM => NAMES_R_ARM,
+        EM_CSKY => NAMES_R_CKCORE,
+        EM_IA_64 => NAMES_R_IA64,
+        EM_SH => NAMES_R_SH,
+        EM_S390 => NAMES_R_390,
+        EM_CRIS => NAMES_R_CRIS,
+        EM_X86_64 => NAMES_R_X86_64,
+        EM_MN10300 => NAMES_R_MN10300,
+        EM_M32R => NAMES_R_M32R,
+        EM_MICROBLAZE => NAMES_R_MICROBLAZE,
+        EM_ALTERA_NIOS2 => NAMES_R_NIOS2,
+        EM_TILEPRO => NAMES_R_TILEPRO,
+        EM_TILEGX => NAMES_R_TILEGX,
+        EM_RISCV => NAMES_R_RISCV,
+        EM_BPF => NAMES_R_BPF,
+        EM_SBF => NAMES_R_SBF,
+        EM_LOONGARCH => NAMES_R_LOONGARCH,
+        EM_METAG => NAMES_R_METAG,
+        EM_NDS32 => NAMES_R_NDS32,
+        EM_XTENSA => NAMES_R_XTENSA,
+        _ => return None,
+    };
+    value_name(r_type, names)
+}
+
+/// Return the names of the flags in a section header's `sh_flags` field.
+pub fn sh_flags_names(e_machine: u16, sh_flags: u64) -> FlagNames {
+    let machine_names = match e_machine {
+        EM_MIPS => NAMES_SHF_MIPS,
+        EM_PARISC => NAMES_SHF_PARISC,
+        EM_ALPHA => NAMES_SHF_ALPHA,
+        EM_ARM => NAMES_SHF_ARM,
+        EM_IA_64 => NAMES_SHF_IA_64,
+        _ => NAMES_SHF_EXCLUDE,
+    };
+    FlagNames::new(sh_flags, NAMES_SHF, machine_names)
+}
+
+/// Return the name of the binding in a symbol's `st_info` field.
+///
+/// The binding must already be extracted from `st_info`, such as by `Sym::st_bind`.
+pub fn st_bind_name(e_machine: u16, st_bind: u8) -> Option<&'static str> {
+    value_name(st_bind, NAMES_STB)
+        .or_else(|| value_name(st_bind, NAMES_STB_GNU))
+        .or_else(|| match e_machine {
+            EM_MIPS => value_name(st_bind, NAMES_STB_MIPS),
+            _ => None,
+        })
+}
+
+/// Return the name of the type in a symbol's `st_info` field.
+///
+/// The type must already be extracted from `st_info`, such as by `Sym::st_type`.
+pub fn st_type_name(e_machine: u16, st_type: u8) -> Option<&'static str> {
+    value_name(st_type, NAMES_STT)
+        .or_else(|| value_name(st_type, NAMES_STT_GNU))
+        .or_else(|| match e_machine {
+            EM_SPARC | EM_SPARC32PLUS | EM_SPARCV9 => value_name(st_type, NAMES_STT_SPARC),
+            EM_PARISC => value_name(st_type, NAMES_STT_PARISC),
+            EM_ARM => value_name(st_type, NAMES_STT_ARM),
+            _ => None,
+        })
+}
+
+/// Return the name of the visibility in a symbol's `st_other` field.
+///
+/// The visibility must already be extracted from `st_other`, such as by `Sym::st_visibility`.
+pub fn st_visibility_name(st_visibility: u8) -> Option<&'static str> {
+    value_name(st_visibility, NAMES_STV)
+}
+
+const NAMES_R_68K: &[(u32, &str)] = &value_names!(
+    R_68K_NONE,
+    R_68K_32,
+    R_68K_16,
+    R_68K_8,
+    R_68K_PC32,
+    R_68K_PC16,
+    R_68K_PC8,
+    R_68K_GOT32,
+    R_68K_GOT16,
+    R_68K_GOT8,
+    R_68K_GOT32O,
+    R_68K_GOT16O,
+    R_68K_GOT8O,
+    R_68K_PLT32,
+    R_68K_PLT16,
+    R_68K_PLT8,
+    R_68K_PLT32O,
+    R_68K_PLT16O,
+    R_68K_PLT8O,
+    R_68K_COPY,
+    R_68K_GLOB_DAT,
+    R_68K_JMP_SLOT,
+    R_68K_RELATIVE,
+    R_68K_TLS_GD32,
+    R_68K_TLS_GD16,
+    R_68K_TLS_GD8,
+    R_68K_TLS_LDM32,
+    R_68K_TLS_LDM16,
+    R_68K_TLS_LDM8,
+    R_68K_TLS_LDO32,
+    R_68K_TLS_LDO16,
+    R_68K_TLS_LDO8,
+    R_68K_TLS_IE32,
+    R_68K_TLS_IE16,
+    R_68K_TLS_IE8,
+    R_68K_TLS_LE32,
+    R_68K_TLS_LE16,
+    R_68K_TLS_LE8,
+    R_68K_TLS_DTPMOD32,
+    R_68K_TLS_DTPREL32,
+    R_68K_TLS_TPREL32
+);
+const NAMES_R_386: &[(u32, &str)] = &value_names!(
+    R_386_NONE,
+    R_386_32,
+    R_386_PC32,
+    R_386_GOT32,
+    R_386_PLT32,
+    R_386_COPY,
+    R_386_GLOB_DAT,
+    R_386_JMP_SLOT,
+    R_386_RELATIVE,
+    R_386_GOTOFF,
+    R_386_GOTPC,
+    R_386_32PLT,
+    R_386_TLS_TPOFF,
+    R_386_TLS_IE,
+    R_386_TLS_GOTIE,
+    R_386_TLS_LE,
+    R_386_TLS_GD,
+    R_386_TLS_LDM,
+    R_386_16,
+    R_386_PC16,
+    R_386_8,
+    R_386_PC8,
+    R_386_TLS_GD_32,
+    R_386_TLS_GD_PUSH,
+    R_386_TLS_GD_CALL,
+    R_386_TLS_GD_POP,
+    R_386_TLS_LDM_32,
+    R_386_TLS_LDM_PUSH,
+    R_386_TLS_LDM_CALL,
+    R_386_TLS_LDM_POP,
+    R_386_TLS_LDO_32,
+    R_386_TLS_IE_32,
+    R_386_TLS_LE_32,
+    R_386_TLS_DTPMOD32,
+    R_386_TLS_DTPOFF32,
+    R_386_TLS_TPOFF32,
+    R_386_SIZE32,
+    R_386_TLS_GOTDESC,
+    R_386_TLS_DESC_CALL,
+    R_386_TLS_DESC,
+    R_386_IRELATIVE,
+    R_386_GOT32X
+);
+const NAMES_R_SPARC: &[(u32, &str)] = &value_names!(
+    R_SPARC_NONE,
+    R_SPARC_8,
+    R_SPARC_16,
+    R_SPARC_32,
+    R_SPARC_DISP8,
+    R_SPARC_DISP16,
+    R_SPARC_DISP32,
+    R_SPARC_WDISP30,
+    R_SPARC_WDISP22,
+    R_SPARC_HI22,
+    R_SPARC_22,
+    R_SPARC_13,
+    R_SPARC_LO10,
+    R_SPARC_GOT10,
+    R_SPARC_GOT13,
+    R_SPARC_GOT22,
+    R_SPARC_PC10,
+    R_SPARC_PC22,
+    R_SPARC_WPLT30,
+    R_SPARC_COPY,
+    R_SPARC_GLOB_DAT,
+    R_SPARC_JMP_SLOT,
+    R_SPARC_RELATIVE,
+    R_SPARC_UA32,
+    R_SPARC_PLT32,
+    R_SPARC_HIPLT22,
+    R_SPARC_LOPLT10,
+    R_SPARC_PCPLT32,
+    R_SPARC_PCPLT22,
+    R_SPARC_PCPLT10,
+    R_SPARC_10,
+    R_SPARC_11,
+    R_SPARC_64,
+    R_SPARC_OLO10,
+    R_SPARC_HH22,
+    R_SPARC_HM10,
+    R_SPARC_LM22,
+    R_SPARC_PC_HH22,
+    R_SPARC_PC_HM10,
+    R_SPARC_PC_LM22,
+    R_SPARC_WDISP16,
+    R_SPARC_WDISP19,
+    R_SPARC_GLOB_JMP,
+    R_SPARC_7,
+    R_SPARC_5,
+    R_SPARC_6,
+    R_SPARC_DISP64,
+    R_SPARC_PLT64,
+    R_SPARC_HIX22,
+    R_SPARC_LOX10,
+    R_SPARC_H44,
+    R_SPARC_M44,
+    R_SPARC_L44,
+    R_SPARC_REGISTER,
+    R_SPARC_UA64,
+    R_SPARC_UA16,
+    R_SPARC_TLS_GD_HI22,
+    R_SPARC_TLS_GD_LO10,
+    R_SPARC_TLS_GD_ADD,
+    R_SPARC_TLS_GD_CALL,
+    R_SPARC_TLS_LDM_HI22,
+    R_SPARC_TLS_LDM_LO10,
+    R_SPARC_TLS_LDM_ADD,
+    R_SPARC_TLS_LDM_CALL,
+    R_SPARC_TLS_LDO_HIX22,
+    R_SPARC_TLS_LDO_LOX10,
+    R_SPARC_TLS_LDO_ADD,
+    R_SPARC_TLS_IE_HI22,
+    R_SPARC_TLS_IE_LO10,
+    R_SPARC_TLS_IE_LD,
+    R_SPARC_TLS_IE_LDX,
+    R_SPARC_TLS_IE_ADD,
+    R_SPARC_TLS_LE_HIX22,
+    R_SPARC_TLS_LE_LOX10,
+    R_SPARC_TLS_DTPMOD32,
+    R_SPARC_TLS_DTPMOD64,
+    R_SPARC_TLS_DTPOFF32,
+    R_SPARC_TLS_DTPOFF64,
+    R_SPARC_TLS_TPOFF32,
+    R_SPARC_TLS_TPOFF64,
+    R_SPARC_GOTDATA_HIX22,
+    R_SPARC_GOTDATA_LOX10,
+    R_SPARC_GOTDATA_OP_HIX22,
+    R_SPARC_GOTDATA_OP_LOX10,
+    R_SPARC_GOTDATA_OP,
+    R_SPARC_H34,
+    R_SPARC_SIZE32,
+    R_SPARC_SIZE64,
+    R_SPARC_WDISP10,
+    R_SPARC_JMP_IREL,
+    R_SPARC_IRELATIVE,
+    R_SPARC_GNU_VTINHERIT,
+    R_SPARC_GNU_VTENTRY,
+    R_SPARC_REV32
+);
+const NAMES_R_MIPS: &[(u32, &str)] = &value_names!(
+    R_MIPS_NONE,
+    R_MIPS_16,
+    R_MIPS_32,
+    R_MIPS_REL32,
+    R_MIPS_26,
+    R_MIPS_HI16,
+    R_MIPS_LO16,
+    R_MIPS_GPREL16,
+    R_MIPS_LITERAL,
+    R_MIPS_GOT16,
+    R_MIPS_PC16,
+    R_MIPS_CALL16,
+    R_MIPS_GPREL32,
+    R_MIPS_SHIFT5,
+    R_MIPS_SHIFT6,
+    R_MIPS_64,
+    R_MIPS_GOT_DISP,
+    R_MIPS_GOT_PAGE,
+    R_MIPS_GOT_OFST,
+    R_MIPS_GOT_HI16,
+    R_MIPS_GOT_LO16,
+    R_MIPS_SUB,
+    R_MIPS_INSERT_A,
+    R_MIPS_INSERT_B,
+    R_MIPS_DELETE,
+    R_MIPS_HIGHER,
+    R_MIPS_HIGHEST,
+    R_MIPS_CALL_HI16,
+    R_MIPS_CALL_LO16,
+    R_MIPS_SCN_DISP,
+    R_MIPS_REL16,
+    R_MIPS_ADD_IMMEDIATE,
+    R_MIPS_PJUMP,
+    R_MIPS_RELGOT,
+    R_MIPS_JALR,
+    R_MIPS_TLS_DTPMOD32,
+    R_MIPS_TLS_DTPREL32,
+    R_MIPS_TLS_DTPMOD64,
+    R_MIPS_TLS_DTPREL64,
+    R_MIPS_TLS_GD,
+    R_MIPS_TLS_LDM,
+    R_MIPS_TLS_DTPREL_HI16,
+    R_MIPS_TLS_DTPREL_LO16,
+    R_MIPS_TLS_GOTTPREL,
+    R_MIPS_TLS_TPREL32,
+    R_MIPS_TLS_TPREL64,
+    R_MIPS_TLS_TPREL_HI16,
+    R_MIPS_TLS_TPREL_LO16,
+    R_MIPS_GLOB_DAT,
+    R_MIPS_COPY,
+    R_MIPS_JUMP_SLOT
+);
+const NAMES_R_PARISC: &[(u32, &str)] = &value_names!(
+    R_PARISC_NONE,
+    R_PARISC_DIR32,
+    R_PARISC_DIR21L,
+    R_PARISC_DIR17R,
+    R_PARISC_DIR17F,
+    R_PARISC_DIR14R,
+    R_PARISC_PCREL32,
+    R_PARISC_PCREL21L,
+    R_PARISC_PCREL17R,
+    R_PARISC_PCREL17F,
+    R_PARISC_PCREL14R,
+    R_PARISC_DPREL21L,
+    R_PARISC_DPREL14R,
+    R_PARISC_GPREL21L,
+    R_PARISC_GPREL14R,
+    R_PARISC_LTOFF21L,
+    R_PARISC_LTOFF14R,
+    R_PARISC_SECREL32,
+    R_PARISC_SEGBASE,
+    R_PARISC_SEGREL32,
+    R_PARISC_PLTOFF21L,
+    R_PARISC_PLTOFF14R,
+    R_PARISC_LTOFF_FPTR32,
+    R_PARISC_LTOFF_FPTR21L,
+    R_PARISC_LTOFF_FPTR14R,
+    R_PARISC_FPTR64,
+    R_PARISC_PLABEL32,
+    R_PARISC_PLABEL21L,
+    R_PARISC_PLABEL14R,
+    R_PARISC_PCREL64,
+    R_PARISC_PCREL22F,
+    R_PARISC_PCREL14WR,
+    R_PARISC_PCREL14DR,
+    R_PARISC_PCREL16F,
+    R_PARISC_PCREL16WF,
+    R_PARISC_PCREL16DF,
+    R_PARISC_DIR64,
+    R_PARISC_DIR14WR,
+    R_PARISC_DIR14DR,
+    R_PARISC_DIR16F,
+    R_PARISC_DIR16WF,
+    R_PARISC_DIR16DF,
+    R_PARISC_GPREL64,
+    R_PARISC_GPREL14WR,
+    R_PARISC_GPREL14DR,
+    R_PARISC_GPREL16F,
+    R_PARISC_GPREL16WF,
+    R_PARISC_GPREL16DF,
+    R_PARISC_LTOFF64,
+    R_PARISC_LTOFF14WR,
+    R_PARISC_LTOFF14DR,
+    R_PARISC_LTOFF16F,
+    R_PARISC_LTOFF16WF,
+    R_PARISC_LTOFF16DF,
+    R_PARISC_SECREL64,
+    R_PARISC_SEGREL64,
+    R_PARISC_PLTOFF14WR,
+    R_PARISC_PLTOFF14DR,
+    R_PARISC_PLTOFF16F,
+    R_PARISC_PLTOFF16WF,
+    R_PARISC_PLTOFF16DF,
+    R_PARISC_LTOFF_FPTR64,
+    R_PARISC_LTOFF_FPTR14WR,
+    R_PARISC_LTOFF_FPTR14DR,
+    R_PARISC_LTOFF_FPTR16F,
+    R_PARISC_LTOFF_FPTR16WF,
+    R_PARISC_LTOFF_FPTR16DF,
+    R_PARISC_COPY,
+    R_PARISC_IPLT,
+    R_PARISC_EPLT,
+    R_PARISC_TPREL32,
+    R_PARISC_TPREL21L,
+    R_PARISC_TPREL14R,
+    R_PARISC_LTOFF_TP21L,
+    R_PARISC_LTOFF_TP14R,
+    R_PARISC_LTOFF_TP14F,
+    R_PARISC_TPREL64,
+    R_PARISC_TPREL14WR,
+    R_PARISC_TPREL14DR,
+    R_PARISC_TPREL16F,
+    R_PARISC_TPREL16WF,
+    R_PARISC_TPREL16DF,
+    R_PARISC_LTOFF_TP64,
+    R_PARISC_LTOFF_TP14WR,
+    R_PARISC_LTOFF_TP14DR,
+    R_PARISC_LTOFF_TP16F,
+    R_PARISC_LTOFF_TP16WF,
+    R_PARISC_LTOFF_TP16DF,
+    R_PARISC_GNU_VTENTRY,
+    R_PARISC_GNU_VTINHERIT,
+    R_PARISC_TLS_GD21L,
+    R_PARISC_TLS_GD14R,
+    R_PARISC_TLS_GDCALL,
+    R_PARISC_TLS_LDM21L,
+    R_PARISC_TLS_LDM14R,
+    R_PARISC_TLS_LDMCALL,
+    R_PARISC_TLS_LDO21L,
+    R_PARISC_TLS_LDO14R,
+    R_PARISC_TLS_DTPMOD32,
+    R_PARISC_TLS_DTPMOD64,
+    R_PARISC_TLS_DTPOFF32,
+    R_PARISC_TLS_DTPOFF64,
+    R_PARISC_TLS_LE21L,
+    R_PARISC_TLS_LE14R,
+    R_PARISC_TLS_IE21L,
+    R_PARISC_TLS_IE14R,
+    R_PARISC_TLS_TPREL32,
+    R_PARISC_TLS_TPREL64
+);
+const NAMES_R_ALPHA: &[(u32, &str)] = &value_names!(
+    R_ALPHA_NONE,
+    R_ALPHA_REFLONG,
+    R_ALPHA_REFQUAD,
+    R_ALPHA_GPREL32,
+    R_ALPHA_LITERAL,
+    R_ALPHA_LITUSE,
+    R_ALPHA_GPDISP,
+    R_ALPHA_BRADDR,
+    R_ALPHA_HINT,
+    R_ALPHA_SREL16,
+    R_ALPHA_SREL32,
+    R_ALPHA_SREL64,
+    R_ALPHA_GPRELHIGH,
+    R_ALPHA_GPRELLOW,
+    R_ALPHA_GPREL16,
+    R_ALPHA_COPY,
+    R_ALPHA_GLOB_DAT,
+    R_ALPHA_JMP_SLOT,
+    R_ALPHA_RELATIVE,
+    R_ALPHA_TLS_GD_HI,
+    R_ALPHA_TLSGD,
+    R_ALPHA_TLS_LDM,
+    R_ALPHA_DTPMOD64,
+    R_ALPHA_GOTDTPREL,
+    R_ALPHA_DTPREL64,
+    R_ALPHA_DTPRELHI,
+    R_ALPHA_DTPRELLO,
+    R_ALPHA_DTPREL16,
+    R_ALPHA_GOTTPREL,
+    R_ALPHA_TPREL64,
+    R_ALPHA_TPRELHI,
+    R_ALPHA_TPRELLO,
+    R_ALPHA_TPREL16
+);
+const NAMES_R_PPC: &[(u32, &str)] = &value_names!(
+    R_PPC_NONE,
+    R_PPC_ADDR32,
+    R_PPC_ADDR24,
+    R_PPC_ADDR16,
+    R_PPC_ADDR16_LO,
+    R_PPC_ADDR16_HI,
+    R_PPC_ADDR16_HA,
+    R_PPC_ADDR14,
+    R_PPC_ADDR14_BRTAKEN,
+    R_PPC_ADDR14_BRNTAKEN,
+    R_PPC_REL24,
+    R_PPC_REL14,
+    R_PPC_REL14_BRTAKEN,
+    R_PPC_REL14_BRNTAKEN,
+    R_PPC_GOT16,
+    R_PPC_GOT16_LO,
+    R_PPC_GOT16_HI,
+    R_PPC_GOT16_HA,
+    R_PPC_PLTREL24,
+    R_PPC_COPY,
+    R_PPC_GLOB_DAT,
+    R_PPC_JMP_SLOT,
+    R_PPC_RELATIVE,
+    R_PPC_LOCAL24PC,
+    R_PPC_UADDR32,
+    R_PPC_UADDR16,
+    R_PPC_REL32,
+    R_PPC_PLT32,
+    R_PPC_PLTREL32,
+    R_PPC_PLT16_LO,
+    R_PPC_PLT16_HI,
+    R_PPC_PLT16_HA,
+    R_PPC_SDAREL16,
+    R_PPC_SECTOFF,
+    R_PPC_SECTOFF_LO,
+    R_PPC_SECTOFF_HI,
+    R_PPC_SECTOFF_HA,
+    R_PPC_TLS,
+    R_PPC_DTPMOD32,
+    R_PPC_TPREL16,
+    R_PPC_TPREL16_LO,
+    R_PPC_TPREL16_HI,
+    R_PPC_TPREL16_HA,
+    R_PPC_TPREL32,
+    R_PPC_DTPREL16,
+    R_PPC_DTPREL16_LO,
+    R_PPC_DTPREL16_HI,
+    R_PPC_DTPREL16_HA,
+    R_PPC_DTPREL32,
+    R_PPC_GOT_TLSGD16,
+    R_PPC_GOT_TLSGD16_LO,
+    R_PPC_GOT_TLSGD16_HI,
+    R_PPC_GOT_TLSGD16_HA,
+    R_PPC_GOT_TLSLD16,
+    R_PPC_GOT_TLSLD16_LO,
+    R_PPC_GOT_TLSLD16_HI,
+    R_PPC_GOT_TLSLD16_HA,
+    R_PPC_GOT_TPREL16,
+    R_PPC_GOT_TPREL16_LO,
+    R_PPC_GOT_TPREL16_HI,
+    R_PPC_GOT_TPREL16_HA,
+    R_PPC_GOT_DTPREL16,
+    R_PPC_GOT_DTPREL16_LO,
+    R_PPC_GOT_DTPREL16_HI,
+    R_PPC_GOT_DTPREL16_HA,
+    R_PPC_TLSGD,
+    R_PPC_TLSLD,
+    R_PPC_EMB_NADDR32,
+    R_PPC_EMB_NADDR16,
+    R_PPC_EMB_NADDR16_LO,
+    R_PPC_EMB_NADDR16_HI,
+    R_PPC_EMB_NADDR16_HA,
+    R_PPC_EMB_SDAI16,
+    R_PPC_EMB_SDA2I16,
+    R_PPC_EMB_SDA2REL,
+    R_PPC_EMB_SDA21,
+    R_PPC_EMB_MRKREF,
+    R_PPC_EMB_RELSEC16,
+    R_PPC_EMB_RELST_LO,
+    R_PPC_EMB_RELST_HI,
+    R_PPC_EMB_RELST_HA,
+    R_PPC_EMB_BIT_FLD,
+    R_PPC_EMB_RELSDA,
+    R_PPC_DIAB_SDA21_LO,
+    R_PPC_DIAB_SDA21_HI,
+    R_PPC_DIAB_SDA21_HA,
+    R_PPC_DIAB_RELSDA_LO,
+    R_PPC_DIAB_RELSDA_HI,
+    R_PPC_DIAB_RELSDA_HA,
+    R_PPC_IRELATIVE,
+    R_PPC_REL16,
+    R_PPC_REL16_LO,
+    R_PPC_REL16_HI,
+    R_PPC_REL16_HA,
+    R_PPC_TOC16
+);
+const NAMES_R_PPC64: &[(u32, &str)] = &value_names!(
+    R_PPC64_NONE,
+    R_PPC64_ADDR32,
+    R_PPC64_ADDR24,
+    R_PPC64_ADDR16,
+    R_PPC64_ADDR16_LO,
+    R_PPC64_ADDR16_HI,
+    R_PPC64_ADDR16_HA,
+    R_PPC64_ADDR14,
+    R_PPC64_ADDR14_BRTAKEN,
+    R_PPC64_ADDR14_BRNTAKEN,
+    R_PPC64_REL24,
+    R_PPC64_REL14,
+    R_PPC64_REL14_BRTAKEN,
+    R_PPC64_REL14_BRNTAKEN,
+    R_PPC64_GOT16,
+    R_PPC64_GOT16_LO,
+    R_PPC64_GOT16_HI,
+    R_PPC64_GOT16_HA,
+    R_PPC64_COPY,
+    R_PPC64_GLOB_DAT,
+    R_PPC64_JMP_SLOT,
+    R_PPC64_RELATIVE,
+    R_PPC64_UADDR32,
+    R_PPC64_UADDR16,
+    R_PPC64_REL32,
+    R_PPC64_PLT32,
+    R_PPC64_PLTREL32,
+    R_PPC64_PLT16_LO,
+    R_PPC64_PLT16_HI,
+    R_PPC64_PLT16_HA,
+    R_PPC64_SECTOFF,
+    R_PPC64_SECTOFF_LO,
+    R_PPC64_SECTOFF_HI,
+    R_PPC64_SECTOFF_HA,
+    R_PPC64_ADDR30,
+    R_PPC64_ADDR64,
+    R_PPC64_ADDR16_HIGHER,
+    R_PPC64_ADDR16_HIGHERA,
+    R_PPC64_ADDR16_HIGHEST,
+    R_PPC64_ADDR16_HIGHESTA,
+    R_PPC64_UADDR64,
+    R_PPC64_REL64,
+    R_PPC64_PLT64,
+    R_PPC64_PLTREL64,
+    R_PPC64_TOC16,
+    R_PPC64_TOC16_LO,
+    R_PPC64_TOC16_HI,
+    R_PPC64_TOC16_HA,
+    R_PPC64_TOC,
+    R_PPC64_PLTGOT16,
+    R_PPC64_PLTGOT16_LO,
+    R_PPC64_PLTGOT16_HI,
+    R_PPC64_PLTGOT16_HA,
+    R_PPC64_ADDR16_DS,
+    R_PPC64_ADDR16_LO_DS,
+    R_PPC64_GOT16_DS,
+    R_PPC64_GOT16_LO_DS,
+    R_PPC64_PLT16_LO_DS,
+    R_PPC64_SECTOFF_DS,
+    R_PPC64_SECTOFF_LO_DS,
+    R_PPC64_TOC16_DS,
+    R_PPC64_TOC16_LO_DS,
+    R_PPC64_PLTGOT16_DS,
+    R_PPC64_PLTGOT16_LO_DS,
+    R_PPC64_TLS,
+    R_PPC64_DTPMOD64,
+    R_PPC64_TPREL16,
+    R_PPC64_TPREL16_LO,
+    R_PPC64_TPREL16_HI,
+    R_PPC64_TPREL16_HA,
+    R_PPC64_TPREL64,
+    R_PPC64_DTPREL16,
+    R_PPC64_DTPREL16_LO,
+    R_PPC64_DTPREL16_HI,
+    R_PPC64_DTPREL16_HA,
+    R_PPC64_DTPREL64,
+    R_PPC64_GOT_TLSGD16,
+    R_PPC64_GOT_TLSGD16_LO,
+    R_PPC64_GOT_TLSGD16_HI,
+    R_PPC64_GOT_TLSGD16_HA,
+    R_PPC64_GOT_TLSLD16,
+    R_PPC64_GOT_TLSLD16_LO,
+    R_PPC64_GOT_TLSLD16_HI,
+    R_PPC64_GOT_TLSLD16_HA,
+    R_PPC64_GOT_TPREL16_DS,
+    R_PPC64_GOT_TPREL16_LO_DS,
+    R_PPC64_GOT_TPREL16_HI,
+    R_PPC64_GOT_TPREL16_HA,
+    R_PPC64_GOT_DTPREL16_DS,
+    R_PPC64_GOT_DTPREL16_LO_DS,
+    R_PPC64_GOT_DTPREL16_HI,
+    R_PPC64_GOT_DTPREL16_HA,
+    R_PPC64_TPREL16_DS,
+    R_PPC64_TPREL16_LO_DS,
+    R_PPC64_TPREL16_HIGHER,
+    R_PPC64_TPREL16_HIGHERA,
+    R_PPC64_TPREL16_HIGHEST,
+    R_PPC64_TPREL16_HIGHESTA,
+    R_PPC64_DTPREL16_DS,
+    R_PPC64_DTPREL16_LO_DS,
+    R_PPC64_DTPREL16_HIGHER,
+    R_PPC64_DTPREL16_HIGHERA,
+    R_PPC64_DTPREL16_HIGHEST,
+    R_PPC64_DTPREL16_HIGHESTA,
+    R_PPC64_TLSGD,
+    R_PPC64_TLSLD,
+    R_PPC64_TOCSAVE,
+    R_PPC64_ADDR16_HIGH,
+    R_PPC64_ADDR16_HIGHA,
+    R_PPC64_TPREL16_HIGH,
+    R_PPC64_TPREL16_HIGHA,
+    R_PPC64_DTPREL16_HIGH,
+    R_PPC64_DTPREL16_HIGHA,
+    R_PPC64_JMP_IREL,
+    R_PPC64_IRELATIVE,
+    R_PPC64_REL16,
+    R_PPC64_REL16_LO,
+    R_PPC64_REL16_HI,
+    R_PPC64_REL16_HA
+);
+const NAMES_R_AARCH64: &[(u32, &str)] = &value_names!(
+    R_AARCH64_NONE,
+    R_AARCH64_P32_ABS32,
+    R_AARCH64_P32_COPY,
+    R_AARCH64_P32_GLOB_DAT,
+    R_AARCH64_P32_JUMP_SLOT,
+    R_AARCH64_P32_RELATIVE,
+    R_AARCH64_P32_TLS_DTPMOD,
+    R_AARCH64_P32_TLS_DTPREL,
+    R_AARCH64_P32_TLS_TPREL,
+    R_AARCH64_P32_TLSDESC,
+    R_AARCH64_P32_IRELATIVE,
+    R_AARCH64_ABS64,
+    R_AARCH64_ABS32,
+    R_AARCH64_ABS16,
+    R_AARCH64_PREL64,
+    R_AARCH64_PREL32,
+    R_AARCH64_PREL16,
+    R_AARCH64_MOVW_UABS_G0,
+    R_AARCH64_MOVW_UABS_G0_NC,
+    R_AARCH64_MOVW_UABS_G1,
+    R_AARCH64_MOVW_UABS_G1_NC,
+    R_AARCH64_MOVW_UABS_G2,
+    R_AARCH64_MOVW_UABS_G2_NC,
+    R_AARCH64_MOVW_UABS_G3,
+    R_AARCH64_MOVW_SABS_G0,
+    R_AARCH64_MOVW_SABS_G1,
+    R_AARCH64_MOVW_SABS_G2,
+    R_AARCH64_LD_PREL_LO19,
+    R_AARCH64_ADR_PREL_LO21,
+    R_AARCH64_ADR_PREL_PG_HI21,
+    R_AARCH64_ADR_PREL_PG_HI21_NC,
+    R_AARCH64_ADD_ABS_LO12_NC,
+    R_AARCH64_LDST8_ABS_LO12_NC,
+    R_AARCH64_TSTBR14,
+    R_AARCH64_CONDBR19,
+    R_AARCH64_JUMP26,
+    R_AARCH64_CALL26,
+    R_AARCH64_LDST16_ABS_LO12_NC,
+    R_AARCH64_LDST32_ABS_LO12_NC,
+    R_AARCH64_LDST64_ABS_LO12_NC,
+    R_AARCH64_MOVW_PREL_G0,
+    R_AARCH64_MOVW_PREL_G0_NC,
+    R_AARCH64_MOVW_PREL_G1,
+    R_AARCH64_MOVW_PREL_G1_NC,
+    R_AARCH64_MOVW_PREL_G2,
+    R_AARCH64_MOVW_PREL_G2_NC,
+    R_AARCH64_MOVW_PREL_G3,
+    R_AARCH64_LDST128_ABS_LO12_NC,
+    R_AARCH64_MOVW_GOTOFF_G0,
+    R_AARCH64_MOVW_GOTOFF_G0_NC,
+    R_AARCH64_MOVW_GOTOFF_G1,
+    R_AARCH64_MOVW_GOTOFF_G1_NC,
+    R_AARCH64_MOVW_GOTOFF_G2,
+    R_AARCH64_MOVW_GOTOFF_G2_NC,
+    R_AARCH64_MOVW_GOTOFF_G3,
+    R_AARCH64_GOTREL64,
+    R_AARCH64_GOTREL32,
+    R_AARCH64_GOT_LD_PREL19,
+    R_AARCH64_LD64_GOTOFF_LO15,
+    R_AARCH64_ADR_GOT_PAGE,
+    R_AARCH64_LD64_GOT_LO12_NC,
+    R_AARCH64_LD64_GOTPAGE_LO15,
+    R_AARCH64_TLSGD_ADR_PREL21,
+    R_AARCH64_TLSGD_ADR_PAGE21,
+    R_AARCH64_TLSGD_ADD_LO12_NC,
+    R_AARCH64_TLSGD_MOVW_G1,
+    R_AARCH64_TLSGD_MOVW_G0_NC,
+    R_AARCH64_TLSLD_ADR_PREL21,
+    R_AARCH64_TLSLD_ADR_PAGE21,
+    R_AARCH64_TLSLD_ADD_LO12_NC,
+    R_AARCH64_TLSLD_MOVW_G1,
+    R_AARCH64_TLSLD_MOVW_G0_NC,
+    R_AARCH64_TLSLD_LD_PREL19,
+    R_AARCH64_TLSLD_MOVW_DTPREL_G2,
+    R_AARCH64_TLSLD_MOVW_DTPREL_G1,
+    R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC,
+    R_AARCH64_TLSLD_MOVW_DTPREL_G0,
+    R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC,
+    R_AARCH64_TLSLD_ADD_DTPREL_HI12,
+    R_AARCH64_TLSLD_ADD_DTPREL_LO12,
+    R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC,
+    R_AARCH64_TLSLD_LDST8_DTPREL_LO12,
+    R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC,
+    R_AARCH64_TLSLD_LDST16_DTPREL_LO12,
+    R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC,
+    R_AARCH64_TLSLD_LDST32_DTPREL_LO12,
+    R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC,
+    R_AARCH64_TLSLD_LDST64_DTPREL_LO12,
+    R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC,
+    R_AARCH64_TLSIE_MOVW_GOTTPREL_G1,
+    R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC,
+    R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21,
+    R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC,
+    R_AARCH64_TLSIE_LD_GOTTPREL_PREL19,
+    R_AARCH64_TLSLE_MOVW_TPREL_G2,
+    R_AARCH64_TLSLE_MOVW_TPREL_G1,
+    R_AARCH64_TLSLE_MOVW_TPREL_G1_NC,
+    R_AARCH64_TLSLE_MOVW_TPREL_G0,
+    R_AARCH64_TLSLE_MOVW_TPREL_G0_NC,
+    R_AARCH64_TLSLE_ADD_TPREL_HI12,
+    R_AARCH64_TLSLE_ADD_TPREL_LO12,
+    R_AARCH64_TLSLE_ADD_TPREL_LO12_NC,
+    R_AARCH64_TLSLE_LDST8_TPREL_LO12,
+    R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC,
+    R_AARCH64_TLSLE_LDST16_TPREL_LO12,
+    R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC,
+    R_AARCH64_TLSLE_LDST32_TPREL_LO12,
+    R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC,
+    R_AARCH64_TLSLE_LDST64_TPREL_LO12,
+    R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC,
+    R_AARCH64_TLSDESC_LD_PREL19,
+    R_AARCH64_TLSDESC_ADR_PREL21,
+    R_AARCH64_TLSDESC_ADR_PAGE21,
+    R_AARCH64_TLSDESC_LD64_LO12,
+    R_AARCH64_TLSDESC_ADD_LO12,
+    R_AARCH64_TLSDESC_OFF_G1,
+    R_AARCH64_TLSDESC_OFF_G0_NC,
+    R_AARCH64_TLSDESC_LDR,
+    R_AARCH64_TLSDESC_ADD,
+    R_AARCH64_TLSDESC_CALL,
+    R_AARCH64_TLSLE_LDST128_TPREL_LO12,
+    R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC,
+    R_AARCH64_TLSLD_LDST128_DTPREL_LO12,
+    R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC,
+    R_AARCH64_COPY,
+    R_AARCH64_GLOB_DAT,
+    R_AARCH64_JUMP_SLOT,
+    R_AARCH64_RELATIVE,
+    R_AARCH64_TLS_DTPMOD,
+    R_AARCH64_TLS_DTPREL,
+    R_AARCH64_TLS_TPREL,
+    R_AARCH64_TLSDESC,
+    R_AARCH64_IRELATIVE
+);
+const NAMES_R_ARM: &[(u32, &str)] = &value_names!(
+    R_ARM_NONE,
+    R_ARM_PC24,
+    R_ARM_ABS32,
+    R_ARM_REL32,
+    R_ARM_PC13,
+    R_ARM_ABS16,
+    R_ARM_ABS12,
+    R_ARM_THM_ABS5,
+    R_ARM_ABS8,
+    R_ARM_SBREL32,
+    R_ARM_THM_PC22,
+    R_ARM_THM_PC8,
+    R_ARM_AMP_VCALL9,
+    R_ARM_SWI24,
+    R_ARM_TLS_DESC,
+    R_ARM_THM_SWI8,
+    R_ARM_XPC25,
+    R_ARM_THM_XPC22,
+    R_ARM_TLS_DTPMOD32,
+    R_ARM_TLS_DTPOFF32,
+    R_ARM_TLS_TPOFF32,
+    R_ARM_COPY,
+    R_ARM_GLOB_DAT,
+    R_ARM_JUMP_SLOT,
+    R_ARM_RELATIVE,
+    R_ARM_GOTOFF,
+    R_ARM_GOTPC,
+    R_ARM_GOT32,
+    R_ARM_PLT32,
+    R_ARM_CALL,
+    R_ARM_JUMP24,
+    R_ARM_THM_JUMP24,
+    R_ARM_BASE_ABS,
+    R_ARM_ALU_PCREL_7_0,
+    R_ARM_ALU_PCREL_15_8,
+    R_ARM_ALU_PCREL_23_15,
+    R_ARM_LDR_SBREL_11_0,
+    R_ARM_ALU_SBREL_19_12,
+    R_ARM_ALU_SBREL_27_20,
+    R_ARM_TARGET1,
+    R_ARM_SBREL31,
+    R_ARM_V4BX,
+    R_ARM_TARGET2,
+    R_ARM_PREL31,
+    R_ARM_MOVW_ABS_NC,
+    R_ARM_MOVT_ABS,
+    R_ARM_MOVW_PREL_NC,
+    R_ARM_MOVT_PREL,
+    R_ARM_THM_MOVW_ABS_NC,
+    R_ARM_THM_MOVT_ABS,
+    R_ARM_THM_MOVW_PREL_NC,
+    R_ARM_THM_MOVT_PREL,
+    R_ARM_THM_JUMP19,
+    R_ARM_THM_JUMP6,
+    R_ARM_THM_ALU_PREL_11_0,
+    R_ARM_THM_PC12,
+    R_ARM_ABS32_NOI,
+    R_ARM_REL32_NOI,
+    R_ARM_ALU_PC_G0_NC,
+    R_ARM_ALU_PC_G0,
+    R_ARM_ALU_PC_G1_NC,
+    R_ARM_ALU_PC_G1,
+    R_ARM_ALU_PC_G2,
+    R_ARM_LDR_PC_G1,
+    R_ARM_LDR_PC_G2,
+    R_ARM_LDRS_PC_G0,
+    R_ARM_LDRS_PC_G1,
+    R_ARM_LDRS_PC_G2,
+    R_ARM_LDC_PC_G0,
+    R_ARM_LDC_PC_G1,
+    R_ARM_LDC_PC_G2,
+    R_ARM_ALU_SB_G0_NC,
+    R_ARM_ALU_SB_G0,
+    R_ARM_ALU_SB_G1_NC,
+    R_ARM_ALU_SB_G1,
+    R_ARM_ALU_SB_G2,
+    R_ARM_LDR_SB_G0,
+    R_ARM_LDR_SB_G1,
+    R_ARM_LDR_SB_G2,
+    R_ARM_LDRS_SB_G0,
+    R_ARM_LDRS_SB_G1,
+    R_ARM_LDRS_SB_G2,
+    R_ARM_LDC_SB_G0,
+    R_ARM_LDC_SB_G1,
+    R_ARM_LDC_SB_G2,
+    R_ARM_MOVW_BREL_NC,
+    R_ARM_MOVT_BREL,
+    R_ARM_MOVW_BREL,
+    R_ARM_THM_MOVW_BREL_NC,
+    R_ARM_THM_MOVT_BREL,
+    R_ARM_THM_MOVW_BREL,
+    R_ARM_TLS_GOTDESC,
+    R_ARM_TLS_CALL,
+    R_ARM_TLS_DESCSEQ,
+    R_ARM_THM_TLS_CALL,
+    R_ARM_PLT32_ABS,
+    R_ARM_GOT_ABS,
+    R_ARM_GOT_PREL,
+    R_ARM_GOT_BREL12,
+    R_ARM_GOTOFF12,
+    R_ARM_GOTRELAX,
+    R_ARM_GNU_VTENTRY,
+    R_ARM_GNU_VTINHERIT,
+    R_ARM_THM_PC11,
+    R_ARM_THM_PC9,
+    R_ARM_TLS_GD32,
+    R_ARM_TLS_LDM32,
+    R_ARM_TLS_LDO32,
+    R_ARM_TLS_IE32,
+    R_ARM_TLS_LE32,
+    R_ARM_TLS_LDO12,
+    R_ARM_TLS_LE12,
+    R_ARM_TLS_IE12GP,
+    R_ARM_ME_TOO,
+    R_ARM_THM_TLS_DESCSEQ,
+    R_ARM_THM_TLS_DESCSEQ16,
+    R_ARM_THM_TLS_DESCSEQ32,
+    R_ARM_THM_GOT_BREL12,
+    R_ARM_IRELATIVE,
+    R_ARM_RXPC25,
+    R_ARM_RSBREL32,
+    R_ARM_THM_RPC22,
+    R_ARM_RREL32,
+    R_ARM_RABS22,
+    R_ARM_RPC24,
+    R_ARM_RBASE
+);
+const NAMES_R_CKCORE: &[(u32, &str)] = &value_names!(
+    R_CKCORE_NONE,
+    R_CKCORE_ADDR32,
+    R_CKCORE_PCRELIMM8BY4,
+    R_CKCORE_PCRELIMM11BY2,
+    R_CKCORE_PCREL32,
+    R_CKCORE_PCRELJSR_IMM11BY2,
+    R_CKCORE_RELATIVE,
+    R_CKCORE_COPY,
+    R_CKCORE_GLOB_DAT,
+    R_CKCORE_JUMP_SLOT,
+    R_CKCORE_GOTOFF,
+    R_CKCORE_GOTPC,
+    R_CKCORE_GOT32,
+    R_CKCORE_PLT32,
+    R_CKCORE_ADDRGOT,
+    R_CKCORE_ADDRPLT,
+    R_CKCORE_PCREL_IMM26BY2,
+    R_CKCORE_PCREL_IMM16BY2,
+    R_CKCORE_PCREL_IMM16BY4,
+    R_CKCORE_PCREL_IMM10BY2,
+    R_CKCORE_PCREL_IMM10BY4,
+    R_CKCORE_ADDR_HI16,
+    R_CKCORE_ADDR_LO16,
+    R_CKCORE_GOTPC_HI16,
+    R_CKCORE_GOTPC_LO16,
+    R_CKCORE_GOTOFF_HI16,
+    R_CKCORE_GOTOFF_LO16,
+    R_CKCORE_GOT12,
+    R_CKCORE_GOT_HI16,
+    R_CKCORE_GOT_LO16,
+    R_CKCORE_PLT12,
+    R_CKCORE_PLT_HI16,
+    R_CKCORE_PLT_LO16,
+    R_CKCORE_ADDRGOT_HI16,
+    R_CKCORE_ADDRGOT_LO16,
+    R_CKCORE_ADDRPLT_HI16,
+    R_CKCORE_ADDRPLT_LO16,
+    R_CKCORE_PCREL_JSR_IMM26BY2,
+    R_CKCORE_TOFFSET_LO16,
+    R_CKCORE_DOFFSET_LO16,
+    R_CKCORE_PCREL_IMM18BY2,
+    R_CKCORE_DOFFSET_IMM18,
+    R_CKCORE_DOFFSET_IMM18BY2,
+    R_CKCORE_DOFFSET_IMM18BY4,
+    R_CKCORE_GOT_IMM18BY4,
+    R_CKCORE_PLT_IMM18BY4,
+    R_CKCORE_PCREL_IMM7BY4,
+    R_CKCORE_TLS_LE32,
+    R_CKCORE_TLS_IE32,
+    R_CKCORE_TLS_GD32,
+    R_CKCORE_TLS_LDM32,
+    R_CKCORE_TLS_LDO32,
+    R_CKCORE_TLS_DTPMOD32,
+    R_CKCORE_TLS_DTPOFF32,
+    R_CKCORE_TLS_TPOFF32
+);
+const NAMES_R_IA64: &[(u32, &str)] = &value_names!(
+    R_IA64_NONE,
+    R_IA64_IMM14,
+    R_IA64_IMM22,
+    R_IA64_IMM64,
+    R_IA64_DIR32MSB,
+    R_IA64_DIR32LSB,
+    R_IA64_DIR64MSB,
+    R_IA64_DIR64LSB,
+    R_IA64_GPREL22,
+    R_IA64_GPREL64I,
+    R_IA64_GPREL32MSB,
+    R_IA64_GPREL32LSB,
+    R_IA64_GPREL64MSB,
+    R_IA64_GPREL64LSB,
+    R_IA64_LTOFF22,
+    R_IA64_LTOFF64I,
+    R_IA64_PLTOFF22,
+    R_IA64_PLTOFF64I,
+    R_IA64_PLTOFF64MSB,
+    R_IA64_PLTOFF64LSB,
+    R_IA64_FPTR64I,
+    R_IA64_FPTR32MSB,
+    R_IA64_FPTR32LSB,
+    R_IA64_FPTR64MSB,
+    R_IA64_FPTR64LSB,
+    R_IA64_PCREL60B,
+    R_IA64_PCREL21B,
+    R_IA64_PCREL21M,
+    R_IA64_PCREL21F,
+    R_IA64_PCREL32MSB,
+    R_IA64_PCREL32LSB,
+    R_IA64_PCREL64MSB,
+    R_IA64_PCREL64LSB,
+    R_IA64_LTOFF_FPTR22,
+    R_IA64_LTOFF_FPTR64I,
+    R_IA64_LTOFF_FPTR32MSB,
+    R_IA64_LTOFF_FPTR32LSB,
+    R_IA64_LTOFF_FPTR64MSB,
+    R_IA64_LTOFF_FPTR64LSB,
+    R_IA64_SEGREL32MSB,
+    R_IA64_SEGREL32LSB,
+    R_IA64_SEGREL64MSB,
+    R_IA64_SEGREL64LSB,
+    R_IA64_SECREL32MSB,
+    R_IA64_SECREL32LSB,
+    R_IA64_SECREL64MSB,
+    R_IA64_SECREL64LSB,
+    R_IA64_REL32MSB,
+    R_IA64_REL32LSB,
+    R_IA64_REL64MSB,
+    R_IA64_REL64LSB,
+    R_IA64_LTV32MSB,
+    R_IA64_LTV32LSB,
+    R_IA64_LTV64MSB,
+    R_IA64_LTV64LSB,
+    R_IA64_PCREL21BI,
+    R_IA64_PCREL22,
+    R_IA64_PCREL64I,
+    R_IA64_IPLTMSB,
+    R_IA64_IPLTLSB,
+    R_IA64_COPY,
+    R_IA64_SUB,
+    R_IA64_LTOFF22X,
+    R_IA64_LDXMOV,
+    R_IA64_TPREL14,
+    R_IA64_TPREL22,
+    R_IA64_TPREL64I,
+    R_IA64_TPREL64MSB,
+    R_IA64_TPREL64LSB,
+    R_IA64_LTOFF_TPREL22,
+    R_IA64_DTPMOD64MSB,
+    R_IA64_DTPMOD64LSB,
+    R_IA64_LTOFF_DTPMOD22,
+    R_IA64_DTPREL14,
+    R_IA64_DTPREL22,
+    R_IA64_DTPREL64I,
+    R_IA64_DTPREL32MSB,
+    R_IA64_DTPREL32LSB,
+    R_IA64_DTPREL64MSB,
+    R_IA64_DTPREL64LSB,
+    R_IA64_LTOFF_DTPREL22
+);
+const NAMES_R_SH: &[(u32, &str)] = &value_names!(
+    R_SH_NONE,
+    R_SH_DIR32,
+    R_SH_REL32,
+    R_SH_DIR8WPN,
+    R_SH_IND12W,
+    R_SH_DIR8WPL,
+    R_SH_DIR8WPZ,
+    R_SH_DIR8BP,
+    R_SH_DIR8W,
+    R_SH_DIR8L,
+    R_SH_SWITCH16,
+    R_SH_SWITCH32,
+    R_SH_USES,
+    R_SH_COUNT,
+    R_SH_ALIGN,
+    R_SH_CODE,
+    R_SH_DATA,
+    R_SH_LABEL,
+    R_SH_SWITCH8,
+    R_SH_GNU_VTINHERIT,
+    R_SH_GNU_VTENTRY,
+    R_SH_TLS_GD_32,
+    R_SH_TLS_LD_32,
+    R_SH_TLS_LDO_32,
+    R_SH_TLS_IE_32,
+    R_SH_TLS_LE_32,
+    R_SH_TLS_DTPMOD32,
+    R_SH_TLS_DTPOFF32,
+    R_SH_TLS_TPOFF32,
+    R_SH_GOT32,
+    R_SH_PLT32,
+    R_SH_COPY,
+    R_SH_GLOB_DAT,
+    R_SH_JMP_SLOT,
+    R_SH_RELATIVE,
+    R_SH_GOTOFF,
+    R_SH_GOTPC
+);
+const NAMES_R_390: &[(u32, &str)] = &value_names!(
+    R_390_NONE,
+    R_390_8,
+    R_390_12,
+    R_390_16,
+    R_390_32,
+    R_390_PC32,
+    R_390_GOT12,
+    R_390_GOT32,
+    R_390_PLT32,
+    R_390_COPY,
+    R_390_GLOB_DAT,
+    R_390_JMP_SLOT,
+    R_390_RELATIVE,
+    R_390_GOTOFF32,
+    R_390_GOTPC,
+    R_390_GOT16,
+    R_390_PC16,
+    R_390_PC16DBL,
+    R_390_PLT16DBL,
+    R_390_PC32DBL,
+    R_390_PLT32DBL,
+    R_390_GOTPCDBL,
+    R_390_64,
+    R_390_PC64,
+    R_390_GOT64,
+    R_390_PLT64,
+    R_390_GOTENT,
+    R_390_GOTOFF16,
+    R_390_GOTOFF64,
+    R_390_GOTPLT12,
+    R_390_GOTPLT16,
+    R_390_GOTPLT32,
+    R_390_GOTPLT64,
+    R_390_GOTPLTENT,
+    R_390_PLTOFF16,
+    R_390_PLTOFF32,
+    R_390_PLTOFF64,
+    R_390_TLS_LOAD,
+    R_390_TLS_GDCALL,
+    R_390_TLS_LDCALL,
+    R_390_TLS_GD32,
+    R_390_TLS_GD64,
+    R_390_TLS_GOTIE12,
+    R_390_TLS_GOTIE32,
+    R_390_TLS_GOTIE64,
+    R_390_TLS_LDM32,
+    R_390_TLS_LDM64,
+    R_390_TLS_IE32,
+    R_390_TLS_IE64,
+    R_390_TLS_IEENT,
+    R_390_TLS_LE32,
+    R_390_TLS_LE64,
+    R_390_TLS_LDO32,
+    R_390_TLS_LDO64,
+    R_390_TLS_DTPMOD,
+    R_390_TLS_DTPOFF,
+    R_390_TLS_TPOFF,
+    R_390_20,
+    R_390_GOT20,
+    R_390_GOTPLT20,
+    R_390_TLS_GOTIE20,
+    R_390_IRELATIVE
+);
+const NAMES_R_CRIS: &[(u32, &str)] = &value_names!(
+    R_CRIS_NONE,
+    R_CRIS_8,
+    R_CRIS_16,
+    R_CRIS_32,
+    R_CRIS_8_PCREL,
+    R_CRIS_16_PCREL,
+    R_CRIS_32_PCREL,
+    R_CRIS_GNU_VTINHERIT,
+    R_CRIS_GNU_VTENTRY,
+    R_CRIS_COPY,
+    R_CRIS_GLOB_DAT,
+    R_CRIS_JUMP_SLOT,
+    R_CRIS_RELATIVE,
+    R_CRIS_16_GOT,
+    R_CRIS_32_GOT,
+    R_CRIS_16_GOTPLT,
+    R_CRIS_32_GOTPLT,
+    R_CRIS_32_GOTREL,
+    R_CRIS_32_PLT_GOTREL,
+    R_CRIS_32_PLT_PCREL
+);
+const NAMES_R_X86_64: &[(u32, &str)] = &value_names!(
+    R_X86_64_NONE,
+    R_X86_64_64,
+    R_X86_64_PC32,
+    R_X86_64_GOT32,
+    R_X86_64_PLT32,
+    R_X86_64_COPY,
+    R_X86_64_GLOB_DAT,
+    R_X86_64_JUMP_SLOT,
+    R_X86_64_RELATIVE,
+    R_X86_64_GOTPCREL,
+    R_X86_64_32,
+    R_X86_64_32S,
+    R_X86_64_16,
+    R_X86_64_PC16,
+    R_X86_64_8,
+    R_X86_64_PC8,
+    R_X86_64_DTPMOD64,
+    R_X86_64_DTPOFF64,
+    R_X86_64_TPOFF64,
+    R_X86_64_TLSGD,
+    R_X86_64_TLSLD,
+    R_X86_64_DTPOFF32,
+    R_X86_64_GOTTPOFF,
+    R_X86_64_TPOFF32,
+    R_X86_64_PC64,
+    R_X86_64_GOTOFF64,
+    R_X86_64_GOTPC32,
+    R_X86_64_GOT64,
+    R_X86_64_GOTPCREL64,
+    R_X86_64_GOTPC64,
+    R_X86_64_GOTPLT64,
+    R_X86_64_PLTOFF64,
+    R_X86_64_SIZE32,
+    R_X86_64_SIZE64,
+    R_X86_64_GOTPC32_TLSDESC,
+    R_X86_64_TLSDESC_CALL,
+    R_X86_64_TLSDESC,
+    R_X86_64_IRELATIVE,
+    R_X86_64_RELATIVE64,
+    R_X86_64_GOTPCRELX,
+    R_X86_64_REX_GOTPCRELX
+);
+const NAMES_R_MN10300: &[(u32, &str)] = &value_names!(
+    R_MN10300_NONE,
+    R_MN10300_32,
+    R_MN10300_16,
+    R_MN10300_8,
+    R_MN10300_PCREL32,
+    R_MN10300_PCREL16,
+    R_MN10300_PCREL8,
+    R_MN10300_GNU_VTINHERIT,
+    R_MN10300_GNU_VTENTRY,
+    R_MN10300_24,
+    R_MN10300_GOTPC32,
+    R_MN10300_GOTPC16,
+    R_MN10300_GOTOFF32,
+    R_MN10300_GOTOFF24,
+    R_MN10300_GOTOFF16,
+    R_MN10300_PLT32,
+    R_MN10300_PLT16,
+    R_MN10300_GOT32,
+    R_MN10300_GOT24,
+    R_MN10300_GOT16,
+    R_MN10300_COPY,
+    R_MN10300_GLOB_DAT,
+    R_MN10300_JMP_SLOT,
+    R_MN10300_RELATIVE,
+    R_MN10300_TLS_GD,
+    R_MN10300_TLS_LD,
+    R_MN10300_TLS_LDO,
+    R_MN10300_TLS_GOTIE,
+    R_MN10300_TLS_IE,
+    R_MN10300_TLS_LE,
+    R_MN10300_TLS_DTPMOD,
+    R_MN10300_TLS_DTPOFF,
+    R_MN10300_TLS_TPOFF,
+    R_MN10300_SYM_DIFF,
+    R_MN10300_ALIGN
+);
+const NAMES_R_M32R: &[(u32, &str)] = &value_names!(
+    R_M32R_NONE,
+    R_M32R_16,
+    R_M32R_32,
+    R_M32R_24,
+    R_M32R_10_PCREL,
+    R_M32R_18_PCREL,
+    R_M32R_26_PCREL,
+    R_M32R_HI16_ULO,
+    R_M32R_HI16_SLO,
+    R_M32R_LO16,
+    R_M32R_SDA16,
+    R_M32R_GNU_VTINHERIT,
+    R_M32R_GNU_VTENTRY,
+    R_M32R_16_RELA,
+    R_M32R_32_RELA,
+    R_M32R_24_RELA,
+    R_M32R_10_PCREL_RELA,
+    R_M32R_18_PCREL_RELA,
+    R_M32R_26_PCREL_RELA,
+    R_M32R_HI16_ULO_RELA,
+    R_M32R_HI16_SLO_RELA,
+    R_M32R_LO16_RELA,
+    R_M32R_SDA16_RELA,
+    R_M32R_RELA_GNU_VTINHERIT,
+    R_M32R_RELA_GNU_VTENTRY,
+    R_M32R_REL32,
+    R_M32R_GOT24,
+    R_M32R_26_PLTREL,
+    R_M32R_COPY,
+    R_M32R_GLOB_DAT,
+    R_M32R_JMP_SLOT,
+    R_M32R_RELATIVE,
+    R_M32R_GOTOFF,
+    R_M32R_GOTPC24,
+    R_M32R_GOT16_HI_ULO,
+    R_M32R_GOT16_HI_SLO,
+    R_M32R_GOT16_LO,
+    R_M32R_GOTPC_HI_ULO,
+    R_M32R_GOTPC_HI_SLO,
+    R_M32R_GOTPC_LO,
+    R_M32R_GOTOFF_HI_ULO,
+    R_M32R_GOTOFF_HI_SLO,
+    R_M32R_GOTOFF_LO,
+    R_M32R_NUM
+);
+const NAMES_R_MICROBLAZE: &[(u32, &str)] = &value_names!(
+    R_MICROBLAZE_NONE,
+    R_MICROBLAZE_32,
+    R_MICROBLAZE_32_PCREL,
+    R_MICROBLAZE_64_PCREL,
+    R_MICROBLAZE_32_PCREL_LO,
+    R_MICROBLAZE_64,
+    R_MICROBLAZE_32_LO,
+    R_MICROBLAZE_SRO32,
+    R_MICROBLAZE_SRW32,
+    R_MICROBLAZE_64_NONE,
+    R_MICROBLAZE_32_SYM_OP_SYM,
+    R_MICROBLAZE_GNU_VTINHERIT,
+    R_MICROBLAZE_GNU_VTENTRY,
+    R_MICROBLAZE_GOTPC_64,
+    R_MICROBLAZE_GOT_64,
+    R_MICROBLAZE_PLT_64,
+    R_MICROBLAZE_REL,
+    R_MICROBLAZE_JUMP_SLOT,
+    R_MICROBLAZE_GLOB_DAT,
+    R_MICROBLAZE_GOTOFF_64,
+    R_MICROBLAZE_GOTOFF_32,
+    R_MICROBLAZE_COPY,
+    R_MICROBLAZE_TLS,
+    R_MICROBLAZE_TLSGD,
+    R_MICROBLAZE_TLSLD,
+    R_MICROBLAZE_TLSDTPMOD32,
+    R_MICROBLAZE_TLSDTPREL32,
+    R_MICROBLAZE_TLSDTPREL64,
+    R_MICROBLAZE_TLSGOTTPREL32,
+    R_MICROBLAZE_TLSTPREL32
+);
+const NAMES_R_NIOS2: &[(u32, &str)] = &value_names!(
+    R_NIOS2_NONE,
+    R_NIOS2_S16,
+    R_NIOS2_U16,
+    R_NIOS2_PCREL16,
+    R_NIOS2_CALL26,
+    R_NIOS2_IMM5,
+    R_NIOS2_CACHE_OPX,
+    R_NIOS2_IMM6,
+    R_NIOS2_IMM8,
+    R_NIOS2_HI16,
+    R_NIOS2_LO16,
+    R_NIOS2_HIADJ16,
+    R_NIOS2_BFD_RELOC_32,
+    R_NIOS2_BFD_RELOC_16,
+    R_NIOS2_BFD_RELOC_8,
+    R_NIOS2_GPREL,
+    R_NIOS2_GNU_VTINHERIT,
+    R_NIOS2_GNU_VTENTRY,
+    R_NIOS2_UJMP,
+    R_NIOS2_CJMP,
+    R_NIOS2_CALLR,
+    R_NIOS2_ALIGN,
+    R_NIOS2_GOT16,
+    R_NIOS2_CALL16,
+    R_NIOS2_GOTOFF_LO,
+    R_NIOS2_GOTOFF_HA,
+    R_NIOS2_PCREL_LO,
+    R_NIOS2_PCREL_HA,
+    R_NIOS2_TLS_GD16,
+    R_NIOS2_TLS_LDM16,
+    R_NIOS2_TLS_LDO16,
+    R_NIOS2_TLS_IE16,
+    R_NIOS2_TLS_LE16,
+    R_NIOS2_TLS_DTPMOD,
+    R_NIOS2_TLS_DTPREL,
+    R_NIOS2_TLS_TPREL,
+    R_NIOS2_COPY,
+    R_NIOS2_GLOB_DAT,
+    R_NIOS2_JUMP_SLOT,
+    R_NIOS2_RELATIVE,
+    R_NIOS2_GOTOFF,
+    R_NIOS2_CALL26_NOAT,
+    R_NIOS2_GOT_LO,
+    R_NIOS2_GOT_HA,
+    R_NIOS2_CALL_LO,
+    R_NIOS2_CALL_HA
+);
+const NAMES_R_TILEPRO: &[(u32, &str)] = &value_names!(
+    R_TILEPRO_NONE,
+    R_TILEPRO_32,
+    R_TILEPRO_16,
+    R_TILEPRO_8,
+    R_TILEPRO_32_PCREL,
+    R_TILEPRO_16_PCREL,
+    R_TILEPRO_8_PCREL,
+    R_TILEPRO_LO16,
+    R_TILEPRO_HI16,
+    R_TILEPRO_HA16,
+    R_TILEPRO_COPY,
+    R_TILEPRO_GLOB_DAT,
+    R_TILEPRO_JMP_SLOT,
+    R_TILEPRO_RELATIVE,
+    R_TILEPRO_BROFF_X1,
+    R_TILEPRO_JOFFLONG_X1,
+    R_TILEPRO_JOFFLONG_X1_PLT,
+    R_TILEPRO_IMM8_X0,
+    R_TILEPRO_IMM8_Y0,
+    R_TILEPRO_IMM8_X1,
+    R_TILEPRO_IMM8_Y1,
+    R_TILEPRO_MT_IMM15_X1,
+    R_TILEPRO_MF_IMM15_X1,
+    R_TILEPRO_IMM16_X0,
+    R_TILEPRO_IMM16_X1,
+    R_TILEPRO_IMM16_X0_LO,
+    R_TILEPRO_IMM16_X1_LO,
+    R_TILEPRO_IMM16_X0_HI,
+    R_TILEPRO_IMM16_X1_HI,
+    R_TILEPRO_IMM16_X0_HA,
+    R_TILEPRO_IMM16_X1_HA,
+    R_TILEPRO_IMM16_X0_PCREL,
+    R_TILEPRO_IMM16_X1_PCREL,
+    R_TILEPRO_IMM16_X0_LO_PCREL,
+    R_TILEPRO_IMM16_X1_LO_PCREL,
+    R_TILEPRO_IMM16_X0_HI_PCREL,
+    R_TILEPRO_IMM16_X1_HI_PCREL,
+    R_TILEPRO_IMM16_X0_HA_PCREL,
+    R_TILEPRO_IMM16_X1_HA_PCREL,
+    R_TILEPRO_IMM16_X0_GOT,
+    R_TILEPRO_IMM16_X1_GOT,
+    R_TILEPRO_IMM16_X0_GOT_LO,
+    R_TILEPRO_IMM16_X1_GOT_LO,
+    R_TILEPRO_IMM16_X0_GOT_HI,
+    R_TILEPRO_IMM16_X1_GOT_HI,
+    R_TILEPRO_IMM16_X0_GOT_HA,
+    R_TILEPRO_IMM16_X1_GOT_HA,
+    R_TILEPRO_MMSTART_X0,
+    R_TILEPRO_MMEND_X0,
+    R_TILEPRO_MMSTART_X1,
+    R_TILEPRO_MMEND_X1,
+    R_TILEPRO_SHAMT_X0,
+    R_TILEPRO_SHAMT_X1,
+    R_TILEPRO_SHAMT_Y0,
+    R_TILEPRO_SHAMT_Y1,
+    R_TILEPRO_DEST_IMM8_X1,
+    R_TILEPRO_TLS_GD_CALL,
+    R_TILEPRO_IMM8_X0_TLS_GD_ADD,
+    R_TILEPRO_IMM8_X1_TLS_GD_ADD,
+    R_TILEPRO_IMM8_Y0_TLS_GD_ADD,
+    R_TILEPRO_IMM8_Y1_TLS_GD_ADD,
+    R_TILEPRO_TLS_IE_LOAD,
+    R_TILEPRO_IMM16_X0_TLS_GD,
+    R_TILEPRO_IMM16_X1_TLS_GD,
+    R_TILEPRO_IMM16_X0_TLS_GD_LO,
+    R_TILEPRO_IMM16_X1_TLS_GD_LO,
+    R_TILEPRO_IMM16_X0_TLS_GD_HI,
+    R_TILEPRO_IMM16_X1_TLS_GD_HI,
+    R_TILEPRO_IMM16_X0_TLS_GD_HA,
+    R_TILEPRO_IMM16_X1_TLS_GD_HA,
+    R_TILEPRO_IMM16_X0_TLS_IE,
+    R_TILEPRO_IMM16_X1_TLS_IE,
+    R_TILEPRO_IMM16_X0_TLS_IE_LO,
+    R_TILEPRO_IMM16_X1_TLS_IE_LO,
+    R_TILEPRO_IMM16_X0_TLS_IE_HI,
+    R_TILEPRO_IMM16_X1_TLS_IE_HI,
+    R_TILEPRO_IMM16_X0_TLS_IE_HA,
+    R_TILEPRO_IMM16_X1_TLS_IE_HA,
+    R_TILEPRO_TLS_DTPMOD32,
+    R_TILEPRO_TLS_DTPOFF32,
+    R_TILEPRO_TLS_TPOFF32,
+    R_TILEPRO_IMM16_X0_TLS_LE,
+    R_TILEPRO_IMM16_X1_TLS_LE,
+    R_TILEPRO_IMM16_X0_TLS_LE_LO,
+    R_TILEPRO_IMM16_X1_TLS_LE_LO,
+    R_TILEPRO_IMM16_X0_TLS_LE_HI,
+    R_TILEPRO_IMM16_X1_TLS_LE_HI,
+    R_TILEPRO_IMM16_X0_TLS_LE_HA,
+    R_TILEPRO_IMM16_X1_TLS_LE_HA,
+    R_TILEPRO_GNU_VTINHERIT,
+    R_TILEPRO_GNU_VTENTRY
+);
+const NAMES_R_TILEGX: &[(u32, &str)] = &value_names!(
+    R_TILEGX_NONE,
+    R_TILEGX_64,
+    R_TILEGX_32,
+    R_TILEGX_16,
+    R_TILEGX_8,
+    R_TILEGX_64_PCREL,
+    R_TILEGX_32_PCREL,
+    R_TILEGX_16_PCREL,
+    R_TILEGX_8_PCREL,
+    R_TILEGX_HW0,
+    R_TILEGX_HW1,
+    R_TILEGX_HW2,
+    R_TILEGX_HW3,
+    R_TILEGX_HW0_LAST,
+    R_TILEGX_HW1_LAST,
+    R_TILEGX_HW2_LAST,
+    R_TILEGX_COPY,
+    R_TILEGX_GLOB_DAT,
+    R_TILEGX_JMP_SLOT,
+    R_TILEGX_RELATIVE,
+    R_TILEGX_BROFF_X1,
+    R_TILEGX_JUMPOFF_X1,
+    R_TILEGX_JUMPOFF_X1_PLT,
+    R_TILEGX_IMM8_X0,
+    R_TILEGX_IMM8_Y0,
+    R_TILEGX_IMM8_X1,
+    R_TILEGX_IMM8_Y1,
+    R_TILEGX_DEST_IMM8_X1,
+    R_TILEGX_MT_IMM14_X1,
+    R_TILEGX_MF_IMM14_X1,
+    R_TILEGX_MMSTART_X0,
+    R_TILEGX_MMEND_X0,
+    R_TILEGX_SHAMT_X0,
+    R_TILEGX_SHAMT_X1,
+    R_TILEGX_SHAMT_Y0,
+    R_TILEGX_SHAMT_Y1,
+    R_TILEGX_IMM16_X0_HW0,
+    R_TILEGX_IMM16_X1_HW0,
+    R_TILEGX_IMM16_X0_HW1,
+    R_TILEGX_IMM16_X1_HW1,
+    R_TILEGX_IMM16_X0_HW2,
+    R_TILEGX_IMM16_X1_HW2,
+    R_TILEGX_IMM16_X0_HW3,
+    R_TILEGX_IMM16_X1_HW3,
+    R_TILEGX_IMM16_X0_HW0_LAST,
+    R_TILEGX_IMM16_X1_HW0_LAST,
+    R_TILEGX_IMM16_X0_HW1_LAST,
+    R_TILEGX_IMM16_X1_HW1_LAST,
+    R_TILEGX_IMM16_X0_HW2_LAST,
+    R_TILEGX_IMM16_X1_HW2_LAST,
+    R_TILEGX_IMM16_X0_HW0_PCREL,
+    R_TILEGX_IMM16_X1_HW0_PCREL,
+    R_TILEGX_IMM16_X0_HW1_PCREL,
+    R_TILEGX_IMM16_X1_HW1_PCREL,
+    R_TILEGX_IMM16_X0_HW2_PCREL,
+    R_TILEGX_IMM16_X1_HW2_PCREL,
+    R_TILEGX_IMM16_X0_HW3_PCREL,
+    R_TILEGX_IMM16_X1_HW3_PCREL,
+    R_TILEGX_IMM16_X0_HW0_LAST_PCREL,
+    R_TILEGX_IMM16_X1_HW0_LAST_PCREL,
+    R_TILEGX_IMM16_X0_HW1_LAST_PCREL,
+    R_TILEGX_IMM16_X1_HW1_LAST_PCREL,
+    R_TILEGX_IMM16_X0_HW2_LAST_PCREL,
+    R_TILEGX_IMM16_X1_HW2_LAST_PCREL,
+    R_TILEGX_IMM16_X0_HW0_GOT,
+    R_TILEGX_IMM16_X1_HW0_GOT,
+    R_TILEGX_IMM16_X0_HW0_PLT_PCREL,
+    R_TILEGX_IMM16_X1_HW0_PLT_PCREL,
+    R_TILEGX_IMM16_X0_HW1_PLT_PCREL,
+    R_TILEGX_IMM16_X1_HW1_PLT_PCREL,
+    R_TILEGX_IMM16_X0_HW2_PLT_PCREL,
+    R_TILEGX_IMM16_X1_HW2_PLT_PCREL,
+    R_TILEGX_IMM16_X0_HW0_LAST_GOT,
+    R_TILEGX_IMM16_X1_HW0_LAST_GOT,
+    R_TILEGX_IMM16_X0_HW1_LAST_GOT,
+    R_TILEGX_IMM16_X1_HW1_LAST_GOT,
+    R_TILEGX_IMM16_X0_HW3_PLT_PCREL,
+    R_TILEGX_IMM16_X1_HW3_PLT_PCREL,
+    R_TILEGX_IMM16_X0_HW0_TLS_GD,
+    R_TILEGX_IMM16_X1_HW0_TLS_GD,
+    R_TILEGX_IMM16_X0_HW0_TLS_LE,
+    R_TILEGX_IMM16_X1_HW0_TLS_LE,
+    R_TILEGX_IMM16_X0_HW0_LAST_TLS_LE,
+    R_TILEGX_IMM16_X1_HW0_LAST_TLS_LE,
+    R_TILEGX_IMM16_X0_HW1_LAST_TLS_LE,
+    R_TILEGX_IMM16_X1_HW1_LAST_TLS_LE,
+    R_TILEGX_IMM16_X0_HW0_LAST_TLS_GD,
+    R_TILEGX_IMM16_X1_HW0_LAST_TLS_GD,
+    R_TILEGX_IMM16_X0_HW1_LAST_TLS_GD,
+    R_TILEGX_IMM16_X1_HW1_LAST_TLS_GD,
+    R_TILEGX_IMM16_X0_HW0_TLS_IE,
+    R_TILEGX_IMM16_X1_HW0_TLS_IE,
+    R_TILEGX_IMM16_X0_HW0_LAST_PLT_PCREL,
+    R_TILEGX_IMM16_X1_HW0_LAST_PLT_PCREL,
+    R_TILEGX_IMM16_X0_HW1_LAST_PLT_PCREL,
+    R_TILEGX_IMM16_X1_HW1_LAST_PLT_PCREL,
+    R_TILEGX_IMM16_X0_HW2_LAST_PLT_PCREL,
+    R_TILEGX_IMM16_X1_HW2_LAST_PLT_PCREL,
+    R_TILEGX_IMM16_X0_HW0_LAST_TLS_IE,
+    R_TILEGX_IMM16_X1_HW0_LAST_TLS_IE,
+    R_TILEGX_IMM16_X0_HW1_LAST_TLS_IE,
+    R_TILEGX_IMM16_X1_HW1_LAST_TLS_IE,
+    R_TILEGX_TLS_DTPMOD64,
+    R_TILEGX_TLS_DTPOFF64,
+    R_TILEGX_TLS_TPOFF64,
+    R_TILEGX_TLS_DTPMOD32,
+    R_TILEGX_TLS_DTPOFF32,
+    R_TILEGX_TLS_TPOFF32,
+    R_TILEGX_TLS_GD_CALL,
+    R_TILEGX_IMM8_X0_TLS_GD_ADD,
+    R_TILEGX_IMM8_X1_TLS_GD_ADD,
+    R_TILEGX_IMM8_Y0_TLS_GD_ADD,
+    R_TILEGX_IMM8_Y1_TLS_GD_ADD,
+    R_TILEGX_TLS_IE_LOAD,
+    R_TILEGX_IMM8_X0_TLS_ADD,
+    R_TILEGX_IMM8_X1_TLS_ADD,
+    R_TILEGX_IMM8_Y0_TLS_ADD,
+    R_TILEGX_IMM8_Y1_TLS_ADD,
+    R_TILEGX_GNU_VTINHERIT,
+    R_TILEGX_GNU_VTENTRY
+);
+const NAMES_R_RISCV: &[(u32, &str)] = &value_names!(
+    R_RISCV_NONE,
+    R_RISCV_32,
+    R_RISCV_64,
+    R_RISCV_RELATIVE,
+    R_RISCV_COPY,
+    R_RISCV_JUMP_SLOT,
+    R_RISCV_TLS_DTPMOD32,
+    R_RISCV_TLS_DTPMOD64,
+    R_RISCV_TLS_DTPREL32,
+    R_RISCV_TLS_DTPREL64,
+    R_RISCV_TLS_TPREL32,
+    R_RISCV_TLS_TPREL64,
+    R_RISCV_BRANCH,
+    R_RISCV_JAL,
+    R_RISCV_CALL,
+    R_RISCV_CALL_PLT,
+    R_RISCV_GOT_HI20,
+    R_RISCV_TLS_GOT_HI20,
+    R_RISCV_TLS_GD_HI20,
+    R_RISCV_PCREL_HI20,
+    R_RISCV_PCREL_LO12_I,
+    R_RISCV_PCREL_LO12_S,
+    R_RISCV_HI20,
+    R_RISCV_LO12_I,
+    R_RISCV_LO12_S,
+    R_RISCV_TPREL_HI20,
+    R_RISCV_TPREL_LO12_I,
+    R_RISCV_TPREL_LO12_S,
+    R_RISCV_TPREL_ADD,
+    R_RISCV_ADD8,
+    R_RISCV_ADD16,
+    R_RISCV_ADD32,
+    R_RISCV_ADD64,
+    R_RISCV_SUB8,
+    R_RISCV_SUB16,
+    R_RISCV_SUB32,
+    R_RISCV_SUB64,
+    R_RISCV_GNU_VTINHERIT,
+    R_RISCV_GNU_VTENTRY,
+    R_RISCV_ALIGN,
+    R_RISCV_RVC_BRANCH,
+    R_RISCV_RVC_JUMP,
+    R_RISCV_RVC_LUI,
+    R_RISCV_GPREL_I,
+    R_RISCV_GPREL_S,
+    R_RISCV_TPREL_I,
+    R_RISCV_TPREL_S,
+    R_RISCV_RELAX,
+    R_RISCV_SUB6,
+    R_RISCV_SET6,
+    R_RISCV_SET8,
+    R_RISCV_SET16,
+    R_RISCV_SET32,
+    R_RISCV_32_PCREL
+);
+const NAMES_R_BPF: &[(u32, &str)] = &value_names!(R_BPF_NONE, R_BPF_64_64, R_BPF_64_32);
+const NAMES_R_SBF: &[(u32, &str)] = &value_names!(R_SBF_NONE, R_SBF_64_64, R_SBF_64_32);
+const NAMES_R_LOONGARCH: &[(u32, &str)] = &value_names!(
+    R_LARCH_NONE,
+    R_LARCH_32,
+    R_LARCH_64,
+    R_LARCH_RELATIVE,
+    R_LARCH_COPY,
+    R_LARCH_JUMP_SLOT,
+    R_LARCH_TLS_DTPMOD32,
+    R_LARCH_TLS_DTPMOD64,
+    R_LARCH_TLS_DTPREL32,
+    R_LARCH_TLS_DTPREL64,
+    R_LARCH_TLS_TPREL32,
+    R_LARCH_TLS_TPREL64,
+    R_LARCH_IRELATIVE,
+    R_LARCH_MARK_LA,
+    R_LARCH_MARK_PCREL,
+    R_LARCH_SOP_PUSH_PCREL,
+    R_LARCH_SOP_PUSH_ABSOLUTE,
+    R_LARCH_SOP_PUSH_DUP,
+    R_LARCH_SOP_PUSH_GPREL,
+    R_LARCH_SOP_PUSH_TLS_TPREL,
+    R_LARCH_SOP_PUSH_TLS_GOT,
+    R_LARCH_SOP_PUSH_TLS_GD,
+    R_LARCH_SOP_PUSH_PLT_PCREL,
+    R_LARCH_SOP_ASSERT,
+    R_LARCH_SOP_NOT,
+    R_LARCH_SOP_SUB,
+    R_LARCH_SOP_SL,
+    R_LARCH_SOP_SR,
+    R_LARCH_SOP_ADD,
+    R_LARCH_SOP_AND,
+    R_LARCH_SOP_IF_ELSE,
+    R_LARCH_SOP_POP_32_S_10_5,
+    R_LARCH_SOP_POP_32_U_10_12,
+    R_LARCH_SOP_POP_32_S_10_12,
+    R_LARCH_SOP_POP_32_S_10_16,
+    R_LARCH_SOP_POP_32_S_10_16_S2,
+    R_LARCH_SOP_POP_32_S_5_20,
+    R_LARCH_SOP_POP_32_S_0_5_10_16_S2,
+    R_LARCH_SOP_POP_32_S_0_10_10_16_S2,
+    R_LARCH_SOP_POP_32_U,
+    R_LARCH_ADD8,
+    R_LARCH_ADD16,
+    R_LARCH_ADD24,
+    R_LARCH_ADD32,
+    R_LARCH_ADD64,
+    R_LARCH_SUB8,
+    R_LARCH_SUB16,
+    R_LARCH_SUB24,
+    R_LARCH_SUB32,
+    R_LARCH_SUB64,
+    R_LARCH_GNU_VTINHERIT,
+    R_LARCH_GNU_VTENTRY,
+    R_LARCH_B16,
+    R_LARCH_B21,
+    R_LARCH_B26,
+    R_LARCH_ABS_HI20,
+    R_LARCH_ABS_LO12,
+    R_LARCH_ABS64_LO20,
+    R_LARCH_ABS64_HI12,
+    R_LARCH_PCALA_HI20,
+    R_LARCH_PCALA_LO12,
+    R_LARCH_PCALA64_LO20,
+    R_LARCH_PCALA64_HI12,
+    R_LARCH_GOT_PC_HI20,
+    R_LARCH_GOT_PC_LO12,
+    R_LARCH_GOT64_PC_LO20,
+    R_LARCH_GOT64_PC_HI12,
+    R_LARCH_GOT_HI20,
+    R_LARCH_GOT_LO12,
+    R_LARCH_GOT64_LO20,
+    R_LARCH_GOT64_HI12,
+    R_LARCH_TLS_LE_HI20,
+    R_LARCH_TLS_LE_LO12,
+    R_LARCH_TLS_LE64_LO20,
+    R_LARCH_TLS_LE64_HI12,
+    R_LARCH_TLS_IE_PC_HI20,
+    R_LARCH_TLS_IE_PC_LO12,
+    R_LARCH_TLS_IE64_PC_LO20,
+    R_LARCH_TLS_IE64_PC_HI12,
+    R_LARCH_TLS_IE_HI20,
+    R_LARCH_TLS_IE_LO12,
+    R_LARCH_TLS_IE64_LO20,
+    R_LARCH_TLS_IE64_HI12,
+    R_LARCH_TLS_LD_PC_HI20,
+    R_LARCH_TLS_LD_HI20,
+    R_LARCH_TLS_GD_PC_HI20,
+    R_LARCH_TLS_GD_HI20,
+    R_LARCH_32_PCREL,
+    R_LARCH_RELAX
+);
+const NAMES_R_METAG: &[(u32, &str)] = &value_names!(
+    R_METAG_HIADDR16,
+    R_METAG_LOADDR16,
+    R_METAG_ADDR32,
+    R_METAG_NONE,
+    R_METAG_RELBRANCH,
+    R_METAG_GETSETOFF,
+    R_METAG_REG32OP1,
+    R_METAG_REG32OP2,
+    R_METAG_REG32OP3,
+    R_METAG_REG16OP1,
+    R_METAG_REG16OP2,
+    R_METAG_REG16OP3,
+    R_METAG_REG32OP4,
+    R_METAG_HIOG,
+    R_METAG_LOOG,
+    R_METAG_REL8,
+    R_METAG_REL16,
+    R_METAG_GNU_VTINHERIT,
+    R_METAG_GNU_VTENTRY,
+    R_METAG_HI16_GOTOFF,
+    R_METAG_LO16_GOTOFF,
+    R_METAG_GETSET_GOTOFF,
+    R_METAG_GETSET_GOT,
+    R_METAG_HI16_GOTPC,
+    R_METAG_LO16_GOTPC,
+    R_METAG_HI16_PLT,
+    R_METAG_LO16_PLT,
+    R_METAG_RELBRANCH_PLT,
+    R_METAG_GOTOFF,
+    R_METAG_PLT,
+    R_METAG_COPY,
+    R_METAG_JMP_SLOT,
+    R_METAG_RELATIVE,
+    R_METAG_GLOB_DAT,
+    R_METAG_TLS_GD,
+    R_METAG_TLS_LDM,
+    R_METAG_TLS_LDO_HI16,
+    R_METAG_TLS_LDO_LO16,
+    R_METAG_TLS_LDO,
+    R_METAG_TLS_IE,
+    R_METAG_TLS_IENONPIC,
+    R_METAG_TLS_IENONPIC_HI16,
+    R_METAG_TLS_IENONPIC_LO16,
+    R_METAG_TLS_TPOFF,
+    R_METAG_TLS_DTPMOD,
+    R_METAG_TLS_DTPOFF,
+    R_METAG_TLS_LE,
+    R_METAG_TLS_LE_HI16,
+    R_METAG_TLS_LE_LO16
+);
+const NAMES_R_NDS32: &[(u32, &str)] = &value_names!(
+    R_NDS32_NONE,
+    R_NDS32_32_RELA,
+    R_NDS32_COPY,
+    R_NDS32_GLOB_DAT,
+    R_NDS32_JMP_SLOT,
+    R_NDS32_RELATIVE,
+    R_NDS32_TLS_TPOFF,
+    R_NDS32_TLS_DESC
+);
+const NAMES_R_XTENSA: &[(u32, &str)] = &value_names!(
+    R_XTENSA_NONE,
+    R_XTENSA_32,
+    R_XTENSA_RTLD,
+    R_XTENSA_GLOB_DAT,
+    R_XTENSA_JMP_SLOT,
+    R_XTENSA_RELATIVE,
+    R_XTENSA_PLT,
+    R_XTENSA_OP0,
+    R_XTENSA_OP1,
+    R_XTENSA_OP2,
+    R_XTENSA_ASM_EXPAND,
+    R_XTENSA_ASM_SIMPLIFY,
+    R_XTENSA_32_PCREL,
+    R_XTENSA_GNU_VTINHERIT,
+    R_XTENSA_GNU_VTENTRY,
+    R_XTENSA_DIFF8,
+    R_XTENSA_DIFF16,
+    R_XTENSA_DIFF32,
+    R_XTENSA_SLOT0_OP,
+    R_XTENSA_SLOT1_OP,
+    R_XTENSA_SLOT2_OP,
+    R_XTENSA_SLOT3_OP,
+    R_XTENSA_SLOT4_OP,
+    R_XTENSA_SLOT5_OP,
+    R_XTENSA_SLOT6_OP,
+    R_XTENSA_SLOT7_OP,
+    R_XTENSA_SLOT8_OP,
+    R_XTENSA_SLOT9_OP,
+    R_XTENSA_SLOT10_OP,
+    R_XTENSA_SLOT11_OP,
+    R_XTENSA_SLOT12_OP,
+    R_XTENSA_SLOT13_OP,
+    R_XTENSA_SLOT14_OP,
+    R_XTENSA_SLOT0_ALT,
+    R_XTENSA_SLOT1_ALT,
+    R_XTENSA_SLOT2_ALT,
+    R_XTENSA_SLOT3_ALT,
+    R_XTENSA_SLOT4_ALT,
+    R_XTENSA_SLOT5_ALT,
+    R_XTENSA_SLOT6_ALT,
+    R_XTENSA_SLOT7_ALT,
+    R_XTENSA_SLOT8_ALT,
+    R_XTENSA_SLOT9_ALT,
+    R_XTENSA_SLOT10_ALT,
+    R_XTENSA_SLOT11_ALT,
+    R_XTENSA_SLOT12_ALT,
+    R_XTENSA_SLOT13_ALT,
+    R_XTENSA_SLOT14_ALT,
+    R_XTENSA_TLSDESC_FN,
+    R_XTENSA_TLSDESC_ARG,
+    R_XTENSA_TLS_DTPOFF,
+    R_XTENSA_TLS_TPOFF,
+    R_XTENSA_TLS_FUNC,
+    R_XTENSA_TLS_ARG,
+    R_XTENSA_TLS_CALL,
+    R_XTENSA_PDIFF8,
+    R_XTENSA_PDIFF16,
+    R_XTENSA_PDIFF32,
+    R_XTENSA_NDIFF8,
+    R_XTENSA_NDIFF16,
+    R_XTENSA_NDIFF32
+);
+const NAMES_SHF: &[FlagName] = &flag_names!(
+    SHF_WRITE,
+    SHF_ALLOC,
+    SHF_EXECINSTR,
+    SHF_MERGE,
+    SHF_STRINGS,
+    SHF_INFO_LINK,
+    SHF_LINK_ORDER,
+    SHF_OS_NONCONFORMING,
+    SHF_GROUP,
+    SHF_TLS,
+    SHF_COMPRESSED
+);
+const NAMES_SHF_MIPS: &[FlagName] = &flag_names!(
+    SHF_MIPS_GPREL,
+    SHF_MIPS_MERGE,
+    SHF_MIPS_ADDR,
+    SHF_MIPS_STRINGS,
+    SHF_MIPS_NOSTRIP,
+    SHF_MIPS_LOCAL,
+    SHF_MIPS_NAMES,
+    SHF_MIPS_NODUPE
+);
+const NAMES_SHF_PARISC: &[FlagName] =
+    &flag_names!(SHF_PARISC_SHORT, SHF_PARISC_HUGE, SHF_PARISC_SBP);
+const NAMES_SHF_ALPHA: &[FlagName] = &flag_names!(SHF_ALPHA_GPREL);
+const NAMES_SHF_ARM: &[FlagName] = &flag_names!(SHF_ARM_ENTRYSECT, SHF_ARM_COMDEF);
+const NAMES_SHF_IA_64: &[FlagName] = &flag_names!(SHF_IA_64_SHORT, SHF_IA_64_NORECOV);
+const NAMES_SHF_EXCLUDE: &[FlagName] = &flag_names!(SHF_EXCLUDE);
+const NAMES_STB: &[(u8, &str)] = &value_names!(STB_LOCAL, STB_GLOBAL, STB_WEAK);
+const NAMES_STB_GNU: &[(u8, &str)] = &value_names!(STB_GNU_UNIQUE);
+const NAMES_STB_MIPS: &[(u8, &str)] = &value_names!(STB_MIPS_SPLIT_COMMON);
+const NAMES_STT: &[(u8, &str)] = &value_names!(
+    STT_NOTYPE,
+    STT_OBJECT,
+    STT_FUNC,
+    STT_SECTION,
+    STT_FILE,
+    STT_COMMON,
+    STT_TLS
+);
+const NAMES_STT_GNU: &[(u8, &str)] = &value_names!(STT_GNU_IFUNC);
+const NAMES_STT_SPARC: &[(u8, &str)] = &value_names!(STT_SPARC_REGISTER);
+const NAMES_STT_PARISC: &[(u8, &str)] = &value_names!(STT_PARISC_MILLICODE);
+const NAMES_STT_ARM: &[(u8, &str)] = &value_names!(STT_ARM_TFUNC, STT_ARM_16BIT);
+const NAMES_STV: &[(u8, &str)] =
+    &value_names!(STV_DEFAULT, STV_INTERNAL, STV_HIDDEN, STV_PROTECTED);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!(
+            r_type_name(EM_X86_64, R_X86_64_PLT32),
+            Some("R_X86_64_PLT32")
+        );
+        assert_eq!(
+            r_type_name(EM_AARCH64, R_AARCH64_CALL26),
+            Some("R_AARCH64_CALL26")
+        );
+        assert_eq!(r_type_name(EM_X86_64, 0xffff), None);
+        assert_eq!(r_type_name(EM_NONE, 0), None);
+
+        let flags = u64::from(SHF_ALLOC | SHF_EXECINSTR | SHF_EXCLUDE) | 0x1_0000_0000;
+        let names = sh_flags_names(EM_X86_64, flags);
+        assert_eq!(names.unknown(), 0x1_0000_0000);
+        assert!(names.eq(["SHF_ALLOC", "SHF_EXECINSTR", "SHF_EXCLUDE"]));
+        let names = sh_flags_names(EM_ARM, u64::from(SHF_ARM_COMDEF));
+        assert!(names.eq(["SHF_ARM_COMDEF"]));
+
+        assert_eq!(
+            st_bind_name(EM_X86_64, STB_GNU_UNIQUE),
+            Some("STB_GNU_UNIQUE")
+        );
+        assert_eq!(st_type_name(EM_ARM, STT_ARM_TFUNC), Some("STT_ARM_TFUNC"));
+        assert_eq!(st_type_name(EM_X86_64, STT_ARM_TFUNC), None);
+        assert_eq!(st_visibility_name(STV_HIDDEN), Some("STV_HIDDEN"));
+    }
+}
@@ -314,6 +314,7 @@ impl Endian for Endianness {
 
 /// Compile-time little endian byte order.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct LittleEndian;
 
 impl Default for LittleEndian {
@@ -341,6 +342,7 @@ impl Endian for LittleEndian {
 
 /// Compile-time big endian byte order.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct BigEndian;
 
 impl Default for BigEndian {
@@ -614,6 +616,45 @@ pub type I32<E> = I32Bytes<E>;
 #[cfg(feature = "unaligned")]
 pub type I64<E> = I64Bytes<E>;
 
+/// Implement `Serialize` for endian types with a fixed endianness.
+///
+/// Only `LittleEndian` and `BigEndian` implement `Serialize`, which allows
+/// deriving `Serialize` for structures that are generic over the endianness.
+/// Types using `Endianness` can't be serialized because the endianness is
+/// not known without the file header.
+#[cfg(feature = "serde")]
+macro_rules! impl_endian_serialize {
+    ($($name:ident => $ty:ty,)*) => {
+        $(
+            impl<E: Endian + serde::Serialize> serde::Serialize for $name<E> {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    <$ty as serde::Serialize>::serialize(&self.get(E::default()), serializer)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(all(feature = "serde", not(feature = "unaligned")))]
+impl_endian_serialize!(
+    U16 => u16,
+    U32 => u32,
+    U64 => u64,
+    I16 => i16,
+    I32 => i32,
+    I64 => i64,
+);
+
+#[cfg(feature = "serde")]
+impl_endian_serialize!(
+    U16Bytes => u16,
+    U32Bytes => u32,
+    U64Bytes => u64,
+    I16Bytes => i16,
+    I32Bytes => i32,
+    I64Bytes => i64,
+);
+
 /// An unaligned `u16` value with an externally specified endianness of type `E`.
 #[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
@@ -16,7 +16,9 @@
 //! by [read::File], which allows reading any file format, as well as implementations
 //! for each file format: [ELF](read::elf::ElfFile), [Mach-O](read::macho::MachOFile),
 //! [COFF](read::coff::CoffFile), [PE](read::pe::PeFile), [Wasm](read::wasm::WasmFile),
-//! [XCOFF](read::xcoff::XcoffFile).
+//! [XCOFF](read::xcoff::XcoffFile), [Intel HEX](read::ihex::IhexFile),
+//! [S-record](read::srec::SrecFile), [raw binary](read::raw::RawFile),
+//! [a.out](read::aout::AoutFile), [OMF](read::omf::OmfFile).
 //!
 //! ## Low level read API
 //!
@@ -24,6 +26,17 @@
 //! operate on the raw structs. These also provide traits that abstract over the differences
 //! between 32-bit and 64-bit versions of the file format.
 //!
+//! ## `no_std` support
+//!
+//! This crate is `no_std`, but reading and writing require the `alloc` crate.
+//! Parsing ELF and PE executables, iterating over their sections and symbols,
+//! and looking up sections by name do not allocate. Looking up symbols by name
+//! only allocates when the `std` feature is enabled, since
+//! [read::Object::symbol_by_name] then builds an index on first use. ELF relocatable
+//! files allocate a map from sections to their relocations during parsing.
+//! Other file formats, and methods that return a `Vec` such as
+//! [read::Object::imports], may allocate.
+//!
 //! ## Unified write API
 //!
 //! [write::Object] allows building a COFF/ELF/Mach-O/XCOFF relocatable object file and
@@ -85,6 +98,7 @@ extern crate alloc;
 #[macro_use]
 extern crate std;
 
+#[macro_use]
 mod common;
 pub use common::*;
 
@@ -104,12 +118,16 @@ pub use read::*;
 #[cfg(feature = "write_core")]
 pub mod write;
 
+#[cfg(feature = "aout")]
+pub mod aout;
 #[cfg(feature = "archive")]
 pub mod archive;
 #[cfg(feature = "elf")]
 pub mod elf;
 #[cfg(feature = "macho")]
 pub mod macho;
+#[cfg(feature = "omf")]
+pub mod omf;
 #[cfg(any(feature = "coff", feature = "pe"))]
 pub mod pe;
 #[cfg(feature = "xcoff")]
@@ -10,6 +10,9 @@
 use crate::endian::{BigEndian, Endian, U64Bytes, U16, U32, U64};
 use crate::pod::Pod;
 
+mod names;
+pub use names::*;
+
 // Definitions from "/usr/include/mach/machine.h".
 
 /*
@@ -412,6 +415,7 @@ pub const FAT_MAGIC: u32 = 0xcafe_babe;
 pub const FAT_CIGAM: u32 = 0xbeba_feca;
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct FatHeader {
     /// FAT_MAGIC or FAT_MAGIC_64
@@ -421,6 +425,7 @@ pub struct FatHeader {
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct FatArch32 {
     /// cpu specifier (int)
@@ -447,6 +452,7 @@ pub const FAT_MAGIC_64: u32 = 0xcafe_babf;
 pub const FAT_CIGAM_64: u32 = 0xbfba_feca;
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct FatArch64 {
     /// cpu specifier (int)
@@ -469,6 +475,7 @@ pub struct FatArch64 {
 ///
 /// Appears at the very beginning of the object file for 32-bit architectures.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct MachHeader32<E: Endian> {
     /// mach magic number identifier
@@ -497,6 +504,7 @@ pub const MH_CIGAM: u32 = 0xcefa_edfe;
 ///
 /// Appears at the very beginning of object files for 64-bit architectures.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct MachHeader64<E: Endian> {
     /// mach magic number identifier
@@ -810,6 +818,7 @@ pub struct LcStr<E: Endian> {
 /// `Section32` structures directly follow the segment command and their size is
 /// reflected in `cmdsize`.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct SegmentCommand32<E: Endian> {
     /// LC_SEGMENT
@@ -843,6 +852,7 @@ pub struct SegmentCommand32<E: Endian> {
 /// sections then `Section64` structures directly follow the 64-bit segment
 /// command and their size is reflected in `cmdsize`.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct SegmentCommand64<E: Endian> {
     /// LC_SEGMENT_64
@@ -910,6 +920,7 @@ pub const SG_READ_ONLY: u32 = 0x10;
  */
 /// 32-bit section.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct Section32<E: Endian> {
     /// name of this section
@@ -938,6 +949,7 @@ pub struct Section32<E: Endian> {
 
 /// 64-bit section.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct Section64<E: Endian> {
     /// name of this section
@@ -1388,6 +1400,16 @@ pub struct ThreadCommand<E: Endian> {
     /* ... */
 }
 
+// Values for the thread state flavor in `ThreadCommand`.
+/// i386 thread state for `CPU_TYPE_X86`. The `eip` register is at offset 40.
+pub const X86_THREAD_STATE32: u32 = 1;
+/// x86_64 thread state for `CPU_TYPE_X86_64`. The `rip` register is at offset 128.
+pub const X86_THREAD_STATE64: u32 = 4;
+/// ARM thread state for `CPU_TYPE_ARM`. The `pc` register is at offset 60.
+pub const ARM_THREAD_STATE: u32 = 1;
+/// ARM64 thread state for `CPU_TYPE_ARM64`. The `pc` register is at offset 256.
+pub const ARM_THREAD_STATE64: u32 = 6;
+
 /*
  * The routines command contains the address of the dynamic shared library
  * initialization routine and an index into the module table for the module
@@ -2300,6 +2322,98 @@ pub const DICE_KIND_JUMP_TABLE16: u32 = 0x0003;
 pub const DICE_KIND_JUMP_TABLE32: u32 = 0x0004;
 pub const DICE_KIND_ABS_JUMP_TABLE32: u32 = 0x0005;
 
+/*
+ * The LC_DYLD_CHAINED_FIXUPS load command uses a LinkeditDataCommand
+ * to point to a DyldChainedFixupsHeader.
+ */
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct DyldChainedFixupsHeader<E: Endian> {
+    /// 0
+    pub fixups_version: U32<E>,
+    /// offset of DyldChainedStartsInImage in chain_data
+    pub starts_offset: U32<E>,
+    /// offset of imports table in chain_data
+    pub imports_offset: U32<E>,
+    /// offset of symbol strings in chain_data
+    pub symbols_offset: U32<E>,
+    /// number of imported symbol names
+    pub imports_count: U32<E>,
+    /// DYLD_CHAINED_IMPORT*
+    pub imports_format: U32<E>,
+    /// 0 => uncompressed, 1 => zlib compressed
+    pub symbols_format: U32<E>,
+}
+
+/*
+ * The DyldChainedStartsInImage header is followed by an array of
+ * `seg_count` U32 offsets. Each offset is from the start of this
+ * header to a DyldChainedStartsInSegment, or 0 if the segment has no fixups.
+ */
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct DyldChainedStartsInImage<E: Endian> {
+    /// number of segments
+    pub seg_count: U32<E>,
+}
+
+/*
+ * The DyldChainedStartsInSegment header is followed by an array of
+ * `page_count` U16 page starts, and then any chain starts for pages
+ * with DYLD_CHAINED_PTR_START_MULTI.
+ */
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct DyldChainedStartsInSegment<E: Endian> {
+    /// size of this (amount kernel needs to copy)
+    pub size: U32<E>,
+    /// 0x1000 or 0x4000
+    pub page_size: U16<E>,
+    /// DYLD_CHAINED_PTR_*
+    pub pointer_format: U16<E>,
+    /// offset in memory to start of segment
+    pub segment_offset: U64Bytes<E>,
+    /// for 32-bit OS, any value beyond this is not a pointer
+    pub max_valid_pointer: U32<E>,
+    /// how many pages are in the page start array
+    pub page_count: U16<E>,
+}
+
+// Values for `DyldChainedStartsInSegment::page_start`.
+/// used in page_start[] to denote a page with no fixups
+pub const DYLD_CHAINED_PTR_START_NONE: u16 = 0xFFFF;
+/// used in page_start[] to denote a page which has multiple starts
+pub const DYLD_CHAINED_PTR_START_MULTI: u16 = 0x8000;
+/// used in chain_starts[] to denote last start in list for page
+pub const DYLD_CHAINED_PTR_START_LAST: u16 = 0x8000;
+
+// Values for `DyldChainedStartsInSegment::pointer_format`.
+/// stride 8, unauth target is vmaddr
+pub const DYLD_CHAINED_PTR_ARM64E: u16 = 1;
+/// target is vmaddr
+pub const DYLD_CHAINED_PTR_64: u16 = 2;
+pub const DYLD_CHAINED_PTR_32: u16 = 3;
+pub const DYLD_CHAINED_PTR_32_CACHE: u16 = 4;
+pub const DYLD_CHAINED_PTR_32_FIRMWARE: u16 = 5;
+/// target is vm offset
+pub const DYLD_CHAINED_PTR_64_OFFSET: u16 = 6;
+/// stride 4, unauth target is vm offset
+pub const DYLD_CHAINED_PTR_ARM64E_KERNEL: u16 = 7;
+pub const DYLD_CHAINED_PTR_64_KERNEL_CACHE: u16 = 8;
+/// stride 8, unauth target is vm offset
+pub const DYLD_CHAINED_PTR_ARM64E_USERLAND: u16 = 9;
+/// stride 4, unauth target is vmaddr
+pub const DYLD_CHAINED_PTR_ARM64E_FIRMWARE: u16 = 10;
+/// stride 1, x86_64 kernel caches
+pub const DYLD_CHAINED_PTR_X86_64_KERNEL_CACHE: u16 = 11;
+/// stride 8, unauth target is vm offset, 24-bit bind
+pub const DYLD_CHAINED_PTR_ARM64E_USERLAND24: u16 = 12;
+
+// Values for `DyldChainedFixupsHeader::imports_format`.
+pub const DYLD_CHAINED_IMPORT: u32 = 1;
+pub const DYLD_CHAINED_IMPORT_ADDEND: u32 = 2;
+pub const DYLD_CHAINED_IMPORT_ADDEND64: u32 = 3;
+
 /*
  * Sections of type S_THREAD_LOCAL_VARIABLES contain an array
  * of TlvDescriptor structures.
@@ -2334,6 +2448,146 @@ pub struct NoteCommand<E: Endian> {
     pub size: U64<E>,
 }
 
+// Definitions for the `LC_NOTE` payloads used by kernel and firmware core files.
+
+/// `NoteCommand::data_owner` for `MainBinSpecPayload`.
+pub const NOTE_MAIN_BIN_SPEC: &[u8] = b"main bin spec";
+/// `NoteCommand::data_owner` for `LoadBinaryPayload`.
+pub const NOTE_LOAD_BINARY: &[u8] = b"load binary";
+/// `NoteCommand::data_owner` for `AddrableBitsPayload`.
+pub const NOTE_ADDRABLE_BITS: &[u8] = b"addrable bits";
+
+/// The main binary of a core file.
+///
+/// This is the layout for version 2. Version 1 does not have the `slide` field.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct MainBinSpecPayload<E: Endian> {
+    /// the payload version, currently 2
+    pub version: U32<E>,
+    /// one of the `MAIN_BIN_SPEC_TYPE_*` constants
+    pub bin_type: U32<E>,
+    /// load address, or `u64::MAX` if not specified
+    pub address: U64Bytes<E>,
+    /// slide, or `u64::MAX` if not specified
+    pub slide: U64Bytes<E>,
+    /// UUID, or all zeros if not specified
+    pub uuid: [u8; 16],
+    /// page size in log base 2, or 0 if not specified
+    pub log2_pagesize: U32<E>,
+    /// one of the `PLATFORM_*` constants, or 0 if not specified
+    pub platform: U32<E>,
+}
+
+/// The type of the main binary is not specified.
+pub const MAIN_BIN_SPEC_TYPE_UNSPECIFIED: u32 = 0;
+/// The main binary is a kernel.
+pub const MAIN_BIN_SPEC_TYPE_KERNEL: u32 = 1;
+/// The main binary is a user process.
+pub const MAIN_BIN_SPEC_TYPE_USER: u32 = 2;
+/// The main binary is a standalone binary, such as firmware.
+pub const MAIN_BIN_SPEC_TYPE_STANDALONE: u32 = 3;
+
+/// A binary loaded in a core file.
+///
+/// This is followed by the null terminated name of the binary.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct LoadBinaryPayload<E: Endian> {
+    /// the payload version, currently 1
+    pub version: U32<E>,
+    /// UUID, or all zeros if not specified
+    pub uuid: [u8; 16],
+    /// load address, or `u64::MAX` if not specified
+    pub load_address: U64Bytes<E>,
+    /// slide, or 0 if not specified
+    pub slide: U64Bytes<E>,
+}
+
+/// The number of bits used for addressing in a core file.
+///
+/// This is the layout for version 3. Versions 1 and 2 only have the `version`
+/// field followed by a single number of bits, which applies to all addresses.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct AddrableBitsPayload<E: Endian> {
+    /// the payload version, currently 3
+    pub version: U32<E>,
+    /// number of bits for low memory addresses, or 0 if not specified
+    pub lo_addr_bits: U32<E>,
+    /// number of bits for high memory addresses, or 0 if not specified
+    pub hi_addr_bits: U32<E>,
+    pub reserved: U32<E>,
+}
+
+// Definitions from "osfmk/kern/cs_blobs.h".
+//
+// The code signature referenced by `LC_CODE_SIGNATURE` is always big-endian.
+
+/// Magic number for `CsSuperBlob` of an embedded signature.
+pub const CSMAGIC_EMBEDDED_SIGNATURE: u32 = 0xfade_0cc0;
+/// Magic number for a code directory blob.
+pub const CSMAGIC_CODEDIRECTORY: u32 = 0xfade_0c02;
+/// Magic number for a requirements set blob.
+pub const CSMAGIC_REQUIREMENTS: u32 = 0xfade_0c01;
+/// Magic number for a CMS signature blob.
+pub const CSMAGIC_BLOBWRAPPER: u32 = 0xfade_0b01;
+/// Magic number for an XML entitlements blob.
+pub const CSMAGIC_EMBEDDED_ENTITLEMENTS: u32 = 0xfade_7171;
+/// Magic number for a DER entitlements blob.
+pub const CSMAGIC_EMBEDDED_DER_ENTITLEMENTS: u32 = 0xfade_7172;
+
+/// `CsBlobIndex::typ` for the code directory.
+pub const CSSLOT_CODEDIRECTORY: u32 = 0;
+/// `CsBlobIndex::typ` for the Info.plist hash.
+pub const CSSLOT_INFOSLOT: u32 = 1;
+/// `CsBlobIndex::typ` for the requirements set.
+pub const CSSLOT_REQUIREMENTS: u32 = 2;
+/// `CsBlobIndex::typ` for the resource directory hash.
+pub const CSSLOT_RESOURCEDIR: u32 = 3;
+/// `CsBlobIndex::typ` for application specific data.
+pub const CSSLOT_APPLICATION: u32 = 4;
+/// `CsBlobIndex::typ` for the XML entitlements.
+pub const CSSLOT_ENTITLEMENTS: u32 = 5;
+/// `CsBlobIndex::typ` for the DER entitlements.
+pub const CSSLOT_DER_ENTITLEMENTS: u32 = 7;
+/// `CsBlobIndex::typ` for the first alternate code directory.
+pub const CSSLOT_ALTERNATE_CODEDIRECTORIES: u32 = 0x1000;
+/// `CsBlobIndex::typ` for the CMS signature.
+pub const CSSLOT_SIGNATURESLOT: u32 = 0x10000;
+
+/// The header of a code signature, followed by `count` instances of `CsBlobIndex`.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct CsSuperBlob {
+    /// CSMAGIC_EMBEDDED_SIGNATURE
+    pub magic: U32<BigEndian>,
+    /// total length of the super blob
+    pub length: U32<BigEndian>,
+    /// number of index entries following
+    pub count: U32<BigEndian>,
+}
+
+/// An entry in the index of a `CsSuperBlob`.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct CsBlobIndex {
+    /// one of the `CSSLOT_*` constants
+    pub typ: U32<BigEndian>,
+    /// offset of the blob from the start of the super blob
+    pub offset: U32<BigEndian>,
+}
+
+/// The header of a blob in a `CsSuperBlob`, followed by the blob data.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct CsGenericBlob {
+    /// one of the `CSMAGIC_*` constants
+    pub magic: U32<BigEndian>,
+    /// total length of the blob, including this header
+    pub length: U32<BigEndian>,
+}
+
 // Definitions from "/usr/include/mach-o/nlist.h".
 
 #[derive(Debug, Clone, Copy)]
@@ -2448,11 +2702,18 @@ pub const MAX_SECT: u8 = 255;
  * the alignment is not set (a value of zero) then natural alignment based on
  * the size is used.
  */
-/* TODO:
-#define GET_COMM_ALIGN(n_desc) (((n_desc) >> 8) & 0x0f)
-#define SET_COMM_ALIGN(n_desc,align) \
-    (n_desc) = (((n_desc) & 0xf0ff) | (((align) & 0x0f) << 8))
- */
+
+/// Return the alignment of a common symbol as a power of 2.
+#[inline]
+pub fn get_comm_align(n_desc: u16) -> u16 {
+    (n_desc >> 8) & 0x0f
+}
+
+/// Return `n_desc` with the alignment of a common symbol set to the given power of 2.
+#[inline]
+pub fn set_comm_align(n_desc: u16, align: u16) -> u16 {
+    (n_desc & 0xf0ff) | ((align & 0x0f) << 8)
+}
 
 /*
  * To support the lazy binding of undefined symbols in the dynamic link-editor,
@@ -3246,7 +3507,14 @@ pub const X86_64_RELOC_SIGNED_4: u8 = 8;
 /// for thread local variables
 pub const X86_64_RELOC_TLV: u8 = 9;
 
-unsafe_impl_pod!(FatHeader, FatArch32, FatArch64,);
+unsafe_impl_pod!(
+    FatHeader,
+    FatArch32,
+    FatArch64,
+    CsSuperBlob,
+    CsBlobIndex,
+    CsGenericBlob,
+);
 unsafe_impl_endian_pod!(
     DyldCacheHeader,
     DyldCacheMappingInfo,
@@ -3299,8 +3567,14 @@ unsafe_impl_endian_pod!(
     EntryPointCommand,
     SourceVersionCommand,
     DataInCodeEntry,
+    DyldChainedFixupsHeader,
+    DyldChainedStartsInImage,
+    DyldChainedStartsInSegment,
     //TlvDescriptor,
     NoteCommand,
+    MainBinSpecPayload,
+    LoadBinaryPayload,
+    AddrableBitsPayload,
     Nlist32,
     Nlist64,
     Relocation,
@@ -0,0 +1,141 @@
+//! Functions for finding the names of Mach-O constants.
+
+use crate::common::{value_name, FlagName, FlagNames};
+use crate::macho::*;
+
+/// Return the name of a relocation type for the given CPU type.
+///
+/// For example, `reloc_type_name(CPU_TYPE_X86_64, 2)` returns
+/// `Some("X86_64_RELOC_BRANCH")`.
+pub fn reloc_type_name(cputype: u32, r_type: u8) -> Option<&'static str> {
+    let names = match cputype {
+        CPU_TYPE_X86 => NAMES_GENERIC_RELOC,
+        CPU_TYPE_X86_64 => NAMES_X86_64_RELOC,
+        CPU_TYPE_ARM => NAMES_ARM_RELOC,
+        CPU_TYPE_ARM64 | CPU_TYPE_ARM64_32 => NAMES_ARM64_RELOC,
+        CPU_TYPE_POWERPC | CPU_TYPE_POWERPC64 => NAMES_PPC_RELOC,
+        _ => return None,
+    };
+    value_name(r_type, names)
+}
+
+/// Return the names of the type and attributes in a section's `flags` field.
+pub fn section_flags_names(flags: u32) -> FlagNames {
+    FlagNames::new(flags.into(), NAMES_S_TYPE, NAMES_S_ATTR)
+}
+
+/// Return the names of the type and flags in a symbol's `n_type` field.
+///
+/// For debugging symbols, this is the name of the stab type.
+pub fn n_type_names(n_type: u8) -> FlagNames {
+    if n_type & N_STAB != 0 {
+        FlagNames::new(n_type.into(), NAMES_N_STAB, &[])
+    } else {
+        FlagNames::new(n_type.into(), NAMES_N_TYPE, NAMES_N_EXT)
+    }
+}
+
+/// Return the names of the reference type and flags in a symbol's `n_desc` field.
+///
+/// The reference type is only included for undefined symbols.
+/// The library ordinal of undefined symbols is not named.
+/// This should not be used for debugging symbols.
+pub fn n_desc_names(n_desc: u16, undefined: bool) -> FlagNames {
+    if undefined {
+        FlagNames::new(n_desc.into(), NAMES_REFERENCE, NAMES_N_DESC_UNDEFINED)
+    } else {
+        FlagNames::new(n_desc.into(), NAMES_N_DESC_DEFINED, &[])
+    }
+}
+
+const NAMES_GENERIC_RELOC: &[(u8, &str)] = &value_names!(
+    GENERIC_RELOC_VANILLA,
+    GENERIC_RELOC_PAIR,
+    GENERIC_RELOC_SECTDIFF,
+    GENERIC_RELOC_PB_LA_PTR,
+    GENERIC_RELOC_LOCAL_SECTDIFF,
+    GENERIC_RELOC_TLV
+);
+const NAMES_X86_64_RELOC: &[(u8, &str)] = &value_names!(
+    X86_64_RELOC_UNSIGNED,
+    X86_64_RELOC_SIGNED,
+    X86_64_RELOC_BRANCH,
+    X86_64_RELOC_GOT_LOAD,
+    X86_64_RELOC_GOT,
+    X86_64_RELOC_SUBTRACTOR,
+    X86_64_RELOC_SIGNED_1,
+    X86_64_RELOC_SIGNED_2,
+    X86_64_RELOC_SIGNED_4,
+    X86_64_RELOC_TLV
+);
+const NAMES_ARM_RELOC: &[(u8, &str)] = &value_names!(
+    ARM_RELOC_VANILLA,
+    ARM_RELOC_PAIR,
+    ARM_RELOC_SECTDIFF,
+    ARM_RELOC_LOCAL_SECTDIFF,
+    ARM_RELOC_PB_LA_PTR,
+    ARM_RELOC_BR24,
+    ARM_THUMB_RELOC_BR22,
+    ARM_THUMB_32BIT_BRANCH,
+    ARM_RELOC_HALF,
+    ARM_RELOC_HALF_SECTDIFF
+);
+const NAMES_ARM64_RELOC: &[(u8, &str)] = &value_names!(
+    ARM64_RELOC_UNSIGNED,
+    ARM64_RELOC_SUBTRACTOR,
+    ARM64_RELOC_BRANCH26,
+    ARM64_RELOC_PAGE21,
+    ARM64_RELOC_PAGEOFF12,
+    ARM64_RELOC_GOT_LOAD_PAGE21,
+    ARM64_RELOC_GOT_LOAD_PAGEOFF12,
+    ARM64_RELOC_POINTER_TO_GOT,
+    ARM64_RELOC_TLVP_LOAD_PAGE21,
+    ARM64_RELOC_TLVP_LOAD_PAGEOFF12,
+    ARM64_RELOC_ADDEND,
+    ARM64_RELOC_AUTHENTICATED_POINTER
+);
+const NAMES_PPC_RELOC: &[(u8, &str)] = &value_names!(
+    PPC_RELOC_VANILLA,
+    PPC_RELOC_PAIR,
+    PPC_RELOC_BR14,
+    PPC_RELOC_BR24,
+    PPC_RELOC_HI16,
+    PPC_RELOC_LO16,
+    PPC_RELOC_HA16,
+    PPC_RELOC_LO14,
+    PPC_RELOC_SECTDIFF,
+    PPC_RELOC_PB_LA_PTR,
+    PPC_RELOC_HI16_SECTDIFF,
+    PPC_RELOC_LO16_SECTDIFF,
+    PPC_RELOC_HA16_SECTDIFF,
+    PPC_RELOC_JBSR,
+    PPC_RELOC_LO14_SECTDIFF,
+    PPC_RELOC_LOCAL_SECTDIFF
+);
+const NAMES_S_TYPE: &[FlagName] = &masked_names!(SECTION_TYPE; S_REGULAR, S_ZEROFILL, S_CSTRING_LITERALS, S_4BYTE_LITERALS, S_8BYTE_LITERALS, S_LITERAL_POINTERS, S_NON_LAZY_SYMBOL_POINTERS, S_LAZY_SYMBOL_POINTERS, S_SYMBOL_STUBS, S_MOD_INIT_FUNC_POINTERS, S_MOD_TERM_FUNC_POINTERS, S_COALESCED, S_GB_ZEROFILL, S_INTERPOSING, S_16BYTE_LITERALS, S_DTRACE_DOF, S_LAZY_DYLIB_SYMBOL_POINTERS, S_THREAD_LOCAL_REGULAR, S_THREAD_LOCAL_ZEROFILL, S_THREAD_LOCAL_VARIABLES, S_THREAD_LOCAL_VARIABLE_POINTERS, S_THREAD_LOCAL_INIT_FUNCTION_POINTERS, S_INIT_FUNC_OFFSETS);
+const NAMES_S_ATTR: &[FlagName] = &flag_names!(
+    S_ATTR_PURE_INSTRUCTIONS,
+    S_ATTR_NO_TOC,
+    S_ATTR_STRIP_STATIC_SYMS,
+    S_ATTR_NO_DEAD_STRIP,
+    S_ATTR_LIVE_SUPPORT,
+    S_ATTR_SELF_MODIFYING_CODE,
+    S_ATTR_DEBUG,
+    S_ATTR_SOME_INSTRUCTIONS,
+    S_ATTR_EXT_RELOC,
+    S_ATTR_LOC_RELOC
+);
+const NAMES_N_STAB: &[FlagName] = &masked_names!(0xff; N_GSYM, N_FNAME, N_FUN, N_STSYM, N_LCSYM, N_BNSYM, N_AST, N_OPT, N_RSYM, N_SLINE, N_ENSYM, N_SSYM, N_SO, N_OSO, N_LSYM, N_BINCL, N_SOL, N_PARAMS, N_VERSION, N_OLEVEL, N_PSYM, N_EINCL, N_ENTRY, N_LBRAC, N_EXCL, N_RBRAC, N_BCOMM, N_ECOMM, N_ECOML, N_LENG, N_PC);
+const NAMES_N_TYPE: &[FlagName] = &masked_names!(N_TYPE; N_UNDF, N_ABS, N_SECT, N_PBUD, N_INDR);
+const NAMES_N_EXT: &[FlagName] = &flag_names!(N_PEXT, N_EXT);
+const NAMES_REFERENCE: &[FlagName] = &masked_names!(REFERENCE_TYPE; REFERENCE_FLAG_UNDEFINED_NON_LAZY, REFERENCE_FLAG_UNDEFINED_LAZY, REFERENCE_FLAG_DEFINED, REFERENCE_FLAG_PRIVATE_DEFINED, REFERENCE_FLAG_PRIVATE_UNDEFINED_NON_LAZY, REFERENCE_FLAG_PRIVATE_UNDEFINED_LAZY);
+const NAMES_N_DESC_DEFINED: &[FlagName] = &flag_names!(
+    REFERENCED_DYNAMICALLY,
+    N_NO_DEAD_STRIP,
+    N_WEAK_DEF,
+    N_ARM_THUMB_DEF,
+    N_SYMBOL_RESOLVER,
+    N_ALT_ENTRY,
+);
+const NAMES_N_DESC_UNDEFINED: &[FlagName] =
+    &flag_names!(REFERENCED_DYNAMICALLY, N_WEAK_REF, N_REF_TO_WEAK);
@@ -0,0 +1,194 @@
+//! OMF definitions.
+//!
+//! These definitions are independent of read/write support, although we do implement
+//! some traits useful for those.
+//!
+//! This module is based on the Intel "Relocatable Object Module Format (OMF)
+//! Specification" version 1.1, as used by 16-bit and 32-bit x86 toolchains.
+
+#![allow(missing_docs)]
+
+use crate::endian::{LittleEndian as LE, U16};
+use crate::pod::Pod;
+
+/// The header at the start of every OMF record.
+///
+/// The record contents follow the header, and the last byte of the contents
+/// is a checksum.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct RecordHeader {
+    /// The record type. One of the `THEADR`... constants.
+    ///
+    /// Odd record types are the 32-bit variants of the preceding even type.
+    pub record_type: u8,
+    /// The length of the record contents, including the checksum.
+    pub length: U16<LE>,
+}
+
+// Record types.
+
+/// Translator header.
+pub const THEADR: u8 = 0x80;
+/// Library module header.
+pub const LHEADR: u8 = 0x82;
+/// Comment.
+pub const COMENT: u8 = 0x88;
+/// Module end.
+pub const MODEND: u8 = 0x8a;
+/// Module end (32-bit).
+pub const MODEND32: u8 = 0x8b;
+/// External names definition.
+pub const EXTDEF: u8 = 0x8c;
+/// Public names definition.
+pub const PUBDEF: u8 = 0x90;
+/// Public names definition (32-bit).
+pub const PUBDEF32: u8 = 0x91;
+/// Line numbers.
+pub const LINNUM: u8 = 0x94;
+/// Line numbers (32-bit).
+pub const LINNUM32: u8 = 0x95;
+/// List of names.
+pub const LNAMES: u8 = 0x96;
+/// Segment definition.
+pub const SEGDEF: u8 = 0x98;
+/// Segment definition (32-bit).
+pub const SEGDEF32: u8 = 0x99;
+/// Group definition.
+pub const GRPDEF: u8 = 0x9a;
+/// Fixup.
+pub const FIXUPP: u8 = 0x9c;
+/// Fixup (32-bit).
+pub const FIXUPP32: u8 = 0x9d;
+/// Logical enumerated data.
+pub const LEDATA: u8 = 0xa0;
+/// Logical enumerated data (32-bit).
+pub const LEDATA32: u8 = 0xa1;
+/// Logical iterated data.
+pub const LIDATA: u8 = 0xa2;
+/// Logical iterated data (32-bit).
+pub const LIDATA32: u8 = 0xa3;
+/// Communal names definition.
+pub const COMDEF: u8 = 0xb0;
+/// Backpatch.
+pub const BAKPAT: u8 = 0xb2;
+/// Backpatch (32-bit).
+pub const BAKPAT32: u8 = 0xb3;
+/// Local external names definition.
+pub const LEXTDEF: u8 = 0xb4;
+/// Local public names definition.
+pub const LPUBDEF: u8 = 0xb6;
+/// Local public names definition (32-bit).
+pub const LPUBDEF32: u8 = 0xb7;
+/// Local communal names definition.
+pub const LCOMDEF: u8 = 0xb8;
+/// COMDAT external names definition.
+pub const CEXTDEF: u8 = 0xbc;
+/// Initialized communal data.
+pub const COMDAT: u8 = 0xc2;
+/// Initialized communal data (32-bit).
+pub const COMDAT32: u8 = 0xc3;
+/// Local logical names definition.
+pub const LLNAMES: u8 = 0xca;
+/// Library header.
+pub const LIBHDR: u8 = 0xf0;
+/// Library end.
+pub const LIBEND: u8 = 0xf1;
+
+// Values for the alignment field of the `SEGDEF` ACBP byte.
+
+/// Absolute segment.
+pub const SEG_ALIGN_ABSOLUTE: u8 = 0;
+/// Relocatable, byte aligned.
+pub const SEG_ALIGN_BYTE: u8 = 1;
+/// Relocatable, word (2 byte) aligned.
+pub const SEG_ALIGN_WORD: u8 = 2;
+/// Relocatable, paragraph (16 byte) aligned.
+pub const SEG_ALIGN_PARAGRAPH: u8 = 3;
+/// Relocatable, page (256 byte) aligned.
+pub const SEG_ALIGN_PAGE: u8 = 4;
+/// Relocatable, double word (4 byte) aligned.
+pub const SEG_ALIGN_DWORD: u8 = 5;
+/// Relocatable, 4K page aligned.
+pub const SEG_ALIGN_PAGE4K: u8 = 6;
+
+// Values for the combination field of the `SEGDEF` ACBP byte.
+
+/// Private segment that is not combined with other segments.
+pub const SEG_COMBINE_PRIVATE: u8 = 0;
+/// Public segment, concatenated with segments of the same name.
+pub const SEG_COMBINE_PUBLIC: u8 = 2;
+/// Public segment (alternate value).
+pub const SEG_COMBINE_PUBLIC2: u8 = 4;
+/// Stack segment.
+pub const SEG_COMBINE_STACK: u8 = 5;
+/// Common segment, overlaid with segments of the same name.
+pub const SEG_COMBINE_COMMON: u8 = 6;
+/// Public segment (alternate value).
+pub const SEG_COMBINE_PUBLIC3: u8 = 7;
+
+/// Bit in the `SEGDEF` ACBP byte for a segment of length 64K or 4G.
+pub const SEG_BIG: u8 = 0x02;
+/// Bit in the `SEGDEF` ACBP byte for a 32-bit (USE32) segment.
+pub const SEG_USE32: u8 = 0x01;
+
+/// Return the alignment field of a `SEGDEF` ACBP byte.
+#[inline]
+pub fn seg_alignment(acbp: u8) -> u8 {
+    acbp >> 5
+}
+
+/// Return the combination field of a `SEGDEF` ACBP byte.
+#[inline]
+pub fn seg_combination(acbp: u8) -> u8 {
+    (acbp >> 2) & 0x7
+}
+
+// Values for the location field of a `FIXUPP` subrecord.
+
+/// Low order byte (8-bit displacement or low byte of a 16-bit offset).
+pub const LOC_LOW_BYTE: u8 = 0;
+/// 16-bit offset.
+pub const LOC_OFFSET: u8 = 1;
+/// 16-bit base (segment or selector).
+pub const LOC_BASE: u8 = 2;
+/// 32-bit long pointer (16-bit base:16-bit offset).
+pub const LOC_POINTER: u8 = 3;
+/// High order byte of a 16-bit offset.
+pub const LOC_HIGH_BYTE: u8 = 4;
+/// 16-bit loader-resolved offset.
+pub const LOC_LOADER_OFFSET: u8 = 5;
+/// 32-bit offset.
+pub const LOC_OFFSET32: u8 = 9;
+/// 48-bit pointer (16-bit base:32-bit offset).
+pub const LOC_POINTER48: u8 = 11;
+/// 32-bit loader-resolved offset.
+pub const LOC_LOADER_OFFSET32: u8 = 13;
+
+// Values for the frame method of a `FIXUPP` subrecord.
+
+/// The frame is the segment given by a segment index.
+pub const FRAME_SEGMENT: u8 = 0;
+/// The frame is the group given by a group index.
+pub const FRAME_GROUP: u8 = 1;
+/// The frame is the segment or group of the external given by an external index.
+pub const FRAME_EXTERNAL: u8 = 2;
+/// The frame is the segment containing the location.
+pub const FRAME_LOCATION: u8 = 4;
+/// The frame is determined by the target.
+pub const FRAME_TARGET: u8 = 5;
+
+// Values for the target method of a `FIXUPP` subrecord.
+//
+// Methods 4 to 6 are the same as methods 0 to 2, but without a displacement.
+
+/// The target is an offset in the segment given by a segment index.
+pub const TARGET_SEGMENT: u8 = 0;
+/// The target is an offset in the group given by a group index.
+pub const TARGET_GROUP: u8 = 1;
+/// The target is an offset from the external given by an external index.
+pub const TARGET_EXTERNAL: u8 = 2;
+/// The target is an offset from an absolute frame number.
+pub const TARGET_FRAME: u8 = 3;
+
+unsafe_impl_pod!(RecordHeader);
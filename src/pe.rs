@@ -12,6 +12,9 @@ use core::convert::TryInto;
 use crate::endian::{I32Bytes, LittleEndian as LE, U16Bytes, U32Bytes, I32, U16, U32, U64};
 use crate::pod::Pod;
 
+mod names;
+pub use names::*;
+
 /// MZ
 pub const IMAGE_DOS_SIGNATURE: u16 = 0x5A4D;
 /// NE
@@ -20,11 +23,14 @@ pub const IMAGE_OS2_SIGNATURE: u16 = 0x454E;
 pub const IMAGE_OS2_SIGNATURE_LE: u16 = 0x454C;
 /// LE
 pub const IMAGE_VXD_SIGNATURE: u16 = 0x454C;
+/// LX
+pub const IMAGE_OS2_SIGNATURE_LX: u16 = 0x584C;
 /// PE00
 pub const IMAGE_NT_SIGNATURE: u32 = 0x0000_4550;
 
 /// DOS .EXE header
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct ImageDosHeader {
     /// Magic number
@@ -69,6 +75,7 @@ pub struct ImageDosHeader {
 
 /// OS/2 .EXE header
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct ImageOs2Header {
     /// Magic number
@@ -135,6 +142,7 @@ pub struct ImageOs2Header {
 
 /// Windows VXD header
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct ImageVxdHeader {
     /// Magic number
@@ -239,6 +247,114 @@ pub struct ImageVxdHeader {
     pub e32_ddkver: U16<LE>,
 }
 
+/// OS/2 .EXE segment table entry
+///
+/// This is from "newexe.h".
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct ImageOs2Segment {
+    /// File sector of start of segment, shifted by `ne_align`
+    pub ns_sector: U16<LE>,
+    /// Number of bytes in file, or 0 for 64K
+    pub ns_cbseg: U16<LE>,
+    /// Attribute flags
+    pub ns_flags: U16<LE>,
+    /// Minimum allocation in bytes, or 0 for 64K
+    pub ns_minalloc: U16<LE>,
+}
+
+// Values for `ImageOs2Segment::ns_flags`.
+/// Segment type mask
+pub const NSTYPE: u16 = 0x0007;
+/// Code segment
+pub const NSCODE: u16 = 0x0000;
+/// Data segment
+pub const NSDATA: u16 = 0x0001;
+/// Iterated segment flag
+pub const NSITER: u16 = 0x0008;
+/// Movable segment flag
+pub const NSMOVE: u16 = 0x0010;
+/// Shared segment flag
+pub const NSSHARED: u16 = 0x0020;
+/// Preload segment flag
+pub const NSPRELOAD: u16 = 0x0040;
+/// Execute-only (code segment), or read-only (data segment)
+pub const NSEXRD: u16 = 0x0080;
+/// Segment has relocations
+pub const NSRELOC: u16 = 0x0100;
+/// Segment is discardable
+pub const NSDISCARD: u16 = 0x1000;
+
+// Values for the type of an OS/2 .EXE entry table bundle.
+/// Unused entries
+pub const ENT_UNUSED: u8 = 0x00;
+/// Constant entries
+pub const ENT_ABSSEG: u8 = 0xfe;
+/// Movable segment entries
+pub const ENT_MOVEABLE: u8 = 0xff;
+
+/// Linear .EXE object table entry
+///
+/// This is from "exe386.h".
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct ImageLxObject {
+    /// Object virtual size
+    pub o32_size: U32<LE>,
+    /// Object base virtual address
+    pub o32_base: U32<LE>,
+    /// Attribute flags
+    pub o32_flags: U32<LE>,
+    /// Object page map index (1-based)
+    pub o32_pagemap: U32<LE>,
+    /// Number of entries in object page map
+    pub o32_mapsize: U32<LE>,
+    /// Reserved
+    pub o32_reserved: U32<LE>,
+}
+
+// Values for `ImageLxObject::o32_flags`.
+/// Readable object
+pub const OBJREAD: u32 = 0x0000_0001;
+/// Writable object
+pub const OBJWRITE: u32 = 0x0000_0002;
+/// Executable object
+pub const OBJEXEC: u32 = 0x0000_0004;
+/// Resource object
+pub const OBJRSRC: u32 = 0x0000_0008;
+/// Discardable object
+pub const OBJDISCARD: u32 = 0x0000_0010;
+/// Shared object
+pub const OBJSHARED: u32 = 0x0000_0020;
+/// Object has preload pages
+pub const OBJPRELOAD: u32 = 0x0000_0040;
+/// Object has invalid pages
+pub const OBJINVALID: u32 = 0x0000_0080;
+/// Object has zero-filled pages
+pub const OBJZEROFIL: u32 = 0x0000_0100;
+/// Object requires 16:16 alias
+pub const OBJALIAS16: u32 = 0x0000_1000;
+/// Big/default bit setting
+pub const OBJBIGDEF: u32 = 0x0000_2000;
+/// Object is conforming for code
+pub const OBJCONFORM: u32 = 0x0000_4000;
+/// Object I/O privilege level
+pub const OBJIOPL: u32 = 0x0000_8000;
+
+// Values for the type of a linear .EXE entry table bundle.
+/// Empty bundle
+pub const EMPTY: u8 = 0x00;
+/// 16-bit offset entries
+pub const ENTRY16: u8 = 0x01;
+/// 286 call gate entries
+pub const GATE16: u8 = 0x02;
+/// 32-bit offset entries
+pub const ENTRY32: u8 = 0x03;
+/// Forwarder entries
+pub const ENTRYFWD: u8 = 0x04;
+/// Bundle has parameter typing information
+pub const TYPEINFO: u8 = 0x80;
+
 /// A PE rich header entry.
 ///
 /// Rich headers have no official documentation, but have been heavily
@@ -259,6 +375,7 @@ pub struct MaskedRichHeaderEntry {
 //
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct ImageFileHeader {
     pub machine: U16<LE>,
@@ -372,6 +489,7 @@ pub const IMAGE_FILE_MACHINE_RISCV128: u16 = 0x5128;
 //
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct ImageDataDirectory {
     pub virtual_address: U32<LE>,
@@ -385,6 +503,7 @@ pub const IMAGE_NUMBEROF_DIRECTORY_ENTRIES: usize = 16;
 //
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct ImageOptionalHeader32 {
     // Standard fields.
@@ -442,6 +561,7 @@ pub struct ImageRomOptionalHeader {
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct ImageOptionalHeader64 {
     pub magic: U16<LE>,
@@ -481,6 +601,7 @@ pub const IMAGE_NT_OPTIONAL_HDR64_MAGIC: u16 = 0x20b;
 pub const IMAGE_ROM_OPTIONAL_HDR_MAGIC: u16 = 0x107;
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct ImageNtHeaders64 {
     pub signature: U32<LE>,
@@ -489,6 +610,7 @@ pub struct ImageNtHeaders64 {
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct ImageNtHeaders32 {
     pub signature: U32<LE>,
@@ -703,6 +825,7 @@ pub const IMAGE_SIZEOF_SHORT_NAME: usize = 8;
 //
 
 #[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct ImageSectionHeader {
     pub name: [u8; IMAGE_SIZEOF_SHORT_NAME],
@@ -2135,6 +2258,95 @@ pub const RT_HTML: u16 = 23;
 /// ID for: Side-by-Side Assembly Manifest.
 pub const RT_MANIFEST: u16 = 24;
 
+//
+// Version information resource.
+//
+
+/// The resource name of the `VS_VERSIONINFO` resource.
+pub const VS_VERSION_INFO: u16 = 1;
+
+/// The value of the `VS_VERSIONINFO` resource.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct VsFixedFileInfo {
+    /// `VS_FFI_SIGNATURE`
+    pub signature: U32<LE>,
+    /// `VS_FFI_STRUCVERSION`
+    pub struc_version: U32<LE>,
+    pub file_version_ms: U32<LE>,
+    pub file_version_ls: U32<LE>,
+    pub product_version_ms: U32<LE>,
+    pub product_version_ls: U32<LE>,
+    /// The bits that are valid in `file_flags`.
+    pub file_flags_mask: U32<LE>,
+    /// `VS_FF_*`
+    pub file_flags: U32<LE>,
+    /// `VOS_*`
+    pub file_os: U32<LE>,
+    /// `VFT_*`
+    pub file_type: U32<LE>,
+    /// `VFT2_*`
+    pub file_subtype: U32<LE>,
+    pub file_date_ms: U32<LE>,
+    pub file_date_ls: U32<LE>,
+}
+
+pub const VS_FFI_SIGNATURE: u32 = 0xfeef_04bd;
+pub const VS_FFI_STRUCVERSION: u32 = 0x0001_0000;
+pub const VS_FFI_FILEFLAGSMASK: u32 = 0x0000_003f;
+
+// Values for `VsFixedFileInfo::file_flags`.
+pub const VS_FF_DEBUG: u32 = 0x0000_0001;
+pub const VS_FF_PRERELEASE: u32 = 0x0000_0002;
+pub const VS_FF_PATCHED: u32 = 0x0000_0004;
+pub const VS_FF_PRIVATEBUILD: u32 = 0x0000_0008;
+pub const VS_FF_INFOINFERRED: u32 = 0x0000_0010;
+pub const VS_FF_SPECIALBUILD: u32 = 0x0000_0020;
+
+// Values for `VsFixedFileInfo::file_os`.
+pub const VOS_UNKNOWN: u32 = 0x0000_0000;
+pub const VOS_DOS: u32 = 0x0001_0000;
+pub const VOS_OS216: u32 = 0x0002_0000;
+pub const VOS_OS232: u32 = 0x0003_0000;
+pub const VOS_NT: u32 = 0x0004_0000;
+pub const VOS__WINDOWS16: u32 = 0x0000_0001;
+pub const VOS__PM16: u32 = 0x0000_0002;
+pub const VOS__PM32: u32 = 0x0000_0003;
+pub const VOS__WINDOWS32: u32 = 0x0000_0004;
+pub const VOS_DOS_WINDOWS16: u32 = 0x0001_0001;
+pub const VOS_DOS_WINDOWS32: u32 = 0x0001_0004;
+pub const VOS_OS216_PM16: u32 = 0x0002_0002;
+pub const VOS_OS232_PM32: u32 = 0x0003_0003;
+pub const VOS_NT_WINDOWS32: u32 = 0x0004_0004;
+
+// Values for `VsFixedFileInfo::file_type`.
+pub const VFT_UNKNOWN: u32 = 0x0000_0000;
+pub const VFT_APP: u32 = 0x0000_0001;
+pub const VFT_DLL: u32 = 0x0000_0002;
+pub const VFT_DRV: u32 = 0x0000_0003;
+pub const VFT_FONT: u32 = 0x0000_0004;
+pub const VFT_VXD: u32 = 0x0000_0005;
+pub const VFT_STATIC_LIB: u32 = 0x0000_0007;
+
+// Values for `VsFixedFileInfo::file_subtype` when `file_type` is `VFT_DRV`.
+pub const VFT2_UNKNOWN: u32 = 0x0000_0000;
+pub const VFT2_DRV_PRINTER: u32 = 0x0000_0001;
+pub const VFT2_DRV_KEYBOARD: u32 = 0x0000_0002;
+pub const VFT2_DRV_LANGUAGE: u32 = 0x0000_0003;
+pub const VFT2_DRV_DISPLAY: u32 = 0x0000_0004;
+pub const VFT2_DRV_MOUSE: u32 = 0x0000_0005;
+pub const VFT2_DRV_NETWORK: u32 = 0x0000_0006;
+pub const VFT2_DRV_SYSTEM: u32 = 0x0000_0007;
+pub const VFT2_DRV_INSTALLABLE: u32 = 0x0000_0008;
+pub const VFT2_DRV_SOUND: u32 = 0x0000_0009;
+pub const VFT2_DRV_COMM: u32 = 0x0000_000a;
+pub const VFT2_DRV_VERSIONED_PRINTER: u32 = 0x0000_000c;
+
+// Values for `VsFixedFileInfo::file_subtype` when `file_type` is `VFT_FONT`.
+pub const VFT2_FONT_RASTER: u32 = 0x0000_0001;
+pub const VFT2_FONT_VECTOR: u32 = 0x0000_0002;
+pub const VFT2_FONT_TRUETYPE: u32 = 0x0000_0003;
+
 //
 // Code Integrity in loadconfig (CI)
 //
@@ -2332,6 +2544,19 @@ pub struct ImageLoadConfigDirectory32 {
     pub enclave_configuration_pointer: U32<LE>,
     /// VA
     pub volatile_metadata_pointer: U32<LE>,
+    /// VA
+    pub guard_eh_continuation_table: U32<LE>,
+    pub guard_eh_continuation_count: U32<LE>,
+    /// VA
+    pub guard_xfg_check_function_pointer: U32<LE>,
+    /// VA
+    pub guard_xfg_dispatch_function_pointer: U32<LE>,
+    /// VA
+    pub guard_xfg_table_dispatch_function_pointer: U32<LE>,
+    /// VA
+    pub cast_guard_os_determined_failure_mode: U32<LE>,
+    /// VA
+    pub guard_memcpy_function_pointer: U32<LE>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -2395,6 +2620,19 @@ pub struct ImageLoadConfigDirectory64 {
     pub enclave_configuration_pointer: U64<LE>,
     /// VA
     pub volatile_metadata_pointer: U64<LE>,
+    /// VA
+    pub guard_eh_continuation_table: U64<LE>,
+    pub guard_eh_continuation_count: U64<LE>,
+    /// VA
+    pub guard_xfg_check_function_pointer: U64<LE>,
+    /// VA
+    pub guard_xfg_dispatch_function_pointer: U64<LE>,
+    /// VA
+    pub guard_xfg_table_dispatch_function_pointer: U64<LE>,
+    /// VA
+    pub cast_guard_os_determined_failure_mode: U64<LE>,
+    /// VA
+    pub guard_memcpy_function_pointer: U64<LE>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -2480,6 +2718,14 @@ pub const IMAGE_GUARD_RF_ENABLE: u32 = 0x0004_0000;
 pub const IMAGE_GUARD_RF_STRICT: u32 = 0x0008_0000;
 /// Module was built with retpoline support
 pub const IMAGE_GUARD_RETPOLINE_PRESENT: u32 = 0x0010_0000;
+/// Module contains EH continuation target information
+pub const IMAGE_GUARD_EH_CONTINUATION_TABLE_PRESENT: u32 = 0x0040_0000;
+/// Module was built with xfg
+pub const IMAGE_GUARD_XFG_ENABLED: u32 = 0x0080_0000;
+/// Module has CastGuard instrumentation present
+pub const IMAGE_GUARD_CASTGUARD_PRESENT: u32 = 0x0100_0000;
+/// Module has Guarded Memcpy instrumentation present
+pub const IMAGE_GUARD_MEMCPY_PRESENT: u32 = 0x0200_0000;
 
 /// Stride of Guard CF function table encoded in these bits (additional count of bytes per element)
 pub const IMAGE_GUARD_CF_FUNCTION_TABLE_SIZE_MASK: u32 = 0xF000_0000;
@@ -2559,6 +2805,19 @@ pub struct ImageRuntimeFunctionEntry {
     pub unwind_info_address_or_data: U32<LE>,
 }
 
+/// Bit in `ImageRuntimeFunctionEntry::unwind_info_address_or_data` indicating
+/// that the address is for another function entry instead of unwind info.
+pub const RUNTIME_FUNCTION_INDIRECT: u32 = 0x1;
+
+//
+// x64 unwind info flags
+//
+
+pub const UNW_FLAG_NHANDLER: u8 = 0x0;
+pub const UNW_FLAG_EHANDLER: u8 = 0x1;
+pub const UNW_FLAG_UHANDLER: u8 = 0x2;
+pub const UNW_FLAG_CHAININFO: u8 = 0x4;
+
 //
 // Software enclave information
 //
@@ -2967,10 +3226,41 @@ pub struct ImageCor20Header {
     pub managed_native_header: ImageDataDirectory,
 }
 
+//
+// Attribute certificate table
+//
+
+/// An entry in the attribute certificate table.
+///
+/// This is followed by the certificate data. Entries are aligned to 8 bytes.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct WinCertificate {
+    /// The length of the entry, including this header.
+    pub length: U32<LE>,
+    /// One of the `WIN_CERT_REVISION_*` constants.
+    pub revision: U16<LE>,
+    /// One of the `WIN_CERT_TYPE_*` constants.
+    pub certificate_type: U16<LE>,
+}
+
+pub const WIN_CERT_REVISION_1_0: u16 = 0x0100;
+pub const WIN_CERT_REVISION_2_0: u16 = 0x0200;
+
+/// An X.509 certificate.
+pub const WIN_CERT_TYPE_X509: u16 = 0x0001;
+/// A PKCS#7 `SignedData` structure, such as an Authenticode signature.
+pub const WIN_CERT_TYPE_PKCS_SIGNED_DATA: u16 = 0x0002;
+pub const WIN_CERT_TYPE_RESERVED_1: u16 = 0x0003;
+/// Terminal server protocol stack certificate signing.
+pub const WIN_CERT_TYPE_TS_STACK_SIGNED: u16 = 0x0004;
+
 unsafe_impl_pod!(
     ImageDosHeader,
     ImageOs2Header,
     ImageVxdHeader,
+    ImageOs2Segment,
+    ImageLxObject,
     ImageFileHeader,
     ImageDataDirectory,
     ImageOptionalHeader32,
@@ -3013,6 +3303,7 @@ unsafe_impl_pod!(
     ImageResourceDirectoryString,
     ImageResourceDirStringU,
     ImageResourceDataEntry,
+    VsFixedFileInfo,
     ImageLoadConfigCodeIntegrity,
     ImageDynamicRelocationTable,
     ImageDynamicRelocation32,
@@ -3050,5 +3341,6 @@ unsafe_impl_pod!(
     ImageArchitectureEntry,
     ImportObjectHeader,
     ImageCor20Header,
+    WinCertificate,
     MaskedRichHeaderEntry,
 );
@@ -0,0 +1,394 @@
+//! Functions for finding the names of PE/COFF constants.
+
+use crate::common::{value_name, FlagName, FlagNames};
+use crate::pe::*;
+
+/// Return the name of a relocation type for the given machine.
+///
+/// For example, `relocation_type_name(IMAGE_FILE_MACHINE_AMD64, 4)` returns
+/// `Some("IMAGE_REL_AMD64_REL32")`.
+pub fn relocation_type_name(machine: u16, typ: u16) -> Option<&'static str> {
+    let names = match machine {
+        IMAGE_FILE_MACHINE_I386 => NAMES_IMAGE_REL_I386,
+        IMAGE_FILE_MACHINE_MIPS16 | IMAGE_FILE_MACHINE_MIPSFPU | IMAGE_FILE_MACHINE_MIPSFPU16 => {
+            NAMES_IMAGE_REL_MIPS
+        }
+        IMAGE_FILE_MACHINE_ALPHA | IMAGE_FILE_MACHINE_ALPHA64 => NAMES_IMAGE_REL_ALPHA,
+        IMAGE_FILE_MACHINE_POWERPC | IMAGE_FILE_MACHINE_POWERPCFP => NAMES_IMAGE_REL_PPC,
+        IMAGE_FILE_MACHINE_SH3
+        | IMAGE_FILE_MACHINE_SH3DSP
+        | IMAGE_FILE_MACHINE_SH3E
+        | IMAGE_FILE_MACHINE_SH4
+        | IMAGE_FILE_MACHINE_SH5 => NAMES_IMAGE_REL_SH,
+        IMAGE_FILE_MACHINE_ARM | IMAGE_FILE_MACHINE_ARMNT => NAMES_IMAGE_REL_ARM,
+        IMAGE_FILE_MACHINE_AM33 => NAMES_IMAGE_REL_AM,
+        IMAGE_FILE_MACHINE_ARM64 => NAMES_IMAGE_REL_ARM64,
+        IMAGE_FILE_MACHINE_AMD64 => NAMES_IMAGE_REL_AMD64,
+        IMAGE_FILE_MACHINE_IA64 => NAMES_IMAGE_REL_IA64,
+        IMAGE_FILE_MACHINE_CEF => NAMES_IMAGE_REL_CEF,
+        IMAGE_FILE_MACHINE_CEE => NAMES_IMAGE_REL_CEE,
+        IMAGE_FILE_MACHINE_M32R => NAMES_IMAGE_REL_M32R,
+        IMAGE_FILE_MACHINE_EBC => NAMES_IMAGE_REL_EBC,
+        _ => return None,
+    };
+    value_name(typ, names)
+}
+
+/// Return the names of the flags in a section header's `characteristics` field.
+///
+/// This includes the `IMAGE_SCN_ALIGN_*` value.
+pub fn section_characteristics_names(characteristics: u32) -> FlagNames {
+    FlagNames::new(
+        characteristics.into(),
+        NAMES_IMAGE_SCN,
+        NAMES_IMAGE_SCN_ALIGN,
+    )
+}
+
+/// Return the name of a symbol's storage class.
+pub fn storage_class_name(storage_class: u8) -> Option<&'static str> {
+    value_name(storage_class, NAMES_IMAGE_SYM_CLASS)
+}
+
+const NAMES_IMAGE_REL_I386: &[(u16, &str)] = &value_names!(
+    IMAGE_REL_I386_ABSOLUTE,
+    IMAGE_REL_I386_DIR16,
+    IMAGE_REL_I386_REL16,
+    IMAGE_REL_I386_DIR32,
+    IMAGE_REL_I386_DIR32NB,
+    IMAGE_REL_I386_SEG12,
+    IMAGE_REL_I386_SECTION,
+    IMAGE_REL_I386_SECREL,
+    IMAGE_REL_I386_TOKEN,
+    IMAGE_REL_I386_SECREL7,
+    IMAGE_REL_I386_REL32
+);
+const NAMES_IMAGE_REL_MIPS: &[(u16, &str)] = &value_names!(
+    IMAGE_REL_MIPS_ABSOLUTE,
+    IMAGE_REL_MIPS_REFHALF,
+    IMAGE_REL_MIPS_REFWORD,
+    IMAGE_REL_MIPS_JMPADDR,
+    IMAGE_REL_MIPS_REFHI,
+    IMAGE_REL_MIPS_REFLO,
+    IMAGE_REL_MIPS_GPREL,
+    IMAGE_REL_MIPS_LITERAL,
+    IMAGE_REL_MIPS_SECTION,
+    IMAGE_REL_MIPS_SECREL,
+    IMAGE_REL_MIPS_SECRELLO,
+    IMAGE_REL_MIPS_SECRELHI,
+    IMAGE_REL_MIPS_TOKEN,
+    IMAGE_REL_MIPS_JMPADDR16,
+    IMAGE_REL_MIPS_REFWORDNB,
+    IMAGE_REL_MIPS_PAIR
+);
+const NAMES_IMAGE_REL_ALPHA: &[(u16, &str)] = &value_names!(
+    IMAGE_REL_ALPHA_ABSOLUTE,
+    IMAGE_REL_ALPHA_REFLONG,
+    IMAGE_REL_ALPHA_REFQUAD,
+    IMAGE_REL_ALPHA_GPREL32,
+    IMAGE_REL_ALPHA_LITERAL,
+    IMAGE_REL_ALPHA_LITUSE,
+    IMAGE_REL_ALPHA_GPDISP,
+    IMAGE_REL_ALPHA_BRADDR,
+    IMAGE_REL_ALPHA_HINT,
+    IMAGE_REL_ALPHA_INLINE_REFLONG,
+    IMAGE_REL_ALPHA_REFHI,
+    IMAGE_REL_ALPHA_REFLO,
+    IMAGE_REL_ALPHA_PAIR,
+    IMAGE_REL_ALPHA_MATCH,
+    IMAGE_REL_ALPHA_SECTION,
+    IMAGE_REL_ALPHA_SECREL,
+    IMAGE_REL_ALPHA_REFLONGNB,
+    IMAGE_REL_ALPHA_SECRELLO,
+    IMAGE_REL_ALPHA_SECRELHI,
+    IMAGE_REL_ALPHA_REFQ3,
+    IMAGE_REL_ALPHA_REFQ2,
+    IMAGE_REL_ALPHA_REFQ1,
+    IMAGE_REL_ALPHA_GPRELLO,
+    IMAGE_REL_ALPHA_GPRELHI
+);
+const NAMES_IMAGE_REL_PPC: &[(u16, &str)] = &value_names!(
+    IMAGE_REL_PPC_ABSOLUTE,
+    IMAGE_REL_PPC_ADDR64,
+    IMAGE_REL_PPC_ADDR32,
+    IMAGE_REL_PPC_ADDR24,
+    IMAGE_REL_PPC_ADDR16,
+    IMAGE_REL_PPC_ADDR14,
+    IMAGE_REL_PPC_REL24,
+    IMAGE_REL_PPC_REL14,
+    IMAGE_REL_PPC_TOCREL16,
+    IMAGE_REL_PPC_TOCREL14,
+    IMAGE_REL_PPC_ADDR32NB,
+    IMAGE_REL_PPC_SECREL,
+    IMAGE_REL_PPC_SECTION,
+    IMAGE_REL_PPC_IFGLUE,
+    IMAGE_REL_PPC_IMGLUE,
+    IMAGE_REL_PPC_SECREL16,
+    IMAGE_REL_PPC_REFHI,
+    IMAGE_REL_PPC_REFLO,
+    IMAGE_REL_PPC_PAIR,
+    IMAGE_REL_PPC_SECRELLO,
+    IMAGE_REL_PPC_SECRELHI,
+    IMAGE_REL_PPC_GPREL,
+    IMAGE_REL_PPC_TOKEN
+);
+const NAMES_IMAGE_REL_SH: &[(u16, &str)] = &value_names!(
+    IMAGE_REL_SH3_ABSOLUTE,
+    IMAGE_REL_SH3_DIRECT16,
+    IMAGE_REL_SH3_DIRECT32,
+    IMAGE_REL_SH3_DIRECT8,
+    IMAGE_REL_SH3_DIRECT8_WORD,
+    IMAGE_REL_SH3_DIRECT8_LONG,
+    IMAGE_REL_SH3_DIRECT4,
+    IMAGE_REL_SH3_DIRECT4_WORD,
+    IMAGE_REL_SH3_DIRECT4_LONG,
+    IMAGE_REL_SH3_PCREL8_WORD,
+    IMAGE_REL_SH3_PCREL8_LONG,
+    IMAGE_REL_SH3_PCREL12_WORD,
+    IMAGE_REL_SH3_STARTOF_SECTION,
+    IMAGE_REL_SH3_SIZEOF_SECTION,
+    IMAGE_REL_SH3_SECTION,
+    IMAGE_REL_SH3_SECREL,
+    IMAGE_REL_SH3_DIRECT32_NB,
+    IMAGE_REL_SH3_GPREL4_LONG,
+    IMAGE_REL_SH3_TOKEN,
+    IMAGE_REL_SHM_PCRELPT,
+    IMAGE_REL_SHM_REFLO,
+    IMAGE_REL_SHM_REFHALF,
+    IMAGE_REL_SHM_RELLO,
+    IMAGE_REL_SHM_RELHALF,
+    IMAGE_REL_SHM_PAIR
+);
+const NAMES_IMAGE_REL_ARM: &[(u16, &str)] = &value_names!(
+    IMAGE_REL_ARM_ABSOLUTE,
+    IMAGE_REL_ARM_ADDR32,
+    IMAGE_REL_ARM_ADDR32NB,
+    IMAGE_REL_ARM_BRANCH24,
+    IMAGE_REL_ARM_BRANCH11,
+    IMAGE_REL_ARM_TOKEN,
+    IMAGE_REL_ARM_GPREL12,
+    IMAGE_REL_ARM_GPREL7,
+    IMAGE_REL_ARM_BLX24,
+    IMAGE_REL_ARM_BLX11,
+    IMAGE_REL_ARM_SECTION,
+    IMAGE_REL_ARM_SECREL,
+    IMAGE_REL_ARM_MOV32A,
+    IMAGE_REL_ARM_MOV32T,
+    IMAGE_REL_ARM_BRANCH20T,
+    IMAGE_REL_ARM_BRANCH24T,
+    IMAGE_REL_ARM_BLX23T
+);
+const NAMES_IMAGE_REL_AM: &[(u16, &str)] = &value_names!(
+    IMAGE_REL_AM_ABSOLUTE,
+    IMAGE_REL_AM_ADDR32,
+    IMAGE_REL_AM_ADDR32NB,
+    IMAGE_REL_AM_CALL32,
+    IMAGE_REL_AM_FUNCINFO,
+    IMAGE_REL_AM_REL32_1,
+    IMAGE_REL_AM_REL32_2,
+    IMAGE_REL_AM_SECREL,
+    IMAGE_REL_AM_SECTION,
+    IMAGE_REL_AM_TOKEN
+);
+const NAMES_IMAGE_REL_ARM64: &[(u16, &str)] = &value_names!(
+    IMAGE_REL_ARM64_ABSOLUTE,
+    IMAGE_REL_ARM64_ADDR32,
+    IMAGE_REL_ARM64_ADDR32NB,
+    IMAGE_REL_ARM64_BRANCH26,
+    IMAGE_REL_ARM64_PAGEBASE_REL21,
+    IMAGE_REL_ARM64_REL21,
+    IMAGE_REL_ARM64_PAGEOFFSET_12A,
+    IMAGE_REL_ARM64_PAGEOFFSET_12L,
+    IMAGE_REL_ARM64_SECREL,
+    IMAGE_REL_ARM64_SECREL_LOW12A,
+    IMAGE_REL_ARM64_SECREL_HIGH12A,
+    IMAGE_REL_ARM64_SECREL_LOW12L,
+    IMAGE_REL_ARM64_TOKEN,
+    IMAGE_REL_ARM64_SECTION,
+    IMAGE_REL_ARM64_ADDR64,
+    IMAGE_REL_ARM64_BRANCH19
+);
+const NAMES_IMAGE_REL_AMD64: &[(u16, &str)] = &value_names!(
+    IMAGE_REL_AMD64_ABSOLUTE,
+    IMAGE_REL_AMD64_ADDR64,
+    IMAGE_REL_AMD64_ADDR32,
+    IMAGE_REL_AMD64_ADDR32NB,
+    IMAGE_REL_AMD64_REL32,
+    IMAGE_REL_AMD64_REL32_1,
+    IMAGE_REL_AMD64_REL32_2,
+    IMAGE_REL_AMD64_REL32_3,
+    IMAGE_REL_AMD64_REL32_4,
+    IMAGE_REL_AMD64_REL32_5,
+    IMAGE_REL_AMD64_SECTION,
+    IMAGE_REL_AMD64_SECREL,
+    IMAGE_REL_AMD64_SECREL7,
+    IMAGE_REL_AMD64_TOKEN,
+    IMAGE_REL_AMD64_SREL32,
+    IMAGE_REL_AMD64_PAIR,
+    IMAGE_REL_AMD64_SSPAN32,
+    IMAGE_REL_AMD64_EHANDLER,
+    IMAGE_REL_AMD64_IMPORT_BR,
+    IMAGE_REL_AMD64_IMPORT_CALL,
+    IMAGE_REL_AMD64_CFG_BR,
+    IMAGE_REL_AMD64_CFG_BR_REX,
+    IMAGE_REL_AMD64_CFG_CALL,
+    IMAGE_REL_AMD64_INDIR_BR,
+    IMAGE_REL_AMD64_INDIR_BR_REX,
+    IMAGE_REL_AMD64_INDIR_CALL,
+    IMAGE_REL_AMD64_INDIR_BR_SWITCHTABLE_FIRST,
+    IMAGE_REL_AMD64_INDIR_BR_SWITCHTABLE_LAST
+);
+const NAMES_IMAGE_REL_IA64: &[(u16, &str)] = &value_names!(
+    IMAGE_REL_IA64_ABSOLUTE,
+    IMAGE_REL_IA64_IMM14,
+    IMAGE_REL_IA64_IMM22,
+    IMAGE_REL_IA64_IMM64,
+    IMAGE_REL_IA64_DIR32,
+    IMAGE_REL_IA64_DIR64,
+    IMAGE_REL_IA64_PCREL21B,
+    IMAGE_REL_IA64_PCREL21M,
+    IMAGE_REL_IA64_PCREL21F,
+    IMAGE_REL_IA64_GPREL22,
+    IMAGE_REL_IA64_LTOFF22,
+    IMAGE_REL_IA64_SECTION,
+    IMAGE_REL_IA64_SECREL22,
+    IMAGE_REL_IA64_SECREL64I,
+    IMAGE_REL_IA64_SECREL32,
+    IMAGE_REL_IA64_DIR32NB,
+    IMAGE_REL_IA64_SREL14,
+    IMAGE_REL_IA64_SREL22,
+    IMAGE_REL_IA64_SREL32,
+    IMAGE_REL_IA64_UREL32,
+    IMAGE_REL_IA64_PCREL60X,
+    IMAGE_REL_IA64_PCREL60B,
+    IMAGE_REL_IA64_PCREL60F,
+    IMAGE_REL_IA64_PCREL60I,
+    IMAGE_REL_IA64_PCREL60M,
+    IMAGE_REL_IA64_IMMGPREL64,
+    IMAGE_REL_IA64_TOKEN,
+    IMAGE_REL_IA64_GPREL32,
+    IMAGE_REL_IA64_ADDEND
+);
+const NAMES_IMAGE_REL_CEF: &[(u16, &str)] = &value_names!(
+    IMAGE_REL_CEF_ABSOLUTE,
+    IMAGE_REL_CEF_ADDR32,
+    IMAGE_REL_CEF_ADDR64,
+    IMAGE_REL_CEF_ADDR32NB,
+    IMAGE_REL_CEF_SECTION,
+    IMAGE_REL_CEF_SECREL,
+    IMAGE_REL_CEF_TOKEN
+);
+const NAMES_IMAGE_REL_CEE: &[(u16, &str)] = &value_names!(
+    IMAGE_REL_CEE_ABSOLUTE,
+    IMAGE_REL_CEE_ADDR32,
+    IMAGE_REL_CEE_ADDR64,
+    IMAGE_REL_CEE_ADDR32NB,
+    IMAGE_REL_CEE_SECTION,
+    IMAGE_REL_CEE_SECREL,
+    IMAGE_REL_CEE_TOKEN
+);
+const NAMES_IMAGE_REL_M32R: &[(u16, &str)] = &value_names!(
+    IMAGE_REL_M32R_ABSOLUTE,
+    IMAGE_REL_M32R_ADDR32,
+    IMAGE_REL_M32R_ADDR32NB,
+    IMAGE_REL_M32R_ADDR24,
+    IMAGE_REL_M32R_GPREL16,
+    IMAGE_REL_M32R_PCREL24,
+    IMAGE_REL_M32R_PCREL16,
+    IMAGE_REL_M32R_PCREL8,
+    IMAGE_REL_M32R_REFHALF,
+    IMAGE_REL_M32R_REFHI,
+    IMAGE_REL_M32R_REFLO,
+    IMAGE_REL_M32R_PAIR,
+    IMAGE_REL_M32R_SECTION,
+    IMAGE_REL_M32R_SECREL32,
+    IMAGE_REL_M32R_TOKEN
+);
+const NAMES_IMAGE_REL_EBC: &[(u16, &str)] = &value_names!(
+    IMAGE_REL_EBC_ABSOLUTE,
+    IMAGE_REL_EBC_ADDR32NB,
+    IMAGE_REL_EBC_REL32,
+    IMAGE_REL_EBC_SECTION,
+    IMAGE_REL_EBC_SECREL
+);
+const NAMES_IMAGE_SCN: &[FlagName] = &flag_names!(
+    IMAGE_SCN_TYPE_NO_PAD,
+    IMAGE_SCN_CNT_CODE,
+    IMAGE_SCN_CNT_INITIALIZED_DATA,
+    IMAGE_SCN_CNT_UNINITIALIZED_DATA,
+    IMAGE_SCN_LNK_OTHER,
+    IMAGE_SCN_LNK_INFO,
+    IMAGE_SCN_LNK_REMOVE,
+    IMAGE_SCN_LNK_COMDAT,
+    IMAGE_SCN_NO_DEFER_SPEC_EXC,
+    IMAGE_SCN_GPREL,
+    IMAGE_SCN_MEM_FARDATA,
+    IMAGE_SCN_MEM_PURGEABLE,
+    IMAGE_SCN_MEM_LOCKED,
+    IMAGE_SCN_MEM_PRELOAD,
+    IMAGE_SCN_LNK_NRELOC_OVFL,
+    IMAGE_SCN_MEM_DISCARDABLE,
+    IMAGE_SCN_MEM_NOT_CACHED,
+    IMAGE_SCN_MEM_NOT_PAGED,
+    IMAGE_SCN_MEM_SHARED,
+    IMAGE_SCN_MEM_EXECUTE,
+    IMAGE_SCN_MEM_READ,
+    IMAGE_SCN_MEM_WRITE
+);
+const NAMES_IMAGE_SCN_ALIGN: &[FlagName] = &masked_names!(IMAGE_SCN_ALIGN_MASK; IMAGE_SCN_ALIGN_1BYTES, IMAGE_SCN_ALIGN_2BYTES, IMAGE_SCN_ALIGN_4BYTES, IMAGE_SCN_ALIGN_8BYTES, IMAGE_SCN_ALIGN_16BYTES, IMAGE_SCN_ALIGN_32BYTES, IMAGE_SCN_ALIGN_64BYTES, IMAGE_SCN_ALIGN_128BYTES, IMAGE_SCN_ALIGN_256BYTES, IMAGE_SCN_ALIGN_512BYTES, IMAGE_SCN_ALIGN_1024BYTES, IMAGE_SCN_ALIGN_2048BYTES, IMAGE_SCN_ALIGN_4096BYTES, IMAGE_SCN_ALIGN_8192BYTES);
+const NAMES_IMAGE_SYM_CLASS: &[(u8, &str)] = &value_names!(
+    IMAGE_SYM_CLASS_END_OF_FUNCTION,
+    IMAGE_SYM_CLASS_NULL,
+    IMAGE_SYM_CLASS_AUTOMATIC,
+    IMAGE_SYM_CLASS_EXTERNAL,
+    IMAGE_SYM_CLASS_STATIC,
+    IMAGE_SYM_CLASS_REGISTER,
+    IMAGE_SYM_CLASS_EXTERNAL_DEF,
+    IMAGE_SYM_CLASS_LABEL,
+    IMAGE_SYM_CLASS_UNDEFINED_LABEL,
+    IMAGE_SYM_CLASS_MEMBER_OF_STRUCT,
+    IMAGE_SYM_CLASS_ARGUMENT,
+    IMAGE_SYM_CLASS_STRUCT_TAG,
+    IMAGE_SYM_CLASS_MEMBER_OF_UNION,
+    IMAGE_SYM_CLASS_UNION_TAG,
+    IMAGE_SYM_CLASS_TYPE_DEFINITION,
+    IMAGE_SYM_CLASS_UNDEFINED_STATIC,
+    IMAGE_SYM_CLASS_ENUM_TAG,
+    IMAGE_SYM_CLASS_MEMBER_OF_ENUM,
+    IMAGE_SYM_CLASS_REGISTER_PARAM,
+    IMAGE_SYM_CLASS_BIT_FIELD,
+    IMAGE_SYM_CLASS_FAR_EXTERNAL,
+    IMAGE_SYM_CLASS_BLOCK,
+    IMAGE_SYM_CLASS_FUNCTION,
+    IMAGE_SYM_CLASS_END_OF_STRUCT,
+    IMAGE_SYM_CLASS_FILE,
+    IMAGE_SYM_CLASS_SECTION,
+    IMAGE_SYM_CLASS_WEAK_EXTERNAL,
+    IMAGE_SYM_CLASS_CLR_TOKEN
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!(
+            relocation_type_name(IMAGE_FILE_MACHINE_AMD64, IMAGE_REL_AMD64_REL32),
+            Some("IMAGE_REL_AMD64_REL32")
+        );
+        let names = section_characteristics_names(
+            IMAGE_SCN_CNT_CODE | IMAGE_SCN_ALIGN_16BYTES | IMAGE_SCN_MEM_EXECUTE,
+        );
+        assert_eq!(names.unknown(), 0);
+        assert!(names.eq([
+            "IMAGE_SCN_CNT_CODE",
+            "IMAGE_SCN_MEM_EXECUTE",
+            "IMAGE_SCN_ALIGN_16BYTES"
+        ]));
+        assert_eq!(
+            storage_class_name(IMAGE_SYM_CLASS_EXTERNAL),
+            Some("IMAGE_SYM_CLASS_EXTERNAL")
+        );
+    }
+}
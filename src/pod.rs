@@ -21,6 +21,13 @@ type Result<T> = result::Result<T, ()>;
 /// - have no padding
 pub unsafe trait Pod: Copy + 'static {}
 
+/// Return a value of a `Pod` type with all bytes set to zero.
+#[inline]
+pub fn zeroed<T: Pod>() -> T {
+    // Safe because `Pod` types have no invalid byte values.
+    unsafe { mem::zeroed() }
+}
+
 /// Cast a byte slice to a `Pod` type.
 ///
 /// Returns the type and the tail of the slice.
@@ -8,6 +8,8 @@ use crate::read::coff;
 use crate::read::elf;
 #[cfg(feature = "macho")]
 use crate::read::macho;
+#[cfg(feature = "omf")]
+use crate::read::omf;
 #[cfg(feature = "pe")]
 use crate::read::pe;
 #[cfg(feature = "wasm")]
@@ -15,11 +17,13 @@ use crate::read::wasm;
 #[cfg(feature = "xcoff")]
 use crate::read::xcoff;
 use crate::read::{
-    self, Architecture, BinaryFormat, CodeView, ComdatKind, CompressedData, CompressedFileRange,
-    Error, Export, FileFlags, FileKind, Import, Object, ObjectComdat, ObjectKind, ObjectMap,
-    ObjectSection, ObjectSegment, ObjectSymbol, ObjectSymbolTable, ReadRef, Relocation, Result,
-    SectionFlags, SectionIndex, SectionKind, SegmentFlags, SymbolFlags, SymbolIndex, SymbolKind,
-    SymbolMap, SymbolMapName, SymbolScope, SymbolSection,
+    self, Architecture, BinaryFormat, CodeSignature, CodeView, ComdatKind, CompressedData,
+    CompressedFileRange, Error, Export, FileFlags, FileKind, FunctionMapEntry, FunctionRange,
+    Import, Object, ObjectComdat, ObjectKind, ObjectMap, ObjectSection, ObjectSegment,
+    ObjectSymbol, ObjectSymbolTable, ParseOptions, Permissions, ReadRef, Relocation, Result,
+    SectionFlags, SectionIndex, SectionKind, SecurityFeatures, SegmentFlags, SegmentKind,
+    SourceFile, SymbolFlags, SymbolIndex, SymbolKind, SymbolMap, SymbolMapName, SymbolMapTables,
+    SymbolScope, SymbolSection,
 };
 #[allow(unused_imports)]
 use crate::{AddressSize, Endian, Endianness};
@@ -52,6 +56,8 @@ macro_rules! with_inner {
             $enum::Xcoff32(ref $var) => $body,
             #[cfg(feature = "xcoff")]
             $enum::Xcoff64(ref $var) => $body,
+            #[cfg(feature = "omf")]
+            $enum::Omf(ref $var) => $body,
         }
     };
 }
@@ -81,6 +87,8 @@ macro_rules! with_inner_mut {
             $enum::Xcoff32(ref mut $var) => $body,
             #[cfg(feature = "xcoff")]
             $enum::Xcoff64(ref mut $var) => $body,
+            #[cfg(feature = "omf")]
+            $enum::Omf(ref mut $var) => $body,
         }
     };
 }
@@ -111,6 +119,8 @@ macro_rules! map_inner {
             $from::Xcoff32(ref $var) => $to::Xcoff32($body),
             #[cfg(feature = "xcoff")]
             $from::Xcoff64(ref $var) => $to::Xcoff64($body),
+            #[cfg(feature = "omf")]
+            $from::Omf(ref $var) => $to::Omf($body),
         }
     };
 }
@@ -141,6 +151,8 @@ macro_rules! map_inner_option {
             $from::Xcoff32(ref $var) => $body.map($to::Xcoff32),
             #[cfg(feature = "xcoff")]
             $from::Xcoff64(ref $var) => $body.map($to::Xcoff64),
+            #[cfg(feature = "omf")]
+            $from::Omf(ref $var) => $body.map($to::Omf),
         }
     };
 }
@@ -170,6 +182,8 @@ macro_rules! map_inner_option_mut {
             $from::Xcoff32(ref mut $var) => $body.map($to::Xcoff32),
             #[cfg(feature = "xcoff")]
             $from::Xcoff64(ref mut $var) => $body.map($to::Xcoff64),
+            #[cfg(feature = "omf")]
+            $from::Omf(ref mut $var) => $body.map($to::Omf),
         }
     };
 }
@@ -200,6 +214,8 @@ macro_rules! next_inner {
             $from::Xcoff32(ref mut iter) => iter.next().map($to::Xcoff32),
             #[cfg(feature = "xcoff")]
             $from::Xcoff64(ref mut iter) => iter.next().map($to::Xcoff64),
+            #[cfg(feature = "omf")]
+            $from::Omf(ref mut iter) => iter.next().map($to::Omf),
         }
     };
 }
@@ -233,34 +249,60 @@ pub enum File<'data, R: ReadRef<'data> = &'data [u8]> {
     Xcoff32(xcoff::XcoffFile32<'data, R>),
     #[cfg(feature = "xcoff")]
     Xcoff64(xcoff::XcoffFile64<'data, R>),
+    #[cfg(feature = "omf")]
+    Omf(omf::OmfFile<'data>),
 }
 
 impl<'data, R: ReadRef<'data>> File<'data, R> {
     /// Parse the raw file data.
     pub fn parse(data: R) -> Result<Self> {
+        Self::parse_with_options(data, &ParseOptions::default())
+    }
+
+    /// Parse the raw file data, with limits on parsing.
+    ///
+    /// This is the same as [`File::parse`], except that it returns an error if
+    /// a section, symbol or load command count read from the file header exceeds
+    /// [`ParseOptions::max_table_entries`]. The counts are checked before the
+    /// tables are read. Wasm files are parsed without limits.
+    /// The limits on other structures must be passed to the functions that parse them,
+    /// such as [`CompressedData::decompress_with_options`].
+    pub fn parse_with_options(data: R, options: &ParseOptions) -> Result<Self> {
         Ok(match FileKind::parse(data)? {
             #[cfg(feature = "elf")]
-            FileKind::Elf32 => File::Elf32(elf::ElfFile32::parse(data)?),
+            FileKind::Elf32 => File::Elf32(elf::ElfFile32::parse_with_options(data, options)?),
             #[cfg(feature = "elf")]
-            FileKind::Elf64 => File::Elf64(elf::ElfFile64::parse(data)?),
+            FileKind::Elf64 => File::Elf64(elf::ElfFile64::parse_with_options(data, options)?),
             #[cfg(feature = "macho")]
-            FileKind::MachO32 => File::MachO32(macho::MachOFile32::parse(data)?),
+            FileKind::MachO32 => {
+                File::MachO32(macho::MachOFile32::parse_with_options(data, options)?)
+            }
             #[cfg(feature = "macho")]
-            FileKind::MachO64 => File::MachO64(macho::MachOFile64::parse(data)?),
+            FileKind::MachO64 => {
+                File::MachO64(macho::MachOFile64::parse_with_options(data, options)?)
+            }
             #[cfg(feature = "wasm")]
             FileKind::Wasm => File::Wasm(wasm::WasmFile::parse(data)?),
             #[cfg(feature = "pe")]
-            FileKind::Pe32 => File::Pe32(pe::PeFile32::parse(data)?),
+            FileKind::Pe32 => File::Pe32(pe::PeFile32::parse_with_options(data, options)?),
             #[cfg(feature = "pe")]
-            FileKind::Pe64 => File::Pe64(pe::PeFile64::parse(data)?),
+            FileKind::Pe64 => File::Pe64(pe::PeFile64::parse_with_options(data, options)?),
             #[cfg(feature = "coff")]
-            FileKind::Coff => File::Coff(coff::CoffFile::parse(data)?),
+            FileKind::Coff => File::Coff(coff::CoffFile::parse_with_options(data, options)?),
             #[cfg(feature = "coff")]
-            FileKind::CoffBig => File::CoffBig(coff::CoffBigFile::parse(data)?),
+            FileKind::CoffBig => {
+                File::CoffBig(coff::CoffBigFile::parse_with_options(data, options)?)
+            }
             #[cfg(feature = "xcoff")]
-            FileKind::Xcoff32 => File::Xcoff32(xcoff::XcoffFile32::parse(data)?),
+            FileKind::Xcoff32 => {
+                File::Xcoff32(xcoff::XcoffFile32::parse_with_options(data, options)?)
+            }
             #[cfg(feature = "xcoff")]
-            FileKind::Xcoff64 => File::Xcoff64(xcoff::XcoffFile64::parse(data)?),
+            FileKind::Xcoff64 => {
+                File::Xcoff64(xcoff::XcoffFile64::parse_with_options(data, options)?)
+            }
+            #[cfg(feature = "omf")]
+            FileKind::Omf => return Err(Error("OMF files must be decoded with `OmfImage::parse`")),
             #[allow(unreachable_patterns)]
             _ => return Err(Error("Unsupported file format")),
         })
@@ -297,6 +339,8 @@ impl<'data, R: ReadRef<'data>> File<'data, R> {
             File::Wasm(_) => BinaryFormat::Wasm,
             #[cfg(feature = "xcoff")]
             File::Xcoff32(_) | File::Xcoff64(_) => BinaryFormat::Xcoff,
+            #[cfg(feature = "omf")]
+            File::Omf(_) => BinaryFormat::Omf,
         }
     }
 }
@@ -341,6 +385,12 @@ where
         }
     }
 
+    fn all_segments(&'file self) -> SegmentIterator<'data, 'file, R> {
+        SegmentIterator {
+            inner: map_inner!(self, File, SegmentIteratorInternal, |x| x.all_segments()),
+        }
+    }
+
     fn section_by_name_bytes(&'file self, section_name: &[u8]) -> Option<Section<'data, 'file, R>> {
         map_inner_option!(self, File, SectionInternal, |x| x
             .section_by_name_bytes(section_name))
@@ -371,6 +421,13 @@ where
         .map(|inner| Symbol { inner })
     }
 
+    fn symbol_by_name_bytes(&'file self, symbol_name: &[u8]) -> Option<Symbol<'data, 'file, R>> {
+        map_inner_option!(self, File, SymbolInternal, |x| x
+            .symbol_by_name_bytes(symbol_name)
+            .map(|x| (x, PhantomData)))
+        .map(|inner| Symbol { inner })
+    }
+
     fn symbols(&'file self) -> SymbolIterator<'data, 'file, R> {
         SymbolIterator {
             inner: map_inner!(self, File, SymbolIteratorInternal, |x| (
@@ -403,30 +460,50 @@ where
         .map(|inner| SymbolTable { inner })
     }
 
-    #[cfg(feature = "elf")]
     fn dynamic_relocations(&'file self) -> Option<DynamicRelocationIterator<'data, 'file, R>> {
         let inner = match self {
+            #[cfg(feature = "elf")]
             File::Elf32(ref elf) => {
                 DynamicRelocationIteratorInternal::Elf32(elf.dynamic_relocations()?)
             }
+            #[cfg(feature = "elf")]
             File::Elf64(ref elf) => {
                 DynamicRelocationIteratorInternal::Elf64(elf.dynamic_relocations()?)
             }
+            #[cfg(feature = "macho")]
+            File::MachO32(ref macho) => {
+                DynamicRelocationIteratorInternal::MachO32(macho.dynamic_relocations()?)
+            }
+            #[cfg(feature = "macho")]
+            File::MachO64(ref macho) => {
+                DynamicRelocationIteratorInternal::MachO64(macho.dynamic_relocations()?)
+            }
+            #[cfg(feature = "pe")]
+            File::Pe32(ref pe) => DynamicRelocationIteratorInternal::Pe(pe.dynamic_relocations()?),
+            #[cfg(feature = "pe")]
+            File::Pe64(ref pe) => DynamicRelocationIteratorInternal::Pe(pe.dynamic_relocations()?),
             #[allow(unreachable_patterns)]
             _ => return None,
         };
         Some(DynamicRelocationIterator { inner })
     }
 
-    #[cfg(not(feature = "elf"))]
-    fn dynamic_relocations(&'file self) -> Option<DynamicRelocationIterator<'data, 'file, R>> {
-        None
-    }
-
     fn symbol_map(&self) -> SymbolMap<SymbolMapName<'data>> {
         with_inner!(self, File, |x| x.symbol_map())
     }
 
+    fn symbol_map_from(&self, tables: SymbolMapTables) -> SymbolMap<SymbolMapName<'data>> {
+        with_inner!(self, File, |x| x.symbol_map_from(tables))
+    }
+
+    fn function_map(&self) -> SymbolMap<FunctionMapEntry<'data>> {
+        with_inner!(self, File, |x| x.function_map())
+    }
+
+    fn function_ranges(&self) -> Result<Vec<FunctionRange>> {
+        with_inner!(self, File, |x| x.function_ranges())
+    }
+
     fn object_map(&self) -> ObjectMap<'data> {
         with_inner!(self, File, |x| x.object_map())
     }
@@ -439,6 +516,14 @@ where
         with_inner!(self, File, |x| x.exports())
     }
 
+    fn needed_libraries(&self) -> Result<Vec<&'data [u8]>> {
+        with_inner!(self, File, |x| x.needed_libraries())
+    }
+
+    fn source_files(&self) -> Result<Vec<SourceFile<'data>>> {
+        with_inner!(self, File, |x| x.source_files())
+    }
+
     fn has_debug_symbols(&self) -> bool {
         with_inner!(self, File, |x| x.has_debug_symbols())
     }
@@ -468,14 +553,38 @@ where
         with_inner!(self, File, |x| x.pdb_info())
     }
 
+    fn code_signature(&self) -> Result<Option<CodeSignature<'data>>> {
+        with_inner!(self, File, |x| x.code_signature())
+    }
+
+    fn security_features(&self) -> SecurityFeatures {
+        with_inner!(self, File, |x| x.security_features())
+    }
+
     fn relative_address_base(&self) -> u64 {
         with_inner!(self, File, |x| x.relative_address_base())
     }
 
+    fn address_to_file_offset(&self, address: u64) -> Option<u64> {
+        with_inner!(self, File, |x| x.address_to_file_offset(address))
+    }
+
+    fn file_offset_to_address(&self, offset: u64) -> Option<u64> {
+        with_inner!(self, File, |x| x.file_offset_to_address(offset))
+    }
+
     fn entry(&self) -> u64 {
         with_inner!(self, File, |x| x.entry())
     }
 
+    fn is_position_independent(&self) -> bool {
+        with_inner!(self, File, |x| x.is_position_independent())
+    }
+
+    fn is_stripped(&self) -> bool {
+        with_inner!(self, File, |x| x.is_stripped())
+    }
+
     fn flags(&self) -> FileFlags {
         with_inner!(self, File, |x| x.flags())
     }
@@ -511,6 +620,8 @@ enum SegmentIteratorInternal<'data, 'file, R: ReadRef<'data>> {
     Xcoff32(xcoff::XcoffSegmentIterator32<'data, 'file, R>),
     #[cfg(feature = "xcoff")]
     Xcoff64(xcoff::XcoffSegmentIterator64<'data, 'file, R>),
+    #[cfg(feature = "omf")]
+    Omf(core::iter::Empty<read::NoSegment>),
 }
 
 impl<'data, 'file, R: ReadRef<'data>> Iterator for SegmentIterator<'data, 'file, R> {
@@ -551,6 +662,8 @@ enum SegmentInternal<'data, 'file, R: ReadRef<'data>> {
     Xcoff32(xcoff::XcoffSegment32<'data, 'file, R>),
     #[cfg(feature = "xcoff")]
     Xcoff64(xcoff::XcoffSegment64<'data, 'file, R>),
+    #[cfg(feature = "omf")]
+    Omf(read::NoSegment),
 }
 
 impl<'data, 'file, R: ReadRef<'data>> fmt::Debug for Segment<'data, 'file, R> {
@@ -579,6 +692,10 @@ impl<'data, 'file, R: ReadRef<'data>> ObjectSegment<'data> for Segment<'data, 'f
         with_inner!(self.inner, SegmentInternal, |x| x.address())
     }
 
+    fn physical_address(&self) -> u64 {
+        with_inner!(self.inner, SegmentInternal, |x| x.physical_address())
+    }
+
     fn size(&self) -> u64 {
         with_inner!(self.inner, SegmentInternal, |x| x.size())
     }
@@ -607,6 +724,14 @@ impl<'data, 'file, R: ReadRef<'data>> ObjectSegment<'data> for Segment<'data, 'f
         with_inner!(self.inner, SegmentInternal, |x| x.name())
     }
 
+    fn kind(&self) -> SegmentKind {
+        with_inner!(self.inner, SegmentInternal, |x| x.kind())
+    }
+
+    fn permissions(&self) -> Permissions {
+        with_inner!(self.inner, SegmentInternal, |x| x.permissions())
+    }
+
     fn flags(&self) -> SegmentFlags {
         with_inner!(self.inner, SegmentInternal, |x| x.flags())
     }
@@ -643,6 +768,8 @@ enum SectionIteratorInternal<'data, 'file, R: ReadRef<'data>> {
     Xcoff32(xcoff::XcoffSectionIterator32<'data, 'file, R>),
     #[cfg(feature = "xcoff")]
     Xcoff64(xcoff::XcoffSectionIterator64<'data, 'file, R>),
+    #[cfg(feature = "omf")]
+    Omf(omf::OmfSectionIterator<'data, 'file>),
 }
 
 impl<'data, 'file, R: ReadRef<'data>> Iterator for SectionIterator<'data, 'file, R> {
@@ -682,6 +809,8 @@ enum SectionInternal<'data, 'file, R: ReadRef<'data>> {
     Xcoff32(xcoff::XcoffSection32<'data, 'file, R>),
     #[cfg(feature = "xcoff")]
     Xcoff64(xcoff::XcoffSection64<'data, 'file, R>),
+    #[cfg(feature = "omf")]
+    Omf(omf::OmfSection<'data, 'file>),
 }
 
 impl<'data, 'file, R: ReadRef<'data>> fmt::Debug for Section<'data, 'file, R> {
@@ -814,6 +943,8 @@ enum ComdatIteratorInternal<'data, 'file, R: ReadRef<'data>> {
     Xcoff32(xcoff::XcoffComdatIterator32<'data, 'file, R>),
     #[cfg(feature = "xcoff")]
     Xcoff64(xcoff::XcoffComdatIterator64<'data, 'file, R>),
+    #[cfg(feature = "omf")]
+    Omf(core::iter::Empty<read::NoComdat>),
 }
 
 impl<'data, 'file, R: ReadRef<'data>> Iterator for ComdatIterator<'data, 'file, R> {
@@ -853,6 +984,8 @@ enum ComdatInternal<'data, 'file, R: ReadRef<'data>> {
     Xcoff32(xcoff::XcoffComdat32<'data, 'file, R>),
     #[cfg(feature = "xcoff")]
     Xcoff64(xcoff::XcoffComdat64<'data, 'file, R>),
+    #[cfg(feature = "omf")]
+    Omf(read::NoComdat),
 }
 
 impl<'data, 'file, R: ReadRef<'data>> fmt::Debug for Comdat<'data, 'file, R> {
@@ -928,6 +1061,8 @@ enum ComdatSectionIteratorInternal<'data, 'file, R: ReadRef<'data>> {
     Xcoff32(xcoff::XcoffComdatSectionIterator32<'data, 'file, R>),
     #[cfg(feature = "xcoff")]
     Xcoff64(xcoff::XcoffComdatSectionIterator64<'data, 'file, R>),
+    #[cfg(feature = "omf")]
+    Omf(core::iter::Empty<SectionIndex>),
 }
 
 impl<'data, 'file, R: ReadRef<'data>> Iterator for ComdatSectionIterator<'data, 'file, R> {
@@ -994,6 +1129,8 @@ where
     Xcoff32((xcoff::XcoffSymbolTable32<'data, 'file, R>, PhantomData<R>)),
     #[cfg(feature = "xcoff")]
     Xcoff64((xcoff::XcoffSymbolTable64<'data, 'file, R>, PhantomData<R>)),
+    #[cfg(feature = "omf")]
+    Omf((omf::OmfSymbolTable<'data, 'file>, PhantomData<R>)),
 }
 
 impl<'data, 'file, R: ReadRef<'data>> read::private::Sealed for SymbolTable<'data, 'file, R> {}
@@ -1088,6 +1225,8 @@ where
             PhantomData<R>,
         ),
     ),
+    #[cfg(feature = "omf")]
+    Omf((omf::OmfSymbolIterator<'data, 'file>, PhantomData<R>)),
 }
 
 impl<'data, 'file, R: ReadRef<'data>> Iterator for SymbolIterator<'data, 'file, R> {
@@ -1155,6 +1294,8 @@ where
     Xcoff32((xcoff::XcoffSymbol32<'data, 'file, R>, PhantomData<R>)),
     #[cfg(feature = "xcoff")]
     Xcoff64((xcoff::XcoffSymbol64<'data, 'file, R>, PhantomData<R>)),
+    #[cfg(feature = "omf")]
+    Omf((omf::OmfSymbol<'data, 'file>, PhantomData<R>)),
 }
 
 impl<'data, 'file, R: ReadRef<'data>> fmt::Debug for Symbol<'data, 'file, R> {
@@ -1254,6 +1395,12 @@ where
     Elf32(elf::ElfDynamicRelocationIterator32<'data, 'file, Endianness, R>),
     #[cfg(feature = "elf")]
     Elf64(elf::ElfDynamicRelocationIterator64<'data, 'file, Endianness, R>),
+    #[cfg(feature = "macho")]
+    MachO32(macho::MachODynamicRelocationIterator32<'data, 'file, Endianness, R>),
+    #[cfg(feature = "macho")]
+    MachO64(macho::MachODynamicRelocationIterator64<'data, 'file, Endianness, R>),
+    #[cfg(feature = "pe")]
+    Pe(pe::PeDynamicRelocationIterator<'data>),
     // We need to always use the lifetime parameters.
     #[allow(unused)]
     None(PhantomData<(&'data (), &'file (), R)>),
@@ -1268,6 +1415,12 @@ impl<'data, 'file, R: ReadRef<'data>> Iterator for DynamicRelocationIterator<'da
             DynamicRelocationIteratorInternal::Elf32(ref mut elf) => elf.next(),
             #[cfg(feature = "elf")]
             DynamicRelocationIteratorInternal::Elf64(ref mut elf) => elf.next(),
+            #[cfg(feature = "macho")]
+            DynamicRelocationIteratorInternal::MachO32(ref mut macho) => macho.next(),
+            #[cfg(feature = "macho")]
+            DynamicRelocationIteratorInternal::MachO64(ref mut macho) => macho.next(),
+            #[cfg(feature = "pe")]
+            DynamicRelocationIteratorInternal::Pe(ref mut pe) => pe.next(),
             DynamicRelocationIteratorInternal::None(_) => None,
         }
     }
@@ -1303,6 +1456,8 @@ enum SectionRelocationIteratorInternal<'data, 'file, R: ReadRef<'data>> {
     Xcoff32(xcoff::XcoffRelocationIterator32<'data, 'file, R>),
     #[cfg(feature = "xcoff")]
     Xcoff64(xcoff::XcoffRelocationIterator64<'data, 'file, R>),
+    #[cfg(feature = "omf")]
+    Omf(omf::OmfRelocationIterator<'data, 'file>),
 }
 
 impl<'data, 'file, R: ReadRef<'data>> Iterator for SectionRelocationIterator<'data, 'file, R> {
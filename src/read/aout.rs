@@ -0,0 +1,996 @@
+//! Support for reading a.out files.
+//!
+//! Provides `AoutFile` and related types which implement the `Object` trait.
+//!
+//! Supports `OMAGIC`, `NMAGIC`, `ZMAGIC` and `QMAGIC` files of either endianness,
+//! using the Linux conventions for the layout of the file.
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::{mem, slice, str};
+
+use crate::aout;
+use crate::endian::{Endianness, U32};
+use crate::read::{
+    self, util, Architecture, ComdatKind, CompressedData, CompressedFileRange, Export, FileFlags,
+    Import, NoDynamicRelocationIterator, Object, ObjectComdat, ObjectKind, ObjectSection,
+    ObjectSegment, ObjectSymbol, ObjectSymbolTable, Permissions, ReadError, ReadRef, Relocation,
+    RelocationEncoding, RelocationKind, RelocationTarget, Result, SectionFlags, SectionIndex,
+    SectionKind, SegmentFlags, SegmentKind, StringTable, SymbolFlags, SymbolIndex, SymbolKind,
+    SymbolScope, SymbolSection,
+};
+
+/// The names of the sections of an `AoutFile`, in section index order.
+const SECTION_NAMES: [&str; 3] = [".text", ".data", ".bss"];
+
+/// The `N_TYPE` of the sections of an `AoutFile`, in section index order.
+const SECTION_TYPES: [u8; 3] = [aout::N_TEXT, aout::N_DATA, aout::N_BSS];
+
+/// An a.out file.
+#[derive(Debug)]
+pub struct AoutFile<'data, R = &'data [u8]>
+where
+    R: ReadRef<'data>,
+{
+    data: R,
+    endian: Endianness,
+    header: &'data aout::Exec<Endianness>,
+    sections: [AoutSectionInternal; 3],
+    symbols: &'data [aout::Nlist<Endianness>],
+    strings: StringTable<'data, R>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct AoutSectionInternal {
+    address: u64,
+    size: u64,
+    // `None` for the bss section.
+    offset: Option<u64>,
+    reloc_offset: u64,
+    reloc_size: u64,
+}
+
+impl<'data, R: ReadRef<'data>> AoutFile<'data, R> {
+    /// Parse the raw a.out file data.
+    pub fn parse(data: R) -> Result<Self> {
+        let header = data
+            .read_at::<aout::Exec<Endianness>>(0)
+            .read_error("Invalid a.out header size or alignment")?;
+        let endian = [Endianness::Little, Endianness::Big]
+            .iter()
+            .copied()
+            .find(|endian| is_supported_magic(header.a_info.get(*endian)))
+            .read_error("Unsupported a.out magic")?;
+
+        let magic = aout::N_MAGIC(header.a_info.get(endian));
+        let a_text = u64::from(header.a_text.get(endian));
+        let a_data = u64::from(header.a_data.get(endian));
+        let a_trsize = u64::from(header.a_trsize.get(endian));
+        let a_drsize = u64::from(header.a_drsize.get(endian));
+
+        let (text_offset, text_address) = text_layout(magic);
+        let data_offset = text_offset + a_text;
+        let mut data_address = text_address + a_text;
+        if magic != aout::OMAGIC {
+            let segment_size = u64::from(aout::SEGMENT_SIZE);
+            data_address = (data_address + segment_size - 1) & !(segment_size - 1);
+        }
+        let text_reloc_offset = data_offset + a_data;
+        let data_reloc_offset = text_reloc_offset + a_trsize;
+        let symbol_offset = data_reloc_offset + a_drsize;
+        let string_offset = symbol_offset + u64::from(header.a_syms.get(endian));
+
+        let sections = [
+            AoutSectionInternal {
+                address: text_address,
+                size: a_text,
+                offset: Some(text_offset),
+                reloc_offset: text_reloc_offset,
+                reloc_size: a_trsize,
+            },
+            AoutSectionInternal {
+                address: data_address,
+                size: a_data,
+                offset: Some(data_offset),
+                reloc_offset: data_reloc_offset,
+                reloc_size: a_drsize,
+            },
+            AoutSectionInternal {
+                address: data_address + a_data,
+                size: u64::from(header.a_bss.get(endian)),
+                offset: None,
+                reloc_offset: 0,
+                reloc_size: 0,
+            },
+        ];
+
+        let nsyms = header.a_syms.get(endian) as usize / mem::size_of::<aout::Nlist<Endianness>>();
+        let symbols = data
+            .read_slice_at(symbol_offset, nsyms)
+            .read_error("Invalid a.out symbol table offset or size")?;
+        let strings = if nsyms != 0 {
+            // The string table size includes the size field.
+            let size = data
+                .read_at::<U32<Endianness>>(string_offset)
+                .read_error("Missing a.out string table")?
+                .get(endian);
+            let string_end = string_offset
+                .checked_add(size.into())
+                .read_error("Invalid a.out string table size")?;
+            StringTable::new(data, string_offset, string_end)
+        } else {
+            StringTable::default()
+        };
+
+        Ok(AoutFile {
+            data,
+            endian,
+            header,
+            sections,
+            symbols,
+            strings,
+        })
+    }
+
+    /// Returns the endianness.
+    #[inline]
+    pub fn endian(&self) -> Endianness {
+        self.endian
+    }
+
+    /// Returns the raw data.
+    #[inline]
+    pub fn data(&self) -> R {
+        self.data
+    }
+
+    /// Returns the a.out header.
+    #[inline]
+    pub fn raw_header(&self) -> &'data aout::Exec<Endianness> {
+        self.header
+    }
+
+    /// Returns the magic number from the header.
+    #[inline]
+    pub fn magic(&self) -> u16 {
+        aout::N_MAGIC(self.header.a_info.get(self.endian))
+    }
+
+    fn section_internal(&self, index: SectionIndex) -> Result<&AoutSectionInternal> {
+        index
+            .0
+            .checked_sub(1)
+            .and_then(|index| self.sections.get(index))
+            .read_error("Invalid a.out section index")
+    }
+
+    fn symbol_internal(&self, index: SymbolIndex) -> Result<&'data aout::Nlist<Endianness>> {
+        self.symbols
+            .get(index.0)
+            .read_error("Invalid a.out symbol index")
+    }
+}
+
+fn is_supported_magic(a_info: u32) -> bool {
+    matches!(
+        aout::N_MAGIC(a_info),
+        aout::OMAGIC | aout::NMAGIC | aout::ZMAGIC | aout::QMAGIC
+    )
+}
+
+/// Return the file offset and address of the text for the given `N_MAGIC`.
+fn text_layout(magic: u16) -> (u64, u64) {
+    match magic {
+        aout::ZMAGIC => (u64::from(aout::ZMAGIC_TEXT_OFFSET), 0),
+        aout::QMAGIC => (0, u64::from(aout::QMAGIC_TEXT_ADDRESS)),
+        _ => (mem::size_of::<aout::Exec<Endianness>>() as u64, 0),
+    }
+}
+
+/// Return true if the data at the given offset starts with an a.out header
+/// whose sizes are valid for a file of the given length.
+///
+/// The magic numbers are short and common, so the sizes must also be checked.
+pub(crate) fn is_aout<'data, R: ReadRef<'data>>(data: R, offset: u64, len: u64) -> bool {
+    let header = match data.read_at::<aout::Exec<Endianness>>(offset) {
+        Ok(header) => header,
+        Err(()) => return false,
+    };
+    [Endianness::Little, Endianness::Big]
+        .iter()
+        .any(|&endian| is_valid_header(header, endian, len))
+}
+
+fn is_valid_header(header: &aout::Exec<Endianness>, endian: Endianness, len: u64) -> bool {
+    let a_info = header.a_info.get(endian);
+    if !is_supported_magic(a_info) {
+        return false;
+    }
+    let a_text = u64::from(header.a_text.get(endian));
+    let a_syms = u64::from(header.a_syms.get(endian));
+    let a_trsize = u64::from(header.a_trsize.get(endian));
+    let a_drsize = u64::from(header.a_drsize.get(endian));
+    let reloc_size = mem::size_of::<aout::RelocationInfo<Endianness>>() as u64;
+    let symbol_size = mem::size_of::<aout::Nlist<Endianness>>() as u64;
+    if a_trsize % reloc_size != 0 || a_drsize % reloc_size != 0 || a_syms % symbol_size != 0 {
+        return false;
+    }
+    let magic = aout::N_MAGIC(a_info);
+    // The header is part of the text for `QMAGIC`.
+    if magic == aout::QMAGIC && a_text < mem::size_of::<aout::Exec<Endianness>>() as u64 {
+        return false;
+    }
+    let (text_offset, _) = text_layout(magic);
+    let mut end = text_offset + a_text + u64::from(header.a_data.get(endian)) + a_trsize + a_drsize;
+    if a_syms != 0 {
+        // The symbols are followed by the size of the string table.
+        end += a_syms + 4;
+    }
+    end <= len
+}
+
+impl<'data, R: ReadRef<'data>> read::private::Sealed for AoutFile<'data, R> {}
+
+impl<'data, 'file, R> Object<'data, 'file> for AoutFile<'data, R>
+where
+    'data: 'file,
+    R: 'file + ReadRef<'data>,
+{
+    type Segment = AoutSegment<'data, 'file, R>;
+    type SegmentIterator = AoutSegmentIterator<'data, 'file, R>;
+    type Section = AoutSection<'data, 'file, R>;
+    type SectionIterator = AoutSectionIterator<'data, 'file, R>;
+    type Comdat = AoutComdat<'data, 'file, R>;
+    type ComdatIterator = AoutComdatIterator<'data, 'file, R>;
+    type Symbol = AoutSymbol<'data, 'file, R>;
+    type SymbolIterator = AoutSymbolIterator<'data, 'file, R>;
+    type SymbolTable = AoutSymbolTable<'data, 'file, R>;
+    type DynamicRelocationIterator = NoDynamicRelocationIterator;
+
+    fn architecture(&self) -> Architecture {
+        match aout::N_MACHTYPE(self.header.a_info.get(self.endian)) {
+            aout::M_386 => Architecture::I386,
+            aout::M_MIPS1 | aout::M_MIPS2 => Architecture::Mips,
+            _ => Architecture::Unknown,
+        }
+    }
+
+    #[inline]
+    fn is_little_endian(&self) -> bool {
+        self.endian == Endianness::Little
+    }
+
+    #[inline]
+    fn is_64(&self) -> bool {
+        false
+    }
+
+    fn kind(&self) -> ObjectKind {
+        if self.magic() == aout::OMAGIC {
+            ObjectKind::Relocatable
+        } else {
+            ObjectKind::Executable
+        }
+    }
+
+    fn segments(&'file self) -> AoutSegmentIterator<'data, 'file, R> {
+        AoutSegmentIterator {
+            file: self,
+            iter: 0..2,
+        }
+    }
+
+    fn section_by_name_bytes(
+        &'file self,
+        section_name: &[u8],
+    ) -> Option<AoutSection<'data, 'file, R>> {
+        SECTION_NAMES
+            .iter()
+            .position(|name| name.as_bytes() == section_name)
+            .map(|index| AoutSection {
+                file: self,
+                index: SectionIndex(index + 1),
+                section: &self.sections[index],
+            })
+    }
+
+    fn section_by_index(&'file self, index: SectionIndex) -> Result<AoutSection<'data, 'file, R>> {
+        let section = self.section_internal(index)?;
+        Ok(AoutSection {
+            file: self,
+            index,
+            section,
+        })
+    }
+
+    fn sections(&'file self) -> AoutSectionIterator<'data, 'file, R> {
+        AoutSectionIterator {
+            file: self,
+            iter: self.sections.iter().enumerate(),
+        }
+    }
+
+    #[inline]
+    fn comdats(&'file self) -> AoutComdatIterator<'data, 'file, R> {
+        AoutComdatIterator(PhantomData)
+    }
+
+    fn symbol_by_index(&'file self, index: SymbolIndex) -> Result<AoutSymbol<'data, 'file, R>> {
+        let symbol = self.symbol_internal(index)?;
+        Ok(AoutSymbol {
+            file: self,
+            index,
+            symbol,
+        })
+    }
+
+    fn symbols(&'file self) -> AoutSymbolIterator<'data, 'file, R> {
+        AoutSymbolIterator {
+            file: self,
+            iter: self.symbols.iter().enumerate(),
+        }
+    }
+
+    fn symbol_table(&'file self) -> Option<AoutSymbolTable<'data, 'file, R>> {
+        if self.symbols.is_empty() {
+            None
+        } else {
+            Some(AoutSymbolTable { file: self })
+        }
+    }
+
+    fn dynamic_symbols(&'file self) -> AoutSymbolIterator<'data, 'file, R> {
+        AoutSymbolIterator {
+            file: self,
+            iter: <&[_]>::default().iter().enumerate(),
+        }
+    }
+
+    #[inline]
+    fn dynamic_symbol_table(&'file self) -> Option<AoutSymbolTable<'data, 'file, R>> {
+        None
+    }
+
+    #[inline]
+    fn dynamic_relocations(&'file self) -> Option<NoDynamicRelocationIterator> {
+        None
+    }
+
+    fn imports(&self) -> Result<Vec<Import<'data>>> {
+        // a.out files have no dynamic linking information.
+        Ok(Vec::new())
+    }
+
+    fn exports(&self) -> Result<Vec<Export<'data>>> {
+        // a.out files have no dynamic linking information.
+        Ok(Vec::new())
+    }
+
+    fn has_debug_symbols(&self) -> bool {
+        self.symbols
+            .iter()
+            .any(|symbol| symbol.n_type & aout::N_STAB != 0)
+    }
+
+    #[inline]
+    fn relative_address_base(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn entry(&self) -> u64 {
+        self.header.a_entry.get(self.endian).into()
+    }
+
+    #[inline]
+    fn flags(&self) -> FileFlags {
+        FileFlags::None
+    }
+}
+
+/// An iterator over the segments of an `AoutFile`.
+#[derive(Debug)]
+pub struct AoutSegmentIterator<'data, 'file, R = &'data [u8]>
+where
+    R: ReadRef<'data>,
+{
+    file: &'file AoutFile<'data, R>,
+    iter: core::ops::Range<usize>,
+}
+
+impl<'data, 'file, R: ReadRef<'data>> Iterator for AoutSegmentIterator<'data, 'file, R> {
+    type Item = AoutSegment<'data, 'file, R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|index| AoutSegment {
+            file: self.file,
+            index,
+        })
+    }
+}
+
+/// A segment of an `AoutFile`.
+///
+/// There are two segments: the text segment, and the data segment which also
+/// contains the bss.
+#[derive(Debug)]
+pub struct AoutSegment<'data, 'file, R = &'data [u8]>
+where
+    R: ReadRef<'data>,
+{
+    file: &'file AoutFile<'data, R>,
+    index: usize,
+}
+
+impl<'data, 'file, R: ReadRef<'data>> AoutSegment<'data, 'file, R> {
+    fn bytes(&self) -> Result<&'data [u8]> {
+        let section = &self.file.sections[self.index];
+        section_bytes(self.file.data, section)
+    }
+}
+
+impl<'data, 'file, R: ReadRef<'data>> read::private::Sealed for AoutSegment<'data, 'file, R> {}
+
+impl<'data, 'file, R: ReadRef<'data>> ObjectSegment<'data> for AoutSegment<'data, 'file, R> {
+    #[inline]
+    fn address(&self) -> u64 {
+        self.file.sections[self.index].address
+    }
+
+    #[inline]
+    fn physical_address(&self) -> u64 {
+        self.address()
+    }
+
+    fn size(&self) -> u64 {
+        let sections = &self.file.sections;
+        if self.index == 0 {
+            sections[0].size
+        } else {
+            sections[2].address + sections[2].size - sections[1].address
+        }
+    }
+
+    #[inline]
+    fn align(&self) -> u64 {
+        1
+    }
+
+    fn file_range(&self) -> (u64, u64) {
+        let section = &self.file.sections[self.index];
+        (section.offset.unwrap_or(0), section.size)
+    }
+
+    fn data(&self) -> Result<&'data [u8]> {
+        self.bytes()
+    }
+
+    fn data_range(&self, address: u64, size: u64) -> Result<Option<&'data [u8]>> {
+        Ok(util::data_range(
+            self.bytes()?,
+            self.address(),
+            address,
+            size,
+        ))
+    }
+
+    #[inline]
+    fn name_bytes(&self) -> Result<Option<&[u8]>> {
+        Ok(None)
+    }
+
+    #[inline]
+    fn name(&self) -> Result<Option<&str>> {
+        Ok(None)
+    }
+
+    #[inline]
+    fn kind(&self) -> SegmentKind {
+        SegmentKind::Load
+    }
+
+    fn permissions(&self) -> Permissions {
+        if self.index == 0 {
+            Permissions::new(true, false, true)
+        } else {
+            Permissions::new(true, true, false)
+        }
+    }
+
+    #[inline]
+    fn flags(&self) -> SegmentFlags {
+        SegmentFlags::None
+    }
+}
+
+fn section_bytes<'data, R: ReadRef<'data>>(
+    data: R,
+    section: &AoutSectionInternal,
+) -> Result<&'data [u8]> {
+    match section.offset {
+        Some(offset) => data
+            .read_bytes_at(offset, section.size)
+            .read_error("Invalid a.out section offset or size"),
+        None => Ok(&[]),
+    }
+}
+
+/// An iterator over the sections of an `AoutFile`.
+#[derive(Debug)]
+pub struct AoutSectionIterator<'data, 'file, R = &'data [u8]>
+where
+    R: ReadRef<'data>,
+{
+    file: &'file AoutFile<'data, R>,
+    iter: core::iter::Enumerate<slice::Iter<'file, AoutSectionInternal>>,
+}
+
+impl<'data, 'file, R: ReadRef<'data>> Iterator for AoutSectionIterator<'data, 'file, R> {
+    type Item = AoutSection<'data, 'file, R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(index, section)| AoutSection {
+            file: self.file,
+            index: SectionIndex(index + 1),
+            section,
+        })
+    }
+}
+
+/// A section of an `AoutFile`.
+///
+/// The sections are always `.text`, `.data` and `.bss`, with indices 1, 2 and 3.
+#[derive(Debug)]
+pub struct AoutSection<'data, 'file, R = &'data [u8]>
+where
+    R: ReadRef<'data>,
+{
+    file: &'file AoutFile<'data, R>,
+    index: SectionIndex,
+    section: &'file AoutSectionInternal,
+}
+
+impl<'data, 'file, R: ReadRef<'data>> read::private::Sealed for AoutSection<'data, 'file, R> {}
+
+impl<'data, 'file, R: ReadRef<'data>> ObjectSection<'data> for AoutSection<'data, 'file, R> {
+    type RelocationIterator = AoutRelocationIterator<'data, 'file, R>;
+
+    #[inline]
+    fn index(&self) -> SectionIndex {
+        self.index
+    }
+
+    #[inline]
+    fn address(&self) -> u64 {
+        self.section.address
+    }
+
+    #[inline]
+    fn size(&self) -> u64 {
+        self.section.size
+    }
+
+    #[inline]
+    fn align(&self) -> u64 {
+        1
+    }
+
+    #[inline]
+    fn file_range(&self) -> Option<(u64, u64)> {
+        self.section
+            .offset
+            .map(|offset| (offset, self.section.size))
+    }
+
+    fn data(&self) -> Result<&'data [u8]> {
+        section_bytes(self.file.data, self.section)
+    }
+
+    fn data_range(&self, address: u64, size: u64) -> Result<Option<&'data [u8]>> {
+        Ok(util::data_range(
+            self.data()?,
+            self.address(),
+            address,
+            size,
+        ))
+    }
+
+    #[inline]
+    fn compressed_file_range(&self) -> Result<CompressedFileRange> {
+        Ok(CompressedFileRange::none(self.file_range()))
+    }
+
+    #[inline]
+    fn compressed_data(&self) -> Result<CompressedData<'data>> {
+        self.data().map(CompressedData::none)
+    }
+
+    #[inline]
+    fn name_bytes(&self) -> Result<&[u8]> {
+        Ok(SECTION_NAMES[self.index.0 - 1].as_bytes())
+    }
+
+    #[inline]
+    fn name(&self) -> Result<&str> {
+        Ok(SECTION_NAMES[self.index.0 - 1])
+    }
+
+    #[inline]
+    fn segment_name_bytes(&self) -> Result<Option<&[u8]>> {
+        Ok(None)
+    }
+
+    #[inline]
+    fn segment_name(&self) -> Result<Option<&str>> {
+        Ok(None)
+    }
+
+    fn kind(&self) -> SectionKind {
+        match self.index.0 {
+            1 => SectionKind::Text,
+            2 => SectionKind::Data,
+            _ => SectionKind::UninitializedData,
+        }
+    }
+
+    fn relocations(&self) -> AoutRelocationIterator<'data, 'file, R> {
+        let count =
+            self.section.reloc_size as usize / mem::size_of::<aout::RelocationInfo<Endianness>>();
+        // Errors are ignored here, consistent with other formats.
+        let relocations = self
+            .file
+            .data
+            .read_slice_at(self.section.reloc_offset, count)
+            .unwrap_or(&[]);
+        AoutRelocationIterator {
+            file: self.file,
+            iter: relocations.iter(),
+        }
+    }
+
+    #[inline]
+    fn flags(&self) -> SectionFlags {
+        SectionFlags::None
+    }
+}
+
+/// An iterator over the COMDAT section groups of an `AoutFile`.
+///
+/// a.out files have no COMDAT section groups, so this is always empty.
+#[derive(Debug)]
+pub struct AoutComdatIterator<'data, 'file, R = &'data [u8]>(
+    PhantomData<(&'data (), &'file (), R)>,
+);
+
+impl<'data, 'file, R> Iterator for AoutComdatIterator<'data, 'file, R> {
+    type Item = AoutComdat<'data, 'file, R>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        None
+    }
+}
+
+/// A COMDAT section group of an `AoutFile`.
+///
+/// a.out files have no COMDAT section groups, so this is never constructed.
+#[derive(Debug)]
+pub struct AoutComdat<'data, 'file, R = &'data [u8]>(PhantomData<(&'data (), &'file (), R)>);
+
+impl<'data, 'file, R> read::private::Sealed for AoutComdat<'data, 'file, R> {}
+
+impl<'data, 'file, R> ObjectComdat<'data> for AoutComdat<'data, 'file, R> {
+    type SectionIterator = AoutComdatSectionIterator<'data, 'file, R>;
+
+    #[inline]
+    fn kind(&self) -> ComdatKind {
+        unreachable!()
+    }
+
+    #[inline]
+    fn symbol(&self) -> SymbolIndex {
+        unreachable!()
+    }
+
+    #[inline]
+    fn name_bytes(&self) -> Result<&[u8]> {
+        unreachable!()
+    }
+
+    #[inline]
+    fn name(&self) -> Result<&str> {
+        unreachable!()
+    }
+
+    #[inline]
+    fn sections(&self) -> Self::SectionIterator {
+        unreachable!()
+    }
+}
+
+/// An iterator over the sections in a COMDAT section group of an `AoutFile`.
+#[derive(Debug)]
+pub struct AoutComdatSectionIterator<'data, 'file, R = &'data [u8]>(
+    PhantomData<(&'data (), &'file (), R)>,
+);
+
+impl<'data, 'file, R> Iterator for AoutComdatSectionIterator<'data, 'file, R> {
+    type Item = SectionIndex;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        None
+    }
+}
+
+/// The symbol table of an `AoutFile`.
+#[derive(Debug, Clone, Copy)]
+pub struct AoutSymbolTable<'data, 'file, R = &'data [u8]>
+where
+    R: ReadRef<'data>,
+{
+    file: &'file AoutFile<'data, R>,
+}
+
+impl<'data, 'file, R: ReadRef<'data>> read::private::Sealed for AoutSymbolTable<'data, 'file, R> {}
+
+impl<'data, 'file, R: ReadRef<'data>> ObjectSymbolTable<'data>
+    for AoutSymbolTable<'data, 'file, R>
+{
+    type Symbol = AoutSymbol<'data, 'file, R>;
+    type SymbolIterator = AoutSymbolIterator<'data, 'file, R>;
+
+    fn symbols(&self) -> Self::SymbolIterator {
+        AoutSymbolIterator {
+            file: self.file,
+            iter: self.file.symbols.iter().enumerate(),
+        }
+    }
+
+    fn symbol_by_index(&self, index: SymbolIndex) -> Result<Self::Symbol> {
+        let symbol = self.file.symbol_internal(index)?;
+        Ok(AoutSymbol {
+            file: self.file,
+            index,
+            symbol,
+        })
+    }
+}
+
+/// An iterator over the symbols of an `AoutFile`.
+#[derive(Debug)]
+pub struct AoutSymbolIterator<'data, 'file, R = &'data [u8]>
+where
+    R: ReadRef<'data>,
+{
+    file: &'file AoutFile<'data, R>,
+    iter: core::iter::Enumerate<slice::Iter<'data, aout::Nlist<Endianness>>>,
+}
+
+impl<'data, 'file, R: ReadRef<'data>> Iterator for AoutSymbolIterator<'data, 'file, R> {
+    type Item = AoutSymbol<'data, 'file, R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(index, symbol)| AoutSymbol {
+            file: self.file,
+            index: SymbolIndex(index),
+            symbol,
+        })
+    }
+}
+
+/// A symbol of an `AoutFile`.
+#[derive(Debug, Clone, Copy)]
+pub struct AoutSymbol<'data, 'file, R = &'data [u8]>
+where
+    R: ReadRef<'data>,
+{
+    file: &'file AoutFile<'data, R>,
+    index: SymbolIndex,
+    symbol: &'data aout::Nlist<Endianness>,
+}
+
+impl<'data, 'file, R: ReadRef<'data>> AoutSymbol<'data, 'file, R> {
+    /// Returns the raw symbol table entry.
+    #[inline]
+    pub fn raw_symbol(&self) -> &'data aout::Nlist<Endianness> {
+        self.symbol
+    }
+
+    #[inline]
+    fn is_stab(&self) -> bool {
+        self.symbol.n_type & aout::N_STAB != 0
+    }
+
+    #[inline]
+    fn n_type(&self) -> u8 {
+        self.symbol.n_type & aout::N_TYPE
+    }
+
+    #[inline]
+    fn n_value(&self) -> u64 {
+        self.symbol.n_value.get(self.file.endian).into()
+    }
+}
+
+impl<'data, 'file, R: ReadRef<'data>> read::private::Sealed for AoutSymbol<'data, 'file, R> {}
+
+impl<'data, 'file, R: ReadRef<'data>> ObjectSymbol<'data> for AoutSymbol<'data, 'file, R> {
+    #[inline]
+    fn index(&self) -> SymbolIndex {
+        self.index
+    }
+
+    fn name_bytes(&self) -> read::Result<&'data [u8]> {
+        let n_strx = self.symbol.n_strx.get(self.file.endian);
+        if n_strx == 0 {
+            return Ok(&[]);
+        }
+        self.file
+            .strings
+            .get(n_strx)
+            .read_error("Invalid a.out symbol name offset")
+    }
+
+    fn name(&self) -> read::Result<&'data str> {
+        let name = self.name_bytes()?;
+        str::from_utf8(name)
+            .ok()
+            .read_error("Non UTF-8 a.out symbol name")
+    }
+
+    fn address(&self) -> u64 {
+        match self.section() {
+            SymbolSection::Undefined | SymbolSection::Common => 0,
+            _ => self.n_value(),
+        }
+    }
+
+    fn size(&self) -> u64 {
+        if self.is_common() {
+            self.n_value()
+        } else {
+            0
+        }
+    }
+
+    fn kind(&self) -> SymbolKind {
+        if self.is_stab() {
+            return SymbolKind::Unknown;
+        }
+        match self.n_type() {
+            aout::N_TEXT => SymbolKind::Text,
+            aout::N_DATA | aout::N_BSS => SymbolKind::Data,
+            n_type if n_type == aout::N_FN & aout::N_TYPE => SymbolKind::File,
+            aout::N_UNDF if self.is_common() => SymbolKind::Data,
+            _ => SymbolKind::Unknown,
+        }
+    }
+
+    fn section(&self) -> SymbolSection {
+        if self.is_stab() {
+            return SymbolSection::None;
+        }
+        let n_type = self.n_type();
+        if let Some(index) = SECTION_TYPES.iter().position(|t| *t == n_type) {
+            return SymbolSection::Section(SectionIndex(index + 1));
+        }
+        match n_type {
+            aout::N_UNDF => {
+                if self.symbol.n_type & aout::N_EXT != 0 && self.n_value() != 0 {
+                    SymbolSection::Common
+                } else {
+                    SymbolSection::Undefined
+                }
+            }
+            aout::N_ABS => SymbolSection::Absolute,
+            _ => SymbolSection::Unknown,
+        }
+    }
+
+    #[inline]
+    fn is_undefined(&self) -> bool {
+        self.section() == SymbolSection::Undefined
+    }
+
+    fn is_definition(&self) -> bool {
+        !self.is_stab()
+            && matches!(
+                self.section(),
+                SymbolSection::Section(_) | SymbolSection::Absolute
+            )
+    }
+
+    #[inline]
+    fn is_common(&self) -> bool {
+        self.section() == SymbolSection::Common
+    }
+
+    #[inline]
+    fn is_weak(&self) -> bool {
+        false
+    }
+
+    fn scope(&self) -> SymbolScope {
+        if self.is_stab() {
+            SymbolScope::Compilation
+        } else if self.is_undefined() {
+            SymbolScope::Unknown
+        } else if self.symbol.n_type & aout::N_EXT != 0 {
+            SymbolScope::Linkage
+        } else {
+            SymbolScope::Compilation
+        }
+    }
+
+    #[inline]
+    fn is_global(&self) -> bool {
+        !self.is_stab() && self.symbol.n_type & aout::N_EXT != 0
+    }
+
+    #[inline]
+    fn is_local(&self) -> bool {
+        !self.is_global()
+    }
+
+    #[inline]
+    fn flags(&self) -> SymbolFlags<SectionIndex, SymbolIndex> {
+        SymbolFlags::None
+    }
+}
+
+/// An iterator over the relocations in an `AoutSection`.
+#[derive(Debug)]
+pub struct AoutRelocationIterator<'data, 'file, R = &'data [u8]>
+where
+    R: ReadRef<'data>,
+{
+    file: &'file AoutFile<'data, R>,
+    iter: slice::Iter<'data, aout::RelocationInfo<Endianness>>,
+}
+
+impl<'data, 'file, R: ReadRef<'data>> Iterator for AoutRelocationIterator<'data, 'file, R> {
+    type Item = (u64, Relocation);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let endian = self.file.endian;
+        let reloc = self.iter.next()?;
+        let fields = reloc.r_info(endian);
+        let kind = if fields.r_copy {
+            RelocationKind::Aout(reloc.r_info.get(endian))
+        } else if fields.r_jmptable {
+            RelocationKind::PltRelative
+        } else if fields.r_relative {
+            RelocationKind::Rebase
+        } else if fields.r_baserel {
+            if fields.r_pcrel {
+                RelocationKind::GotRelative
+            } else {
+                RelocationKind::Got
+            }
+        } else if fields.r_pcrel {
+            RelocationKind::Relative
+        } else {
+            RelocationKind::Absolute
+        };
+        let target = if fields.r_extern {
+            RelocationTarget::Symbol(SymbolIndex(fields.r_symbolnum as usize))
+        } else {
+            let n_type = fields.r_symbolnum as u8 & aout::N_TYPE;
+            match SECTION_TYPES.iter().position(|t| *t == n_type) {
+                Some(index) => RelocationTarget::Section(SectionIndex(index + 1)),
+                None => RelocationTarget::Absolute,
+            }
+        };
+        Some((
+            reloc.r_address.get(endian).into(),
+            Relocation {
+                kind,
+                encoding: RelocationEncoding::Generic,
+                size: 8 << fields.r_length,
+                target,
+                addend: 0,
+                implicit_addend: true,
+            },
+        ))
+    }
+}
@@ -1,12 +1,16 @@
 //! Support for archive files.
 
+use alloc::vec::Vec;
 use core::convert::TryInto;
+use core::slice;
 
 use crate::archive;
-use crate::read::{self, Bytes, Error, ReadError, ReadRef};
+use crate::endian::{BigEndian as BE, LittleEndian as LE, U16Bytes, U32Bytes, U64Bytes};
+use crate::read::{self, Bytes, Error, ParseOptions, ReadError, ReadRef};
 
 /// The kind of archive format.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum ArchiveKind {
     /// There are no special files that indicate the archive format.
@@ -47,9 +51,21 @@ pub struct ArchiveFile<'data, R: ReadRef<'data> = &'data [u8]> {
     members: Members<'data>,
     symbols: (u64, u64),
     names: &'data [u8],
+    thin: bool,
+    options: ParseOptions,
 }
 
 impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
+    /// Parse the archive header and special members, with limits on parsing.
+    ///
+    /// [`ArchiveFile::resolve_member_data`] follows at most
+    /// [`ParseOptions::max_depth`] nested thin archives.
+    pub fn parse_with_options(data: R, options: &ParseOptions) -> read::Result<Self> {
+        let mut file = Self::parse(data)?;
+        file.options = *options;
+        Ok(file)
+    }
+
     /// Parse the archive header and special members.
     pub fn parse(data: R) -> read::Result<Self> {
         let len = data.len().read_error("Unknown archive length")?;
@@ -58,11 +74,15 @@ impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
             .read_bytes(&mut tail, archive::MAGIC.len() as u64)
             .read_error("Invalid archive size")?;
 
-        if magic == archive::AIX_BIG_MAGIC {
+        let thin = if magic == archive::AIX_BIG_MAGIC {
             return Self::parse_aixbig(data);
-        } else if magic != archive::MAGIC {
+        } else if magic == archive::THIN_MAGIC {
+            true
+        } else if magic == archive::MAGIC {
+            false
+        } else {
             return Err(Error("Unsupported archive identifier"));
-        }
+        };
 
         let mut members_offset = tail;
         let members_end_offset = len;
@@ -76,6 +96,8 @@ impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
             },
             symbols: (0, 0),
             names: &[],
+            thin,
+            options: ParseOptions::default(),
         };
 
         // The first few members may be special, so parse them.
@@ -93,7 +115,7 @@ impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
         // BSD may use the extended name for the symbol table. This is handled
         // by `ArchiveMember::parse`.
         if tail < len {
-            let member = ArchiveMember::parse(data, &mut tail, &[])?;
+            let member = ArchiveMember::parse(data, &mut tail, &[], thin)?;
             if member.name == b"/" {
                 // GNU symbol table (unless we later determine this is COFF).
                 file.kind = ArchiveKind::Gnu;
@@ -101,7 +123,7 @@ impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
                 members_offset = tail;
 
                 if tail < len {
-                    let member = ArchiveMember::parse(data, &mut tail, &[])?;
+                    let member = ArchiveMember::parse(data, &mut tail, &[], thin)?;
                     if member.name == b"/" {
                         // COFF linker member.
                         file.kind = ArchiveKind::Coff;
@@ -109,7 +131,7 @@ impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
                         members_offset = tail;
 
                         if tail < len {
-                            let member = ArchiveMember::parse(data, &mut tail, &[])?;
+                            let member = ArchiveMember::parse(data, &mut tail, &[], thin)?;
                             if member.name == b"//" {
                                 // COFF names table.
                                 file.names = member.data(data)?;
@@ -129,7 +151,7 @@ impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
                 members_offset = tail;
 
                 if tail < len {
-                    let member = ArchiveMember::parse(data, &mut tail, &[])?;
+                    let member = ArchiveMember::parse(data, &mut tail, &[], thin)?;
                     if member.name == b"//" {
                         // GNU names table.
                         file.names = member.data(data)?;
@@ -177,6 +199,8 @@ impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
             members: Members::AixBig { index: &[] },
             symbols: (0, 0),
             names: &[],
+            thin: false,
+            options: ParseOptions::default(),
         };
 
         // Read the span of symbol table.
@@ -234,6 +258,15 @@ impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
         self.kind
     }
 
+    /// Return true if the archive is a thin archive.
+    ///
+    /// The data for the members of a thin archive is stored in external files.
+    /// Use [`Self::resolve_member_data`] to read it.
+    #[inline]
+    pub fn is_thin(&self) -> bool {
+        self.thin
+    }
+
     /// Iterate over the members of the archive.
     ///
     /// This does not return special members.
@@ -243,7 +276,305 @@ impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
             data: self.data,
             members: self.members,
             names: self.names,
+            thin: self.thin,
+        }
+    }
+
+    /// Return the member at the given offset.
+    ///
+    /// The offset is typically obtained from an [`ArchiveSymbol`].
+    pub fn member(&self, member: ArchiveOffset) -> read::Result<ArchiveMember<'data>> {
+        match self.members {
+            Members::Common { offset, end_offset } => {
+                if member.0 < offset || member.0 >= end_offset {
+                    return Err(Error("Invalid archive member offset"));
+                }
+                let mut offset = member.0;
+                ArchiveMember::parse(self.data, &mut offset, self.names, self.thin)
+            }
+            Members::AixBig { .. } => ArchiveMember::parse_aixbig(self.data, member.0),
+        }
+    }
+
+    /// Return the file data for a member, reading external files as needed.
+    ///
+    /// For a member of a thin archive, `read_file` is called with the path of the
+    /// external file, and must return the contents of that file. Relative paths
+    /// are relative to the directory containing this archive. If the member refers
+    /// to a member of a nested archive, then `read_file` is used to read the nested
+    /// archive, and the data is read from the member of that archive, resolving
+    /// any further thin archive members.
+    ///
+    /// For other members, this is the same as [`ArchiveMember::data`], and
+    /// `read_file` is not called.
+    pub fn resolve_member_data<F, RF>(
+        &self,
+        member: &ArchiveMember<'data>,
+        mut read_file: F,
+    ) -> read::Result<&'data [u8]>
+    where
+        F: FnMut(&[u8]) -> read::Result<RF>,
+        RF: ReadRef<'data>,
+    {
+        let options = &self.options;
+        resolve_member_data(self.data, member, &[], &mut read_file, options, 0)
+    }
+
+    /// Iterate over the symbols in the archive symbol table.
+    ///
+    /// Returns `Ok(None)` if the archive does not have a symbol table.
+    ///
+    /// The GNU and BSD 32-bit and 64-bit symbol tables, and the COFF second linker member,
+    /// are supported.
+    pub fn symbols(&self) -> read::Result<Option<ArchiveSymbolIterator<'data>>> {
+        let (offset, size) = self.symbols;
+        if offset == 0 {
+            return Ok(None);
+        }
+        let mut data = self
+            .data
+            .read_bytes_at(offset, size)
+            .map(Bytes)
+            .read_error("Invalid archive symbol table size or offset")?;
+        let symbols = match self.kind {
+            ArchiveKind::Gnu => {
+                let count = data
+                    .read::<U32Bytes<BE>>()
+                    .read_error("Missing archive symbol table count")?
+                    .get(BE);
+                let offsets = data
+                    .read_slice::<U32Bytes<BE>>(count as usize)
+                    .read_error("Invalid archive symbol table count")?;
+                SymbolIteratorInternal::Gnu {
+                    offsets: offsets.iter(),
+                    names: data,
+                }
+            }
+            ArchiveKind::Gnu64 => {
+                let count = data
+                    .read::<U64Bytes<BE>>()
+                    .read_error("Missing archive symbol table count")?
+                    .get(BE);
+                let count = count
+                    .try_into()
+                    .ok()
+                    .read_error("Invalid archive symbol table count")?;
+                let offsets = data
+                    .read_slice::<U64Bytes<BE>>(count)
+                    .read_error("Invalid archive symbol table count")?;
+                SymbolIteratorInternal::Gnu64 {
+                    offsets: offsets.iter(),
+                    names: data,
+                }
+            }
+            ArchiveKind::Coff => {
+                let member_count = data
+                    .read::<U32Bytes<LE>>()
+                    .read_error("Missing archive linker member count")?
+                    .get(LE);
+                let members = data
+                    .read_slice::<U32Bytes<LE>>(member_count as usize)
+                    .read_error("Invalid archive linker member count")?;
+                let count = data
+                    .read::<U32Bytes<LE>>()
+                    .read_error("Missing archive symbol table count")?
+                    .get(LE);
+                let indices = data
+                    .read_slice::<U16Bytes<LE>>(count as usize)
+                    .read_error("Invalid archive symbol table count")?;
+                SymbolIteratorInternal::Coff {
+                    members,
+                    indices: indices.iter(),
+                    names: data,
+                }
+            }
+            ArchiveKind::Bsd => {
+                let ranlib_size = data
+                    .read::<U32Bytes<LE>>()
+                    .read_error("Missing archive symbol table size")?
+                    .get(LE);
+                let ranlibs = data
+                    .read_slice::<U32Bytes<LE>>(ranlib_size as usize / 4)
+                    .read_error("Invalid archive symbol table size")?;
+                let strtab_size = data
+                    .read::<U32Bytes<LE>>()
+                    .read_error("Missing archive symbol string table size")?
+                    .get(LE);
+                let names = data
+                    .read_bytes(strtab_size as usize)
+                    .read_error("Invalid archive symbol string table size")?;
+                SymbolIteratorInternal::Bsd {
+                    ranlibs: ranlibs.iter(),
+                    names,
+                }
+            }
+            ArchiveKind::Bsd64 => {
+                let ranlib_size = data
+                    .read::<U64Bytes<LE>>()
+                    .read_error("Missing archive symbol table size")?
+                    .get(LE);
+                let ranlib_count = (ranlib_size / 8)
+                    .try_into()
+                    .ok()
+                    .read_error("Invalid archive symbol table size")?;
+                let ranlibs = data
+                    .read_slice::<U64Bytes<LE>>(ranlib_count)
+                    .read_error("Invalid archive symbol table size")?;
+                let strtab_size = data
+                    .read::<U64Bytes<LE>>()
+                    .read_error("Missing archive symbol string table size")?
+                    .get(LE);
+                let strtab_size = strtab_size
+                    .try_into()
+                    .ok()
+                    .read_error("Invalid archive symbol string table size")?;
+                let names = data
+                    .read_bytes(strtab_size)
+                    .read_error("Invalid archive symbol string table size")?;
+                SymbolIteratorInternal::Bsd64 {
+                    ranlibs: ranlibs.iter(),
+                    names,
+                }
+            }
+            _ => return Err(Error("Unsupported archive symbol table")),
+        };
+        Ok(Some(ArchiveSymbolIterator(symbols)))
+    }
+}
+
+/// An offset of a member in an archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArchiveOffset(pub u64);
+
+/// An iterator over the symbols in the archive symbol table.
+#[derive(Debug, Clone)]
+pub struct ArchiveSymbolIterator<'data>(SymbolIteratorInternal<'data>);
+
+#[derive(Debug, Clone)]
+enum SymbolIteratorInternal<'data> {
+    Gnu {
+        offsets: slice::Iter<'data, U32Bytes<BE>>,
+        names: Bytes<'data>,
+    },
+    Gnu64 {
+        offsets: slice::Iter<'data, U64Bytes<BE>>,
+        names: Bytes<'data>,
+    },
+    Coff {
+        members: &'data [U32Bytes<LE>],
+        indices: slice::Iter<'data, U16Bytes<LE>>,
+        names: Bytes<'data>,
+    },
+    // Each ranlib is a pair of string offset and member offset.
+    Bsd {
+        ranlibs: slice::Iter<'data, U32Bytes<LE>>,
+        names: Bytes<'data>,
+    },
+    Bsd64 {
+        ranlibs: slice::Iter<'data, U64Bytes<LE>>,
+        names: Bytes<'data>,
+    },
+}
+
+impl<'data> ArchiveSymbolIterator<'data> {
+    fn next_symbol(&mut self) -> Option<read::Result<ArchiveSymbol<'data>>> {
+        let (offset, names) = match &mut self.0 {
+            SymbolIteratorInternal::Gnu { offsets, names } => {
+                (u64::from(offsets.next()?.get(BE)), names)
+            }
+            SymbolIteratorInternal::Gnu64 { offsets, names } => (offsets.next()?.get(BE), names),
+            SymbolIteratorInternal::Coff {
+                members,
+                indices,
+                names,
+            } => {
+                // The member indices are 1-based.
+                let index = indices.next()?.get(LE);
+                let offset = match members.get(usize::from(index).wrapping_sub(1)) {
+                    Some(offset) => offset.get(LE),
+                    None => return Some(Err(Error("Invalid archive symbol member index"))),
+                };
+                (u64::from(offset), names)
+            }
+            SymbolIteratorInternal::Bsd { ranlibs, names } => {
+                let name = ranlibs.next()?.get(LE);
+                let offset = ranlibs.next()?.get(LE);
+                let name = match names.read_string_at(name as usize) {
+                    Ok(name) => name,
+                    Err(()) => return Some(Err(Error("Invalid archive symbol name offset"))),
+                };
+                return Some(Ok(ArchiveSymbol {
+                    name,
+                    offset: ArchiveOffset(u64::from(offset)),
+                }));
+            }
+            SymbolIteratorInternal::Bsd64 { ranlibs, names } => {
+                let name = ranlibs.next()?.get(LE);
+                let offset = ranlibs.next()?.get(LE);
+                let name = match name
+                    .try_into()
+                    .ok()
+                    .and_then(|name| names.read_string_at(name).ok())
+                {
+                    Some(name) => name,
+                    None => return Some(Err(Error("Invalid archive symbol name offset"))),
+                };
+                return Some(Ok(ArchiveSymbol {
+                    name,
+                    offset: ArchiveOffset(offset),
+                }));
+            }
+        };
+        let name = match names.read_string() {
+            Ok(name) => name,
+            Err(()) => return Some(Err(Error("Missing archive symbol name"))),
+        };
+        Some(Ok(ArchiveSymbol {
+            name,
+            offset: ArchiveOffset(offset),
+        }))
+    }
+}
+
+impl<'data> Iterator for ArchiveSymbolIterator<'data> {
+    type Item = read::Result<ArchiveSymbol<'data>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let symbol = self.next_symbol();
+        if let Some(Err(_)) = symbol {
+            // Stop iterating after an error.
+            match &mut self.0 {
+                SymbolIteratorInternal::Gnu { offsets, .. } => *offsets = [].iter(),
+                SymbolIteratorInternal::Gnu64 { offsets, .. } => *offsets = [].iter(),
+                SymbolIteratorInternal::Coff { indices, .. } => *indices = [].iter(),
+                SymbolIteratorInternal::Bsd { ranlibs, .. } => *ranlibs = [].iter(),
+                SymbolIteratorInternal::Bsd64 { ranlibs, .. } => *ranlibs = [].iter(),
+            }
         }
+        symbol
+    }
+}
+
+/// A symbol in the archive symbol table.
+#[derive(Debug, Clone, Copy)]
+pub struct ArchiveSymbol<'data> {
+    name: &'data [u8],
+    offset: ArchiveOffset,
+}
+
+impl<'data> ArchiveSymbol<'data> {
+    /// Return the symbol name.
+    #[inline]
+    pub fn name(&self) -> &'data [u8] {
+        self.name
+    }
+
+    /// Return the offset of the header for the member that defines the symbol.
+    ///
+    /// Use [`ArchiveFile::member`] to parse the member.
+    #[inline]
+    pub fn offset(&self) -> ArchiveOffset {
+        self.offset
     }
 }
 
@@ -253,6 +584,7 @@ pub struct ArchiveMemberIterator<'data, R: ReadRef<'data> = &'data [u8]> {
     data: R,
     members: Members<'data>,
     names: &'data [u8],
+    thin: bool,
 }
 
 impl<'data, R: ReadRef<'data>> Iterator for ArchiveMemberIterator<'data, R> {
@@ -267,7 +599,7 @@ impl<'data, R: ReadRef<'data>> Iterator for ArchiveMemberIterator<'data, R> {
                 if *offset >= *end_offset {
                     return None;
                 }
-                let member = ArchiveMember::parse(self.data, offset, self.names);
+                let member = ArchiveMember::parse(self.data, offset, self.names, self.thin);
                 if member.is_err() {
                     *offset = *end_offset;
                 }
@@ -304,16 +636,22 @@ pub struct ArchiveMember<'data> {
     name: &'data [u8],
     offset: u64,
     size: u64,
+    thin: bool,
+    nested_offset: Option<u64>,
 }
 
 impl<'data> ArchiveMember<'data> {
     /// Parse the member header, name, and file data in an archive with the common format.
     ///
     /// This reads the extended name (if any) and adjusts the file size.
+    ///
+    /// If `thin` is true, then the file data for members other than the
+    /// special members is not stored in the archive.
     fn parse<R: ReadRef<'data>>(
         data: R,
         offset: &mut u64,
         names: &'data [u8],
+        thin: bool,
     ) -> read::Result<Self> {
         let header = data
             .read::<archive::Header>(offset)
@@ -322,20 +660,40 @@ impl<'data> ArchiveMember<'data> {
             return Err(Error("Invalid archive terminator"));
         }
 
+        // The special members are the only members that start with '/'
+        // but don't use an extended name.
+        let special = header.name[0] == b'/' && !(header.name[1] as char).is_ascii_digit();
+        let thin = thin && !special;
+
         let mut file_offset = *offset;
         let mut file_size =
             parse_u64_digits(&header.size, 10).read_error("Invalid archive member size")?;
-        *offset = offset
-            .checked_add(file_size)
-            .read_error("Archive member size is too large")?;
-        // Entries are padded to an even number of bytes.
-        if (file_size & 1) != 0 {
-            *offset = offset.saturating_add(1);
+        if !thin {
+            *offset = offset
+                .checked_add(file_size)
+                .read_error("Archive member size is too large")?;
+            // Entries are padded to an even number of bytes.
+            if (file_size & 1) != 0 {
+                *offset = offset.saturating_add(1);
+            }
         }
 
+        let mut nested_offset = None;
         let name = if header.name[0] == b'/' && (header.name[1] as char).is_ascii_digit() {
+            let mut digits = &header.name[1..];
+            if thin {
+                // A member of a nested archive is given by the name of the
+                // nested archive, followed by the offset of the member.
+                if let Some(colon) = memchr::memchr(b':', digits) {
+                    nested_offset = Some(
+                        parse_u64_digits(&digits[colon + 1..], 10)
+                            .read_error("Invalid thin archive nested member offset")?,
+                    );
+                    digits = &digits[..colon];
+                }
+            }
             // Read file name from the names table.
-            parse_sysv_extended_name(&header.name[1..], names)
+            parse_sysv_extended_name(digits, names, thin)
                 .read_error("Invalid archive extended name offset")?
         } else if &header.name[..3] == b"#1/" && (header.name[3] as char).is_ascii_digit() {
             // Read file name from the start of the file data.
@@ -356,6 +714,8 @@ impl<'data> ArchiveMember<'data> {
             name,
             offset: file_offset,
             size: file_size,
+            thin,
+            nested_offset,
         })
     }
 
@@ -404,6 +764,8 @@ impl<'data> ArchiveMember<'data> {
             name,
             offset,
             size,
+            thin: false,
+            nested_offset: None,
         })
     }
 
@@ -473,19 +835,88 @@ impl<'data> ArchiveMember<'data> {
         }
     }
 
+    /// Return true if the member is in a thin archive.
+    ///
+    /// The file data for the member is stored in an external file, which is
+    /// given by the member name.
+    #[inline]
+    pub fn is_thin(&self) -> bool {
+        self.thin
+    }
+
+    /// Return the offset of the member within a nested archive.
+    ///
+    /// This is only set for thin archive members that refer to a member of
+    /// another archive. The member name is the path of the nested archive.
+    #[inline]
+    pub fn nested_offset(&self) -> Option<ArchiveOffset> {
+        self.nested_offset.map(ArchiveOffset)
+    }
+
     /// Return the offset and size of the file data.
+    ///
+    /// For a thin archive member, the offset is the end of the member header,
+    /// and the size is the size of the external file.
     pub fn file_range(&self) -> (u64, u64) {
         (self.offset, self.size)
     }
 
     /// Return the file data.
+    ///
+    /// Returns an error for a thin archive member.
+    /// Use [`ArchiveFile::resolve_member_data`] instead.
     #[inline]
     pub fn data<R: ReadRef<'data>>(&self, data: R) -> read::Result<&'data [u8]> {
+        if self.thin {
+            return Err(Error(
+                "Thin archive member data is not stored in the archive",
+            ));
+        }
         data.read_bytes_at(self.offset, self.size)
             .read_error("Archive member size is too large")
     }
 }
 
+fn resolve_member_data<'data, R, F, RF>(
+    data: R,
+    member: &ArchiveMember<'data>,
+    directory: &[u8],
+    read_file: &mut F,
+    options: &ParseOptions,
+    depth: u32,
+) -> read::Result<&'data [u8]>
+where
+    R: ReadRef<'data>,
+    F: FnMut(&[u8]) -> read::Result<RF>,
+    RF: ReadRef<'data>,
+{
+    if !member.thin {
+        return member.data(data);
+    }
+    let mut path = Vec::new();
+    if member.name.first() != Some(&b'/') {
+        path.extend_from_slice(directory);
+    }
+    path.extend_from_slice(member.name);
+    let file = read_file(&path)?;
+    match member.nested_offset {
+        None => file
+            .read_bytes_at(0, member.size)
+            .read_error("Thin archive member size is too large"),
+        Some(offset) => {
+            options
+                .check_depth(depth + 1)
+                .read_error("Too many nested thin archives")?;
+            let archive = ArchiveFile::parse(file)?;
+            let nested = archive.member(ArchiveOffset(offset))?;
+            // Members of a nested thin archive are relative to its directory.
+            let directory_len = path.iter().rposition(|&c| c == b'/').map_or(0, |i| i + 1);
+            path.truncate(directory_len);
+            resolve_member_data(file, &nested, &path, read_file, options, depth + 1)
+        }
+    }
+}
+
 // Ignores bytes starting from the first space.
 fn parse_u64_digits(digits: &[u8], radix: u32) -> Option<u64> {
     if let [b' ', ..] = digits {
@@ -505,13 +936,26 @@ fn parse_u64_digits(digits: &[u8], radix: u32) -> Option<u64> {
     Some(result)
 }
 
-fn parse_sysv_extended_name<'data>(digits: &[u8], names: &'data [u8]) -> Result<&'data [u8], ()> {
+/// Thin archive names are paths that may contain '/', so they are terminated by "/\n".
+fn parse_sysv_extended_name<'data>(
+    digits: &[u8],
+    names: &'data [u8],
+    thin: bool,
+) -> Result<&'data [u8], ()> {
     let offset = parse_u64_digits(digits, 10).ok_or(())?;
     let offset = offset.try_into().map_err(|_| ())?;
     let name_data = names.get(offset..).ok_or(())?;
-    let name = match memchr::memchr2(b'/', b'\0', name_data) {
-        Some(len) => &name_data[..len],
-        None => name_data,
+    let name = if thin {
+        let name = match memchr::memchr(b'\n', name_data) {
+            Some(len) => &name_data[..len],
+            None => name_data,
+        };
+        name.strip_suffix(b"/").unwrap_or(name)
+    } else {
+        match memchr::memchr2(b'/', b'\0', name_data) {
+            Some(len) => &name_data[..len],
+            None => name_data,
+        }
     };
     Ok(name)
 }
@@ -649,6 +1093,30 @@ mod tests {
         assert_eq!(archive.kind(), ArchiveKind::AixBig);
     }
 
+    #[test]
+    fn coff_symbols() {
+        let data = b"\
+            !<arch>\n\
+            /               0           0     0     0       4         `\n\
+            \0\0\0\0\
+            /               0           0     0     0       18        `\n\
+            \x01\0\0\0\x96\0\0\0\x01\0\0\0\x01\0sym\0\
+            a.obj/          0           0     0     644     4         `\n\
+            0000";
+        let data = &data[..];
+        let archive = ArchiveFile::parse(data).unwrap();
+        assert_eq!(archive.kind(), ArchiveKind::Coff);
+        let mut symbols = archive.symbols().unwrap().unwrap();
+
+        let symbol = symbols.next().unwrap().unwrap();
+        assert_eq!(symbol.name(), b"sym");
+        assert_eq!(symbol.offset(), ArchiveOffset(150));
+        let member = archive.member(symbol.offset()).unwrap();
+        assert_eq!(member.name(), b"a.obj");
+
+        assert!(symbols.next().is_none());
+    }
+
     #[test]
     fn gnu_names() {
         let data = b"\
@@ -705,6 +1173,65 @@ mod tests {
         assert!(members.next().is_none());
     }
 
+    #[test]
+    fn thin_members() {
+        let data = b"\
+            !<thin>\n\
+            //                                              35        `\n\
+            dir/a.o/\n\
+            lib/nested.a/\n\
+            lib/thin.a/\n\n\
+            /0              0           0     0     644     3         `\n\
+            /9:8            0           0     0     644     4         `\n\
+            /23:8           0           0     0     644     5         `\n";
+        let nested = b"\
+            !<arch>\n\
+            b.o/            0           0     0     644     4         `\n\
+            even";
+        let nested_thin = b"\
+            !<thin>\n\
+            c.o/            0           0     0     644     5         `\n";
+        let read_file = |path: &[u8]| match path {
+            b"dir/a.o" => Ok(&b"odd"[..]),
+            b"lib/nested.a" => Ok(&nested[..]),
+            b"lib/thin.a" => Ok(&nested_thin[..]),
+            b"lib/c.o" => Ok(&b"hello"[..]),
+            _ => Err(Error("Unknown file")),
+        };
+        let data = &data[..];
+        let archive = ArchiveFile::parse(data).unwrap();
+        assert_eq!(archive.kind(), ArchiveKind::Gnu);
+        assert!(archive.is_thin());
+        let mut members = archive.members();
+
+        let member = members.next().unwrap().unwrap();
+        assert_eq!(member.name(), b"dir/a.o");
+        assert!(member.is_thin());
+        assert_eq!(member.nested_offset(), None);
+        assert!(member.data(data).is_err());
+        assert_eq!(
+            archive.resolve_member_data(&member, read_file).unwrap(),
+            &b"odd"[..]
+        );
+
+        let member = members.next().unwrap().unwrap();
+        assert_eq!(member.name(), b"lib/nested.a");
+        assert_eq!(member.nested_offset(), Some(ArchiveOffset(8)));
+        assert_eq!(
+            archive.resolve_member_data(&member, read_file).unwrap(),
+            &b"even"[..]
+        );
+
+        let member = members.next().unwrap().unwrap();
+        assert_eq!(member.name(), b"lib/thin.a");
+        assert_eq!(
+            archive.resolve_member_data(&member, read_file).unwrap(),
+            &b"hello"[..]
+        );
+
+        assert!(members.next().is_none());
+    }
+
     #[test]
     fn aix_names() {
         let data = b"\
@@ -0,0 +1,445 @@
+//! Support for reading the symbol table of LLVM bitcode files.
+//!
+//! Provides `BitcodeFile`, which locates the symbol table and string table
+//! blocks of a bitcode file, and `BitcodeSymbol`, which describes a symbol in
+//! the symbol table.
+//!
+//! The symbol table is written by LLVM 5 and later, and is the same table that
+//! is used by linkers and archivers when performing link time optimization.
+//! This does not parse the IR in the module blocks.
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+use crate::read::{Error, ReadError, ReadRef, Result};
+use crate::SymbolScope;
+
+/// The magic number of a bitcode wrapper header.
+const WRAPPER_MAGIC: [u8; 4] = [0xde, 0xc0, 0x17, 0x0b];
+/// The magic number of a raw bitcode file.
+const BITCODE_MAGIC: [u8; 4] = [b'B', b'C', 0xc0, 0xde];
+
+const END_BLOCK: u64 = 0;
+const ENTER_SUBBLOCK: u64 = 1;
+const DEFINE_ABBREV: u64 = 2;
+const UNABBREV_RECORD: u64 = 3;
+
+const STRTAB_BLOCK_ID: u64 = 23;
+const SYMTAB_BLOCK_ID: u64 = 25;
+/// The record code of the blob in the string table and symbol table blocks.
+const BLOB_RECORD: u64 = 1;
+
+/// The size of `irsymtab::storage::Symbol`.
+const SYMBOL_SIZE: usize = 24;
+
+const FB_VISIBILITY: u32 = 0;
+const FB_UNDEFINED: u32 = 3;
+const FB_WEAK: u32 = 4;
+const FB_COMMON: u32 = 5;
+const FB_INDIRECT: u32 = 6;
+const FB_TLS: u32 = 8;
+const FB_GLOBAL: u32 = 10;
+const FB_FORMAT_SPECIFIC: u32 = 11;
+const FB_EXECUTABLE: u32 = 13;
+
+/// An LLVM bitcode file.
+///
+/// Only the symbol table is supported.
+#[derive(Debug, Clone, Copy)]
+pub struct BitcodeFile<'data> {
+    symtab: &'data [u8],
+    strtab: &'data [u8],
+}
+
+impl<'data> BitcodeFile<'data> {
+    /// Parse the raw bitcode data, which may have a bitcode wrapper header.
+    ///
+    /// Returns an error if the file does not contain a symbol table.
+    pub fn parse<R: ReadRef<'data>>(data: R) -> Result<Self> {
+        let len = data.len().read_error("Unknown bitcode file size")?;
+        let mut data = data
+            .read_bytes_at(0, len)
+            .read_error("Bitcode read failed")?;
+        if data.starts_with(&WRAPPER_MAGIC) {
+            let offset = read_u32(data, 8).read_error("Invalid bitcode wrapper header")?;
+            let size = read_u32(data, 12).read_error("Invalid bitcode wrapper header")?;
+            data = data
+                .read_bytes_at(offset.into(), size.into())
+                .read_error("Invalid bitcode wrapper offset or size")?;
+        }
+        if !data.starts_with(&BITCODE_MAGIC) {
+            return Err(Error("Unsupported bitcode magic"));
+        }
+
+        let mut symtab = None;
+        let mut strtab = None;
+        let mut reader = BitReader { data, pos: 32 };
+        while reader.remaining() >= 32 {
+            // The top level abbreviation width is 2, and only blocks are allowed.
+            if reader.read(2)? != ENTER_SUBBLOCK {
+                break;
+            }
+            let (block_id, width, block) = reader.enter_block()?;
+            match block_id {
+                SYMTAB_BLOCK_ID if symtab.is_none() => symtab = read_blob(block, width)?,
+                STRTAB_BLOCK_ID if strtab.is_none() => strtab = read_blob(block, width)?,
+                _ => {}
+            }
+        }
+        Ok(BitcodeFile {
+            symtab: symtab.read_error("Missing bitcode symbol table")?,
+            strtab: strtab.read_error("Missing bitcode string table")?,
+        })
+    }
+
+    /// Return the target triple of the modules in the file.
+    pub fn target_triple(&self) -> Result<&'data [u8]> {
+        // `irsymtab::storage::Header::TargetTriple`
+        self.header_str(44)
+    }
+
+    /// Return the source file name of the first module in the file.
+    pub fn source_filename(&self) -> Result<&'data [u8]> {
+        // `irsymtab::storage::Header::SourceFileName`
+        self.header_str(52)
+    }
+
+    fn header_str(&self, offset: usize) -> Result<&'data [u8]> {
+        self.str_at(self.symtab, offset)
+            .read_error("Invalid bitcode symbol table header")
+    }
+
+    fn str_at(&self, data: &[u8], offset: usize) -> Option<&'data [u8]> {
+        let str_offset = read_u32(data, offset)?;
+        let str_size = read_u32(data, offset + 4)?;
+        self.strtab
+            .read_bytes_at(str_offset.into(), str_size.into())
+            .ok()
+    }
+
+    /// Return an iterator over the symbols in the symbol table.
+    pub fn symbols(&self) -> Result<BitcodeSymbolIterator<'data>> {
+        // `irsymtab::storage::Header::Symbols`
+        let symbols = (|| {
+            let offset = read_u32(self.symtab, 28)?;
+            let count = read_u32(self.symtab, 32)?;
+            let size = (count as usize).checked_mul(SYMBOL_SIZE)?;
+            self.symtab.read_bytes_at(offset.into(), size as u64).ok()
+        })()
+        .read_error("Invalid bitcode symbol table")?;
+        Ok(BitcodeSymbolIterator {
+            file: *self,
+            symbols: symbols.chunks_exact(SYMBOL_SIZE),
+        })
+    }
+}
+
+/// An iterator over the symbols of a `BitcodeFile`.
+#[derive(Debug)]
+pub struct BitcodeSymbolIterator<'data> {
+    file: BitcodeFile<'data>,
+    symbols: core::slice::ChunksExact<'data, u8>,
+}
+
+impl<'data> Iterator for BitcodeSymbolIterator<'data> {
+    type Item = Result<BitcodeSymbol<'data>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let symbol = self.symbols.next()?;
+        Some(
+            (|| {
+                Some(BitcodeSymbol {
+                    name: self.file.str_at(symbol, 0)?,
+                    ir_name: self.file.str_at(symbol, 8)?,
+                    flags: read_u32(symbol, 20)?,
+                })
+            })()
+            .read_error("Invalid bitcode symbol"),
+        )
+    }
+}
+
+/// A symbol in the symbol table of a `BitcodeFile`.
+#[derive(Debug, Clone, Copy)]
+pub struct BitcodeSymbol<'data> {
+    name: &'data [u8],
+    ir_name: &'data [u8],
+    flags: u32,
+}
+
+impl<'data> BitcodeSymbol<'data> {
+    /// Return the name of the symbol, as it will appear in the object file.
+    ///
+    /// This includes any mangling, such as a leading underscore for Mach-O.
+    #[inline]
+    pub fn name(&self) -> &'data [u8] {
+        self.name
+    }
+
+    /// Return the name of the symbol in the IR.
+    ///
+    /// This is empty for symbols that are not IR global values, such as
+    /// symbols defined by module level inline assembly.
+    #[inline]
+    pub fn ir_name(&self) -> &'data [u8] {
+        self.ir_name
+    }
+
+    /// Return the raw `irsymtab::storage::Symbol::Flags` value.
+    #[inline]
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+
+    fn flag(&self, bit: u32) -> bool {
+        self.flags & (1 << bit) != 0
+    }
+
+    /// Return true if the symbol is undefined.
+    #[inline]
+    pub fn is_undefined(&self) -> bool {
+        self.flag(FB_UNDEFINED)
+    }
+
+    /// Return true if the symbol is weak.
+    #[inline]
+    pub fn is_weak(&self) -> bool {
+        self.flag(FB_WEAK)
+    }
+
+    /// Return true if the symbol is a common symbol.
+    #[inline]
+    pub fn is_common(&self) -> bool {
+        self.flag(FB_COMMON)
+    }
+
+    /// Return true if the symbol is an alias to another symbol.
+    #[inline]
+    pub fn is_indirect(&self) -> bool {
+        self.flag(FB_INDIRECT)
+    }
+
+    /// Return true if the symbol is a thread local variable.
+    #[inline]
+    pub fn is_tls(&self) -> bool {
+        self.flag(FB_TLS)
+    }
+
+    /// Return true if the symbol is a function.
+    #[inline]
+    pub fn is_executable(&self) -> bool {
+        self.flag(FB_EXECUTABLE)
+    }
+
+    /// Return true if the symbol is visible outside of the module.
+    #[inline]
+    pub fn is_global(&self) -> bool {
+        self.flag(FB_GLOBAL)
+    }
+
+    /// Return true if the symbol is specific to the object file format and
+    /// should not be added to an archive symbol table, such as `llvm.*` symbols.
+    #[inline]
+    pub fn is_format_specific(&self) -> bool {
+        self.flag(FB_FORMAT_SPECIFIC)
+    }
+
+    /// Return the scope of the symbol.
+    pub fn scope(&self) -> SymbolScope {
+        if !self.is_global() {
+            SymbolScope::Compilation
+        } else if (self.flags >> FB_VISIBILITY) & 3 == 0 {
+            // Default visibility.
+            SymbolScope::Dynamic
+        } else {
+            SymbolScope::Linkage
+        }
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..)?.get(..4)?;
+    Some(u32::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+/// Read the blob of the first blob record in a block.
+fn read_blob<'data>(mut reader: BitReader<'data>, width: u32) -> Result<Option<&'data [u8]>> {
+    let mut abbrevs = Vec::new();
+    while reader.remaining() != 0 {
+        match reader.read(width)? {
+            END_BLOCK => break,
+            ENTER_SUBBLOCK => {
+                reader.enter_block()?;
+            }
+            DEFINE_ABBREV => {
+                let count = reader.read_vbr(5)?;
+                let mut ops = Vec::new();
+                for _ in 0..count {
+                    let op = if reader.read(1)? != 0 {
+                        AbbrevOp::Literal(reader.read_vbr(8)?)
+                    } else {
+                        match reader.read(3)? {
+                            1 => AbbrevOp::Fixed(reader.read_vbr(5)? as u32),
+                            2 => AbbrevOp::Vbr(reader.read_vbr(5)? as u32),
+                            3 => AbbrevOp::Array,
+                            4 => AbbrevOp::Char6,
+                            5 => AbbrevOp::Blob,
+                            _ => return Err(Error("Invalid bitcode abbreviation encoding")),
+                        }
+                    };
+                    ops.push(op);
+                }
+                abbrevs.push(ops);
+            }
+            UNABBREV_RECORD => {
+                reader.read_vbr(6)?;
+                let count = reader.read_vbr(6)?;
+                for _ in 0..count {
+                    reader.read_vbr(6)?;
+                }
+            }
+            id => {
+                let ops = abbrevs
+                    .get((id - 4) as usize)
+                    .read_error("Invalid bitcode abbreviation id")?;
+                let mut code = None;
+                let mut blob = None;
+                let mut ops = ops.iter();
+                while let Some(op) = ops.next() {
+                    match op {
+                        AbbrevOp::Array => {
+                            let element = ops.next().read_error("Invalid bitcode array")?;
+                            // Require each element to read at least one bit, so that
+                            // the array length is bounded by the remaining data.
+                            if let AbbrevOp::Literal(_) | AbbrevOp::Fixed(0) = element {
+                                return Err(Error("Invalid bitcode array element"));
+                            }
+                            let count = reader.read_vbr(6)?;
+                            if count > reader.remaining() as u64 {
+                                return Err(Error("Invalid bitcode array length"));
+                            }
+                            for _ in 0..count {
+                                reader.read_scalar(element)?;
+                            }
+                        }
+                        AbbrevOp::Blob => {
+                            let len = reader.read_vbr(6)?;
+                            blob = Some(reader.read_blob(len)?);
+                        }
+                        op => {
+                            let value = reader.read_scalar(op)?;
+                            code.get_or_insert(value);
+                        }
+                    }
+                }
+                if code == Some(BLOB_RECORD) && blob.is_some() {
+                    return Ok(blob);
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum AbbrevOp {
+    Literal(u64),
+    Fixed(u32),
+    Vbr(u32),
+    Array,
+    Char6,
+    Blob,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BitReader<'data> {
+    data: &'data [u8],
+    /// The current position in bits.
+    pos: usize,
+}
+
+impl<'data> BitReader<'data> {
+    fn remaining(&self) -> usize {
+        (self.data.len() * 8).saturating_sub(self.pos)
+    }
+
+    fn read(&mut self, width: u32) -> Result<u64> {
+        let mut value = 0;
+        let mut done = 0;
+        while done < width {
+            let byte = *self
+                .data
+                .get(self.pos / 8)
+                .read_error("Unexpected end of bitcode")?;
+            let shift = (self.pos % 8) as u32;
+            let count = (8 - shift).min(width - done);
+            let bits = (u64::from(byte) >> shift) & ((1 << count) - 1);
+            value |= bits << done;
+            done += count;
+            self.pos += count as usize;
+        }
+        Ok(value)
+    }
+
+    fn read_vbr(&mut self, width: u32) -> Result<u64> {
+        let high = 1 << (width - 1);
+        let mut value = 0;
+        let mut shift = 0;
+        loop {
+            let chunk = self.read(width)?;
+            if shift >= 64 {
+                return Err(Error("Invalid bitcode VBR value"));
+            }
+            value |= (chunk & (high - 1)) << shift;
+            if chunk & high == 0 {
+                return Ok(value);
+            }
+            shift += width - 1;
+        }
+    }
+
+    fn read_scalar(&mut self, op: &AbbrevOp) -> Result<u64> {
+        match *op {
+            AbbrevOp::Literal(value) => Ok(value),
+            AbbrevOp::Fixed(width) if width <= 64 => self.read(width),
+            AbbrevOp::Vbr(width) if (2..=32).contains(&width) => self.read_vbr(width),
+            AbbrevOp::Char6 => self.read(6),
+            _ => Err(Error("Invalid bitcode abbreviation operand")),
+        }
+    }
+
+    fn align32(&mut self) {
+        self.pos = (self.pos + 31) & !31;
+    }
+
+    fn read_blob(&mut self, len: u64) -> Result<&'data [u8]> {
+        self.align32();
+        let blob = self
+            .data
+            .read_bytes_at((self.pos / 8) as u64, len)
+            .read_error("Invalid bitcode blob size")?;
+        self.pos += blob.len() * 8;
+        self.align32();
+        Ok(blob)
+    }
+
+    /// Read the header of a block after the `ENTER_SUBBLOCK` abbreviation id,
+    /// and skip to the end of the block.
+    ///
+    /// Returns the block id, the abbreviation width and a reader for the block.
+    fn enter_block(&mut self) -> Result<(u64, u32, BitReader<'data>)> {
+        let block_id = self.read_vbr(8)?;
+        let width = self.read_vbr(4)?;
+        if !(1..=32).contains(&width) {
+            return Err(Error("Invalid bitcode abbreviation width"));
+        }
+        self.align32();
+        let words = self.read(32)?;
+        let start = self.pos / 8;
+        let data = self
+            .data
+            .read_bytes_at(start as u64, words * 4)
+            .read_error("Invalid bitcode block size")?;
+        self.pos += data.len() * 8;
+        Ok((block_id, width as u32, BitReader { data, pos: 0 }))
+    }
+}
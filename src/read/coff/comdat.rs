@@ -1,3 +1,6 @@
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
 use core::str;
 
 use crate::endian::LittleEndian as LE;
@@ -22,6 +25,8 @@ pub struct CoffComdatIterator<
 > {
     pub(super) file: &'file CoffFile<'data, R, Coff>,
     pub(super) index: usize,
+    /// The associative section definitions, found when the first COMDAT is returned.
+    pub(super) associations: Option<Rc<[(i32, i32)]>>,
 }
 
 impl<'data, 'file, R: ReadRef<'data>, Coff: CoffHeader> Iterator
@@ -34,7 +39,9 @@ impl<'data, 'file, R: ReadRef<'data>, Coff: CoffHeader> Iterator
             let index = self.index;
             let symbol = self.file.common.symbols.symbol(index).ok()?;
             self.index += 1 + symbol.number_of_aux_symbols() as usize;
-            if let Some(comdat) = CoffComdat::parse(self.file, symbol, index) {
+            if let Some(comdat) =
+                CoffComdat::parse(self.file, symbol, index, &mut self.associations)
+            {
                 return Some(comdat);
             }
         }
@@ -57,6 +64,7 @@ pub struct CoffComdat<
     symbol_index: SymbolIndex,
     symbol: &'data Coff::ImageSymbol,
     selection: u8,
+    associations: Rc<[(i32, i32)]>,
 }
 
 impl<'data, 'file, R: ReadRef<'data>, Coff: CoffHeader> CoffComdat<'data, 'file, R, Coff> {
@@ -64,6 +72,7 @@ impl<'data, 'file, R: ReadRef<'data>, Coff: CoffHeader> CoffComdat<'data, 'file,
         file: &'file CoffFile<'data, R, Coff>,
         section_symbol: &'data Coff::ImageSymbol,
         index: usize,
+        associations: &mut Option<Rc<[(i32, i32)]>>,
     ) -> Option<CoffComdat<'data, 'file, R, Coff>> {
         // Must be a section symbol.
         if !section_symbol.has_aux_section() {
@@ -89,13 +98,34 @@ impl<'data, 'file, R: ReadRef<'data>, Coff: CoffHeader> CoffComdat<'data, 'file,
             }
         }
 
+        let associations = associations
+            .get_or_insert_with(|| find_associations(file).into())
+            .clone();
         Some(CoffComdat {
             file,
             symbol_index: SymbolIndex(symbol_index),
             symbol,
             selection,
+            associations,
         })
     }
+
+    /// Get the COMDAT selection value.
+    ///
+    /// This is one of the `IMAGE_COMDAT_SELECT_*` constants, other than
+    /// `IMAGE_COMDAT_SELECT_ASSOCIATIVE`.
+    #[inline]
+    pub fn selection(&self) -> u8 {
+        self.selection
+    }
+
+    /// Get the index of the section that the COMDAT symbol is defined in.
+    ///
+    /// The other sections in the group are associated with this section.
+    #[inline]
+    pub fn section(&self) -> SectionIndex {
+        SectionIndex(self.symbol.section_number() as usize)
+    }
 }
 
 impl<'data, 'file, R: ReadRef<'data>, Coff: CoffHeader> read::private::Sealed
@@ -143,9 +173,8 @@ impl<'data, 'file, R: ReadRef<'data>, Coff: CoffHeader> ObjectComdat<'data>
     #[inline]
     fn sections(&self) -> Self::SectionIterator {
         CoffComdatSectionIterator {
-            file: self.file,
-            section_number: self.symbol.section_number(),
-            index: 0,
+            sections: comdat_sections(&self.associations, self.symbol.section_number()).into_iter(),
+            marker: PhantomData,
         }
     }
 }
@@ -155,6 +184,9 @@ pub type CoffBigComdatSectionIterator<'data, 'file, R = &'data [u8]> =
     CoffComdatSectionIterator<'data, 'file, R, pe::AnonObjectHeaderBigobj>;
 
 /// An iterator over the sections in a COMDAT section group of a `CoffFile`.
+///
+/// This includes sections that are associated with the COMDAT section, either
+/// directly or through other associated sections.
 #[derive(Debug)]
 pub struct CoffComdatSectionIterator<
     'data,
@@ -162,9 +194,8 @@ pub struct CoffComdatSectionIterator<
     R: ReadRef<'data> = &'data [u8],
     Coff: CoffHeader = pe::ImageFileHeader,
 > {
-    file: &'file CoffFile<'data, R, Coff>,
-    section_number: i32,
-    index: usize,
+    sections: alloc::vec::IntoIter<SectionIndex>,
+    marker: PhantomData<&'file CoffFile<'data, R, Coff>>,
 }
 
 impl<'data, 'file, R: ReadRef<'data>, Coff: CoffHeader> Iterator
@@ -173,35 +204,68 @@ impl<'data, 'file, R: ReadRef<'data>, Coff: CoffHeader> Iterator
     type Item = SectionIndex;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // Find associated COMDAT symbols.
-        // TODO: it seems gcc doesn't use associated symbols for this
-        loop {
-            let index = self.index;
-            let symbol = self.file.common.symbols.symbol(index).ok()?;
-            self.index += 1 + symbol.number_of_aux_symbols() as usize;
+        self.sections.next()
+    }
+}
 
-            // Must be a section symbol.
-            if !symbol.has_aux_section() {
-                continue;
-            }
+/// Find the associative section definitions.
+///
+/// Returns pairs of the section number that a section is associated with and the
+/// section number, sorted by the first element.
+fn find_associations<'data, R: ReadRef<'data>, Coff: CoffHeader>(
+    file: &CoffFile<'data, R, Coff>,
+) -> Vec<(i32, i32)> {
+    // TODO: it seems gcc doesn't use associated symbols for this
+    let symbols = &file.common.symbols;
+    let mut associations = Vec::new();
+    let mut index = 0;
+    while let Ok(symbol) = symbols.symbol(index) {
+        let aux_index = index;
+        index += 1 + symbol.number_of_aux_symbols() as usize;
+        // Must be a section symbol.
+        if !symbol.has_aux_section() {
+            continue;
+        }
+        let aux = match symbols.aux_section(aux_index) {
+            Ok(aux) => aux,
+            Err(_) => break,
+        };
+        if aux.selection == pe::IMAGE_COMDAT_SELECT_ASSOCIATIVE {
+            let number = if Coff::is_type_bigobj() {
+                u32::from(aux.number.get(LE)) | (u32::from(aux.high_number.get(LE)) << 16)
+            } else {
+                u32::from(aux.number.get(LE))
+            };
+            associations.push((number as i32, symbol.section_number()));
+        }
+    }
+    associations.sort_unstable();
+    associations
+}
 
-            let section_number = symbol.section_number();
-
-            let aux = self.file.common.symbols.aux_section(index).ok()?;
-            if aux.selection == pe::IMAGE_COMDAT_SELECT_ASSOCIATIVE {
-                let number = if Coff::is_type_bigobj() {
-                    u32::from(aux.number.get(LE)) | (u32::from(aux.high_number.get(LE)) << 16)
-                } else {
-                    u32::from(aux.number.get(LE))
-                };
-                if number as i32 == self.section_number {
-                    return Some(SectionIndex(section_number as usize));
-                }
-            } else if aux.selection != 0 {
-                if section_number == self.section_number {
-                    return Some(SectionIndex(section_number as usize));
-                }
+/// Return the section with the given number, and the sections associated with it.
+///
+/// `associations` is the result of `find_associations`.
+/// The sections are sorted by index.
+fn comdat_sections(associations: &[(i32, i32)], section_number: i32) -> Vec<SectionIndex> {
+    // Follow the associations from the COMDAT section.
+    let mut sections = vec![section_number];
+    let mut i = 0;
+    while let Some(&target) = sections.get(i) {
+        let start = associations.partition_point(|&(associative, _)| associative < target);
+        for &(associative, section) in &associations[start..] {
+            if associative != target {
+                break;
+            }
+            if !sections.contains(&section) {
+                sections.push(section);
             }
         }
+        i += 1;
     }
+    sections.sort_unstable();
+    sections
+        .into_iter()
+        .map(|section| SectionIndex(section as usize))
+        .collect()
 }
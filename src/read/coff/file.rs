@@ -3,7 +3,8 @@ use core::fmt::Debug;
 
 use crate::read::{
     self, Architecture, Export, FileFlags, Import, NoDynamicRelocationIterator, Object, ObjectKind,
-    ObjectSection, ReadError, ReadRef, Result, SectionIndex, SymbolIndex,
+    ObjectSection, ParseOptions, ReadError, ReadRef, Result, SectionIndex, SymbolIndex,
+    SymbolNameCache,
 };
 use crate::{pe, LittleEndian as LE, Pod};
 
@@ -30,15 +31,27 @@ pub struct CoffFile<'data, R: ReadRef<'data> = &'data [u8], Coff: CoffHeader = p
 {
     pub(super) header: &'data Coff,
     pub(super) common: CoffCommon<'data, R, Coff>,
+    pub(super) symbol_names: SymbolNameCache<'data>,
     pub(super) data: R,
 }
 
 impl<'data, R: ReadRef<'data>, Coff: CoffHeader> CoffFile<'data, R, Coff> {
     /// Parse the raw COFF file data.
     pub fn parse(data: R) -> Result<Self> {
+        Self::parse_with_options(data, &ParseOptions::default())
+    }
+
+    /// Parse the raw COFF file data, with limits on parsing.
+    ///
+    /// Returns an error if the number of sections or symbols in the header exceeds
+    /// [`ParseOptions::max_table_entries`]. These counts are checked before the
+    /// tables are read.
+    pub fn parse_with_options(data: R, options: &ParseOptions) -> Result<Self> {
         let mut offset = 0;
         let header = Coff::parse(data, &mut offset)?;
+        options.check_table_entries(header.number_of_sections().into())?;
         let sections = header.sections(data, offset)?;
+        options.check_table_entries(header.number_of_symbols().into())?;
         let symbols = header.symbols(data)?;
 
         Ok(CoffFile {
@@ -49,6 +62,7 @@ impl<'data, R: ReadRef<'data>, Coff: CoffHeader> CoffFile<'data, R, Coff> {
                 image_base: 0,
             },
             data,
+            symbol_names: SymbolNameCache::default(),
         })
     }
 }
@@ -138,6 +152,7 @@ where
         CoffComdatIterator {
             file: self,
             index: 0,
+            associations: None,
         }
     }
 
@@ -165,6 +180,11 @@ where
         Some(CoffSymbolTable { file: &self.common })
     }
 
+    fn symbol_by_name_bytes(&'file self, symbol_name: &[u8]) -> Option<Self::Symbol> {
+        let index = self.symbol_names.get(&self.symbol_table()?, symbol_name)?;
+        self.symbol_by_index(index).ok()
+    }
+
     fn dynamic_symbols(&'file self) -> CoffSymbolIterator<'data, 'file, R, Coff> {
         CoffSymbolIterator {
             file: &self.common,
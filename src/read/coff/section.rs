@@ -5,8 +5,9 @@ use crate::endian::LittleEndian as LE;
 use crate::pe;
 use crate::read::util::StringTable;
 use crate::read::{
-    self, CompressedData, CompressedFileRange, Error, ObjectSection, ObjectSegment, ReadError,
-    ReadRef, Result, SectionFlags, SectionIndex, SectionKind, SegmentFlags,
+    self, CompressedData, CompressedFileRange, Error, ObjectSection, ObjectSegment, Permissions,
+    ReadError, ReadRef, Result, SectionFlags, SectionIndex, SectionKind, SegmentFlags, SegmentKind,
+    SymbolIndex,
 };
 
 use super::{CoffFile, CoffHeader, CoffRelocationIterator};
@@ -170,6 +171,11 @@ impl<'data, 'file, R: ReadRef<'data>, Coff: CoffHeader> ObjectSegment<'data>
         u64::from(self.section.virtual_address.get(LE))
     }
 
+    #[inline]
+    fn physical_address(&self) -> u64 {
+        self.address()
+    }
+
     #[inline]
     fn size(&self) -> u64 {
         u64::from(self.section.virtual_size.get(LE))
@@ -215,6 +221,21 @@ impl<'data, 'file, R: ReadRef<'data>, Coff: CoffHeader> ObjectSegment<'data>
             .map(Some)
     }
 
+    #[inline]
+    fn kind(&self) -> SegmentKind {
+        SegmentKind::Load
+    }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        let characteristics = self.section.characteristics.get(LE);
+        Permissions::new(
+            characteristics & pe::IMAGE_SCN_MEM_READ != 0,
+            characteristics & pe::IMAGE_SCN_MEM_WRITE != 0,
+            characteristics & pe::IMAGE_SCN_MEM_EXECUTE != 0,
+        )
+    }
+
     #[inline]
     fn flags(&self) -> SegmentFlags {
         let characteristics = self.section.characteristics.get(LE);
@@ -275,6 +296,14 @@ impl<'data, 'file, R: ReadRef<'data>, Coff: CoffHeader> CoffSection<'data, 'file
             .coff_data(self.file.data)
             .read_error("Invalid COFF section offset or size")
     }
+
+    /// Return an iterator over the line number records for this section.
+    pub fn line_numbers(&self) -> Result<CoffLineNumberIterator<'data>> {
+        let line_numbers = self.section.coff_linenumbers(self.file.data)?;
+        Ok(CoffLineNumberIterator {
+            iter: line_numbers.iter(),
+        })
+    }
 }
 
 impl<'data, 'file, R: ReadRef<'data>, Coff: CoffHeader> read::private::Sealed
@@ -383,7 +412,9 @@ impl<'data, 'file, R: ReadRef<'data>, Coff: CoffHeader> ObjectSection<'data>
 impl pe::ImageSectionHeader {
     pub(crate) fn kind(&self) -> SectionKind {
         let characteristics = self.characteristics.get(LE);
-        if characteristics & (pe::IMAGE_SCN_CNT_CODE | pe::IMAGE_SCN_MEM_EXECUTE) != 0 {
+        if let b".pdata" | b".xdata" = self.raw_name() {
+            SectionKind::Unwind
+        } else if characteristics & (pe::IMAGE_SCN_CNT_CODE | pe::IMAGE_SCN_MEM_EXECUTE) != 0 {
             SectionKind::Text
         } else if characteristics & pe::IMAGE_SCN_CNT_INITIALIZED_DATA != 0 {
             if characteristics & pe::IMAGE_SCN_MEM_DISCARDABLE != 0 {
@@ -547,6 +578,70 @@ impl pe::ImageSectionHeader {
         data.read_slice_at(pointer, number)
             .read_error("Invalid COFF relocation offset or number")
     }
+
+    /// Read the line number records in a COFF file.
+    ///
+    /// `data` must be the entire file data.
+    pub fn coff_linenumbers<'data, R: ReadRef<'data>>(
+        &self,
+        data: R,
+    ) -> read::Result<&'data [pe::ImageLinenumber]> {
+        let pointer = self.pointer_to_linenumbers.get(LE).into();
+        let number = self.number_of_linenumbers.get(LE).into();
+        data.read_slice_at(pointer, number)
+            .read_error("Invalid COFF line number offset or number")
+    }
+}
+
+/// An iterator over the line number records in a `CoffSection`.
+#[derive(Debug)]
+pub struct CoffLineNumberIterator<'data> {
+    iter: slice::Iter<'data, pe::ImageLinenumber>,
+}
+
+impl<'data> Iterator for CoffLineNumberIterator<'data> {
+    type Item = CoffLineNumber;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(CoffLineNumber::parse)
+    }
+}
+
+/// A line number record in a COFF section.
+///
+/// Line number records are grouped by function. Each group starts with a
+/// `Function` record, followed by the `Line` records for that function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoffLineNumber {
+    /// The start of the line numbers for a function.
+    Function {
+        /// The symbol table index of the function.
+        symbol: SymbolIndex,
+    },
+    /// A line number within the current function.
+    Line {
+        /// The virtual address of the code for the line.
+        address: u32,
+        /// The one-based line number, relative to the line number in the
+        /// `.bf` symbol for the function.
+        line: u16,
+    },
+}
+
+impl CoffLineNumber {
+    /// Parse a raw line number record.
+    pub fn parse(linenumber: &pe::ImageLinenumber) -> Self {
+        let value = linenumber.symbol_table_index_or_virtual_address.get(LE);
+        match linenumber.linenumber.get(LE) {
+            0 => CoffLineNumber::Function {
+                symbol: SymbolIndex(value as usize),
+            },
+            line => CoffLineNumber::Line {
+                address: value,
+                line,
+            },
+        }
+    }
 }
 
 #[cfg(test)]
@@ -115,6 +115,14 @@ impl<'data, R: ReadRef<'data>, Coff: CoffHeader> SymbolTable<'data, R, Coff> {
         self.get::<pe::ImageAuxSymbolSection>(index, 1)
     }
 
+    /// Return the auxiliary weak external symbol for the symbol table entry at the given index.
+    ///
+    /// Note that the index is of the symbol, not the first auxiliary record.
+    #[inline]
+    pub fn aux_weak_external(&self, index: usize) -> Result<&'data pe::ImageAuxSymbolWeak> {
+        self.get::<pe::ImageAuxSymbolWeak>(index, 1)
+    }
+
     /// Return the auxiliary file name for the symbol table entry at the given index.
     ///
     /// Note that the index is of the symbol, not the first auxiliary record.
@@ -488,7 +496,24 @@ impl<'data, 'file, R: ReadRef<'data>, Coff: CoffHeader> ObjectSymbol<'data>
                 };
             }
         }
-        SymbolFlags::None
+        let storage_class = self.symbol.storage_class();
+        let mut weak_default_symbol = None;
+        let mut weak_search_type = 0;
+        if storage_class == pe::IMAGE_SYM_CLASS_WEAK_EXTERNAL
+            && self.symbol.number_of_aux_symbols() > 0
+        {
+            if let Ok(aux) = self.file.symbols.aux_weak_external(self.index.0) {
+                weak_default_symbol =
+                    Some(SymbolIndex(aux.weak_default_sym_index.get(LE) as usize));
+                weak_search_type = aux.weak_search_type.get(LE);
+            }
+        }
+        SymbolFlags::CoffSymbol {
+            typ: self.symbol.typ(),
+            storage_class,
+            weak_default_symbol,
+            weak_search_type,
+        }
     }
 }
 
@@ -0,0 +1,60 @@
+use alloc::string::String;
+use core::str;
+
+/// Demangle a symbol name.
+///
+/// The mangling scheme is detected from the prefix of the name. This supports
+/// the Rust legacy and v0 schemes, the Itanium C++ scheme, and the MSVC scheme.
+/// A leading underscore, such as is added for Mach-O symbols, is ignored.
+///
+/// Returns `None` if the name is not mangled using a supported scheme, or if
+/// demangling fails.
+pub fn demangle(name: &[u8]) -> Option<String> {
+    let name = str::from_utf8(name).ok()?;
+    if name.starts_with('?') {
+        return msvc_demangler::demangle(name, msvc_demangler::DemangleFlags::llvm()).ok();
+    }
+
+    let name = if name.starts_with("__Z") || name.starts_with("__R") {
+        &name[1..]
+    } else {
+        name
+    };
+    if name.starts_with("_R") || name.starts_with("_ZN") {
+        if let Ok(demangled) = rustc_demangle::try_demangle(name) {
+            // Omit the hash for the legacy scheme.
+            return Some(alloc::format!("{:#}", demangled));
+        }
+    }
+    if name.starts_with("_Z") {
+        let symbol = cpp_demangle::Symbol::new(name).ok()?;
+        return symbol
+            .demangle(&cpp_demangle::DemangleOptions::default())
+            .ok();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schemes() {
+        assert_eq!(
+            demangle(b"_ZN4core3fmt5write17h1d3a6a4b1c8e9f00E").as_deref(),
+            Some("core::fmt::write")
+        );
+        assert_eq!(
+            demangle(b"_RNvCs1234_7mycrate3foo").as_deref(),
+            Some("mycrate::foo")
+        );
+        assert_eq!(demangle(b"_Z3fooi").as_deref(), Some("foo(int)"));
+        assert_eq!(demangle(b"__Z3fooi").as_deref(), Some("foo(int)"));
+        assert_eq!(
+            demangle(b"?foo@@YAHH@Z").as_deref(),
+            Some("int __cdecl foo(int)")
+        );
+        assert_eq!(demangle(b"main"), None);
+    }
+}
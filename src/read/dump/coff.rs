@@ -0,0 +1,985 @@
+//! Dumping of COFF and PE files.
+//!
+//! The `FLAGS_*` tables give the names of the COFF and PE constants for each field.
+//! They are used for the values of dump nodes, and may also be used to print
+//! the fields of headers that are parsed by other means.
+#![allow(missing_docs)]
+
+use core::convert::TryInto;
+#[cfg(feature = "pe")]
+use core::mem;
+
+use crate::endian::LittleEndian as LE;
+use crate::pe::*;
+#[cfg(feature = "pe")]
+use crate::pod::{self, Pod};
+use crate::read::coff::{CoffHeader, ImageSymbol as _, SectionTable, SymbolTable};
+#[cfg(feature = "pe")]
+use crate::read::pe::{
+    DataDirectories, ExportTable, ImageNtHeaders, ImageOptionalHeader, ImageThunkData, ImportTable,
+};
+#[cfg(feature = "pe")]
+use crate::read::ReadError;
+use crate::read::{Result, StringTable};
+
+use super::{DumpValue, Dumper, Names};
+
+pub(super) fn dump_coff(d: &mut Dumper<'_>) -> Result<()> {
+    let data = d.data;
+    let mut offset = 0;
+    let header = ImageFileHeader::parse(data, &mut offset)?;
+    dump_file_header(d, header);
+    let symbols = d.result("SymbolTable", header.symbols(data));
+    let strings = symbols
+        .as_ref()
+        .map(SymbolTable::strings)
+        .unwrap_or_default();
+    if let Some(sections) = d.result("SectionHeaders", header.sections(data, offset)) {
+        dump_section_headers(d, &sections, strings);
+    }
+    if let Some(symbols) = symbols {
+        dump_symbols(d, header, &symbols);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "pe")]
+pub(super) fn dump_pe<'data, Pe: ImageNtHeaders>(d: &mut Dumper<'data>) -> Result<()> {
+    let data = d.data;
+    let dos_header = ImageDosHeader::parse(data)?;
+    dump_dos_header(d, dos_header);
+    let mut offset = dos_header.nt_headers_offset().into();
+    let (nt_headers, data_directories) = Pe::parse(data, &mut offset)?;
+    d.structure("NtHeaders", DumpValue::None, nt_headers, |d| {
+        d.enumeration("Signature", nt_headers.signature(), FLAGS_NT_SIGNATURE);
+        dump_file_header(d, nt_headers.file_header());
+        dump_optional_header(d, nt_headers);
+    });
+    let raw = pod::bytes_of_slice(data_directories.iter().as_slice());
+    d.group("DataDirectories", DumpValue::None, raw, |d| {
+        for (index, directory) in data_directories.enumerate() {
+            let value = super::enum_value(index as u64, FLAGS_IMAGE_DIRECTORY_ENTRY);
+            d.structure("DataDirectory", value, directory, |d| {
+                d.hex("VirtualAddress", directory.virtual_address.get(LE));
+                d.hex("Size", directory.size.get(LE));
+            });
+        }
+    });
+    let header = nt_headers.file_header();
+    let symbols = d.result("SymbolTable", header.symbols(data));
+    let strings = symbols
+        .as_ref()
+        .map(SymbolTable::strings)
+        .unwrap_or_default();
+    if let Some(sections) = d.result("SectionHeaders", nt_headers.sections(data, offset)) {
+        dump_section_headers(d, &sections, strings);
+        dump_export_table(d, &data_directories, &sections);
+        dump_import_table::<Pe>(d, &data_directories, &sections);
+    }
+    if let Some(symbols) = symbols {
+        dump_symbols(d, header, &symbols);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "pe")]
+fn dump_dos_header<'data>(d: &mut Dumper<'data>, header: &'data ImageDosHeader) {
+    d.structure("DosHeader", DumpValue::None, header, |d| {
+        d.enumeration("Magic", header.e_magic.get(LE), FLAGS_DOS_SIGNATURE);
+        d.hex("BytesOnLastPage", header.e_cblp.get(LE));
+        d.hex("PagesInFile", header.e_cp.get(LE));
+        d.decimal("Relocations", header.e_crlc.get(LE));
+        d.hex("SizeOfHeaderInParagraphs", header.e_cparhdr.get(LE));
+        d.hex("MinExtraParagraphs", header.e_minalloc.get(LE));
+        d.hex("MaxExtraParagraphs", header.e_maxalloc.get(LE));
+        d.hex("InitialRelativeSs", header.e_ss.get(LE));
+        d.hex("InitialSp", header.e_sp.get(LE));
+        d.hex("Checksum", header.e_csum.get(LE));
+        d.hex("InitialIp", header.e_ip.get(LE));
+        d.hex("InitialRelativeCs", header.e_cs.get(LE));
+        d.hex("AddressOfRelocationTable", header.e_lfarlc.get(LE));
+        d.hex("OverlayNumber", header.e_ovno.get(LE));
+        d.bytes("Reserved", core::mem::size_of_val(&header.e_res));
+        d.hex("OemId", header.e_oemid.get(LE));
+        d.hex("OemInfo", header.e_oeminfo.get(LE));
+        d.bytes("Reserved2", core::mem::size_of_val(&header.e_res2));
+        d.hex("AddressOfNewHeader", header.e_lfanew.get(LE));
+    });
+}
+
+fn dump_file_header<'data>(d: &mut Dumper<'data>, header: &'data ImageFileHeader) {
+    d.structure("FileHeader", DumpValue::None, header, |d| {
+        d.enumeration("Machine", header.machine.get(LE), FLAGS_IMAGE_FILE_MACHINE);
+        d.decimal("NumberOfSections", header.number_of_sections.get(LE));
+        d.hex("TimeDateStamp", header.time_date_stamp.get(LE));
+        d.hex(
+            "PointerToSymbolTable",
+            header.pointer_to_symbol_table.get(LE),
+        );
+        d.decimal("NumberOfSymbols", header.number_of_symbols.get(LE));
+        d.hex(
+            "SizeOfOptionalHeader",
+            header.size_of_optional_header.get(LE),
+        );
+        d.flags(
+            "Characteristics",
+            header.characteristics.get(LE),
+            FLAGS_IMAGE_FILE,
+        );
+    });
+}
+
+#[cfg(feature = "pe")]
+fn dump_optional_header<'data, Pe: ImageNtHeaders>(d: &mut Dumper<'data>, nt_headers: &'data Pe) {
+    let header = nt_headers.optional_header();
+    // Some fields are the size of an address.
+    let word = if nt_headers.is_type_64() { 8 } else { 4 };
+    d.structure("OptionalHeader", DumpValue::None, header, |d| {
+        d.enumeration("Magic", header.magic(), FLAGS_NT_OPTIONAL_HDR_MAGIC);
+        d.decimal("MajorLinkerVersion", header.major_linker_version());
+        d.decimal("MinorLinkerVersion", header.minor_linker_version());
+        d.hex("SizeOfCode", header.size_of_code());
+        d.hex("SizeOfInitializedData", header.size_of_initialized_data());
+        d.hex(
+            "SizeOfUninitializedData",
+            header.size_of_uninitialized_data(),
+        );
+        d.hex("AddressOfEntryPoint", header.address_of_entry_point());
+        d.hex("BaseOfCode", header.base_of_code());
+        if let Some(base_of_data) = header.base_of_data() {
+            d.hex("BaseOfData", base_of_data);
+        }
+        d.field("ImageBase", word, DumpValue::Hex(header.image_base()));
+        d.hex("SectionAlignment", header.section_alignment());
+        d.hex("FileAlignment", header.file_alignment());
+        d.decimal(
+            "MajorOperatingSystemVersion",
+            header.major_operating_system_version(),
+        );
+        d.decimal(
+            "MinorOperatingSystemVersion",
+            header.minor_operating_system_version(),
+        );
+        d.decimal("MajorImageVersion", header.major_image_version());
+        d.decimal("MinorImageVersion", header.minor_image_version());
+        d.decimal("MajorSubsystemVersion", header.major_subsystem_version());
+        d.decimal("MinorSubsystemVersion", header.minor_subsystem_version());
+        d.hex("Win32VersionValue", header.win32_version_value());
+        d.hex("SizeOfImage", header.size_of_image());
+        d.hex("SizeOfHeaders", header.size_of_headers());
+        d.hex("CheckSum", header.check_sum());
+        d.enumeration("Subsystem", header.subsystem(), FLAGS_IMAGE_SUBSYSTEM);
+        d.flags(
+            "DllCharacteristics",
+            header.dll_characteristics(),
+            FLAGS_IMAGE_DLLCHARACTERISTICS,
+        );
+        let reserve = DumpValue::Hex(header.size_of_stack_reserve());
+        d.field("SizeOfStackReserve", word, reserve);
+        let commit = DumpValue::Hex(header.size_of_stack_commit());
+        d.field("SizeOfStackCommit", word, commit);
+        let reserve = DumpValue::Hex(header.size_of_heap_reserve());
+        d.field("SizeOfHeapReserve", word, reserve);
+        let commit = DumpValue::Hex(header.size_of_heap_commit());
+        d.field("SizeOfHeapCommit", word, commit);
+        d.hex("LoaderFlags", header.loader_flags());
+        d.decimal("NumberOfRvaAndSizes", header.number_of_rva_and_sizes());
+    });
+}
+
+fn dump_section_headers<'data>(
+    d: &mut Dumper<'data>,
+    sections: &SectionTable<'data>,
+    strings: StringTable<'data>,
+) {
+    let headers = sections.iter().as_slice();
+    d.table("SectionHeaders", DumpValue::None, headers, |d, section| {
+        let name = section.name(strings);
+        let value = name.map(DumpValue::String).unwrap_or(DumpValue::None);
+        d.structure("SectionHeader", value, section, |d| {
+            // Long names are stored as a decimal string table offset.
+            match section.name_offset() {
+                Ok(Some(offset)) => {
+                    d.string_reference("Name", section.name.len(), offset.into(), name)
+                }
+                _ => d.string("Name", section.name.len()),
+            }
+            d.hex("VirtualSize", section.virtual_size.get(LE));
+            d.hex("VirtualAddress", section.virtual_address.get(LE));
+            d.hex("SizeOfRawData", section.size_of_raw_data.get(LE));
+            d.hex("PointerToRawData", section.pointer_to_raw_data.get(LE));
+            d.hex(
+                "PointerToRelocations",
+                section.pointer_to_relocations.get(LE),
+            );
+            d.hex(
+                "PointerToLinenumbers",
+                section.pointer_to_linenumbers.get(LE),
+            );
+            d.decimal("NumberOfRelocations", section.number_of_relocations.get(LE));
+            d.decimal("NumberOfLinenumbers", section.number_of_linenumbers.get(LE));
+            d.flags(
+                "Characteristics",
+                section.characteristics.get(LE),
+                FLAGS_IMAGE_SCN,
+            );
+        });
+    });
+}
+
+#[cfg(feature = "pe")]
+fn dump_export_table<'data>(
+    d: &mut Dumper<'data>,
+    data_directories: &DataDirectories<'data>,
+    sections: &SectionTable<'data>,
+) {
+    let data = d.data;
+    let export_table = match d.result(
+        "ExportDirectory",
+        data_directories.export_table(data, sections),
+    ) {
+        Some(Some(export_table)) => export_table,
+        _ => return,
+    };
+    let directory = export_table.directory();
+    d.structure("ExportDirectory", DumpValue::None, directory, |d| {
+        d.hex("Characteristics", directory.characteristics.get(LE));
+        d.hex("TimeDateStamp", directory.time_date_stamp.get(LE));
+        d.decimal("MajorVersion", directory.major_version.get(LE));
+        d.decimal("MinorVersion", directory.minor_version.get(LE));
+        let name = directory.name.get(LE);
+        d.string_offset("Name", name, export_table.name_from_pointer(name));
+        d.decimal("Base", directory.base.get(LE));
+        d.decimal("NumberOfFunctions", directory.number_of_functions.get(LE));
+        d.decimal("NumberOfNames", directory.number_of_names.get(LE));
+        d.hex("AddressOfFunctions", directory.address_of_functions.get(LE));
+        d.hex("AddressOfNames", directory.address_of_names.get(LE));
+        d.hex(
+            "AddressOfNameOrdinals",
+            directory.address_of_name_ordinals.get(LE),
+        );
+    });
+    dump_export_tables(d, &export_table);
+}
+
+#[cfg(feature = "pe")]
+fn dump_export_tables<'data>(d: &mut Dumper<'data>, export_table: &ExportTable<'data>) {
+    let addresses = export_table.addresses();
+    if !addresses.is_empty() {
+        d.table(
+            "ExportAddressTable",
+            DumpValue::None,
+            addresses,
+            |d, address| {
+                let address = address.get(LE);
+                // Forwarded exports are the address of a string in the export directory.
+                match export_table.forward_string(address).transpose() {
+                    Some(forward) => d.string_offset("Forward", address, forward),
+                    None => d.hex("Address", address),
+                }
+            },
+        );
+    }
+    let name_pointers = export_table.name_pointers();
+    if !name_pointers.is_empty() {
+        d.table(
+            "NamePointerTable",
+            DumpValue::None,
+            name_pointers,
+            |d, pointer| {
+                let pointer = pointer.get(LE);
+                d.string_offset("Name", pointer, export_table.name_from_pointer(pointer));
+            },
+        );
+    }
+    let name_ordinals = export_table.name_ordinals();
+    if !name_ordinals.is_empty() {
+        d.table(
+            "OrdinalTable",
+            DumpValue::None,
+            name_ordinals,
+            |d, ordinal| {
+                d.decimal("Index", ordinal.get(LE));
+            },
+        );
+    }
+}
+
+#[cfg(feature = "pe")]
+fn dump_import_table<'data, Pe: ImageNtHeaders>(
+    d: &mut Dumper<'data>,
+    data_directories: &DataDirectories<'data>,
+    sections: &SectionTable<'data>,
+) {
+    let data = d.data;
+    let import_table = match d.result(
+        "ImportDirectory",
+        data_directories.import_table(data, sections),
+    ) {
+        Some(Some(import_table)) => import_table,
+        _ => return,
+    };
+    // The size of the directory is ignored by the loader, so parse until a null descriptor.
+    let descriptors = data_directories
+        .get(IMAGE_DIRECTORY_ENTRY_IMPORT)
+        .and_then(|directory| sections.pe_data_at(data, directory.virtual_address.get(LE)))
+        .and_then(|data| null_terminated(data, ImageImportDescriptor::is_null))
+        .read_error("Missing PE null import descriptor");
+    let descriptors = match d.result("ImportDirectory", descriptors) {
+        Some(descriptors) => descriptors,
+        None => return,
+    };
+    d.table(
+        "ImportDirectory",
+        DumpValue::None,
+        descriptors,
+        |d, descriptor| {
+            let name = descriptor.name.get(LE);
+            let library = import_table.name(name);
+            let value = library.map(DumpValue::String).unwrap_or(DumpValue::None);
+            d.structure("ImportDescriptor", value, descriptor, |d| {
+                d.hex(
+                    "OriginalFirstThunk",
+                    descriptor.original_first_thunk.get(LE),
+                );
+                d.hex("TimeDateStamp", descriptor.time_date_stamp.get(LE));
+                d.hex("ForwarderChain", descriptor.forwarder_chain.get(LE));
+                d.string_offset("Name", name, library);
+                d.hex("FirstThunk", descriptor.first_thunk.get(LE));
+            });
+        },
+    );
+    for descriptor in descriptors {
+        // The address table is the same as the lookup table until it is bound,
+        // and is the only table in some files.
+        let mut address = descriptor.original_first_thunk.get(LE);
+        if address == 0 {
+            address = descriptor.first_thunk.get(LE);
+        }
+        let library = import_table.name(descriptor.name.get(LE));
+        let value = library.map(DumpValue::String).unwrap_or(DumpValue::None);
+        dump_import_lookup_table::<Pe>(d, &import_table, sections, address, value);
+    }
+}
+
+#[cfg(feature = "pe")]
+fn dump_import_lookup_table<'data, Pe: ImageNtHeaders>(
+    d: &mut Dumper<'data>,
+    import_table: &ImportTable<'data>,
+    sections: &SectionTable<'data>,
+    address: u32,
+    value: DumpValue<'data>,
+) {
+    let thunks = sections
+        .pe_data_at(d.data, address)
+        .and_then(|data| null_terminated(data, |thunk: &Pe::ImageThunkData| thunk.address() == 0))
+        .read_error("Missing PE null import thunk");
+    let thunks = match d.result("ImportLookupTable", thunks) {
+        Some(thunks) if !thunks.is_empty() => thunks,
+        _ => return,
+    };
+    let size = mem::size_of::<Pe::ImageThunkData>();
+    d.table("ImportLookupTable", value, thunks, |d, thunk| {
+        if thunk.is_ordinal() {
+            let ordinal = DumpValue::Decimal(thunk.ordinal().into());
+            d.field("Ordinal", size, ordinal);
+        } else {
+            let address = thunk.address();
+            let name = import_table.hint_name(address).map(|(_, name)| name);
+            d.string_reference("HintName", size, address.into(), name);
+        }
+    });
+}
+
+/// Return the entries before the first null entry.
+#[cfg(feature = "pe")]
+fn null_terminated<T: Pod, F: Fn(&T) -> bool>(data: &[u8], is_null: F) -> Option<&[T]> {
+    let count = data.len() / mem::size_of::<T>();
+    let (entries, _) = pod::slice_from_bytes::<T>(data, count).ok()?;
+    let count = entries.iter().position(is_null)?;
+    Some(&entries[..count])
+}
+
+fn dump_symbols<'data>(
+    d: &mut Dumper<'data>,
+    header: &ImageFileHeader,
+    symbols: &SymbolTable<'data>,
+) {
+    if symbols.is_empty() {
+        return;
+    }
+    let offset = header.pointer_to_symbol_table.get(LE) as usize;
+    let size = symbols.len() * IMAGE_SIZEOF_SYMBOL;
+    let raw = match d.data.get(offset..).and_then(|raw| raw.get(..size)) {
+        Some(raw) => raw,
+        None => return,
+    };
+    let strings = symbols.strings();
+    d.group("SymbolTable", DumpValue::None, raw, |d| {
+        for (index, symbol) in symbols.iter() {
+            let name = symbol.name(strings);
+            let value = name.map(DumpValue::String).unwrap_or(DumpValue::None);
+            d.structure("Symbol", value, symbol, |d| {
+                if symbol.name[..4] == [0; 4] {
+                    d.bytes("Zeroes", 4);
+                    let offset = u32::from_le_bytes(symbol.name[4..].try_into().unwrap());
+                    d.string_offset("Name", offset, name);
+                } else {
+                    d.string("Name", symbol.name.len());
+                }
+                d.hex("Value", symbol.value.get(LE));
+                d.decimal("SectionNumber", symbol.section_number.get(LE));
+                d.hex("Type", symbol.typ.get(LE));
+                d.enumeration("StorageClass", symbol.storage_class, FLAGS_IMAGE_SYM_CLASS);
+                d.decimal("NumberOfAuxSymbols", symbol.number_of_aux_symbols);
+            });
+            for aux in 1..=usize::from(symbol.number_of_aux_symbols) {
+                if let Some(aux) = d.result("AuxSymbol", symbols.get(index + aux, 0)) {
+                    let aux: &ImageSymbolBytes = aux;
+                    d.structure("AuxSymbol", DumpValue::None, aux, |d| {
+                        d.bytes("Data", IMAGE_SIZEOF_SYMBOL)
+                    });
+                }
+            }
+        }
+    });
+}
+
+#[cfg(feature = "pe")]
+pub const FLAGS_DOS_SIGNATURE: Names = names!(IMAGE_DOS_SIGNATURE);
+#[cfg(feature = "pe")]
+pub const FLAGS_NT_SIGNATURE: Names = names!(IMAGE_NT_SIGNATURE);
+#[cfg(feature = "pe")]
+pub const FLAGS_NT_OPTIONAL_HDR_MAGIC: Names =
+    names!(IMAGE_NT_OPTIONAL_HDR32_MAGIC, IMAGE_NT_OPTIONAL_HDR64_MAGIC,);
+pub const FLAGS_IMAGE_FILE: Names = names!(
+    IMAGE_FILE_RELOCS_STRIPPED,
+    IMAGE_FILE_EXECUTABLE_IMAGE,
+    IMAGE_FILE_LINE_NUMS_STRIPPED,
+    IMAGE_FILE_LOCAL_SYMS_STRIPPED,
+    IMAGE_FILE_AGGRESIVE_WS_TRIM,
+    IMAGE_FILE_LARGE_ADDRESS_AWARE,
+    IMAGE_FILE_BYTES_REVERSED_LO,
+    IMAGE_FILE_32BIT_MACHINE,
+    IMAGE_FILE_DEBUG_STRIPPED,
+    IMAGE_FILE_REMOVABLE_RUN_FROM_SWAP,
+    IMAGE_FILE_NET_RUN_FROM_SWAP,
+    IMAGE_FILE_SYSTEM,
+    IMAGE_FILE_DLL,
+    IMAGE_FILE_UP_SYSTEM_ONLY,
+    IMAGE_FILE_BYTES_REVERSED_HI,
+);
+pub const FLAGS_IMAGE_FILE_MACHINE: Names = names!(
+    IMAGE_FILE_MACHINE_UNKNOWN,
+    IMAGE_FILE_MACHINE_TARGET_HOST,
+    IMAGE_FILE_MACHINE_I386,
+    IMAGE_FILE_MACHINE_R3000,
+    IMAGE_FILE_MACHINE_R4000,
+    IMAGE_FILE_MACHINE_R10000,
+    IMAGE_FILE_MACHINE_WCEMIPSV2,
+    IMAGE_FILE_MACHINE_ALPHA,
+    IMAGE_FILE_MACHINE_SH3,
+    IMAGE_FILE_MACHINE_SH3DSP,
+    IMAGE_FILE_MACHINE_SH3E,
+    IMAGE_FILE_MACHINE_SH4,
+    IMAGE_FILE_MACHINE_SH5,
+    IMAGE_FILE_MACHINE_ARM,
+    IMAGE_FILE_MACHINE_THUMB,
+    IMAGE_FILE_MACHINE_ARMNT,
+    IMAGE_FILE_MACHINE_AM33,
+    IMAGE_FILE_MACHINE_POWERPC,
+    IMAGE_FILE_MACHINE_POWERPCFP,
+    IMAGE_FILE_MACHINE_IA64,
+    IMAGE_FILE_MACHINE_MIPS16,
+    IMAGE_FILE_MACHINE_ALPHA64,
+    IMAGE_FILE_MACHINE_MIPSFPU,
+    IMAGE_FILE_MACHINE_MIPSFPU16,
+    IMAGE_FILE_MACHINE_AXP64,
+    IMAGE_FILE_MACHINE_TRICORE,
+    IMAGE_FILE_MACHINE_CEF,
+    IMAGE_FILE_MACHINE_EBC,
+    IMAGE_FILE_MACHINE_AMD64,
+    IMAGE_FILE_MACHINE_M32R,
+    IMAGE_FILE_MACHINE_ARM64,
+    IMAGE_FILE_MACHINE_CEE,
+    IMAGE_FILE_MACHINE_RISCV32,
+    IMAGE_FILE_MACHINE_RISCV64,
+    IMAGE_FILE_MACHINE_RISCV128,
+);
+pub const FLAGS_IMAGE_SCN: Names = names!(
+    IMAGE_SCN_TYPE_NO_PAD,
+    IMAGE_SCN_CNT_CODE,
+    IMAGE_SCN_CNT_INITIALIZED_DATA,
+    IMAGE_SCN_CNT_UNINITIALIZED_DATA,
+    IMAGE_SCN_LNK_OTHER,
+    IMAGE_SCN_LNK_INFO,
+    IMAGE_SCN_LNK_REMOVE,
+    IMAGE_SCN_LNK_COMDAT,
+    IMAGE_SCN_NO_DEFER_SPEC_EXC,
+    IMAGE_SCN_GPREL,
+    IMAGE_SCN_MEM_FARDATA,
+    IMAGE_SCN_MEM_PURGEABLE,
+    IMAGE_SCN_MEM_16BIT,
+    IMAGE_SCN_MEM_LOCKED,
+    IMAGE_SCN_MEM_PRELOAD,
+    IMAGE_SCN_LNK_NRELOC_OVFL,
+    IMAGE_SCN_MEM_DISCARDABLE,
+    IMAGE_SCN_MEM_NOT_CACHED,
+    IMAGE_SCN_MEM_NOT_PAGED,
+    IMAGE_SCN_MEM_SHARED,
+    IMAGE_SCN_MEM_EXECUTE,
+    IMAGE_SCN_MEM_READ,
+    IMAGE_SCN_MEM_WRITE,
+);
+pub const FLAGS_IMAGE_SCN_ALIGN: Names = names!(
+    IMAGE_SCN_ALIGN_1BYTES,
+    IMAGE_SCN_ALIGN_2BYTES,
+    IMAGE_SCN_ALIGN_4BYTES,
+    IMAGE_SCN_ALIGN_8BYTES,
+    IMAGE_SCN_ALIGN_16BYTES,
+    IMAGE_SCN_ALIGN_32BYTES,
+    IMAGE_SCN_ALIGN_64BYTES,
+    IMAGE_SCN_ALIGN_128BYTES,
+    IMAGE_SCN_ALIGN_256BYTES,
+    IMAGE_SCN_ALIGN_512BYTES,
+    IMAGE_SCN_ALIGN_1024BYTES,
+    IMAGE_SCN_ALIGN_2048BYTES,
+    IMAGE_SCN_ALIGN_4096BYTES,
+    IMAGE_SCN_ALIGN_8192BYTES,
+);
+pub const FLAGS_IMAGE_REL_I386: Names = names!(
+    IMAGE_REL_I386_ABSOLUTE,
+    IMAGE_REL_I386_DIR16,
+    IMAGE_REL_I386_REL16,
+    IMAGE_REL_I386_DIR32,
+    IMAGE_REL_I386_DIR32NB,
+    IMAGE_REL_I386_SEG12,
+    IMAGE_REL_I386_SECTION,
+    IMAGE_REL_I386_SECREL,
+    IMAGE_REL_I386_TOKEN,
+    IMAGE_REL_I386_SECREL7,
+    IMAGE_REL_I386_REL32,
+);
+pub const FLAGS_IMAGE_REL_MIPS: Names = names!(
+    IMAGE_REL_MIPS_ABSOLUTE,
+    IMAGE_REL_MIPS_REFHALF,
+    IMAGE_REL_MIPS_REFWORD,
+    IMAGE_REL_MIPS_JMPADDR,
+    IMAGE_REL_MIPS_REFHI,
+    IMAGE_REL_MIPS_REFLO,
+    IMAGE_REL_MIPS_GPREL,
+    IMAGE_REL_MIPS_LITERAL,
+    IMAGE_REL_MIPS_SECTION,
+    IMAGE_REL_MIPS_SECREL,
+    IMAGE_REL_MIPS_SECRELLO,
+    IMAGE_REL_MIPS_SECRELHI,
+    IMAGE_REL_MIPS_TOKEN,
+    IMAGE_REL_MIPS_JMPADDR16,
+    IMAGE_REL_MIPS_REFWORDNB,
+    IMAGE_REL_MIPS_PAIR,
+);
+pub const FLAGS_IMAGE_REL_ALPHA: Names = names!(
+    IMAGE_REL_ALPHA_ABSOLUTE,
+    IMAGE_REL_ALPHA_REFLONG,
+    IMAGE_REL_ALPHA_REFQUAD,
+    IMAGE_REL_ALPHA_GPREL32,
+    IMAGE_REL_ALPHA_LITERAL,
+    IMAGE_REL_ALPHA_LITUSE,
+    IMAGE_REL_ALPHA_GPDISP,
+    IMAGE_REL_ALPHA_BRADDR,
+    IMAGE_REL_ALPHA_HINT,
+    IMAGE_REL_ALPHA_INLINE_REFLONG,
+    IMAGE_REL_ALPHA_REFHI,
+    IMAGE_REL_ALPHA_REFLO,
+    IMAGE_REL_ALPHA_PAIR,
+    IMAGE_REL_ALPHA_MATCH,
+    IMAGE_REL_ALPHA_SECTION,
+    IMAGE_REL_ALPHA_SECREL,
+    IMAGE_REL_ALPHA_REFLONGNB,
+    IMAGE_REL_ALPHA_SECRELLO,
+    IMAGE_REL_ALPHA_SECRELHI,
+    IMAGE_REL_ALPHA_REFQ3,
+    IMAGE_REL_ALPHA_REFQ2,
+    IMAGE_REL_ALPHA_REFQ1,
+    IMAGE_REL_ALPHA_GPRELLO,
+    IMAGE_REL_ALPHA_GPRELHI,
+);
+pub const FLAGS_IMAGE_REL_PPC: Names = names!(
+    IMAGE_REL_PPC_ABSOLUTE,
+    IMAGE_REL_PPC_ADDR64,
+    IMAGE_REL_PPC_ADDR32,
+    IMAGE_REL_PPC_ADDR24,
+    IMAGE_REL_PPC_ADDR16,
+    IMAGE_REL_PPC_ADDR14,
+    IMAGE_REL_PPC_REL24,
+    IMAGE_REL_PPC_REL14,
+    IMAGE_REL_PPC_TOCREL16,
+    IMAGE_REL_PPC_TOCREL14,
+    IMAGE_REL_PPC_ADDR32NB,
+    IMAGE_REL_PPC_SECREL,
+    IMAGE_REL_PPC_SECTION,
+    IMAGE_REL_PPC_IFGLUE,
+    IMAGE_REL_PPC_IMGLUE,
+    IMAGE_REL_PPC_SECREL16,
+    IMAGE_REL_PPC_REFHI,
+    IMAGE_REL_PPC_REFLO,
+    IMAGE_REL_PPC_PAIR,
+    IMAGE_REL_PPC_SECRELLO,
+    IMAGE_REL_PPC_SECRELHI,
+    IMAGE_REL_PPC_GPREL,
+    IMAGE_REL_PPC_TOKEN,
+);
+pub const FLAGS_IMAGE_REL_PPC_BITS: Names = names!(
+    IMAGE_REL_PPC_NEG,
+    IMAGE_REL_PPC_BRTAKEN,
+    IMAGE_REL_PPC_BRNTAKEN,
+    IMAGE_REL_PPC_TOCDEFN,
+);
+pub const FLAGS_IMAGE_REL_SH: Names = names!(
+    IMAGE_REL_SH3_ABSOLUTE,
+    IMAGE_REL_SH3_DIRECT16,
+    IMAGE_REL_SH3_DIRECT32,
+    IMAGE_REL_SH3_DIRECT8,
+    IMAGE_REL_SH3_DIRECT8_WORD,
+    IMAGE_REL_SH3_DIRECT8_LONG,
+    IMAGE_REL_SH3_DIRECT4,
+    IMAGE_REL_SH3_DIRECT4_WORD,
+    IMAGE_REL_SH3_DIRECT4_LONG,
+    IMAGE_REL_SH3_PCREL8_WORD,
+    IMAGE_REL_SH3_PCREL8_LONG,
+    IMAGE_REL_SH3_PCREL12_WORD,
+    IMAGE_REL_SH3_STARTOF_SECTION,
+    IMAGE_REL_SH3_SIZEOF_SECTION,
+    IMAGE_REL_SH3_SECTION,
+    IMAGE_REL_SH3_SECREL,
+    IMAGE_REL_SH3_DIRECT32_NB,
+    IMAGE_REL_SH3_GPREL4_LONG,
+    IMAGE_REL_SH3_TOKEN,
+    IMAGE_REL_SHM_PCRELPT,
+    IMAGE_REL_SHM_REFLO,
+    IMAGE_REL_SHM_REFHALF,
+    IMAGE_REL_SHM_RELLO,
+    IMAGE_REL_SHM_RELHALF,
+    IMAGE_REL_SHM_PAIR,
+);
+pub const FLAGS_IMAGE_REL_SH_BITS: Names = names!(IMAGE_REL_SH_NOMODE,);
+pub const FLAGS_IMAGE_REL_ARM: Names = names!(
+    IMAGE_REL_ARM_ABSOLUTE,
+    IMAGE_REL_ARM_ADDR32,
+    IMAGE_REL_ARM_ADDR32NB,
+    IMAGE_REL_ARM_BRANCH24,
+    IMAGE_REL_ARM_BRANCH11,
+    IMAGE_REL_ARM_TOKEN,
+    IMAGE_REL_ARM_GPREL12,
+    IMAGE_REL_ARM_GPREL7,
+    IMAGE_REL_ARM_BLX24,
+    IMAGE_REL_ARM_BLX11,
+    IMAGE_REL_ARM_SECTION,
+    IMAGE_REL_ARM_SECREL,
+    IMAGE_REL_ARM_MOV32A,
+    IMAGE_REL_ARM_MOV32T,
+    IMAGE_REL_ARM_BRANCH20T,
+    IMAGE_REL_ARM_BRANCH24T,
+    IMAGE_REL_ARM_BLX23T,
+);
+pub const FLAGS_IMAGE_REL_AM: Names = names!(
+    IMAGE_REL_AM_ABSOLUTE,
+    IMAGE_REL_AM_ADDR32,
+    IMAGE_REL_AM_ADDR32NB,
+    IMAGE_REL_AM_CALL32,
+    IMAGE_REL_AM_FUNCINFO,
+    IMAGE_REL_AM_REL32_1,
+    IMAGE_REL_AM_REL32_2,
+    IMAGE_REL_AM_SECREL,
+    IMAGE_REL_AM_SECTION,
+    IMAGE_REL_AM_TOKEN,
+);
+pub const FLAGS_IMAGE_REL_ARM64: Names = names!(
+    IMAGE_REL_ARM64_ABSOLUTE,
+    IMAGE_REL_ARM64_ADDR32,
+    IMAGE_REL_ARM64_ADDR32NB,
+    IMAGE_REL_ARM64_BRANCH26,
+    IMAGE_REL_ARM64_PAGEBASE_REL21,
+    IMAGE_REL_ARM64_REL21,
+    IMAGE_REL_ARM64_PAGEOFFSET_12A,
+    IMAGE_REL_ARM64_PAGEOFFSET_12L,
+    IMAGE_REL_ARM64_SECREL,
+    IMAGE_REL_ARM64_SECREL_LOW12A,
+    IMAGE_REL_ARM64_SECREL_HIGH12A,
+    IMAGE_REL_ARM64_SECREL_LOW12L,
+    IMAGE_REL_ARM64_TOKEN,
+    IMAGE_REL_ARM64_SECTION,
+    IMAGE_REL_ARM64_ADDR64,
+    IMAGE_REL_ARM64_BRANCH19,
+);
+pub const FLAGS_IMAGE_REL_AMD64: Names = names!(
+    IMAGE_REL_AMD64_ABSOLUTE,
+    IMAGE_REL_AMD64_ADDR64,
+    IMAGE_REL_AMD64_ADDR32,
+    IMAGE_REL_AMD64_ADDR32NB,
+    IMAGE_REL_AMD64_REL32,
+    IMAGE_REL_AMD64_REL32_1,
+    IMAGE_REL_AMD64_REL32_2,
+    IMAGE_REL_AMD64_REL32_3,
+    IMAGE_REL_AMD64_REL32_4,
+    IMAGE_REL_AMD64_REL32_5,
+    IMAGE_REL_AMD64_SECTION,
+    IMAGE_REL_AMD64_SECREL,
+    IMAGE_REL_AMD64_SECREL7,
+    IMAGE_REL_AMD64_TOKEN,
+    IMAGE_REL_AMD64_SREL32,
+    IMAGE_REL_AMD64_PAIR,
+    IMAGE_REL_AMD64_SSPAN32,
+    IMAGE_REL_AMD64_EHANDLER,
+    IMAGE_REL_AMD64_IMPORT_BR,
+    IMAGE_REL_AMD64_IMPORT_CALL,
+    IMAGE_REL_AMD64_CFG_BR,
+    IMAGE_REL_AMD64_CFG_BR_REX,
+    IMAGE_REL_AMD64_CFG_CALL,
+    IMAGE_REL_AMD64_INDIR_BR,
+    IMAGE_REL_AMD64_INDIR_BR_REX,
+    IMAGE_REL_AMD64_INDIR_CALL,
+    IMAGE_REL_AMD64_INDIR_BR_SWITCHTABLE_FIRST,
+    IMAGE_REL_AMD64_INDIR_BR_SWITCHTABLE_LAST,
+);
+pub const FLAGS_IMAGE_REL_IA64: Names = names!(
+    IMAGE_REL_IA64_ABSOLUTE,
+    IMAGE_REL_IA64_IMM14,
+    IMAGE_REL_IA64_IMM22,
+    IMAGE_REL_IA64_IMM64,
+    IMAGE_REL_IA64_DIR32,
+    IMAGE_REL_IA64_DIR64,
+    IMAGE_REL_IA64_PCREL21B,
+    IMAGE_REL_IA64_PCREL21M,
+    IMAGE_REL_IA64_PCREL21F,
+    IMAGE_REL_IA64_GPREL22,
+    IMAGE_REL_IA64_LTOFF22,
+    IMAGE_REL_IA64_SECTION,
+    IMAGE_REL_IA64_SECREL22,
+    IMAGE_REL_IA64_SECREL64I,
+    IMAGE_REL_IA64_SECREL32,
+    IMAGE_REL_IA64_DIR32NB,
+    IMAGE_REL_IA64_SREL14,
+    IMAGE_REL_IA64_SREL22,
+    IMAGE_REL_IA64_SREL32,
+    IMAGE_REL_IA64_UREL32,
+    IMAGE_REL_IA64_PCREL60X,
+    IMAGE_REL_IA64_PCREL60B,
+    IMAGE_REL_IA64_PCREL60F,
+    IMAGE_REL_IA64_PCREL60I,
+    IMAGE_REL_IA64_PCREL60M,
+    IMAGE_REL_IA64_IMMGPREL64,
+    IMAGE_REL_IA64_TOKEN,
+    IMAGE_REL_IA64_GPREL32,
+    IMAGE_REL_IA64_ADDEND,
+);
+pub const FLAGS_IMAGE_REL_CEF: Names = names!(
+    IMAGE_REL_CEF_ABSOLUTE,
+    IMAGE_REL_CEF_ADDR32,
+    IMAGE_REL_CEF_ADDR64,
+    IMAGE_REL_CEF_ADDR32NB,
+    IMAGE_REL_CEF_SECTION,
+    IMAGE_REL_CEF_SECREL,
+    IMAGE_REL_CEF_TOKEN,
+);
+pub const FLAGS_IMAGE_REL_CEE: Names = names!(
+    IMAGE_REL_CEE_ABSOLUTE,
+    IMAGE_REL_CEE_ADDR32,
+    IMAGE_REL_CEE_ADDR64,
+    IMAGE_REL_CEE_ADDR32NB,
+    IMAGE_REL_CEE_SECTION,
+    IMAGE_REL_CEE_SECREL,
+    IMAGE_REL_CEE_TOKEN,
+);
+pub const FLAGS_IMAGE_REL_M32R: Names = names!(
+    IMAGE_REL_M32R_ABSOLUTE,
+    IMAGE_REL_M32R_ADDR32,
+    IMAGE_REL_M32R_ADDR32NB,
+    IMAGE_REL_M32R_ADDR24,
+    IMAGE_REL_M32R_GPREL16,
+    IMAGE_REL_M32R_PCREL24,
+    IMAGE_REL_M32R_PCREL16,
+    IMAGE_REL_M32R_PCREL8,
+    IMAGE_REL_M32R_REFHALF,
+    IMAGE_REL_M32R_REFHI,
+    IMAGE_REL_M32R_REFLO,
+    IMAGE_REL_M32R_PAIR,
+    IMAGE_REL_M32R_SECTION,
+    IMAGE_REL_M32R_SECREL32,
+    IMAGE_REL_M32R_TOKEN,
+);
+pub const FLAGS_IMAGE_REL_EBC: Names = names!(
+    IMAGE_REL_EBC_ABSOLUTE,
+    IMAGE_REL_EBC_ADDR32NB,
+    IMAGE_REL_EBC_REL32,
+    IMAGE_REL_EBC_SECTION,
+    IMAGE_REL_EBC_SECREL,
+);
+pub const FLAGS_IMAGE_SYM: Names =
+    names!(IMAGE_SYM_UNDEFINED, IMAGE_SYM_ABSOLUTE, IMAGE_SYM_DEBUG,);
+pub const FLAGS_IMAGE_SYM_TYPE: Names = names!(
+    IMAGE_SYM_TYPE_NULL,
+    IMAGE_SYM_TYPE_VOID,
+    IMAGE_SYM_TYPE_CHAR,
+    IMAGE_SYM_TYPE_SHORT,
+    IMAGE_SYM_TYPE_INT,
+    IMAGE_SYM_TYPE_LONG,
+    IMAGE_SYM_TYPE_FLOAT,
+    IMAGE_SYM_TYPE_DOUBLE,
+    IMAGE_SYM_TYPE_STRUCT,
+    IMAGE_SYM_TYPE_UNION,
+    IMAGE_SYM_TYPE_ENUM,
+    IMAGE_SYM_TYPE_MOE,
+    IMAGE_SYM_TYPE_BYTE,
+    IMAGE_SYM_TYPE_WORD,
+    IMAGE_SYM_TYPE_UINT,
+    IMAGE_SYM_TYPE_DWORD,
+    IMAGE_SYM_TYPE_PCODE,
+);
+pub const FLAGS_IMAGE_SYM_DTYPE: Names = names!(
+    IMAGE_SYM_DTYPE_NULL,
+    IMAGE_SYM_DTYPE_POINTER,
+    IMAGE_SYM_DTYPE_FUNCTION,
+    IMAGE_SYM_DTYPE_ARRAY,
+);
+pub const FLAGS_IMAGE_SYM_CLASS: Names = names!(
+    IMAGE_SYM_CLASS_END_OF_FUNCTION,
+    IMAGE_SYM_CLASS_NULL,
+    IMAGE_SYM_CLASS_AUTOMATIC,
+    IMAGE_SYM_CLASS_EXTERNAL,
+    IMAGE_SYM_CLASS_STATIC,
+    IMAGE_SYM_CLASS_REGISTER,
+    IMAGE_SYM_CLASS_EXTERNAL_DEF,
+    IMAGE_SYM_CLASS_LABEL,
+    IMAGE_SYM_CLASS_UNDEFINED_LABEL,
+    IMAGE_SYM_CLASS_MEMBER_OF_STRUCT,
+    IMAGE_SYM_CLASS_ARGUMENT,
+    IMAGE_SYM_CLASS_STRUCT_TAG,
+    IMAGE_SYM_CLASS_MEMBER_OF_UNION,
+    IMAGE_SYM_CLASS_UNION_TAG,
+    IMAGE_SYM_CLASS_TYPE_DEFINITION,
+    IMAGE_SYM_CLASS_UNDEFINED_STATIC,
+    IMAGE_SYM_CLASS_ENUM_TAG,
+    IMAGE_SYM_CLASS_MEMBER_OF_ENUM,
+    IMAGE_SYM_CLASS_REGISTER_PARAM,
+    IMAGE_SYM_CLASS_BIT_FIELD,
+    IMAGE_SYM_CLASS_FAR_EXTERNAL,
+    IMAGE_SYM_CLASS_BLOCK,
+    IMAGE_SYM_CLASS_FUNCTION,
+    IMAGE_SYM_CLASS_END_OF_STRUCT,
+    IMAGE_SYM_CLASS_FILE,
+    IMAGE_SYM_CLASS_SECTION,
+    IMAGE_SYM_CLASS_WEAK_EXTERNAL,
+    IMAGE_SYM_CLASS_CLR_TOKEN,
+);
+pub const FLAGS_IMAGE_COMDAT_SELECT: Names = names!(
+    IMAGE_COMDAT_SELECT_NODUPLICATES,
+    IMAGE_COMDAT_SELECT_ANY,
+    IMAGE_COMDAT_SELECT_SAME_SIZE,
+    IMAGE_COMDAT_SELECT_EXACT_MATCH,
+    IMAGE_COMDAT_SELECT_ASSOCIATIVE,
+    IMAGE_COMDAT_SELECT_LARGEST,
+    IMAGE_COMDAT_SELECT_NEWEST,
+);
+pub const FLAGS_IMAGE_SUBSYSTEM: Names = names!(
+    IMAGE_SUBSYSTEM_UNKNOWN,
+    IMAGE_SUBSYSTEM_NATIVE,
+    IMAGE_SUBSYSTEM_WINDOWS_GUI,
+    IMAGE_SUBSYSTEM_WINDOWS_CUI,
+    IMAGE_SUBSYSTEM_OS2_CUI,
+    IMAGE_SUBSYSTEM_POSIX_CUI,
+    IMAGE_SUBSYSTEM_NATIVE_WINDOWS,
+    IMAGE_SUBSYSTEM_WINDOWS_CE_GUI,
+    IMAGE_SUBSYSTEM_EFI_APPLICATION,
+    IMAGE_SUBSYSTEM_EFI_BOOT_SERVICE_DRIVER,
+    IMAGE_SUBSYSTEM_EFI_RUNTIME_DRIVER,
+    IMAGE_SUBSYSTEM_EFI_ROM,
+    IMAGE_SUBSYSTEM_XBOX,
+    IMAGE_SUBSYSTEM_WINDOWS_BOOT_APPLICATION,
+    IMAGE_SUBSYSTEM_XBOX_CODE_CATALOG,
+);
+pub const FLAGS_IMAGE_DLLCHARACTERISTICS: Names = names!(
+    IMAGE_DLLCHARACTERISTICS_HIGH_ENTROPY_VA,
+    IMAGE_DLLCHARACTERISTICS_DYNAMIC_BASE,
+    IMAGE_DLLCHARACTERISTICS_FORCE_INTEGRITY,
+    IMAGE_DLLCHARACTERISTICS_NX_COMPAT,
+    IMAGE_DLLCHARACTERISTICS_NO_ISOLATION,
+    IMAGE_DLLCHARACTERISTICS_NO_SEH,
+    IMAGE_DLLCHARACTERISTICS_NO_BIND,
+    IMAGE_DLLCHARACTERISTICS_APPCONTAINER,
+    IMAGE_DLLCHARACTERISTICS_WDM_DRIVER,
+    IMAGE_DLLCHARACTERISTICS_GUARD_CF,
+    IMAGE_DLLCHARACTERISTICS_TERMINAL_SERVER_AWARE,
+);
+pub const FLAGS_IMAGE_DIRECTORY_ENTRY: Names = names!(
+    IMAGE_DIRECTORY_ENTRY_EXPORT,
+    IMAGE_DIRECTORY_ENTRY_IMPORT,
+    IMAGE_DIRECTORY_ENTRY_RESOURCE,
+    IMAGE_DIRECTORY_ENTRY_EXCEPTION,
+    IMAGE_DIRECTORY_ENTRY_SECURITY,
+    IMAGE_DIRECTORY_ENTRY_BASERELOC,
+    IMAGE_DIRECTORY_ENTRY_DEBUG,
+    IMAGE_DIRECTORY_ENTRY_ARCHITECTURE,
+    IMAGE_DIRECTORY_ENTRY_GLOBALPTR,
+    IMAGE_DIRECTORY_ENTRY_TLS,
+    IMAGE_DIRECTORY_ENTRY_LOAD_CONFIG,
+    IMAGE_DIRECTORY_ENTRY_BOUND_IMPORT,
+    IMAGE_DIRECTORY_ENTRY_IAT,
+    IMAGE_DIRECTORY_ENTRY_DELAY_IMPORT,
+    IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR,
+);
+pub const FLAGS_IMAGE_REL_BASED: Names = names!(
+    IMAGE_REL_BASED_ABSOLUTE,
+    IMAGE_REL_BASED_HIGH,
+    IMAGE_REL_BASED_LOW,
+    IMAGE_REL_BASED_HIGHLOW,
+    IMAGE_REL_BASED_HIGHADJ,
+    IMAGE_REL_BASED_MACHINE_SPECIFIC_5,
+    IMAGE_REL_BASED_RESERVED,
+    IMAGE_REL_BASED_MACHINE_SPECIFIC_7,
+    IMAGE_REL_BASED_MACHINE_SPECIFIC_8,
+    IMAGE_REL_BASED_MACHINE_SPECIFIC_9,
+    IMAGE_REL_BASED_DIR64,
+);
+pub const FLAGS_IMAGE_REL_IA64_BASED: Names = names!(IMAGE_REL_BASED_IA64_IMM64,);
+pub const FLAGS_IMAGE_REL_MIPS_BASED: Names =
+    names!(IMAGE_REL_BASED_MIPS_JMPADDR, IMAGE_REL_BASED_MIPS_JMPADDR16,);
+pub const FLAGS_IMAGE_REL_ARM_BASED: Names =
+    names!(IMAGE_REL_BASED_ARM_MOV32, IMAGE_REL_BASED_THUMB_MOV32,);
+pub const FLAGS_IMAGE_REL_RISCV_BASED: Names = names!(
+    IMAGE_REL_BASED_RISCV_HIGH20,
+    IMAGE_REL_BASED_RISCV_LOW12I,
+    IMAGE_REL_BASED_RISCV_LOW12S,
+);
+pub const FLAGS_RT: Names = names!(
+    RT_CURSOR,
+    RT_BITMAP,
+    RT_ICON,
+    RT_MENU,
+    RT_DIALOG,
+    RT_STRING,
+    RT_FONTDIR,
+    RT_FONT,
+    RT_ACCELERATOR,
+    RT_RCDATA,
+    RT_MESSAGETABLE,
+    RT_GROUP_CURSOR,
+    RT_GROUP_ICON,
+    RT_VERSION,
+    RT_DLGINCLUDE,
+    RT_PLUGPLAY,
+    RT_VXD,
+    RT_ANICURSOR,
+    RT_ANIICON,
+    RT_HTML,
+    RT_MANIFEST,
+);
+pub const FLAGS_IMAGE_OBJECT_TYPE: Names =
+    names!(IMPORT_OBJECT_CODE, IMPORT_OBJECT_DATA, IMPORT_OBJECT_CONST);
+pub const FLAGS_IMAGE_OBJECT_NAME: Names = names!(
+    IMPORT_OBJECT_ORDINAL,
+    IMPORT_OBJECT_NAME,
+    IMPORT_OBJECT_NAME_NO_PREFIX,
+    IMPORT_OBJECT_NAME_UNDECORATE,
+    IMPORT_OBJECT_NAME_EXPORTAS,
+);
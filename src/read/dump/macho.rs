@@ -0,0 +1,567 @@
+//! Dumping of Mach-O files.
+//!
+//! The `FLAGS_*` tables give the names of the Mach-O constants for each field.
+//! They are used for the values of dump nodes, and may also be used to print
+//! the fields of headers that are parsed by other means.
+#![allow(missing_docs)]
+
+use alloc::vec::Vec;
+
+use crate::macho::*;
+use crate::read::macho::{LoadCommandVariant, MachHeader, Nlist, Section, Segment};
+use crate::read::{Result, StringTable};
+use crate::Endianness;
+
+use super::{DumpValue, Dumper, Names};
+
+pub(super) fn dump_macho<'data, Mach: MachHeader<Endian = Endianness>>(
+    d: &mut Dumper<'data>,
+) -> Result<()> {
+    let data = d.data;
+    let header = Mach::parse(data, 0)?;
+    let endian = header.endian()?;
+    dump_mach_header(d, endian, header);
+    let mut commands = match d.result("LoadCommands", header.load_commands(endian, data, 0)) {
+        Some(commands) => commands,
+        None => return Ok(()),
+    };
+    let mut symtab = None;
+    while let Some(Some(command)) = d.result("LoadCommand", commands.next()) {
+        let cmd = command.cmd();
+        let cmdsize = command.cmdsize();
+        let value = super::enum_value(cmd.into(), FLAGS_LC);
+        d.group("LoadCommand", value, command.raw_data(), |d| {
+            let variant = match d.result("Data", command.variant()) {
+                Some(variant) => variant,
+                None => return,
+            };
+            match variant {
+                LoadCommandVariant::Segment32(segment, section_data) => {
+                    dump_segment(d, endian, segment, section_data)
+                }
+                LoadCommandVariant::Segment64(segment, section_data) => {
+                    dump_segment(d, endian, segment, section_data)
+                }
+                LoadCommandVariant::Symtab(command) => {
+                    dump_command_header(d, cmd, cmdsize);
+                    d.hex("SymbolOffset", command.symoff.get(endian));
+                    d.decimal("NumberOfSymbols", command.nsyms.get(endian));
+                    d.hex("StringOffset", command.stroff.get(endian));
+                    d.hex("StringSize", command.strsize.get(endian));
+                    symtab = Some(command);
+                }
+                LoadCommandVariant::Dylib(dylib) | LoadCommandVariant::IdDylib(dylib) => {
+                    dump_command_header(d, cmd, cmdsize);
+                    let offset = dylib.dylib.name.offset.get(endian);
+                    d.string_offset("Name", offset, command.string(endian, dylib.dylib.name));
+                    d.hex("Timestamp", dylib.dylib.timestamp.get(endian));
+                    d.hex("CurrentVersion", dylib.dylib.current_version.get(endian));
+                    d.hex(
+                        "CompatibilityVersion",
+                        dylib.dylib.compatibility_version.get(endian),
+                    );
+                }
+                LoadCommandVariant::Uuid(uuid) => {
+                    dump_command_header(d, cmd, cmdsize);
+                    d.bytes("Uuid", uuid.uuid.len());
+                }
+                LoadCommandVariant::LinkeditData(linkedit) => {
+                    dump_command_header(d, cmd, cmdsize);
+                    d.hex("DataOffset", linkedit.dataoff.get(endian));
+                    d.hex("DataSize", linkedit.datasize.get(endian));
+                }
+                LoadCommandVariant::EntryPoint(entry) => {
+                    dump_command_header(d, cmd, cmdsize);
+                    d.hex("EntryOffset", entry.entryoff.get(endian));
+                    d.hex("StackSize", entry.stacksize.get(endian));
+                }
+                _ => dump_command_header(d, cmd, cmdsize),
+            }
+        });
+    }
+    if let Some(symtab) = symtab {
+        if let Some(symbols) = d.result("SymbolTable", symtab.symbols::<Mach, _>(endian, data)) {
+            let strings = symbols.strings();
+            d.table(
+                "SymbolTable",
+                DumpValue::None,
+                symbols.iter().as_slice(),
+                |d, symbol| dump_nlist::<Mach>(d, endian, symbol, strings),
+            );
+        }
+    }
+    Ok(())
+}
+
+fn dump_mach_header<'data, Mach: MachHeader<Endian = Endianness>>(
+    d: &mut Dumper<'data>,
+    endian: Endianness,
+    header: &'data Mach,
+) {
+    d.structure("MachHeader", DumpValue::None, header, |d| {
+        d.enumeration("Magic", header.magic(), FLAGS_MH_MAGIC);
+        d.enumeration("CpuType", header.cputype(endian), FLAGS_CPU_TYPE);
+        d.hex("CpuSubtype", header.cpusubtype(endian));
+        d.enumeration("FileType", header.filetype(endian), FLAGS_MH_FILETYPE);
+        d.decimal("NumberOfCmds", header.ncmds(endian));
+        d.hex("SizeOfCmds", header.sizeofcmds(endian));
+        d.flags("Flags", header.flags(endian), FLAGS_MH);
+        d.remaining_bytes("Reserved");
+    });
+}
+
+/// Add the fields that are common to all load commands.
+fn dump_command_header(d: &mut Dumper<'_>, cmd: u32, cmdsize: u32) {
+    d.enumeration("Cmd", cmd, FLAGS_LC);
+    d.hex("CmdSize", cmdsize);
+}
+
+fn dump_segment<'data, S: Segment<Endian = Endianness>>(
+    d: &mut Dumper<'data>,
+    endian: Endianness,
+    segment: &'data S,
+    section_data: &'data [u8],
+) {
+    d.enumeration("Cmd", segment.cmd(endian), FLAGS_LC);
+    d.hex("CmdSize", segment.cmdsize(endian));
+    d.string("SegmentName", segment.segname().len());
+    d.hex("VmAddress", segment.vmaddr(endian));
+    d.hex("VmSize", segment.vmsize(endian));
+    d.hex("FileOffset", segment.fileoff(endian));
+    d.hex("FileSize", segment.filesize(endian));
+    d.flags("MaxProtection", segment.maxprot(endian), FLAGS_VM);
+    d.flags("InitProtection", segment.initprot(endian), FLAGS_VM);
+    d.decimal("NumberOfSections", segment.nsects(endian));
+    d.flags("Flags", segment.flags(endian), FLAGS_SG);
+    let sections = match d.result("Sections", segment.sections(endian, section_data)) {
+        Some(sections) => sections,
+        None => return,
+    };
+    for section in sections {
+        let value = DumpValue::String(section.name());
+        d.structure("Section", value, section, |d| {
+            d.string("SectionName", section.sectname().len());
+            d.string("SegmentName", section.segname().len());
+            d.hex("Address", section.addr(endian));
+            d.hex("Size", section.size(endian));
+            d.hex("Offset", section.offset(endian));
+            d.decimal("Align", section.align(endian));
+            d.hex("RelocationOffset", section.reloff(endian));
+            d.decimal("NumberOfRelocations", section.nreloc(endian));
+            // The low byte of the flags is the section type.
+            let flags = section.flags(endian);
+            let mut names = Vec::new();
+            if let Some((_, name)) = FLAGS_S_TYPE
+                .iter()
+                .find(|x| x.0 == (flags & SECTION_TYPE).into())
+            {
+                names.push(*name);
+            }
+            names.extend(
+                FLAGS_S_ATTR
+                    .iter()
+                    .filter(|(flag, _)| u64::from(flags) & flag == *flag)
+                    .map(|(_, name)| *name),
+            );
+            d.field("Flags", 4, DumpValue::Flags(flags.into(), names));
+            d.remaining_bytes("Reserved");
+        });
+    }
+}
+
+fn dump_nlist<'data, Mach: MachHeader<Endian = Endianness>>(
+    d: &mut Dumper<'data>,
+    endian: Endianness,
+    symbol: &'data Mach::Nlist,
+    strings: StringTable<'data>,
+) {
+    let name = symbol.name(endian, strings);
+    let value = name.map(DumpValue::String).unwrap_or(DumpValue::None);
+    d.structure("Nlist", value, symbol, |d| {
+        d.string_offset("Name", symbol.n_strx(endian), name);
+        d.hex("Type", symbol.n_type());
+        d.decimal("Section", symbol.n_sect());
+        d.hex("Desc", symbol.n_desc(endian));
+        d.hex("Value", symbol.n_value(endian));
+    });
+}
+
+pub const FLAGS_MH_MAGIC: Names = names!(MH_MAGIC, MH_CIGAM, MH_MAGIC_64, MH_CIGAM_64);
+pub const FLAGS_CPU_TYPE: Names = names!(
+    CPU_TYPE_ANY,
+    CPU_TYPE_VAX,
+    CPU_TYPE_MC680X0,
+    CPU_TYPE_X86,
+    CPU_TYPE_X86_64,
+    CPU_TYPE_MIPS,
+    CPU_TYPE_MC98000,
+    CPU_TYPE_HPPA,
+    CPU_TYPE_ARM,
+    CPU_TYPE_ARM64,
+    CPU_TYPE_ARM64_32,
+    CPU_TYPE_MC88000,
+    CPU_TYPE_SPARC,
+    CPU_TYPE_I860,
+    CPU_TYPE_ALPHA,
+    CPU_TYPE_POWERPC,
+    CPU_TYPE_POWERPC64,
+);
+pub const FLAGS_CPU_SUBTYPE: Names = names!(CPU_SUBTYPE_LIB64);
+pub const FLAGS_CPU_SUBTYPE_ANY: Names = names!(
+    CPU_SUBTYPE_MULTIPLE,
+    CPU_SUBTYPE_LITTLE_ENDIAN,
+    CPU_SUBTYPE_BIG_ENDIAN,
+);
+pub const FLAGS_CPU_SUBTYPE_VAX: Names = names!(
+    CPU_SUBTYPE_VAX_ALL,
+    CPU_SUBTYPE_VAX780,
+    CPU_SUBTYPE_VAX785,
+    CPU_SUBTYPE_VAX750,
+    CPU_SUBTYPE_VAX730,
+    CPU_SUBTYPE_UVAXI,
+    CPU_SUBTYPE_UVAXII,
+    CPU_SUBTYPE_VAX8200,
+    CPU_SUBTYPE_VAX8500,
+    CPU_SUBTYPE_VAX8600,
+    CPU_SUBTYPE_VAX8650,
+    CPU_SUBTYPE_VAX8800,
+    CPU_SUBTYPE_UVAXIII,
+);
+pub const FLAGS_CPU_SUBTYPE_MC680X0: Names = names!(
+    CPU_SUBTYPE_MC680X0_ALL,
+    CPU_SUBTYPE_MC68040,
+    CPU_SUBTYPE_MC68030_ONLY,
+);
+pub const FLAGS_CPU_SUBTYPE_X86: Names = names!(
+    CPU_SUBTYPE_I386_ALL,
+    CPU_SUBTYPE_386,
+    CPU_SUBTYPE_486,
+    CPU_SUBTYPE_486SX,
+    CPU_SUBTYPE_586,
+    CPU_SUBTYPE_PENT,
+    CPU_SUBTYPE_PENTPRO,
+    CPU_SUBTYPE_PENTII_M3,
+    CPU_SUBTYPE_PENTII_M5,
+    CPU_SUBTYPE_CELERON,
+    CPU_SUBTYPE_CELERON_MOBILE,
+    CPU_SUBTYPE_PENTIUM_3,
+    CPU_SUBTYPE_PENTIUM_3_M,
+    CPU_SUBTYPE_PENTIUM_3_XEON,
+    CPU_SUBTYPE_PENTIUM_M,
+    CPU_SUBTYPE_PENTIUM_4,
+    CPU_SUBTYPE_PENTIUM_4_M,
+    CPU_SUBTYPE_ITANIUM,
+    CPU_SUBTYPE_ITANIUM_2,
+    CPU_SUBTYPE_XEON,
+    CPU_SUBTYPE_XEON_MP,
+);
+pub const FLAGS_CPU_SUBTYPE_X86_64: Names = names!(
+    CPU_SUBTYPE_X86_64_ALL,
+    CPU_SUBTYPE_X86_ARCH1,
+    CPU_SUBTYPE_X86_64_H,
+);
+pub const FLAGS_CPU_SUBTYPE_MIPS: Names = names!(
+    CPU_SUBTYPE_MIPS_ALL,
+    CPU_SUBTYPE_MIPS_R2300,
+    CPU_SUBTYPE_MIPS_R2600,
+    CPU_SUBTYPE_MIPS_R2800,
+    CPU_SUBTYPE_MIPS_R2000A,
+    CPU_SUBTYPE_MIPS_R2000,
+    CPU_SUBTYPE_MIPS_R3000A,
+    CPU_SUBTYPE_MIPS_R3000,
+);
+pub const FLAGS_CPU_SUBTYPE_MC98000: Names = names!(CPU_SUBTYPE_MC98000_ALL, CPU_SUBTYPE_MC98601);
+pub const FLAGS_CPU_SUBTYPE_HPPA: Names = names!(CPU_SUBTYPE_HPPA_ALL, CPU_SUBTYPE_HPPA_7100LC);
+pub const FLAGS_CPU_SUBTYPE_MC88000: Names = names!(
+    CPU_SUBTYPE_MC88000_ALL,
+    CPU_SUBTYPE_MC88100,
+    CPU_SUBTYPE_MC88110,
+);
+pub const FLAGS_CPU_SUBTYPE_SPARC: Names = names!(CPU_SUBTYPE_SPARC_ALL);
+pub const FLAGS_CPU_SUBTYPE_I860: Names = names!(CPU_SUBTYPE_I860_ALL, CPU_SUBTYPE_I860_860);
+pub const FLAGS_CPU_SUBTYPE_POWERPC: Names = names!(
+    CPU_SUBTYPE_POWERPC_ALL,
+    CPU_SUBTYPE_POWERPC_601,
+    CPU_SUBTYPE_POWERPC_602,
+    CPU_SUBTYPE_POWERPC_603,
+    CPU_SUBTYPE_POWERPC_603E,
+    CPU_SUBTYPE_POWERPC_603EV,
+    CPU_SUBTYPE_POWERPC_604,
+    CPU_SUBTYPE_POWERPC_604E,
+    CPU_SUBTYPE_POWERPC_620,
+    CPU_SUBTYPE_POWERPC_750,
+    CPU_SUBTYPE_POWERPC_7400,
+    CPU_SUBTYPE_POWERPC_7450,
+    CPU_SUBTYPE_POWERPC_970,
+);
+pub const FLAGS_CPU_SUBTYPE_ARM: Names = names!(
+    CPU_SUBTYPE_ARM_ALL,
+    CPU_SUBTYPE_ARM_V4T,
+    CPU_SUBTYPE_ARM_V6,
+    CPU_SUBTYPE_ARM_V5TEJ,
+    CPU_SUBTYPE_ARM_XSCALE,
+    CPU_SUBTYPE_ARM_V7,
+    CPU_SUBTYPE_ARM_V7F,
+    CPU_SUBTYPE_ARM_V7S,
+    CPU_SUBTYPE_ARM_V7K,
+    CPU_SUBTYPE_ARM_V8,
+    CPU_SUBTYPE_ARM_V6M,
+    CPU_SUBTYPE_ARM_V7M,
+    CPU_SUBTYPE_ARM_V7EM,
+    CPU_SUBTYPE_ARM_V8M,
+);
+pub const FLAGS_CPU_SUBTYPE_ARM64: Names = names!(
+    CPU_SUBTYPE_ARM64_ALL,
+    CPU_SUBTYPE_ARM64_V8,
+    CPU_SUBTYPE_ARM64E,
+);
+pub const FLAGS_CPU_SUBTYPE_ARM64_32: Names =
+    names!(CPU_SUBTYPE_ARM64_32_ALL, CPU_SUBTYPE_ARM64_32_V8);
+pub const FLAGS_MH_FILETYPE: Names = names!(
+    MH_OBJECT,
+    MH_EXECUTE,
+    MH_FVMLIB,
+    MH_CORE,
+    MH_PRELOAD,
+    MH_DYLIB,
+    MH_DYLINKER,
+    MH_BUNDLE,
+    MH_DYLIB_STUB,
+    MH_DSYM,
+    MH_KEXT_BUNDLE,
+    MH_FILESET,
+);
+pub const FLAGS_MH: Names = names!(
+    MH_NOUNDEFS,
+    MH_INCRLINK,
+    MH_DYLDLINK,
+    MH_BINDATLOAD,
+    MH_PREBOUND,
+    MH_SPLIT_SEGS,
+    MH_LAZY_INIT,
+    MH_TWOLEVEL,
+    MH_FORCE_FLAT,
+    MH_NOMULTIDEFS,
+    MH_NOFIXPREBINDING,
+    MH_PREBINDABLE,
+    MH_ALLMODSBOUND,
+    MH_SUBSECTIONS_VIA_SYMBOLS,
+    MH_CANONICAL,
+    MH_WEAK_DEFINES,
+    MH_BINDS_TO_WEAK,
+    MH_ALLOW_STACK_EXECUTION,
+    MH_ROOT_SAFE,
+    MH_SETUID_SAFE,
+    MH_NO_REEXPORTED_DYLIBS,
+    MH_PIE,
+    MH_DEAD_STRIPPABLE_DYLIB,
+    MH_HAS_TLV_DESCRIPTORS,
+    MH_NO_HEAP_EXECUTION,
+    MH_APP_EXTENSION_SAFE,
+    MH_NLIST_OUTOFSYNC_WITH_DYLDINFO,
+    MH_SIM_SUPPORT,
+    MH_DYLIB_IN_CACHE,
+);
+pub const FLAGS_LC: Names = names!(
+    LC_SEGMENT,
+    LC_SYMTAB,
+    LC_SYMSEG,
+    LC_THREAD,
+    LC_UNIXTHREAD,
+    LC_LOADFVMLIB,
+    LC_IDFVMLIB,
+    LC_IDENT,
+    LC_FVMFILE,
+    LC_PREPAGE,
+    LC_DYSYMTAB,
+    LC_LOAD_DYLIB,
+    LC_ID_DYLIB,
+    LC_LOAD_DYLINKER,
+    LC_ID_DYLINKER,
+    LC_PREBOUND_DYLIB,
+    LC_ROUTINES,
+    LC_SUB_FRAMEWORK,
+    LC_SUB_UMBRELLA,
+    LC_SUB_CLIENT,
+    LC_SUB_LIBRARY,
+    LC_TWOLEVEL_HINTS,
+    LC_PREBIND_CKSUM,
+    LC_LOAD_WEAK_DYLIB,
+    LC_SEGMENT_64,
+    LC_ROUTINES_64,
+    LC_UUID,
+    LC_RPATH,
+    LC_CODE_SIGNATURE,
+    LC_SEGMENT_SPLIT_INFO,
+    LC_REEXPORT_DYLIB,
+    LC_LAZY_LOAD_DYLIB,
+    LC_ENCRYPTION_INFO,
+    LC_DYLD_INFO,
+    LC_DYLD_INFO_ONLY,
+    LC_LOAD_UPWARD_DYLIB,
+    LC_VERSION_MIN_MACOSX,
+    LC_VERSION_MIN_IPHONEOS,
+    LC_FUNCTION_STARTS,
+    LC_DYLD_ENVIRONMENT,
+    LC_MAIN,
+    LC_DATA_IN_CODE,
+    LC_SOURCE_VERSION,
+    LC_DYLIB_CODE_SIGN_DRS,
+    LC_ENCRYPTION_INFO_64,
+    LC_LINKER_OPTION,
+    LC_LINKER_OPTIMIZATION_HINT,
+    LC_VERSION_MIN_TVOS,
+    LC_VERSION_MIN_WATCHOS,
+    LC_NOTE,
+    LC_BUILD_VERSION,
+    LC_DYLD_EXPORTS_TRIE,
+    LC_DYLD_CHAINED_FIXUPS,
+    LC_FILESET_ENTRY,
+);
+pub const FLAGS_VM: Names = names!(VM_PROT_READ, VM_PROT_WRITE, VM_PROT_EXECUTE);
+pub const FLAGS_SG: Names = names!(
+    SG_HIGHVM,
+    SG_FVMLIB,
+    SG_NORELOC,
+    SG_PROTECTED_VERSION_1,
+    SG_READ_ONLY,
+);
+pub const FLAGS_S_TYPE: Names = names!(
+    S_REGULAR,
+    S_ZEROFILL,
+    S_CSTRING_LITERALS,
+    S_4BYTE_LITERALS,
+    S_8BYTE_LITERALS,
+    S_LITERAL_POINTERS,
+    S_NON_LAZY_SYMBOL_POINTERS,
+    S_LAZY_SYMBOL_POINTERS,
+    S_SYMBOL_STUBS,
+    S_MOD_INIT_FUNC_POINTERS,
+    S_MOD_TERM_FUNC_POINTERS,
+    S_COALESCED,
+    S_GB_ZEROFILL,
+    S_INTERPOSING,
+    S_16BYTE_LITERALS,
+    S_DTRACE_DOF,
+    S_LAZY_DYLIB_SYMBOL_POINTERS,
+    S_THREAD_LOCAL_REGULAR,
+    S_THREAD_LOCAL_ZEROFILL,
+    S_THREAD_LOCAL_VARIABLES,
+    S_THREAD_LOCAL_VARIABLE_POINTERS,
+    S_THREAD_LOCAL_INIT_FUNCTION_POINTERS,
+    S_INIT_FUNC_OFFSETS,
+);
+pub const FLAGS_S_ATTR: Names = names!(
+    S_ATTR_PURE_INSTRUCTIONS,
+    S_ATTR_NO_TOC,
+    S_ATTR_STRIP_STATIC_SYMS,
+    S_ATTR_NO_DEAD_STRIP,
+    S_ATTR_LIVE_SUPPORT,
+    S_ATTR_SELF_MODIFYING_CODE,
+    S_ATTR_DEBUG,
+    S_ATTR_SOME_INSTRUCTIONS,
+    S_ATTR_EXT_RELOC,
+    S_ATTR_LOC_RELOC,
+);
+pub const FLAGS_PLATFORM: Names = names!(
+    PLATFORM_MACOS,
+    PLATFORM_IOS,
+    PLATFORM_TVOS,
+    PLATFORM_WATCHOS,
+    PLATFORM_BRIDGEOS,
+    PLATFORM_MACCATALYST,
+    PLATFORM_IOSSIMULATOR,
+    PLATFORM_TVOSSIMULATOR,
+    PLATFORM_WATCHOSSIMULATOR,
+    PLATFORM_DRIVERKIT,
+);
+pub const FLAGS_N_EXT: Names = names!(N_PEXT, N_EXT);
+pub const FLAGS_N_TYPE: Names = names!(N_UNDF, N_ABS, N_SECT, N_PBUD, N_INDR);
+pub const FLAGS_N_STAB: Names = names!(
+    N_GSYM, N_FNAME, N_FUN, N_STSYM, N_LCSYM, N_BNSYM, N_AST, N_OPT, N_RSYM, N_SLINE, N_ENSYM,
+    N_SSYM, N_SO, N_OSO, N_LSYM, N_BINCL, N_SOL, N_PARAMS, N_VERSION, N_OLEVEL, N_PSYM, N_EINCL,
+    N_ENTRY, N_LBRAC, N_EXCL, N_RBRAC, N_BCOMM, N_ECOMM, N_ECOML, N_LENG, N_PC,
+);
+pub const FLAGS_REFERENCE: Names = names!(
+    REFERENCE_FLAG_UNDEFINED_NON_LAZY,
+    REFERENCE_FLAG_UNDEFINED_LAZY,
+    REFERENCE_FLAG_DEFINED,
+    REFERENCE_FLAG_PRIVATE_DEFINED,
+    REFERENCE_FLAG_PRIVATE_UNDEFINED_NON_LAZY,
+    REFERENCE_FLAG_PRIVATE_UNDEFINED_LAZY,
+);
+pub const FLAGS_N_DESC: Names = names!(
+    REFERENCED_DYNAMICALLY,
+    N_NO_DEAD_STRIP,
+    N_DESC_DISCARDED,
+    N_WEAK_REF,
+    N_WEAK_DEF,
+    N_REF_TO_WEAK,
+    N_ARM_THUMB_DEF,
+    N_SYMBOL_RESOLVER,
+    N_ALT_ENTRY,
+);
+pub const FLAGS_GENERIC_RELOC: Names = names!(
+    GENERIC_RELOC_VANILLA,
+    GENERIC_RELOC_PAIR,
+    GENERIC_RELOC_SECTDIFF,
+    GENERIC_RELOC_PB_LA_PTR,
+    GENERIC_RELOC_LOCAL_SECTDIFF,
+    GENERIC_RELOC_TLV,
+);
+pub const FLAGS_ARM_RELOC: Names = names!(
+    ARM_RELOC_VANILLA,
+    ARM_RELOC_PAIR,
+    ARM_RELOC_SECTDIFF,
+    ARM_RELOC_LOCAL_SECTDIFF,
+    ARM_RELOC_PB_LA_PTR,
+    ARM_RELOC_BR24,
+    ARM_THUMB_RELOC_BR22,
+    ARM_THUMB_32BIT_BRANCH,
+    ARM_RELOC_HALF,
+    ARM_RELOC_HALF_SECTDIFF,
+);
+pub const FLAGS_ARM64_RELOC: Names = names!(
+    ARM64_RELOC_UNSIGNED,
+    ARM64_RELOC_SUBTRACTOR,
+    ARM64_RELOC_BRANCH26,
+    ARM64_RELOC_PAGE21,
+    ARM64_RELOC_PAGEOFF12,
+    ARM64_RELOC_GOT_LOAD_PAGE21,
+    ARM64_RELOC_GOT_LOAD_PAGEOFF12,
+    ARM64_RELOC_POINTER_TO_GOT,
+    ARM64_RELOC_TLVP_LOAD_PAGE21,
+    ARM64_RELOC_TLVP_LOAD_PAGEOFF12,
+    ARM64_RELOC_ADDEND,
+    ARM64_RELOC_AUTHENTICATED_POINTER,
+);
+pub const FLAGS_PPC_RELOC: Names = names!(
+    PPC_RELOC_VANILLA,
+    PPC_RELOC_PAIR,
+    PPC_RELOC_BR14,
+    PPC_RELOC_BR24,
+    PPC_RELOC_HI16,
+    PPC_RELOC_LO16,
+    PPC_RELOC_HA16,
+    PPC_RELOC_LO14,
+    PPC_RELOC_SECTDIFF,
+    PPC_RELOC_PB_LA_PTR,
+    PPC_RELOC_HI16_SECTDIFF,
+    PPC_RELOC_LO16_SECTDIFF,
+    PPC_RELOC_HA16_SECTDIFF,
+    PPC_RELOC_JBSR,
+    PPC_RELOC_LO14_SECTDIFF,
+    PPC_RELOC_LOCAL_SECTDIFF,
+);
+pub const FLAGS_X86_64_RELOC: Names = names!(
+    X86_64_RELOC_UNSIGNED,
+    X86_64_RELOC_SIGNED,
+    X86_64_RELOC_BRANCH,
+    X86_64_RELOC_GOT_LOAD,
+    X86_64_RELOC_GOT,
+    X86_64_RELOC_SUBTRACTOR,
+    X86_64_RELOC_SIGNED_1,
+    X86_64_RELOC_SIGNED_2,
+    X86_64_RELOC_SIGNED_4,
+    X86_64_RELOC_TLV,
+);
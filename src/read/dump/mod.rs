@@ -0,0 +1,441 @@
+//! Structured dumps of the headers and tables in a file.
+//!
+//! [`parse`] walks the headers, load commands, directories and tables of a file,
+//! and returns a tree of [`DumpNode`]s. Each node has a name, a decoded value,
+//! and the raw bytes in the file that the value was decoded from. This is the
+//! information needed by hex viewers to annotate a file, or by diff tools to
+//! compare files field by field.
+//!
+//! The names of the nodes and the shape of the tree are specific to each file
+//! format, and closely follow the layout of the file. Errors in individual
+//! tables are recorded as [`DumpValue::Error`] nodes, and the rest of the file
+//! is still dumped.
+//!
+//! ## Example
+//! ```no_run
+//! use object::read::dump;
+//! use std::error::Error;
+//! use std::fs;
+//!
+//! fn main() -> Result<(), Box<dyn Error>> {
+//!     let data = fs::read("path/to/binary")?;
+//!     let root = dump::parse(&*data)?;
+//!     for node in root.children() {
+//!         println!("{} at 0x{:x}", node.name(), node.offset());
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use alloc::vec::Vec;
+use core::{fmt, mem};
+
+use crate::pod::{self, Pod};
+use crate::read::{self, ByteString, Error, FileKind, Result};
+
+/// The names of the constants for a field.
+///
+/// Each entry is the value of a constant and its name. Signed values are sign extended.
+pub type Names = &'static [(u64, &'static str)];
+
+macro_rules! names {
+    ($($name:ident),+ $(,)?) => ( &[ $(($name as u64, stringify!($name))),+ ] )
+}
+
+#[cfg(feature = "coff")]
+pub mod coff;
+
+#[cfg(feature = "elf")]
+pub mod elf;
+
+#[cfg(feature = "macho")]
+pub mod macho;
+
+/// Parse the headers and tables of a file into a tree of nodes.
+///
+/// The root node is named `File`, and its raw bytes are the whole file.
+///
+/// Supports ELF, Mach-O, COFF and PE files. Returns an error for other formats,
+/// or if the file header can't be parsed.
+pub fn parse(data: &[u8]) -> Result<DumpNode<'_>> {
+    let mut d = Dumper::new(data);
+    match FileKind::parse(data)? {
+        #[cfg(feature = "coff")]
+        FileKind::Coff => coff::dump_coff(&mut d)?,
+        #[cfg(feature = "elf")]
+        FileKind::Elf32 => elf::dump_elf::<crate::elf::FileHeader32<crate::Endianness>>(&mut d)?,
+        #[cfg(feature = "elf")]
+        FileKind::Elf64 => elf::dump_elf::<crate::elf::FileHeader64<crate::Endianness>>(&mut d)?,
+        #[cfg(feature = "macho")]
+        FileKind::MachO32 => {
+            macho::dump_macho::<crate::macho::MachHeader32<crate::Endianness>>(&mut d)?
+        }
+        #[cfg(feature = "macho")]
+        FileKind::MachO64 => {
+            macho::dump_macho::<crate::macho::MachHeader64<crate::Endianness>>(&mut d)?
+        }
+        #[cfg(feature = "pe")]
+        FileKind::Pe32 => coff::dump_pe::<crate::pe::ImageNtHeaders32>(&mut d)?,
+        #[cfg(feature = "pe")]
+        FileKind::Pe64 => coff::dump_pe::<crate::pe::ImageNtHeaders64>(&mut d)?,
+        _ => return Err(Error("Unsupported file format for dump")),
+    }
+    Ok(d.finish())
+}
+
+/// A node in the tree returned by [`parse`].
+///
+/// A node is either a structure, such as a header or a table entry, that
+/// contains child nodes for its fields, or it is a single field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DumpNode<'data> {
+    name: &'static str,
+    value: DumpValue<'data>,
+    offset: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "size", serialize_with = "serialize_size")
+    )]
+    raw: &'data [u8],
+    children: Vec<DumpNode<'data>>,
+}
+
+impl<'data> DumpNode<'data> {
+    /// The name of the node, such as `FileHeader` or `Machine`.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The decoded value of the node.
+    ///
+    /// Structures usually have no value, but table entries may use the value
+    /// to identify the entry, such as the name of a section.
+    #[inline]
+    pub fn value(&self) -> &DumpValue<'data> {
+        &self.value
+    }
+
+    /// The offset of the raw bytes in the file.
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// The raw bytes in the file that the node was decoded from.
+    ///
+    /// This is empty for nodes that don't correspond to data in the file,
+    /// such as errors.
+    #[inline]
+    pub fn raw(&self) -> &'data [u8] {
+        self.raw
+    }
+
+    /// The child nodes, in file order.
+    #[inline]
+    pub fn children(&self) -> &[DumpNode<'data>] {
+        &self.children
+    }
+
+    /// Return the first child node with the given name.
+    pub fn child(&self, name: &str) -> Option<&DumpNode<'data>> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+        write!(f, "{:1$}{2}", "", indent * 4, self.name)?;
+        if self.value != DumpValue::None {
+            write!(f, ": {}", self.value)?;
+        }
+        if self.children.is_empty() {
+            return writeln!(f);
+        }
+        writeln!(f, " {{")?;
+        for child in &self.children {
+            child.write(f, indent + 1)?;
+        }
+        writeln!(f, "{:1$}}}", "", indent * 4)
+    }
+}
+
+/// Displays the node and its children with one node per line.
+impl<'data> fmt::Display for DumpNode<'data> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+/// The decoded value of a [`DumpNode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[non_exhaustive]
+pub enum DumpValue<'data> {
+    /// The node has no value of its own.
+    None,
+    /// An integer that is usually displayed in decimal, such as a count.
+    Decimal(u64),
+    /// An integer that is usually displayed in hexadecimal, such as an address.
+    Hex(u64),
+    /// An integer that has a name defined by the file format.
+    Enum(u64, &'static str),
+    /// A set of flags, and the names of the known flags that are set.
+    Flags(u64, Vec<&'static str>),
+    /// A string of unknown encoding.
+    String(#[cfg_attr(feature = "serde", serde(serialize_with = "serialize_string"))] &'data [u8]),
+    /// A sequence of bytes.
+    Bytes(&'data [u8]),
+    /// An error that prevented part of the file from being dumped.
+    Error(&'static str),
+}
+
+impl<'data> fmt::Display for DumpValue<'data> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DumpValue::None => Ok(()),
+            DumpValue::Decimal(value) => write!(f, "{}", value),
+            DumpValue::Hex(value) => write!(f, "0x{:X}", value),
+            DumpValue::Enum(value, name) => write!(f, "{} (0x{:X})", name, value),
+            DumpValue::Flags(value, names) => {
+                write!(f, "0x{:X}", value)?;
+                for (i, name) in names.iter().enumerate() {
+                    f.write_str(if i == 0 { " (" } else { " | " })?;
+                    f.write_str(name)?;
+                }
+                if !names.is_empty() {
+                    f.write_str(")")?;
+                }
+                Ok(())
+            }
+            DumpValue::String(s) => write!(f, "{:?}", ByteString(s)),
+            DumpValue::Bytes(bytes) => write!(f, "{:X?}", bytes),
+            DumpValue::Error(error) => write!(f, "Error: {}", error),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_size<S: serde::Serializer>(
+    raw: &&[u8],
+    serializer: S,
+) -> core::result::Result<S::Ok, S::Error> {
+    serializer.serialize_u64(raw.len() as u64)
+}
+
+#[cfg(feature = "serde")]
+fn serialize_string<S: serde::Serializer>(
+    s: &&[u8],
+    serializer: S,
+) -> core::result::Result<S::Ok, S::Error> {
+    serde::Serialize::serialize(&ByteString(s), serializer)
+}
+
+/// Builds the tree of nodes.
+///
+/// Nodes are built on a stack. Fields consume the raw bytes of the structure
+/// on the top of the stack in order, so they must be added in the order that
+/// they are declared in the structure.
+struct Dumper<'data> {
+    data: &'data [u8],
+    stack: Vec<(DumpNode<'data>, usize)>,
+}
+
+impl<'data> Dumper<'data> {
+    fn new(data: &'data [u8]) -> Self {
+        let root = DumpNode {
+            name: "File",
+            value: DumpValue::None,
+            offset: 0,
+            raw: data,
+            children: Vec::new(),
+        };
+        Dumper {
+            data,
+            stack: vec![(root, 0)],
+        }
+    }
+
+    fn finish(mut self) -> DumpNode<'data> {
+        debug_assert_eq!(self.stack.len(), 1);
+        self.stack.pop().unwrap().0
+    }
+
+    fn node(
+        &self,
+        name: &'static str,
+        value: DumpValue<'data>,
+        raw: &'data [u8],
+    ) -> DumpNode<'data> {
+        let offset = (raw.as_ptr() as usize).wrapping_sub(self.data.as_ptr() as usize);
+        debug_assert!(offset <= self.data.len());
+        DumpNode {
+            name,
+            value,
+            offset: offset as u64,
+            raw,
+            children: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, node: DumpNode<'data>) {
+        let (parent, cursor) = self.stack.last_mut().unwrap();
+        // Nested structures are also fields of their parent.
+        if parent.raw.get(*cursor..).map(<[u8]>::as_ptr) == Some(node.raw.as_ptr()) {
+            *cursor += node.raw.len();
+        }
+        parent.children.push(node);
+    }
+
+    /// Add a node for a structure, and call `f` to add its children.
+    fn group<F: FnOnce(&mut Self)>(
+        &mut self,
+        name: &'static str,
+        value: DumpValue<'data>,
+        raw: &'data [u8],
+        f: F,
+    ) {
+        let node = self.node(name, value, raw);
+        self.stack.push((node, 0));
+        f(self);
+        let (node, _) = self.stack.pop().unwrap();
+        self.add(node);
+    }
+
+    fn structure<T: Pod, F: FnOnce(&mut Self)>(
+        &mut self,
+        name: &'static str,
+        value: DumpValue<'data>,
+        raw: &'data T,
+        f: F,
+    ) {
+        self.group(name, value, pod::bytes_of(raw), f)
+    }
+
+    fn table<T: Pod, F: FnMut(&mut Self, &'data T)>(
+        &mut self,
+        name: &'static str,
+        value: DumpValue<'data>,
+        table: &'data [T],
+        mut f: F,
+    ) {
+        self.group(name, value, pod::bytes_of_slice(table), |d| {
+            for entry in table {
+                f(d, entry);
+            }
+        })
+    }
+
+    /// Return the raw bytes for the next field of the current structure.
+    fn next_raw(&mut self, size: usize) -> &'data [u8] {
+        let (parent, cursor) = self.stack.last_mut().unwrap();
+        let raw = parent.raw;
+        let start = (*cursor).min(raw.len());
+        let end = cursor.saturating_add(size).min(raw.len());
+        *cursor = end;
+        &raw[start..end]
+    }
+
+    fn field(&mut self, name: &'static str, size: usize, value: DumpValue<'data>) {
+        let raw = self.next_raw(size);
+        let node = self.node(name, value, raw);
+        self.stack.last_mut().unwrap().0.children.push(node);
+    }
+
+    fn decimal<T: Into<u64>>(&mut self, name: &'static str, value: T) {
+        self.field(name, mem::size_of::<T>(), DumpValue::Decimal(value.into()))
+    }
+
+    fn hex<T: Into<u64>>(&mut self, name: &'static str, value: T) {
+        self.field(name, mem::size_of::<T>(), DumpValue::Hex(value.into()))
+    }
+
+    fn enumeration<T: Into<u64>>(&mut self, name: &'static str, value: T, names: Names) {
+        self.field(name, mem::size_of::<T>(), enum_value(value.into(), names))
+    }
+
+    fn flags<T: Into<u64>>(&mut self, name: &'static str, value: T, names: Names) {
+        let value = value.into();
+        let set = names
+            .iter()
+            .filter(|(flag, _)| *flag != 0 && value & flag == *flag)
+            .map(|(_, name)| *name)
+            .collect();
+        self.field(name, mem::size_of::<T>(), DumpValue::Flags(value, set))
+    }
+
+    fn bytes(&mut self, name: &'static str, size: usize) {
+        let raw = self.next_raw(size);
+        let node = self.node(name, DumpValue::Bytes(raw), raw);
+        self.stack.last_mut().unwrap().0.children.push(node);
+    }
+
+    /// Add a field for the remaining bytes of the current structure, if any.
+    #[allow(dead_code)]
+    fn remaining_bytes(&mut self, name: &'static str) {
+        let (parent, cursor) = self.stack.last().unwrap();
+        let size = parent.raw.len().saturating_sub(*cursor);
+        if size != 0 {
+            self.bytes(name, size);
+        }
+    }
+
+    /// Add a field for a fixed size string that is padded with nulls.
+    #[allow(dead_code)]
+    fn string(&mut self, name: &'static str, size: usize) {
+        let raw = self.next_raw(size);
+        let len = memchr::memchr(b'\0', raw).unwrap_or(raw.len());
+        let node = self.node(name, DumpValue::String(&raw[..len]), raw);
+        self.stack.last_mut().unwrap().0.children.push(node);
+    }
+
+    /// Add a field for an offset into a string table.
+    ///
+    /// If the string can be read, it is added as a child of the field.
+    fn string_offset<T: Into<u64>>(
+        &mut self,
+        name: &'static str,
+        value: T,
+        string: read::Result<&'data [u8]>,
+    ) {
+        self.string_reference(name, mem::size_of::<T>(), value.into(), string)
+    }
+
+    fn string_reference(
+        &mut self,
+        name: &'static str,
+        size: usize,
+        value: u64,
+        string: read::Result<&'data [u8]>,
+    ) {
+        let raw = self.next_raw(size);
+        let mut node = self.node(name, DumpValue::Hex(value), raw);
+        node.children.push(match string {
+            Ok(s) => self.node("String", DumpValue::String(s), s),
+            Err(e) => self.node("String", DumpValue::Error(e.0), &self.data[..0]),
+        });
+        self.stack.last_mut().unwrap().0.children.push(node);
+    }
+
+    fn error(&mut self, name: &'static str, error: Error) {
+        let node = self.node(name, DumpValue::Error(error.0), &self.data[..0]);
+        self.stack.last_mut().unwrap().0.children.push(node);
+    }
+
+    /// Return the value, or add an error node.
+    fn result<T>(&mut self, name: &'static str, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.error(name, error);
+                None
+            }
+        }
+    }
+}
+
+fn enum_value<'data>(value: u64, names: Names) -> DumpValue<'data> {
+    match names.iter().find(|(known, _)| *known == value) {
+        Some((_, name)) => DumpValue::Enum(value, name),
+        None => DumpValue::Hex(value),
+    }
+}
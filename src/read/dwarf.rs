@@ -0,0 +1,218 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+use crate::read::{
+    Object, ObjectKind, ObjectSection, ObjectSymbol, ReadError, RelocationEncoding, RelocationKind,
+    RelocationTarget, Result, SectionNameIndex,
+};
+
+/// The names of the DWARF sections that are loaded by [`DwarfSections::load`].
+///
+/// These use the ELF naming convention.
+pub const DWARF_SECTION_NAMES: &[&str] = &[
+    ".debug_abbrev",
+    ".debug_addr",
+    ".debug_aranges",
+    ".debug_cu_index",
+    ".debug_frame",
+    ".eh_frame",
+    ".eh_frame_hdr",
+    ".debug_info",
+    ".debug_line",
+    ".debug_line_str",
+    ".debug_loc",
+    ".debug_loclists",
+    ".debug_macinfo",
+    ".debug_macro",
+    ".debug_pubnames",
+    ".debug_pubtypes",
+    ".debug_ranges",
+    ".debug_rnglists",
+    ".debug_str",
+    ".debug_str_offsets",
+    ".debug_tu_index",
+    ".debug_types",
+];
+
+/// Return the XCOFF name for a DWARF section, if it has one.
+fn xcoff_section_name(name: &str) -> Option<&'static str> {
+    Some(match name {
+        ".debug_abbrev" => ".dwabrev",
+        ".debug_aranges" => ".dwarnge",
+        ".debug_frame" => ".dwframe",
+        ".debug_info" => ".dwinfo",
+        ".debug_line" => ".dwline",
+        ".debug_loc" => ".dwloc",
+        ".debug_macinfo" => ".dwmac",
+        ".debug_pubnames" => ".dwpbnms",
+        ".debug_pubtypes" => ".dwpbtyp",
+        ".debug_ranges" => ".dwrnges",
+        ".debug_str" => ".dwstr",
+        _ => return None,
+    })
+}
+
+/// The data for the DWARF sections in an object file.
+///
+/// This handles the differences between file formats, such as section naming
+/// and compression, so that the section data can be passed directly to a DWARF
+/// parser such as `gimli`.
+///
+/// For relocatable object files, the relocations for each section are applied
+/// to the section data. Only absolute and section offset relocations are
+/// supported; other relocations are ignored.
+#[derive(Debug, Default, Clone)]
+pub struct DwarfSections<'data> {
+    sections: Vec<(&'static str, Cow<'data, [u8]>)>,
+}
+
+impl<'data> DwarfSections<'data> {
+    /// Load the data for all of the sections in [`DWARF_SECTION_NAMES`].
+    ///
+    /// Sections that are not present in the file are omitted.
+    pub fn load<'file, O>(file: &'file O) -> Result<Self>
+    where
+        'data: 'file,
+        O: Object<'data, 'file>,
+    {
+        let index = SectionNameIndex::new(file);
+        let mut sections = Vec::new();
+        for name in DWARF_SECTION_NAMES {
+            let section = index.section(file, name.as_bytes()).or_else(|| {
+                xcoff_section_name(name).and_then(|name| index.section(file, name.as_bytes()))
+            });
+            if let Some(section) = section {
+                sections.push((*name, load_section(file, section)?));
+            }
+        }
+        Ok(DwarfSections { sections })
+    }
+
+    /// Get the data for the DWARF section with the given name.
+    ///
+    /// The name must use the ELF naming convention, such as ".debug_info".
+    ///
+    /// Returns an empty slice if the section is not present.
+    pub fn get(&self, name: &str) -> &[u8] {
+        self.sections
+            .iter()
+            .find(|(section_name, _)| *section_name == name)
+            .map(|(_, data)| &**data)
+            .unwrap_or(&[])
+    }
+
+    /// Get an iterator over the names and data of the sections that are present.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &[u8])> {
+        self.sections.iter().map(|(name, data)| (*name, &**data))
+    }
+}
+
+/// Load the data for a DWARF section.
+///
+/// The name must use the ELF naming convention, such as ".debug_info".
+/// It is translated to the naming convention of the file format as needed,
+/// such as "__debug_info" for Mach-O or ".dwinfo" for XCOFF.
+///
+/// The section data is decompressed if needed. For relocatable object files,
+/// the absolute and section offset relocations for the section are applied.
+///
+/// Returns `Ok(None)` if the section is not present.
+pub fn load_dwarf_section<'data, 'file, O>(
+    file: &'file O,
+    name: &str,
+) -> Result<Option<Cow<'data, [u8]>>>
+where
+    'data: 'file,
+    O: Object<'data, 'file>,
+{
+    let section = match file
+        .section_by_name(name)
+        .or_else(|| xcoff_section_name(name).and_then(|name| file.section_by_name(name)))
+    {
+        Some(section) => section,
+        None => return Ok(None),
+    };
+    load_section(file, section).map(Some)
+}
+
+fn load_section<'data, 'file, O>(file: &'file O, section: O::Section) -> Result<Cow<'data, [u8]>>
+where
+    'data: 'file,
+    O: Object<'data, 'file>,
+{
+    let data = section.uncompressed_data()?;
+    if file.kind() != ObjectKind::Relocatable {
+        return Ok(data);
+    }
+    let mut relocations = section.relocations().peekable();
+    if relocations.peek().is_none() {
+        return Ok(data);
+    }
+
+    let mut data = data.into_owned();
+    for (offset, relocation) in relocations {
+        let target = match relocation.target() {
+            RelocationTarget::Symbol(index) => {
+                let symbol = file.symbol_by_index(index)?;
+                let section_address = match symbol.section_index() {
+                    Some(index) => file.section_by_index(index)?.address(),
+                    None => 0,
+                };
+                (symbol.address(), section_address)
+            }
+            RelocationTarget::Section(index) => {
+                let address = file.section_by_index(index)?.address();
+                (address, address)
+            }
+            _ => continue,
+        };
+        let value = match (relocation.kind(), relocation.encoding()) {
+            (RelocationKind::Absolute, RelocationEncoding::Generic) => target.0,
+            (RelocationKind::SectionOffset, _) => target.0.wrapping_sub(target.1),
+            _ => continue,
+        };
+        let size = match relocation.size() {
+            32 => 4,
+            64 => 8,
+            _ => continue,
+        };
+        let bytes = offset
+            .try_into()
+            .ok()
+            .and_then(|offset: usize| data.get_mut(offset..)?.get_mut(..size))
+            .read_error("Invalid DWARF relocation offset")?;
+        let mut addend = relocation.addend() as u64;
+        if relocation.has_implicit_addend() {
+            addend = addend.wrapping_add(read_value(bytes, file.is_little_endian()));
+        }
+        write_value(bytes, file.is_little_endian(), value.wrapping_add(addend));
+    }
+    Ok(Cow::Owned(data))
+}
+
+fn read_value(bytes: &[u8], little_endian: bool) -> u64 {
+    let mut value = 0;
+    for i in 0..bytes.len() {
+        let byte = if little_endian {
+            bytes[bytes.len() - 1 - i]
+        } else {
+            bytes[i]
+        };
+        value = (value << 8) | u64::from(byte);
+    }
+    // Sign extend so that negative implicit addends are handled.
+    if bytes.len() == 4 {
+        value = value as u32 as i32 as i64 as u64;
+    }
+    value
+}
+
+fn write_value(bytes: &mut [u8], little_endian: bool, mut value: u64) {
+    let len = bytes.len();
+    for i in 0..len {
+        let index = if little_endian { i } else { len - 1 - i };
+        bytes[index] = value as u8;
+        value >>= 8;
+    }
+}
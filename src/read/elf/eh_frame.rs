@@ -0,0 +1,210 @@
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+use crate::endian::{Endian, U16Bytes, U32Bytes, U64Bytes};
+use crate::read::{Bytes, FunctionRange};
+
+const DW_EH_PE_ABSPTR: u8 = 0x00;
+const DW_EH_PE_ULEB128: u8 = 0x01;
+const DW_EH_PE_UDATA2: u8 = 0x02;
+const DW_EH_PE_UDATA4: u8 = 0x03;
+const DW_EH_PE_UDATA8: u8 = 0x04;
+const DW_EH_PE_SLEB128: u8 = 0x09;
+const DW_EH_PE_SDATA2: u8 = 0x0a;
+const DW_EH_PE_SDATA4: u8 = 0x0b;
+const DW_EH_PE_SDATA8: u8 = 0x0c;
+const DW_EH_PE_PCREL: u8 = 0x10;
+const DW_EH_PE_FORMAT_MASK: u8 = 0x0f;
+const DW_EH_PE_APPLICATION_MASK: u8 = 0x70;
+
+/// Return the address ranges of the FDEs in an `.eh_frame` section.
+///
+/// `address` is the address of the section data, which is used for PC relative
+/// pointers.
+///
+/// Parsing stops at the first entry that cannot be parsed, and the ranges
+/// that were found before that entry are returned. Pointer encodings other than
+/// absolute and PC relative are not supported, and FDEs that use them are skipped.
+pub(super) fn eh_frame_ranges<E: Endian>(
+    endian: E,
+    is_64: bool,
+    data: &[u8],
+    address: u64,
+) -> Vec<FunctionRange> {
+    let mut ranges = Vec::new();
+    let mut offset = 0;
+    // The offset and pointer encoding of the most recently used CIE.
+    let mut cie = None;
+    while let Some((entry_offset, entry, next)) = entry_at(endian, data, offset) {
+        offset = next;
+        let mut entry = Bytes(entry);
+        let id = match entry.read::<U32Bytes<E>>() {
+            Ok(id) => id.get(endian),
+            Err(()) => break,
+        };
+        if id == 0 {
+            continue;
+        }
+        let cie_offset = match entry_offset.checked_sub(id as usize) {
+            Some(cie_offset) => cie_offset,
+            None => break,
+        };
+        let encoding = match cie {
+            Some((offset, encoding)) if offset == cie_offset => encoding,
+            _ => match cie_encoding(endian, is_64, data, cie_offset) {
+                Some(encoding) => {
+                    cie = Some((cie_offset, encoding));
+                    encoding
+                }
+                None => break,
+            },
+        };
+        let pc = address.wrapping_add((entry_offset + 4) as u64);
+        let begin = read_encoded(endian, is_64, &mut entry, encoding, pc);
+        let size = read_encoded(
+            endian,
+            is_64,
+            &mut entry,
+            encoding & DW_EH_PE_FORMAT_MASK,
+            0,
+        );
+        if let (Some(begin), Some(size)) = (begin, size) {
+            if begin != 0 && size != 0 {
+                ranges.push(FunctionRange::new(begin, size));
+            }
+        }
+    }
+    ranges
+}
+
+/// Return the entry at the given offset.
+///
+/// Returns the offset of the entry contents, the entry contents, and the offset
+/// of the next entry. Returns `None` for the terminator or an invalid entry.
+fn entry_at<E: Endian>(endian: E, data: &[u8], offset: usize) -> Option<(usize, &[u8], usize)> {
+    let mut bytes = Bytes(data);
+    bytes.skip(offset).ok()?;
+    let mut length = u64::from(bytes.read::<U32Bytes<E>>().ok()?.get(endian));
+    let mut header = 4;
+    if length == 0 {
+        return None;
+    }
+    if length == 0xffff_ffff {
+        length = bytes.read::<U64Bytes<E>>().ok()?.get(endian);
+        header += 8;
+    }
+    let length = length.try_into().ok()?;
+    let entry = bytes.read_bytes(length).ok()?;
+    let start = offset + header;
+    Some((start, entry.0, start + length))
+}
+
+/// Return the FDE pointer encoding for the CIE at the given offset.
+fn cie_encoding<E: Endian>(endian: E, is_64: bool, data: &[u8], offset: usize) -> Option<u8> {
+    let (_, entry, _) = entry_at(endian, data, offset)?;
+    let mut entry = Bytes(entry);
+    if entry.read::<U32Bytes<E>>().ok()?.get(endian) != 0 {
+        return None;
+    }
+    let version = *entry.read::<u8>().ok()?;
+    let augmentation = entry.read_string().ok()?;
+    if augmentation.starts_with(b"eh") {
+        entry.skip(if is_64 { 8 } else { 4 }).ok()?;
+    }
+    // Code alignment factor and data alignment factor.
+    entry.read_uleb128().ok()?;
+    entry.read_sleb128().ok()?;
+    // Return address register.
+    if version == 1 {
+        entry.read::<u8>().ok()?;
+    } else {
+        entry.read_uleb128().ok()?;
+    }
+    let augmentation = match augmentation.strip_prefix(b"z") {
+        Some(augmentation) => augmentation,
+        None => return Some(DW_EH_PE_ABSPTR),
+    };
+    entry.read_uleb128().ok()?;
+    for c in augmentation {
+        match c {
+            b'R' => return Some(*entry.read::<u8>().ok()?),
+            b'P' => {
+                let encoding = *entry.read::<u8>().ok()?;
+                read_encoded(
+                    endian,
+                    is_64,
+                    &mut entry,
+                    encoding & DW_EH_PE_FORMAT_MASK,
+                    0,
+                )?;
+            }
+            b'L' => {
+                entry.read::<u8>().ok()?;
+            }
+            b'S' | b'B' | b'G' => {}
+            _ => return None,
+        }
+    }
+    Some(DW_EH_PE_ABSPTR)
+}
+
+/// Read a pointer with the given encoding.
+///
+/// `pc` is the address of the pointer, which is used for PC relative pointers.
+fn read_encoded<E: Endian>(
+    endian: E,
+    is_64: bool,
+    bytes: &mut Bytes<'_>,
+    encoding: u8,
+    pc: u64,
+) -> Option<u64> {
+    let value = match encoding & DW_EH_PE_FORMAT_MASK {
+        DW_EH_PE_ABSPTR if is_64 => bytes.read::<U64Bytes<E>>().ok()?.get(endian),
+        DW_EH_PE_ABSPTR => bytes.read::<U32Bytes<E>>().ok()?.get(endian).into(),
+        DW_EH_PE_ULEB128 => bytes.read_uleb128().ok()?,
+        DW_EH_PE_UDATA2 => bytes.read::<U16Bytes<E>>().ok()?.get(endian).into(),
+        DW_EH_PE_UDATA4 => bytes.read::<U32Bytes<E>>().ok()?.get(endian).into(),
+        DW_EH_PE_UDATA8 => bytes.read::<U64Bytes<E>>().ok()?.get(endian),
+        DW_EH_PE_SLEB128 => bytes.read_sleb128().ok()? as u64,
+        DW_EH_PE_SDATA2 => bytes.read::<U16Bytes<E>>().ok()?.get(endian) as i16 as u64,
+        DW_EH_PE_SDATA4 => bytes.read::<U32Bytes<E>>().ok()?.get(endian) as i32 as u64,
+        DW_EH_PE_SDATA8 => bytes.read::<U64Bytes<E>>().ok()?.get(endian),
+        _ => return None,
+    };
+    let value = match encoding & DW_EH_PE_APPLICATION_MASK {
+        0 => value,
+        DW_EH_PE_PCREL => pc.wrapping_add(value),
+        _ => return None,
+    };
+    if is_64 {
+        Some(value)
+    } else {
+        Some(value & 0xffff_ffff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LittleEndian as LE;
+
+    #[test]
+    fn fde_ranges() {
+        let mut data = Vec::new();
+        // CIE with augmentation "zR" and a PC relative FDE pointer encoding.
+        data.extend_from_slice(&16u32.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&[1, b'z', b'R', 0, 1, 0x78, 0x10, 1, 0x1b, 0, 0, 0]);
+        // FDE.
+        data.extend_from_slice(&13u32.to_le_bytes());
+        data.extend_from_slice(&24u32.to_le_bytes());
+        data.extend_from_slice(&(0x1000i32 - 0x201c).to_le_bytes());
+        data.extend_from_slice(&0x40u32.to_le_bytes());
+        data.push(0);
+        // Terminator.
+        data.extend_from_slice(&0u32.to_le_bytes());
+
+        let ranges = eh_frame_ranges(LE, true, &data, 0x2000);
+        assert_eq!(ranges, [FunctionRange::new(0x1000, 0x40)]);
+    }
+}
@@ -4,15 +4,16 @@ use core::fmt::Debug;
 use core::mem;
 
 use crate::read::{
-    self, util, Architecture, ByteString, Bytes, Error, Export, FileFlags, Import, Object,
-    ObjectKind, ReadError, ReadRef, SectionIndex, StringTable, SymbolIndex,
+    self, security, util, Architecture, ByteString, Bytes, Error, Export, FileFlags, FunctionRange,
+    Import, ImportKind, Object, ObjectKind, ParseOptions, ReadError, ReadRef, Relro, SectionIndex,
+    SecurityFeatures, StringTable, SymbolIndex, SymbolNameCache,
 };
 use crate::{elf, endian, Endian, Endianness, Pod, U32};
 
 use super::{
-    CompressionHeader, Dyn, ElfComdat, ElfComdatIterator, ElfDynamicRelocationIterator, ElfSection,
-    ElfSectionIterator, ElfSegment, ElfSegmentIterator, ElfSymbol, ElfSymbolIterator,
-    ElfSymbolTable, NoteHeader, ProgramHeader, Rel, Rela, RelocationSections, SectionHeader,
+    eh_frame, CompressionHeader, Dyn, ElfComdat, ElfComdatIterator, ElfDynamicRelocationIterator,
+    ElfSection, ElfSectionIterator, ElfSegment, ElfSegmentIterator, ElfSymbol, ElfSymbolIterator,
+    ElfSymbolTable, NoteHeader, ProgramHeader, Rel, Rela, RelocationSections, Relr, SectionHeader,
     SectionTable, Sym, SymbolTable,
 };
 
@@ -40,6 +41,7 @@ where
     pub(super) relocations: RelocationSections,
     pub(super) symbols: SymbolTable<'data, Elf, R>,
     pub(super) dynamic_symbols: SymbolTable<'data, Elf, R>,
+    pub(super) symbol_names: SymbolNameCache<'data>,
 }
 
 impl<'data, Elf, R> ElfFile<'data, Elf, R>
@@ -49,13 +51,25 @@ where
 {
     /// Parse the raw ELF file data.
     pub fn parse(data: R) -> read::Result<Self> {
+        Self::parse_with_options(data, &ParseOptions::default())
+    }
+
+    /// Parse the raw ELF file data, with limits on parsing.
+    ///
+    /// Returns an error if the number of program headers, section headers, or
+    /// entries in a symbol table exceeds [`ParseOptions::max_table_entries`].
+    /// These counts are checked before the tables are read.
+    pub fn parse_with_options(data: R, options: &ParseOptions) -> read::Result<Self> {
         let header = Elf::parse(data)?;
         let endian = header.endian()?;
+        options.check_table_entries(header.phnum(endian, data)? as u64)?;
         let segments = header.program_headers(endian, data)?;
+        options.check_table_entries(header.shnum(endian, data)? as u64)?;
         let sections = header.sections(endian, data)?;
-        let symbols = sections.symbols(endian, data, elf::SHT_SYMTAB)?;
+        let symbols = Self::parse_symbols(endian, data, &sections, elf::SHT_SYMTAB, options)?;
         // TODO: get dynamic symbols from DT_SYMTAB if there are no sections
-        let dynamic_symbols = sections.symbols(endian, data, elf::SHT_DYNSYM)?;
+        let dynamic_symbols =
+            Self::parse_symbols(endian, data, &sections, elf::SHT_DYNSYM, options)?;
         // The API we provide requires a mapping from section to relocations, so build it now.
         let relocations = sections.relocation_sections(endian, symbols.section())?;
 
@@ -68,9 +82,31 @@ where
             relocations,
             symbols,
             dynamic_symbols,
+            symbol_names: SymbolNameCache::default(),
         })
     }
 
+    /// Parse the first symbol table of the given type, after checking its entry count.
+    fn parse_symbols(
+        endian: Elf::Endian,
+        data: R,
+        sections: &SectionTable<'data, Elf, R>,
+        sh_type: u32,
+        options: &ParseOptions,
+    ) -> read::Result<SymbolTable<'data, Elf, R>> {
+        let (index, section) = match sections
+            .iter()
+            .enumerate()
+            .find(|s| s.1.sh_type(endian) == sh_type)
+        {
+            Some(s) => s,
+            None => return Ok(SymbolTable::default()),
+        };
+        let count = section.sh_size(endian).into() / mem::size_of::<Elf::Sym>() as u64;
+        options.check_table_entries(count)?;
+        SymbolTable::parse(endian, data, sections, SectionIndex(index), section)
+    }
+
     /// Returns the endianness.
     pub fn endian(&self) -> Elf::Endian {
         self.endian
@@ -104,18 +140,91 @@ where
             })
     }
 
+    /// Return true if the dynamic section requires immediate binding.
+    fn bind_now(&self) -> bool {
+        let endian = self.endian;
+        if let Ok(Some((dynamic, _))) = self.sections.dynamic(endian, self.data) {
+            for d in dynamic {
+                let now = match d.tag32(endian) {
+                    Some(elf::DT_BIND_NOW) => true,
+                    Some(elf::DT_FLAGS) => d.val32(endian).unwrap_or(0) & elf::DF_BIND_NOW != 0,
+                    Some(elf::DT_FLAGS_1) => d.val32(endian).unwrap_or(0) & elf::DF_1_NOW != 0,
+                    _ => false,
+                };
+                if now {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Return a flag for each dynamic symbol that is true if the symbol is bound lazily.
+    ///
+    /// These are the symbols referenced by the `DT_JMPREL` relocations, unless
+    /// immediate binding is required.
+    fn lazy_symbols(&self) -> read::Result<Vec<bool>> {
+        let endian = self.endian;
+        let mut lazy = vec![false; self.dynamic_symbols.len()];
+        if self.bind_now() {
+            return Ok(lazy);
+        }
+        let mut jmprel = None;
+        if let Some((dynamic, _)) = self.sections.dynamic(endian, self.data)? {
+            for d in dynamic {
+                if d.tag32(endian) == Some(elf::DT_JMPREL) {
+                    jmprel = Some(d.d_val(endian).into());
+                }
+            }
+        }
+        let jmprel = match jmprel {
+            Some(jmprel) => jmprel,
+            None => return Ok(lazy),
+        };
+        let is_mips64el = self.header.is_mips64el(endian);
+        for section in self.sections.iter() {
+            if section.sh_addr(endian).into() != jmprel {
+                continue;
+            }
+            if let Some((rel, _)) = section.rel(endian, self.data)? {
+                for r in rel {
+                    if let Some(flag) = lazy.get_mut(r.r_sym(endian) as usize) {
+                        *flag = true;
+                    }
+                }
+            } else if let Some((rela, _)) = section.rela(endian, self.data)? {
+                for r in rela {
+                    if let Some(flag) = lazy.get_mut(r.r_sym(endian, is_mips64el) as usize) {
+                        *flag = true;
+                    }
+                }
+            }
+        }
+        Ok(lazy)
+    }
+
     #[cfg(feature = "compression")]
     fn zdebug_section_by_name<'file>(
         &'file self,
         section_name: &[u8],
     ) -> Option<ElfSection<'data, 'file, Elf, R>> {
-        if !section_name.starts_with(b".debug_") {
-            return None;
-        }
-        let mut name = Vec::with_capacity(section_name.len() + 1);
-        name.extend_from_slice(b".zdebug_");
-        name.extend_from_slice(&section_name[7..]);
-        self.raw_section_by_name(&name)
+        let suffix = section_name.strip_prefix(b".debug_")?;
+        // Compare the name in place to avoid allocating a copy for every lookup.
+        self.sections
+            .iter()
+            .enumerate()
+            .find(|(_, section)| {
+                self.sections
+                    .section_name(self.endian, section)
+                    .ok()
+                    .and_then(|name| name.strip_prefix(b".zdebug_"))
+                    == Some(suffix)
+            })
+            .map(|(index, section)| ElfSection {
+                file: self,
+                index: SectionIndex(index),
+                section,
+            })
     }
 
     #[cfg(not(feature = "compression"))]
@@ -209,6 +318,15 @@ where
         ElfSegmentIterator {
             file: self,
             iter: self.segments.iter(),
+            all: false,
+        }
+    }
+
+    fn all_segments(&'file self) -> ElfSegmentIterator<'data, 'file, Elf, R> {
+        ElfSegmentIterator {
+            file: self,
+            iter: self.segments.iter(),
+            all: true,
         }
     }
 
@@ -277,6 +395,11 @@ where
         })
     }
 
+    fn symbol_by_name_bytes(&'file self, symbol_name: &[u8]) -> Option<Self::Symbol> {
+        let index = self.symbol_names.get(&self.symbol_table()?, symbol_name)?;
+        self.symbol_by_index(index).ok()
+    }
+
     fn dynamic_symbols(&'file self) -> ElfSymbolIterator<'data, 'file, Elf, R> {
         ElfSymbolIterator {
             endian: self.endian,
@@ -302,20 +425,59 @@ where
             section_index: SectionIndex(1),
             file: self,
             relocations: None,
+            relr: None,
         })
     }
 
+    fn function_ranges(&'file self) -> read::Result<Vec<FunctionRange>> {
+        let mut ranges = Vec::new();
+        // The FDE addresses in relocatable files need relocations applied.
+        if self.kind() != ObjectKind::Relocatable {
+            if let Some((_, section)) = self.sections.section_by_name(self.endian, b".eh_frame") {
+                if let Ok(data) = section.data(self.endian, self.data) {
+                    ranges = eh_frame::eh_frame_ranges(
+                        self.endian,
+                        self.is_64(),
+                        data,
+                        section.sh_addr(self.endian).into(),
+                    );
+                }
+            }
+        }
+        Ok(read::function_ranges(self, ranges))
+    }
+
     /// Get the imported symbols.
     fn imports(&self) -> read::Result<Vec<Import<'data>>> {
+        let versions = self.sections.versions(self.endian, self.data)?;
+        let lazy = self.lazy_symbols()?;
         let mut imports = Vec::new();
-        for symbol in self.dynamic_symbols.iter() {
+        for (index, symbol) in self.dynamic_symbols.iter().enumerate() {
             if symbol.is_undefined(self.endian) {
                 let name = symbol.name(self.endian, self.dynamic_symbols.strings())?;
                 if !name.is_empty() {
-                    // TODO: use symbol versioning to determine library
+                    let mut library = &[][..];
+                    let mut version = None;
+                    if let Some(ref versions) = versions {
+                        let version_index = versions.version_index(self.endian, index);
+                        if let Some(v) = versions.version(version_index)? {
+                            library = v.file().unwrap_or(&[]);
+                            version = Some(ByteString(v.name()));
+                        }
+                    }
+                    let kind = if symbol.st_bind() == elf::STB_WEAK {
+                        ImportKind::Weak
+                    } else if lazy[index] {
+                        ImportKind::Lazy
+                    } else {
+                        ImportKind::Eager
+                    };
                     imports.push(Import {
                         name: ByteString(name),
-                        library: ByteString(&[]),
+                        library: ByteString(library),
+                        ordinal: None,
+                        version,
+                        kind,
                     });
                 }
             }
@@ -333,12 +495,34 @@ where
                 exports.push(Export {
                     name: ByteString(name),
                     address,
+                    forward: None,
                 });
             }
         }
         Ok(exports)
     }
 
+    fn needed_libraries(&self) -> read::Result<Vec<&'data [u8]>> {
+        let mut libraries = Vec::new();
+        if let Some((dynamic, link)) = self.sections.dynamic(self.endian, self.data)? {
+            let strings = self.sections.strings(self.endian, self.data, link)?;
+            for d in dynamic {
+                match d.tag32(self.endian) {
+                    Some(elf::DT_NULL) => break,
+                    Some(elf::DT_NEEDED) => {
+                        let name = d
+                            .val32(self.endian)
+                            .and_then(|val| strings.get(val).ok())
+                            .read_error("Invalid ELF DT_NEEDED string")?;
+                        libraries.push(name);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(libraries)
+    }
+
     fn has_debug_symbols(&self) -> bool {
         for section in self.sections.iter() {
             if let Ok(name) = self.sections.section_name(self.endian, section) {
@@ -427,6 +611,68 @@ where
         self.header.e_entry(self.endian).into()
     }
 
+    fn is_position_independent(&self) -> bool {
+        self.header.e_type(self.endian) == elf::ET_DYN
+    }
+
+    fn security_features(&'file self) -> SecurityFeatures {
+        let endian = self.endian;
+        let mut features = SecurityFeatures {
+            stack_canary: security::stack_canary(self),
+            signed: Some(false),
+            ..Default::default()
+        };
+        if self.header.e_type(endian) != elf::ET_REL {
+            features.position_independent = Some(self.is_position_independent());
+            // The stack is executable if there is no `PT_GNU_STACK`.
+            features.nx = Some(false);
+            features.relro = Some(Relro::None);
+            for segment in self.segments {
+                match segment.p_type(endian) {
+                    elf::PT_GNU_STACK => {
+                        features.nx = Some(segment.p_flags(endian) & elf::PF_X == 0);
+                    }
+                    elf::PT_GNU_RELRO => features.relro = Some(Relro::Partial),
+                    _ => {}
+                }
+            }
+            if features.relro == Some(Relro::Partial) && self.bind_now() {
+                features.relro = Some(Relro::Full);
+            }
+        }
+
+        let machine = self.header.e_machine(endian);
+        let feature_1_and = match machine {
+            elf::EM_AARCH64 => elf::GNU_PROPERTY_AARCH64_FEATURE_1_AND,
+            elf::EM_386 | elf::EM_X86_64 => elf::GNU_PROPERTY_X86_FEATURE_1_AND,
+            _ => return features,
+        };
+        let mut feature_1 = 0;
+        for section in self.sections.iter() {
+            if let Ok(Some(mut notes)) = section.notes(endian, self.data) {
+                while let Ok(Some(note)) = notes.next() {
+                    if let Some(mut properties) = note.gnu_properties(endian) {
+                        while let Ok(Some(property)) = properties.next() {
+                            if property.pr_type() == feature_1_and {
+                                feature_1 = property.data_u32(endian).unwrap_or(0);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        if machine == elf::EM_AARCH64 {
+            features.branch_protection =
+                Some(feature_1 & elf::GNU_PROPERTY_AARCH64_FEATURE_1_BTI != 0);
+            features.pointer_authentication =
+                Some(feature_1 & elf::GNU_PROPERTY_AARCH64_FEATURE_1_PAC != 0);
+        } else {
+            features.branch_protection = Some(feature_1 & elf::GNU_PROPERTY_X86_FEATURE_1_IBT != 0);
+            features.shadow_stack = Some(feature_1 & elf::GNU_PROPERTY_X86_FEATURE_1_SHSTK != 0);
+        }
+        features
+    }
+
     fn flags(&self) -> FileFlags {
         FileFlags::Elf {
             os_abi: self.header.e_ident().os_abi,
@@ -451,6 +697,7 @@ pub trait FileHeader: Debug + Pod {
     type Sym: Sym<Endian = Self::Endian, Word = Self::Word>;
     type Rel: Rel<Endian = Self::Endian, Word = Self::Word>;
     type Rela: Rela<Endian = Self::Endian, Word = Self::Word> + From<Self::Rel>;
+    type Relr: Relr<Endian = Self::Endian, Word = Self::Word>;
 
     /// Return true if this type is a 64-bit header.
     ///
@@ -728,6 +975,7 @@ impl<Endian: endian::Endian> FileHeader for elf::FileHeader32<Endian> {
     type Sym = elf::Sym32<Endian>;
     type Rel = elf::Rel32<Endian>;
     type Rela = elf::Rela32<Endian>;
+    type Relr = elf::Relr32<Endian>;
 
     #[inline]
     fn is_type_64(&self) -> bool {
@@ -825,6 +1073,7 @@ impl<Endian: endian::Endian> FileHeader for elf::FileHeader64<Endian> {
     type Sym = elf::Sym64<Endian>;
     type Rel = elf::Rel64<Endian>;
     type Rela = elf::Rela64<Endian>;
+    type Relr = elf::Relr64<Endian>;
 
     #[inline]
     fn is_type_64(&self) -> bool {
@@ -40,3 +40,5 @@ pub use version::*;
 
 mod attributes;
 pub use attributes::*;
+
+mod eh_frame;
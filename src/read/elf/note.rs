@@ -2,7 +2,7 @@ use core::fmt::Debug;
 use core::mem;
 
 use crate::elf;
-use crate::endian::{self, U32};
+use crate::endian::{self, U32, U64};
 use crate::pod::Pod;
 use crate::read::util;
 use crate::read::{self, Bytes, Error, ReadError};
@@ -158,6 +158,77 @@ impl<'data, Elf: FileHeader> Note<'data, Elf> {
             data: Bytes(self.desc),
         })
     }
+
+    /// Parse the note if it is a known BSD or illumos note.
+    ///
+    /// Returns `Ok(None)` if the note is not recognized.
+    /// Returns `Err` if the note is recognized but its descriptor is invalid.
+    pub fn os_note(&self, endian: Elf::Endian) -> read::Result<Option<OsNote<'data>>> {
+        let desc_u32 = || {
+            Bytes(self.desc)
+                .read_at::<U32<Elf::Endian>>(0)
+                .read_error("Invalid ELF note descriptor size")
+                .map(|val| val.get(endian))
+        };
+        let desc_string = || match memchr::memchr(b'\0', self.desc) {
+            Some(end) => &self.desc[..end],
+            None => self.desc,
+        };
+        let note = match (self.name(), self.n_type(endian)) {
+            (elf::ELF_NOTE_FREEBSD, elf::NT_FREEBSD_ABI_TAG) => OsNote::FreeBsdAbiTag(desc_u32()?),
+            (elf::ELF_NOTE_FREEBSD, elf::NT_FREEBSD_NOINIT_TAG) => OsNote::FreeBsdNoInit,
+            (elf::ELF_NOTE_FREEBSD, elf::NT_FREEBSD_ARCH_TAG) => OsNote::FreeBsdArch(desc_string()),
+            (elf::ELF_NOTE_FREEBSD, elf::NT_FREEBSD_FEATURE_CTL) => {
+                OsNote::FreeBsdFeatureControl(desc_u32()?)
+            }
+            (elf::ELF_NOTE_NETBSD, elf::NT_NETBSD_IDENT) => OsNote::NetBsdIdent(desc_u32()?),
+            (elf::ELF_NOTE_NETBSD, elf::NT_NETBSD_MARCH) => OsNote::NetBsdMarch(desc_string()),
+            (elf::ELF_NOTE_PAX, elf::NT_NETBSD_PAX) => OsNote::NetBsdPax(desc_u32()?),
+            (elf::ELF_NOTE_OPENBSD, elf::NT_OPENBSD_IDENT) => OsNote::OpenBsdIdent(desc_u32()?),
+            (elf::ELF_NOTE_SOLARIS, elf::NT_SOLARIS_PAGESIZE_HINT) => {
+                // The hint is a word or an extended word, depending on the ELF class.
+                let hint = if self.desc.len() == 8 {
+                    Bytes(self.desc)
+                        .read_at::<U64<Elf::Endian>>(0)
+                        .read_error("Invalid ELF note descriptor size")?
+                        .get(endian)
+                } else {
+                    desc_u32()?.into()
+                };
+                OsNote::SolarisPageSizeHint(hint)
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(note))
+    }
+}
+
+/// A parsed BSD or illumos note.
+///
+/// Returned by [`Note::os_note`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OsNote<'data> {
+    /// `NT_FREEBSD_ABI_TAG`: the value of `__FreeBSD_version`.
+    FreeBsdAbiTag(u32),
+    /// `NT_FREEBSD_NOINIT_TAG`.
+    FreeBsdNoInit,
+    /// `NT_FREEBSD_ARCH_TAG`: the architecture name.
+    FreeBsdArch(&'data [u8]),
+    /// `NT_FREEBSD_FEATURE_CTL`: the `NT_FREEBSD_FCTL_*` flags.
+    FreeBsdFeatureControl(u32),
+    /// `NT_NETBSD_IDENT`: the value of `__NetBSD_Version__`.
+    NetBsdIdent(u32),
+    /// `NT_NETBSD_MARCH`: the machine architecture name.
+    NetBsdMarch(&'data [u8]),
+    /// `NT_NETBSD_PAX`: the `NT_NETBSD_PAX_*` flags.
+    NetBsdPax(u32),
+    /// `NT_OPENBSD_IDENT`: the descriptor value.
+    ///
+    /// OpenBSD does not use notes for W^X; see `PT_OPENBSD_WXNEEDED`.
+    OpenBsdIdent(u32),
+    /// `NT_SOLARIS_PAGESIZE_HINT`: the desired page size.
+    SolarisPageSizeHint(u64),
 }
 
 /// A trait for generic access to `NoteHeader32` and `NoteHeader64`.
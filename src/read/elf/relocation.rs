@@ -28,7 +28,8 @@ impl RelocationSections {
         sections: &SectionTable<'data, Elf, R>,
         symbol_section: SectionIndex,
     ) -> read::Result<Self> {
-        let mut relocations = vec![0; sections.len()];
+        // Only allocate the mapping if there are relocation sections to map.
+        let mut relocations = Vec::new();
         for (index, section) in sections.iter().enumerate().rev() {
             let sh_type = section.sh_type(endian);
             if sh_type == elf::SHT_REL || sh_type == elf::SHT_RELA {
@@ -44,9 +45,12 @@ impl RelocationSections {
                     // Skip dynamic relocations.
                     continue;
                 }
-                if sh_info >= relocations.len() {
+                if sh_info >= sections.len() {
                     return Err(Error("Invalid ELF sh_info for relocation section"));
                 }
+                if relocations.is_empty() {
+                    relocations = vec![0; sections.len()];
+                }
 
                 // Handle multiple relocation sections by chaining them.
                 let next = relocations[sh_info];
@@ -108,6 +112,7 @@ where
     pub(super) section_index: SectionIndex,
     pub(super) file: &'file ElfFile<'data, Elf, R>,
     pub(super) relocations: Option<ElfRelaIterator<'data, Elf>>,
+    pub(super) relr: Option<RelrIterator<'data, Elf>>,
 }
 
 impl<'data, 'file, Elf, R> Iterator for ElfDynamicRelocationIterator<'data, 'file, Elf, R>
@@ -128,10 +133,29 @@ where
                 }
                 self.relocations = None;
             }
+            if let Some(ref mut relr) = self.relr {
+                if let Some(address) = relr.next() {
+                    let relocation = Relocation {
+                        kind: RelocationKind::Rebase,
+                        encoding: RelocationEncoding::Generic,
+                        size: <Elf::Relr as Relr>::BITS,
+                        target: RelocationTarget::Absolute,
+                        addend: 0,
+                        implicit_addend: true,
+                    };
+                    return Some((address, relocation));
+                }
+                self.relr = None;
+            }
 
             let section = self.file.sections.section(self.section_index).ok()?;
             self.section_index.0 += 1;
 
+            if let Ok(Some(relr)) = section.relr(endian, self.file.data) {
+                self.relr = Some(relr);
+                continue;
+            }
+
             let sh_link = SectionIndex(section.sh_link(endian) as usize);
             if sh_link != self.file.dynamic_symbols.section() {
                 continue;
@@ -249,6 +273,7 @@ fn parse_relocation<Elf: FileHeader>(
                     elf::R_AARCH64_PREL64 => (RelocationKind::Relative, 64),
                     elf::R_AARCH64_PREL32 => (RelocationKind::Relative, 32),
                     elf::R_AARCH64_PREL16 => (RelocationKind::Relative, 16),
+                    elf::R_AARCH64_RELATIVE => (RelocationKind::Rebase, 64),
                     elf::R_AARCH64_CALL26 => {
                         encoding = RelocationEncoding::AArch64Call;
                         (RelocationKind::PltRelative, 26)
@@ -258,12 +283,14 @@ fn parse_relocation<Elf: FileHeader>(
             } else {
                 match reloc.r_type(endian, false) {
                     elf::R_AARCH64_P32_ABS32 => (RelocationKind::Absolute, 32),
+                    elf::R_AARCH64_P32_RELATIVE => (RelocationKind::Rebase, 32),
                     r_type => (RelocationKind::Elf(r_type), 0),
                 }
             }
         }
         elf::EM_ARM => match reloc.r_type(endian, false) {
             elf::R_ARM_ABS32 => (RelocationKind::Absolute, 32),
+            elf::R_ARM_RELATIVE => (RelocationKind::Rebase, 32),
             r_type => (RelocationKind::Elf(r_type), 0),
         },
         elf::EM_AVR => match reloc.r_type(endian, false) {
@@ -292,6 +319,7 @@ fn parse_relocation<Elf: FileHeader>(
             elf::R_386_PC16 => (RelocationKind::Relative, 16),
             elf::R_386_8 => (RelocationKind::Absolute, 8),
             elf::R_386_PC8 => (RelocationKind::Relative, 8),
+            elf::R_386_RELATIVE => (RelocationKind::Rebase, 32),
             r_type => (RelocationKind::Elf(r_type), 0),
         },
         elf::EM_X86_64 => match reloc.r_type(endian, false) {
@@ -309,6 +337,7 @@ fn parse_relocation<Elf: FileHeader>(
             elf::R_X86_64_PC16 => (RelocationKind::Relative, 16),
             elf::R_X86_64_8 => (RelocationKind::Absolute, 8),
             elf::R_X86_64_PC8 => (RelocationKind::Relative, 8),
+            elf::R_X86_64_RELATIVE => (RelocationKind::Rebase, 64),
             r_type => (RelocationKind::Elf(r_type), 0),
         },
         elf::EM_HEXAGON => match reloc.r_type(endian, false) {
@@ -319,6 +348,10 @@ fn parse_relocation<Elf: FileHeader>(
             elf::R_LARCH_32 => (RelocationKind::Absolute, 32),
             elf::R_LARCH_64 => (RelocationKind::Absolute, 64),
             elf::R_LARCH_32_PCREL => (RelocationKind::Relative, 32),
+            elf::R_LARCH_RELATIVE => (
+                RelocationKind::Rebase,
+                if header.is_type_64() { 64 } else { 32 },
+            ),
             elf::R_LARCH_B16 => {
                 encoding = RelocationEncoding::LoongArchBranch;
                 (RelocationKind::Relative, 16)
@@ -346,16 +379,22 @@ fn parse_relocation<Elf: FileHeader>(
         },
         elf::EM_PPC => match reloc.r_type(endian, false) {
             elf::R_PPC_ADDR32 => (RelocationKind::Absolute, 32),
+            elf::R_PPC_RELATIVE => (RelocationKind::Rebase, 32),
             r_type => (RelocationKind::Elf(r_type), 0),
         },
         elf::EM_PPC64 => match reloc.r_type(endian, false) {
             elf::R_PPC64_ADDR32 => (RelocationKind::Absolute, 32),
             elf::R_PPC64_ADDR64 => (RelocationKind::Absolute, 64),
+            elf::R_PPC64_RELATIVE => (RelocationKind::Rebase, 64),
             r_type => (RelocationKind::Elf(r_type), 0),
         },
         elf::EM_RISCV => match reloc.r_type(endian, false) {
             elf::R_RISCV_32 => (RelocationKind::Absolute, 32),
             elf::R_RISCV_64 => (RelocationKind::Absolute, 64),
+            elf::R_RISCV_RELATIVE => (
+                RelocationKind::Rebase,
+                if header.is_type_64() { 64 } else { 32 },
+            ),
             r_type => (RelocationKind::Elf(r_type), 0),
         },
         elf::EM_S390 => match reloc.r_type(endian, false) {
@@ -574,3 +613,114 @@ impl<Endian: endian::Endian> Rela for elf::Rela64<Endian> {
         self.r_type(endian, is_mips64el)
     }
 }
+
+/// A trait for generic access to `Relr32` and `Relr64`.
+#[allow(missing_docs)]
+pub trait Relr: Debug + Pod + Clone {
+    type Word: Into<u64>;
+    type Endian: endian::Endian;
+
+    /// The number of bits in a word.
+    const BITS: u8;
+
+    fn get(&self, endian: Self::Endian) -> Self::Word;
+}
+
+impl<Endian: endian::Endian> Relr for elf::Relr32<Endian> {
+    type Word = u32;
+    type Endian = Endian;
+    const BITS: u8 = 32;
+
+    #[inline]
+    fn get(&self, endian: Self::Endian) -> Self::Word {
+        self.0.get(endian)
+    }
+}
+
+impl<Endian: endian::Endian> Relr for elf::Relr64<Endian> {
+    type Word = u64;
+    type Endian = Endian;
+    const BITS: u8 = 64;
+
+    #[inline]
+    fn get(&self, endian: Self::Endian) -> Self::Word {
+        self.0.get(endian)
+    }
+}
+
+/// An iterator over the addresses of the places in an `SHT_RELR` section.
+#[derive(Debug, Clone)]
+pub struct RelrIterator<'data, Elf: FileHeader> {
+    endian: Elf::Endian,
+    entries: slice::Iter<'data, Elf::Relr>,
+    /// The address of the first place covered by the next bitmap entry.
+    base: u64,
+    /// The address of the place for the lowest bit in `bits`.
+    address: u64,
+    /// The remaining bits of the current bitmap entry.
+    bits: u64,
+}
+
+impl<'data, Elf: FileHeader> RelrIterator<'data, Elf> {
+    /// Create a new iterator over the given entries.
+    pub fn new(endian: Elf::Endian, entries: &'data [Elf::Relr]) -> Self {
+        RelrIterator {
+            endian,
+            entries: entries.iter(),
+            base: 0,
+            address: 0,
+            bits: 0,
+        }
+    }
+}
+
+impl<'data, Elf: FileHeader> Iterator for RelrIterator<'data, Elf> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let bits = <Elf::Relr as Relr>::BITS;
+        let word_size = u64::from(bits / 8);
+        loop {
+            while self.bits != 0 {
+                let set = self.bits & 1 != 0;
+                let address = self.address;
+                self.bits >>= 1;
+                self.address = self.address.wrapping_add(word_size);
+                if set {
+                    return Some(address);
+                }
+            }
+
+            let entry: u64 = self.entries.next()?.get(self.endian).into();
+            if entry & 1 == 0 {
+                // An address entry. Bitmap entries that follow start at the next word.
+                self.base = entry.wrapping_add(word_size);
+                return Some(entry);
+            }
+            // A bitmap entry. Bit 0 is the marker, and each remaining bit
+            // covers one word.
+            self.bits = entry >> 1;
+            self.address = self.base;
+            self.base = self.base.wrapping_add(word_size * u64::from(bits - 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endian::{LittleEndian as LE, U64};
+
+    #[test]
+    fn relr() {
+        let entries = [
+            elf::Relr64(U64::new(LE, 0x1000)),
+            elf::Relr64(U64::new(LE, (0b101 << 1) | 1)),
+            elf::Relr64(U64::new(LE, (1 << 1) | 1)),
+            elf::Relr64(U64::new(LE, 0x2000)),
+        ];
+        let addresses: Vec<u64> =
+            RelrIterator::<elf::FileHeader64<LE>>::new(LE, &entries).collect();
+        assert_eq!(addresses, [0x1000, 0x1008, 0x1018, 0x1200, 0x2000]);
+    }
+}
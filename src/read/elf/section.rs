@@ -11,8 +11,8 @@ use crate::read::{
 
 use super::{
     AttributesSection, CompressionHeader, ElfFile, ElfSectionRelocationIterator, FileHeader,
-    GnuHashTable, HashTable, NoteIterator, RelocationSections, SymbolTable, VerdefIterator,
-    VerneedIterator, VersionTable,
+    GnuHashTable, HashTable, NoteIterator, RelocationSections, RelrIterator, SymbolTable,
+    VerdefIterator, VerneedIterator, VersionTable,
 };
 
 /// The table of section headers in an ELF file.
@@ -539,6 +539,15 @@ where
         match sh_type {
             elf::SHT_PROGBITS => {
                 if flags & u64::from(elf::SHF_ALLOC) != 0 {
+                    match self.name_bytes() {
+                        Ok(b".got" | b".got.plt") => return SectionKind::Got,
+                        Ok(b".plt" | b".plt.got" | b".plt.sec") => return SectionKind::Plt,
+                        Ok(b".eh_frame" | b".eh_frame_hdr") => return SectionKind::Unwind,
+                        Ok(name) if name.starts_with(b".gcc_except_table") => {
+                            return SectionKind::Unwind
+                        }
+                        _ => {}
+                    }
                     if flags & u64::from(elf::SHF_EXECINSTR) != 0 {
                         SectionKind::Text
                     } else if flags & u64::from(elf::SHF_TLS) != 0 {
@@ -564,15 +573,37 @@ where
                 }
             }
             elf::SHT_NOTE => SectionKind::Note,
+            elf::SHT_INIT_ARRAY => SectionKind::InitArray,
+            elf::SHT_FINI_ARRAY => SectionKind::FiniArray,
+            elf::SHT_DYNAMIC
+            | elf::SHT_DYNSYM
+            | elf::SHT_HASH
+            | elf::SHT_GNU_HASH
+            | elf::SHT_GNU_VERSYM
+            | elf::SHT_GNU_VERDEF
+            | elf::SHT_GNU_VERNEED => SectionKind::Dynamic,
+            // Loadable string tables and relocations are used by the dynamic linker,
+            // such as `.dynstr` and `.rela.dyn`.
+            elf::SHT_STRTAB | elf::SHT_RELA | elf::SHT_REL | elf::SHT_RELR
+                if flags & u64::from(elf::SHF_ALLOC) != 0 =>
+            {
+                SectionKind::Dynamic
+            }
             elf::SHT_NULL
             | elf::SHT_SYMTAB
             | elf::SHT_STRTAB
             | elf::SHT_RELA
-            | elf::SHT_HASH
-            | elf::SHT_DYNAMIC
             | elf::SHT_REL
-            | elf::SHT_DYNSYM
-            | elf::SHT_GROUP => SectionKind::Metadata,
+            | elf::SHT_GROUP
+            | elf::SHT_RELR => SectionKind::Metadata,
+            elf::SHT_X86_64_UNWIND
+                if self.file.header.e_machine(self.file.endian) == elf::EM_X86_64 =>
+            {
+                SectionKind::Unwind
+            }
+            elf::SHT_ARM_EXIDX if self.file.header.e_machine(self.file.endian) == elf::EM_ARM => {
+                SectionKind::Unwind
+            }
             _ => SectionKind::Elf(sh_type),
         }
     }
@@ -749,6 +780,24 @@ pub trait SectionHeader: Debug + Pod {
         Ok(Some((rela, link)))
     }
 
+    /// Return an iterator over the places relocated by an `SHT_RELR` section.
+    ///
+    /// Returns `Ok(None)` if the section does not contain relative relocations.
+    /// Returns `Err` for invalid values.
+    fn relr<'data, R: ReadRef<'data>>(
+        &self,
+        endian: Self::Endian,
+        data: R,
+    ) -> read::Result<Option<RelrIterator<'data, Self::Elf>>> {
+        if self.sh_type(endian) != elf::SHT_RELR {
+            return Ok(None);
+        }
+        let relr = self
+            .data_as_array(endian, data)
+            .read_error("Invalid ELF relocation section offset or size")?;
+        Ok(Some(RelrIterator::new(endian, relr)))
+    }
+
     /// Return entries in a dynamic section.
     ///
     /// Also returns the linked string table index.
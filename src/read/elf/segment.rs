@@ -4,7 +4,9 @@ use core::{mem, slice, str};
 use crate::elf;
 use crate::endian::{self, Endianness};
 use crate::pod::Pod;
-use crate::read::{self, Bytes, ObjectSegment, ReadError, ReadRef, SegmentFlags};
+use crate::read::{
+    self, Bytes, ObjectSegment, Permissions, ReadError, ReadRef, SegmentFlags, SegmentKind,
+};
 
 use super::{ElfFile, FileHeader, NoteIterator};
 
@@ -24,6 +26,7 @@ where
 {
     pub(super) file: &'file ElfFile<'data, Elf, R>,
     pub(super) iter: slice::Iter<'data, Elf::ProgramHeader>,
+    pub(super) all: bool,
 }
 
 impl<'data, 'file, Elf, R> Iterator for ElfSegmentIterator<'data, 'file, Elf, R>
@@ -35,7 +38,7 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         for segment in self.iter.by_ref() {
-            if segment.p_type(self.file.endian) == elf::PT_LOAD {
+            if self.all || segment.p_type(self.file.endian) == elf::PT_LOAD {
                 return Some(ElfSegment {
                     file: self.file,
                     segment,
@@ -89,6 +92,11 @@ where
         self.segment.p_vaddr(self.file.endian).into()
     }
 
+    #[inline]
+    fn physical_address(&self) -> u64 {
+        self.segment.p_paddr(self.file.endian).into()
+    }
+
     #[inline]
     fn size(&self) -> u64 {
         self.segment.p_memsz(self.file.endian).into()
@@ -128,6 +136,26 @@ where
         Ok(None)
     }
 
+    fn kind(&self) -> SegmentKind {
+        match self.segment.p_type(self.file.endian) {
+            elf::PT_LOAD => SegmentKind::Load,
+            elf::PT_DYNAMIC => SegmentKind::Dynamic,
+            elf::PT_TLS => SegmentKind::Tls,
+            elf::PT_NOTE => SegmentKind::Note,
+            p_type => SegmentKind::Elf(p_type),
+        }
+    }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        let p_flags = self.segment.p_flags(self.file.endian);
+        Permissions::new(
+            p_flags & elf::PF_R != 0,
+            p_flags & elf::PF_W != 0,
+            p_flags & elf::PF_X != 0,
+        )
+    }
+
     #[inline]
     fn flags(&self) -> SegmentFlags {
         let p_flags = self.segment.p_flags(self.file.endian);
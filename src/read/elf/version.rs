@@ -38,6 +38,7 @@ impl VersionIndex {
 pub struct Version<'data> {
     name: &'data [u8],
     hash: u32,
+    file: Option<&'data [u8]>,
     // Used to keep track of valid indices in `VersionTable`.
     valid: bool,
 }
@@ -52,6 +53,13 @@ impl<'data> Version<'data> {
     pub fn hash(&self) -> u32 {
         self.hash
     }
+
+    /// Return the file name of the library that the version is required from.
+    ///
+    /// Returns `None` for version definitions.
+    pub fn file(&self) -> Option<&'data [u8]> {
+        self.file
+    }
 }
 
 /// A table of version definitions and requirements.
@@ -124,13 +132,15 @@ impl<'data, Elf: FileHeader> VersionTable<'data, Elf> {
                     versions[usize::from(index)] = Version {
                         name: verdaux.name(endian, strings)?,
                         hash: verdef.vd_hash.get(endian),
+                        file: None,
                         valid: true,
                     };
                 }
             }
         }
         if let Some(mut verneeds) = verneeds {
-            while let Some((_, mut vernauxs)) = verneeds.next()? {
+            while let Some((verneed, mut vernauxs)) = verneeds.next()? {
+                let file = verneed.file(endian, strings)?;
                 while let Some(vernaux) = vernauxs.next()? {
                     let index = vernaux.vna_other.get(endian) & elf::VERSYM_VERSION;
                     if index <= elf::VER_NDX_GLOBAL {
@@ -140,6 +150,7 @@ impl<'data, Elf: FileHeader> VersionTable<'data, Elf> {
                     versions[usize::from(index)] = Version {
                         name: vernaux.name(endian, strings)?,
                         hash: vernaux.vna_hash.get(endian),
+                        file: Some(file),
                         valid: true,
                     };
                 }
@@ -0,0 +1,374 @@
+//! Support for reading Intel HEX files.
+//!
+//! Provides `IhexImage`, which decodes the records of an Intel HEX file into
+//! contiguous blocks of data, and `IhexFile` and related types which implement
+//! the `Object` trait for the decoded data.
+//!
+//! Each block of data is exposed as a loadable segment. Intel HEX files have no
+//! sections, symbols or relocations.
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::{iter, slice};
+
+use crate::read::util::DataBlock;
+use crate::read::{
+    self, Architecture, Error, Export, FileFlags, Import, NoComdat, NoDynamicRelocationIterator,
+    NoSection, NoSymbol, NoSymbolTable, Object, ObjectKind, ObjectSegment, Permissions, ReadError,
+    Result, SectionIndex, SegmentFlags, SegmentKind, SymbolIndex,
+};
+
+const RECORD_DATA: u8 = 0x00;
+const RECORD_END_OF_FILE: u8 = 0x01;
+const RECORD_EXTENDED_SEGMENT_ADDRESS: u8 = 0x02;
+const RECORD_START_SEGMENT_ADDRESS: u8 = 0x03;
+const RECORD_EXTENDED_LINEAR_ADDRESS: u8 = 0x04;
+const RECORD_START_LINEAR_ADDRESS: u8 = 0x05;
+
+/// The decoded contents of an Intel HEX file.
+///
+/// Intel HEX files are text, so the data must be decoded before it can be
+/// accessed through the `Object` API. Use [`IhexImage::file`] to obtain an
+/// `IhexFile` that borrows the decoded data.
+#[derive(Debug, Clone, Default)]
+pub struct IhexImage {
+    segments: Vec<DataBlock>,
+    entry: u64,
+}
+
+impl IhexImage {
+    /// Decode the records of an Intel HEX file.
+    ///
+    /// Records with adjacent addresses are combined into a single segment, and
+    /// the segments are sorted by address. Addresses are computed using the most
+    /// recent extended segment address or extended linear address record.
+    ///
+    /// Returns an error if a record is invalid, if records contain overlapping
+    /// data, or if the end of file record is missing.
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let mut image = IhexImage::default();
+        let mut base = 0;
+        let mut end_of_file = false;
+        for line in data.split(|c| *c == b'\n') {
+            let line = read::util::trim_ascii(line);
+            if line.is_empty() {
+                continue;
+            }
+            if end_of_file {
+                return Err(Error("Intel HEX data after end of file record"));
+            }
+            let record = line
+                .strip_prefix(b":")
+                .read_error("Missing Intel HEX record start code")?;
+            let record = decode_record(record)?;
+            let kind = record[3];
+            let offset = u64::from(u16::from_be_bytes([record[1], record[2]]));
+            let payload = &record[4..record.len() - 1];
+            match kind {
+                RECORD_DATA => {
+                    read::util::add_data_block(&mut image.segments, base + offset, payload)
+                }
+                RECORD_END_OF_FILE => end_of_file = true,
+                RECORD_EXTENDED_SEGMENT_ADDRESS => {
+                    base = u64::from(read_u16(payload)?) << 4;
+                }
+                RECORD_START_SEGMENT_ADDRESS => {
+                    let (segment, pointer) = match *payload {
+                        [a, b, c, d] => (u16::from_be_bytes([a, b]), u16::from_be_bytes([c, d])),
+                        _ => return Err(Error("Invalid Intel HEX start segment address")),
+                    };
+                    image.entry = (u64::from(segment) << 4) + u64::from(pointer);
+                }
+                RECORD_EXTENDED_LINEAR_ADDRESS => {
+                    base = u64::from(read_u16(payload)?) << 16;
+                }
+                RECORD_START_LINEAR_ADDRESS => {
+                    image.entry = match *payload {
+                        [a, b, c, d] => u64::from(u32::from_be_bytes([a, b, c, d])),
+                        _ => return Err(Error("Invalid Intel HEX start linear address")),
+                    };
+                }
+                _ => return Err(Error("Unknown Intel HEX record type")),
+            }
+        }
+        if !end_of_file {
+            return Err(Error("Missing Intel HEX end of file record"));
+        }
+
+        image.segments = read::util::merge_data_blocks(image.segments)
+            .read_error("Overlapping Intel HEX data records")?;
+        Ok(image)
+    }
+
+    /// Return an `IhexFile` for accessing the decoded data.
+    #[inline]
+    pub fn file(&self) -> IhexFile<'_> {
+        IhexFile { image: self }
+    }
+}
+
+/// Return true if the data starts with an Intel HEX record.
+///
+/// This checks the start code and the hex digits of the smallest possible record.
+pub(crate) fn is_ihex(data: &[u8]) -> bool {
+    match data {
+        [b':', digits @ ..] => digits
+            .get(..10)
+            .map_or(false, |digits| digits.iter().all(u8::is_ascii_hexdigit)),
+        _ => false,
+    }
+}
+
+/// Decode the hex digits of a record and validate its length and checksum.
+///
+/// The returned bytes include the byte count, address, record type and checksum.
+fn decode_record(record: &[u8]) -> Result<Vec<u8>> {
+    if record.len() % 2 != 0 {
+        return Err(Error("Invalid Intel HEX record length"));
+    }
+    let bytes = read::util::decode_hex(record).read_error("Invalid Intel HEX digit")?;
+    if bytes.len() < 5 || bytes.len() != usize::from(bytes[0]) + 5 {
+        return Err(Error("Invalid Intel HEX record length"));
+    }
+    if bytes.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte)) != 0 {
+        return Err(Error("Invalid Intel HEX record checksum"));
+    }
+    Ok(bytes)
+}
+
+fn read_u16(payload: &[u8]) -> Result<u16> {
+    match *payload {
+        [high, low] => Ok(u16::from_be_bytes([high, low])),
+        _ => Err(Error("Invalid Intel HEX address record")),
+    }
+}
+
+/// An Intel HEX file.
+///
+/// This borrows the data decoded by [`IhexImage::parse`].
+#[derive(Debug, Clone, Copy)]
+pub struct IhexFile<'data> {
+    image: &'data IhexImage,
+}
+
+impl<'data> read::private::Sealed for IhexFile<'data> {}
+
+impl<'data, 'file> Object<'data, 'file> for IhexFile<'data>
+where
+    'data: 'file,
+{
+    type Segment = IhexSegment<'data, 'file>;
+    type SegmentIterator = IhexSegmentIterator<'data, 'file>;
+    type Section = NoSection;
+    type SectionIterator = iter::Empty<NoSection>;
+    type Comdat = NoComdat;
+    type ComdatIterator = iter::Empty<NoComdat>;
+    type Symbol = NoSymbol;
+    type SymbolIterator = iter::Empty<NoSymbol>;
+    type SymbolTable = NoSymbolTable;
+    type DynamicRelocationIterator = NoDynamicRelocationIterator;
+
+    #[inline]
+    fn architecture(&self) -> Architecture {
+        Architecture::Unknown
+    }
+
+    #[inline]
+    fn is_little_endian(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn is_64(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn kind(&self) -> ObjectKind {
+        ObjectKind::Unknown
+    }
+
+    fn segments(&'file self) -> Self::SegmentIterator {
+        IhexSegmentIterator {
+            segments: self.image.segments.iter(),
+            marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn section_by_name_bytes(&'file self, _section_name: &[u8]) -> Option<NoSection> {
+        None
+    }
+
+    #[inline]
+    fn section_by_index(&'file self, _index: SectionIndex) -> Result<NoSection> {
+        Err(Error("Invalid Intel HEX section index"))
+    }
+
+    #[inline]
+    fn sections(&'file self) -> Self::SectionIterator {
+        iter::empty()
+    }
+
+    #[inline]
+    fn comdats(&'file self) -> Self::ComdatIterator {
+        iter::empty()
+    }
+
+    #[inline]
+    fn symbol_by_index(&'file self, _index: SymbolIndex) -> Result<NoSymbol> {
+        Err(Error("Invalid Intel HEX symbol index"))
+    }
+
+    #[inline]
+    fn symbols(&'file self) -> Self::SymbolIterator {
+        iter::empty()
+    }
+
+    #[inline]
+    fn symbol_table(&'file self) -> Option<NoSymbolTable> {
+        None
+    }
+
+    #[inline]
+    fn dynamic_symbols(&'file self) -> Self::SymbolIterator {
+        iter::empty()
+    }
+
+    #[inline]
+    fn dynamic_symbol_table(&'file self) -> Option<NoSymbolTable> {
+        None
+    }
+
+    #[inline]
+    fn dynamic_relocations(&self) -> Option<NoDynamicRelocationIterator> {
+        None
+    }
+
+    fn imports(&self) -> Result<Vec<Import<'data>>> {
+        Ok(Vec::new())
+    }
+
+    fn exports(&self) -> Result<Vec<Export<'data>>> {
+        Ok(Vec::new())
+    }
+
+    #[inline]
+    fn has_debug_symbols(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn relative_address_base(&self) -> u64 {
+        0
+    }
+
+    /// Returns the address from the start linear address or start segment
+    /// address record, or 0 if there is no such record.
+    #[inline]
+    fn entry(&self) -> u64 {
+        self.image.entry
+    }
+
+    #[inline]
+    fn flags(&self) -> FileFlags {
+        FileFlags::None
+    }
+}
+
+/// An iterator over the segments of an `IhexFile`.
+#[derive(Debug)]
+pub struct IhexSegmentIterator<'data, 'file> {
+    segments: slice::Iter<'data, DataBlock>,
+    marker: PhantomData<&'file ()>,
+}
+
+impl<'data, 'file> Iterator for IhexSegmentIterator<'data, 'file> {
+    type Item = IhexSegment<'data, 'file>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.segments.next().map(|segment| IhexSegment {
+            segment,
+            marker: PhantomData,
+        })
+    }
+}
+
+/// A segment of an `IhexFile`.
+///
+/// This is a block of contiguous data from one or more data records.
+#[derive(Debug)]
+pub struct IhexSegment<'data, 'file> {
+    segment: &'data DataBlock,
+    marker: PhantomData<&'file ()>,
+}
+
+impl<'data, 'file> read::private::Sealed for IhexSegment<'data, 'file> {}
+
+impl<'data, 'file> ObjectSegment<'data> for IhexSegment<'data, 'file> {
+    #[inline]
+    fn address(&self) -> u64 {
+        self.segment.address
+    }
+
+    #[inline]
+    fn physical_address(&self) -> u64 {
+        self.address()
+    }
+
+    #[inline]
+    fn size(&self) -> u64 {
+        self.segment.data.len() as u64
+    }
+
+    #[inline]
+    fn align(&self) -> u64 {
+        1
+    }
+
+    /// Returns `(0, 0)` because the decoded data does not correspond to a
+    /// range of the file.
+    #[inline]
+    fn file_range(&self) -> (u64, u64) {
+        (0, 0)
+    }
+
+    #[inline]
+    fn data(&self) -> Result<&'data [u8]> {
+        Ok(&self.segment.data)
+    }
+
+    fn data_range(&self, address: u64, size: u64) -> Result<Option<&'data [u8]>> {
+        Ok(read::util::data_range(
+            &self.segment.data,
+            self.segment.address,
+            address,
+            size,
+        ))
+    }
+
+    #[inline]
+    fn name_bytes(&self) -> Result<Option<&[u8]>> {
+        Ok(None)
+    }
+
+    #[inline]
+    fn name(&self) -> Result<Option<&str>> {
+        Ok(None)
+    }
+
+    #[inline]
+    fn kind(&self) -> SegmentKind {
+        SegmentKind::Load
+    }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        Permissions::default()
+    }
+
+    #[inline]
+    fn flags(&self) -> SegmentFlags {
+        SegmentFlags::None
+    }
+}
@@ -211,6 +211,16 @@ where
     }
 }
 
+/// Return true if the data is a dyld subcache file.
+///
+/// Subcache files have the same header as the main cache file, but do not
+/// contain an image list.
+pub(crate) fn is_dyld_subcache<'data, R: ReadRef<'data>>(data: R) -> Result<bool> {
+    let header = macho::DyldCacheHeader::<Endianness>::parse(data)?;
+    let (_arch, endian) = header.parse_magic()?;
+    Ok(header.images(endian, data)?.is_empty())
+}
+
 impl<E: Endian> macho::DyldCacheHeader<E> {
     /// Read the dyld cache header.
     pub fn parse<'data, R: ReadRef<'data>>(data: R) -> Result<&'data Self> {
@@ -0,0 +1,154 @@
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+use crate::macho;
+use crate::read::{Bytes, Error, ParseOptions, ReadError, Result};
+
+/// A Mach-O export trie.
+///
+/// This is the data referenced by the `LC_DYLD_EXPORTS_TRIE` load command,
+/// or by the export fields of the `LC_DYLD_INFO` load command.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ExportTrie<'data> {
+    data: Bytes<'data>,
+}
+
+impl<'data> ExportTrie<'data> {
+    /// Construct a new export trie from the given data.
+    pub fn new(data: &'data [u8]) -> Self {
+        ExportTrie { data: Bytes(data) }
+    }
+
+    /// Return the raw trie data.
+    pub fn data(&self) -> &'data [u8] {
+        self.data.0
+    }
+
+    /// Return all of the terminal entries in the trie.
+    ///
+    /// The entries are returned in trie order.
+    pub fn entries(&self) -> Result<Vec<ExportTrieEntry<'data>>> {
+        self.entries_with_options(&ParseOptions::default())
+    }
+
+    /// Return all of the terminal entries in the trie, with limits on parsing.
+    ///
+    /// Returns an error if a node is nested more than [`ParseOptions::max_depth`]
+    /// edges below the root, or if a node is reached by more than one edge.
+    pub fn entries_with_options(
+        &self,
+        options: &ParseOptions,
+    ) -> Result<Vec<ExportTrieEntry<'data>>> {
+        let mut entries = Vec::new();
+        if self.data.is_empty() {
+            return Ok(entries);
+        }
+        let mut visited = BTreeSet::new();
+        // A stack of node offsets, names and depths, with the next node to visit last.
+        let mut stack = alloc::vec![(0, Vec::new(), 0)];
+        let mut children = Vec::new();
+        while let Some((offset, name, depth)) = stack.pop() {
+            options.check_depth(depth)?;
+            if !visited.insert(offset) {
+                return Err(Error("Mach-O export trie node is reached more than once"));
+            }
+            let mut node = self.data;
+            node.skip(offset)
+                .read_error("Invalid Mach-O export trie node offset")?;
+            let terminal_size = node
+                .read_uleb128()
+                .read_error("Invalid Mach-O export trie terminal size")?;
+            let terminal = node
+                .read_bytes(terminal_size as usize)
+                .read_error("Invalid Mach-O export trie terminal size")?;
+            if !terminal.is_empty() {
+                entries.push(
+                    ExportTrieEntry::parse(name.clone(), terminal)
+                        .read_error("Invalid Mach-O export trie terminal information")?,
+                );
+            }
+            let child_count = *node
+                .read::<u8>()
+                .read_error("Invalid Mach-O export trie child count")?;
+            for _ in 0..child_count {
+                let edge = node
+                    .read_string()
+                    .read_error("Invalid Mach-O export trie edge")?;
+                let child = node
+                    .read_uleb128()
+                    .read_error("Invalid Mach-O export trie child offset")?;
+                let mut child_name = name.clone();
+                child_name.extend_from_slice(edge);
+                children.push((child as usize, child_name, depth + 1));
+            }
+            stack.extend(children.drain(..).rev());
+        }
+        Ok(entries)
+    }
+}
+
+/// A terminal entry in a Mach-O export trie.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportTrieEntry<'data> {
+    name: Vec<u8>,
+    flags: u64,
+    address: u64,
+    other: u64,
+    import_name: &'data [u8],
+}
+
+impl<'data> ExportTrieEntry<'data> {
+    fn parse(name: Vec<u8>, mut terminal: Bytes<'data>) -> core::result::Result<Self, ()> {
+        let flags = terminal.read_uleb128()?;
+        let mut entry = ExportTrieEntry {
+            name,
+            flags,
+            address: 0,
+            other: 0,
+            import_name: &[],
+        };
+        if flags & u64::from(macho::EXPORT_SYMBOL_FLAGS_REEXPORT) != 0 {
+            entry.other = terminal.read_uleb128()?;
+            entry.import_name = terminal.read_string()?;
+        } else {
+            entry.address = terminal.read_uleb128()?;
+            if flags & u64::from(macho::EXPORT_SYMBOL_FLAGS_STUB_AND_RESOLVER) != 0 {
+                entry.other = terminal.read_uleb128()?;
+            }
+        }
+        Ok(entry)
+    }
+
+    /// The symbol name.
+    ///
+    /// This is the concatenation of the edge labels from the root to this node.
+    pub fn name(&self) -> &[u8] {
+        &self.name
+    }
+
+    /// The `EXPORT_SYMBOL_FLAGS_*` flags.
+    pub fn flags(&self) -> u64 {
+        self.flags
+    }
+
+    /// The symbol address relative to the image base.
+    ///
+    /// For `EXPORT_SYMBOL_FLAGS_STUB_AND_RESOLVER` this is the stub offset.
+    /// This is zero for re-exports.
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// The library ordinal for re-exports, or the resolver offset for
+    /// `EXPORT_SYMBOL_FLAGS_STUB_AND_RESOLVER`.
+    pub fn other(&self) -> u64 {
+        self.other
+    }
+
+    /// The name of the symbol in the library for re-exports.
+    ///
+    /// This is empty if the symbol has the same name in the library.
+    pub fn import_name(&self) -> &'data [u8] {
+        self.import_name
+    }
+}
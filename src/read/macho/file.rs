@@ -1,18 +1,23 @@
 use alloc::vec::Vec;
 use core::fmt::Debug;
+use core::marker::PhantomData;
 use core::{mem, str};
 
+use crate::endian::{U32Bytes, U64Bytes};
 use crate::read::{
-    self, Architecture, ComdatKind, Error, Export, FileFlags, Import, NoDynamicRelocationIterator,
-    Object, ObjectComdat, ObjectKind, ObjectMap, ObjectSection, ReadError, ReadRef, Result,
-    SectionIndex, SymbolIndex,
+    self, security, Architecture, CodeSignature, ComdatKind, Error, Export, ExportForward,
+    FileFlags, FunctionRange, Import, ImportKind, Object, ObjectComdat, ObjectKind, ObjectMap,
+    ObjectSection, ParseOptions, ReadError, ReadRef, Result, SectionIndex, SecurityFeatures,
+    SourceFile, SymbolIndex, SymbolNameCache,
 };
-use crate::{endian, macho, BigEndian, ByteString, Endian, Endianness, Pod};
+use crate::{endian, macho, BigEndian, ByteString, Bytes, Endian, Endianness, Pod};
 
 use super::{
-    DyldCacheImage, LoadCommandIterator, MachOSection, MachOSectionInternal, MachOSectionIterator,
-    MachOSegment, MachOSegmentInternal, MachOSegmentIterator, MachOSymbol, MachOSymbolIterator,
-    MachOSymbolTable, Nlist, Section, Segment, SymbolTable,
+    lazy_bind_names, ChainedState, DyldCacheImage, ExportTrie, LoadCommandIterator,
+    MachODynamicRelocationIterator, MachODynamicRelocationState, MachOSection,
+    MachOSectionInternal, MachOSectionIterator, MachOSegment, MachOSegmentInternal,
+    MachOSegmentIterator, MachOSymbol, MachOSymbolIterator, MachOSymbolTable, Nlist, RebaseState,
+    Section, Segment, SymbolTable,
 };
 
 /// A 32-bit Mach-O object file.
@@ -38,6 +43,7 @@ where
     pub(super) segments: Vec<MachOSegmentInternal<'data, Mach, R>>,
     pub(super) sections: Vec<MachOSectionInternal<'data, Mach>>,
     pub(super) symbols: SymbolTable<'data, Mach, R>,
+    pub(super) symbol_names: SymbolNameCache<'data>,
 }
 
 impl<'data, Mach, R> MachOFile<'data, Mach, R>
@@ -47,8 +53,18 @@ where
 {
     /// Parse the raw Mach-O file data.
     pub fn parse(data: R) -> Result<Self> {
+        Self::parse_with_options(data, &ParseOptions::default())
+    }
+
+    /// Parse the raw Mach-O file data, with limits on parsing.
+    ///
+    /// Returns an error if the number of load commands, sections, or symbols
+    /// exceeds [`ParseOptions::max_table_entries`]. These counts are checked
+    /// before the tables are read.
+    pub fn parse_with_options(data: R, options: &ParseOptions) -> Result<Self> {
         let header = Mach::parse(data, 0)?;
         let endian = header.endian()?;
+        options.check_table_entries(header.ncmds(endian).into())?;
 
         // Build a list of segments and sections to make some operations more efficient.
         let mut segments = Vec::new();
@@ -59,11 +75,19 @@ where
                 if let Some((segment, section_data)) = Mach::Segment::from_command(command)? {
                     let segment_index = segments.len();
                     segments.push(MachOSegmentInternal { segment, data });
+                    let nsects = u64::from(segment.nsects(endian));
+                    options.check_table_entries(sections.len() as u64 + nsects)?;
                     for section in segment.sections(endian, section_data)? {
                         let index = SectionIndex(sections.len() + 1);
-                        sections.push(MachOSectionInternal::parse(index, segment_index, section));
+                        sections.push(MachOSectionInternal::parse(
+                            index,
+                            segment_index,
+                            section,
+                            endian,
+                        ));
                     }
                 } else if let Some(symtab) = command.symtab()? {
+                    options.check_table_entries(symtab.nsyms.get(endian).into())?;
                     symbols = symtab.symbols(endian, data)?;
                 }
             }
@@ -77,6 +101,7 @@ where
             segments,
             sections,
             symbols,
+            symbol_names: SymbolNameCache::default(),
         })
     }
 
@@ -115,7 +140,12 @@ where
 
                     for section in segment.sections(endian, section_data)? {
                         let index = SectionIndex(sections.len() + 1);
-                        sections.push(MachOSectionInternal::parse(index, segment_index, section));
+                        sections.push(MachOSectionInternal::parse(
+                            index,
+                            segment_index,
+                            section,
+                            endian,
+                        ));
                     }
                 } else if let Some(st) = command.symtab()? {
                     symtab = Some(st);
@@ -138,6 +168,7 @@ where
             segments,
             sections,
             symbols,
+            symbol_names: SymbolNameCache::default(),
         })
     }
 
@@ -154,6 +185,202 @@ where
             .read_error("Invalid Mach-O section index")
     }
 
+    /// Return the program counter from the data of a `LC_UNIXTHREAD` command.
+    fn thread_pc(&self, data: &'data [u8]) -> Option<u64> {
+        let mut data = Bytes(data);
+        // Skip `cmd` and `cmdsize`.
+        data.skip(8).ok()?;
+        let flavor = data.read::<U32Bytes<Mach::Endian>>().ok()?.get(self.endian);
+        let _count = data.read::<U32Bytes<Mach::Endian>>().ok()?;
+        let (offset, is_64) = match (self.header.cputype(self.endian), flavor) {
+            (macho::CPU_TYPE_X86, macho::X86_THREAD_STATE32) => (40, false),
+            (macho::CPU_TYPE_X86_64, macho::X86_THREAD_STATE64) => (128, true),
+            (macho::CPU_TYPE_ARM, macho::ARM_THREAD_STATE) => (60, false),
+            (macho::CPU_TYPE_ARM64, macho::ARM_THREAD_STATE64) => (256, true),
+            _ => return None,
+        };
+        if is_64 {
+            let pc = data.read_at::<U64Bytes<Mach::Endian>>(offset).ok()?;
+            Some(pc.get(self.endian))
+        } else {
+            let pc = data.read_at::<U32Bytes<Mach::Endian>>(offset).ok()?;
+            Some(pc.get(self.endian).into())
+        }
+    }
+
+    /// Return the dynamic symbol table command, and the library names
+    /// indexed by library ordinal if the file uses two-level namespaces.
+    #[allow(clippy::type_complexity)]
+    fn dysymtab_and_libraries(
+        &self,
+    ) -> Result<(
+        Option<&'data macho::DysymtabCommand<Mach::Endian>>,
+        Option<Vec<&'data [u8]>>,
+    )> {
+        let mut dysymtab = None;
+        let twolevel = self.header.flags(self.endian) & macho::MH_TWOLEVEL != 0;
+        let mut libraries = Vec::new();
+        if twolevel {
+            libraries.push(&[][..]);
+        }
+        let mut commands = self
+            .header
+            .load_commands(self.endian, self.data, self.header_offset)?;
+        while let Some(command) = commands.next()? {
+            if let Some(command) = command.dysymtab()? {
+                dysymtab = Some(command);
+            }
+            if twolevel {
+                if let Some(dylib) = command.dylib()? {
+                    libraries.push(command.string(self.endian, dylib.dylib.name)?);
+                }
+            }
+        }
+        Ok((dysymtab, if twolevel { Some(libraries) } else { None }))
+    }
+
+    /// Return the names of the symbols in the lazy binding opcodes of `LC_DYLD_INFO`.
+    ///
+    /// The names are sorted.
+    fn lazy_bind_symbols(&self) -> Result<Vec<&'data [u8]>> {
+        let mut names = Vec::new();
+        let mut commands = self
+            .header
+            .load_commands(self.endian, self.data, self.header_offset)?;
+        while let Some(command) = commands.next()? {
+            if let Some(dyld_info) = command.dyld_info()? {
+                let offset = dyld_info.lazy_bind_off.get(self.endian);
+                let size = dyld_info.lazy_bind_size.get(self.endian);
+                let opcodes = self
+                    .data
+                    .read_bytes_at(offset.into(), size.into())
+                    .read_error("Invalid Mach-O lazy bind opcodes offset or size")?;
+                // An invalid opcode stops the decoding, but the names before it
+                // are still used to determine which imports are lazy.
+                let _ = lazy_bind_names(Bytes(opcodes), &mut names);
+            }
+        }
+        names.sort_unstable();
+        Ok(names)
+    }
+
+    /// Return the export trie from `LC_DYLD_EXPORTS_TRIE` or `LC_DYLD_INFO`.
+    ///
+    /// Returns `Ok(None)` if there is no export trie.
+    pub fn export_trie(&self) -> Result<Option<ExportTrie<'data>>> {
+        let mut commands = self
+            .header
+            .load_commands(self.endian, self.data, self.header_offset)?;
+        while let Some(command) = commands.next()? {
+            let (offset, size) = if let Some(command) = command.dyld_exports_trie()? {
+                (
+                    command.dataoff.get(self.endian),
+                    command.datasize.get(self.endian),
+                )
+            } else if let Some(dyld_info) = command.dyld_info()? {
+                (
+                    dyld_info.export_off.get(self.endian),
+                    dyld_info.export_size.get(self.endian),
+                )
+            } else {
+                continue;
+            };
+            if size == 0 {
+                continue;
+            }
+            let data = self
+                .data
+                .read_bytes_at(offset.into(), size.into())
+                .read_error("Invalid Mach-O export trie offset or size")?;
+            return Ok(Some(ExportTrie::new(data)));
+        }
+        Ok(None)
+    }
+
+    /// Return the file offset and contents of the `__TEXT,__info_plist` section.
+    ///
+    /// This is the `Info.plist` that the linker embeds in command line tools.
+    pub fn info_plist(&self) -> Result<Option<(u64, &'data [u8])>> {
+        let internal = match self.sections.iter().find(|internal| {
+            internal.section.segment_name() == macho::SEG_TEXT.as_bytes()
+                && internal.section.name() == b"__info_plist"
+        }) {
+            Some(internal) => internal,
+            None => return Ok(None),
+        };
+        let (offset, size) = match internal.section.file_range(self.endian) {
+            Some(range) => range,
+            None => return Ok(None),
+        };
+        let segment = self.segment_internal(internal.segment_index)?;
+        let data = segment
+            .data
+            .read_bytes_at(offset, size)
+            .read_error("Invalid Mach-O __info_plist offset or size")?;
+        Ok(Some((offset, data)))
+    }
+
+    /// Return the file offset and contents of the XML entitlements in the code signature.
+    ///
+    /// The contents exclude the blob header.
+    /// Returns `Ok(None)` if there is no code signature or it has no entitlements.
+    pub fn entitlements(&self) -> Result<Option<(u64, &'data [u8])>> {
+        let (signature_offset, signature) = match self.code_signature_data()? {
+            Some(signature) => signature,
+            None => return Ok(None),
+        };
+        let signature = Bytes(signature);
+        let mut tail = signature;
+        let header = tail
+            .read::<macho::CsSuperBlob>()
+            .read_error("Invalid Mach-O code signature header")?;
+        if header.magic.get(BigEndian) != macho::CSMAGIC_EMBEDDED_SIGNATURE {
+            return Err(Error("Invalid Mach-O code signature magic"));
+        }
+        let index = tail
+            .read_slice::<macho::CsBlobIndex>(header.count.get(BigEndian) as usize)
+            .read_error("Invalid Mach-O code signature index count")?;
+        for entry in index {
+            if entry.typ.get(BigEndian) != macho::CSSLOT_ENTITLEMENTS {
+                continue;
+            }
+            // Blob offsets are relative to the start of the super blob.
+            let offset = entry.offset.get(BigEndian) as usize;
+            let blob = signature
+                .read_at::<macho::CsGenericBlob>(offset)
+                .read_error("Invalid Mach-O code signature blob offset")?;
+            if blob.magic.get(BigEndian) != macho::CSMAGIC_EMBEDDED_ENTITLEMENTS {
+                return Err(Error("Invalid Mach-O entitlements magic"));
+            }
+            let header_size = mem::size_of::<macho::CsGenericBlob>();
+            let data = (blob.length.get(BigEndian) as usize)
+                .checked_sub(header_size)
+                .and_then(|size| signature.read_bytes_at(offset + header_size, size).ok())
+                .read_error("Invalid Mach-O entitlements length")?;
+            let data_offset = signature_offset + (offset + header_size) as u64;
+            return Ok(Some((data_offset, data.0)));
+        }
+        Ok(None)
+    }
+
+    /// Return the file offset and contents of the `LC_CODE_SIGNATURE` data.
+    fn code_signature_data(&self) -> Result<Option<(u64, &'data [u8])>> {
+        let mut commands = self
+            .header
+            .load_commands(self.endian, self.data, self.header_offset)?;
+        while let Some(command) = commands.next()? {
+            if let Some(command) = command.code_signature()? {
+                let offset = command.dataoff.get(self.endian).into();
+                let data = self
+                    .data
+                    .read_bytes_at(offset, command.datasize.get(self.endian).into())
+                    .read_error("Invalid Mach-O code signature offset or size")?;
+                return Ok(Some((offset, data)));
+            }
+        }
+        Ok(None)
+    }
+
     pub(super) fn segment_internal(
         &self,
         index: usize,
@@ -186,7 +413,7 @@ where
     type Symbol = MachOSymbol<'data, 'file, Mach, R>;
     type SymbolIterator = MachOSymbolIterator<'data, 'file, Mach, R>;
     type SymbolTable = MachOSymbolTable<'data, 'file, Mach, R>;
-    type DynamicRelocationIterator = NoDynamicRelocationIterator;
+    type DynamicRelocationIterator = MachODynamicRelocationIterator<'data, 'file, Mach, R>;
 
     fn architecture(&self) -> Architecture {
         match self.header.cputype(self.endian) {
@@ -280,7 +507,10 @@ where
     }
 
     fn comdats(&'file self) -> MachOComdatIterator<'data, 'file, Mach, R> {
-        MachOComdatIterator { file: self }
+        MachOComdatIterator {
+            file: self,
+            index: 0,
+        }
     }
 
     fn symbol_by_index(
@@ -303,6 +533,11 @@ where
         Some(MachOSymbolTable { file: self })
     }
 
+    fn symbol_by_name_bytes(&'file self, symbol_name: &[u8]) -> Option<Self::Symbol> {
+        let index = self.symbol_names.get(&self.symbol_table()?, symbol_name)?;
+        self.symbol_by_index(index).ok()
+    }
+
     fn dynamic_symbols(&'file self) -> MachOSymbolIterator<'data, 'file, Mach, R> {
         MachOSymbolIterator {
             file: self,
@@ -319,27 +554,13 @@ where
         self.symbols.object_map(self.endian)
     }
 
-    fn imports(&self) -> Result<Vec<Import<'data>>> {
-        let mut dysymtab = None;
-        let mut libraries = Vec::new();
-        let twolevel = self.header.flags(self.endian) & macho::MH_TWOLEVEL != 0;
-        if twolevel {
-            libraries.push(&[][..]);
-        }
-        let mut commands = self
-            .header
-            .load_commands(self.endian, self.data, self.header_offset)?;
-        while let Some(command) = commands.next()? {
-            if let Some(command) = command.dysymtab()? {
-                dysymtab = Some(command);
-            }
-            if twolevel {
-                if let Some(dylib) = command.dylib()? {
-                    libraries.push(command.string(self.endian, dylib.dylib.name)?);
-                }
-            }
-        }
+    fn source_files(&'file self) -> Result<Vec<SourceFile<'data>>> {
+        self.symbols.source_files(self.endian)
+    }
 
+    fn imports(&self) -> Result<Vec<Import<'data>>> {
+        let (dysymtab, libraries) = self.dysymtab_and_libraries()?;
+        let lazy = self.lazy_bind_symbols()?;
         let mut imports = Vec::new();
         if let Some(dysymtab) = dysymtab {
             let index = dysymtab.iundefsym.get(self.endian) as usize;
@@ -347,7 +568,7 @@ where
             for i in index..(index.wrapping_add(number)) {
                 let symbol = self.symbols.symbol(i)?;
                 let name = symbol.name(self.endian, self.symbols.strings())?;
-                let library = if twolevel {
+                let library = if let Some(ref libraries) = libraries {
                     libraries
                         .get(symbol.library_ordinal(self.endian) as usize)
                         .copied()
@@ -355,9 +576,19 @@ where
                 } else {
                     &[]
                 };
+                let kind = if symbol.n_desc(self.endian) & macho::N_WEAK_REF != 0 {
+                    ImportKind::Weak
+                } else if lazy.binary_search(&name).is_ok() {
+                    ImportKind::Lazy
+                } else {
+                    ImportKind::Eager
+                };
                 imports.push(Import {
                     name: ByteString(name),
                     library: ByteString(library),
+                    ordinal: None,
+                    version: None,
+                    kind,
                 });
             }
         }
@@ -365,17 +596,7 @@ where
     }
 
     fn exports(&self) -> Result<Vec<Export<'data>>> {
-        let mut dysymtab = None;
-        let mut commands = self
-            .header
-            .load_commands(self.endian, self.data, self.header_offset)?;
-        while let Some(command) = commands.next()? {
-            if let Some(command) = command.dysymtab()? {
-                dysymtab = Some(command);
-                break;
-            }
-        }
-
+        let (dysymtab, libraries) = self.dysymtab_and_libraries()?;
         let mut exports = Vec::new();
         if let Some(dysymtab) = dysymtab {
             let index = dysymtab.iextdefsym.get(self.endian) as usize;
@@ -383,19 +604,95 @@ where
             for i in index..(index.wrapping_add(number)) {
                 let symbol = self.symbols.symbol(i)?;
                 let name = symbol.name(self.endian, self.symbols.strings())?;
+                if symbol.n_type() & macho::N_TYPE == macho::N_INDR {
+                    // A re-export. The value is the string table offset of the target name.
+                    let target = self
+                        .symbols
+                        .strings()
+                        .get(symbol.n_value(self.endian).into() as u32)
+                        .read_error("Invalid Mach-O indirect symbol name offset")?;
+                    let library = libraries
+                        .as_ref()
+                        .and_then(|libraries| {
+                            libraries.get(symbol.library_ordinal(self.endian) as usize)
+                        })
+                        .copied()
+                        .unwrap_or(&[]);
+                    exports.push(Export {
+                        name: ByteString(name),
+                        address: 0,
+                        forward: Some(ExportForward {
+                            library: ByteString(library),
+                            name: ByteString(target),
+                            ordinal: None,
+                        }),
+                    });
+                    continue;
+                }
                 let address = symbol.n_value(self.endian).into();
                 exports.push(Export {
                     name: ByteString(name),
                     address,
+                    forward: None,
                 });
             }
         }
         Ok(exports)
     }
 
-    #[inline]
-    fn dynamic_relocations(&'file self) -> Option<NoDynamicRelocationIterator> {
-        None
+    fn needed_libraries(&self) -> Result<Vec<&'data [u8]>> {
+        let mut libraries = Vec::new();
+        let mut commands = self
+            .header
+            .load_commands(self.endian, self.data, self.header_offset)?;
+        while let Some(command) = commands.next()? {
+            if let Some(dylib) = command.dylib()? {
+                libraries.push(command.string(self.endian, dylib.dylib.name)?);
+            }
+        }
+        Ok(libraries)
+    }
+
+    fn dynamic_relocations(
+        &'file self,
+    ) -> Option<MachODynamicRelocationIterator<'data, 'file, Mach, R>> {
+        let mut state = None;
+        let mut commands = self
+            .header
+            .load_commands(self.endian, self.data, self.header_offset)
+            .ok()?;
+        while let Ok(Some(command)) = commands.next() {
+            if let Ok(Some(dyld_info)) = command.dyld_info() {
+                let offset = dyld_info.rebase_off.get(self.endian);
+                let size = dyld_info.rebase_size.get(self.endian);
+                let opcodes = self.data.read_bytes_at(offset.into(), size.into()).ok()?;
+                state = Some(MachODynamicRelocationState::Rebase(RebaseState::new(
+                    opcodes,
+                )));
+            } else if let Ok(Some(fixups)) = command.dyld_chained_fixups() {
+                let offset = fixups.dataoff.get(self.endian);
+                let size = fixups.datasize.get(self.endian);
+                let data = self.data.read_bytes_at(offset.into(), size.into()).ok()?;
+                state = ChainedState::parse(self.endian, data)
+                    .map(MachODynamicRelocationState::Chained);
+            }
+        }
+        // The image base is the address of the segment containing the header.
+        let image_base = self
+            .segments
+            .iter()
+            .map(|segment| segment.segment)
+            .find(|segment| {
+                segment.fileoff(self.endian).into() == 0
+                    && segment.filesize(self.endian).into() != 0
+            })
+            .map(|segment| segment.vmaddr(self.endian).into())
+            .unwrap_or(0);
+        Some(MachODynamicRelocationIterator {
+            file: self,
+            image_base,
+            state: state?,
+        })
     }
 
     fn has_debug_symbols(&self) -> bool {
@@ -406,6 +703,48 @@ where
         self.header.uuid(self.endian, self.data, self.header_offset)
     }
 
+    fn code_signature(&self) -> Result<Option<CodeSignature<'data>>> {
+        Ok(self
+            .code_signature_data()?
+            .map(|(_, data)| CodeSignature::MachO(data)))
+    }
+
+    fn function_ranges(&'file self) -> Result<Vec<FunctionRange>> {
+        let mut ranges = Vec::new();
+        let mut commands = self
+            .header
+            .load_commands(self.endian, self.data, self.header_offset)?;
+        while let Some(command) = commands.next()? {
+            if let Some(command) = command.function_starts()? {
+                let mut data = self
+                    .data
+                    .read_bytes_at(
+                        command.dataoff.get(self.endian).into(),
+                        command.datasize.get(self.endian).into(),
+                    )
+                    .map(Bytes)
+                    .read_error("Invalid Mach-O function starts offset or size")?;
+                // The addresses are encoded as ULEB128 deltas, starting from
+                // the address of the `__TEXT` segment.
+                let mut address = self
+                    .segments
+                    .iter()
+                    .find(|internal| internal.segment.name() == b"__TEXT")
+                    .map(|internal| internal.segment.vmaddr(self.endian).into())
+                    .unwrap_or(0);
+                while let Ok(delta) = data.read_uleb128() {
+                    if delta == 0 {
+                        break;
+                    }
+                    address = address.wrapping_add(delta);
+                    ranges.push(FunctionRange::new(address, 0));
+                }
+                break;
+            }
+        }
+        Ok(read::function_ranges(self, ranges))
+    }
+
     fn relative_address_base(&self) -> u64 {
         0
     }
@@ -417,13 +756,81 @@ where
         {
             while let Ok(Some(command)) = commands.next() {
                 if let Ok(Some(command)) = command.entry_point() {
-                    return command.entryoff.get(self.endian);
+                    // This is a file offset, which needs converting to an address.
+                    let offset = command.entryoff.get(self.endian);
+                    for segment in &self.segments {
+                        let (fileoff, filesize) = segment.segment.file_range(self.endian);
+                        if offset >= fileoff && offset - fileoff < filesize {
+                            let vmaddr: u64 = segment.segment.vmaddr(self.endian).into();
+                            return vmaddr.wrapping_add(offset - fileoff);
+                        }
+                    }
+                    return 0;
+                }
+                if command.cmd() == macho::LC_UNIXTHREAD {
+                    if let Some(address) = self.thread_pc(command.raw_data()) {
+                        return address;
+                    }
                 }
             }
         }
         0
     }
 
+    fn is_position_independent(&self) -> bool {
+        match self.header.filetype(self.endian) {
+            macho::MH_DYLIB | macho::MH_BUNDLE => true,
+            _ => self.header.flags(self.endian) & macho::MH_PIE != 0,
+        }
+    }
+
+    fn security_features(&'file self) -> SecurityFeatures {
+        let flags = self.header.flags(self.endian);
+        let executable = self.header.filetype(self.endian) == macho::MH_EXECUTE;
+        let pointer_authentication = if self.header.cputype(self.endian) == macho::CPU_TYPE_ARM64 {
+            Some(
+                self.header.cpusubtype(self.endian) & !macho::CPU_SUBTYPE_MASK
+                    == macho::CPU_SUBTYPE_ARM64E,
+            )
+        } else {
+            None
+        };
+        SecurityFeatures {
+            nx: if executable {
+                Some(flags & macho::MH_ALLOW_STACK_EXECUTION == 0)
+            } else {
+                None
+            },
+            position_independent: if self.header.filetype(self.endian) == macho::MH_OBJECT {
+                None
+            } else {
+                Some(self.is_position_independent())
+            },
+            pointer_authentication,
+            stack_canary: security::stack_canary(self),
+            signed: Some(matches!(self.code_signature(), Ok(Some(_)))),
+            ..Default::default()
+        }
+    }
+
+    fn is_stripped(&self) -> bool {
+        let mut commands =
+            match self
+                .header
+                .load_commands(self.endian, self.data, self.header_offset)
+            {
+                Ok(commands) => commands,
+                Err(_) => return true,
+            };
+        while let Ok(Some(command)) = commands.next() {
+            if let Ok(Some(dysymtab)) = command.dysymtab() {
+                // Stripping removes the local symbols, including debugging symbols.
+                return dysymtab.nlocalsym.get(self.endian) == 0 && !self.has_debug_symbols();
+            }
+        }
+        self.symbols.is_empty() && !self.has_debug_symbols()
+    }
+
     fn flags(&self) -> FileFlags {
         FileFlags::MachO {
             flags: self.header.flags(self.endian),
@@ -431,7 +838,7 @@ where
     }
 }
 
-/// An iterator over the COMDAT section groups of a `MachOFile64`.
+/// An iterator over the COMDAT section groups of a `MachOFile32`.
 pub type MachOComdatIterator32<'data, 'file, Endian = Endianness, R = &'data [u8]> =
     MachOComdatIterator<'data, 'file, macho::MachHeader32<Endian>, R>;
 /// An iterator over the COMDAT section groups of a `MachOFile64`.
@@ -439,14 +846,19 @@ pub type MachOComdatIterator64<'data, 'file, Endian = Endianness, R = &'data [u8
     MachOComdatIterator<'data, 'file, macho::MachHeader64<Endian>, R>;
 
 /// An iterator over the COMDAT section groups of a `MachOFile`.
+///
+/// Mach-O does not have section groups. Instead, each external weak definition
+/// in a `S_COALESCED` section is treated as a COMDAT with an `Any` selection kind,
+/// containing the section that the symbol is defined in. Weak definitions in
+/// other sections are not COMDATs, since their section may contain other code.
 #[derive(Debug)]
 pub struct MachOComdatIterator<'data, 'file, Mach, R = &'data [u8]>
 where
     Mach: MachHeader,
     R: ReadRef<'data>,
 {
-    #[allow(unused)]
     file: &'file MachOFile<'data, Mach, R>,
+    index: usize,
 }
 
 impl<'data, 'file, Mach, R> Iterator for MachOComdatIterator<'data, 'file, Mach, R>
@@ -456,9 +868,34 @@ where
 {
     type Item = MachOComdat<'data, 'file, Mach, R>;
 
-    #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        None
+        loop {
+            let index = self.index;
+            let nlist = self.file.symbols.symbol(index).ok()?;
+            self.index += 1;
+            if nlist.is_stab()
+                || nlist.n_type() & macho::N_TYPE != macho::N_SECT
+                || nlist.n_type() & macho::N_EXT == 0
+                || nlist.n_desc(self.file.endian) & macho::N_WEAK_DEF == 0
+            {
+                continue;
+            }
+            let section_type = match self
+                .file
+                .section_internal(SectionIndex(nlist.n_sect().into()))
+            {
+                Ok(internal) => internal.section.flags(self.file.endian) & macho::SECTION_TYPE,
+                Err(_) => continue,
+            };
+            if section_type != macho::S_COALESCED {
+                continue;
+            }
+            return Some(MachOComdat {
+                file: self.file,
+                symbol_index: SymbolIndex(index),
+                nlist,
+            });
+        }
     }
 }
 
@@ -471,14 +908,17 @@ pub type MachOComdat64<'data, 'file, Endian = Endianness, R = &'data [u8]> =
     MachOComdat<'data, 'file, macho::MachHeader64<Endian>, R>;
 
 /// A COMDAT section group of a `MachOFile`.
+///
+/// This is an external weak definition in a `S_COALESCED` section.
 #[derive(Debug)]
 pub struct MachOComdat<'data, 'file, Mach, R = &'data [u8]>
 where
     Mach: MachHeader,
     R: ReadRef<'data>,
 {
-    #[allow(unused)]
     file: &'file MachOFile<'data, Mach, R>,
+    symbol_index: SymbolIndex,
+    nlist: &'data Mach::Nlist,
 }
 
 impl<'data, 'file, Mach, R> read::private::Sealed for MachOComdat<'data, 'file, Mach, R>
@@ -497,27 +937,34 @@ where
 
     #[inline]
     fn kind(&self) -> ComdatKind {
-        unreachable!();
+        ComdatKind::Any
     }
 
     #[inline]
     fn symbol(&self) -> SymbolIndex {
-        unreachable!();
+        self.symbol_index
     }
 
     #[inline]
     fn name_bytes(&self) -> Result<&[u8]> {
-        unreachable!();
+        self.nlist
+            .name(self.file.endian, self.file.symbols.strings())
     }
 
     #[inline]
     fn name(&self) -> Result<&str> {
-        unreachable!();
+        let bytes = self.name_bytes()?;
+        str::from_utf8(bytes)
+            .ok()
+            .read_error("Non UTF-8 Mach-O COMDAT name")
     }
 
     #[inline]
     fn sections(&self) -> Self::SectionIterator {
-        unreachable!();
+        MachOComdatSectionIterator {
+            section: Some(SectionIndex(self.nlist.n_sect().into())),
+            marker: PhantomData,
+        }
     }
 }
 
@@ -535,8 +982,8 @@ where
     Mach: MachHeader,
     R: ReadRef<'data>,
 {
-    #[allow(unused)]
-    file: &'file MachOFile<'data, Mach, R>,
+    section: Option<SectionIndex>,
+    marker: PhantomData<&'file MachOFile<'data, Mach, R>>,
 }
 
 impl<'data, 'file, Mach, R> Iterator for MachOComdatSectionIterator<'data, 'file, Mach, R>
@@ -547,7 +994,7 @@ where
     type Item = SectionIndex;
 
     fn next(&mut self) -> Option<Self::Item> {
-        None
+        self.section.take()
     }
 }
 
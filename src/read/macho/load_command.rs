@@ -239,6 +239,42 @@ impl<'data, E: Endian> LoadCommandData<'data, E> {
         }
     }
 
+    /// Try to parse this command as a `LinkeditDataCommand` for `LC_DYLD_CHAINED_FIXUPS`.
+    pub fn dyld_chained_fixups(self) -> Result<Option<&'data macho::LinkeditDataCommand<E>>> {
+        if self.cmd == macho::LC_DYLD_CHAINED_FIXUPS {
+            Some(self.data()).transpose()
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Try to parse this command as a `LinkeditDataCommand` for `LC_DYLD_EXPORTS_TRIE`.
+    pub fn dyld_exports_trie(self) -> Result<Option<&'data macho::LinkeditDataCommand<E>>> {
+        if self.cmd == macho::LC_DYLD_EXPORTS_TRIE {
+            Some(self.data()).transpose()
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Try to parse this command as a `LinkeditDataCommand` for `LC_CODE_SIGNATURE`.
+    pub fn code_signature(self) -> Result<Option<&'data macho::LinkeditDataCommand<E>>> {
+        if self.cmd == macho::LC_CODE_SIGNATURE {
+            Some(self.data()).transpose()
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Try to parse this command as a `LinkeditDataCommand` for `LC_FUNCTION_STARTS`.
+    pub fn function_starts(self) -> Result<Option<&'data macho::LinkeditDataCommand<E>>> {
+        if self.cmd == macho::LC_FUNCTION_STARTS {
+            Some(self.data()).transpose()
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Try to parse this command as an `EntryPointCommand`.
     pub fn entry_point(self) -> Result<Option<&'data macho::EntryPointCommand<E>>> {
         if self.cmd == macho::LC_MAIN {
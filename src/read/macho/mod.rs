@@ -8,6 +8,9 @@
 mod dyld_cache;
 pub use dyld_cache::*;
 
+mod export;
+pub use export::*;
+
 mod fat;
 pub use fat::*;
 
@@ -17,6 +20,9 @@ pub use file::*;
 mod load_command;
 pub use load_command::*;
 
+mod note;
+pub use note::*;
+
 mod segment;
 pub use segment::*;
 
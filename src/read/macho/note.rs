@@ -0,0 +1,229 @@
+use crate::endian::{Endian, U64Bytes, U32};
+use crate::macho;
+use crate::read::{Bytes, ReadError, ReadRef, Result};
+
+impl<E: Endian> macho::NoteCommand<E> {
+    /// Return the owner name, with any trailing null bytes removed.
+    pub fn owner(&self) -> &[u8] {
+        match memchr::memchr(b'\0', &self.data_owner) {
+            Some(end) => &self.data_owner[..end],
+            None => &self.data_owner,
+        }
+    }
+
+    /// Return the data that this command references.
+    pub fn data<'data, R: ReadRef<'data>>(&self, endian: E, data: R) -> Result<&'data [u8]> {
+        data.read_bytes_at(self.offset.get(endian), self.size.get(endian))
+            .read_error("Invalid Mach-O LC_NOTE offset or size")
+    }
+
+    /// Parse the data that this command references.
+    ///
+    /// Returns `MachONotePayload::Unknown` if the owner is not recognized.
+    pub fn payload<'data, R: ReadRef<'data>>(
+        &self,
+        endian: E,
+        data: R,
+    ) -> Result<MachONotePayload<'data>> {
+        let data = self.data(endian, data)?;
+        match self.owner() {
+            macho::NOTE_MAIN_BIN_SPEC => MainBinSpec::parse(endian, data).map(Into::into),
+            macho::NOTE_LOAD_BINARY => LoadBinary::parse(endian, data).map(Into::into),
+            macho::NOTE_ADDRABLE_BITS => AddressingBits::parse(endian, data).map(Into::into),
+            _ => Ok(MachONotePayload::Unknown(data)),
+        }
+    }
+}
+
+/// A parsed `LC_NOTE` payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MachONotePayload<'data> {
+    /// A `"main bin spec"` payload.
+    MainBinSpec(MainBinSpec),
+    /// A `"load binary"` payload.
+    LoadBinary(LoadBinary<'data>),
+    /// An `"addrable bits"` payload.
+    AddressingBits(AddressingBits),
+    /// The data for an unrecognized owner.
+    Unknown(&'data [u8]),
+}
+
+/// A parsed `"main bin spec"` payload, which describes the main binary of a core file.
+///
+/// Unspecified values are `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MainBinSpec {
+    /// The payload version.
+    pub version: u32,
+    /// One of the `macho::MAIN_BIN_SPEC_TYPE_*` constants.
+    pub bin_type: u32,
+    /// The load address of the binary.
+    pub address: Option<u64>,
+    /// The slide of the binary.
+    ///
+    /// This is always `None` for version 1.
+    pub slide: Option<u64>,
+    /// The UUID of the binary.
+    pub uuid: Option<[u8; 16]>,
+    /// The page size in log base 2.
+    pub log2_page_size: Option<u32>,
+    /// One of the `macho::PLATFORM_*` constants.
+    ///
+    /// This is always `None` for version 1.
+    pub platform: Option<u32>,
+}
+
+impl MainBinSpec {
+    fn parse<E: Endian>(endian: E, data: &[u8]) -> Result<Self> {
+        let version = read_version(endian, data)?;
+        if version == 1 {
+            // Version 1 has no slide, and the platform field is unused.
+            let mut data = Bytes(data);
+            let fields = (|| {
+                data.skip(4)?;
+                let bin_type = data.read::<U32<E>>()?.get(endian);
+                let address = data.read::<U64Bytes<E>>()?.get(endian);
+                let mut uuid = [0; 16];
+                uuid.copy_from_slice(data.read_bytes(16)?.0);
+                let log2_pagesize = data.read::<U32<E>>()?.get(endian);
+                Ok::<_, ()>((bin_type, address, uuid, log2_pagesize))
+            })();
+            let (bin_type, address, uuid, log2_pagesize) =
+                fields.read_error("Invalid Mach-O main bin spec size")?;
+            return Ok(MainBinSpec {
+                version,
+                bin_type,
+                address: specified(address, u64::MAX),
+                slide: None,
+                uuid: specified(uuid, [0; 16]),
+                log2_page_size: specified(log2_pagesize, 0),
+                platform: None,
+            });
+        }
+        let payload = Bytes(data)
+            .read::<macho::MainBinSpecPayload<E>>()
+            .read_error("Invalid Mach-O main bin spec size")?;
+        Ok(MainBinSpec {
+            version,
+            bin_type: payload.bin_type.get(endian),
+            address: specified(payload.address.get(endian), u64::MAX),
+            slide: specified(payload.slide.get(endian), u64::MAX),
+            uuid: specified(payload.uuid, [0; 16]),
+            log2_page_size: specified(payload.log2_pagesize.get(endian), 0),
+            platform: specified(payload.platform.get(endian), 0),
+        })
+    }
+}
+
+impl<'data> From<MainBinSpec> for MachONotePayload<'data> {
+    fn from(payload: MainBinSpec) -> Self {
+        MachONotePayload::MainBinSpec(payload)
+    }
+}
+
+/// A parsed `"load binary"` payload, which describes a binary loaded in a core file.
+///
+/// Unspecified values are `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadBinary<'data> {
+    /// The payload version.
+    pub version: u32,
+    /// The UUID of the binary.
+    pub uuid: Option<[u8; 16]>,
+    /// The load address of the binary.
+    pub load_address: Option<u64>,
+    /// The slide of the binary.
+    pub slide: u64,
+    /// The name of the binary.
+    pub name: Option<&'data [u8]>,
+}
+
+impl<'data> LoadBinary<'data> {
+    fn parse<E: Endian>(endian: E, data: &'data [u8]) -> Result<Self> {
+        let version = read_version(endian, data)?;
+        let mut data = Bytes(data);
+        let payload = data
+            .read::<macho::LoadBinaryPayload<E>>()
+            .read_error("Invalid Mach-O load binary size")?;
+        let name = data
+            .read_string()
+            .read_error("Missing Mach-O load binary name terminator")?;
+        Ok(LoadBinary {
+            version,
+            uuid: specified(payload.uuid, [0; 16]),
+            load_address: specified(payload.load_address.get(endian), u64::MAX),
+            slide: payload.slide.get(endian),
+            name: specified(name, &[]),
+        })
+    }
+}
+
+impl<'data> From<LoadBinary<'data>> for MachONotePayload<'data> {
+    fn from(payload: LoadBinary<'data>) -> Self {
+        MachONotePayload::LoadBinary(payload)
+    }
+}
+
+/// A parsed `"addrable bits"` payload, which gives the number of bits used for
+/// addresses in a core file.
+///
+/// Unspecified values are `None`. Versions 1 and 2 specify the same number
+/// of bits for low and high memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddressingBits {
+    /// The payload version.
+    pub version: u32,
+    /// The number of bits for addresses in low memory.
+    pub low: Option<u32>,
+    /// The number of bits for addresses in high memory.
+    pub high: Option<u32>,
+}
+
+impl AddressingBits {
+    fn parse<E: Endian>(endian: E, data: &[u8]) -> Result<Self> {
+        let version = read_version(endian, data)?;
+        let (low, high) = if version < 3 {
+            let bits = Bytes(data)
+                .read_at::<U32<E>>(4)
+                .read_error("Invalid Mach-O addrable bits size")?
+                .get(endian);
+            (bits, bits)
+        } else {
+            let payload = Bytes(data)
+                .read::<macho::AddrableBitsPayload<E>>()
+                .read_error("Invalid Mach-O addrable bits size")?;
+            (
+                payload.lo_addr_bits.get(endian),
+                payload.hi_addr_bits.get(endian),
+            )
+        };
+        Ok(AddressingBits {
+            version,
+            low: specified(low, 0),
+            high: specified(high, 0),
+        })
+    }
+}
+
+impl<'data> From<AddressingBits> for MachONotePayload<'data> {
+    fn from(payload: AddressingBits) -> Self {
+        MachONotePayload::AddressingBits(payload)
+    }
+}
+
+fn read_version<E: Endian>(endian: E, data: &[u8]) -> Result<u32> {
+    Bytes(data)
+        .read_at::<U32<E>>(0)
+        .read_error("Invalid Mach-O LC_NOTE payload size")
+        .map(|version| version.get(endian))
+}
+
+/// Return `None` if the value is the sentinel for an unspecified value.
+fn specified<T: PartialEq>(value: T, unspecified: T) -> Option<T> {
+    if value == unspecified {
+        None
+    } else {
+        Some(value)
+    }
+}
@@ -1,13 +1,14 @@
+use alloc::vec::Vec;
 use core::{fmt, slice};
 
-use crate::endian::Endianness;
+use crate::endian::{Endian, Endianness, U16Bytes, U32Bytes, U64Bytes};
 use crate::macho;
 use crate::read::{
-    ReadRef, Relocation, RelocationEncoding, RelocationKind, RelocationTarget, SectionIndex,
+    Bytes, ReadRef, Relocation, RelocationEncoding, RelocationKind, RelocationTarget, SectionIndex,
     SymbolIndex,
 };
 
-use super::{MachHeader, MachOFile};
+use super::{MachHeader, MachOFile, Segment};
 
 /// An iterator over the relocations in a `MachOSection32`.
 pub type MachORelocationIterator32<'data, 'file, Endian = Endianness, R = &'data [u8]> =
@@ -125,3 +126,422 @@ where
         f.debug_struct("MachORelocationIterator").finish()
     }
 }
+
+/// An iterator over the dynamic relocations for a `MachOFile32`.
+pub type MachODynamicRelocationIterator32<'data, 'file, Endian = Endianness, R = &'data [u8]> =
+    MachODynamicRelocationIterator<'data, 'file, macho::MachHeader32<Endian>, R>;
+/// An iterator over the dynamic relocations for a `MachOFile64`.
+pub type MachODynamicRelocationIterator64<'data, 'file, Endian = Endianness, R = &'data [u8]> =
+    MachODynamicRelocationIterator<'data, 'file, macho::MachHeader64<Endian>, R>;
+
+/// An iterator over the dynamic relocations for a `MachOFile`.
+///
+/// This returns the rebases from either the `LC_DYLD_INFO` rebase opcodes or
+/// the `LC_DYLD_CHAINED_FIXUPS` chains. Binds are not included.
+pub struct MachODynamicRelocationIterator<'data, 'file, Mach, R = &'data [u8]>
+where
+    Mach: MachHeader,
+    R: ReadRef<'data>,
+{
+    pub(super) file: &'file MachOFile<'data, Mach, R>,
+    /// The address of the start of the image.
+    pub(super) image_base: u64,
+    pub(super) state: MachODynamicRelocationState<'data, Mach::Endian>,
+}
+
+pub(super) enum MachODynamicRelocationState<'data, E: Endian> {
+    Rebase(RebaseState<'data>),
+    Chained(ChainedState<'data, E>),
+    Done,
+}
+
+impl<'data, 'file, Mach, R> Iterator for MachODynamicRelocationIterator<'data, 'file, Mach, R>
+where
+    Mach: MachHeader,
+    R: ReadRef<'data>,
+{
+    type Item = (u64, Relocation);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = match self.state {
+            MachODynamicRelocationState::Rebase(ref mut state) => state.next(self.file),
+            MachODynamicRelocationState::Chained(ref mut state) => {
+                state.next(self.file, self.image_base)
+            }
+            MachODynamicRelocationState::Done => None,
+        };
+        if next.is_none() {
+            // Also stops after an error.
+            self.state = MachODynamicRelocationState::Done;
+        }
+        next
+    }
+}
+
+impl<'data, 'file, Mach, R> fmt::Debug for MachODynamicRelocationIterator<'data, 'file, Mach, R>
+where
+    Mach: MachHeader,
+    R: ReadRef<'data>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MachODynamicRelocationIterator").finish()
+    }
+}
+
+fn rebase_relocation(size: u8, addend: i64, implicit_addend: bool) -> Relocation {
+    Relocation {
+        kind: RelocationKind::Rebase,
+        encoding: RelocationEncoding::Generic,
+        size,
+        target: RelocationTarget::Absolute,
+        addend,
+        implicit_addend,
+    }
+}
+
+/// The state for interpreting `REBASE_OPCODE_*` opcodes.
+pub(super) struct RebaseState<'data> {
+    pub(super) opcodes: Bytes<'data>,
+    address: u64,
+    typ: u8,
+    count: u64,
+    skip: u64,
+}
+
+impl<'data> RebaseState<'data> {
+    pub(super) fn new(opcodes: &'data [u8]) -> Self {
+        RebaseState {
+            opcodes: Bytes(opcodes),
+            address: 0,
+            typ: 0,
+            count: 0,
+            skip: 0,
+        }
+    }
+
+    fn next<Mach: MachHeader, R: ReadRef<'data>>(
+        &mut self,
+        file: &MachOFile<'data, Mach, R>,
+    ) -> Option<(u64, Relocation)> {
+        let pointer_size: u64 = if file.header.is_type_64() { 8 } else { 4 };
+        loop {
+            if self.count != 0 {
+                self.count -= 1;
+                let address = self.address;
+                self.address = address.wrapping_add(pointer_size).wrapping_add(self.skip);
+                let size = match self.typ {
+                    macho::REBASE_TYPE_POINTER => pointer_size as u8 * 8,
+                    macho::REBASE_TYPE_TEXT_ABSOLUTE32 => 32,
+                    _ => 0,
+                };
+                return Some((address, rebase_relocation(size, 0, true)));
+            }
+
+            self.skip = 0;
+            let byte = *self.opcodes.read::<u8>().ok()?;
+            let immediate = byte & macho::REBASE_IMMEDIATE_MASK;
+            match byte & macho::REBASE_OPCODE_MASK {
+                macho::REBASE_OPCODE_DONE => return None,
+                macho::REBASE_OPCODE_SET_TYPE_IMM => self.typ = immediate,
+                macho::REBASE_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB => {
+                    let segment = file.segments.get(usize::from(immediate))?.segment;
+                    let offset = self.opcodes.read_uleb128().ok()?;
+                    self.address = segment.vmaddr(file.endian).into().wrapping_add(offset);
+                }
+                macho::REBASE_OPCODE_ADD_ADDR_ULEB => {
+                    let offset = self.opcodes.read_uleb128().ok()?;
+                    self.address = self.address.wrapping_add(offset);
+                }
+                macho::REBASE_OPCODE_ADD_ADDR_IMM_SCALED => {
+                    let offset = u64::from(immediate) * pointer_size;
+                    self.address = self.address.wrapping_add(offset);
+                }
+                macho::REBASE_OPCODE_DO_REBASE_IMM_TIMES => {
+                    self.count = immediate.into();
+                }
+                macho::REBASE_OPCODE_DO_REBASE_ULEB_TIMES => {
+                    self.count = self.opcodes.read_uleb128().ok()?;
+                }
+                macho::REBASE_OPCODE_DO_REBASE_ADD_ADDR_ULEB => {
+                    self.count = 1;
+                    self.skip = self.opcodes.read_uleb128().ok()?;
+                }
+                macho::REBASE_OPCODE_DO_REBASE_ULEB_TIMES_SKIPPING_ULEB => {
+                    self.count = self.opcodes.read_uleb128().ok()?;
+                    self.skip = self.opcodes.read_uleb128().ok()?;
+                }
+                _ => return None,
+            }
+        }
+    }
+}
+
+/// Append the symbol names that are bound by `BIND_OPCODE_*` opcodes.
+///
+/// Returns an error for an invalid opcode. The names before it are still appended.
+pub(super) fn lazy_bind_names<'data>(
+    mut opcodes: Bytes<'data>,
+    names: &mut Vec<&'data [u8]>,
+) -> Result<(), ()> {
+    while let Ok(byte) = opcodes.read::<u8>() {
+        let immediate = byte & macho::BIND_IMMEDIATE_MASK;
+        match byte & macho::BIND_OPCODE_MASK {
+            // Lazy binding opcodes use `BIND_OPCODE_DONE` to separate entries.
+            macho::BIND_OPCODE_DONE
+            | macho::BIND_OPCODE_SET_DYLIB_ORDINAL_IMM
+            | macho::BIND_OPCODE_SET_DYLIB_SPECIAL_IMM
+            | macho::BIND_OPCODE_SET_TYPE_IMM
+            | macho::BIND_OPCODE_DO_BIND
+            | macho::BIND_OPCODE_DO_BIND_ADD_ADDR_IMM_SCALED => {}
+            macho::BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM => {
+                names.push(opcodes.read_string()?);
+            }
+            macho::BIND_OPCODE_SET_DYLIB_ORDINAL_ULEB
+            | macho::BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB
+            | macho::BIND_OPCODE_ADD_ADDR_ULEB
+            | macho::BIND_OPCODE_DO_BIND_ADD_ADDR_ULEB => {
+                opcodes.read_uleb128()?;
+            }
+            macho::BIND_OPCODE_SET_ADDEND_SLEB => {
+                opcodes.read_sleb128()?;
+            }
+            macho::BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB => {
+                opcodes.read_uleb128()?;
+                opcodes.read_uleb128()?;
+            }
+            macho::BIND_OPCODE_THREADED => match immediate {
+                macho::BIND_SUBOPCODE_THREADED_SET_BIND_ORDINAL_TABLE_SIZE_ULEB => {
+                    opcodes.read_uleb128()?;
+                }
+                macho::BIND_SUBOPCODE_THREADED_APPLY => {}
+                _ => return Err(()),
+            },
+            _ => return Err(()),
+        }
+    }
+    Ok(())
+}
+
+/// The state for walking the chains in `LC_DYLD_CHAINED_FIXUPS`.
+pub(super) struct ChainedState<'data, E: Endian> {
+    /// The data starting at the `DyldChainedStartsInImage`.
+    starts: Bytes<'data>,
+    seg_count: u32,
+    next_segment: u32,
+    /// The index of the segment being walked.
+    segment: usize,
+    pointer_format: u16,
+    page_size: u64,
+    max_valid_pointer: u32,
+    page_starts: &'data [U16Bytes<E>],
+    page_count: usize,
+    next_page: usize,
+    /// The offset in the segment of the current page.
+    page_offset: u64,
+    /// The index in `page_starts` of the next chain start for the current page.
+    multi_start: Option<usize>,
+    /// The offset in the segment of the next fixup in the chain.
+    chain: Option<u64>,
+}
+
+impl<'data, E: Endian> ChainedState<'data, E> {
+    /// Parse the data of the `LC_DYLD_CHAINED_FIXUPS` command.
+    pub(super) fn parse(endian: E, data: &'data [u8]) -> Option<Self> {
+        let data = Bytes(data);
+        let header = data.read_at::<macho::DyldChainedFixupsHeader<E>>(0).ok()?;
+        let starts_offset = header.starts_offset.get(endian) as usize;
+        let mut starts = data;
+        starts.skip(starts_offset).ok()?;
+        let seg_count = starts
+            .read_at::<macho::DyldChainedStartsInImage<E>>(0)
+            .ok()?
+            .seg_count
+            .get(endian);
+        Some(ChainedState {
+            starts,
+            seg_count,
+            next_segment: 0,
+            segment: 0,
+            pointer_format: 0,
+            page_size: 0,
+            max_valid_pointer: 0,
+            page_starts: &[],
+            page_count: 0,
+            next_page: 0,
+            page_offset: 0,
+            multi_start: None,
+            chain: None,
+        })
+    }
+
+    fn next<Mach: MachHeader<Endian = E>, R: ReadRef<'data>>(
+        &mut self,
+        file: &MachOFile<'data, Mach, R>,
+        image_base: u64,
+    ) -> Option<(u64, Relocation)> {
+        let endian = file.endian;
+        loop {
+            if let Some(offset) = self.chain {
+                let segment = file.segments.get(self.segment)?;
+                let fileoff = segment.segment.fileoff(endian).into();
+                let raw = if self.pointer_format == macho::DYLD_CHAINED_PTR_32 {
+                    u64::from(
+                        segment
+                            .data
+                            .read_at::<U32Bytes<E>>(fileoff.checked_add(offset)?)
+                            .ok()?
+                            .get(endian),
+                    )
+                } else {
+                    segment
+                        .data
+                        .read_at::<U64Bytes<E>>(fileoff.checked_add(offset)?)
+                        .ok()?
+                        .get(endian)
+                };
+                let (next, stride, fixup) = self.decode(raw, image_base)?;
+                self.chain = if next == 0 {
+                    None
+                } else {
+                    Some(offset.checked_add(next * stride)?)
+                };
+                if let Some((size, addend)) = fixup {
+                    let address = segment.segment.vmaddr(endian).into().wrapping_add(offset);
+                    return Some((address, rebase_relocation(size, addend, false)));
+                }
+                continue;
+            }
+
+            if let Some(index) = self.multi_start {
+                let start = self.page_starts.get(index)?.get(endian);
+                self.multi_start = if start & macho::DYLD_CHAINED_PTR_START_LAST != 0 {
+                    None
+                } else {
+                    Some(index + 1)
+                };
+                let start = start & !macho::DYLD_CHAINED_PTR_START_LAST;
+                self.chain = Some(self.page_offset + u64::from(start));
+                continue;
+            }
+
+            if self.next_page < self.page_count {
+                let page = self.next_page;
+                self.next_page += 1;
+                self.page_offset = page as u64 * self.page_size;
+                let start = self.page_starts.get(page)?.get(endian);
+                if start == macho::DYLD_CHAINED_PTR_START_NONE {
+                    continue;
+                }
+                if start & macho::DYLD_CHAINED_PTR_START_MULTI != 0 {
+                    let index = start & !macho::DYLD_CHAINED_PTR_START_MULTI;
+                    self.multi_start = Some(index.into());
+                } else {
+                    self.chain = Some(self.page_offset + u64::from(start));
+                }
+                continue;
+            }
+
+            if self.next_segment >= self.seg_count {
+                return None;
+            }
+            self.segment = self.next_segment as usize;
+            self.next_segment += 1;
+            self.page_count = 0;
+            self.next_page = 0;
+            let seg_info_offset = self
+                .starts
+                .read_at::<U32Bytes<E>>(4 + self.segment * 4)
+                .ok()?
+                .get(endian) as usize;
+            if seg_info_offset == 0 {
+                continue;
+            }
+            let mut seg_info = self.starts;
+            seg_info.skip(seg_info_offset).ok()?;
+            let header = seg_info
+                .read_at::<macho::DyldChainedStartsInSegment<E>>(0)
+                .ok()?;
+            // The page starts follow the 22 bytes of fields in the header,
+            // which may be less than the size of the padded struct.
+            seg_info.skip(22).ok()?;
+            let size = header.size.get(endian) as usize;
+            let count = size.checked_sub(22)? / 2;
+            let page_count = usize::from(header.page_count.get(endian));
+            if count < page_count {
+                return None;
+            }
+            self.pointer_format = header.pointer_format.get(endian);
+            self.page_size = header.page_size.get(endian).into();
+            self.max_valid_pointer = header.max_valid_pointer.get(endian);
+            self.page_starts = seg_info.read_slice(count).ok()?;
+            match self.pointer_format {
+                macho::DYLD_CHAINED_PTR_ARM64E
+                | macho::DYLD_CHAINED_PTR_64
+                | macho::DYLD_CHAINED_PTR_32
+                | macho::DYLD_CHAINED_PTR_64_OFFSET
+                | macho::DYLD_CHAINED_PTR_ARM64E_USERLAND
+                | macho::DYLD_CHAINED_PTR_ARM64E_USERLAND24 => self.page_count = page_count,
+                // Formats used in caches and firmware are not supported.
+                _ => {}
+            }
+        }
+    }
+
+    /// Decode a chained pointer.
+    ///
+    /// Returns the chain delta, the chain stride, and the size and addend of a rebase.
+    #[allow(clippy::type_complexity)]
+    fn decode(&self, raw: u64, image_base: u64) -> Option<(u64, u64, Option<(u8, i64)>)> {
+        match self.pointer_format {
+            macho::DYLD_CHAINED_PTR_ARM64E
+            | macho::DYLD_CHAINED_PTR_ARM64E_USERLAND
+            | macho::DYLD_CHAINED_PTR_ARM64E_USERLAND24 => {
+                let next = (raw >> 51) & 0x7ff;
+                let auth = raw & (1 << 63) != 0;
+                let bind = raw & (1 << 62) != 0;
+                let fixup = if bind {
+                    None
+                } else if auth {
+                    // The target is always an offset from the image base.
+                    Some((64, (raw & 0xffff_ffff) as i64))
+                } else {
+                    let mut target = raw & 0x7ff_ffff_ffff;
+                    if self.pointer_format == macho::DYLD_CHAINED_PTR_ARM64E {
+                        target = target.wrapping_sub(image_base);
+                    }
+                    let high8 = (raw >> 43) & 0xff;
+                    Some((64, (target | (high8 << 56)) as i64))
+                };
+                Some((next, 8, fixup))
+            }
+            macho::DYLD_CHAINED_PTR_64 | macho::DYLD_CHAINED_PTR_64_OFFSET => {
+                let next = (raw >> 51) & 0xfff;
+                let bind = raw & (1 << 63) != 0;
+                let fixup = if bind {
+                    None
+                } else {
+                    let mut target = raw & 0xf_ffff_ffff;
+                    if self.pointer_format == macho::DYLD_CHAINED_PTR_64 {
+                        target = target.wrapping_sub(image_base);
+                    }
+                    let high8 = (raw >> 36) & 0xff;
+                    Some((64, (target | (high8 << 56)) as i64))
+                };
+                Some((next, 4, fixup))
+            }
+            macho::DYLD_CHAINED_PTR_32 => {
+                let next = (raw >> 26) & 0x1f;
+                let bind = raw & (1 << 31) != 0;
+                let target = raw & 0x3ff_ffff;
+                // Targets above the maximum are non-pointers that are not rebased.
+                let fixup = if bind || target > u64::from(self.max_valid_pointer) {
+                    None
+                } else {
+                    Some((32, target.wrapping_sub(image_base) as i64))
+                };
+                Some((next, 4, fixup))
+            }
+            _ => None,
+        }
+    }
+}
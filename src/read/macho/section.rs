@@ -217,17 +217,28 @@ impl<'data, Mach: MachHeader> MachOSectionInternal<'data, Mach> {
         index: SectionIndex,
         segment_index: usize,
         section: &'data Mach::Section,
+        endian: Mach::Endian,
     ) -> Self {
+        let kind = match section.flags(endian) & macho::SECTION_TYPE {
+            macho::S_NON_LAZY_SYMBOL_POINTERS
+            | macho::S_LAZY_SYMBOL_POINTERS
+            | macho::S_LAZY_DYLIB_SYMBOL_POINTERS => Some(SectionKind::Got),
+            macho::S_SYMBOL_STUBS => Some(SectionKind::Plt),
+            macho::S_MOD_INIT_FUNC_POINTERS => Some(SectionKind::InitArray),
+            macho::S_MOD_TERM_FUNC_POINTERS => Some(SectionKind::FiniArray),
+            _ => None,
+        };
         // TODO: we don't validate flags, should we?
-        let kind = match (section.segment_name(), section.name()) {
+        let kind = kind.unwrap_or_else(|| match (section.segment_name(), section.name()) {
             (b"__TEXT", b"__text") => SectionKind::Text,
             (b"__TEXT", b"__const") => SectionKind::ReadOnlyData,
             (b"__TEXT", b"__cstring") => SectionKind::ReadOnlyString,
             (b"__TEXT", b"__literal4") => SectionKind::ReadOnlyData,
             (b"__TEXT", b"__literal8") => SectionKind::ReadOnlyData,
             (b"__TEXT", b"__literal16") => SectionKind::ReadOnlyData,
-            (b"__TEXT", b"__eh_frame") => SectionKind::ReadOnlyData,
-            (b"__TEXT", b"__gcc_except_tab") => SectionKind::ReadOnlyData,
+            (b"__TEXT", b"__eh_frame") => SectionKind::Unwind,
+            (b"__TEXT", b"__unwind_info") => SectionKind::Unwind,
+            (b"__TEXT", b"__gcc_except_tab") => SectionKind::Unwind,
             (b"__DATA", b"__data") => SectionKind::Data,
             (b"__DATA", b"__const") => SectionKind::ReadOnlyData,
             (b"__DATA", b"__bss") => SectionKind::UninitializedData,
@@ -235,9 +246,10 @@ impl<'data, Mach: MachHeader> MachOSectionInternal<'data, Mach> {
             (b"__DATA", b"__thread_data") => SectionKind::Tls,
             (b"__DATA", b"__thread_bss") => SectionKind::UninitializedTls,
             (b"__DATA", b"__thread_vars") => SectionKind::TlsVariables,
+            (b"__DATA", b"__got") | (b"__DATA_CONST", b"__got") => SectionKind::Got,
             (b"__DWARF", _) => SectionKind::Debug,
             _ => SectionKind::Unknown,
-        };
+        });
         MachOSectionInternal {
             index,
             segment_index,
@@ -4,7 +4,9 @@ use core::{result, slice, str};
 use crate::endian::{self, Endianness};
 use crate::macho;
 use crate::pod::Pod;
-use crate::read::{self, ObjectSegment, ReadError, ReadRef, Result, SegmentFlags};
+use crate::read::{
+    self, ObjectSegment, Permissions, ReadError, ReadRef, Result, SegmentFlags, SegmentKind,
+};
 
 use super::{LoadCommandData, MachHeader, MachOFile, Section};
 
@@ -89,6 +91,11 @@ where
         self.internal.segment.vmaddr(self.file.endian).into()
     }
 
+    #[inline]
+    fn physical_address(&self) -> u64 {
+        self.address()
+    }
+
     #[inline]
     fn size(&self) -> u64 {
         self.internal.segment.vmsize(self.file.endian).into()
@@ -97,7 +104,10 @@ where
     #[inline]
     fn align(&self) -> u64 {
         // Page size.
-        0x1000
+        match self.file.header.cputype(self.file.endian) {
+            macho::CPU_TYPE_ARM64 | macho::CPU_TYPE_ARM64_32 => 0x4000,
+            _ => 0x1000,
+        }
     }
 
     #[inline]
@@ -132,6 +142,21 @@ where
         ))
     }
 
+    #[inline]
+    fn kind(&self) -> SegmentKind {
+        SegmentKind::Load
+    }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        let initprot = self.internal.segment.initprot(self.file.endian);
+        Permissions::new(
+            initprot & macho::VM_PROT_READ != 0,
+            initprot & macho::VM_PROT_WRITE != 0,
+            initprot & macho::VM_PROT_EXECUTE != 0,
+        )
+    }
+
     #[inline]
     fn flags(&self) -> SegmentFlags {
         let flags = self.internal.segment.flags(self.file.endian);
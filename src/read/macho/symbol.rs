@@ -7,9 +7,9 @@ use crate::macho;
 use crate::pod::Pod;
 use crate::read::util::StringTable;
 use crate::read::{
-    self, ObjectMap, ObjectMapEntry, ObjectSymbol, ObjectSymbolTable, ReadError, ReadRef, Result,
-    SectionIndex, SectionKind, SymbolFlags, SymbolIndex, SymbolKind, SymbolMap, SymbolMapEntry,
-    SymbolScope, SymbolSection,
+    self, ObjectMap, ObjectMapEntry, ObjectMapFile, ObjectSymbol, ObjectSymbolTable, ReadError,
+    ReadRef, Result, SectionIndex, SectionKind, SourceFile, SymbolFlags, SymbolIndex, SymbolKind,
+    SymbolMap, SymbolMapEntry, SymbolScope, SymbolSection,
 };
 
 use super::{MachHeader, MachOFile};
@@ -90,11 +90,18 @@ impl<'data, Mach: MachHeader, R: ReadRef<'data>> SymbolTable<'data, Mach, R> {
     }
 
     /// Construct a map from addresses to symbol names and object file names.
+    ///
+    /// This is the debug map that is used by tools such as `dsymutil` to locate
+    /// the debug information in the object files that the file was linked from.
     pub fn object_map(&self, endian: Mach::Endian) -> ObjectMap<'data> {
         let mut symbols = Vec::new();
         let mut objects = Vec::new();
+        let mut object_files = Vec::new();
         let mut object = None;
         let mut current_function = None;
+        let mut source_directory: &[u8] = &[];
+        let mut source_name: &[u8] = &[];
+        let mut globals = None;
         // Each module starts with one or two N_SO symbols (path, or directory + filename)
         // and one N_OSO symbol. The module is terminated by an empty N_SO symbol.
         for nlist in self.symbols {
@@ -102,34 +109,75 @@ impl<'data, Mach: MachHeader, R: ReadRef<'data>> SymbolTable<'data, Mach, R> {
             if n_type & macho::N_STAB == 0 {
                 continue;
             }
-            // TODO: includes variables too (N_GSYM, N_STSYM). These may need to get their
-            // address from regular symbols though.
+            let name = nlist.name(endian, self.strings).unwrap_or(&[]);
             match n_type {
                 macho::N_SO => {
                     object = None;
+                    current_function = None;
+                    if name.is_empty() {
+                        source_directory = &[];
+                        source_name = &[];
+                    } else if name.ends_with(b"/") {
+                        source_directory = name;
+                        source_name = &[];
+                    } else {
+                        source_name = name;
+                    }
                 }
                 macho::N_OSO => {
                     object = None;
-                    if let Ok(name) = nlist.name(endian, self.strings) {
-                        if !name.is_empty() {
-                            object = Some(objects.len());
-                            objects.push(name);
-                        }
+                    if !name.is_empty() {
+                        object = Some(objects.len());
+                        objects.push(name);
+                        object_files.push(ObjectMapFile::new(
+                            name,
+                            nlist.n_value(endian).into(),
+                            source_directory,
+                            source_name,
+                        ));
                     }
                 }
                 macho::N_FUN => {
-                    if let Ok(name) = nlist.name(endian, self.strings) {
-                        if !name.is_empty() {
-                            current_function = Some((name, nlist.n_value(endian).into()))
-                        } else if let Some((name, address)) = current_function.take() {
-                            if let Some(object) = object {
-                                symbols.push(ObjectMapEntry {
-                                    address,
-                                    size: nlist.n_value(endian).into(),
-                                    name,
-                                    object,
-                                });
-                            }
+                    // Functions have a pair of N_FUN symbols: the first gives the name
+                    // and address, and the second has no name and gives the size.
+                    if !name.is_empty() {
+                        current_function = Some((name, nlist.n_value(endian).into()))
+                    } else if let Some((name, address)) = current_function.take() {
+                        if let Some(object) = object {
+                            symbols.push(ObjectMapEntry {
+                                address,
+                                size: nlist.n_value(endian).into(),
+                                name,
+                                kind: SymbolKind::Text,
+                                object,
+                            });
+                        }
+                    }
+                }
+                macho::N_STSYM => {
+                    if let (false, Some(object)) = (name.is_empty(), object) {
+                        symbols.push(ObjectMapEntry {
+                            address: nlist.n_value(endian).into(),
+                            size: 0,
+                            name,
+                            kind: SymbolKind::Data,
+                            object,
+                        });
+                    }
+                }
+                macho::N_GSYM => {
+                    // Global variables don't have an address in the STAB entry, so
+                    // get it from the corresponding external symbol instead.
+                    if let (false, Some(object)) = (name.is_empty(), object) {
+                        let globals = globals.get_or_insert_with(|| self.globals(endian));
+                        if let Ok(index) = globals.binary_search_by_key(&name, |&(n, _)| n) {
+                            symbols.push(ObjectMapEntry {
+                                address: globals[index].1,
+                                size: 0,
+                                name,
+                                kind: SymbolKind::Data,
+                                object,
+                            });
                         }
                     }
                 }
@@ -139,8 +187,55 @@ impl<'data, Mach: MachHeader, R: ReadRef<'data>> SymbolTable<'data, Mach, R> {
         ObjectMap {
             symbols: SymbolMap::new(symbols),
             objects,
+            object_files,
         }
     }
+
+    /// Return the source files given by the `N_SO` and `N_OSO` STAB entries.
+    pub fn source_files(&self, endian: Mach::Endian) -> Result<Vec<SourceFile<'data>>> {
+        let mut files = Vec::new();
+        let mut directory: &[u8] = &[];
+        // The index of the file in the current module, if it has no object yet.
+        let mut current = None;
+        for nlist in self.symbols {
+            match nlist.n_type() {
+                macho::N_SO => {
+                    let name = nlist.name(endian, self.strings)?;
+                    current = None;
+                    if name.is_empty() {
+                        directory = &[];
+                    } else if name.ends_with(b"/") {
+                        directory = name;
+                    } else {
+                        current = Some(files.len());
+                        files.push(SourceFile::new(directory, name));
+                    }
+                }
+                macho::N_OSO => {
+                    let name = nlist.name(endian, self.strings)?;
+                    if let Some(index) = current.take() {
+                        files[index].object = Some(name);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(files)
+    }
+
+    /// Return the names and addresses of the defined external symbols, sorted by name.
+    fn globals(&self, endian: Mach::Endian) -> Vec<(&'data [u8], u64)> {
+        let mut globals = Vec::new();
+        for nlist in self.symbols {
+            if nlist.is_definition() && nlist.n_type() & macho::N_EXT != 0 {
+                if let Ok(name) = nlist.name(endian, self.strings) {
+                    globals.push((name, nlist.n_value(endian).into()));
+                }
+            }
+        }
+        globals.sort_unstable();
+        globals
+    }
 }
 
 /// An iterator over the symbols of a `MachOFile32`.
@@ -300,25 +395,39 @@ where
 
     #[inline]
     fn address(&self) -> u64 {
+        if self.is_common() {
+            return 0;
+        }
         self.nlist.n_value(self.file.endian).into()
     }
 
     #[inline]
     fn size(&self) -> u64 {
+        // The value of a common symbol is its size.
+        if self.is_common() {
+            return self.nlist.n_value(self.file.endian).into();
+        }
         0
     }
 
     fn kind(&self) -> SymbolKind {
+        if self.is_common() {
+            return SymbolKind::Data;
+        }
         self.section()
             .index()
             .and_then(|index| self.file.section_internal(index).ok())
             .map(|section| match section.kind {
-                SectionKind::Text => SymbolKind::Text,
+                SectionKind::Text | SectionKind::Plt => SymbolKind::Text,
                 SectionKind::Data
                 | SectionKind::ReadOnlyData
                 | SectionKind::ReadOnlyString
                 | SectionKind::UninitializedData
-                | SectionKind::Common => SymbolKind::Data,
+                | SectionKind::Common
+                | SectionKind::Got
+                | SectionKind::InitArray
+                | SectionKind::FiniArray
+                | SectionKind::Unwind => SymbolKind::Data,
                 SectionKind::Tls | SectionKind::UninitializedTls | SectionKind::TlsVariables => {
                     SymbolKind::Tls
                 }
@@ -329,6 +438,7 @@ where
 
     fn section(&self) -> SymbolSection {
         match self.nlist.n_type() & macho::N_TYPE {
+            macho::N_UNDF if self.is_common() => SymbolSection::Common,
             macho::N_UNDF => SymbolSection::Undefined,
             macho::N_ABS => SymbolSection::Absolute,
             macho::N_SECT => {
@@ -345,7 +455,7 @@ where
 
     #[inline]
     fn is_undefined(&self) -> bool {
-        self.nlist.n_type() & macho::N_TYPE == macho::N_UNDF
+        self.nlist.n_type() & macho::N_TYPE == macho::N_UNDF && !self.is_common()
     }
 
     #[inline]
@@ -355,8 +465,11 @@ where
 
     #[inline]
     fn is_common(&self) -> bool {
-        // Mach-O common symbols are based on section, not symbol
-        false
+        // Common symbols are undefined external symbols with a non-zero value.
+        // Mach-O files may also contain definitions in a `__common` section,
+        // which are not common symbols.
+        self.nlist.n_type() & (macho::N_TYPE | macho::N_EXT) == macho::N_UNDF | macho::N_EXT
+            && self.nlist.n_value(self.file.endian).into() != 0
     }
 
     #[inline]
@@ -366,7 +479,7 @@ where
 
     fn scope(&self) -> SymbolScope {
         let n_type = self.nlist.n_type();
-        if n_type & macho::N_TYPE == macho::N_UNDF {
+        if self.is_undefined() {
             SymbolScope::Unknown
         } else if n_type & macho::N_EXT == 0 {
             SymbolScope::Compilation
@@ -0,0 +1,88 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[cfg(feature = "archive")]
+use crate::read::archive::ArchiveFile;
+#[cfg(any(
+    feature = "coff",
+    feature = "elf",
+    feature = "macho",
+    feature = "pe",
+    feature = "wasm",
+    feature = "xcoff"
+))]
+use crate::read::File;
+#[cfg(any(
+    feature = "archive",
+    feature = "coff",
+    feature = "elf",
+    feature = "macho",
+    feature = "pe",
+    feature = "wasm",
+    feature = "xcoff"
+))]
+use crate::read::Result;
+
+/// A read-only memory mapped file.
+///
+/// This owns the mapping, and the parsed file borrows from it, so the mapping
+/// remains valid for as long as the parsed file is in use.
+///
+/// ```no_run
+/// # #[cfg(feature = "elf")] {
+/// use object::read::{MappedFile, Object};
+///
+/// let mapped = MappedFile::open("/bin/ls")?;
+/// let file = mapped.parse()?;
+/// println!("{:?}", file.architecture());
+/// # }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct MappedFile {
+    mmap: memmap2::Mmap,
+}
+
+impl MappedFile {
+    /// Open and map the file at the given path.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::map(&fs::File::open(path)?)
+    }
+
+    /// Map the given file.
+    ///
+    /// The file does not need to remain open after this returns.
+    pub fn map(file: &fs::File) -> io::Result<Self> {
+        // Safety: the mapping is read-only, but other processes may still modify
+        // the file while it is mapped. This is the same restriction as for any
+        // other use of a memory mapped file.
+        let mmap = unsafe { memmap2::Mmap::map(file)? };
+        Ok(MappedFile { mmap })
+    }
+
+    /// Return the mapped data.
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        &self.mmap
+    }
+
+    /// Parse the mapped data as an object file.
+    #[cfg(any(
+        feature = "coff",
+        feature = "elf",
+        feature = "macho",
+        feature = "pe",
+        feature = "wasm",
+        feature = "xcoff"
+    ))]
+    pub fn parse(&self) -> Result<File<'_>> {
+        File::parse(self.data())
+    }
+
+    /// Parse the mapped data as an archive.
+    #[cfg(feature = "archive")]
+    pub fn parse_archive(&self) -> Result<ArchiveFile<'_>> {
+        ArchiveFile::parse(self.data())
+    }
+}
@@ -2,7 +2,8 @@
 
 use alloc::borrow::Cow;
 use alloc::vec::Vec;
-use core::{fmt, result};
+use core::hash::{Hash, Hasher};
+use core::{cmp, fmt, result};
 
 use crate::common::*;
 
@@ -14,9 +15,31 @@ mod read_cache;
 #[cfg(feature = "std")]
 pub use read_cache::*;
 
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "mmap")]
+pub use mmap::*;
+
 mod util;
 pub use util::*;
 
+mod dwarf;
+pub use dwarf::*;
+
+mod security;
+pub use security::*;
+
+mod summary;
+pub use summary::*;
+
+#[cfg(any(feature = "coff", feature = "elf", feature = "macho"))]
+pub mod dump;
+
+#[cfg(feature = "demangle")]
+mod demangle;
+#[cfg(feature = "demangle")]
+pub use demangle::*;
+
 #[cfg(any(
     feature = "coff",
     feature = "elf",
@@ -36,21 +59,39 @@ mod any;
 ))]
 pub use any::*;
 
+#[cfg(feature = "aout")]
+pub mod aout;
+
 #[cfg(feature = "archive")]
 pub mod archive;
 
+#[cfg(feature = "bitcode")]
+pub mod bitcode;
+
 #[cfg(feature = "coff")]
 pub mod coff;
 
 #[cfg(feature = "elf")]
 pub mod elf;
 
+#[cfg(feature = "ihex")]
+pub mod ihex;
+
 #[cfg(feature = "macho")]
 pub mod macho;
 
+#[cfg(feature = "omf")]
+pub mod omf;
+
 #[cfg(feature = "pe")]
 pub mod pe;
 
+#[cfg(feature = "raw")]
+pub mod raw;
+
+#[cfg(feature = "srec")]
+pub mod srec;
+
 #[cfg(feature = "wasm")]
 pub mod wasm;
 
@@ -103,6 +144,63 @@ impl<T> ReadError<T> for Option<T> {
     }
 }
 
+/// Limits on the resources used when parsing untrusted data.
+///
+/// Exceeding a limit results in an error instead of excessive recursion or
+/// memory use. Start from `ParseOptions::default()` and lower the limits as needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// The maximum nesting depth of structures that are followed.
+    ///
+    /// This applies to PE resource directories, chained PE function entries,
+    /// nested thin archives, OMF iterated data, and Mach-O export tries. Defaults to 32.
+    pub max_depth: u32,
+    /// The maximum number of entries in a table whose size is read from the file.
+    ///
+    /// This applies to the section, segment, symbol and load command counts in
+    /// object file headers, PE resource directory tables, and PE exception tables.
+    /// Defaults to no limit.
+    pub max_table_entries: usize,
+    /// The maximum size of a buffer that is allocated for data derived from the file.
+    ///
+    /// This applies to decompressed data and OMF segment data. Defaults to no limit.
+    pub max_allocation_size: usize,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            max_depth: 32,
+            max_table_entries: usize::MAX,
+            max_allocation_size: usize::MAX,
+        }
+    }
+}
+
+impl ParseOptions {
+    fn check_depth(&self, depth: u32) -> Result<()> {
+        if depth > self.max_depth {
+            return Err(Error("Nesting depth exceeds parse limit"));
+        }
+        Ok(())
+    }
+
+    fn check_table_entries(&self, count: u64) -> Result<()> {
+        if count > self.max_table_entries as u64 {
+            return Err(Error("Table entry count exceeds parse limit"));
+        }
+        Ok(())
+    }
+
+    fn check_allocation_size(&self, size: u64) -> Result<()> {
+        if size > self.max_allocation_size as u64 {
+            return Err(Error("Allocation size exceeds parse limit"));
+        }
+        Ok(())
+    }
+}
+
 /// The native executable file for the target platform.
 #[cfg(all(
     unix,
@@ -143,8 +241,12 @@ pub type NativeFile<'data, R = &'data [u8]> = wasm::WasmFile<'data, R>;
 
 /// A file format kind.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum FileKind {
+    /// An a.out file.
+    #[cfg(feature = "aout")]
+    Aout,
     /// A Unix archive.
     #[cfg(feature = "archive")]
     Archive,
@@ -162,12 +264,30 @@ pub enum FileKind {
     /// A dyld cache file containing Mach-O images.
     #[cfg(feature = "macho")]
     DyldCache,
+    /// A dyld subcache file.
+    ///
+    /// This is a file that contains no image list, such as
+    /// `dyld_shared_cache_arm64e.1` or `dyld_shared_cache_arm64e.symbols`.
+    /// It must be parsed together with its main dyld cache file.
+    #[cfg(feature = "macho")]
+    DyldSubCache,
     /// A 32-bit ELF file.
     #[cfg(feature = "elf")]
     Elf32,
     /// A 64-bit ELF file.
     #[cfg(feature = "elf")]
     Elf64,
+    /// A file compressed with gzip.
+    Gzip,
+    /// An Intel HEX file.
+    ///
+    /// This must be decoded with `IhexImage::parse`.
+    #[cfg(feature = "ihex")]
+    Ihex,
+    /// An LLVM bitcode file, optionally with a bitcode wrapper header.
+    ///
+    /// The symbol table can be read with `BitcodeFile::parse`.
+    LlvmBitcode,
     /// A 32-bit Mach-O file.
     #[cfg(feature = "macho")]
     MachO32,
@@ -180,21 +300,59 @@ pub enum FileKind {
     /// A 64-bit Mach-O fat binary.
     #[cfg(feature = "macho")]
     MachOFat64,
+    /// A linear executable (LX or LE) file.
+    ///
+    /// This must be parsed with `LxFile::parse`.
+    #[cfg(feature = "pe")]
+    Lx,
+    /// A 16-bit NE file.
+    ///
+    /// This must be parsed with `NeFile::parse`.
+    #[cfg(feature = "pe")]
+    Ne,
+    /// An OMF object module.
+    ///
+    /// This must be decoded with `OmfImage::parse`.
+    #[cfg(feature = "omf")]
+    Omf,
+    /// An OMF library.
+    ///
+    /// This must be parsed with `OmfLibrary::parse`.
+    #[cfg(feature = "omf")]
+    OmfLibrary,
     /// A 32-bit PE file.
     #[cfg(feature = "pe")]
     Pe32,
     /// A 64-bit PE file.
     #[cfg(feature = "pe")]
     Pe64,
+    /// A Motorola S-record file.
+    ///
+    /// This must be decoded with `SrecImage::parse`.
+    #[cfg(feature = "srec")]
+    Srec,
+    /// A Terse Executable (TE) image, as used by UEFI firmware.
+    #[cfg(feature = "pe")]
+    Te,
     /// A Wasm file.
     #[cfg(feature = "wasm")]
     Wasm,
+    /// A Wasm component.
+    #[cfg(feature = "wasm")]
+    WasmComponent,
     /// A 32-bit XCOFF file.
     #[cfg(feature = "xcoff")]
     Xcoff32,
     /// A 64-bit XCOFF file.
     #[cfg(feature = "xcoff")]
     Xcoff64,
+    /// A file compressed with xz.
+    ///
+    /// This is used for the MiniDebugInfo in `.gnu_debugdata` sections, and for
+    /// separate debug files.
+    Xz,
+    /// A file compressed with Zstandard.
+    Zstandard,
 }
 
 impl FileKind {
@@ -205,16 +363,35 @@ impl FileKind {
 
     /// Determine a file kind by parsing at the given offset.
     pub fn parse_at<'data, R: ReadRef<'data>>(data: R, offset: u64) -> Result<FileKind> {
+        let len = data.len().read_error("Could not read file length")?;
         let magic = data
-            .read_bytes_at(offset, 16)
+            .read_bytes_at(offset, cmp::min(len.saturating_sub(offset), 16))
             .read_error("Could not read file magic")?;
         if magic.len() < 16 {
+            // Text formats may have a single record that is shorter than 16 bytes.
+            #[cfg(feature = "ihex")]
+            if ihex::is_ihex(magic) {
+                return Ok(FileKind::Ihex);
+            }
+            #[cfg(feature = "srec")]
+            if srec::is_srec(magic) {
+                return Ok(FileKind::Srec);
+            }
             return Err(Error("File too short"));
         }
 
         let kind = match [magic[0], magic[1], magic[2], magic[3], magic[4], magic[5], magic[6], magic[7]] {
             #[cfg(feature = "archive")]
-            [b'!', b'<', b'a', b'r', b'c', b'h', b'>', b'\n'] => FileKind::Archive,
+            [b'!', b'<', b'a', b'r', b'c', b'h', b'>', b'\n']
+            | [b'!', b'<', b't', b'h', b'i', b'n', b'>', b'\n'] => FileKind::Archive,
+            #[cfg(feature = "macho")]
+            [b'd', b'y', b'l', b'd', b'_', b'v', b'1', b' '] if offset == 0 => {
+                // offset == 0 restriction is because DyldCacheHeader::parse only looks at offset 0
+                match macho::is_dyld_subcache(data) {
+                    Ok(true) => FileKind::DyldSubCache,
+                    _ => FileKind::DyldCache,
+                }
+            }
             #[cfg(feature = "macho")]
             [b'd', b'y', b'l', b'd', b'_', b'v', b'1', b' '] => FileKind::DyldCache,
             #[cfg(feature = "elf")]
@@ -232,7 +409,19 @@ impl FileKind {
             #[cfg(feature = "macho")]
             [0xca, 0xfe, 0xba, 0xbf, ..] => FileKind::MachOFat64,
             #[cfg(feature = "wasm")]
+            [0x00, b'a', b's', b'm', _, _, 0x01, 0x00] => FileKind::WasmComponent,
+            #[cfg(feature = "wasm")]
             [0x00, b'a', b's', b'm', ..] => FileKind::Wasm,
+            [b'B', b'C', 0xc0, 0xde, ..]
+            // Bitcode wrapper
+            | [0xde, 0xc0, 0x17, 0x0b, ..] => FileKind::LlvmBitcode,
+            [0x1f, 0x8b, ..] => FileKind::Gzip,
+            [0xfd, b'7', b'z', b'X', b'Z', 0x00, ..] => FileKind::Xz,
+            [0x28, 0xb5, 0x2f, 0xfd, ..] => FileKind::Zstandard,
+            #[cfg(feature = "ihex")]
+            [b':', ..] if ihex::is_ihex(magic) => FileKind::Ihex,
+            #[cfg(feature = "srec")]
+            [b'S', ..] if srec::is_srec(magic) => FileKind::Srec,
             #[cfg(feature = "pe")]
             [b'M', b'Z', ..] if offset == 0 => {
                 // offset == 0 restriction is because optional_header_magic only looks at offset 0
@@ -243,9 +432,16 @@ impl FileKind {
                     Ok(crate::pe::IMAGE_NT_OPTIONAL_HDR64_MAGIC) => {
                         FileKind::Pe64
                     }
-                    _ => return Err(Error("Unknown MS-DOS file")),
+                    _ => match pe::new_header_signature(data) {
+                        Ok(crate::pe::IMAGE_OS2_SIGNATURE) => FileKind::Ne,
+                        Ok(crate::pe::IMAGE_OS2_SIGNATURE_LE)
+                        | Ok(crate::pe::IMAGE_OS2_SIGNATURE_LX) => FileKind::Lx,
+                        _ => return Err(Error("Unknown MS-DOS file")),
+                    },
                 }
             }
+            #[cfg(feature = "pe")]
+            [b'V', b'Z', ..] => FileKind::Te,
             // TODO: more COFF machines
             #[cfg(feature = "coff")]
             // COFF arm
@@ -270,6 +466,29 @@ impl FileKind {
             [0x01, 0xdf, ..] => FileKind::Xcoff32,
             #[cfg(feature = "xcoff")]
             [0x01, 0xf7, ..] => FileKind::Xcoff64,
+            #[cfg(feature = "omf")]
+            // THEADR or LHEADR record containing only the module name
+            [0x80 | 0x82, low, high, len, ..]
+                if u16::from_le_bytes([low, high]) == u16::from(len) + 2 =>
+            {
+                FileKind::Omf
+            }
+            #[cfg(feature = "omf")]
+            // LIBHDR record with a valid page size and dictionary location
+            [0xf0, ..] if omf::is_omf_library(magic, len.saturating_sub(offset)) => {
+                FileKind::OmfLibrary
+            }
+            #[cfg(feature = "aout")]
+            // a.out OMAGIC, NMAGIC, ZMAGIC (little endian)
+            [0x07 | 0x08 | 0x0b, 0x01, ..]
+            // a.out QMAGIC (little endian)
+            | [0xcc, 0x00, ..]
+            // a.out OMAGIC, NMAGIC, ZMAGIC (big endian, 68010, 68020 or SPARC)
+            | [_, 0x01..=0x03, 0x01, 0x07 | 0x08 | 0x0b, ..]
+                if aout::is_aout(data, offset, len.saturating_sub(offset)) =>
+            {
+                FileKind::Aout
+            }
             _ => return Err(Error("Unknown file magic")),
         };
         Ok(kind)
@@ -278,6 +497,7 @@ impl FileKind {
 
 /// An object kind.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum ObjectKind {
     /// The object kind is unknown.
@@ -294,14 +514,17 @@ pub enum ObjectKind {
 
 /// The index used to identify a section of a file.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SectionIndex(pub usize);
 
 /// The index used to identify a symbol of a file.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SymbolIndex(pub usize);
 
 /// The section where a symbol is defined.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum SymbolSection {
     /// The section is unknown.
@@ -336,6 +559,14 @@ impl SymbolSection {
 pub trait SymbolMapEntry {
     /// The symbol address.
     fn address(&self) -> u64;
+
+    /// The symbol size.
+    ///
+    /// This may be 0 if the size is unknown.
+    #[inline]
+    fn size(&self) -> u64 {
+        0
+    }
 }
 
 /// A map from addresses to symbols.
@@ -365,6 +596,35 @@ impl<T: SymbolMapEntry> SymbolMap<T> {
         self.symbols.get(index)
     }
 
+    /// Get the symbol containing the given address.
+    ///
+    /// Unlike `get`, this only matches if the address is within the size of the
+    /// symbol before the address. If the size of that symbol is 0, then the address
+    /// must be at most `max_gap` bytes after the symbol address instead.
+    pub fn get_within(&self, address: u64, max_gap: u64) -> Option<&T> {
+        let symbol = self.get(address)?;
+        let offset = address - symbol.address();
+        let size = symbol.size();
+        let contained = if size != 0 {
+            offset < size
+        } else {
+            offset <= max_gap
+        };
+        if contained {
+            Some(symbol)
+        } else {
+            None
+        }
+    }
+
+    /// Get the first symbol after the given address.
+    pub fn get_next(&self, address: u64) -> Option<&T> {
+        let index = self
+            .symbols
+            .partition_point(|symbol| symbol.address() <= address);
+        self.symbols.get(index)
+    }
+
     /// Get all symbols in the map.
     #[inline]
     pub fn symbols(&self) -> &[T] {
@@ -372,17 +632,48 @@ impl<T: SymbolMapEntry> SymbolMap<T> {
     }
 }
 
-/// A `SymbolMap` entry for symbol names.
+/// The symbol tables used by [`Object::symbol_map_from`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SymbolMapTables {
+    /// Use the symbol table, or the dynamic symbol table if there is no symbol table.
+    Preferred,
+    /// Use whichever of the symbol table and the dynamic symbol table has more symbols.
+    Largest,
+    /// Use both the symbol table and the dynamic symbol table.
+    ///
+    /// Symbols with the same address and name in both tables are only included once.
+    All,
+}
+
+/// A `SymbolMap` entry for symbol names.
+///
+/// Equality and hashing only use the address and the name, and ignore the size.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SymbolMapName<'data> {
     address: u64,
+    size: u64,
     name: &'data str,
 }
 
 impl<'data> SymbolMapName<'data> {
     /// Construct a `SymbolMapName`.
     pub fn new(address: u64, name: &'data str) -> Self {
-        SymbolMapName { address, name }
+        SymbolMapName {
+            address,
+            size: 0,
+            name,
+        }
+    }
+
+    /// Construct a `SymbolMapName` with a known size.
+    pub fn with_size(address: u64, size: u64, name: &'data str) -> Self {
+        SymbolMapName {
+            address,
+            size,
+            name,
+        }
     }
 
     /// The symbol address.
@@ -391,6 +682,14 @@ impl<'data> SymbolMapName<'data> {
         self.address
     }
 
+    /// The symbol size.
+    ///
+    /// This may be 0 if the size is unknown.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
     /// The symbol name.
     #[inline]
     pub fn name(&self) -> &'data str {
@@ -398,11 +697,441 @@ impl<'data> SymbolMapName<'data> {
     }
 }
 
+impl<'data> PartialEq for SymbolMapName<'data> {
+    fn eq(&self, other: &Self) -> bool {
+        self.address == other.address && self.name == other.name
+    }
+}
+
+impl<'data> Eq for SymbolMapName<'data> {}
+
+impl<'data> Hash for SymbolMapName<'data> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.address.hash(state);
+        self.name.hash(state);
+    }
+}
+
 impl<'data> SymbolMapEntry for SymbolMapName<'data> {
     #[inline]
     fn address(&self) -> u64 {
         self.address
     }
+
+    #[inline]
+    fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// A `SymbolMap` entry for function names and sizes.
+///
+/// This is returned by [`Object::function_map`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FunctionMapEntry<'data> {
+    address: u64,
+    size: u64,
+    name: &'data str,
+    inferred: bool,
+}
+
+impl<'data> FunctionMapEntry<'data> {
+    /// Construct a `FunctionMapEntry`.
+    pub fn new(address: u64, size: u64, name: &'data str) -> Self {
+        FunctionMapEntry {
+            address,
+            size,
+            name,
+            inferred: false,
+        }
+    }
+
+    /// The function address.
+    #[inline]
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// The function size.
+    ///
+    /// This may be 0 if the size is unknown and could not be inferred.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The function name.
+    #[inline]
+    pub fn name(&self) -> &'data str {
+        self.name
+    }
+
+    /// Return true if the size was inferred instead of read from the symbol.
+    #[inline]
+    pub fn is_size_inferred(&self) -> bool {
+        self.inferred
+    }
+
+    /// Return true if the function contains the given address.
+    #[inline]
+    pub fn contains(&self, address: u64) -> bool {
+        address.wrapping_sub(self.address) < self.size
+    }
+}
+
+impl<'data> SymbolMapEntry for FunctionMapEntry<'data> {
+    #[inline]
+    fn address(&self) -> u64 {
+        self.address
+    }
+
+    #[inline]
+    fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+impl<'data> SymbolMap<FunctionMapEntry<'data>> {
+    /// Construct a new function map, inferring sizes for functions with a size of 0.
+    ///
+    /// Each entry is paired with the end address of its containing section, if known.
+    ///
+    /// The inferred size of a function extends to the next function with a higher
+    /// address, or to the end of the section, whichever is lower.
+    /// If there is no following function and the section end is unknown then
+    /// the size remains 0.
+    pub fn with_inferred_sizes(mut functions: Vec<(FunctionMapEntry<'data>, Option<u64>)>) -> Self {
+        functions.sort_unstable_by_key(|(f, _)| f.address);
+        let mut next = None;
+        let mut next_address = None;
+        for (function, section_end) in functions.iter_mut().rev() {
+            // Aliases share an address, so skip them when finding the next function.
+            if next != Some(function.address) {
+                next_address = next;
+                next = Some(function.address);
+            }
+            if function.size != 0 {
+                continue;
+            }
+            let end = match (next_address, *section_end) {
+                (Some(next), Some(section_end)) => next.min(section_end),
+                (Some(next), None) => next,
+                (None, Some(section_end)) => section_end,
+                (None, None) => continue,
+            };
+            if end > function.address {
+                function.size = end - function.address;
+                function.inferred = true;
+            }
+        }
+        SymbolMap {
+            symbols: functions.into_iter().map(|(f, _)| f).collect(),
+        }
+    }
+}
+
+/// The address range of a function.
+///
+/// This is returned by [`Object::function_ranges`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FunctionRange {
+    address: u64,
+    size: u64,
+    inferred: bool,
+}
+
+impl FunctionRange {
+    /// Construct a `FunctionRange`.
+    pub fn new(address: u64, size: u64) -> Self {
+        FunctionRange {
+            address,
+            size,
+            inferred: false,
+        }
+    }
+
+    /// The function address.
+    #[inline]
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// The function size.
+    ///
+    /// This may be 0 if the size is unknown and could not be inferred.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Return true if the size was inferred from the address of the next function
+    /// or the end of the section.
+    #[inline]
+    pub fn is_size_inferred(&self) -> bool {
+        self.inferred
+    }
+
+    /// Return true if the function contains the given address.
+    #[inline]
+    pub fn contains(&self, address: u64) -> bool {
+        address.wrapping_sub(self.address) < self.size
+    }
+}
+
+/// Combine the given function ranges with the functions in the symbol table.
+///
+/// Ranges with a size of 0 have their size inferred. If there are multiple
+/// ranges for an address, then a range with a known size is preferred.
+pub(crate) fn function_ranges<'data: 'file, 'file, O: Object<'data, 'file> + ?Sized>(
+    file: &'file O,
+    ranges: Vec<FunctionRange>,
+) -> Vec<FunctionRange> {
+    let mut sections = file
+        .sections()
+        .filter(|section| section.kind() == SectionKind::Text)
+        .map(|section| {
+            (
+                section.address(),
+                section.address().wrapping_add(section.size()),
+            )
+        })
+        .collect::<Vec<_>>();
+    sections.sort_unstable();
+    let section_end = |address: u64| {
+        let index = sections.partition_point(|(start, _)| *start <= address);
+        let (_, end) = *sections.get(index.checked_sub(1)?)?;
+        if address < end {
+            Some(end)
+        } else {
+            None
+        }
+    };
+
+    let mut functions = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        functions.push((
+            FunctionMapEntry::new(range.address, range.size, ""),
+            section_end(range.address),
+        ));
+    }
+    for function in file.function_map().symbols() {
+        let size = if function.is_size_inferred() {
+            0
+        } else {
+            function.size()
+        };
+        functions.push((
+            FunctionMapEntry::new(function.address(), size, ""),
+            section_end(function.address()),
+        ));
+    }
+
+    let functions = SymbolMap::with_inferred_sizes(functions);
+    let mut ranges = functions
+        .symbols()
+        .iter()
+        .map(|function| FunctionRange {
+            address: function.address(),
+            size: function.size(),
+            inferred: function.is_size_inferred(),
+        })
+        .collect::<Vec<_>>();
+    ranges.sort_by_key(|range| {
+        (
+            range.address,
+            range.inferred,
+            core::cmp::Reverse(range.size),
+        )
+    });
+    ranges.dedup_by_key(|range| range.address);
+    ranges
+}
+
+/// An index from names to symbols in a symbol table.
+///
+/// Constructing the index sorts the symbol names, and each lookup is a binary search.
+/// A sorted table is used instead of a hash map because the `read` feature does not
+/// depend on a hash map implementation, and so that [`Self::get_all`] can return
+/// duplicate names in symbol table order.
+///
+/// With the `std` feature, [`Object::symbol_by_name`] builds an index for the file's
+/// symbol table on first use. Construct an index explicitly for other symbol tables,
+/// or when the `std` feature is not enabled.
+#[derive(Debug, Default, Clone)]
+pub struct SymbolNameIndex<'data> {
+    symbols: Vec<(&'data [u8], SymbolIndex)>,
+}
+
+impl<'data> SymbolNameIndex<'data> {
+    /// Construct an index of the symbols in the given symbol table.
+    ///
+    /// Symbols with empty or invalid names are not included.
+    pub fn new<T: ObjectSymbolTable<'data>>(table: &T) -> Self {
+        let mut symbols: Vec<_> = table
+            .symbols()
+            .filter_map(|symbol| match symbol.name_bytes() {
+                Ok(name) if !name.is_empty() => Some((name, symbol.index())),
+                _ => None,
+            })
+            .collect();
+        // Use a stable sort so that duplicate names remain in symbol table order.
+        symbols.sort_by_key(|(name, _)| *name);
+        SymbolNameIndex { symbols }
+    }
+
+    /// Get the index of the first symbol in the table with the given name.
+    pub fn get(&self, name: &[u8]) -> Option<SymbolIndex> {
+        self.entries(name).first().map(|(_, index)| *index)
+    }
+
+    /// Get the indices of all symbols in the table with the given name.
+    pub fn get_all(&self, name: &[u8]) -> impl Iterator<Item = SymbolIndex> + '_ {
+        self.entries(name).iter().map(|(_, index)| *index)
+    }
+
+    fn entries(&self, name: &[u8]) -> &[(&'data [u8], SymbolIndex)] {
+        let start = self.symbols.partition_point(|(n, _)| *n < name);
+        let end = start + self.symbols[start..].partition_point(|(n, _)| *n == name);
+        &self.symbols[start..end]
+    }
+}
+
+/// A [`SymbolNameIndex`] for a file's symbol table that is constructed on first use.
+///
+/// This is used to implement [`Object::symbol_by_name`]. Without the `std` feature,
+/// each lookup searches the whole symbol table instead.
+#[derive(Debug, Default)]
+pub(crate) struct SymbolNameCache<'data> {
+    #[cfg(feature = "std")]
+    index: std::sync::Mutex<Option<SymbolNameIndex<'data>>>,
+    #[cfg(not(feature = "std"))]
+    marker: core::marker::PhantomData<&'data [u8]>,
+}
+
+impl<'data> SymbolNameCache<'data> {
+    /// Get the index of the first symbol in `table` with the given name.
+    ///
+    /// `table` must be the same symbol table for every call.
+    pub(crate) fn get<T: ObjectSymbolTable<'data>>(
+        &self,
+        table: &T,
+        name: &[u8],
+    ) -> Option<SymbolIndex> {
+        #[cfg(feature = "std")]
+        {
+            let mut index = self.index.lock().unwrap_or_else(|e| e.into_inner());
+            index
+                .get_or_insert_with(|| SymbolNameIndex::new(table))
+                .get(name)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            table
+                .symbols()
+                .find(|symbol| symbol.name_bytes() == Ok(name))
+                .map(|symbol| symbol.index())
+        }
+    }
+}
+
+/// An index from names to sections in an object file.
+///
+/// This is an alternative to [`Object::section_by_name`] for when many lookups
+/// are needed, since that method iterates over the sections and decodes their
+/// names for each lookup. Constructing the index decodes and sorts the section
+/// names, and each lookup is a binary search.
+///
+/// Lookups use the same conventions for system section names as
+/// [`Object::section_by_name`].
+#[derive(Debug, Default, Clone)]
+pub struct SectionNameIndex {
+    macho: bool,
+    // The name, whether the name is an alias for a system section name, and the index.
+    sections: Vec<(Vec<u8>, bool, SectionIndex)>,
+}
+
+impl SectionNameIndex {
+    /// Construct an index of the sections in the given file.
+    ///
+    /// Sections with invalid names are not included.
+    pub fn new<'data, 'file, O>(file: &'file O) -> Self
+    where
+        'data: 'file,
+        O: Object<'data, 'file>,
+    {
+        let mut macho = false;
+        let mut sections = Vec::new();
+        for section in file.sections() {
+            let name = match section.name_bytes() {
+                Ok(name) => name,
+                Err(_) => continue,
+            };
+            let index = section.index();
+            sections.push((name.to_vec(), false, index));
+            let alias = match section.flags() {
+                SectionFlags::MachO { .. } => {
+                    macho = true;
+                    name.strip_prefix(b"__").map(|name| (&b"."[..], name))
+                }
+                #[cfg(feature = "compression")]
+                SectionFlags::Elf { .. } => name
+                    .strip_prefix(b".zdebug_")
+                    .map(|name| (&b".debug_"[..], name)),
+                _ => None,
+            };
+            if let Some((prefix, name)) = alias {
+                let mut alias = prefix.to_vec();
+                alias.extend_from_slice(name);
+                sections.push((alias, true, index));
+            }
+        }
+        // For ELF, aliases for compressed sections must sort after the uncompressed sections.
+        sections.sort_unstable_by(|a, b| (&a.0, a.1, a.2 .0).cmp(&(&b.0, b.1, b.2 .0)));
+        SectionNameIndex { macho, sections }
+    }
+
+    /// Get the index of the section with the given name.
+    ///
+    /// If multiple sections match, then the first matching section is used.
+    pub fn get(&self, name: &[u8]) -> Option<SectionIndex> {
+        if !self.macho {
+            return self.entries(name).first().map(|(_, _, index)| *index);
+        }
+        // Mach-O section names are limited to 16 bytes, so long system section
+        // names are truncated before comparing them with aliases.
+        let mut found = self.entries(name).iter().map(|(_, _, index)| *index).next();
+        if name.starts_with(b".") && name.len() > 15 {
+            let truncated = self
+                .entries(&name[..15])
+                .iter()
+                .find(|(_, alias, _)| *alias);
+            if let Some((_, _, index)) = truncated {
+                if found.map_or(true, |found| index.0 < found.0) {
+                    found = Some(*index);
+                }
+            }
+        }
+        found
+    }
+
+    /// Get the section with the given name.
+    ///
+    /// `file` must be the file that was used to construct the index.
+    pub fn section<'data, 'file, O>(&self, file: &'file O, name: &[u8]) -> Option<O::Section>
+    where
+        'data: 'file,
+        O: Object<'data, 'file>,
+    {
+        file.section_by_index(self.get(name)?).ok()
+    }
+
+    fn entries(&self, name: &[u8]) -> &[(Vec<u8>, bool, SectionIndex)] {
+        let start = self.sections.partition_point(|(n, _, _)| &**n < name);
+        let end = start + self.sections[start..].partition_point(|(n, _, _)| &**n == name);
+        &self.sections[start..end]
+    }
 }
 
 /// A map from addresses to symbol names and object files.
@@ -412,6 +1141,7 @@ impl<'data> SymbolMapEntry for SymbolMapName<'data> {
 pub struct ObjectMap<'data> {
     symbols: SymbolMap<ObjectMapEntry<'data>>,
     objects: Vec<&'data [u8]>,
+    object_files: Vec<ObjectMapFile<'data>>,
 }
 
 impl<'data> ObjectMap<'data> {
@@ -433,17 +1163,40 @@ impl<'data> ObjectMap<'data> {
     pub fn objects(&self) -> &[&'data [u8]] {
         &self.objects
     }
+
+    /// Get all object files in the map.
+    ///
+    /// This has the same order as [`Self::objects`], but with the path split into
+    /// the archive path and member name, and with additional information from the
+    /// STAB entries.
+    #[inline]
+    pub fn object_files(&self) -> &[ObjectMapFile<'data>] {
+        &self.object_files
+    }
 }
 
 /// A `ObjectMap` entry.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ObjectMapEntry<'data> {
     address: u64,
     size: u64,
     name: &'data [u8],
+    kind: SymbolKind,
     object: usize,
 }
 
+impl<'data> Default for ObjectMapEntry<'data> {
+    fn default() -> Self {
+        ObjectMapEntry {
+            address: 0,
+            size: 0,
+            name: &[],
+            kind: SymbolKind::Unknown,
+            object: 0,
+        }
+    }
+}
+
 impl<'data> ObjectMapEntry<'data> {
     /// Get the symbol address.
     #[inline]
@@ -465,6 +1218,14 @@ impl<'data> ObjectMapEntry<'data> {
         self.name
     }
 
+    /// Get the symbol kind.
+    ///
+    /// This is `SymbolKind::Text` for functions, and `SymbolKind::Data` for variables.
+    #[inline]
+    pub fn kind(&self) -> SymbolKind {
+        self.kind
+    }
+
     /// Get the index of the object file name.
     #[inline]
     pub fn object_index(&self) -> usize {
@@ -476,6 +1237,12 @@ impl<'data> ObjectMapEntry<'data> {
     pub fn object(&self, map: &ObjectMap<'data>) -> &'data [u8] {
         map.objects[self.object]
     }
+
+    /// Get the object file.
+    #[inline]
+    pub fn object_file<'a>(&self, map: &'a ObjectMap<'data>) -> &'a ObjectMapFile<'data> {
+        &map.object_files[self.object]
+    }
 }
 
 impl<'data> SymbolMapEntry for ObjectMapEntry<'data> {
@@ -483,36 +1250,212 @@ impl<'data> SymbolMapEntry for ObjectMapEntry<'data> {
     fn address(&self) -> u64 {
         self.address
     }
+
+    #[inline]
+    fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// An object file in an `ObjectMap`.
+///
+/// This is derived from an `N_OSO` STAB entry, and the `N_SO` entries that precede it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ObjectMapFile<'data> {
+    path: &'data [u8],
+    member: Option<&'data [u8]>,
+    timestamp: u64,
+    source_directory: &'data [u8],
+    source_name: &'data [u8],
+}
+
+impl<'data> ObjectMapFile<'data> {
+    #[cfg(feature = "macho")]
+    fn new(
+        name: &'data [u8],
+        timestamp: u64,
+        source_directory: &'data [u8],
+        source_name: &'data [u8],
+    ) -> Self {
+        // Archive members are written as `path/to/archive.a(member.o)`.
+        let (path, member) = match name.split_last() {
+            Some((b')', rest)) => match memchr::memchr(b'(', rest) {
+                Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+                None => (name, None),
+            },
+            _ => (name, None),
+        };
+        ObjectMapFile {
+            path,
+            member,
+            timestamp,
+            source_directory,
+            source_name,
+        }
+    }
+
+    /// Get the path of the object file.
+    ///
+    /// If the object file is a member of an archive, then this is the path of the archive.
+    #[inline]
+    pub fn path(&self) -> &'data [u8] {
+        self.path
+    }
+
+    /// Get the name of the archive member, if the object file is a member of an archive.
+    #[inline]
+    pub fn member(&self) -> Option<&'data [u8]> {
+        self.member
+    }
+
+    /// Get the modification time of the object file.
+    ///
+    /// This is the number of seconds since the Unix epoch, or 0 if unknown.
+    /// Tools should compare this with the modification time of the file before
+    /// using it.
+    #[inline]
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Get the directory of the source file that the object file was compiled from.
+    ///
+    /// This may be empty.
+    #[inline]
+    pub fn source_directory(&self) -> &'data [u8] {
+        self.source_directory
+    }
+
+    /// Get the name of the source file that the object file was compiled from.
+    ///
+    /// This may be a path relative to `source_directory`, or empty if unknown.
+    #[inline]
+    pub fn source_name(&self) -> &'data [u8] {
+        self.source_name
+    }
+}
+
+/// A source file that an object file was compiled from.
+///
+/// This is returned by [`Object::source_files`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SourceFile<'data> {
+    directory: &'data [u8],
+    name: &'data [u8],
+    object: Option<&'data [u8]>,
+}
+
+impl<'data> SourceFile<'data> {
+    pub(crate) fn new(directory: &'data [u8], name: &'data [u8]) -> Self {
+        SourceFile {
+            directory,
+            name,
+            object: None,
+        }
+    }
+
+    /// Get the directory that the source file was compiled in.
+    ///
+    /// This is only known for Mach-O, and may be empty.
+    #[inline]
+    pub fn directory(&self) -> &'data [u8] {
+        self.directory
+    }
+
+    /// Get the name of the source file.
+    ///
+    /// This may be a path relative to `directory`.
+    #[inline]
+    pub fn name(&self) -> &'data [u8] {
+        self.name
+    }
+
+    /// Get the path of the object file that the source file was compiled to.
+    ///
+    /// This is only known for Mach-O files that were linked without
+    /// debugging information, and may be an `archive.a(member.o)` path.
+    #[inline]
+    pub fn object(&self) -> Option<&'data [u8]> {
+        self.object
+    }
 }
 
 /// An imported symbol.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Import<'data> {
     library: ByteString<'data>,
-    // TODO: or ordinal
     name: ByteString<'data>,
+    ordinal: Option<u16>,
+    version: Option<ByteString<'data>>,
+    kind: ImportKind,
 }
 
 impl<'data> Import<'data> {
     /// The symbol name.
+    ///
+    /// This is empty for imports by ordinal.
     #[inline]
     pub fn name(&self) -> &'data [u8] {
         self.name.0
     }
 
     /// The name of the library to import the symbol from.
+    ///
+    /// This is empty if the library is unknown.
     #[inline]
     pub fn library(&self) -> &'data [u8] {
         self.library.0
     }
+
+    /// The ordinal of the symbol in the library, if the symbol is imported by ordinal.
+    ///
+    /// This is only used for PE files.
+    #[inline]
+    pub fn ordinal(&self) -> Option<u16> {
+        self.ordinal
+    }
+
+    /// The version name required for the symbol.
+    ///
+    /// This is only used for ELF files with symbol versioning.
+    #[inline]
+    pub fn version(&self) -> Option<&'data [u8]> {
+        self.version.map(|version| version.0)
+    }
+
+    /// How the import is bound when the file is loaded.
+    #[inline]
+    pub fn kind(&self) -> ImportKind {
+        self.kind
+    }
+}
+
+/// How an imported symbol is bound when the file is loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[non_exhaustive]
+pub enum ImportKind {
+    /// The symbol is bound when the file is loaded.
+    Eager,
+    /// The symbol is bound when it is first used.
+    Lazy,
+    /// The library is loaded and the symbol is bound when it is first used.
+    ///
+    /// This is used for PE delay-load imports.
+    Delay,
+    /// The symbol is not required to exist.
+    Weak,
 }
 
 /// An exported symbol.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Export<'data> {
     // TODO: and ordinal?
     name: ByteString<'data>,
     address: u64,
+    forward: Option<ExportForward<'data>>,
 }
 
 impl<'data> Export<'data> {
@@ -523,14 +1466,60 @@ impl<'data> Export<'data> {
     }
 
     /// The virtual address of the symbol.
+    ///
+    /// This is 0 for forwarded exports.
     #[inline]
     pub fn address(&self) -> u64 {
         self.address
     }
+
+    /// The target of the export, if it is forwarded to another library.
+    ///
+    /// This is used for PE forwarders and Mach-O re-exports.
+    #[inline]
+    pub fn forward(&self) -> Option<ExportForward<'data>> {
+        self.forward
+    }
+}
+
+/// The target of an export that is forwarded to another library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ExportForward<'data> {
+    library: ByteString<'data>,
+    name: ByteString<'data>,
+    ordinal: Option<u32>,
+}
+
+impl<'data> ExportForward<'data> {
+    /// The name of the library that the export is forwarded to.
+    ///
+    /// This is empty if the library is unknown.
+    #[inline]
+    pub fn library(&self) -> &'data [u8] {
+        self.library.0
+    }
+
+    /// The name of the symbol in the target library.
+    ///
+    /// This is empty if the export is forwarded by ordinal.
+    #[inline]
+    pub fn name(&self) -> &'data [u8] {
+        self.name.0
+    }
+
+    /// The ordinal of the symbol in the target library, if the export is forwarded by ordinal.
+    ///
+    /// This is only used for PE files.
+    #[inline]
+    pub fn ordinal(&self) -> Option<u32> {
+        self.ordinal
+    }
 }
 
 /// PDB Information
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CodeView<'data> {
     guid: [u8; 16],
     path: ByteString<'data>,
@@ -557,8 +1546,37 @@ impl<'data> CodeView<'data> {
     }
 }
 
+/// Embedded code signing data.
+///
+/// This is returned by [`Object::code_signature`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CodeSignature<'data> {
+    /// The PE attribute certificate table.
+    ///
+    /// This is a sequence of `WIN_CERTIFICATE` structures, which normally contain
+    /// an Authenticode signature.
+    Pe(&'data [u8]),
+    /// The Mach-O code signature from the `LC_CODE_SIGNATURE` load command.
+    ///
+    /// This is a `CS_SuperBlob` containing code directories and an optional
+    /// CMS signature.
+    MachO(&'data [u8]),
+}
+
+impl<'data> CodeSignature<'data> {
+    /// The raw bytes of the code signing data.
+    #[inline]
+    pub fn data(&self) -> &'data [u8] {
+        match *self {
+            CodeSignature::Pe(data) | CodeSignature::MachO(data) => data,
+        }
+    }
+}
+
 /// The target referenced by a relocation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum RelocationTarget {
     /// The target is a symbol.
@@ -571,6 +1589,7 @@ pub enum RelocationTarget {
 
 /// A relocation entry.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Relocation {
     kind: RelocationKind,
     encoding: RelocationEncoding,
@@ -629,6 +1648,7 @@ impl Relocation {
 
 /// A data compression format.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[non_exhaustive]
 pub enum CompressionFormat {
     /// The data is uncompressed.
@@ -647,6 +1667,7 @@ pub enum CompressionFormat {
 
 /// A range in a file that may be compressed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CompressedFileRange {
     /// The data compression format.
     pub format: CompressionFormat,
@@ -720,6 +1741,17 @@ impl<'data> CompressedData<'data> {
     /// This includes if the data is compressed but the `compression` feature
     /// for this crate is disabled.
     pub fn decompress(self) -> Result<Cow<'data, [u8]>> {
+        self.decompress_with_options(&ParseOptions::default())
+    }
+
+    /// Return the uncompressed data, with limits on the size of the allocation.
+    ///
+    /// Returns an error if the uncompressed size exceeds
+    /// [`ParseOptions::max_allocation_size`], before any memory is allocated.
+    pub fn decompress_with_options(self, options: &ParseOptions) -> Result<Cow<'data, [u8]>> {
+        if self.format != CompressionFormat::None {
+            options.check_allocation_size(self.uncompressed_size)?;
+        }
         match self.format {
             CompressionFormat::None => Ok(Cow::Borrowed(self.data)),
             #[cfg(feature = "compression")]
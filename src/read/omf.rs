@@ -0,0 +1,1388 @@
+//! Support for reading OMF object files and libraries.
+//!
+//! Provides `OmfImage`, which decodes the records of an OMF object module, and
+//! `OmfFile` and related types which implement the `Object` trait for the decoded
+//! module. `OmfLibrary` provides access to the modules of an OMF library, and
+//! `OmfRecordIterator` provides low level access to the records.
+//!
+//! Each segment definition is exposed as a section. Public and external names are
+//! exposed as symbols, and fixups for enumerated data are exposed as relocations.
+use alloc::vec::Vec;
+use core::{iter, slice, str};
+
+use crate::endian::{LittleEndian as LE, U16, U32};
+use crate::omf;
+use crate::read::{
+    self, Architecture, Bytes, CompressedData, CompressedFileRange, Error, Export, FileFlags,
+    Import, NoComdat, NoDynamicRelocationIterator, NoSegment, Object, ObjectKind, ObjectSection,
+    ObjectSymbol, ObjectSymbolTable, ParseOptions, ReadError, Relocation, RelocationEncoding,
+    RelocationKind, RelocationTarget, Result, SectionFlags, SectionIndex, SectionKind, SymbolFlags,
+    SymbolIndex, SymbolKind, SymbolScope, SymbolSection,
+};
+
+/// A record in an OMF file.
+#[derive(Debug, Clone, Copy)]
+pub struct OmfRecord<'data> {
+    record_type: u8,
+    offset: u64,
+    data: &'data [u8],
+}
+
+impl<'data> OmfRecord<'data> {
+    /// The record type. One of the `omf::THEADR`... constants.
+    #[inline]
+    pub fn record_type(&self) -> u8 {
+        self.record_type
+    }
+
+    /// Return true if this is the 32-bit variant of the record type.
+    #[inline]
+    pub fn is_32(&self) -> bool {
+        self.record_type & 1 != 0
+    }
+
+    /// The offset of the record header in the file.
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// The record contents, excluding the header and the checksum.
+    #[inline]
+    pub fn data(&self) -> &'data [u8] {
+        self.data
+    }
+}
+
+/// An iterator over the records in an OMF file.
+///
+/// Checksums are validated unless they are zero, which indicates that the
+/// checksum was not computed.
+#[derive(Debug, Clone)]
+pub struct OmfRecordIterator<'data> {
+    data: Bytes<'data>,
+    offset: u64,
+}
+
+impl<'data> OmfRecordIterator<'data> {
+    /// Construct a new iterator for the records starting at the beginning of `data`.
+    pub fn new(data: &'data [u8]) -> Self {
+        OmfRecordIterator {
+            data: Bytes(data),
+            offset: 0,
+        }
+    }
+
+    fn parse(&mut self) -> Result<OmfRecord<'data>> {
+        let record = self.data;
+        let header = self
+            .data
+            .read::<omf::RecordHeader>()
+            .read_error("Invalid OMF record header")?;
+        let length = usize::from(header.length.get(LE));
+        let contents = self
+            .data
+            .read_bytes(length)
+            .read_error("Invalid OMF record length")?;
+        let (checksum, data) = match contents.0.split_last() {
+            Some(split) => split,
+            None => return Err(Error("Invalid OMF record length")),
+        };
+        if *checksum != 0 {
+            let sum = record.0[..length + 3]
+                .iter()
+                .fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+            if sum != 0 {
+                return Err(Error("Invalid OMF record checksum"));
+            }
+        }
+        let offset = self.offset;
+        self.offset += length as u64 + 3;
+        Ok(OmfRecord {
+            record_type: header.record_type,
+            offset,
+            data,
+        })
+    }
+}
+
+impl<'data> Iterator for OmfRecordIterator<'data> {
+    type Item = Result<OmfRecord<'data>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let result = self.parse();
+        if result.is_err() {
+            self.data = Bytes(&[]);
+        }
+        Some(result)
+    }
+}
+
+/// A fixup in an OMF file.
+///
+/// This is the decoded form of a `FIXUPP` subrecord, with any thread references
+/// resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OmfFixup {
+    /// The offset of the location in the segment.
+    pub offset: u64,
+    /// The location type. One of the `omf::LOC_*` constants.
+    pub location: u8,
+    /// True if the fixup is segment relative, or false if it is self relative.
+    pub segment_relative: bool,
+    /// The frame method. One of the `omf::FRAME_*` constants.
+    pub frame_method: u8,
+    /// The index or frame number for the frame method, or 0 if there is none.
+    pub frame_datum: u16,
+    /// The target method. One of the `omf::TARGET_*` constants.
+    ///
+    /// Target methods without a displacement are converted to the equivalent
+    /// method with a displacement of 0.
+    pub target_method: u8,
+    /// The index or frame number for the target method.
+    pub target_datum: u16,
+    /// The displacement from the target.
+    pub displacement: u32,
+}
+
+/// The decoded contents of an OMF object module.
+///
+/// The data of a segment may be split over many data records, so the records
+/// must be decoded before the data can be accessed through the `Object` API.
+/// Use [`OmfImage::file`] to obtain an `OmfFile` that borrows the decoded data.
+#[derive(Debug, Clone, Default)]
+pub struct OmfImage<'data> {
+    name: &'data [u8],
+    segments: Vec<OmfSectionInternal<'data>>,
+    symbols: Vec<OmfSymbolInternal<'data>>,
+    /// The symbol index of each external name, in external index order.
+    externals: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct OmfSectionInternal<'data> {
+    name: &'data [u8],
+    class: &'data [u8],
+    acbp: u8,
+    length: u64,
+    data: Vec<u8>,
+    fixups: Vec<OmfFixup>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OmfSymbolKind {
+    Public,
+    LocalPublic,
+    External,
+    LocalExternal,
+    Common,
+    LocalCommon,
+}
+
+#[derive(Debug, Clone)]
+struct OmfSymbolInternal<'data> {
+    name: &'data [u8],
+    kind: OmfSymbolKind,
+    segment: u16,
+    value: u64,
+}
+
+/// The state for decoding the records of a module.
+#[derive(Default)]
+struct OmfParser<'data> {
+    names: Vec<&'data [u8]>,
+    /// The target threads and frame threads, as (method, datum) pairs.
+    threads: [[(u8, u16); 4]; 2],
+    /// The segment index and offset of the last `LEDATA` record.
+    ///
+    /// This is `None` after a `LIDATA` record, since fixups for iterated data
+    /// are not supported.
+    last_data: Option<(u16, u64)>,
+    options: ParseOptions,
+}
+
+impl<'data> OmfImage<'data> {
+    /// Decode the records of an OMF object module.
+    ///
+    /// Decoding stops at the `MODEND` record. Fixups for `LIDATA` records
+    /// are ignored.
+    ///
+    /// Returns an error if a record is invalid, if the module header or end
+    /// is missing, or if data records exceed the length of their segment.
+    pub fn parse(data: &'data [u8]) -> Result<Self> {
+        Self::parse_with_options(data, &ParseOptions::default())
+    }
+
+    /// Decode the records of an OMF object module, with limits on parsing.
+    ///
+    /// In addition to the errors for [`OmfImage::parse`], returns an error if
+    /// the decoded data of a segment exceeds [`ParseOptions::max_allocation_size`],
+    /// or if iterated data is nested deeper than [`ParseOptions::max_depth`].
+    pub fn parse_with_options(data: &'data [u8], options: &ParseOptions) -> Result<Self> {
+        let mut image = OmfImage::default();
+        let mut parser = OmfParser {
+            options: *options,
+            ..Default::default()
+        };
+        let mut records = OmfRecordIterator::new(data);
+        match records.next() {
+            Some(Ok(record))
+                if record.record_type == omf::THEADR || record.record_type == omf::LHEADR =>
+            {
+                image.name = read_name(&mut Bytes(record.data))?;
+            }
+            Some(Err(e)) => return Err(e),
+            _ => return Err(Error("Missing OMF module header")),
+        }
+        for record in records {
+            let record = record?;
+            let is_32 = record.is_32();
+            let mut data = Bytes(record.data);
+            match record.record_type {
+                omf::MODEND | omf::MODEND32 => return Ok(image),
+                omf::LNAMES | omf::LLNAMES => {
+                    while !data.is_empty() {
+                        parser.names.push(read_name(&mut data)?);
+                    }
+                }
+                omf::SEGDEF | omf::SEGDEF32 => {
+                    let acbp = *data.read::<u8>().read_error("Invalid OMF SEGDEF record")?;
+                    if omf::seg_alignment(acbp) == omf::SEG_ALIGN_ABSOLUTE {
+                        // Skip the frame number and offset.
+                        data.skip(3).read_error("Invalid OMF SEGDEF record")?;
+                    }
+                    let mut length = read_offset(&mut data, is_32)?;
+                    if acbp & omf::SEG_BIG != 0 && length == 0 {
+                        length = if is_32 { 1 << 32 } else { 1 << 16 };
+                    }
+                    let name = parser.name(read_index(&mut data)?)?;
+                    let class = parser.name(read_index(&mut data)?)?;
+                    image.segments.push(OmfSectionInternal {
+                        name,
+                        class,
+                        acbp,
+                        length,
+                        data: Vec::new(),
+                        fixups: Vec::new(),
+                    });
+                }
+                omf::PUBDEF | omf::PUBDEF32 | omf::LPUBDEF | omf::LPUBDEF32 => {
+                    let kind = if record.record_type & !1 == omf::PUBDEF {
+                        OmfSymbolKind::Public
+                    } else {
+                        OmfSymbolKind::LocalPublic
+                    };
+                    let _group = read_index(&mut data)?;
+                    let segment = image.segment_index(read_index(&mut data)?)?;
+                    let base = if segment == 0 {
+                        let frame = data
+                            .read::<U16<LE>>()
+                            .read_error("Invalid OMF PUBDEF record")?;
+                        u64::from(frame.get(LE)) << 4
+                    } else {
+                        0
+                    };
+                    while !data.is_empty() {
+                        let name = read_name(&mut data)?;
+                        let offset = read_offset(&mut data, is_32)?;
+                        let _type = read_index(&mut data)?;
+                        image.symbols.push(OmfSymbolInternal {
+                            name,
+                            kind,
+                            segment,
+                            value: base + offset,
+                        });
+                    }
+                }
+                omf::EXTDEF | omf::LEXTDEF | omf::CEXTDEF => {
+                    let kind = if record.record_type == omf::LEXTDEF {
+                        OmfSymbolKind::LocalExternal
+                    } else {
+                        OmfSymbolKind::External
+                    };
+                    while !data.is_empty() {
+                        let name = if record.record_type == omf::CEXTDEF {
+                            parser.name(read_index(&mut data)?)?
+                        } else {
+                            read_name(&mut data)?
+                        };
+                        let _type = read_index(&mut data)?;
+                        image.externals.push(image.symbols.len());
+                        image.symbols.push(OmfSymbolInternal {
+                            name,
+                            kind,
+                            segment: 0,
+                            value: 0,
+                        });
+                    }
+                }
+                omf::COMDEF | omf::LCOMDEF => {
+                    let kind = if record.record_type == omf::COMDEF {
+                        OmfSymbolKind::Common
+                    } else {
+                        OmfSymbolKind::LocalCommon
+                    };
+                    while !data.is_empty() {
+                        let name = read_name(&mut data)?;
+                        let _type = read_index(&mut data)?;
+                        let data_type =
+                            *data.read::<u8>().read_error("Invalid OMF COMDEF record")?;
+                        let mut size = read_communal_length(&mut data)?;
+                        if data_type == 0x61 {
+                            // FAR data: the number of elements and the element size.
+                            size = size.wrapping_mul(read_communal_length(&mut data)?);
+                        }
+                        image.externals.push(image.symbols.len());
+                        image.symbols.push(OmfSymbolInternal {
+                            name,
+                            kind,
+                            segment: 0,
+                            value: size,
+                        });
+                    }
+                }
+                omf::LEDATA | omf::LEDATA32 => {
+                    let segment = image.segment_index(read_index(&mut data)?)?;
+                    let offset = read_offset(&mut data, is_32)?;
+                    image.write_data(segment, offset, data.0, &parser.options)?;
+                    parser.last_data = Some((segment, offset));
+                }
+                omf::LIDATA | omf::LIDATA32 => {
+                    let segment = image.segment_index(read_index(&mut data)?)?;
+                    let offset = read_offset(&mut data, is_32)?;
+                    let limit = image.segment(segment)?.length;
+                    let limit = limit.min(parser.options.max_allocation_size as u64);
+                    let max_depth = parser.options.max_depth;
+                    let mut expanded = Vec::new();
+                    while !data.is_empty() {
+                        expand_iterated(&mut data, is_32, &mut expanded, limit, max_depth)?;
+                    }
+                    image.write_data(segment, offset, &expanded, &parser.options)?;
+                    parser.last_data = None;
+                }
+                omf::FIXUPP | omf::FIXUPP32 => {
+                    while !data.is_empty() {
+                        if let Some(fixup) = parser.read_fixup(&mut data, is_32)? {
+                            if fixup.fixup.target_method == omf::TARGET_EXTERNAL
+                                && image.external(fixup.fixup.target_datum).is_none()
+                            {
+                                return Err(Error("Invalid OMF external index"));
+                            }
+                            image.segment(fixup.segment)?.fixups.push(fixup.fixup);
+                        }
+                    }
+                }
+                // Ignore comments, groups, line numbers, and other records that
+                // do not affect the sections or symbols.
+                _ => {}
+            }
+        }
+        Err(Error("Missing OMF module end"))
+    }
+
+    /// Validate a segment index.
+    fn segment_index(&self, index: u16) -> Result<u16> {
+        if usize::from(index) > self.segments.len() {
+            return Err(Error("Invalid OMF segment index"));
+        }
+        Ok(index)
+    }
+
+    fn segment(&mut self, index: u16) -> Result<&mut OmfSectionInternal<'data>> {
+        usize::from(index)
+            .checked_sub(1)
+            .and_then(move |index| self.segments.get_mut(index))
+            .read_error("Invalid OMF segment index")
+    }
+
+    /// Return the symbol index for an external index.
+    fn external(&self, index: u16) -> Option<usize> {
+        let index = usize::from(index).checked_sub(1)?;
+        self.externals.get(index).copied()
+    }
+
+    fn write_data(
+        &mut self,
+        segment: u16,
+        offset: u64,
+        data: &[u8],
+        options: &ParseOptions,
+    ) -> Result<()> {
+        let segment = self.segment(segment)?;
+        let end = offset + data.len() as u64;
+        if end > segment.length {
+            return Err(Error("OMF data exceeds segment length"));
+        }
+        options.check_allocation_size(end)?;
+        let (offset, end) = (offset as usize, end as usize);
+        if segment.data.len() < end {
+            segment.data.resize(end, 0);
+        }
+        segment.data[offset..end].copy_from_slice(data);
+        Ok(())
+    }
+
+    /// The name of the module from the `THEADR` or `LHEADR` record.
+    #[inline]
+    pub fn name(&self) -> &'data [u8] {
+        self.name
+    }
+
+    /// Return an `OmfFile` for accessing the decoded data.
+    #[inline]
+    pub fn file(&self) -> OmfFile<'_> {
+        OmfFile { image: self }
+    }
+}
+
+impl<'data> OmfParser<'data> {
+    fn name(&self, index: u16) -> Result<&'data [u8]> {
+        usize::from(index)
+            .checked_sub(1)
+            .and_then(|index| self.names.get(index))
+            .copied()
+            .read_error("Invalid OMF name index")
+    }
+
+    /// Read a `FIXUPP` subrecord.
+    ///
+    /// Returns `None` for a thread subrecord, or if there is no preceding
+    /// `LEDATA` record.
+    fn read_fixup(
+        &mut self,
+        data: &mut Bytes<'data>,
+        is_32: bool,
+    ) -> Result<Option<OmfSegmentFixup>> {
+        let first = *data.read::<u8>().read_error("Invalid OMF FIXUPP record")?;
+        if first & 0x80 == 0 {
+            let is_frame = first & 0x40 != 0;
+            let method = (first >> 2) & 0x7;
+            let datum = if is_frame {
+                read_datum(data, method)?
+            } else {
+                read_datum(data, method & 0x3)?
+            };
+            self.threads[usize::from(is_frame)][usize::from(first & 0x3)] = (method, datum);
+            return Ok(None);
+        }
+
+        let second = *data.read::<u8>().read_error("Invalid OMF FIXUPP record")?;
+        let fix_data = *data.read::<u8>().read_error("Invalid OMF FIXUPP record")?;
+        let (frame_method, frame_datum) = if fix_data & 0x80 != 0 {
+            self.threads[1][usize::from((fix_data >> 4) & 0x3)]
+        } else {
+            let method = (fix_data >> 4) & 0x7;
+            (method, read_datum(data, method)?)
+        };
+        let (target_method, target_datum) = if fix_data & 0x08 != 0 {
+            let (method, datum) = self.threads[0][usize::from(fix_data & 0x3)];
+            (method & 0x3, datum)
+        } else {
+            let method = fix_data & 0x3;
+            (method, read_datum(data, method)?)
+        };
+        let displacement = if fix_data & 0x04 == 0 {
+            read_offset(data, is_32)? as u32
+        } else {
+            0
+        };
+        let (segment, offset) = match self.last_data {
+            Some(last_data) => last_data,
+            None => return Ok(None),
+        };
+        let fixup = OmfFixup {
+            offset: offset + u64::from(u16::from(first & 0x3) << 8 | u16::from(second)),
+            location: (first >> 2) & 0xf,
+            segment_relative: first & 0x40 != 0,
+            frame_method,
+            frame_datum,
+            target_method,
+            target_datum,
+            displacement,
+        };
+        Ok(Some(OmfSegmentFixup { segment, fixup }))
+    }
+}
+
+/// A fixup and the index of the segment containing its location.
+struct OmfSegmentFixup {
+    segment: u16,
+    fixup: OmfFixup,
+}
+
+/// Read the index or frame number for a frame or target method.
+fn read_datum(data: &mut Bytes<'_>, method: u8) -> Result<u16> {
+    match method {
+        0..=2 => read_index(data),
+        3 => data
+            .read::<U16<LE>>()
+            .map(|frame| frame.get(LE))
+            .read_error("Invalid OMF frame number"),
+        _ => Ok(0),
+    }
+}
+
+/// Read a one or two byte index.
+fn read_index(data: &mut Bytes<'_>) -> Result<u16> {
+    let first = *data.read::<u8>().read_error("Invalid OMF index")?;
+    if first & 0x80 == 0 {
+        return Ok(first.into());
+    }
+    let second = *data.read::<u8>().read_error("Invalid OMF index")?;
+    Ok(u16::from(first & 0x7f) << 8 | u16::from(second))
+}
+
+/// Read a name that is prefixed by a one byte length.
+fn read_name<'data>(data: &mut Bytes<'data>) -> Result<&'data [u8]> {
+    let length = *data.read::<u8>().read_error("Invalid OMF name")?;
+    data.read_bytes(length.into())
+        .map(|name| name.0)
+        .read_error("Invalid OMF name")
+}
+
+/// Read a 16-bit or 32-bit offset or length.
+fn read_offset(data: &mut Bytes<'_>, is_32: bool) -> Result<u64> {
+    if is_32 {
+        data.read::<U32<LE>>().map(|x| x.get(LE).into())
+    } else {
+        data.read::<U16<LE>>().map(|x| x.get(LE).into())
+    }
+    .read_error("Invalid OMF offset")
+}
+
+/// Read a length in a `COMDEF` record.
+fn read_communal_length(data: &mut Bytes<'_>) -> Result<u64> {
+    let first = *data
+        .read::<u8>()
+        .read_error("Invalid OMF communal length")?;
+    let size = match first {
+        0..=0x80 => return Ok(first.into()),
+        0x81 => 2,
+        0x84 => 3,
+        0x88 => 4,
+        _ => return Err(Error("Invalid OMF communal length")),
+    };
+    let bytes = data
+        .read_bytes(size)
+        .read_error("Invalid OMF communal length")?;
+    Ok(bytes
+        .0
+        .iter()
+        .rev()
+        .fold(0, |value, byte| value << 8 | u64::from(*byte)))
+}
+
+/// Expand a block of a `LIDATA` record.
+///
+/// `depth` is the number of further levels of nested blocks that are allowed.
+fn expand_iterated(
+    data: &mut Bytes<'_>,
+    is_32: bool,
+    out: &mut Vec<u8>,
+    limit: u64,
+    depth: u32,
+) -> Result<()> {
+    let repeat = read_offset(data, is_32)?;
+    let blocks = data
+        .read::<U16<LE>>()
+        .read_error("Invalid OMF LIDATA record")?
+        .get(LE);
+    let start = out.len();
+    if blocks == 0 {
+        let content = read_name(data)?;
+        out.extend_from_slice(content);
+    } else {
+        let depth = depth
+            .checked_sub(1)
+            .read_error("OMF iterated data is nested too deeply")?;
+        for _ in 0..blocks {
+            expand_iterated(data, is_32, out, limit, depth)?;
+        }
+    }
+    let block_len = (out.len() - start) as u64;
+    if block_len == 0 {
+        // Repeating an empty block has no effect, and may be repeated many times.
+        return Ok(());
+    }
+    if start as u64 + block_len.saturating_mul(repeat) > limit {
+        return Err(Error("OMF data exceeds segment length"));
+    }
+    if repeat == 0 {
+        out.truncate(start);
+    }
+    for _ in 1..repeat {
+        out.extend_from_within(start..start + block_len as usize);
+    }
+    Ok(())
+}
+
+/// An OMF object file.
+///
+/// This borrows the data decoded by [`OmfImage::parse`]. `File::parse` can't
+/// decode OMF files, but an `OmfFile` can be wrapped in `File::Omf` instead.
+#[derive(Debug, Clone, Copy)]
+pub struct OmfFile<'data> {
+    image: &'data OmfImage<'data>,
+}
+
+impl<'data> OmfFile<'data> {
+    /// The name of the module from the `THEADR` or `LHEADR` record.
+    #[inline]
+    pub fn module_name(&self) -> &'data [u8] {
+        self.image.name
+    }
+}
+
+impl<'data> read::private::Sealed for OmfFile<'data> {}
+
+impl<'data, 'file> Object<'data, 'file> for OmfFile<'data>
+where
+    'data: 'file,
+{
+    type Segment = NoSegment;
+    type SegmentIterator = iter::Empty<NoSegment>;
+    type Section = OmfSection<'data, 'file>;
+    type SectionIterator = OmfSectionIterator<'data, 'file>;
+    type Comdat = NoComdat;
+    type ComdatIterator = iter::Empty<NoComdat>;
+    type Symbol = OmfSymbol<'data, 'file>;
+    type SymbolIterator = OmfSymbolIterator<'data, 'file>;
+    type SymbolTable = OmfSymbolTable<'data, 'file>;
+    type DynamicRelocationIterator = NoDynamicRelocationIterator;
+
+    #[inline]
+    fn architecture(&self) -> Architecture {
+        Architecture::I386
+    }
+
+    #[inline]
+    fn is_little_endian(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn is_64(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn kind(&self) -> ObjectKind {
+        ObjectKind::Relocatable
+    }
+
+    #[inline]
+    fn segments(&'file self) -> Self::SegmentIterator {
+        iter::empty()
+    }
+
+    fn section_by_name_bytes(&'file self, section_name: &[u8]) -> Option<OmfSection<'data, 'file>> {
+        self.sections()
+            .find(|section| section.section.name == section_name)
+    }
+
+    fn section_by_index(&'file self, index: SectionIndex) -> Result<OmfSection<'data, 'file>> {
+        let section = index
+            .0
+            .checked_sub(1)
+            .and_then(|i| self.image.segments.get(i))
+            .read_error("Invalid OMF section index")?;
+        Ok(OmfSection {
+            file: self,
+            index,
+            section,
+        })
+    }
+
+    fn sections(&'file self) -> Self::SectionIterator {
+        OmfSectionIterator {
+            file: self,
+            iter: self.image.segments.iter().enumerate(),
+        }
+    }
+
+    #[inline]
+    fn comdats(&'file self) -> Self::ComdatIterator {
+        iter::empty()
+    }
+
+    fn symbol_by_index(&'file self, index: SymbolIndex) -> Result<OmfSymbol<'data, 'file>> {
+        let symbol = self
+            .image
+            .symbols
+            .get(index.0)
+            .read_error("Invalid OMF symbol index")?;
+        Ok(OmfSymbol {
+            file: self,
+            index,
+            symbol,
+        })
+    }
+
+    fn symbols(&'file self) -> Self::SymbolIterator {
+        OmfSymbolIterator {
+            file: self,
+            iter: self.image.symbols.iter().enumerate(),
+        }
+    }
+
+    fn symbol_table(&'file self) -> Option<OmfSymbolTable<'data, 'file>> {
+        if self.image.symbols.is_empty() {
+            None
+        } else {
+            Some(OmfSymbolTable { file: self })
+        }
+    }
+
+    fn dynamic_symbols(&'file self) -> Self::SymbolIterator {
+        OmfSymbolIterator {
+            file: self,
+            iter: <&[_]>::default().iter().enumerate(),
+        }
+    }
+
+    #[inline]
+    fn dynamic_symbol_table(&'file self) -> Option<OmfSymbolTable<'data, 'file>> {
+        None
+    }
+
+    #[inline]
+    fn dynamic_relocations(&self) -> Option<NoDynamicRelocationIterator> {
+        None
+    }
+
+    fn imports(&self) -> Result<Vec<Import<'data>>> {
+        Ok(Vec::new())
+    }
+
+    fn exports(&self) -> Result<Vec<Export<'data>>> {
+        Ok(Vec::new())
+    }
+
+    #[inline]
+    fn has_debug_symbols(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn relative_address_base(&self) -> u64 {
+        0
+    }
+
+    /// Returns 0 because the start address in the `MODEND` record is not decoded.
+    #[inline]
+    fn entry(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn flags(&self) -> FileFlags {
+        FileFlags::None
+    }
+}
+
+/// An iterator over the sections of an `OmfFile`.
+#[derive(Debug)]
+pub struct OmfSectionIterator<'data, 'file> {
+    file: &'file OmfFile<'data>,
+    iter: core::iter::Enumerate<slice::Iter<'data, OmfSectionInternal<'data>>>,
+}
+
+impl<'data, 'file> Iterator for OmfSectionIterator<'data, 'file> {
+    type Item = OmfSection<'data, 'file>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, section) = self.iter.next()?;
+        Some(OmfSection {
+            file: self.file,
+            index: SectionIndex(index + 1),
+            section,
+        })
+    }
+}
+
+/// A section of an `OmfFile`.
+///
+/// This corresponds to a `SEGDEF` record. Section indices are the same as
+/// OMF segment indices, and start at 1.
+#[derive(Debug)]
+pub struct OmfSection<'data, 'file> {
+    file: &'file OmfFile<'data>,
+    index: SectionIndex,
+    section: &'data OmfSectionInternal<'data>,
+}
+
+impl<'data, 'file> OmfSection<'data, 'file> {
+    /// The class name of the segment, such as `CODE`.
+    #[inline]
+    pub fn class_name(&self) -> &'data [u8] {
+        self.section.class
+    }
+
+    /// The ACBP byte of the segment definition.
+    #[inline]
+    pub fn acbp(&self) -> u8 {
+        self.section.acbp
+    }
+
+    /// The decoded fixups for the enumerated data of the segment.
+    #[inline]
+    pub fn fixups(&self) -> &'data [OmfFixup] {
+        &self.section.fixups
+    }
+}
+
+impl<'data, 'file> read::private::Sealed for OmfSection<'data, 'file> {}
+
+impl<'data, 'file> ObjectSection<'data> for OmfSection<'data, 'file> {
+    type RelocationIterator = OmfRelocationIterator<'data, 'file>;
+
+    #[inline]
+    fn index(&self) -> SectionIndex {
+        self.index
+    }
+
+    /// Returns 0 because OMF segments are relocatable.
+    #[inline]
+    fn address(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn size(&self) -> u64 {
+        self.section.length
+    }
+
+    fn align(&self) -> u64 {
+        match omf::seg_alignment(self.section.acbp) {
+            omf::SEG_ALIGN_WORD => 2,
+            omf::SEG_ALIGN_PARAGRAPH => 16,
+            omf::SEG_ALIGN_PAGE => 256,
+            omf::SEG_ALIGN_DWORD => 4,
+            omf::SEG_ALIGN_PAGE4K => 4096,
+            _ => 1,
+        }
+    }
+
+    /// Returns `None` because the data may be split over many data records.
+    #[inline]
+    fn file_range(&self) -> Option<(u64, u64)> {
+        None
+    }
+
+    /// Returns the data from the `LEDATA` and `LIDATA` records for the segment.
+    ///
+    /// This may be shorter than the segment size if the end of the segment
+    /// is not initialized.
+    #[inline]
+    fn data(&self) -> Result<&'data [u8]> {
+        Ok(&self.section.data)
+    }
+
+    fn data_range(&self, address: u64, size: u64) -> Result<Option<&'data [u8]>> {
+        Ok(read::util::data_range(
+            &self.section.data,
+            self.address(),
+            address,
+            size,
+        ))
+    }
+
+    #[inline]
+    fn compressed_file_range(&self) -> Result<CompressedFileRange> {
+        Ok(CompressedFileRange::none(self.file_range()))
+    }
+
+    #[inline]
+    fn compressed_data(&self) -> Result<CompressedData<'data>> {
+        self.data().map(CompressedData::none)
+    }
+
+    #[inline]
+    fn name_bytes(&self) -> Result<&[u8]> {
+        Ok(self.section.name)
+    }
+
+    fn name(&self) -> Result<&str> {
+        str::from_utf8(self.section.name)
+            .ok()
+            .read_error("Non UTF-8 OMF segment name")
+    }
+
+    #[inline]
+    fn segment_name_bytes(&self) -> Result<Option<&[u8]>> {
+        Ok(None)
+    }
+
+    #[inline]
+    fn segment_name(&self) -> Result<Option<&str>> {
+        Ok(None)
+    }
+
+    /// Returns the kind based on the conventional suffix of the class name.
+    fn kind(&self) -> SectionKind {
+        let class = self.section.class;
+        let ends_with = |suffix: &[u8]| {
+            class.len() >= suffix.len()
+                && class[class.len() - suffix.len()..].eq_ignore_ascii_case(suffix)
+        };
+        if ends_with(b"CODE") {
+            SectionKind::Text
+        } else if ends_with(b"CONST") {
+            SectionKind::ReadOnlyData
+        } else if ends_with(b"DATA") {
+            SectionKind::Data
+        } else if ends_with(b"BSS") || ends_with(b"STACK") {
+            SectionKind::UninitializedData
+        } else if class.starts_with(b"DEB") {
+            SectionKind::Debug
+        } else {
+            SectionKind::Unknown
+        }
+    }
+
+    fn relocations(&self) -> OmfRelocationIterator<'data, 'file> {
+        OmfRelocationIterator {
+            file: self.file,
+            iter: self.section.fixups.iter(),
+        }
+    }
+
+    #[inline]
+    fn flags(&self) -> SectionFlags {
+        SectionFlags::None
+    }
+}
+
+/// A symbol table of an `OmfFile`.
+#[derive(Debug, Clone, Copy)]
+pub struct OmfSymbolTable<'data, 'file> {
+    file: &'file OmfFile<'data>,
+}
+
+impl<'data, 'file> read::private::Sealed for OmfSymbolTable<'data, 'file> {}
+
+impl<'data, 'file> ObjectSymbolTable<'data> for OmfSymbolTable<'data, 'file> {
+    type Symbol = OmfSymbol<'data, 'file>;
+    type SymbolIterator = OmfSymbolIterator<'data, 'file>;
+
+    fn symbols(&self) -> Self::SymbolIterator {
+        self.file.symbols()
+    }
+
+    fn symbol_by_index(&self, index: SymbolIndex) -> Result<Self::Symbol> {
+        self.file.symbol_by_index(index)
+    }
+}
+
+/// An iterator over the symbols of an `OmfFile`.
+#[derive(Debug)]
+pub struct OmfSymbolIterator<'data, 'file> {
+    file: &'file OmfFile<'data>,
+    iter: core::iter::Enumerate<slice::Iter<'data, OmfSymbolInternal<'data>>>,
+}
+
+impl<'data, 'file> Iterator for OmfSymbolIterator<'data, 'file> {
+    type Item = OmfSymbol<'data, 'file>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, symbol) = self.iter.next()?;
+        Some(OmfSymbol {
+            file: self.file,
+            index: SymbolIndex(index),
+            symbol,
+        })
+    }
+}
+
+/// A symbol of an `OmfFile`.
+///
+/// This is a name from a `PUBDEF`, `EXTDEF` or `COMDEF` record, or one of their
+/// local variants. Symbols are in record order.
+#[derive(Debug, Clone, Copy)]
+pub struct OmfSymbol<'data, 'file> {
+    file: &'file OmfFile<'data>,
+    index: SymbolIndex,
+    symbol: &'data OmfSymbolInternal<'data>,
+}
+
+impl<'data, 'file> read::private::Sealed for OmfSymbol<'data, 'file> {}
+
+impl<'data, 'file> ObjectSymbol<'data> for OmfSymbol<'data, 'file> {
+    #[inline]
+    fn index(&self) -> SymbolIndex {
+        self.index
+    }
+
+    #[inline]
+    fn name_bytes(&self) -> Result<&'data [u8]> {
+        Ok(self.symbol.name)
+    }
+
+    fn name(&self) -> Result<&'data str> {
+        str::from_utf8(self.symbol.name)
+            .ok()
+            .read_error("Non UTF-8 OMF symbol name")
+    }
+
+    fn address(&self) -> u64 {
+        match self.symbol.kind {
+            OmfSymbolKind::Public | OmfSymbolKind::LocalPublic => self.symbol.value,
+            _ => 0,
+        }
+    }
+
+    fn size(&self) -> u64 {
+        if self.is_common() {
+            self.symbol.value
+        } else {
+            0
+        }
+    }
+
+    fn kind(&self) -> SymbolKind {
+        match self.section() {
+            SymbolSection::Section(index) => match self.file.section_by_index(index) {
+                Ok(section) if section.kind() == SectionKind::Text => SymbolKind::Text,
+                Ok(_) => SymbolKind::Data,
+                Err(_) => SymbolKind::Unknown,
+            },
+            SymbolSection::Common => SymbolKind::Data,
+            _ => SymbolKind::Unknown,
+        }
+    }
+
+    fn section(&self) -> SymbolSection {
+        match self.symbol.kind {
+            OmfSymbolKind::Public | OmfSymbolKind::LocalPublic => {
+                if self.symbol.segment == 0 {
+                    SymbolSection::Absolute
+                } else {
+                    SymbolSection::Section(SectionIndex(self.symbol.segment.into()))
+                }
+            }
+            OmfSymbolKind::External | OmfSymbolKind::LocalExternal => SymbolSection::Undefined,
+            OmfSymbolKind::Common | OmfSymbolKind::LocalCommon => SymbolSection::Common,
+        }
+    }
+
+    #[inline]
+    fn is_undefined(&self) -> bool {
+        self.section() == SymbolSection::Undefined
+    }
+
+    #[inline]
+    fn is_definition(&self) -> bool {
+        matches!(
+            self.symbol.kind,
+            OmfSymbolKind::Public | OmfSymbolKind::LocalPublic
+        )
+    }
+
+    #[inline]
+    fn is_common(&self) -> bool {
+        self.section() == SymbolSection::Common
+    }
+
+    #[inline]
+    fn is_weak(&self) -> bool {
+        false
+    }
+
+    fn scope(&self) -> SymbolScope {
+        match self.symbol.kind {
+            OmfSymbolKind::External => SymbolScope::Unknown,
+            OmfSymbolKind::Public | OmfSymbolKind::Common => SymbolScope::Linkage,
+            _ => SymbolScope::Compilation,
+        }
+    }
+
+    #[inline]
+    fn is_global(&self) -> bool {
+        matches!(
+            self.symbol.kind,
+            OmfSymbolKind::Public | OmfSymbolKind::External | OmfSymbolKind::Common
+        )
+    }
+
+    #[inline]
+    fn is_local(&self) -> bool {
+        !self.is_global()
+    }
+
+    #[inline]
+    fn flags(&self) -> SymbolFlags<SectionIndex, SymbolIndex> {
+        SymbolFlags::None
+    }
+}
+
+/// An iterator over the relocations in an `OmfSection`.
+///
+/// Offset fixups with a segment or external target are converted to absolute or
+/// relative relocations. Other fixups use `RelocationKind::Omf`, and the full
+/// details are available from [`OmfSection::fixups`].
+#[derive(Debug)]
+pub struct OmfRelocationIterator<'data, 'file> {
+    file: &'file OmfFile<'data>,
+    iter: slice::Iter<'data, OmfFixup>,
+}
+
+impl<'data, 'file> Iterator for OmfRelocationIterator<'data, 'file> {
+    type Item = (u64, Relocation);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let fixup = self.iter.next()?;
+        let target = match fixup.target_method {
+            omf::TARGET_SEGMENT => {
+                RelocationTarget::Section(SectionIndex(fixup.target_datum.into()))
+            }
+            omf::TARGET_EXTERNAL => match self.file.image.external(fixup.target_datum) {
+                Some(index) => RelocationTarget::Symbol(SymbolIndex(index)),
+                None => RelocationTarget::Absolute,
+            },
+            _ => RelocationTarget::Absolute,
+        };
+        let offset_size = match fixup.location {
+            omf::LOC_LOW_BYTE => Some(8),
+            omf::LOC_OFFSET | omf::LOC_LOADER_OFFSET => Some(16),
+            omf::LOC_OFFSET32 | omf::LOC_LOADER_OFFSET32 => Some(32),
+            _ => None,
+        };
+        let mut addend = i64::from(fixup.displacement);
+        let (kind, size) = match offset_size {
+            Some(size) if target != RelocationTarget::Absolute => {
+                if fixup.segment_relative {
+                    (RelocationKind::Absolute, size)
+                } else {
+                    // Self relative fixups are relative to the end of the location.
+                    addend -= i64::from(size / 8);
+                    (RelocationKind::Relative, size)
+                }
+            }
+            _ => {
+                let size = match fixup.location {
+                    omf::LOC_LOW_BYTE | omf::LOC_HIGH_BYTE => 8,
+                    omf::LOC_POINTER | omf::LOC_OFFSET32 | omf::LOC_LOADER_OFFSET32 => 32,
+                    omf::LOC_POINTER48 => 48,
+                    _ => 16,
+                };
+                (RelocationKind::Omf(fixup.location), size)
+            }
+        };
+        Some((
+            fixup.offset,
+            Relocation {
+                kind,
+                encoding: RelocationEncoding::Generic,
+                size,
+                target,
+                addend,
+                implicit_addend: true,
+            },
+        ))
+    }
+}
+
+/// An OMF library.
+///
+/// The library consists of a `LIBHDR` record, followed by object modules that
+/// are aligned to the page size, and a dictionary.
+#[derive(Debug, Clone, Copy)]
+pub struct OmfLibrary<'data> {
+    data: &'data [u8],
+    page_size: u64,
+    dictionary_offset: u64,
+    dictionary_blocks: u16,
+    flags: u8,
+}
+
+impl<'data> OmfLibrary<'data> {
+    /// Parse the library header.
+    pub fn parse(data: &'data [u8]) -> Result<Self> {
+        let header = match OmfRecordIterator::new(data).next() {
+            Some(record) => record?,
+            None => return Err(Error("Invalid OMF library header")),
+        };
+        if header.record_type != omf::LIBHDR {
+            return Err(Error("Invalid OMF library header"));
+        }
+        let page_size = header.data.len() as u64 + 4;
+        if !page_size.is_power_of_two() || page_size < 16 {
+            return Err(Error("Invalid OMF library page size"));
+        }
+        let mut fields = Bytes(header.data);
+        let dictionary_offset = fields
+            .read::<U32<LE>>()
+            .read_error("Invalid OMF library header")?
+            .get(LE);
+        let dictionary_blocks = fields
+            .read::<U16<LE>>()
+            .read_error("Invalid OMF library header")?
+            .get(LE);
+        let flags = *fields
+            .read::<u8>()
+            .read_error("Invalid OMF library header")?;
+        if !is_valid_dictionary(
+            page_size,
+            dictionary_offset.into(),
+            dictionary_blocks,
+            data.len() as u64,
+        ) {
+            return Err(Error("Invalid OMF library dictionary"));
+        }
+        Ok(OmfLibrary {
+            data,
+            page_size,
+            dictionary_offset: dictionary_offset.into(),
+            dictionary_blocks,
+            flags,
+        })
+    }
+
+    /// The alignment of the modules in the library.
+    #[inline]
+    pub fn page_size(&self) -> u64 {
+        self.page_size
+    }
+
+    /// The offset of the dictionary in the file.
+    #[inline]
+    pub fn dictionary_offset(&self) -> u64 {
+        self.dictionary_offset
+    }
+
+    /// The number of 512 byte blocks in the dictionary.
+    #[inline]
+    pub fn dictionary_blocks(&self) -> u16 {
+        self.dictionary_blocks
+    }
+
+    /// Return true if the dictionary is case sensitive.
+    #[inline]
+    pub fn is_case_sensitive(&self) -> bool {
+        self.flags & 1 != 0
+    }
+
+    /// Iterate over the modules in the library.
+    pub fn members(&self) -> OmfLibraryIterator<'data> {
+        OmfLibraryIterator {
+            data: self.data,
+            offset: self.page_size,
+            page_size: self.page_size,
+        }
+    }
+}
+
+/// Return true if the data starts with a library header that is valid for a file
+/// of the given length.
+pub(crate) fn is_omf_library(header: &[u8], len: u64) -> bool {
+    match *header {
+        [omf::LIBHDR, l0, l1, o0, o1, o2, o3, b0, b1, ..] => is_valid_dictionary(
+            u64::from(u16::from_le_bytes([l0, l1])) + 3,
+            u32::from_le_bytes([o0, o1, o2, o3]).into(),
+            u16::from_le_bytes([b0, b1]),
+            len,
+        ),
+        _ => false,
+    }
+}
+
+/// Check the page size and the dictionary location of a library.
+///
+/// The dictionary follows the modules, starts on a 512 byte boundary,
+/// and has at least one block.
+fn is_valid_dictionary(page_size: u64, offset: u64, blocks: u16, len: u64) -> bool {
+    page_size.is_power_of_two()
+        && page_size >= 16
+        && offset >= page_size
+        && offset % 512 == 0
+        && blocks != 0
+        && offset + u64::from(blocks) * 512 <= len
+}
+
+/// An iterator over the modules in an `OmfLibrary`.
+#[derive(Debug)]
+pub struct OmfLibraryIterator<'data> {
+    data: &'data [u8],
+    offset: u64,
+    page_size: u64,
+}
+
+impl<'data> OmfLibraryIterator<'data> {
+    fn parse(&mut self) -> Result<Option<OmfLibraryMember<'data>>> {
+        let start = self.offset;
+        let data = self
+            .data
+            .get(start as usize..)
+            .read_error("Invalid OMF library module offset")?;
+        let mut records = OmfRecordIterator::new(data);
+        let name = match records.next() {
+            Some(record) => {
+                let record = record?;
+                match record.record_type {
+                    omf::LIBEND => return Ok(None),
+                    omf::THEADR | omf::LHEADR => read_name(&mut Bytes(record.data))?,
+                    _ => return Err(Error("Missing OMF module header")),
+                }
+            }
+            None => return Ok(None),
+        };
+        for record in records {
+            let record = record?;
+            if record.record_type == omf::MODEND || record.record_type == omf::MODEND32 {
+                let end = record.offset + record.data.len() as u64 + 4;
+                self.offset = start + (end + self.page_size - 1) / self.page_size * self.page_size;
+                return Ok(Some(OmfLibraryMember {
+                    name,
+                    offset: start,
+                    data: &data[..end as usize],
+                }));
+            }
+        }
+        Err(Error("Missing OMF module end"))
+    }
+}
+
+impl<'data> Iterator for OmfLibraryIterator<'data> {
+    type Item = Result<OmfLibraryMember<'data>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.parse().transpose();
+        if !matches!(result, Some(Ok(_))) {
+            self.offset = self.data.len() as u64;
+        }
+        result
+    }
+}
+
+/// A module in an `OmfLibrary`.
+///
+/// The module data can be decoded with [`OmfImage::parse`].
+#[derive(Debug, Clone, Copy)]
+pub struct OmfLibraryMember<'data> {
+    name: &'data [u8],
+    offset: u64,
+    data: &'data [u8],
+}
+
+impl<'data> OmfLibraryMember<'data> {
+    /// The name of the module from the `THEADR` or `LHEADR` record.
+    #[inline]
+    pub fn name(&self) -> &'data [u8] {
+        self.name
+    }
+
+    /// The offset of the module in the library.
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// The data of the module, including the `MODEND` record.
+    #[inline]
+    pub fn data(&self) -> &'data [u8] {
+        self.data
+    }
+}
@@ -0,0 +1,384 @@
+//! PE attribute certificates and Authenticode signatures.
+//!
+//! This only parses the structure of the signature. Verifying the signature
+//! requires cryptographic support, which is out of scope for this crate.
+
+use alloc::vec::Vec;
+
+use crate::read::{Bytes, Error, ReadError, Result};
+use crate::{pe, LittleEndian as LE};
+
+/// An iterator over the entries in a PE attribute certificate table.
+///
+/// Returned by [`PeFile::certificates`](super::PeFile::certificates) and
+/// [`DataDirectories::security_directory`](super::DataDirectories::security_directory).
+#[derive(Debug, Default, Clone)]
+pub struct CertificateIterator<'data> {
+    data: Bytes<'data>,
+}
+
+impl<'data> CertificateIterator<'data> {
+    /// Construct an iterator over the given attribute certificate table data.
+    pub fn new(data: &'data [u8]) -> Self {
+        CertificateIterator { data: Bytes(data) }
+    }
+
+    /// Return the next certificate.
+    ///
+    /// Returns `Ok(None)` at the end of the table.
+    pub fn next(&mut self) -> Result<Option<Certificate<'data>>> {
+        if self.data.is_empty() {
+            return Ok(None);
+        }
+        let result = self.parse().map(Some);
+        if result.is_err() {
+            self.data = Bytes(&[]);
+        }
+        result
+    }
+
+    fn parse(&mut self) -> Result<Certificate<'data>> {
+        let mut data = self.data;
+        let header = data
+            .read::<pe::WinCertificate>()
+            .read_error("Invalid PE certificate header")?;
+        let length = header.length.get(LE) as usize;
+        let size = length
+            .checked_sub(core::mem::size_of::<pe::WinCertificate>())
+            .read_error("Invalid PE certificate length")?;
+        let certificate = data
+            .read_bytes(size)
+            .read_error("Invalid PE certificate length")?;
+        // Entries are aligned to 8 bytes. Allow the padding to be missing at the end.
+        self.data = data;
+        let padding = length.wrapping_neg() & 7;
+        if self.data.skip(padding).is_err() {
+            self.data = Bytes(&[]);
+        }
+        Ok(Certificate {
+            header,
+            data: certificate.0,
+        })
+    }
+}
+
+/// An entry in a PE attribute certificate table.
+#[derive(Debug, Clone, Copy)]
+pub struct Certificate<'data> {
+    header: &'data pe::WinCertificate,
+    data: &'data [u8],
+}
+
+impl<'data> Certificate<'data> {
+    /// Return the header of the entry.
+    #[inline]
+    pub fn header(&self) -> &'data pe::WinCertificate {
+        self.header
+    }
+
+    /// Return the revision of the entry.
+    ///
+    /// This is one of the `WIN_CERT_REVISION_*` constants.
+    #[inline]
+    pub fn revision(&self) -> u16 {
+        self.header.revision.get(LE)
+    }
+
+    /// Return the type of the certificate data.
+    ///
+    /// This is one of the `WIN_CERT_TYPE_*` constants.
+    #[inline]
+    pub fn certificate_type(&self) -> u16 {
+        self.header.certificate_type.get(LE)
+    }
+
+    /// Return the certificate data, excluding the header and padding.
+    #[inline]
+    pub fn data(&self) -> &'data [u8] {
+        self.data
+    }
+
+    /// Parse the certificate data as an Authenticode signature.
+    ///
+    /// Returns an error if the certificate type is not `WIN_CERT_TYPE_PKCS_SIGNED_DATA`.
+    pub fn authenticode(&self) -> Result<AuthenticodeSignature<'data>> {
+        if self.certificate_type() != pe::WIN_CERT_TYPE_PKCS_SIGNED_DATA {
+            return Err(Error("Unsupported PE certificate type"));
+        }
+        AuthenticodeSignature::parse(self.data)
+    }
+}
+
+/// A digest algorithm used by an Authenticode signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DigestAlgorithm {
+    /// An unknown algorithm.
+    ///
+    /// Use [`AuthenticodeSignature::digest_algorithm_oid`] to identify it.
+    Unknown,
+    /// MD5.
+    Md5,
+    /// SHA-1.
+    Sha1,
+    /// SHA-256.
+    Sha256,
+    /// SHA-384.
+    Sha384,
+    /// SHA-512.
+    Sha512,
+}
+
+// DER encoded object identifiers, excluding the tag and length.
+/// 1.2.840.113549.1.7.2
+const OID_SIGNED_DATA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x07, 0x02];
+/// 1.3.6.1.4.1.311.2.1.4
+const OID_SPC_INDIRECT_DATA: &[u8] = &[0x2b, 0x06, 0x01, 0x04, 0x01, 0x82, 0x37, 0x02, 0x01, 0x04];
+/// 1.2.840.113549.1.9.6
+const OID_COUNTERSIGNATURE: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x09, 0x06];
+/// 1.3.6.1.4.1.311.3.3.1
+const OID_RFC3161_TIMESTAMP: &[u8] = &[0x2b, 0x06, 0x01, 0x04, 0x01, 0x82, 0x37, 0x03, 0x03, 0x01];
+/// 1.2.840.113549.2.5
+const OID_MD5: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x02, 0x05];
+/// 1.3.14.3.2.26
+const OID_SHA1: &[u8] = &[0x2b, 0x0e, 0x03, 0x02, 0x1a];
+/// 2.16.840.1.101.3.4.2.1
+const OID_SHA256: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01];
+/// 2.16.840.1.101.3.4.2.2
+const OID_SHA384: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02];
+/// 2.16.840.1.101.3.4.2.3
+const OID_SHA512: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03];
+
+const TAG_INTEGER: u8 = 0x02;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_OID: u8 = 0x06;
+const TAG_SEQUENCE: u8 = 0x30;
+const TAG_SET: u8 = 0x31;
+const TAG_CONTEXT_0: u8 = 0xa0;
+const TAG_CONTEXT_1: u8 = 0xa1;
+
+/// The structure of an Authenticode signature.
+///
+/// This is a PKCS#7 `SignedData` structure containing an `SpcIndirectDataContent`.
+/// Only the fields needed to check the signed digest are parsed; the signature
+/// itself is not verified.
+#[derive(Debug, Clone, Copy)]
+pub struct AuthenticodeSignature<'data> {
+    data: &'data [u8],
+    digest_algorithm: &'data [u8],
+    digest: &'data [u8],
+    certificates: &'data [u8],
+    has_timestamp: bool,
+}
+
+impl<'data> AuthenticodeSignature<'data> {
+    /// Parse a DER encoded PKCS#7 `ContentInfo` containing an Authenticode signature.
+    pub fn parse(data: &'data [u8]) -> Result<Self> {
+        let mut content_info = Der::new(data).read(TAG_SEQUENCE)?;
+        if content_info.read(TAG_OID)?.0 .0 != OID_SIGNED_DATA {
+            return Err(Error("Unsupported PKCS#7 content type"));
+        }
+        let mut signed_data = content_info.read(TAG_CONTEXT_0)?.read(TAG_SEQUENCE)?;
+        signed_data.read(TAG_INTEGER)?;
+        signed_data.read(TAG_SET)?;
+
+        let mut content = signed_data.read(TAG_SEQUENCE)?;
+        if content.read(TAG_OID)?.0 .0 != OID_SPC_INDIRECT_DATA {
+            return Err(Error("Unsupported Authenticode content type"));
+        }
+        let mut indirect_data = content.read(TAG_CONTEXT_0)?.read(TAG_SEQUENCE)?;
+        indirect_data.read(TAG_SEQUENCE)?;
+        let mut digest_info = indirect_data.read(TAG_SEQUENCE)?;
+        let digest_algorithm = digest_info.read(TAG_SEQUENCE)?.read(TAG_OID)?.0 .0;
+        let digest = digest_info.read(TAG_OCTET_STRING)?.0 .0;
+
+        let mut certificates: &[u8] = &[];
+        if signed_data.peek() == Some(TAG_CONTEXT_0) {
+            certificates = signed_data.read(TAG_CONTEXT_0)?.0 .0;
+        }
+        if signed_data.peek() == Some(TAG_CONTEXT_1) {
+            signed_data.read(TAG_CONTEXT_1)?;
+        }
+
+        // Only the first signer is used. Any nested signatures are ignored.
+        let mut has_timestamp = false;
+        let mut signer_info = signed_data.read(TAG_SET)?.read(TAG_SEQUENCE)?;
+        while let Some(tag) = signer_info.peek() {
+            let value = signer_info.read(tag)?;
+            if tag == TAG_CONTEXT_1 {
+                has_timestamp = Self::has_timestamp_attribute(value)?;
+            }
+        }
+
+        Ok(AuthenticodeSignature {
+            data,
+            digest_algorithm,
+            digest,
+            certificates,
+            has_timestamp,
+        })
+    }
+
+    fn has_timestamp_attribute(mut attributes: Der<'data>) -> Result<bool> {
+        while attributes.peek().is_some() {
+            let mut attribute = attributes.read(TAG_SEQUENCE)?;
+            let oid = attribute.read(TAG_OID)?.0 .0;
+            if oid == OID_COUNTERSIGNATURE || oid == OID_RFC3161_TIMESTAMP {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Return the raw DER encoded signature.
+    #[inline]
+    pub fn data(&self) -> &'data [u8] {
+        self.data
+    }
+
+    /// Return the DER encoded object identifier of the digest algorithm.
+    ///
+    /// This excludes the tag and length.
+    #[inline]
+    pub fn digest_algorithm_oid(&self) -> &'data [u8] {
+        self.digest_algorithm
+    }
+
+    /// Return the digest algorithm that was used to compute the signed digest.
+    pub fn digest_algorithm(&self) -> DigestAlgorithm {
+        match self.digest_algorithm {
+            OID_MD5 => DigestAlgorithm::Md5,
+            OID_SHA1 => DigestAlgorithm::Sha1,
+            OID_SHA256 => DigestAlgorithm::Sha256,
+            OID_SHA384 => DigestAlgorithm::Sha384,
+            OID_SHA512 => DigestAlgorithm::Sha512,
+            _ => DigestAlgorithm::Unknown,
+        }
+    }
+
+    /// Return the signed digest of the file.
+    #[inline]
+    pub fn digest(&self) -> &'data [u8] {
+        self.digest
+    }
+
+    /// Return true if the signed digest is equal to the given digest.
+    ///
+    /// The given digest should be computed using `digest_algorithm` over the
+    /// ranges returned by [`PeFile::authenticode_ranges`](super::PeFile::authenticode_ranges).
+    #[inline]
+    pub fn matches_digest(&self, digest: &[u8]) -> bool {
+        self.digest == digest
+    }
+
+    /// Return an iterator over the certificates included in the signature.
+    ///
+    /// This normally includes the signer certificate and its chain.
+    #[inline]
+    pub fn certificates(&self) -> AuthenticodeCertificateIterator<'data> {
+        AuthenticodeCertificateIterator {
+            data: Der::new(self.certificates),
+        }
+    }
+
+    /// Return true if the signer has a timestamp countersignature.
+    ///
+    /// This includes both PKCS#9 countersignatures and RFC 3161 timestamps.
+    #[inline]
+    pub fn has_timestamp(&self) -> bool {
+        self.has_timestamp
+    }
+}
+
+/// A page that is hashed for an Authenticode page hash table.
+///
+/// Returned by [`PeFile::authenticode_pages`](super::PeFile::authenticode_pages).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthenticodePage {
+    /// The file offset of the page.
+    ///
+    /// This is the offset that is stored in the page hash table.
+    pub offset: u64,
+    /// The file ranges that are hashed for the page, as `(offset, size)` pairs.
+    pub ranges: Vec<(u64, u64)>,
+    /// The number of zero bytes that are hashed after the ranges.
+    pub padding: u64,
+}
+
+/// An iterator over the certificates in an Authenticode signature.
+///
+/// Returned by [`AuthenticodeSignature::certificates`].
+#[derive(Debug, Clone)]
+pub struct AuthenticodeCertificateIterator<'data> {
+    data: Der<'data>,
+}
+
+impl<'data> AuthenticodeCertificateIterator<'data> {
+    /// Return the next certificate as a DER encoded blob, including the tag and length.
+    ///
+    /// Returns `Ok(None)` when there are no more certificates.
+    pub fn next(&mut self) -> Result<Option<&'data [u8]>> {
+        let tag = match self.data.peek() {
+            Some(tag) => tag,
+            None => return Ok(None),
+        };
+        let result = self.data.read_raw(tag).map(Some);
+        if result.is_err() {
+            self.data = Der::new(&[]);
+        }
+        result
+    }
+}
+
+/// A minimal reader for DER encoded data.
+///
+/// Only low tag numbers and definite lengths are supported, which is sufficient
+/// for DER.
+#[derive(Debug, Clone, Copy)]
+struct Der<'data>(Bytes<'data>);
+
+impl<'data> Der<'data> {
+    fn new(data: &'data [u8]) -> Self {
+        Der(Bytes(data))
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.0 .0.first().copied()
+    }
+
+    /// Read an element with the given tag, and return its contents.
+    fn read(&mut self, tag: u8) -> Result<Der<'data>> {
+        let (_, contents) = self.read_element(tag)?;
+        Ok(Der(contents))
+    }
+
+    /// Read an element with the given tag, and return the whole element.
+    fn read_raw(&mut self, tag: u8) -> Result<&'data [u8]> {
+        let (element, _) = self.read_element(tag)?;
+        Ok(element)
+    }
+
+    fn read_element(&mut self, tag: u8) -> Result<(&'data [u8], Bytes<'data>)> {
+        let start = self.0;
+        if *self.0.read::<u8>().read_error("Invalid DER tag")? != tag {
+            return Err(Error("Unexpected DER tag"));
+        }
+        let first = *self.0.read::<u8>().read_error("Invalid DER length")?;
+        let length = if first < 0x80 {
+            usize::from(first)
+        } else {
+            let count = usize::from(first & 0x7f);
+            if count == 0 || count > 4 {
+                return Err(Error("Unsupported DER length"));
+            }
+            let mut length = 0;
+            for byte in self.0.read_bytes(count).read_error("Invalid DER length")?.0 {
+                length = (length << 8) | usize::from(*byte);
+            }
+            length
+        };
+        let contents = self.0.read_bytes(length).read_error("Invalid DER length")?;
+        let element = &start.0[..start.len() - self.0.len()];
+        Ok((element, contents))
+    }
+}
@@ -4,8 +4,9 @@ use crate::read::{Error, ReadError, ReadRef, Result};
 use crate::{pe, LittleEndian as LE};
 
 use super::{
-    DelayLoadImportTable, ExportTable, ImportTable, RelocationBlockIterator, ResourceDirectory,
-    SectionTable,
+    CertificateIterator, DelayLoadImportTable, ExceptionTable, ExportTable,
+    ImageLoadConfigDirectory, ImportTable, LoadConfigDirectory, RelocationBlockIterator,
+    ResourceDirectory, SectionTable,
 };
 
 /// The table of data directories in a PE file.
@@ -162,6 +163,78 @@ impl<'data> DataDirectories<'data> {
         let rsrc_data = data_dir.data(data, sections)?;
         Ok(Some(ResourceDirectory::new(rsrc_data)))
     }
+
+    /// Returns an iterator over the entries in the attribute certificate table.
+    ///
+    /// Unlike other data directories, the address of the certificate table is a
+    /// file offset instead of a virtual address, since the table is not loaded.
+    ///
+    /// `data` must be the entire file data.
+    pub fn security_directory<R: ReadRef<'data>>(
+        &self,
+        data: R,
+    ) -> Result<Option<CertificateIterator<'data>>> {
+        Ok(self.certificate_table(data)?.map(CertificateIterator::new))
+    }
+
+    /// Returns the data of the attribute certificate table.
+    pub(crate) fn certificate_table<R: ReadRef<'data>>(
+        &self,
+        data: R,
+    ) -> Result<Option<&'data [u8]>> {
+        let data_dir = match self.get(pe::IMAGE_DIRECTORY_ENTRY_SECURITY) {
+            Some(data_dir) => data_dir,
+            None => return Ok(None),
+        };
+        let (offset, size) = data_dir.address_range();
+        data.read_bytes_at(offset.into(), size.into())
+            .read_error("Invalid PE certificate table offset or size")
+            .map(Some)
+    }
+
+    /// Returns the x64 function entries in the exception directory.
+    ///
+    /// `data` must be the entire file data.
+    /// `machine` must be the machine in the file header. Returns an error if the
+    /// file has an exception directory and the machine is not
+    /// `IMAGE_FILE_MACHINE_AMD64`, since other machines use a different entry format.
+    pub fn exception_table<R: ReadRef<'data>>(
+        &self,
+        data: R,
+        sections: &SectionTable<'data>,
+        machine: u16,
+    ) -> Result<Option<ExceptionTable<'data>>> {
+        let data_dir = match self.get(pe::IMAGE_DIRECTORY_ENTRY_EXCEPTION) {
+            Some(data_dir) => data_dir,
+            None => return Ok(None),
+        };
+        if machine != pe::IMAGE_FILE_MACHINE_AMD64 {
+            return Err(Error("Unsupported PE exception directory machine"));
+        }
+        let pdata = data_dir.data(data, sections)?;
+        ExceptionTable::parse(pdata).map(Some)
+    }
+
+    /// Returns the load configuration directory.
+    ///
+    /// `C` must be [`pe::ImageLoadConfigDirectory32`] or [`pe::ImageLoadConfigDirectory64`],
+    /// depending on the type of the optional header.
+    ///
+    /// `data` must be the entire file data.
+    pub fn load_config_directory<C: ImageLoadConfigDirectory, R: ReadRef<'data>>(
+        &self,
+        data: R,
+        sections: &SectionTable<'data>,
+    ) -> Result<Option<LoadConfigDirectory<C>>> {
+        let data_dir = match self.get(pe::IMAGE_DIRECTORY_ENTRY_LOAD_CONFIG) {
+            Some(data_dir) => data_dir,
+            None => return Ok(None),
+        };
+        let load_config_data = sections
+            .pe_data_at(data, data_dir.virtual_address.get(LE))
+            .read_error("Invalid PE load config address")?;
+        LoadConfigDirectory::parse(load_config_data).map(Some)
+    }
 }
 
 impl pe::ImageDataDirectory {
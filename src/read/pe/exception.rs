@@ -0,0 +1,139 @@
+use core::mem;
+
+use crate::read::{Bytes, Error, ParseOptions, ReadError, ReadRef, Result};
+use crate::{pe, LittleEndian as LE};
+
+use super::SectionTable;
+
+/// A table of x64 function entries in the exception directory (`.pdata`) of a PE file.
+///
+/// The entries are sorted by address, which allows an enclosing function
+/// to be found with a binary search.
+#[derive(Debug, Clone, Copy)]
+pub struct ExceptionTable<'data> {
+    entries: &'data [pe::ImageRuntimeFunctionEntry],
+    max_depth: u32,
+}
+
+impl<'data> ExceptionTable<'data> {
+    /// Parse the exception table.
+    ///
+    /// `data` must be the data of the exception directory of an x64 file.
+    /// Any trailing bytes that are too short for an entry are ignored.
+    pub fn parse(data: &'data [u8]) -> Result<Self> {
+        Self::parse_with_options(data, &ParseOptions::default())
+    }
+
+    /// Parse the exception table, with limits on parsing.
+    ///
+    /// Returns an error if the number of entries exceeds [`ParseOptions::max_table_entries`].
+    /// `find_function` follows at most [`ParseOptions::max_depth`] chained entries.
+    pub fn parse_with_options(data: &'data [u8], options: &ParseOptions) -> Result<Self> {
+        let count = data.len() / mem::size_of::<pe::ImageRuntimeFunctionEntry>();
+        options.check_table_entries(count as u64)?;
+        let entries = data
+            .read_slice_at(0, count)
+            .read_error("Invalid PE exception directory size")?;
+        Ok(ExceptionTable {
+            entries,
+            max_depth: options.max_depth,
+        })
+    }
+
+    /// Return the function entries.
+    pub fn entries(&self) -> &'data [pe::ImageRuntimeFunctionEntry] {
+        self.entries
+    }
+
+    /// Find the function entry with an address range containing the given
+    /// relative virtual address.
+    ///
+    /// This does not resolve chained entries. See `find_function`.
+    pub fn find_entry(&self, rva: u32) -> Option<&'data pe::ImageRuntimeFunctionEntry> {
+        let index = match self
+            .entries
+            .binary_search_by_key(&rva, |entry| entry.begin_address.get(LE))
+        {
+            Ok(index) => index,
+            Err(0) => return None,
+            Err(index) => index - 1,
+        };
+        let entry = &self.entries[index];
+        if rva < entry.end_address.get(LE) {
+            Some(entry)
+        } else {
+            None
+        }
+    }
+
+    /// Find the primary function entry for the function containing the given
+    /// relative virtual address.
+    ///
+    /// A function may be split into multiple entries, where the unwind info for
+    /// each secondary entry is chained to another entry. This follows the chain
+    /// to the primary entry, which gives the start of the function.
+    ///
+    /// `data` must be the entire file data.
+    pub fn find_function<R: ReadRef<'data>>(
+        &self,
+        data: R,
+        sections: &SectionTable<'data>,
+        rva: u32,
+    ) -> Result<Option<&'data pe::ImageRuntimeFunctionEntry>> {
+        let mut entry = match self.find_entry(rva) {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+        let mut depth = 0;
+        while let Some(chained) = entry.chained_entry(data, sections)? {
+            if depth == self.max_depth {
+                return Err(Error("Too many chained PE function entries"));
+            }
+            entry = chained;
+            depth += 1;
+        }
+        Ok(Some(entry))
+    }
+}
+
+impl pe::ImageRuntimeFunctionEntry {
+    /// Return the entry that this entry is chained to, if any.
+    ///
+    /// `data` must be the entire file data.
+    pub fn chained_entry<'data, R: ReadRef<'data>>(
+        &self,
+        data: R,
+        sections: &SectionTable<'data>,
+    ) -> Result<Option<&'data pe::ImageRuntimeFunctionEntry>> {
+        let unwind_info = self.unwind_info_address_or_data.get(LE);
+        if unwind_info & pe::RUNTIME_FUNCTION_INDIRECT != 0 {
+            // The unwind info address is the address of another function entry.
+            let entry = sections
+                .pe_data_at(data, unwind_info & !pe::RUNTIME_FUNCTION_INDIRECT)
+                .read_error("Invalid PE chained function entry address")?;
+            return Bytes(entry)
+                .read()
+                .read_error("Invalid PE chained function entry size")
+                .map(Some);
+        }
+
+        let mut info = sections
+            .pe_data_at(data, unwind_info)
+            .map(Bytes)
+            .read_error("Invalid PE unwind info address")?;
+        let header = info
+            .read_bytes(4)
+            .read_error("Invalid PE unwind info size")?
+            .0;
+        if (header[0] >> 3) & pe::UNW_FLAG_CHAININFO == 0 {
+            return Ok(None);
+        }
+        // The chained entry follows the unwind codes, which are padded to an even count.
+        let count = usize::from(header[2]);
+        info.skip((count + (count & 1)) * 2)
+            .read_error("Invalid PE unwind code count")?;
+        info.read()
+            .read_error("Invalid PE chained function entry size")
+            .map(Some)
+    }
+}
@@ -290,7 +290,7 @@ impl<'data> ExportTable<'data> {
     /// Returns the parsed exports in this table.
     pub fn exports(&self) -> Result<Vec<Export<'data>>> {
         // First, let's list all exports.
-        let mut exports = Vec::new();
+        let mut exports = Vec::with_capacity(self.addresses.len());
         let ordinal_base = self.ordinal_base();
         for (i, address) in self.addresses.iter().enumerate() {
             // Convert from an array index to an ordinal.
@@ -2,18 +2,22 @@ use alloc::vec::Vec;
 use core::fmt::Debug;
 use core::{mem, str};
 
-use core::convert::TryInto;
+use core::convert::{TryFrom, TryInto};
 
 use crate::read::coff::{CoffCommon, CoffSymbol, CoffSymbolIterator, CoffSymbolTable, SymbolTable};
 use crate::read::{
-    self, Architecture, ComdatKind, Error, Export, FileFlags, Import, NoDynamicRelocationIterator,
-    Object, ObjectComdat, ObjectKind, ReadError, ReadRef, Result, SectionIndex, SymbolIndex,
+    self, security, Architecture, CodeSignature, ComdatKind, Error, Export, ExportForward,
+    FileFlags, FunctionRange, Import, ImportKind, Object, ObjectComdat, ObjectKind, ParseOptions,
+    ReadError, ReadRef, Result, SectionIndex, SecurityFeatures, SymbolIndex, SymbolNameCache,
 };
-use crate::{pe, ByteString, Bytes, CodeView, LittleEndian as LE, Pod, U32};
+use crate::{pe, ByteString, Bytes, CodeView, LittleEndian as LE, Pod, U32Bytes, U16, U32};
 
 use super::{
-    DataDirectories, ExportTable, ImageThunkData, ImportTable, PeSection, PeSectionIterator,
-    PeSegment, PeSegmentIterator, RichHeaderInfo, SectionTable,
+    AuthenticodePage, CertificateIterator, DataDirectories, DelayLoadDescriptorIterator,
+    DelayLoadImportTable, ExceptionTable, ExportTable, ExportTarget, HintNameSection,
+    ImageLoadConfigDirectory, ImageThunkData, ImportDescriptorIterator, ImportTable,
+    ImportThunkList, LoadConfigDirectory, PeDynamicRelocationIterator, PeSection,
+    PeSectionIterator, PeSegment, PeSegmentIterator, RichHeaderInfo, SectionTable,
 };
 
 /// A PE32 (32-bit) image file.
@@ -32,6 +36,7 @@ where
     pub(super) nt_headers: &'data Pe,
     pub(super) data_directories: DataDirectories<'data>,
     pub(super) common: CoffCommon<'data, R>,
+    pub(super) symbol_names: SymbolNameCache<'data>,
     pub(super) data: R,
 }
 
@@ -42,10 +47,22 @@ where
 {
     /// Parse the raw PE file data.
     pub fn parse(data: R) -> Result<Self> {
+        Self::parse_with_options(data, &ParseOptions::default())
+    }
+
+    /// Parse the raw PE file data, with limits on parsing.
+    ///
+    /// Returns an error if the number of sections or COFF symbols in the file header
+    /// exceeds [`ParseOptions::max_table_entries`]. These counts are checked before
+    /// the tables are read.
+    pub fn parse_with_options(data: R, options: &ParseOptions) -> Result<Self> {
         let dos_header = pe::ImageDosHeader::parse(data)?;
         let mut offset = dos_header.nt_headers_offset().into();
         let (nt_headers, data_directories) = Pe::parse(data, &mut offset)?;
+        let file_header = nt_headers.file_header();
+        options.check_table_entries(file_header.number_of_sections.get(LE).into())?;
         let sections = nt_headers.sections(data, offset)?;
+        options.check_table_entries(file_header.number_of_symbols.get(LE).into())?;
         let coff_symbols = nt_headers.symbols(data);
         let image_base = nt_headers.optional_header().image_base();
 
@@ -61,6 +78,7 @@ where
                 image_base,
             },
             data,
+            symbol_names: SymbolNameCache::default(),
         })
     }
 
@@ -99,6 +117,210 @@ where
         self.data_directories.get(id)
     }
 
+    /// Returns the load configuration directory of this file.
+    ///
+    /// The tables in the directory can be read using [`Self::relative_address_base`]
+    /// as the image base.
+    pub fn load_config_directory(
+        &self,
+    ) -> Result<Option<LoadConfigDirectory<Pe::ImageLoadConfigDirectory>>> {
+        self.data_directories
+            .load_config_directory(self.data, &self.common.sections)
+    }
+
+    /// Returns the attribute certificate table of this file.
+    ///
+    /// The certificate table is located using the data directory. Unlike other data
+    /// directories, its address is a file offset.
+    pub fn certificate_table(&self) -> Result<Option<&'data [u8]>> {
+        self.data_directories.certificate_table(self.data)
+    }
+
+    /// Returns an iterator over the entries in the attribute certificate table.
+    ///
+    /// The iterator is empty if there is no certificate table.
+    pub fn certificates(&self) -> Result<CertificateIterator<'data>> {
+        Ok(self
+            .data_directories
+            .security_directory(self.data)?
+            .unwrap_or_default())
+    }
+
+    /// Returns the file ranges that are hashed to compute the Authenticode digest.
+    ///
+    /// This is the whole file except for the checksum in the optional header, the
+    /// certificate table data directory entry, and the certificate table itself.
+    /// The ranges are sorted, and are returned as `(offset, size)` pairs.
+    ///
+    /// The digest of the concatenated ranges can be compared with
+    /// [`AuthenticodeSignature::matches_digest`](super::AuthenticodeSignature::matches_digest).
+    pub fn authenticode_ranges(&self) -> Result<Vec<(u64, u64)>> {
+        let file_size = self.data.len().read_error("Invalid PE file size")?;
+        let mut excluded = self.authenticode_header_exclusions();
+        if let Some(data_dir) = self
+            .data_directories
+            .get(pe::IMAGE_DIRECTORY_ENTRY_SECURITY)
+        {
+            let (offset, size) = data_dir.address_range();
+            if size != 0 {
+                excluded.push((offset.into(), size.into()));
+            }
+        }
+        authenticode_ranges_excluding(0, file_size, &excluded)
+    }
+
+    /// Pass the data that is hashed to compute the Authenticode digest to `update`.
+    ///
+    /// This calls `update` with the data of each range returned by
+    /// [`Self::authenticode_ranges`], in order. `update` is typically the update
+    /// function of a hasher for the algorithm given by
+    /// [`AuthenticodeSignature::digest_algorithm`](super::AuthenticodeSignature::digest_algorithm).
+    pub fn authenticode_hash<F: FnMut(&'data [u8])>(&self, mut update: F) -> Result<()> {
+        for (offset, size) in self.authenticode_ranges()? {
+            let data = self
+                .data
+                .read_bytes_at(offset, size)
+                .read_error("Invalid PE authenticode range")?;
+            update(data);
+        }
+        Ok(())
+    }
+
+    /// Returns the pages that are hashed to compute an Authenticode page hash table.
+    ///
+    /// Page hashes are used by Windows to verify each page of an image as it is
+    /// loaded. The first page is the headers, with the same exclusions as
+    /// [`Self::authenticode_ranges`]. This is followed by the raw data of each
+    /// section in file order, split into 4096 byte pages. Each page is padded with
+    /// zeros to the page size before hashing.
+    ///
+    /// The page hash table is terminated by an entry that is not included here.
+    /// See [`Self::authenticode_page_hashes`].
+    pub fn authenticode_pages(&self) -> Result<Vec<AuthenticodePage>> {
+        let size_of_headers = u64::from(self.nt_headers.optional_header().size_of_headers());
+        let mut excluded = self.authenticode_header_exclusions();
+        excluded.retain(|&(offset, _)| offset < size_of_headers);
+        let ranges = authenticode_ranges_excluding(0, size_of_headers, &excluded)?;
+        let mut pages = vec![AuthenticodePage {
+            offset: 0,
+            ranges,
+            padding: AUTHENTICODE_PAGE_SIZE.saturating_sub(size_of_headers),
+        }];
+
+        let file_size = self.data.len().read_error("Invalid PE file size")?;
+        let mut sections: Vec<_> = self
+            .common
+            .sections
+            .iter()
+            .map(|section| {
+                (
+                    u64::from(section.pointer_to_raw_data.get(LE)),
+                    u64::from(section.size_of_raw_data.get(LE)),
+                )
+            })
+            .filter(|&(_, size)| size != 0)
+            .collect();
+        sections.sort_unstable();
+        for (offset, size) in sections {
+            offset
+                .checked_add(size)
+                .filter(|&end| end <= file_size)
+                .read_error("Invalid PE section raw data offset or size")?;
+            let mut page = 0;
+            while page < size {
+                let page_size = (size - page).min(AUTHENTICODE_PAGE_SIZE);
+                pages.push(AuthenticodePage {
+                    offset: offset + page,
+                    ranges: vec![(offset + page, page_size)],
+                    padding: AUTHENTICODE_PAGE_SIZE - page_size,
+                });
+                page += page_size;
+            }
+        }
+        Ok(pages)
+    }
+
+    /// Returns the Authenticode page hash table.
+    ///
+    /// `hash_page` is called for each page returned by [`Self::authenticode_pages`],
+    /// with the data for the page ranges and the number of padding zeros.
+    /// It must return the digest of the data followed by the padding.
+    ///
+    /// Each entry in the table is the 32-bit little endian file offset of the page,
+    /// followed by the page digest. The final entry is the offset of the end of
+    /// the section data, followed by a digest of zeros.
+    pub fn authenticode_page_hashes<F>(&self, mut hash_page: F) -> Result<Vec<u8>>
+    where
+        F: FnMut(&[&'data [u8]], u64) -> Vec<u8>,
+    {
+        let mut table = Vec::new();
+        let mut digest_size = 0;
+        let mut end = 0;
+        for page in self.authenticode_pages()? {
+            let data = page
+                .ranges
+                .iter()
+                .map(|&(offset, size)| {
+                    self.data
+                        .read_bytes_at(offset, size)
+                        .read_error("Invalid PE Authenticode page range")
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let digest = hash_page(&data, page.padding);
+            digest_size = digest.len();
+            let offset = u32::try_from(page.offset).map_err(|_| Error("Invalid PE page offset"))?;
+            table.extend_from_slice(&offset.to_le_bytes());
+            table.extend_from_slice(&digest);
+            end = page.offset + page.ranges.iter().map(|&(_, size)| size).sum::<u64>();
+        }
+        let end = u32::try_from(end).map_err(|_| Error("Invalid PE page offset"))?;
+        table.extend_from_slice(&end.to_le_bytes());
+        table.resize(table.len() + digest_size, 0);
+        Ok(table)
+    }
+
+    /// Returns the checksum and certificate table data directory entry.
+    fn authenticode_header_exclusions(&self) -> Vec<(u64, u64)> {
+        let optional_header = u64::from(self.dos_header.nt_headers_offset())
+            + mem::size_of::<U32<LE>>() as u64
+            + mem::size_of::<pe::ImageFileHeader>() as u64;
+        // The checksum is at the same offset for both PE32 and PE32+.
+        let mut excluded = vec![(optional_header + 64, 4)];
+        if self.data_directories.len() > pe::IMAGE_DIRECTORY_ENTRY_SECURITY {
+            let entry = optional_header
+                + mem::size_of::<Pe::ImageOptionalHeader>() as u64
+                + (pe::IMAGE_DIRECTORY_ENTRY_SECURITY * mem::size_of::<pe::ImageDataDirectory>())
+                    as u64;
+            excluded.push((entry, mem::size_of::<pe::ImageDataDirectory>() as u64));
+        }
+        excluded
+    }
+
+    /// Parse an import thunk, using `names` for the hint/name lookup.
+    ///
+    /// The hint/name table is usually in the same section as the import descriptors,
+    /// but it may be elsewhere. If so, the section containing it is resolved and
+    /// stored in `names` for use by later thunks.
+    fn import(
+        &self,
+        names: &mut HintNameSection<'data>,
+        thunk: Pe::ImageThunkData,
+    ) -> Result<super::Import<'data>> {
+        if thunk.is_ordinal() {
+            return Ok(super::Import::Ordinal(thunk.ordinal()));
+        }
+        let address = thunk.address();
+        if !names.contains(address) {
+            if let Some((data, section_address)) =
+                self.common.sections.pe_data_containing(self.data, address)
+            {
+                *names = HintNameSection::new(data, section_address);
+            }
+        }
+        let (hint, name) = names.hint_name(address)?;
+        Ok(super::Import::Name(hint, name))
+    }
+
     /// Returns the export table of this file.
     ///
     /// The export table is located using the data directory.
@@ -115,6 +337,56 @@ where
             .import_table(self.data, &self.common.sections)
     }
 
+    /// Returns the delay-load import table of this file.
+    ///
+    /// The delay-load import table is located using the data directory.
+    pub fn delay_load_import_table(&self) -> Result<Option<DelayLoadImportTable<'data>>> {
+        self.data_directories
+            .delay_load_import_table(self.data, &self.common.sections)
+    }
+
+    /// Returns an iterator over the imports in the import table and the
+    /// delay-load import table.
+    ///
+    /// This yields the same imports as [`Object::imports`], without collecting
+    /// them into a `Vec`.
+    pub fn import_iter(&self) -> Result<PeImportIterator<'data, '_, Pe, R>> {
+        let import_table = self.import_table()?;
+        let delay_load_import_table = self.delay_load_import_table()?;
+        let names = match (&import_table, &delay_load_import_table) {
+            (Some(table), _) => table.hint_name_section(),
+            (None, Some(table)) => table.hint_name_section(),
+            (None, None) => HintNameSection::new(&[], 0),
+        };
+        Ok(PeImportIterator {
+            file: self,
+            import_table: match import_table {
+                Some(table) => Some((table.descriptors()?, table)),
+                None => None,
+            },
+            delay_load_import_table: match delay_load_import_table {
+                Some(table) => Some((table.descriptors()?, table)),
+                None => None,
+            },
+            names,
+            library: &[],
+            kind: ImportKind::Eager,
+            thunks: None,
+        })
+    }
+
+    /// Returns an iterator over the named exports in the export table.
+    ///
+    /// This yields the same exports as [`Object::exports`], without collecting
+    /// them into a `Vec`.
+    pub fn export_iter(&self) -> Result<PeExportIterator<'data>> {
+        Ok(PeExportIterator {
+            image_base: self.common.image_base,
+            table: self.export_table()?,
+            index: 0,
+        })
+    }
+
     pub(super) fn section_alignment(&self) -> u64 {
         u64::from(self.nt_headers.optional_header().section_alignment())
     }
@@ -142,7 +414,7 @@ where
     type Symbol = CoffSymbol<'data, 'file, R>;
     type SymbolIterator = CoffSymbolIterator<'data, 'file, R>;
     type SymbolTable = CoffSymbolTable<'data, 'file, R>;
-    type DynamicRelocationIterator = NoDynamicRelocationIterator;
+    type DynamicRelocationIterator = PeDynamicRelocationIterator<'data>;
 
     fn architecture(&self) -> Architecture {
         match self.nt_headers.file_header().machine.get(LE) {
@@ -240,6 +512,11 @@ where
         Some(CoffSymbolTable { file: &self.common })
     }
 
+    fn symbol_by_name_bytes(&'file self, symbol_name: &[u8]) -> Option<Self::Symbol> {
+        let index = self.symbol_names.get(&self.symbol_table()?, symbol_name)?;
+        self.symbol_by_index(index).ok()
+    }
+
     fn dynamic_symbols(&'file self) -> CoffSymbolIterator<'data, 'file, R> {
         CoffSymbolIterator {
             file: &self.common,
@@ -252,50 +529,50 @@ where
         None
     }
 
-    fn dynamic_relocations(&'file self) -> Option<NoDynamicRelocationIterator> {
-        None
+    fn dynamic_relocations(&'file self) -> Option<PeDynamicRelocationIterator<'data>> {
+        let blocks = self
+            .data_directories
+            .relocation_blocks(self.data, &self.common.sections)
+            .ok()??;
+        Some(PeDynamicRelocationIterator {
+            image_base: self.common.image_base,
+            blocks,
+            relocations: None,
+        })
     }
 
     fn imports(&self) -> Result<Vec<Import<'data>>> {
-        let mut imports = Vec::new();
+        self.import_iter()?.collect()
+    }
+
+    fn exports(&self) -> Result<Vec<Export<'data>>> {
+        let iter = self.export_iter()?;
+        let mut exports = Vec::with_capacity(iter.size_hint().1.unwrap_or(0));
+        for export in iter {
+            exports.push(export?);
+        }
+        Ok(exports)
+    }
+
+    fn needed_libraries(&self) -> Result<Vec<&'data [u8]>> {
+        let mut libraries = Vec::new();
         if let Some(import_table) = self.import_table()? {
             let mut import_descs = import_table.descriptors()?;
             while let Some(import_desc) = import_descs.next()? {
-                let library = import_table.name(import_desc.name.get(LE))?;
-                let mut first_thunk = import_desc.original_first_thunk.get(LE);
-                if first_thunk == 0 {
-                    first_thunk = import_desc.first_thunk.get(LE);
-                }
-                let mut thunks = import_table.thunks(first_thunk)?;
-                while let Some(thunk) = thunks.next::<Pe>()? {
-                    if !thunk.is_ordinal() {
-                        let (_hint, name) = import_table.hint_name(thunk.address())?;
-                        imports.push(Import {
-                            library: ByteString(library),
-                            name: ByteString(name),
-                        });
-                    }
-                }
+                libraries.push(import_table.name(import_desc.name.get(LE))?);
             }
         }
-        Ok(imports)
-    }
-
-    fn exports(&self) -> Result<Vec<Export<'data>>> {
-        let mut exports = Vec::new();
-        if let Some(export_table) = self.export_table()? {
-            for (name_pointer, address_index) in export_table.name_iter() {
-                let name = export_table.name_from_pointer(name_pointer)?;
-                let address = export_table.address_by_index(address_index.into())?;
-                if !export_table.is_forward(address) {
-                    exports.push(Export {
-                        name: ByteString(name),
-                        address: self.common.image_base.wrapping_add(address.into()),
-                    })
-                }
+        if let Some(import_table) = self.delay_load_import_table()? {
+            let mut import_descs = import_table.descriptors()?;
+            while let Some(import_desc) = import_descs.next()? {
+                libraries.push(import_table.name(import_desc.dll_name_rva.get(LE))?);
             }
         }
-        Ok(exports)
+        Ok(libraries)
+    }
+
+    fn code_signature(&self) -> Result<Option<CodeSignature<'data>>> {
+        Ok(self.certificate_table()?.map(CodeSignature::Pe))
     }
 
     fn pdb_info(&self) -> Result<Option<CodeView<'_>>> {
@@ -364,13 +641,122 @@ where
         self.section_by_name(".debug_info").is_some()
     }
 
+    fn function_ranges(&'file self) -> Result<Vec<FunctionRange>> {
+        let mut ranges = Vec::new();
+        if let Some(data_dir) = self.data_directory(pe::IMAGE_DIRECTORY_ENTRY_EXCEPTION) {
+            let data = Bytes(data_dir.data(self.data, &self.common.sections)?);
+            let image_base = self.common.image_base;
+            match self.nt_headers.file_header().machine.get(LE) {
+                pe::IMAGE_FILE_MACHINE_AMD64 => {
+                    for entry in ExceptionTable::parse(data.0)?.entries() {
+                        let begin = entry.begin_address.get(LE);
+                        let end = entry.end_address.get(LE);
+                        ranges.push(FunctionRange::new(
+                            image_base.wrapping_add(begin.into()),
+                            end.wrapping_sub(begin).into(),
+                        ));
+                    }
+                }
+                machine @ (pe::IMAGE_FILE_MACHINE_ARM64 | pe::IMAGE_FILE_MACHINE_ARMNT) => {
+                    // The function length is in units of 4 bytes for AArch64,
+                    // and 2 bytes for Thumb-2.
+                    let unit = if machine == pe::IMAGE_FILE_MACHINE_ARM64 {
+                        4
+                    } else {
+                        2
+                    };
+                    let count = data.len() / mem::size_of::<pe::ImageArm64RuntimeFunctionEntry>();
+                    let entries = data
+                        .read_slice_at::<pe::ImageArm64RuntimeFunctionEntry>(0, count)
+                        .read_error("Invalid PE exception directory size")?;
+                    for entry in entries {
+                        let begin = entry.begin_address.get(LE) & !1;
+                        let unwind_data = entry.unwind_data.get(LE);
+                        let length = if unwind_data & 3 != 0 {
+                            // Packed unwind data.
+                            (unwind_data >> 2) & 0x7ff
+                        } else {
+                            // The first word of the `.xdata` record.
+                            match self
+                                .common
+                                .sections
+                                .pe_data_at(self.data, unwind_data)
+                                .and_then(|data| Bytes(data).read::<U32Bytes<LE>>().ok())
+                            {
+                                Some(header) => header.get(LE) & 0x3_ffff,
+                                None => 0,
+                            }
+                        };
+                        ranges.push(FunctionRange::new(
+                            image_base.wrapping_add(begin.into()),
+                            u64::from(length) * unit,
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(read::function_ranges(self, ranges))
+    }
+
     fn relative_address_base(&self) -> u64 {
         self.common.image_base
     }
 
+    fn address_to_file_offset(&self, address: u64) -> Option<u64> {
+        let va = u32::try_from(address.checked_sub(self.common.image_base)?).ok()?;
+        let size_of_headers = self.nt_headers.optional_header().size_of_headers();
+        if va < size_of_headers {
+            return Some(va.into());
+        }
+        let (offset, _) = self.common.sections.pe_file_range_at(va)?;
+        Some(offset.into())
+    }
+
+    fn file_offset_to_address(&self, offset: u64) -> Option<u64> {
+        let size_of_headers = self.nt_headers.optional_header().size_of_headers();
+        if offset < size_of_headers.into() {
+            return self.common.image_base.checked_add(offset);
+        }
+        for section in self.common.sections.iter() {
+            let (file_offset, file_size) = section.pe_file_range();
+            if let Some(offset) = offset.checked_sub(file_offset.into()) {
+                if offset < file_size.into() {
+                    let va = u64::from(section.virtual_address.get(LE)) + offset;
+                    return self.common.image_base.checked_add(va);
+                }
+            }
+        }
+        None
+    }
+
     fn entry(&self) -> u64 {
-        u64::from(self.nt_headers.optional_header().address_of_entry_point())
-            .wrapping_add(self.common.image_base)
+        let address = self.nt_headers.optional_header().address_of_entry_point();
+        if address == 0 {
+            // DLLs are not required to have an entry point.
+            return 0;
+        }
+        u64::from(address).wrapping_add(self.common.image_base)
+    }
+
+    fn is_position_independent(&self) -> bool {
+        self.nt_headers.optional_header().dll_characteristics()
+            & pe::IMAGE_DLLCHARACTERISTICS_DYNAMIC_BASE
+            != 0
+    }
+
+    fn security_features(&'file self) -> SecurityFeatures {
+        let dll_characteristics = self.nt_headers.optional_header().dll_characteristics();
+        SecurityFeatures {
+            nx: Some(dll_characteristics & pe::IMAGE_DLLCHARACTERISTICS_NX_COMPAT != 0),
+            position_independent: Some(self.is_position_independent()),
+            control_flow_guard: Some(
+                dll_characteristics & pe::IMAGE_DLLCHARACTERISTICS_GUARD_CF != 0,
+            ),
+            stack_canary: security::stack_canary(self),
+            signed: Some(matches!(self.code_signature(), Ok(Some(_)))),
+            ..Default::default()
+        }
     }
 
     fn flags(&self) -> FileFlags {
@@ -380,6 +766,233 @@ where
     }
 }
 
+/// The page size used for Authenticode page hashes.
+const AUTHENTICODE_PAGE_SIZE: u64 = 0x1000;
+
+/// Return the ranges in `start..end` that are not in `excluded`.
+///
+/// `excluded` must be sorted and non-overlapping.
+fn authenticode_ranges_excluding(
+    start: u64,
+    end: u64,
+    excluded: &[(u64, u64)],
+) -> Result<Vec<(u64, u64)>> {
+    let mut ranges = Vec::new();
+    let mut offset = start;
+    for &(excluded_start, excluded_size) in excluded {
+        let excluded_end = excluded_start
+            .checked_add(excluded_size)
+            .filter(|&excluded_end| excluded_start >= offset && excluded_end <= end)
+            .read_error("Invalid PE Authenticode range")?;
+        if excluded_start > offset {
+            ranges.push((offset, excluded_start - offset));
+        }
+        offset = excluded_end;
+    }
+    if end > offset {
+        ranges.push((offset, end - offset));
+    }
+    Ok(ranges)
+}
+
+fn pe_import<'data>(
+    library: &'data [u8],
+    import: super::Import<'data>,
+    kind: ImportKind,
+) -> Import<'data> {
+    let (name, ordinal) = match import {
+        super::Import::Ordinal(ordinal) => (&[][..], Some(ordinal)),
+        super::Import::Name(_hint, name) => (name, None),
+    };
+    Import {
+        library: ByteString(library),
+        name: ByteString(name),
+        ordinal,
+        version: None,
+        kind,
+    }
+}
+
+/// An iterator over the imports of a [`PeFile`].
+///
+/// Returned by [`PeFile::import_iter`].
+#[derive(Debug)]
+pub struct PeImportIterator<'data, 'file, Pe, R = &'data [u8]>
+where
+    Pe: ImageNtHeaders,
+    R: ReadRef<'data>,
+{
+    file: &'file PeFile<'data, Pe, R>,
+    import_table: Option<(ImportDescriptorIterator<'data>, ImportTable<'data>)>,
+    delay_load_import_table: Option<(
+        DelayLoadDescriptorIterator<'data>,
+        DelayLoadImportTable<'data>,
+    )>,
+    names: HintNameSection<'data>,
+    library: &'data [u8],
+    kind: ImportKind,
+    thunks: Option<ImportThunkList<'data>>,
+}
+
+impl<'data, 'file, Pe, R> PeImportIterator<'data, 'file, Pe, R>
+where
+    Pe: ImageNtHeaders,
+    R: ReadRef<'data>,
+{
+    fn next_import(&mut self) -> Result<Option<Import<'data>>> {
+        loop {
+            if let Some(thunks) = &mut self.thunks {
+                if let Some(thunk) = thunks.next::<Pe>()? {
+                    let import = self.file.import(&mut self.names, thunk)?;
+                    return Ok(Some(pe_import(self.library, import, self.kind)));
+                }
+                self.thunks = None;
+            }
+            if let Some((import_descs, import_table)) = &mut self.import_table {
+                if let Some(import_desc) = import_descs.next()? {
+                    self.library = import_table.name(import_desc.name.get(LE))?;
+                    let mut first_thunk = import_desc.original_first_thunk.get(LE);
+                    if first_thunk == 0 {
+                        first_thunk = import_desc.first_thunk.get(LE);
+                    }
+                    self.thunks = Some(import_table.thunks(first_thunk)?);
+                    self.kind = ImportKind::Eager;
+                    continue;
+                }
+                self.import_table = None;
+                if let Some((_, import_table)) = &self.delay_load_import_table {
+                    self.names = import_table.hint_name_section();
+                }
+            }
+            if let Some((import_descs, import_table)) = &mut self.delay_load_import_table {
+                if let Some(import_desc) = import_descs.next()? {
+                    self.library = import_table.name(import_desc.dll_name_rva.get(LE))?;
+                    self.thunks =
+                        Some(import_table.thunks(import_desc.import_name_table_rva.get(LE))?);
+                    self.kind = ImportKind::Delay;
+                    continue;
+                }
+                self.delay_load_import_table = None;
+            }
+            return Ok(None);
+        }
+    }
+}
+
+impl<'data, 'file, Pe, R> Iterator for PeImportIterator<'data, 'file, Pe, R>
+where
+    Pe: ImageNtHeaders,
+    R: ReadRef<'data>,
+{
+    type Item = Result<Import<'data>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_import() {
+            Ok(import) => import.map(Ok),
+            Err(error) => {
+                self.thunks = None;
+                self.import_table = None;
+                self.delay_load_import_table = None;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+/// An iterator over the named exports of a [`PeFile`].
+///
+/// Returned by [`PeFile::export_iter`].
+///
+/// Exports with a malformed forwarder string are skipped.
+#[derive(Debug)]
+pub struct PeExportIterator<'data> {
+    image_base: u64,
+    table: Option<ExportTable<'data>>,
+    index: usize,
+}
+
+impl<'data> PeExportIterator<'data> {
+    fn len(&self) -> usize {
+        self.table.as_ref().map_or(0, |table| {
+            table.name_pointers().len().min(table.name_ordinals().len())
+        })
+    }
+
+    fn next_export(&mut self) -> Result<Option<Export<'data>>> {
+        let export_table = match &self.table {
+            Some(table) => table,
+            None => return Ok(None),
+        };
+        loop {
+            let index = self.index;
+            let (name_pointer, address_index) = match (
+                export_table.name_pointers().get(index),
+                export_table.name_ordinals().get(index),
+            ) {
+                (Some(name_pointer), Some(address_index)) => {
+                    (name_pointer.get(LE), address_index.get(LE))
+                }
+                _ => return Ok(None),
+            };
+            self.index += 1;
+            let name = export_table.name_from_pointer(name_pointer)?;
+            let address = export_table.address_by_index(address_index.into())?;
+            // Skip exports with a malformed forwarder string, since they can't
+            // be represented, but the other exports may still be useful.
+            let target = match export_table.target_from_address(address) {
+                Ok(target) => target,
+                Err(_) => continue,
+            };
+            let (address, forward) = match target {
+                ExportTarget::Address(address) => {
+                    (self.image_base.wrapping_add(address.into()), None)
+                }
+                ExportTarget::ForwardByName(library, name) => (
+                    0,
+                    Some(ExportForward {
+                        library: ByteString(library),
+                        name: ByteString(name),
+                        ordinal: None,
+                    }),
+                ),
+                ExportTarget::ForwardByOrdinal(library, ordinal) => (
+                    0,
+                    Some(ExportForward {
+                        library: ByteString(library),
+                        name: ByteString(&[]),
+                        ordinal: Some(ordinal),
+                    }),
+                ),
+            };
+            return Ok(Some(Export {
+                name: ByteString(name),
+                address,
+                forward,
+            }));
+        }
+    }
+}
+
+impl<'data> Iterator for PeExportIterator<'data> {
+    type Item = Result<Export<'data>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_export() {
+            Ok(export) => export.map(Ok),
+            Err(error) => {
+                self.table = None;
+                Some(Err(error))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Exports with a malformed forwarder are skipped, so the number of
+        // remaining names is only an upper bound.
+        (0, Some(self.len().saturating_sub(self.index)))
+    }
+}
+
 /// An iterator over the COMDAT section groups of a `PeFile32`.
 pub type PeComdatIterator32<'data, 'file, R = &'data [u8]> =
     PeComdatIterator<'data, 'file, pe::ImageNtHeaders32, R>;
@@ -540,11 +1153,25 @@ pub fn optional_header_magic<'data, R: ReadRef<'data>>(data: R) -> Result<u16> {
     Ok(nt_headers.optional_header().magic())
 }
 
+/// Read the signature of the header that follows the DOS header.
+///
+/// This is used to distinguish PE, NE and LX files. For PE files, this is the low
+/// 16 bits of `IMAGE_NT_SIGNATURE`.
+pub fn new_header_signature<'data, R: ReadRef<'data>>(data: R) -> Result<u16> {
+    let dos_header = pe::ImageDosHeader::parse(data)?;
+    let offset = dos_header.nt_headers_offset().into();
+    let signature = data
+        .read_at::<U16<LE>>(offset)
+        .read_error("Invalid new header offset or size")?;
+    Ok(signature.get(LE))
+}
+
 /// A trait for generic access to `ImageNtHeaders32` and `ImageNtHeaders64`.
 #[allow(missing_docs)]
 pub trait ImageNtHeaders: Debug + Pod {
     type ImageOptionalHeader: ImageOptionalHeader;
     type ImageThunkData: ImageThunkData;
+    type ImageLoadConfigDirectory: ImageLoadConfigDirectory;
 
     /// Return true if this type is a 64-bit header.
     ///
@@ -667,6 +1294,7 @@ pub trait ImageOptionalHeader: Debug + Pod {
 impl ImageNtHeaders for pe::ImageNtHeaders32 {
     type ImageOptionalHeader = pe::ImageOptionalHeader32;
     type ImageThunkData = pe::ImageThunkData32;
+    type ImageLoadConfigDirectory = pe::ImageLoadConfigDirectory32;
 
     #[inline]
     fn is_type_64(&self) -> bool {
@@ -849,6 +1477,7 @@ impl ImageOptionalHeader for pe::ImageOptionalHeader32 {
 impl ImageNtHeaders for pe::ImageNtHeaders64 {
     type ImageOptionalHeader = pe::ImageOptionalHeader64;
     type ImageThunkData = pe::ImageThunkData64;
+    type ImageLoadConfigDirectory = pe::ImageLoadConfigDirectory64;
 
     #[inline]
     fn is_type_64(&self) -> bool {
@@ -78,8 +78,46 @@ impl<'data> ImportTable<'data> {
     ///
     /// The hint is an index into the export name pointer table in the target library.
     pub fn hint_name(&self, address: u32) -> Result<(u16, &'data [u8])> {
-        let offset = address.wrapping_sub(self.section_address);
-        let mut data = self.section_data;
+        self.hint_name_section().hint_name(address)
+    }
+
+    /// Return the section data that is used for hint/name lookups.
+    pub(super) fn hint_name_section(&self) -> HintNameSection<'data> {
+        HintNameSection {
+            data: self.section_data,
+            address: self.section_address,
+        }
+    }
+}
+
+/// The data of a section containing import hint/name entries.
+///
+/// This allows the section for the hint/name table to be resolved once and reused
+/// for each import.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct HintNameSection<'data> {
+    data: Bytes<'data>,
+    address: u32,
+}
+
+impl<'data> HintNameSection<'data> {
+    /// `data` should be from the section at `address`.
+    pub(super) fn new(data: &'data [u8], address: u32) -> Self {
+        HintNameSection {
+            data: Bytes(data),
+            address,
+        }
+    }
+
+    /// Return true if the given address is within the section data.
+    pub(super) fn contains(&self, address: u32) -> bool {
+        (address.wrapping_sub(self.address) as usize) < self.data.len()
+    }
+
+    /// Return the hint and name at the given address.
+    pub(super) fn hint_name(&self, address: u32) -> Result<(u16, &'data [u8])> {
+        let offset = address.wrapping_sub(self.address);
+        let mut data = self.data;
         data.skip(offset as usize)
             .read_error("Invalid PE import thunk address")?;
         let hint = data
@@ -306,6 +344,14 @@ impl<'data> DelayLoadImportTable<'data> {
             .read_error("Missing PE delay load import thunk name")?;
         Ok((hint, name))
     }
+
+    /// Return the section data that is used for hint/name lookups.
+    pub(super) fn hint_name_section(&self) -> HintNameSection<'data> {
+        HintNameSection {
+            data: self.section_data,
+            address: self.section_address,
+        }
+    }
 }
 
 /// A fallible iterator for the descriptors in the delay-load data directory.
@@ -0,0 +1,418 @@
+use core::convert::{TryFrom, TryInto};
+use core::fmt::Debug;
+use core::mem;
+
+use crate::endian::{LittleEndian as LE, U32Bytes};
+use crate::pe;
+use crate::pod::{self, Pod};
+use crate::read::{Bytes, Error, ReadError, ReadRef, Result};
+
+use super::SectionTable;
+
+/// The load configuration directory of a PE file.
+///
+/// The directory starts with its size, and newer linkers append fields to it.
+/// This holds a copy of the directory in which the fields that are beyond the
+/// size of the directory are zero.
+#[derive(Debug, Clone, Copy)]
+pub struct LoadConfigDirectory<C: ImageLoadConfigDirectory> {
+    directory: C,
+}
+
+impl<C: ImageLoadConfigDirectory> LoadConfigDirectory<C> {
+    /// Parse the load configuration directory.
+    ///
+    /// `data` must start at the directory. The `size` field of the directory
+    /// determines its size, and the size in the data directory entry is ignored.
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let size = data
+            .read_at::<U32Bytes<LE>>(0)
+            .read_error("Invalid PE load config size")?
+            .get(LE);
+        let size = size as usize;
+        if size < mem::size_of::<U32Bytes<LE>>() {
+            return Err(Error("Invalid PE load config size"));
+        }
+        let data = data.get(..size).read_error("Invalid PE load config size")?;
+        let mut directory = pod::zeroed::<C>();
+        let len = size.min(mem::size_of::<C>());
+        pod::bytes_of_mut(&mut directory)[..len].copy_from_slice(&data[..len]);
+        Ok(LoadConfigDirectory { directory })
+    }
+
+    /// Return the directory.
+    ///
+    /// Fields that are beyond the size of the directory are zero.
+    pub fn directory(&self) -> &C {
+        &self.directory
+    }
+
+    /// Return the size of the directory in the file.
+    pub fn size(&self) -> u32 {
+        self.directory.size()
+    }
+
+    /// Return the Control Flow Guard flags.
+    ///
+    /// This is a combination of the `IMAGE_GUARD_*` flags.
+    pub fn guard_flags(&self) -> u32 {
+        self.directory.guard_flags()
+    }
+
+    /// Return the relative addresses of the safe exception handlers.
+    ///
+    /// This table is only used for 32-bit images. It is empty if it is not present.
+    ///
+    /// `data` must be the entire file data.
+    /// `image_base` is used to convert the virtual address of the table to a relative address.
+    pub fn sehandler_table<'data, R: ReadRef<'data>>(
+        &self,
+        data: R,
+        sections: &SectionTable<'data>,
+        image_base: u64,
+    ) -> Result<&'data [U32Bytes<LE>]> {
+        let table = self.table_data(
+            data,
+            sections,
+            image_base,
+            self.directory.sehandler_table(),
+            self.directory.sehandler_count(),
+            mem::size_of::<U32Bytes<LE>>(),
+        )?;
+        table
+            .read_slice_at(0, table.len() / mem::size_of::<U32Bytes<LE>>())
+            .read_error("Invalid PE SEH handler table")
+    }
+
+    /// Return the Control Flow Guard table of valid indirect call targets.
+    ///
+    /// `data` must be the entire file data.
+    /// `image_base` is used to convert the virtual address of the table to a relative address.
+    pub fn guard_cf_function_table<'data, R: ReadRef<'data>>(
+        &self,
+        data: R,
+        sections: &SectionTable<'data>,
+        image_base: u64,
+    ) -> Result<GuardFunctionIterator<'data>> {
+        self.guard_table(
+            data,
+            sections,
+            image_base,
+            self.directory.guard_cf_function_table(),
+            self.directory.guard_cf_function_count(),
+        )
+    }
+
+    /// Return the Control Flow Guard table of import address table entries
+    /// whose addresses are taken.
+    ///
+    /// `data` must be the entire file data.
+    /// `image_base` is used to convert the virtual address of the table to a relative address.
+    pub fn guard_address_taken_iat_entry_table<'data, R: ReadRef<'data>>(
+        &self,
+        data: R,
+        sections: &SectionTable<'data>,
+        image_base: u64,
+    ) -> Result<GuardFunctionIterator<'data>> {
+        self.guard_table(
+            data,
+            sections,
+            image_base,
+            self.directory.guard_address_taken_iat_entry_table(),
+            self.directory.guard_address_taken_iat_entry_count(),
+        )
+    }
+
+    /// Return the Control Flow Guard table of valid `longjmp` targets.
+    ///
+    /// `data` must be the entire file data.
+    /// `image_base` is used to convert the virtual address of the table to a relative address.
+    pub fn guard_long_jump_target_table<'data, R: ReadRef<'data>>(
+        &self,
+        data: R,
+        sections: &SectionTable<'data>,
+        image_base: u64,
+    ) -> Result<GuardFunctionIterator<'data>> {
+        self.guard_table(
+            data,
+            sections,
+            image_base,
+            self.directory.guard_long_jump_target_table(),
+            self.directory.guard_long_jump_target_count(),
+        )
+    }
+
+    /// Return the table of valid exception handling continuation targets.
+    ///
+    /// This table is used with CET shadow stacks, and is present if the guard
+    /// flags include `IMAGE_GUARD_EH_CONTINUATION_TABLE_PRESENT`.
+    ///
+    /// `data` must be the entire file data.
+    /// `image_base` is used to convert the virtual address of the table to a relative address.
+    pub fn guard_eh_continuation_table<'data, R: ReadRef<'data>>(
+        &self,
+        data: R,
+        sections: &SectionTable<'data>,
+        image_base: u64,
+    ) -> Result<GuardFunctionIterator<'data>> {
+        self.guard_table(
+            data,
+            sections,
+            image_base,
+            self.directory.guard_eh_continuation_table(),
+            self.directory.guard_eh_continuation_count(),
+        )
+    }
+
+    fn guard_table<'data, R: ReadRef<'data>>(
+        &self,
+        data: R,
+        sections: &SectionTable<'data>,
+        image_base: u64,
+        address: u64,
+        count: u64,
+    ) -> Result<GuardFunctionIterator<'data>> {
+        // Each entry is a relative address followed by a number of bytes of flags.
+        let flags_size = (self.guard_flags() & pe::IMAGE_GUARD_CF_FUNCTION_TABLE_SIZE_MASK)
+            >> pe::IMAGE_GUARD_CF_FUNCTION_TABLE_SIZE_SHIFT;
+        let entry_size = mem::size_of::<U32Bytes<LE>>() + flags_size as usize;
+        let data = self.table_data(data, sections, image_base, address, count, entry_size)?;
+        Ok(GuardFunctionIterator {
+            data: Bytes(data),
+            entry_size,
+        })
+    }
+
+    fn table_data<'data, R: ReadRef<'data>>(
+        &self,
+        data: R,
+        sections: &SectionTable<'data>,
+        image_base: u64,
+        address: u64,
+        count: u64,
+        entry_size: usize,
+    ) -> Result<&'data [u8]> {
+        if address == 0 || count == 0 {
+            return Ok(&[]);
+        }
+        let address = address
+            .checked_sub(image_base)
+            .and_then(|address| u32::try_from(address).ok())
+            .read_error("Invalid PE load config table address")?;
+        let size = count
+            .checked_mul(entry_size as u64)
+            .and_then(|size| usize::try_from(size).ok())
+            .read_error("Invalid PE load config table count")?;
+        sections
+            .pe_data_at(data, address)
+            .read_error("Invalid PE load config table address")?
+            .get(..size)
+            .read_error("Invalid PE load config table count")
+    }
+}
+
+/// An iterator over the entries of a Control Flow Guard table.
+///
+/// Returned by [`LoadConfigDirectory::guard_cf_function_table`] and similar functions.
+#[derive(Debug, Clone)]
+pub struct GuardFunctionIterator<'data> {
+    data: Bytes<'data>,
+    entry_size: usize,
+}
+
+impl<'data> Iterator for GuardFunctionIterator<'data> {
+    type Item = GuardFunction;
+
+    fn next(&mut self) -> Option<GuardFunction> {
+        // The size of the table was checked when the iterator was created.
+        let entry = self.data.read_bytes(self.entry_size).ok()?;
+        let address = entry.0[..4].try_into().ok().map(u32::from_le_bytes)?;
+        let flags = entry.0.get(4).copied().map_or(0, u16::from);
+        Some(GuardFunction { address, flags })
+    }
+}
+
+/// An entry in a Control Flow Guard table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuardFunction {
+    /// The relative address of the target.
+    pub address: u32,
+    /// A combination of the `IMAGE_GUARD_FLAG_*` flags.
+    ///
+    /// This is zero if the table does not have flags.
+    pub flags: u16,
+}
+
+/// A trait for generic access to [`pe::ImageLoadConfigDirectory32`] and [`pe::ImageLoadConfigDirectory64`].
+#[allow(missing_docs)]
+pub trait ImageLoadConfigDirectory: Debug + Pod {
+    fn size(&self) -> u32;
+    fn guard_flags(&self) -> u32;
+    fn security_cookie(&self) -> u64;
+    fn sehandler_table(&self) -> u64;
+    fn sehandler_count(&self) -> u64;
+    fn guard_cf_check_function_pointer(&self) -> u64;
+    fn guard_cf_dispatch_function_pointer(&self) -> u64;
+    fn guard_cf_function_table(&self) -> u64;
+    fn guard_cf_function_count(&self) -> u64;
+    fn guard_address_taken_iat_entry_table(&self) -> u64;
+    fn guard_address_taken_iat_entry_count(&self) -> u64;
+    fn guard_long_jump_target_table(&self) -> u64;
+    fn guard_long_jump_target_count(&self) -> u64;
+    fn guard_eh_continuation_table(&self) -> u64;
+    fn guard_eh_continuation_count(&self) -> u64;
+}
+
+impl ImageLoadConfigDirectory for pe::ImageLoadConfigDirectory32 {
+    #[inline]
+    fn size(&self) -> u32 {
+        self.size.get(LE)
+    }
+
+    #[inline]
+    fn guard_flags(&self) -> u32 {
+        self.guard_flags.get(LE)
+    }
+
+    #[inline]
+    fn security_cookie(&self) -> u64 {
+        self.security_cookie.get(LE).into()
+    }
+
+    #[inline]
+    fn sehandler_table(&self) -> u64 {
+        self.sehandler_table.get(LE).into()
+    }
+
+    #[inline]
+    fn sehandler_count(&self) -> u64 {
+        self.sehandler_count.get(LE).into()
+    }
+
+    #[inline]
+    fn guard_cf_check_function_pointer(&self) -> u64 {
+        self.guard_cf_check_function_pointer.get(LE).into()
+    }
+
+    #[inline]
+    fn guard_cf_dispatch_function_pointer(&self) -> u64 {
+        self.guard_cf_dispatch_function_pointer.get(LE).into()
+    }
+
+    #[inline]
+    fn guard_cf_function_table(&self) -> u64 {
+        self.guard_cf_function_table.get(LE).into()
+    }
+
+    #[inline]
+    fn guard_cf_function_count(&self) -> u64 {
+        self.guard_cf_function_count.get(LE).into()
+    }
+
+    #[inline]
+    fn guard_address_taken_iat_entry_table(&self) -> u64 {
+        self.guard_address_taken_iat_entry_table.get(LE).into()
+    }
+
+    #[inline]
+    fn guard_address_taken_iat_entry_count(&self) -> u64 {
+        self.guard_address_taken_iat_entry_count.get(LE).into()
+    }
+
+    #[inline]
+    fn guard_long_jump_target_table(&self) -> u64 {
+        self.guard_long_jump_target_table.get(LE).into()
+    }
+
+    #[inline]
+    fn guard_long_jump_target_count(&self) -> u64 {
+        self.guard_long_jump_target_count.get(LE).into()
+    }
+
+    #[inline]
+    fn guard_eh_continuation_table(&self) -> u64 {
+        self.guard_eh_continuation_table.get(LE).into()
+    }
+
+    #[inline]
+    fn guard_eh_continuation_count(&self) -> u64 {
+        self.guard_eh_continuation_count.get(LE).into()
+    }
+}
+
+impl ImageLoadConfigDirectory for pe::ImageLoadConfigDirectory64 {
+    #[inline]
+    fn size(&self) -> u32 {
+        self.size.get(LE)
+    }
+
+    #[inline]
+    fn guard_flags(&self) -> u32 {
+        self.guard_flags.get(LE)
+    }
+
+    #[inline]
+    fn security_cookie(&self) -> u64 {
+        self.security_cookie.get(LE)
+    }
+
+    #[inline]
+    fn sehandler_table(&self) -> u64 {
+        self.sehandler_table.get(LE)
+    }
+
+    #[inline]
+    fn sehandler_count(&self) -> u64 {
+        self.sehandler_count.get(LE)
+    }
+
+    #[inline]
+    fn guard_cf_check_function_pointer(&self) -> u64 {
+        self.guard_cf_check_function_pointer.get(LE)
+    }
+
+    #[inline]
+    fn guard_cf_dispatch_function_pointer(&self) -> u64 {
+        self.guard_cf_dispatch_function_pointer.get(LE)
+    }
+
+    #[inline]
+    fn guard_cf_function_table(&self) -> u64 {
+        self.guard_cf_function_table.get(LE)
+    }
+
+    #[inline]
+    fn guard_cf_function_count(&self) -> u64 {
+        self.guard_cf_function_count.get(LE)
+    }
+
+    #[inline]
+    fn guard_address_taken_iat_entry_table(&self) -> u64 {
+        self.guard_address_taken_iat_entry_table.get(LE)
+    }
+
+    #[inline]
+    fn guard_address_taken_iat_entry_count(&self) -> u64 {
+        self.guard_address_taken_iat_entry_count.get(LE)
+    }
+
+    #[inline]
+    fn guard_long_jump_target_table(&self) -> u64 {
+        self.guard_long_jump_target_table.get(LE)
+    }
+
+    #[inline]
+    fn guard_long_jump_target_count(&self) -> u64 {
+        self.guard_long_jump_target_count.get(LE)
+    }
+
+    #[inline]
+    fn guard_eh_continuation_table(&self) -> u64 {
+        self.guard_eh_continuation_table.get(LE)
+    }
+
+    #[inline]
+    fn guard_eh_continuation_count(&self) -> u64 {
+        self.guard_eh_continuation_count.get(LE)
+    }
+}
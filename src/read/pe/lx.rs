@@ -0,0 +1,327 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::endian::LittleEndian as LE;
+use crate::pe;
+use crate::read::{ByteString, Bytes, Error, ReadError, ReadRef, Result};
+
+use super::ne::{exported_names, find_name, NeNameIterator};
+
+/// A linear executable (LX or LE) file.
+///
+/// This is the format used by 32-bit OS/2 executables (LX), and by Windows
+/// virtual device drivers (LE). It provides access to the headers, object table,
+/// and name and entry tables, but does not implement the `Object` trait.
+///
+/// Only little endian files are supported.
+#[derive(Debug)]
+pub struct LxFile<'data, R = &'data [u8]>
+where
+    R: ReadRef<'data>,
+{
+    data: R,
+    dos_header: &'data pe::ImageDosHeader,
+    header_offset: u64,
+    header: &'data pe::ImageVxdHeader,
+}
+
+impl<'data, R: ReadRef<'data>> LxFile<'data, R> {
+    /// Parse the raw LX or LE file data.
+    pub fn parse(data: R) -> Result<Self> {
+        let dos_header = pe::ImageDosHeader::parse(data)?;
+        let header_offset = dos_header.nt_headers_offset().into();
+        let header = data
+            .read_at::<pe::ImageVxdHeader>(header_offset)
+            .read_error("Invalid LX header offset or size")?;
+        match header.e32_magic.get(LE) {
+            pe::IMAGE_OS2_SIGNATURE_LX | pe::IMAGE_OS2_SIGNATURE_LE => {}
+            _ => return Err(Error("Invalid LX magic")),
+        }
+        if header.e32_border != 0 || header.e32_worder != 0 {
+            return Err(Error("Unsupported big endian LX file"));
+        }
+        Ok(LxFile {
+            data,
+            dos_header,
+            header_offset,
+            header,
+        })
+    }
+
+    /// Returns the raw data.
+    #[inline]
+    pub fn data(&self) -> R {
+        self.data
+    }
+
+    /// Returns the DOS header.
+    #[inline]
+    pub fn dos_header(&self) -> &'data pe::ImageDosHeader {
+        self.dos_header
+    }
+
+    /// Returns the LX header.
+    #[inline]
+    pub fn header(&self) -> &'data pe::ImageVxdHeader {
+        self.header
+    }
+
+    /// Returns the file offset of the LX header.
+    ///
+    /// Most table offsets in the header are relative to this.
+    #[inline]
+    pub fn header_offset(&self) -> u64 {
+        self.header_offset
+    }
+
+    /// Returns true if this is an LE file, such as a Windows virtual device driver.
+    #[inline]
+    pub fn is_le(&self) -> bool {
+        self.header.e32_magic.get(LE) == pe::IMAGE_OS2_SIGNATURE_LE
+    }
+
+    /// Returns the object table.
+    pub fn objects(&self) -> Result<&'data [pe::ImageLxObject]> {
+        let offset = self.header_offset + u64::from(self.header.e32_objtab.get(LE));
+        let count = self.header.e32_objcnt.get(LE) as usize;
+        self.data
+            .read_slice_at(offset, count)
+            .read_error("Invalid LX object table offset or size")
+    }
+
+    /// Returns the resident name table.
+    ///
+    /// The first entry is the module name.
+    pub fn resident_names(&self) -> Result<NeNameIterator<'data>> {
+        // The resident name table is followed by the entry table.
+        let start = self.header.e32_restab.get(LE);
+        let end = self.header.e32_enttab.get(LE);
+        let size = end
+            .checked_sub(start)
+            .read_error("Invalid LX resident name table size")?;
+        let data = self
+            .data
+            .read_bytes_at(self.header_offset + u64::from(start), size.into())
+            .read_error("Invalid LX resident name table offset or size")?;
+        Ok(NeNameIterator::new(data))
+    }
+
+    /// Returns the non-resident name table.
+    ///
+    /// The first entry is the module description.
+    pub fn nonresident_names(&self) -> Result<NeNameIterator<'data>> {
+        let size = self.header.e32_cbnrestab.get(LE);
+        if size == 0 {
+            return Ok(NeNameIterator::default());
+        }
+        let data = self
+            .data
+            .read_bytes_at(self.header.e32_nrestab.get(LE).into(), size.into())
+            .read_error("Invalid LX non-resident name table offset or size")?;
+        Ok(NeNameIterator::new(data))
+    }
+
+    /// Returns the module name.
+    ///
+    /// This is the first entry in the resident name table.
+    pub fn module_name(&self) -> Result<&'data [u8]> {
+        match self.resident_names()?.next()? {
+            Some(name) => Ok(name.name),
+            None => Err(Error("Missing LX module name")),
+        }
+    }
+
+    /// Returns the entry table.
+    pub fn entries(&self) -> Result<LxEntryIterator<'data>> {
+        // The entry table is in the loader section, which starts at the object table.
+        let start = self.header.e32_enttab.get(LE);
+        let end = self
+            .header
+            .e32_objtab
+            .get(LE)
+            .checked_add(self.header.e32_ldrsize.get(LE))
+            .read_error("Invalid LX loader section size")?;
+        let size = end
+            .checked_sub(start)
+            .read_error("Invalid LX entry table offset")?;
+        let data = self
+            .data
+            .read_bytes_at(self.header_offset + u64::from(start), size.into())
+            .read_error("Invalid LX entry table offset or size")?;
+        Ok(LxEntryIterator {
+            data: Bytes(data),
+            ordinal: 0,
+            count: 0,
+            kind: 0,
+            object: 0,
+        })
+    }
+
+    /// Returns the exported entry points.
+    ///
+    /// This includes all entries, other than forwarders, that have a name in the
+    /// resident or non-resident name tables, or that have the exported flag set.
+    /// The exports are sorted by ordinal.
+    pub fn exports(&self) -> Result<Vec<LxExport<'data>>> {
+        let names = exported_names(self.resident_names()?, self.nonresident_names()?)?;
+        let mut exports = Vec::new();
+        let mut entries = self.entries()?;
+        while let Some(entry) = entries.next()? {
+            if entry.kind == pe::ENTRYFWD {
+                continue;
+            }
+            let name = find_name(&names, entry.ordinal);
+            if name.is_some() || entry.flags & LX_EXPORTED != 0 {
+                exports.push(LxExport {
+                    ordinal: entry.ordinal,
+                    name,
+                    object: entry.object,
+                    offset: entry.offset,
+                });
+            }
+        }
+        Ok(exports)
+    }
+}
+
+/// The flag in an entry table entry that indicates that the entry is exported.
+const LX_EXPORTED: u8 = 0x01;
+
+/// An entry in the LX entry table.
+#[derive(Debug, Clone, Copy)]
+pub struct LxEntry {
+    /// The ordinal of the entry.
+    pub ordinal: u16,
+    /// The type of the entry bundle.
+    ///
+    /// One of `pe::ENTRY16`, `pe::GATE16`, `pe::ENTRY32` or `pe::ENTRYFWD`.
+    pub kind: u8,
+    /// The entry flags.
+    ///
+    /// Bit 0 is set if the entry is exported.
+    pub flags: u8,
+    /// The 1-based object number.
+    ///
+    /// For forwarders, this is the ordinal in the import module name table instead.
+    pub object: u16,
+    /// The offset within the object.
+    ///
+    /// For forwarders, this is the offset in the import procedure name table, or
+    /// the ordinal if bit 0 of the flags is set.
+    pub offset: u32,
+}
+
+/// An iterator over the entries in the LX entry table.
+///
+/// Unused entries are skipped.
+#[derive(Debug, Clone)]
+pub struct LxEntryIterator<'data> {
+    data: Bytes<'data>,
+    ordinal: u16,
+    count: u8,
+    kind: u8,
+    object: u16,
+}
+
+impl<'data> LxEntryIterator<'data> {
+    /// Return the next entry.
+    ///
+    /// Returns `Ok(None)` at the end of the table.
+    pub fn next(&mut self) -> Result<Option<LxEntry>> {
+        let result = self.parse();
+        if result.is_err() {
+            self.data = Bytes(&[]);
+            self.count = 0;
+        }
+        result
+    }
+
+    fn parse(&mut self) -> Result<Option<LxEntry>> {
+        while self.count == 0 {
+            if self.data.is_empty() {
+                return Ok(None);
+            }
+            let count = *self
+                .data
+                .read::<u8>()
+                .read_error("Invalid LX entry bundle")?;
+            if count == 0 {
+                self.data = Bytes(&[]);
+                return Ok(None);
+            }
+            let kind = *self
+                .data
+                .read::<u8>()
+                .read_error("Invalid LX entry bundle")?
+                & !pe::TYPEINFO;
+            if kind == pe::EMPTY {
+                self.ordinal = self.ordinal.wrapping_add(count.into());
+                continue;
+            }
+            if !matches!(kind, pe::ENTRY16 | pe::GATE16 | pe::ENTRY32 | pe::ENTRYFWD) {
+                return Err(Error("Unsupported LX entry bundle type"));
+            }
+            self.object = self
+                .data
+                .read::<crate::endian::U16Bytes<LE>>()
+                .read_error("Invalid LX entry bundle")?
+                .get(LE);
+            self.count = count;
+            self.kind = kind;
+        }
+        self.count -= 1;
+        self.ordinal = self.ordinal.wrapping_add(1);
+        let size = match self.kind {
+            pe::ENTRY16 => 3,
+            pe::GATE16 | pe::ENTRY32 => 5,
+            _ => 7,
+        };
+        let entry = self.data.read_bytes(size).read_error("Invalid LX entry")?.0;
+        let flags = entry[0];
+        let (object, offset) = match self.kind {
+            // The call gate selector that follows the offset is ignored.
+            pe::ENTRY16 | pe::GATE16 => {
+                (self.object, u16::from_le_bytes([entry[1], entry[2]]).into())
+            }
+            pe::ENTRY32 => (
+                self.object,
+                u32::from_le_bytes([entry[1], entry[2], entry[3], entry[4]]),
+            ),
+            _ => (
+                u16::from_le_bytes([entry[1], entry[2]]),
+                u32::from_le_bytes([entry[3], entry[4], entry[5], entry[6]]),
+            ),
+        };
+        Ok(Some(LxEntry {
+            ordinal: self.ordinal,
+            kind: self.kind,
+            flags,
+            object,
+            offset,
+        }))
+    }
+}
+
+/// An export from an LX file.
+#[derive(Clone, Copy)]
+pub struct LxExport<'data> {
+    /// The ordinal of the export.
+    pub ordinal: u16,
+    /// The name of the export, if known.
+    pub name: Option<&'data [u8]>,
+    /// The 1-based object number.
+    pub object: u16,
+    /// The offset within the object.
+    pub offset: u32,
+}
+
+impl<'data> Debug for LxExport<'data> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LxExport")
+            .field("ordinal", &self.ordinal)
+            .field("name", &self.name.map(ByteString))
+            .field("object", &self.object)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
@@ -31,4 +31,19 @@ pub use resource::*;
 mod rich;
 pub use rich::*;
 
+mod exception;
+pub use exception::*;
+
+mod load_config;
+pub use load_config::*;
+
+mod certificate;
+pub use certificate::*;
+
+mod ne;
+pub use ne::*;
+
+mod lx;
+pub use lx::*;
+
 pub use super::coff::{SectionTable, SymbolTable};
@@ -0,0 +1,435 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::endian::LittleEndian as LE;
+use crate::pe;
+use crate::read::{ByteString, Bytes, Error, ReadError, ReadRef, Result};
+
+/// A 16-bit NE (New Executable) file.
+///
+/// This is the format used by 16-bit Windows and OS/2 1.x executables. It
+/// provides access to the headers, segment table, and name and entry tables,
+/// but does not implement the `Object` trait.
+#[derive(Debug)]
+pub struct NeFile<'data, R = &'data [u8]>
+where
+    R: ReadRef<'data>,
+{
+    data: R,
+    dos_header: &'data pe::ImageDosHeader,
+    header_offset: u64,
+    header: &'data pe::ImageOs2Header,
+}
+
+impl<'data, R: ReadRef<'data>> NeFile<'data, R> {
+    /// Parse the raw NE file data.
+    pub fn parse(data: R) -> Result<Self> {
+        let dos_header = pe::ImageDosHeader::parse(data)?;
+        let header_offset = dos_header.nt_headers_offset().into();
+        let header = data
+            .read_at::<pe::ImageOs2Header>(header_offset)
+            .read_error("Invalid NE header offset or size")?;
+        if header.ne_magic.get(LE) != pe::IMAGE_OS2_SIGNATURE {
+            return Err(Error("Invalid NE magic"));
+        }
+        Ok(NeFile {
+            data,
+            dos_header,
+            header_offset,
+            header,
+        })
+    }
+
+    /// Returns the raw data.
+    #[inline]
+    pub fn data(&self) -> R {
+        self.data
+    }
+
+    /// Returns the DOS header.
+    #[inline]
+    pub fn dos_header(&self) -> &'data pe::ImageDosHeader {
+        self.dos_header
+    }
+
+    /// Returns the NE header.
+    #[inline]
+    pub fn header(&self) -> &'data pe::ImageOs2Header {
+        self.header
+    }
+
+    /// Returns the file offset of the NE header.
+    ///
+    /// Most table offsets in the header are relative to this.
+    #[inline]
+    pub fn header_offset(&self) -> u64 {
+        self.header_offset
+    }
+
+    /// Returns the shift count used for segment file offsets.
+    pub fn align_shift(&self) -> u16 {
+        match self.header.ne_align.get(LE) {
+            // A value of 0 is treated as the default of 512 byte sectors.
+            0 => 9,
+            shift => shift,
+        }
+    }
+
+    /// Returns the segment table.
+    pub fn segments(&self) -> Result<&'data [pe::ImageOs2Segment]> {
+        let offset = self.header_offset + u64::from(self.header.ne_segtab.get(LE));
+        self.data
+            .read_slice_at(offset, self.header.ne_cseg.get(LE).into())
+            .read_error("Invalid NE segment table offset or size")
+    }
+
+    /// Returns the file data of a segment.
+    pub fn segment_data(&self, segment: &pe::ImageOs2Segment) -> Result<&'data [u8]> {
+        segment.data(self.data, self.align_shift())
+    }
+
+    /// Returns the resident name table.
+    ///
+    /// The first entry is the module name.
+    pub fn resident_names(&self) -> Result<NeNameIterator<'data>> {
+        let start = self.header.ne_restab.get(LE);
+        let end = self.header.ne_modtab.get(LE);
+        let size = end
+            .checked_sub(start)
+            .read_error("Invalid NE resident name table size")?;
+        let data = self
+            .data
+            .read_bytes_at(self.header_offset + u64::from(start), size.into())
+            .read_error("Invalid NE resident name table offset or size")?;
+        Ok(NeNameIterator::new(data))
+    }
+
+    /// Returns the non-resident name table.
+    ///
+    /// The first entry is the module description.
+    pub fn nonresident_names(&self) -> Result<NeNameIterator<'data>> {
+        let size = self.header.ne_cbnrestab.get(LE);
+        if size == 0 {
+            return Ok(NeNameIterator::default());
+        }
+        let data = self
+            .data
+            .read_bytes_at(self.header.ne_nrestab.get(LE) as u32 as u64, size.into())
+            .read_error("Invalid NE non-resident name table offset or size")?;
+        Ok(NeNameIterator::new(data))
+    }
+
+    /// Returns the module name.
+    ///
+    /// This is the first entry in the resident name table.
+    pub fn module_name(&self) -> Result<&'data [u8]> {
+        match self.resident_names()?.next()? {
+            Some(name) => Ok(name.name),
+            None => Err(Error("Missing NE module name")),
+        }
+    }
+
+    /// Returns the entry table.
+    pub fn entries(&self) -> Result<NeEntryIterator<'data>> {
+        let data = self
+            .data
+            .read_bytes_at(
+                self.header_offset + u64::from(self.header.ne_enttab.get(LE)),
+                self.header.ne_cbenttab.get(LE).into(),
+            )
+            .read_error("Invalid NE entry table offset or size")?;
+        Ok(NeEntryIterator {
+            data: Bytes(data),
+            ordinal: 0,
+            count: 0,
+            kind: 0,
+        })
+    }
+
+    /// Returns the exported entry points.
+    ///
+    /// This includes all entries that have a name in the resident or
+    /// non-resident name tables, or that have the exported flag set.
+    /// The exports are sorted by ordinal.
+    pub fn exports(&self) -> Result<Vec<NeExport<'data>>> {
+        let names = exported_names(self.resident_names()?, self.nonresident_names()?)?;
+        let mut exports = Vec::new();
+        let mut entries = self.entries()?;
+        while let Some(entry) = entries.next()? {
+            let name = find_name(&names, entry.ordinal);
+            if name.is_some() || entry.flags & NE_EXPORTED != 0 {
+                exports.push(NeExport {
+                    ordinal: entry.ordinal,
+                    name,
+                    segment: entry.segment,
+                    offset: entry.offset,
+                });
+            }
+        }
+        Ok(exports)
+    }
+}
+
+/// The flag in an entry table entry that indicates that the entry is exported.
+const NE_EXPORTED: u8 = 0x01;
+
+/// Collect the names from the resident and non-resident name tables, sorted by ordinal.
+///
+/// The first entry in each table is skipped, since it is the module name or description.
+pub(super) fn exported_names<'data>(
+    mut resident: NeNameIterator<'data>,
+    mut nonresident: NeNameIterator<'data>,
+) -> Result<Vec<NeName<'data>>> {
+    let mut names = Vec::new();
+    for table in [&mut resident, &mut nonresident] {
+        table.next()?;
+        while let Some(name) = table.next()? {
+            names.push(name);
+        }
+    }
+    names.sort_by_key(|name| name.ordinal);
+    Ok(names)
+}
+
+pub(super) fn find_name<'data>(names: &[NeName<'data>], ordinal: u16) -> Option<&'data [u8]> {
+    names
+        .binary_search_by_key(&ordinal, |name| name.ordinal)
+        .ok()
+        .map(|index| names[index].name)
+}
+
+impl pe::ImageOs2Segment {
+    /// Return the offset and size of the segment's data in the file.
+    ///
+    /// `align_shift` is the shift count from the NE header.
+    ///
+    /// Returns `None` if the segment has no data in the file.
+    pub fn file_range(&self, align_shift: u16) -> Option<(u64, u64)> {
+        let sector = self.ns_sector.get(LE);
+        if sector == 0 {
+            return None;
+        }
+        let offset = u64::from(sector).checked_shl(align_shift.into())?;
+        let size = match self.ns_cbseg.get(LE) {
+            0 => 0x10000,
+            size => size.into(),
+        };
+        Some((offset, size))
+    }
+
+    /// Return the segment data.
+    ///
+    /// `align_shift` is the shift count from the NE header.
+    ///
+    /// Returns `Ok(&[])` if the segment has no data in the file.
+    pub fn data<'data, R: ReadRef<'data>>(&self, data: R, align_shift: u16) -> Result<&'data [u8]> {
+        match self.file_range(align_shift) {
+            Some((offset, size)) => data
+                .read_bytes_at(offset, size)
+                .read_error("Invalid NE segment offset or size"),
+            None => Ok(&[]),
+        }
+    }
+
+    /// Return the size of memory to allocate for the segment.
+    pub fn min_alloc(&self) -> u32 {
+        match self.ns_minalloc.get(LE) {
+            0 => 0x10000,
+            size => size.into(),
+        }
+    }
+
+    /// Return true if this is a data segment.
+    #[inline]
+    pub fn is_data(&self) -> bool {
+        self.ns_flags.get(LE) & pe::NSTYPE == pe::NSDATA
+    }
+}
+
+/// An entry in a resident or non-resident name table.
+#[derive(Clone, Copy)]
+pub struct NeName<'data> {
+    /// The name.
+    pub name: &'data [u8],
+    /// The ordinal of the entry in the entry table.
+    pub ordinal: u16,
+}
+
+impl<'data> Debug for NeName<'data> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NeName")
+            .field("name", &ByteString(self.name))
+            .field("ordinal", &self.ordinal)
+            .finish()
+    }
+}
+
+/// An iterator over the entries in a resident or non-resident name table.
+///
+/// This format is used by both NE and LX files.
+#[derive(Debug, Default, Clone)]
+pub struct NeNameIterator<'data> {
+    data: Bytes<'data>,
+}
+
+impl<'data> NeNameIterator<'data> {
+    pub(super) fn new(data: &'data [u8]) -> Self {
+        NeNameIterator { data: Bytes(data) }
+    }
+
+    /// Return the next name.
+    ///
+    /// Returns `Ok(None)` at the end of the table.
+    pub fn next(&mut self) -> Result<Option<NeName<'data>>> {
+        if self.data.is_empty() {
+            return Ok(None);
+        }
+        let result = self.parse();
+        if result.is_err() {
+            self.data = Bytes(&[]);
+        }
+        result
+    }
+
+    fn parse(&mut self) -> Result<Option<NeName<'data>>> {
+        let len = *self
+            .data
+            .read::<u8>()
+            .read_error("Invalid name table entry")?;
+        if len == 0 {
+            self.data = Bytes(&[]);
+            return Ok(None);
+        }
+        let name = self
+            .data
+            .read_bytes(len.into())
+            .read_error("Invalid name table entry length")?
+            .0;
+        let ordinal = self
+            .data
+            .read::<crate::endian::U16Bytes<LE>>()
+            .read_error("Invalid name table entry ordinal")?
+            .get(LE);
+        Ok(Some(NeName { name, ordinal }))
+    }
+}
+
+/// An entry in the NE entry table.
+#[derive(Debug, Clone, Copy)]
+pub struct NeEntry {
+    /// The ordinal of the entry.
+    pub ordinal: u16,
+    /// The entry flags.
+    ///
+    /// Bit 0 is set if the entry is exported, and bit 1 is set if the entry
+    /// uses a shared data segment.
+    pub flags: u8,
+    /// The 1-based segment number, or `pe::ENT_ABSSEG` for a constant.
+    pub segment: u8,
+    /// The offset within the segment, or the value of a constant.
+    pub offset: u16,
+}
+
+/// An iterator over the entries in the NE entry table.
+///
+/// Unused entries are skipped.
+#[derive(Debug, Clone)]
+pub struct NeEntryIterator<'data> {
+    data: Bytes<'data>,
+    ordinal: u16,
+    count: u8,
+    kind: u8,
+}
+
+impl<'data> NeEntryIterator<'data> {
+    /// Return the next entry.
+    ///
+    /// Returns `Ok(None)` at the end of the table.
+    pub fn next(&mut self) -> Result<Option<NeEntry>> {
+        let result = self.parse();
+        if result.is_err() {
+            self.data = Bytes(&[]);
+            self.count = 0;
+        }
+        result
+    }
+
+    fn parse(&mut self) -> Result<Option<NeEntry>> {
+        while self.count == 0 {
+            if self.data.is_empty() {
+                return Ok(None);
+            }
+            let count = *self
+                .data
+                .read::<u8>()
+                .read_error("Invalid NE entry bundle")?;
+            if count == 0 {
+                self.data = Bytes(&[]);
+                return Ok(None);
+            }
+            let kind = *self
+                .data
+                .read::<u8>()
+                .read_error("Invalid NE entry bundle")?;
+            if kind == pe::ENT_UNUSED {
+                self.ordinal = self.ordinal.wrapping_add(count.into());
+            } else {
+                self.count = count;
+                self.kind = kind;
+            }
+        }
+        self.count -= 1;
+        self.ordinal = self.ordinal.wrapping_add(1);
+        let entry = if self.kind == pe::ENT_MOVEABLE {
+            let entry = self
+                .data
+                .read_bytes(6)
+                .read_error("Invalid NE movable entry")?
+                .0;
+            // The entry contains the flags, an INT 3Fh instruction, the segment and offset.
+            NeEntry {
+                ordinal: self.ordinal,
+                flags: entry[0],
+                segment: entry[3],
+                offset: u16::from_le_bytes([entry[4], entry[5]]),
+            }
+        } else {
+            let entry = self
+                .data
+                .read_bytes(3)
+                .read_error("Invalid NE fixed entry")?
+                .0;
+            NeEntry {
+                ordinal: self.ordinal,
+                flags: entry[0],
+                segment: self.kind,
+                offset: u16::from_le_bytes([entry[1], entry[2]]),
+            }
+        };
+        Ok(Some(entry))
+    }
+}
+
+/// An export from an NE file.
+#[derive(Clone, Copy)]
+pub struct NeExport<'data> {
+    /// The ordinal of the export.
+    pub ordinal: u16,
+    /// The name of the export, if known.
+    pub name: Option<&'data [u8]>,
+    /// The 1-based segment number, or `pe::ENT_ABSSEG` for a constant.
+    pub segment: u8,
+    /// The offset within the segment, or the value of a constant.
+    pub offset: u16,
+}
+
+impl<'data> Debug for NeExport<'data> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NeExport")
+            .field("ordinal", &self.ordinal)
+            .field("name", &self.name.map(ByteString))
+            .field("segment", &self.segment)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
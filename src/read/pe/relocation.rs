@@ -2,7 +2,9 @@ use core::slice;
 
 use crate::endian::{LittleEndian as LE, U16};
 use crate::pe;
-use crate::read::{Bytes, Error, ReadError, Result};
+use crate::read::{
+    self, Bytes, Error, ReadError, RelocationEncoding, RelocationKind, RelocationTarget, Result,
+};
 
 /// An iterator over the relocation blocks in the `.reloc` section of a PE file.
 #[derive(Debug, Default, Clone, Copy)]
@@ -88,3 +90,53 @@ pub struct Relocation {
     /// One of the `pe::IMAGE_REL_BASED_*` constants.
     pub typ: u16,
 }
+
+/// An iterator over the base relocations of a `PeFile`.
+///
+/// This is returned by `Object::dynamic_relocations`. The relocation addresses are
+/// virtual addresses using the preferred image base.
+#[derive(Debug, Clone)]
+pub struct PeDynamicRelocationIterator<'data> {
+    pub(super) image_base: u64,
+    pub(super) blocks: RelocationBlockIterator<'data>,
+    pub(super) relocations: Option<RelocationIterator<'data>>,
+}
+
+impl<'data> Iterator for PeDynamicRelocationIterator<'data> {
+    type Item = (u64, read::Relocation);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut relocations) = self.relocations {
+                if let Some(reloc) = relocations.next() {
+                    let size = match reloc.typ {
+                        pe::IMAGE_REL_BASED_ABSOLUTE => continue,
+                        pe::IMAGE_REL_BASED_HIGHLOW => 32,
+                        pe::IMAGE_REL_BASED_DIR64 => 64,
+                        // Machine specific base relocations, or partial words.
+                        _ => 0,
+                    };
+                    let relocation = read::Relocation {
+                        kind: RelocationKind::Rebase,
+                        encoding: RelocationEncoding::Generic,
+                        size,
+                        target: RelocationTarget::Absolute,
+                        addend: 0,
+                        implicit_addend: true,
+                    };
+                    let address = self.image_base.wrapping_add(reloc.virtual_address.into());
+                    return Some((address, relocation));
+                }
+                self.relocations = None;
+            }
+            match self.blocks.next() {
+                Ok(Some(relocations)) => self.relocations = Some(relocations),
+                Ok(None) => return None,
+                Err(_) => {
+                    self.blocks = RelocationBlockIterator::default();
+                    return None;
+                }
+            }
+        }
+    }
+}
@@ -1,24 +1,36 @@
 use alloc::string::String;
 use core::char;
 
-use crate::read::{ReadError, ReadRef, Result};
+use crate::read::{ParseOptions, ReadError, ReadRef, Result};
 use crate::{pe, LittleEndian as LE, U16Bytes};
 
 /// The `.rsrc` section of a PE file.
 #[derive(Debug, Clone, Copy)]
 pub struct ResourceDirectory<'data> {
     data: &'data [u8],
+    options: ParseOptions,
 }
 
 impl<'data> ResourceDirectory<'data> {
     /// Construct from the data of the `.rsrc` section.
     pub fn new(data: &'data [u8]) -> Self {
-        ResourceDirectory { data }
+        Self::new_with_options(data, &ParseOptions::default())
+    }
+
+    /// Construct from the data of the `.rsrc` section, with limits on parsing.
+    ///
+    /// Tables with more entries than [`ParseOptions::max_table_entries`] are invalid.
+    /// [`ResourceDirectoryTable::entry_data`] also limits the depth of nested tables.
+    pub fn new_with_options(data: &'data [u8], options: &ParseOptions) -> Self {
+        ResourceDirectory {
+            data,
+            options: *options,
+        }
     }
 
     /// Parses the root resource directory.
     pub fn root(&self) -> Result<ResourceDirectoryTable<'data>> {
-        ResourceDirectoryTable::parse(self.data, 0)
+        ResourceDirectoryTable::parse(*self, 0, 0)
     }
 }
 
@@ -29,20 +41,40 @@ pub struct ResourceDirectoryTable<'data> {
     pub header: &'data pe::ImageResourceDirectory,
     /// The table entries.
     pub entries: &'data [pe::ImageResourceDirectoryEntry],
+    depth: u32,
 }
 
 impl<'data> ResourceDirectoryTable<'data> {
-    fn parse(data: &'data [u8], offset: u32) -> Result<Self> {
+    fn parse(section: ResourceDirectory<'data>, offset: u32, depth: u32) -> Result<Self> {
+        section.options.check_depth(depth)?;
+        let data = section.data;
         let mut offset = u64::from(offset);
         let header = data
             .read::<pe::ImageResourceDirectory>(&mut offset)
             .read_error("Invalid resource table header")?;
         let entries_count = header.number_of_id_entries.get(LE) as usize
             + header.number_of_named_entries.get(LE) as usize;
+        section.options.check_table_entries(entries_count as u64)?;
         let entries = data
             .read_slice::<pe::ImageResourceDirectoryEntry>(&mut offset, entries_count)
             .read_error("Invalid resource table entries")?;
-        Ok(Self { header, entries })
+        Ok(Self {
+            header,
+            entries,
+            depth,
+        })
+    }
+
+    /// Returns the data associated to an entry of this table.
+    ///
+    /// This is the same as [`pe::ImageResourceDirectoryEntry::data`], except
+    /// that it returns an error if the subtable depth exceeds [`ParseOptions::max_depth`].
+    pub fn entry_data(
+        &self,
+        entry: &pe::ImageResourceDirectoryEntry,
+        section: ResourceDirectory<'data>,
+    ) -> Result<ResourceDirectoryEntryData<'data>> {
+        entry.data_at_depth(section, self.depth + 1)
     }
 }
 
@@ -87,12 +119,23 @@ impl pe::ImageResourceDirectoryEntry {
     }
 
     /// Returns the data associated to this directory entry.
+    ///
+    /// The depth of the entry is unknown, so subtable depth is not limited.
+    /// Use [`ResourceDirectoryTable::entry_data`] to limit it.
     pub fn data<'data>(
         &self,
         section: ResourceDirectory<'data>,
+    ) -> Result<ResourceDirectoryEntryData<'data>> {
+        self.data_at_depth(section, 0)
+    }
+
+    fn data_at_depth<'data>(
+        &self,
+        section: ResourceDirectory<'data>,
+        depth: u32,
     ) -> Result<ResourceDirectoryEntryData<'data>> {
         if self.is_table() {
-            ResourceDirectoryTable::parse(section.data, self.data_offset())
+            ResourceDirectoryTable::parse(section, self.data_offset(), depth)
                 .map(ResourceDirectoryEntryData::Table)
         } else {
             section
@@ -5,8 +5,9 @@ use crate::endian::LittleEndian as LE;
 use crate::pe;
 use crate::pe::ImageSectionHeader;
 use crate::read::{
-    self, CompressedData, CompressedFileRange, ObjectSection, ObjectSegment, ReadError, ReadRef,
-    Relocation, Result, SectionFlags, SectionIndex, SectionKind, SegmentFlags,
+    self, CompressedData, CompressedFileRange, ObjectSection, ObjectSegment, Permissions,
+    ReadError, ReadRef, Relocation, Result, SectionFlags, SectionIndex, SectionKind, SegmentFlags,
+    SegmentKind,
 };
 
 use super::{ImageNtHeaders, PeFile, SectionTable};
@@ -79,6 +80,11 @@ where
         u64::from(self.section.virtual_address.get(LE)).wrapping_add(self.file.common.image_base)
     }
 
+    #[inline]
+    fn physical_address(&self) -> u64 {
+        self.address()
+    }
+
     #[inline]
     fn size(&self) -> u64 {
         u64::from(self.section.virtual_size.get(LE))
@@ -125,6 +131,21 @@ where
         ))
     }
 
+    #[inline]
+    fn kind(&self) -> SegmentKind {
+        SegmentKind::Load
+    }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        let characteristics = self.section.characteristics.get(LE);
+        Permissions::new(
+            characteristics & pe::IMAGE_SCN_MEM_READ != 0,
+            characteristics & pe::IMAGE_SCN_MEM_WRITE != 0,
+            characteristics & pe::IMAGE_SCN_MEM_EXECUTE != 0,
+        )
+    }
+
     #[inline]
     fn flags(&self) -> SegmentFlags {
         let characteristics = self.section.characteristics.get(LE);
@@ -0,0 +1,394 @@
+//! Support for reading raw binary images.
+//!
+//! Provides `RawFile` and related types which implement the `Object` trait for
+//! data that has no file format, such as a firmware image or a memory dump.
+//!
+//! The data is exposed as a single loadable segment and a single text section
+//! at a load address that is specified by the caller. This allows addresses in
+//! the data to be symbolicated and disassembled in the same way as for other
+//! file formats.
+use alloc::vec::Vec;
+use core::iter;
+
+use crate::read::{
+    self, Architecture, CompressedData, CompressedFileRange, Error, Export, FileFlags, Import,
+    NoComdat, NoDynamicRelocationIterator, NoSymbol, NoSymbolTable, Object, ObjectKind,
+    ObjectSection, ObjectSegment, Permissions, Relocation, Result, SectionFlags, SectionIndex,
+    SectionKind, SegmentFlags, SegmentKind, SymbolIndex,
+};
+use crate::{AddressSize, Endianness};
+
+/// The name of the section that contains the data of a `RawFile`.
+pub const RAW_SECTION_NAME: &str = ".text";
+
+/// A raw binary image.
+#[derive(Debug, Clone, Copy)]
+pub struct RawFile<'data> {
+    data: &'data [u8],
+    architecture: Architecture,
+    endian: Endianness,
+    address: u64,
+    entry: u64,
+}
+
+impl<'data> RawFile<'data> {
+    /// Create a `RawFile` for data that is loaded at the given address.
+    ///
+    /// The entry point defaults to the load address.
+    pub fn new(
+        data: &'data [u8],
+        architecture: Architecture,
+        endian: Endianness,
+        address: u64,
+    ) -> Self {
+        RawFile {
+            data,
+            architecture,
+            endian,
+            address,
+            entry: address,
+        }
+    }
+
+    /// Set the address of the entry point.
+    pub fn set_entry(&mut self, entry: u64) {
+        self.entry = entry;
+    }
+
+    /// Return the raw data.
+    #[inline]
+    pub fn data(&self) -> &'data [u8] {
+        self.data
+    }
+}
+
+impl<'data> read::private::Sealed for RawFile<'data> {}
+
+impl<'data, 'file> Object<'data, 'file> for RawFile<'data>
+where
+    'data: 'file,
+{
+    type Segment = RawSegment<'data, 'file>;
+    type SegmentIterator = RawSegmentIterator<'data, 'file>;
+    type Section = RawSection<'data, 'file>;
+    type SectionIterator = RawSectionIterator<'data, 'file>;
+    type Comdat = NoComdat;
+    type ComdatIterator = iter::Empty<NoComdat>;
+    type Symbol = NoSymbol;
+    type SymbolIterator = iter::Empty<NoSymbol>;
+    type SymbolTable = NoSymbolTable;
+    type DynamicRelocationIterator = NoDynamicRelocationIterator;
+
+    #[inline]
+    fn architecture(&self) -> Architecture {
+        self.architecture
+    }
+
+    #[inline]
+    fn is_little_endian(&self) -> bool {
+        self.endian == Endianness::Little
+    }
+
+    #[inline]
+    fn is_64(&self) -> bool {
+        self.architecture.address_size() == Some(AddressSize::U64)
+    }
+
+    #[inline]
+    fn kind(&self) -> ObjectKind {
+        ObjectKind::Executable
+    }
+
+    #[inline]
+    fn segments(&'file self) -> Self::SegmentIterator {
+        RawSegmentIterator { file: Some(self) }
+    }
+
+    fn section_by_name_bytes(&'file self, section_name: &[u8]) -> Option<RawSection<'data, 'file>> {
+        if section_name == RAW_SECTION_NAME.as_bytes() {
+            Some(RawSection { file: self })
+        } else {
+            None
+        }
+    }
+
+    fn section_by_index(&'file self, index: SectionIndex) -> Result<RawSection<'data, 'file>> {
+        if index == SectionIndex(1) {
+            Ok(RawSection { file: self })
+        } else {
+            Err(Error("Invalid raw section index"))
+        }
+    }
+
+    #[inline]
+    fn sections(&'file self) -> Self::SectionIterator {
+        RawSectionIterator { file: Some(self) }
+    }
+
+    #[inline]
+    fn comdats(&'file self) -> Self::ComdatIterator {
+        iter::empty()
+    }
+
+    #[inline]
+    fn symbol_by_index(&'file self, _index: SymbolIndex) -> Result<NoSymbol> {
+        Err(Error("Invalid raw symbol index"))
+    }
+
+    #[inline]
+    fn symbols(&'file self) -> Self::SymbolIterator {
+        iter::empty()
+    }
+
+    #[inline]
+    fn symbol_table(&'file self) -> Option<NoSymbolTable> {
+        None
+    }
+
+    #[inline]
+    fn dynamic_symbols(&'file self) -> Self::SymbolIterator {
+        iter::empty()
+    }
+
+    #[inline]
+    fn dynamic_symbol_table(&'file self) -> Option<NoSymbolTable> {
+        None
+    }
+
+    #[inline]
+    fn dynamic_relocations(&self) -> Option<NoDynamicRelocationIterator> {
+        None
+    }
+
+    fn imports(&self) -> Result<Vec<Import<'data>>> {
+        Ok(Vec::new())
+    }
+
+    fn exports(&self) -> Result<Vec<Export<'data>>> {
+        Ok(Vec::new())
+    }
+
+    #[inline]
+    fn has_debug_symbols(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn relative_address_base(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn entry(&self) -> u64 {
+        self.entry
+    }
+
+    #[inline]
+    fn flags(&self) -> FileFlags {
+        FileFlags::None
+    }
+}
+
+/// An iterator over the segments of a `RawFile`.
+#[derive(Debug)]
+pub struct RawSegmentIterator<'data, 'file> {
+    file: Option<&'file RawFile<'data>>,
+}
+
+impl<'data, 'file> Iterator for RawSegmentIterator<'data, 'file> {
+    type Item = RawSegment<'data, 'file>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.file.take().map(|file| RawSegment { file })
+    }
+}
+
+/// The segment of a `RawFile`.
+///
+/// This contains all of the data.
+#[derive(Debug)]
+pub struct RawSegment<'data, 'file> {
+    file: &'file RawFile<'data>,
+}
+
+impl<'data, 'file> read::private::Sealed for RawSegment<'data, 'file> {}
+
+impl<'data, 'file> ObjectSegment<'data> for RawSegment<'data, 'file> {
+    #[inline]
+    fn address(&self) -> u64 {
+        self.file.address
+    }
+
+    #[inline]
+    fn physical_address(&self) -> u64 {
+        self.address()
+    }
+
+    #[inline]
+    fn size(&self) -> u64 {
+        self.file.data.len() as u64
+    }
+
+    #[inline]
+    fn align(&self) -> u64 {
+        1
+    }
+
+    #[inline]
+    fn file_range(&self) -> (u64, u64) {
+        (0, self.file.data.len() as u64)
+    }
+
+    #[inline]
+    fn data(&self) -> Result<&'data [u8]> {
+        Ok(self.file.data)
+    }
+
+    fn data_range(&self, address: u64, size: u64) -> Result<Option<&'data [u8]>> {
+        Ok(read::util::data_range(
+            self.file.data,
+            self.file.address,
+            address,
+            size,
+        ))
+    }
+
+    #[inline]
+    fn name_bytes(&self) -> Result<Option<&[u8]>> {
+        Ok(None)
+    }
+
+    #[inline]
+    fn name(&self) -> Result<Option<&str>> {
+        Ok(None)
+    }
+
+    #[inline]
+    fn kind(&self) -> SegmentKind {
+        SegmentKind::Load
+    }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        Permissions::new(true, false, true)
+    }
+
+    #[inline]
+    fn flags(&self) -> SegmentFlags {
+        SegmentFlags::None
+    }
+}
+
+/// An iterator over the sections of a `RawFile`.
+#[derive(Debug)]
+pub struct RawSectionIterator<'data, 'file> {
+    file: Option<&'file RawFile<'data>>,
+}
+
+impl<'data, 'file> Iterator for RawSectionIterator<'data, 'file> {
+    type Item = RawSection<'data, 'file>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.file.take().map(|file| RawSection { file })
+    }
+}
+
+/// The section of a `RawFile`.
+///
+/// This is a text section named [`RAW_SECTION_NAME`] that contains all of the data.
+#[derive(Debug)]
+pub struct RawSection<'data, 'file> {
+    file: &'file RawFile<'data>,
+}
+
+impl<'data, 'file> read::private::Sealed for RawSection<'data, 'file> {}
+
+impl<'data, 'file> ObjectSection<'data> for RawSection<'data, 'file> {
+    type RelocationIterator = iter::Empty<(u64, Relocation)>;
+
+    #[inline]
+    fn index(&self) -> SectionIndex {
+        SectionIndex(1)
+    }
+
+    #[inline]
+    fn address(&self) -> u64 {
+        self.file.address
+    }
+
+    #[inline]
+    fn size(&self) -> u64 {
+        self.file.data.len() as u64
+    }
+
+    #[inline]
+    fn align(&self) -> u64 {
+        1
+    }
+
+    #[inline]
+    fn file_range(&self) -> Option<(u64, u64)> {
+        Some((0, self.file.data.len() as u64))
+    }
+
+    #[inline]
+    fn data(&self) -> Result<&'data [u8]> {
+        Ok(self.file.data)
+    }
+
+    fn data_range(&self, address: u64, size: u64) -> Result<Option<&'data [u8]>> {
+        Ok(read::util::data_range(
+            self.file.data,
+            self.file.address,
+            address,
+            size,
+        ))
+    }
+
+    #[inline]
+    fn compressed_file_range(&self) -> Result<CompressedFileRange> {
+        Ok(CompressedFileRange::none(self.file_range()))
+    }
+
+    #[inline]
+    fn compressed_data(&self) -> Result<CompressedData<'data>> {
+        self.data().map(CompressedData::none)
+    }
+
+    #[inline]
+    fn name_bytes(&self) -> Result<&[u8]> {
+        Ok(RAW_SECTION_NAME.as_bytes())
+    }
+
+    #[inline]
+    fn name(&self) -> Result<&str> {
+        Ok(RAW_SECTION_NAME)
+    }
+
+    #[inline]
+    fn segment_name_bytes(&self) -> Result<Option<&[u8]>> {
+        Ok(None)
+    }
+
+    #[inline]
+    fn segment_name(&self) -> Result<Option<&str>> {
+        Ok(None)
+    }
+
+    #[inline]
+    fn kind(&self) -> SectionKind {
+        SectionKind::Text
+    }
+
+    #[inline]
+    fn relocations(&self) -> Self::RelocationIterator {
+        iter::empty()
+    }
+
+    #[inline]
+    fn flags(&self) -> SectionFlags {
+        SectionFlags::None
+    }
+}
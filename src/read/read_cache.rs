@@ -4,8 +4,9 @@ use std::cell::RefCell;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::convert::TryInto;
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
 use std::mem;
+use std::sync::{Mutex, MutexGuard};
 use std::vec::Vec;
 
 use crate::read::ReadRef;
@@ -14,9 +15,16 @@ use crate::read::ReadRef;
 /// `Read + Seek`.
 ///
 /// Contains a cache of read-only blocks of data, allowing references to
-/// them to be returned. Entries in the cache are never removed.
+/// them to be returned. Entries in the cache are never removed while the cache
+/// is borrowed.
 /// Entries are keyed on the offset and size of the read.
 /// Currently overlapping reads are considered separate reads.
+///
+/// The memory used by the cache can be bounded by using [`ReadCache::with_size_limit`].
+/// Entries can be removed by calling [`ReadCache::trim`] when there are no
+/// outstanding borrows, such as after each file is parsed.
+///
+/// This type is not `Sync`. Use [`SharedReadCache`] to share a cache between threads.
 #[derive(Debug)]
 pub struct ReadCache<R: Read + Seek> {
     cache: RefCell<ReadCacheInternal<R>>,
@@ -24,23 +32,337 @@ pub struct ReadCache<R: Read + Seek> {
 
 #[derive(Debug)]
 struct ReadCacheInternal<R: Read + Seek> {
+    stream: ReadCacheStream<R>,
+    bufs: HashMap<(u64, u64), ReadCacheEntry>,
+    strings: HashMap<(u64, u8), ReadCacheEntry>,
+    /// The total size of all entries.
+    size: usize,
+    /// The maximum total size of all entries.
+    size_limit: usize,
+    /// Incremented for every access, and used to find the least recently used entry.
+    tick: u64,
+    /// The size of each read from the stream when searching for a delimiter.
+    chunk_size: usize,
+}
+
+#[derive(Debug)]
+struct ReadCacheStream<R: Read + Seek> {
     read: R,
-    bufs: HashMap<(u64, u64), Box<[u8]>>,
-    strings: HashMap<(u64, u8), Box<[u8]>>,
+    /// The minimum size of each read from the stream.
+    read_ahead: usize,
+    /// Data that was read from the stream by a previous read, which may be used
+    /// for later reads.
+    window: Vec<u8>,
+    /// The stream offset of `window`.
+    window_offset: u64,
+    /// The maximum size of `window`, so that the cache stays within its size limit.
+    window_limit: usize,
+    /// The current position of the stream, if known.
+    position: Option<u64>,
+}
+
+#[derive(Debug)]
+struct ReadCacheEntry {
+    data: Box<[u8]>,
+    last_use: u64,
+}
+
+impl<R: Read + Seek> ReadCacheInternal<R> {
+    fn new(read: R, size_limit: usize) -> Self {
+        ReadCacheInternal {
+            stream: ReadCacheStream {
+                read,
+                read_ahead: 0,
+                window: Vec::new(),
+                window_offset: 0,
+                window_limit: 0,
+                position: None,
+            },
+            bufs: HashMap::new(),
+            strings: HashMap::new(),
+            size: 0,
+            size_limit,
+            tick: 0,
+            chunk_size: 256,
+        }
+    }
+
+    fn tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    /// Return the total size of all entries and the read-ahead window.
+    fn size(&self) -> usize {
+        self.size + self.stream.window.len()
+    }
+
+    fn read_bytes_at(&mut self, offset: u64, size: u64) -> Result<&[u8], ()> {
+        let tick = self.tick();
+        match self.bufs.entry((offset, size)) {
+            Entry::Occupied(entry) => {
+                let entry = entry.into_mut();
+                entry.last_use = tick;
+                Ok(&entry.data)
+            }
+            Entry::Vacant(entry) => {
+                let size = size.try_into().map_err(|_| ())?;
+                self.stream.reserve(self.size_limit - self.size, size)?;
+                let mut bytes = vec![0; size].into_boxed_slice();
+                if self.stream.read_at(offset, &mut bytes)? != size {
+                    return Err(());
+                }
+                self.size += size;
+                Ok(&entry
+                    .insert(ReadCacheEntry {
+                        data: bytes,
+                        last_use: tick,
+                    })
+                    .data)
+            }
+        }
+    }
+
+    fn read_bytes_at_until(&mut self, range: Range<u64>, delimiter: u8) -> Result<&[u8], ()> {
+        let tick = self.tick();
+        match self.strings.entry((range.start, delimiter)) {
+            Entry::Occupied(entry) => {
+                let entry = entry.into_mut();
+                entry.last_use = tick;
+                Ok(&entry.data)
+            }
+            Entry::Vacant(entry) => {
+                let max_check: usize = (range.end - range.start).try_into().map_err(|_| ())?;
+                // Strings should be relatively small.
+                // TODO: make this configurable?
+                let max_check = max_check.min(4096);
+
+                self.stream.reserve(self.size_limit - self.size, 0)?;
+                let mut bytes = Vec::new();
+                let mut checked = 0;
+                loop {
+                    bytes.resize((checked + self.chunk_size).min(max_check), 0);
+                    let read = self
+                        .stream
+                        .read_some(range.start + checked as u64, &mut bytes[checked..])?;
+                    if read == 0 {
+                        return Err(());
+                    }
+                    if let Some(len) = memchr::memchr(delimiter, &bytes[checked..][..read]) {
+                        bytes.truncate(checked + len);
+                        self.stream
+                            .reserve(self.size_limit - self.size, bytes.len())?;
+                        self.size += bytes.len();
+                        break Ok(&entry
+                            .insert(ReadCacheEntry {
+                                data: bytes.into_boxed_slice(),
+                                last_use: tick,
+                            })
+                            .data);
+                    }
+                    checked += read;
+                    if checked >= max_check {
+                        return Err(());
+                    }
+                }
+            }
+        }
+    }
+
+    fn trim(&mut self, size: usize) {
+        if self.size <= size {
+            return;
+        }
+        let mut entries = Vec::with_capacity(self.bufs.len() + self.strings.len());
+        entries.extend(
+            self.bufs
+                .iter()
+                .map(|(key, entry)| (entry.last_use, Some(*key), None)),
+        );
+        entries.extend(
+            self.strings
+                .iter()
+                .map(|(key, entry)| (entry.last_use, None, Some(*key))),
+        );
+        entries.sort_unstable_by_key(|(last_use, _, _)| *last_use);
+        for (_, buf, string) in entries {
+            if self.size <= size {
+                break;
+            }
+            let entry = match (buf, string) {
+                (Some(key), _) => self.bufs.remove(&key),
+                (_, Some(key)) => self.strings.remove(&key),
+                _ => None,
+            };
+            if let Some(entry) = entry {
+                self.size -= entry.data.len();
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.bufs.clear();
+        self.strings.clear();
+        self.size = 0;
+        self.stream.window = Vec::new();
+    }
+
+    fn set_chunk_size(&mut self, chunk_size: usize) {
+        self.chunk_size = chunk_size.max(1);
+    }
+
+    fn set_read_ahead(&mut self, read_ahead: usize) {
+        self.stream.read_ahead = read_ahead;
+        self.stream.window = Vec::new();
+    }
+}
+
+impl<R: Read + Seek> ReadCacheStream<R> {
+    fn len(&mut self) -> Result<u64, ()> {
+        self.position = None;
+        let len = self.read.seek(SeekFrom::End(0)).map_err(|_| ())?;
+        self.position = Some(len);
+        Ok(len)
+    }
+
+    /// Check that an entry of the given size can be added to the cache without
+    /// exceeding the `available` size, and limit the size of the read-ahead window
+    /// for the next read.
+    ///
+    /// The read-ahead window is discarded if it is needed to make room for the entry.
+    fn reserve(&mut self, available: usize, size: usize) -> Result<(), ()> {
+        if size > available {
+            return Err(());
+        }
+        if size > available - self.window.len() {
+            self.window = Vec::new();
+        }
+        self.window_limit = available - size;
+        Ok(())
+    }
+
+    /// Return the data in the read-ahead window starting at the given offset.
+    fn window_data(&self, offset: u64) -> &[u8] {
+        offset
+            .checked_sub(self.window_offset)
+            .and_then(|offset| offset.try_into().ok())
+            .and_then(|offset: usize| self.window.get(offset..))
+            .unwrap_or(&[])
+    }
+
+    /// Read data at the given offset, and return the number of bytes read.
+    ///
+    /// This is only less than the size of `buf` at the end of the stream.
+    fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<usize, ()> {
+        if self.window_data(offset).len() < buf.len() {
+            if buf.len() >= self.read_ahead || self.read_ahead > self.window_limit {
+                return self.read_stream(offset, buf);
+            }
+            let mut window = mem::take(&mut self.window);
+            window.resize(self.read_ahead, 0);
+            let len = self.read_stream(offset, &mut window)?;
+            window.truncate(len);
+            self.window = window;
+            self.window_offset = offset;
+        }
+        let data = self.window_data(offset);
+        let len = data.len().min(buf.len());
+        buf[..len].copy_from_slice(&data[..len]);
+        Ok(len)
+    }
+
+    /// Read data at the given offset, and return the number of bytes read.
+    ///
+    /// This may be less than the size of `buf` if some of the data is already
+    /// in the read-ahead window.
+    fn read_some(&mut self, offset: u64, buf: &mut [u8]) -> Result<usize, ()> {
+        let data = self.window_data(offset);
+        if data.is_empty() {
+            return self.read_at(offset, buf);
+        }
+        let len = data.len().min(buf.len());
+        buf[..len].copy_from_slice(&data[..len]);
+        Ok(len)
+    }
+
+    /// Read data from the stream at the given offset, and return the number of bytes read.
+    fn read_stream(&mut self, offset: u64, buf: &mut [u8]) -> Result<usize, ()> {
+        // Avoid seeking for sequential reads, since this may discard buffered data.
+        if self.position != Some(offset) {
+            self.position = None;
+            self.read.seek(SeekFrom::Start(offset)).map_err(|_| ())?;
+        }
+        self.position = None;
+        let mut len = 0;
+        while len < buf.len() {
+            match self.read.read(&mut buf[len..]) {
+                Ok(0) => break,
+                Ok(read) => len += read,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(_) => return Err(()),
+            }
+        }
+        self.position = Some(offset + len as u64);
+        Ok(len)
+    }
 }
 
 impl<R: Read + Seek> ReadCache<R> {
     /// Create an empty `ReadCache` for the given stream.
     pub fn new(read: R) -> Self {
+        Self::with_size_limit(read, usize::MAX)
+    }
+
+    /// Create an empty `ReadCache` for the given stream, with a limit on the
+    /// total size of the cached data.
+    ///
+    /// References returned by the cache must remain valid while the cache is
+    /// borrowed, so entries can't be removed during a read. Instead, a read that
+    /// would increase the size beyond the limit returns an error.
+    /// Use [`ReadCache::trim`] to remove entries between reads.
+    pub fn with_size_limit(read: R, size_limit: usize) -> Self {
         ReadCache {
-            cache: RefCell::new(ReadCacheInternal {
-                read,
-                bufs: HashMap::new(),
-                strings: HashMap::new(),
-            }),
+            cache: RefCell::new(ReadCacheInternal::new(read, size_limit)),
         }
     }
 
+    /// Set the size of each read from the stream when reading a string.
+    ///
+    /// Strings are read in chunks because their length is not known in advance.
+    /// The default is 256 bytes.
+    pub fn set_chunk_size(&mut self, chunk_size: usize) {
+        self.cache.get_mut().set_chunk_size(chunk_size);
+    }
+
+    /// Set the minimum size of each read from the stream.
+    ///
+    /// Smaller reads are extended to this size, and the extra data is kept to
+    /// satisfy later reads that are contained within it. This reduces the number
+    /// of reads from the stream when parsing many small nearby structures, such as
+    /// headers and string tables. Reads that are at least this size are not affected.
+    ///
+    /// The read-ahead window counts towards the size limit. Read-ahead is skipped
+    /// if the window would exceed the limit.
+    ///
+    /// The default is 0, which disables read-ahead.
+    pub fn set_read_ahead(&mut self, read_ahead: usize) {
+        self.cache.get_mut().set_read_ahead(read_ahead);
+    }
+
+    /// Return the total size of the cached data, including the read-ahead window.
+    pub fn size(&self) -> usize {
+        self.cache.borrow().size()
+    }
+
+    /// Remove the least recently used entries until the total size of the cached
+    /// data is at most `size`.
+    ///
+    /// This can be used to free space for parsing another file, while keeping
+    /// data that is often used, such as headers.
+    pub fn trim(&mut self, size: usize) {
+        self.cache.get_mut().trim(size);
+    }
+
     /// Return an implementation of `ReadRef` that restricts reads
     /// to the given range of the stream.
     pub fn range(&self, offset: u64, size: u64) -> ReadCacheRange<'_, R> {
@@ -53,19 +375,18 @@ impl<R: Read + Seek> ReadCache<R> {
 
     /// Free buffers used by the cache.
     pub fn clear(&mut self) {
-        self.cache.borrow_mut().bufs.clear();
+        self.cache.get_mut().clear();
     }
 
     /// Unwrap this `ReadCache<R>`, returning the underlying reader.
     pub fn into_inner(self) -> R {
-        self.cache.into_inner().read
+        self.cache.into_inner().stream.read
     }
 }
 
 impl<'a, R: Read + Seek> ReadRef<'a> for &'a ReadCache<R> {
     fn len(self) -> Result<u64, ()> {
-        let cache = &mut *self.cache.borrow_mut();
-        cache.read.seek(SeekFrom::End(0)).map_err(|_| ())
+        self.cache.borrow_mut().stream.len()
     }
 
     fn read_bytes_at(self, offset: u64, size: u64) -> Result<&'a [u8], ()> {
@@ -73,57 +394,19 @@ impl<'a, R: Read + Seek> ReadRef<'a> for &'a ReadCache<R> {
             return Ok(&[]);
         }
         let cache = &mut *self.cache.borrow_mut();
-        let buf = match cache.bufs.entry((offset, size)) {
-            Entry::Occupied(entry) => entry.into_mut(),
-            Entry::Vacant(entry) => {
-                let size = size.try_into().map_err(|_| ())?;
-                cache.read.seek(SeekFrom::Start(offset)).map_err(|_| ())?;
-                let mut bytes = vec![0; size].into_boxed_slice();
-                cache.read.read_exact(&mut bytes).map_err(|_| ())?;
-                entry.insert(bytes)
-            }
-        };
+        let buf = cache.read_bytes_at(offset, size)?;
         // Extend the lifetime to that of self.
-        // This is OK because we never mutate or remove entries.
+        // This is OK because we never mutate entries, and only remove them
+        // when self is mutably borrowed.
         Ok(unsafe { mem::transmute::<&[u8], &[u8]>(buf) })
     }
 
     fn read_bytes_at_until(self, range: Range<u64>, delimiter: u8) -> Result<&'a [u8], ()> {
         let cache = &mut *self.cache.borrow_mut();
-        let buf = match cache.strings.entry((range.start, delimiter)) {
-            Entry::Occupied(entry) => entry.into_mut(),
-            Entry::Vacant(entry) => {
-                cache
-                    .read
-                    .seek(SeekFrom::Start(range.start))
-                    .map_err(|_| ())?;
-
-                let max_check: usize = (range.end - range.start).try_into().map_err(|_| ())?;
-                // Strings should be relatively small.
-                // TODO: make this configurable?
-                let max_check = max_check.min(4096);
-
-                let mut bytes = Vec::new();
-                let mut checked = 0;
-                loop {
-                    bytes.resize((checked + 256).min(max_check), 0);
-                    let read = cache.read.read(&mut bytes[checked..]).map_err(|_| ())?;
-                    if read == 0 {
-                        return Err(());
-                    }
-                    if let Some(len) = memchr::memchr(delimiter, &bytes[checked..][..read]) {
-                        bytes.truncate(checked + len);
-                        break entry.insert(bytes.into_boxed_slice());
-                    }
-                    checked += read;
-                    if checked >= max_check {
-                        return Err(());
-                    }
-                }
-            }
-        };
+        let buf = cache.read_bytes_at_until(range, delimiter)?;
         // Extend the lifetime to that of self.
-        // This is OK because we never mutate or remove entries.
+        // This is OK because we never mutate entries, and only remove them
+        // when self is mutably borrowed.
         Ok(unsafe { mem::transmute::<&[u8], &[u8]>(buf) })
     }
 }
@@ -156,27 +439,342 @@ impl<'a, R: Read + Seek> ReadRef<'a> for ReadCacheRange<'a, R> {
         Ok(self.size)
     }
 
+    fn read_bytes_at(self, offset: u64, size: u64) -> Result<&'a [u8], ()> {
+        range_read_bytes_at(self.r, self.offset, self.size, offset, size)
+    }
+
+    fn read_bytes_at_until(self, range: Range<u64>, delimiter: u8) -> Result<&'a [u8], ()> {
+        range_read_bytes_at_until(self.r, self.offset, self.size, range, delimiter)
+    }
+}
+
+/// An implementation of `ReadRef` for data in a stream that implements
+/// `Read + Seek`, which can be shared between threads.
+///
+/// This is the same as [`ReadCache`], except that the cache is protected by
+/// a mutex instead of a `RefCell`, so it is `Sync` if the stream is `Send`.
+/// This allows a single stream to be used for parsing from multiple threads.
+/// Reads from the stream are serialized, but reads of data that is already
+/// cached only hold the lock briefly.
+#[derive(Debug)]
+pub struct SharedReadCache<R: Read + Seek> {
+    cache: Mutex<ReadCacheInternal<R>>,
+}
+
+impl<R: Read + Seek> SharedReadCache<R> {
+    /// Create an empty `SharedReadCache` for the given stream.
+    pub fn new(read: R) -> Self {
+        Self::with_size_limit(read, usize::MAX)
+    }
+
+    /// Create an empty `SharedReadCache` for the given stream, with a limit on the
+    /// total size of the cached data.
+    ///
+    /// See [`ReadCache::with_size_limit`].
+    pub fn with_size_limit(read: R, size_limit: usize) -> Self {
+        SharedReadCache {
+            cache: Mutex::new(ReadCacheInternal::new(read, size_limit)),
+        }
+    }
+
+    /// Set the size of each read from the stream when reading a string.
+    ///
+    /// See [`ReadCache::set_chunk_size`].
+    pub fn set_chunk_size(&mut self, chunk_size: usize) {
+        self.get_mut().set_chunk_size(chunk_size);
+    }
+
+    /// Set the minimum size of each read from the stream.
+    ///
+    /// See [`ReadCache::set_read_ahead`].
+    pub fn set_read_ahead(&mut self, read_ahead: usize) {
+        self.get_mut().set_read_ahead(read_ahead);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ReadCacheInternal<R>> {
+        // A poisoned lock means a read panicked, but the cache is still consistent.
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn get_mut(&mut self) -> &mut ReadCacheInternal<R> {
+        self.cache.get_mut().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Return the total size of the cached data, including the read-ahead window.
+    pub fn size(&self) -> usize {
+        self.lock().size()
+    }
+
+    /// Remove the least recently used entries until the total size of the cached
+    /// data is at most `size`.
+    ///
+    /// See [`ReadCache::trim`].
+    pub fn trim(&mut self, size: usize) {
+        self.get_mut().trim(size);
+    }
+
+    /// Return an implementation of `ReadRef` that restricts reads
+    /// to the given range of the stream.
+    pub fn range(&self, offset: u64, size: u64) -> SharedReadCacheRange<'_, R> {
+        SharedReadCacheRange {
+            r: self,
+            offset,
+            size,
+        }
+    }
+
+    /// Free buffers used by the cache.
+    pub fn clear(&mut self) {
+        self.get_mut().clear();
+    }
+
+    /// Unwrap this `SharedReadCache<R>`, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.cache
+            .into_inner()
+            .unwrap_or_else(|e| e.into_inner())
+            .stream
+            .read
+    }
+}
+
+impl<'a, R: Read + Seek> ReadRef<'a> for &'a SharedReadCache<R> {
+    fn len(self) -> Result<u64, ()> {
+        self.lock().stream.len()
+    }
+
     fn read_bytes_at(self, offset: u64, size: u64) -> Result<&'a [u8], ()> {
         if size == 0 {
             return Ok(&[]);
         }
-        let end = offset.checked_add(size).ok_or(())?;
-        if end > self.size {
-            return Err(());
-        }
-        let r_offset = self.offset.checked_add(offset).ok_or(())?;
-        self.r.read_bytes_at(r_offset, size)
+        let mut cache = self.lock();
+        let buf = cache.read_bytes_at(offset, size)?;
+        // Extend the lifetime to that of self.
+        // This is OK because we never mutate entries, and only remove them
+        // when self is mutably borrowed.
+        Ok(unsafe { mem::transmute::<&[u8], &[u8]>(buf) })
     }
 
     fn read_bytes_at_until(self, range: Range<u64>, delimiter: u8) -> Result<&'a [u8], ()> {
-        let r_start = self.offset.checked_add(range.start).ok_or(())?;
-        let r_end = self.offset.checked_add(range.end).ok_or(())?;
-        let bytes = self.r.read_bytes_at_until(r_start..r_end, delimiter)?;
-        let size = bytes.len().try_into().map_err(|_| ())?;
-        let end = range.start.checked_add(size).ok_or(())?;
-        if end > self.size {
-            return Err(());
+        let mut cache = self.lock();
+        let buf = cache.read_bytes_at_until(range, delimiter)?;
+        // Extend the lifetime to that of self.
+        // This is OK because we never mutate entries, and only remove them
+        // when self is mutably borrowed.
+        Ok(unsafe { mem::transmute::<&[u8], &[u8]>(buf) })
+    }
+}
+
+/// An implementation of `ReadRef` for a range of data in a stream that
+/// implements `Read + Seek`.
+///
+/// Shares an underlying `SharedReadCache` with a lifetime of `'a`.
+#[derive(Debug)]
+pub struct SharedReadCacheRange<'a, R: Read + Seek> {
+    r: &'a SharedReadCache<R>,
+    offset: u64,
+    size: u64,
+}
+
+impl<'a, R: Read + Seek> Clone for SharedReadCacheRange<'a, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, R: Read + Seek> Copy for SharedReadCacheRange<'a, R> {}
+
+impl<'a, R: Read + Seek> ReadRef<'a> for SharedReadCacheRange<'a, R> {
+    fn len(self) -> Result<u64, ()> {
+        Ok(self.size)
+    }
+
+    fn read_bytes_at(self, offset: u64, size: u64) -> Result<&'a [u8], ()> {
+        range_read_bytes_at(self.r, self.offset, self.size, offset, size)
+    }
+
+    fn read_bytes_at_until(self, range: Range<u64>, delimiter: u8) -> Result<&'a [u8], ()> {
+        range_read_bytes_at_until(self.r, self.offset, self.size, range, delimiter)
+    }
+}
+
+fn range_read_bytes_at<'a, T: ReadRef<'a>>(
+    r: T,
+    r_offset: u64,
+    r_size: u64,
+    offset: u64,
+    size: u64,
+) -> Result<&'a [u8], ()> {
+    if size == 0 {
+        return Ok(&[]);
+    }
+    let end = offset.checked_add(size).ok_or(())?;
+    if end > r_size {
+        return Err(());
+    }
+    let r_offset = r_offset.checked_add(offset).ok_or(())?;
+    r.read_bytes_at(r_offset, size)
+}
+
+fn range_read_bytes_at_until<'a, T: ReadRef<'a>>(
+    r: T,
+    r_offset: u64,
+    r_size: u64,
+    range: Range<u64>,
+    delimiter: u8,
+) -> Result<&'a [u8], ()> {
+    let r_start = r_offset.checked_add(range.start).ok_or(())?;
+    let r_end = r_offset.checked_add(range.end).ok_or(())?;
+    let bytes = r.read_bytes_at_until(r_start..r_end, delimiter)?;
+    let size = bytes.len().try_into().map_err(|_| ())?;
+    let end = range.start.checked_add(size).ok_or(())?;
+    if end > r_size {
+        return Err(());
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn trim() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut cache = ReadCache::new(Cursor::new(data));
+        assert_eq!((&cache).read_bytes_at(0, 16).unwrap()[0], 0);
+        assert_eq!((&cache).read_bytes_at(16, 16).unwrap()[0], 16);
+        assert_eq!((&cache).read_bytes_at(0, 16).unwrap()[0], 0);
+        assert_eq!(cache.size(), 32);
+
+        // The least recently used entry is removed.
+        cache.trim(24);
+        assert_eq!(cache.size(), 16);
+        let internal = cache.cache.get_mut();
+        assert!(internal.bufs.contains_key(&(0, 16)));
+        assert!(!internal.bufs.contains_key(&(16, 16)));
+
+        cache.clear();
+        assert_eq!(cache.size(), 0);
+    }
+
+    #[test]
+    fn size_limit() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut cache = ReadCache::with_size_limit(Cursor::new(data), 24);
+        assert_eq!((&cache).read_bytes_at(0, 16).unwrap()[0], 0);
+        // Cached data does not count again.
+        assert_eq!((&cache).read_bytes_at(0, 16).unwrap()[0], 0);
+        // Reads that exceed the limit fail while the earlier data is borrowed.
+        assert!((&cache).read_bytes_at(16, 16).is_err());
+        assert!((&cache).read_bytes_at_until(16..256, 40).is_err());
+        assert_eq!((&cache).read_bytes_at_until(16..256, 20).unwrap().len(), 4);
+        assert_eq!(cache.size(), 20);
+
+        cache.trim(0);
+        assert_eq!((&cache).read_bytes_at(16, 16).unwrap()[0], 16);
+    }
+
+    #[test]
+    fn shared() {
+        fn assert_sync<T: Sync>(_: &T) {}
+
+        let data: Vec<u8> = (0..=255).collect();
+        let cache = std::sync::Arc::new(SharedReadCache::new(Cursor::new(data)));
+        assert_sync(&*cache);
+        let threads = (0..4u8)
+            .map(|i| {
+                let cache = cache.clone();
+                std::thread::spawn(move || {
+                    let offset = u64::from(i) * 16;
+                    assert_eq!(
+                        (&*cache).read_bytes_at(offset, 4).unwrap(),
+                        [i * 16, i * 16 + 1, i * 16 + 2, i * 16 + 3]
+                    );
+                    assert_eq!(
+                        cache.range(offset, 16).read_bytes_at(15, 1).unwrap(),
+                        [i * 16 + 15]
+                    );
+                })
+            })
+            .collect::<Vec<_>>();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert_eq!(cache.size(), 4 * 5);
+    }
+
+    #[derive(Debug)]
+    struct CountingReader {
+        inner: Cursor<Vec<u8>>,
+        reads: usize,
+    }
+
+    impl Read for CountingReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.reads += 1;
+            self.inner.read(buf)
         }
-        Ok(bytes)
+    }
+
+    impl Seek for CountingReader {
+        fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+            self.inner.seek(pos)
+        }
+    }
+
+    #[test]
+    fn read_ahead() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut cache = ReadCache::new(CountingReader {
+            inner: Cursor::new(data),
+            reads: 0,
+        });
+        cache.set_read_ahead(64);
+        assert_eq!((&cache).read_bytes_at(0, 4).unwrap(), [0, 1, 2, 3]);
+        assert_eq!((&cache).read_bytes_at(60, 4).unwrap(), [60, 61, 62, 63]);
+        assert_eq!(
+            (&cache).read_bytes_at_until(40..256, 48).unwrap(),
+            [40, 41, 42, 43, 44, 45, 46, 47]
+        );
+        assert_eq!(cache.cache.get_mut().stream.read.reads, 1);
+
+        // Reads outside the window or larger than the window are not combined.
+        assert_eq!((&cache).read_bytes_at(62, 4).unwrap(), [62, 63, 64, 65]);
+        assert_eq!((&cache).read_bytes_at(128, 64).unwrap().len(), 64);
+        assert_eq!(cache.cache.get_mut().stream.read.reads, 3);
+
+        // The window is truncated at the end of the stream.
+        assert_eq!(
+            (&cache).read_bytes_at(252, 4).unwrap(),
+            [252, 253, 254, 255]
+        );
+        assert!((&cache).read_bytes_at(254, 4).is_err());
+
+        let data: Vec<u8> = (0..=255).collect();
+        let mut cache = ReadCache::new(CountingReader {
+            inner: Cursor::new(data),
+            reads: 0,
+        });
+        cache.set_chunk_size(8);
+        assert_eq!((&cache).read_bytes_at_until(0..256, 20).unwrap().len(), 20);
+        assert_eq!(cache.cache.get_mut().stream.read.reads, 3);
+    }
+
+    #[test]
+    fn read_ahead_size_limit() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut cache = ReadCache::with_size_limit(Cursor::new(data), 100);
+        cache.set_read_ahead(64);
+        assert_eq!((&cache).read_bytes_at(0, 4).unwrap(), [0, 1, 2, 3]);
+        assert_eq!((&cache).read_bytes_at(8, 4).unwrap(), [8, 9, 10, 11]);
+        assert_eq!(cache.size(), 4 + 4 + 64);
+
+        // The window is discarded to make room, and there is no room for a new window.
+        assert_eq!((&cache).read_bytes_at(200, 40).unwrap()[0], 200);
+        assert_eq!(cache.size(), 4 + 4 + 40);
+        assert!(cache.cache.get_mut().stream.window.is_empty());
+        assert!((&cache).read_bytes_at(100, 60).is_err());
     }
 }
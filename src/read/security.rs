@@ -0,0 +1,144 @@
+#[cfg(any(feature = "elf", feature = "macho", feature = "pe"))]
+use crate::read::{Object, ObjectSymbol};
+
+/// The kind of read-only relocation protection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[non_exhaustive]
+pub enum Relro {
+    /// There is no `PT_GNU_RELRO` segment.
+    None,
+    /// There is a `PT_GNU_RELRO` segment, but lazy binding is enabled, so the
+    /// PLT GOT remains writable.
+    Partial,
+    /// There is a `PT_GNU_RELRO` segment and immediate binding is required.
+    Full,
+}
+
+/// The hardening features that are enabled for a file.
+///
+/// This is returned by [`Object::security_features`](crate::read::Object::security_features).
+///
+/// Each feature is `None` if it is not applicable to the file format or
+/// could not be determined.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SecurityFeatures {
+    pub(crate) nx: Option<bool>,
+    pub(crate) position_independent: Option<bool>,
+    pub(crate) relro: Option<Relro>,
+    pub(crate) control_flow_guard: Option<bool>,
+    pub(crate) branch_protection: Option<bool>,
+    pub(crate) pointer_authentication: Option<bool>,
+    pub(crate) shadow_stack: Option<bool>,
+    pub(crate) stack_canary: Option<bool>,
+    pub(crate) signed: Option<bool>,
+}
+
+impl SecurityFeatures {
+    /// Return true if the stack and data are not executable.
+    ///
+    /// This is `PT_GNU_STACK` for ELF, `IMAGE_DLLCHARACTERISTICS_NX_COMPAT` for PE,
+    /// and the absence of `MH_ALLOW_STACK_EXECUTION` for Mach-O.
+    #[inline]
+    pub fn nx(&self) -> Option<bool> {
+        self.nx
+    }
+
+    /// Return true if the image can be loaded at a random address.
+    ///
+    /// This is `ET_DYN` for ELF, `IMAGE_DLLCHARACTERISTICS_DYNAMIC_BASE` for PE,
+    /// and `MH_PIE` for Mach-O.
+    #[inline]
+    pub fn position_independent(&self) -> Option<bool> {
+        self.position_independent
+    }
+
+    /// The read-only relocation protection.
+    ///
+    /// This is only determined for ELF.
+    #[inline]
+    pub fn relro(&self) -> Option<Relro> {
+        self.relro
+    }
+
+    /// Return true if Control Flow Guard is enabled.
+    ///
+    /// This is `IMAGE_DLLCHARACTERISTICS_GUARD_CF` for PE.
+    #[inline]
+    pub fn control_flow_guard(&self) -> Option<bool> {
+        self.control_flow_guard
+    }
+
+    /// Return true if indirect branch targets are protected.
+    ///
+    /// This is the BTI or IBT feature in the ELF GNU property note.
+    #[inline]
+    pub fn branch_protection(&self) -> Option<bool> {
+        self.branch_protection
+    }
+
+    /// Return true if return addresses are protected by pointer authentication.
+    ///
+    /// This is the PAC feature in the ELF GNU property note, or the arm64e
+    /// CPU subtype for Mach-O.
+    #[inline]
+    pub fn pointer_authentication(&self) -> Option<bool> {
+        self.pointer_authentication
+    }
+
+    /// Return true if return addresses are protected by a shadow stack.
+    ///
+    /// This is the SHSTK feature in the ELF GNU property note.
+    #[inline]
+    pub fn shadow_stack(&self) -> Option<bool> {
+        self.shadow_stack
+    }
+
+    /// Return true if the file references the stack protector runtime.
+    ///
+    /// This is determined from the symbol names, so it is only available if
+    /// the file has symbols.
+    #[inline]
+    pub fn stack_canary(&self) -> Option<bool> {
+        self.stack_canary
+    }
+
+    /// Return true if the file contains code signing data.
+    ///
+    /// This is determined using [`Object::code_signature`](crate::read::Object::code_signature).
+    #[inline]
+    pub fn signed(&self) -> Option<bool> {
+        self.signed
+    }
+}
+
+/// Determine whether a file references the stack protector runtime.
+///
+/// Returns `None` if the file has no symbols.
+#[cfg(any(feature = "elf", feature = "macho", feature = "pe"))]
+pub(crate) fn stack_canary<'data: 'file, 'file, O: Object<'data, 'file>>(
+    file: &'file O,
+) -> Option<bool> {
+    let mut has_symbols = false;
+    for symbol in file.symbols().chain(file.dynamic_symbols()) {
+        has_symbols = true;
+        // Mach-O and 32-bit COFF symbol names have an extra leading underscore.
+        if let Ok(
+            b"__stack_chk_fail"
+            | b"__stack_chk_guard"
+            | b"___stack_chk_fail"
+            | b"___stack_chk_guard"
+            | b"__security_cookie"
+            | b"___security_cookie",
+        ) = symbol.name_bytes()
+        {
+            return Some(true);
+        }
+    }
+    if has_symbols {
+        Some(false)
+    } else {
+        None
+    }
+}
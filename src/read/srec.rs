@@ -0,0 +1,372 @@
+//! Support for reading Motorola S-record files.
+//!
+//! Provides `SrecImage`, which decodes the records of an S-record file into
+//! contiguous blocks of data, and `SrecFile` and related types which implement
+//! the `Object` trait for the decoded data.
+//!
+//! Each block of data is exposed as a loadable segment. S-record files have no
+//! sections, symbols or relocations.
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::{iter, slice};
+
+use crate::read::util::DataBlock;
+use crate::read::{
+    self, Architecture, Error, Export, FileFlags, Import, NoComdat, NoDynamicRelocationIterator,
+    NoSection, NoSymbol, NoSymbolTable, Object, ObjectKind, ObjectSegment, Permissions, ReadError,
+    Result, SectionIndex, SegmentFlags, SegmentKind, SymbolIndex,
+};
+
+/// The decoded contents of an S-record file.
+///
+/// S-record files are text, so the data must be decoded before it can be
+/// accessed through the `Object` API. Use [`SrecImage::file`] to obtain a
+/// `SrecFile` that borrows the decoded data.
+#[derive(Debug, Clone, Default)]
+pub struct SrecImage {
+    header: Vec<u8>,
+    segments: Vec<DataBlock>,
+    entry: u64,
+}
+
+impl SrecImage {
+    /// Decode the records of an S-record file.
+    ///
+    /// This supports the S19, S28 and S37 variants, and files that mix them.
+    /// Records with adjacent addresses are combined into a single segment, and
+    /// the segments are sorted by address.
+    ///
+    /// Returns an error if a record is invalid, if records contain overlapping
+    /// data, if a record count does not match the number of data records, or if
+    /// the termination record is missing.
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let mut image = SrecImage::default();
+        let mut data_records = 0u64;
+        let mut terminated = false;
+        for line in data.split(|c| *c == b'\n') {
+            let line = read::util::trim_ascii(line);
+            if line.is_empty() {
+                continue;
+            }
+            if terminated {
+                return Err(Error("S-record data after termination record"));
+            }
+            let (kind, record) = match *line {
+                [b'S', kind, ref record @ ..] => (kind, record),
+                _ => return Err(Error("Missing S-record start code")),
+            };
+            let address_size = match kind {
+                b'0' | b'1' | b'5' | b'9' => 2,
+                b'2' | b'6' | b'8' => 3,
+                b'3' | b'7' => 4,
+                _ => return Err(Error("Unknown S-record type")),
+            };
+            let record = decode_record(record)?;
+            if record.len() < address_size + 2 {
+                return Err(Error("Invalid S-record length"));
+            }
+            let address = record[1..][..address_size]
+                .iter()
+                .fold(0u64, |address, byte| address << 8 | u64::from(*byte));
+            let payload = &record[1 + address_size..record.len() - 1];
+            match kind {
+                b'0' => image.header = payload.to_vec(),
+                b'1' | b'2' | b'3' => {
+                    read::util::add_data_block(&mut image.segments, address, payload);
+                    data_records += 1;
+                }
+                b'5' | b'6' => {
+                    if address != data_records {
+                        return Err(Error("Invalid S-record count"));
+                    }
+                }
+                _ => {
+                    image.entry = address;
+                    terminated = true;
+                }
+            }
+        }
+        if !terminated {
+            return Err(Error("Missing S-record termination record"));
+        }
+
+        image.segments = read::util::merge_data_blocks(image.segments)
+            .read_error("Overlapping S-record data records")?;
+        Ok(image)
+    }
+
+    /// Return the data of the header record, or an empty slice if there is no header.
+    ///
+    /// This is usually a module name or description, but its contents are not
+    /// standardized.
+    #[inline]
+    pub fn header(&self) -> &[u8] {
+        &self.header
+    }
+
+    /// Return a `SrecFile` for accessing the decoded data.
+    #[inline]
+    pub fn file(&self) -> SrecFile<'_> {
+        SrecFile { image: self }
+    }
+}
+
+/// Return true if the data starts with an S-record.
+///
+/// This checks the start code, the record type and the hex digits of the smallest
+/// possible record.
+pub(crate) fn is_srec(data: &[u8]) -> bool {
+    match data {
+        [b'S', b'0'..=b'9', digits @ ..] => digits
+            .get(..8)
+            .map_or(false, |digits| digits.iter().all(u8::is_ascii_hexdigit)),
+        _ => false,
+    }
+}
+
+/// Decode the hex digits of a record and validate its length and checksum.
+///
+/// The returned bytes include the byte count, address and checksum.
+fn decode_record(record: &[u8]) -> Result<Vec<u8>> {
+    if record.len() % 2 != 0 {
+        return Err(Error("Invalid S-record length"));
+    }
+    let bytes = read::util::decode_hex(record).read_error("Invalid S-record digit")?;
+    if bytes.is_empty() || bytes.len() != usize::from(bytes[0]) + 1 {
+        return Err(Error("Invalid S-record length"));
+    }
+    if bytes.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte)) != 0xff {
+        return Err(Error("Invalid S-record checksum"));
+    }
+    Ok(bytes)
+}
+
+/// An S-record file.
+///
+/// This borrows the data decoded by [`SrecImage::parse`].
+#[derive(Debug, Clone, Copy)]
+pub struct SrecFile<'data> {
+    image: &'data SrecImage,
+}
+
+impl<'data> read::private::Sealed for SrecFile<'data> {}
+
+impl<'data, 'file> Object<'data, 'file> for SrecFile<'data>
+where
+    'data: 'file,
+{
+    type Segment = SrecSegment<'data, 'file>;
+    type SegmentIterator = SrecSegmentIterator<'data, 'file>;
+    type Section = NoSection;
+    type SectionIterator = iter::Empty<NoSection>;
+    type Comdat = NoComdat;
+    type ComdatIterator = iter::Empty<NoComdat>;
+    type Symbol = NoSymbol;
+    type SymbolIterator = iter::Empty<NoSymbol>;
+    type SymbolTable = NoSymbolTable;
+    type DynamicRelocationIterator = NoDynamicRelocationIterator;
+
+    #[inline]
+    fn architecture(&self) -> Architecture {
+        Architecture::Unknown
+    }
+
+    #[inline]
+    fn is_little_endian(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn is_64(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn kind(&self) -> ObjectKind {
+        ObjectKind::Unknown
+    }
+
+    fn segments(&'file self) -> Self::SegmentIterator {
+        SrecSegmentIterator {
+            segments: self.image.segments.iter(),
+            marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn section_by_name_bytes(&'file self, _section_name: &[u8]) -> Option<NoSection> {
+        None
+    }
+
+    #[inline]
+    fn section_by_index(&'file self, _index: SectionIndex) -> Result<NoSection> {
+        Err(Error("Invalid S-record section index"))
+    }
+
+    #[inline]
+    fn sections(&'file self) -> Self::SectionIterator {
+        iter::empty()
+    }
+
+    #[inline]
+    fn comdats(&'file self) -> Self::ComdatIterator {
+        iter::empty()
+    }
+
+    #[inline]
+    fn symbol_by_index(&'file self, _index: SymbolIndex) -> Result<NoSymbol> {
+        Err(Error("Invalid S-record symbol index"))
+    }
+
+    #[inline]
+    fn symbols(&'file self) -> Self::SymbolIterator {
+        iter::empty()
+    }
+
+    #[inline]
+    fn symbol_table(&'file self) -> Option<NoSymbolTable> {
+        None
+    }
+
+    #[inline]
+    fn dynamic_symbols(&'file self) -> Self::SymbolIterator {
+        iter::empty()
+    }
+
+    #[inline]
+    fn dynamic_symbol_table(&'file self) -> Option<NoSymbolTable> {
+        None
+    }
+
+    #[inline]
+    fn dynamic_relocations(&self) -> Option<NoDynamicRelocationIterator> {
+        None
+    }
+
+    fn imports(&self) -> Result<Vec<Import<'data>>> {
+        Ok(Vec::new())
+    }
+
+    fn exports(&self) -> Result<Vec<Export<'data>>> {
+        Ok(Vec::new())
+    }
+
+    #[inline]
+    fn has_debug_symbols(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn relative_address_base(&self) -> u64 {
+        0
+    }
+
+    /// Returns the address from the termination record.
+    #[inline]
+    fn entry(&self) -> u64 {
+        self.image.entry
+    }
+
+    #[inline]
+    fn flags(&self) -> FileFlags {
+        FileFlags::None
+    }
+}
+
+/// An iterator over the segments of a `SrecFile`.
+#[derive(Debug)]
+pub struct SrecSegmentIterator<'data, 'file> {
+    segments: slice::Iter<'data, DataBlock>,
+    marker: PhantomData<&'file ()>,
+}
+
+impl<'data, 'file> Iterator for SrecSegmentIterator<'data, 'file> {
+    type Item = SrecSegment<'data, 'file>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.segments.next().map(|segment| SrecSegment {
+            segment,
+            marker: PhantomData,
+        })
+    }
+}
+
+/// A segment of a `SrecFile`.
+///
+/// This is a block of contiguous data from one or more data records.
+#[derive(Debug)]
+pub struct SrecSegment<'data, 'file> {
+    segment: &'data DataBlock,
+    marker: PhantomData<&'file ()>,
+}
+
+impl<'data, 'file> read::private::Sealed for SrecSegment<'data, 'file> {}
+
+impl<'data, 'file> ObjectSegment<'data> for SrecSegment<'data, 'file> {
+    #[inline]
+    fn address(&self) -> u64 {
+        self.segment.address
+    }
+
+    #[inline]
+    fn physical_address(&self) -> u64 {
+        self.address()
+    }
+
+    #[inline]
+    fn size(&self) -> u64 {
+        self.segment.data.len() as u64
+    }
+
+    #[inline]
+    fn align(&self) -> u64 {
+        1
+    }
+
+    /// Returns `(0, 0)` because the decoded data does not correspond to a
+    /// range of the file.
+    #[inline]
+    fn file_range(&self) -> (u64, u64) {
+        (0, 0)
+    }
+
+    #[inline]
+    fn data(&self) -> Result<&'data [u8]> {
+        Ok(&self.segment.data)
+    }
+
+    fn data_range(&self, address: u64, size: u64) -> Result<Option<&'data [u8]>> {
+        Ok(read::util::data_range(
+            &self.segment.data,
+            self.segment.address,
+            address,
+            size,
+        ))
+    }
+
+    #[inline]
+    fn name_bytes(&self) -> Result<Option<&[u8]>> {
+        Ok(None)
+    }
+
+    #[inline]
+    fn name(&self) -> Result<Option<&str>> {
+        Ok(None)
+    }
+
+    #[inline]
+    fn kind(&self) -> SegmentKind {
+        SegmentKind::Load
+    }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        Permissions::default()
+    }
+
+    #[inline]
+    fn flags(&self) -> SegmentFlags {
+        SegmentFlags::None
+    }
+}
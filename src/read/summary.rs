@@ -0,0 +1,291 @@
+use alloc::string::String;
+
+use crate::read::{ObjectSection, ObjectSegment, ObjectSymbol};
+use crate::{
+    Permissions, SectionFlags, SectionIndex, SectionKind, SegmentFlags, SegmentKind, SymbolFlags,
+    SymbolIndex, SymbolKind, SymbolScope, SymbolSection,
+};
+
+/// An owned summary of the properties of a segment.
+///
+/// This does not borrow the file, so it can be stored or serialized
+/// independently of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SegmentSummary {
+    name: Option<String>,
+    address: u64,
+    size: u64,
+    align: u64,
+    file_range: (u64, u64),
+    kind: SegmentKind,
+    permissions: Permissions,
+    flags: SegmentFlags,
+}
+
+impl SegmentSummary {
+    /// Construct a summary of the given segment.
+    ///
+    /// Invalid UTF-8 in the segment name is replaced, and a name that can't be
+    /// read is treated as no name.
+    pub fn new<'data, S: ObjectSegment<'data>>(segment: &S) -> Self {
+        SegmentSummary {
+            name: segment
+                .name_bytes()
+                .ok()
+                .flatten()
+                .map(|name| String::from_utf8_lossy(name).into_owned()),
+            address: segment.address(),
+            size: segment.size(),
+            align: segment.align(),
+            file_range: segment.file_range(),
+            kind: segment.kind(),
+            permissions: segment.permissions(),
+            flags: segment.flags(),
+        }
+    }
+
+    /// The name of the segment, if it has one.
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The virtual address of the segment.
+    #[inline]
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// The size of the segment in memory.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The alignment of the segment in memory.
+    #[inline]
+    pub fn align(&self) -> u64 {
+        self.align
+    }
+
+    /// The offset and size of the segment in the file.
+    #[inline]
+    pub fn file_range(&self) -> (u64, u64) {
+        self.file_range
+    }
+
+    /// The kind of the segment.
+    #[inline]
+    pub fn kind(&self) -> SegmentKind {
+        self.kind
+    }
+
+    /// The memory permissions of the segment.
+    #[inline]
+    pub fn permissions(&self) -> Permissions {
+        self.permissions
+    }
+
+    /// The format specific flags of the segment.
+    #[inline]
+    pub fn flags(&self) -> SegmentFlags {
+        self.flags
+    }
+}
+
+/// An owned summary of the properties of a section.
+///
+/// This does not borrow the file, so it can be stored or serialized
+/// independently of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SectionSummary {
+    index: SectionIndex,
+    name: String,
+    segment_name: Option<String>,
+    address: u64,
+    size: u64,
+    align: u64,
+    file_range: Option<(u64, u64)>,
+    kind: SectionKind,
+    flags: SectionFlags,
+}
+
+impl SectionSummary {
+    /// Construct a summary of the given section.
+    ///
+    /// Invalid UTF-8 in the names is replaced, and names that can't be read are
+    /// treated as empty.
+    pub fn new<'data, S: ObjectSection<'data>>(section: &S) -> Self {
+        SectionSummary {
+            index: section.index(),
+            name: section
+                .name_bytes()
+                .map(|name| String::from_utf8_lossy(name).into_owned())
+                .unwrap_or_default(),
+            segment_name: section
+                .segment_name_bytes()
+                .ok()
+                .flatten()
+                .map(|name| String::from_utf8_lossy(name).into_owned()),
+            address: section.address(),
+            size: section.size(),
+            align: section.align(),
+            file_range: section.file_range(),
+            kind: section.kind(),
+            flags: section.flags(),
+        }
+    }
+
+    /// The index of the section.
+    #[inline]
+    pub fn index(&self) -> SectionIndex {
+        self.index
+    }
+
+    /// The name of the section.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The name of the segment containing the section, if known.
+    ///
+    /// This is only used for Mach-O.
+    #[inline]
+    pub fn segment_name(&self) -> Option<&str> {
+        self.segment_name.as_deref()
+    }
+
+    /// The virtual address of the section.
+    #[inline]
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// The size of the section in memory.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The alignment of the section in memory.
+    #[inline]
+    pub fn align(&self) -> u64 {
+        self.align
+    }
+
+    /// The offset and size of the section in the file, if it has file data.
+    #[inline]
+    pub fn file_range(&self) -> Option<(u64, u64)> {
+        self.file_range
+    }
+
+    /// The kind of the section.
+    #[inline]
+    pub fn kind(&self) -> SectionKind {
+        self.kind
+    }
+
+    /// The format specific flags of the section.
+    #[inline]
+    pub fn flags(&self) -> SectionFlags {
+        self.flags
+    }
+}
+
+/// An owned summary of the properties of a symbol.
+///
+/// This does not borrow the file, so it can be stored or serialized
+/// independently of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SymbolSummary {
+    index: SymbolIndex,
+    name: String,
+    address: u64,
+    size: u64,
+    kind: SymbolKind,
+    section: SymbolSection,
+    scope: SymbolScope,
+    weak: bool,
+    flags: SymbolFlags<SectionIndex, SymbolIndex>,
+}
+
+impl SymbolSummary {
+    /// Construct a summary of the given symbol.
+    ///
+    /// Invalid UTF-8 in the name is replaced, and a name that can't be read is
+    /// treated as empty.
+    pub fn new<'data, S: ObjectSymbol<'data>>(symbol: &S) -> Self {
+        SymbolSummary {
+            index: symbol.index(),
+            name: symbol
+                .name_bytes()
+                .map(|name| String::from_utf8_lossy(name).into_owned())
+                .unwrap_or_default(),
+            address: symbol.address(),
+            size: symbol.size(),
+            kind: symbol.kind(),
+            section: symbol.section(),
+            scope: symbol.scope(),
+            weak: symbol.is_weak(),
+            flags: symbol.flags(),
+        }
+    }
+
+    /// The index of the symbol.
+    #[inline]
+    pub fn index(&self) -> SymbolIndex {
+        self.index
+    }
+
+    /// The name of the symbol.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The address of the symbol.
+    #[inline]
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// The size of the symbol.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The kind of the symbol.
+    #[inline]
+    pub fn kind(&self) -> SymbolKind {
+        self.kind
+    }
+
+    /// The section where the symbol is defined.
+    #[inline]
+    pub fn section(&self) -> SymbolSection {
+        self.section
+    }
+
+    /// The visibility of the symbol.
+    #[inline]
+    pub fn scope(&self) -> SymbolScope {
+        self.scope
+    }
+
+    /// Return true if the symbol is weak.
+    #[inline]
+    pub fn is_weak(&self) -> bool {
+        self.weak
+    }
+
+    /// The format specific flags of the symbol.
+    #[inline]
+    pub fn flags(&self) -> SymbolFlags<SectionIndex, SymbolIndex> {
+        self.flags
+    }
+}
@@ -2,10 +2,11 @@ use alloc::borrow::Cow;
 use alloc::vec::Vec;
 
 use crate::read::{
-    self, Architecture, CodeView, ComdatKind, CompressedData, CompressedFileRange, Export,
-    FileFlags, Import, ObjectKind, ObjectMap, Relocation, Result, SectionFlags, SectionIndex,
-    SectionKind, SegmentFlags, SymbolFlags, SymbolIndex, SymbolKind, SymbolMap, SymbolMapName,
-    SymbolScope, SymbolSection,
+    self, Architecture, CodeSignature, CodeView, ComdatKind, CompressedData, CompressedFileRange,
+    Export, FileFlags, FunctionMapEntry, FunctionRange, Import, ObjectKind, ObjectMap, Permissions,
+    Relocation, Result, SectionFlags, SectionIndex, SectionKind, SecurityFeatures, SegmentFlags,
+    SegmentKind, SourceFile, SymbolFlags, SymbolIndex, SymbolKind, SymbolMap, SymbolMapName,
+    SymbolMapTables, SymbolScope, SymbolSection,
 };
 use crate::Endianness;
 
@@ -73,6 +74,15 @@ pub trait Object<'data: 'file, 'file>: read::private::Sealed {
     /// Get an iterator over the segments in the file.
     fn segments(&'file self) -> Self::SegmentIterator;
 
+    /// Get an iterator over all of the segments in the file.
+    ///
+    /// For ELF, this includes program headers of all types, such as `PT_DYNAMIC`,
+    /// `PT_NOTE` and `PT_TLS`, whereas `segments` only includes `PT_LOAD`.
+    /// For other file formats, this is the same as `segments`.
+    fn all_segments(&'file self) -> Self::SegmentIterator {
+        self.segments()
+    }
+
     /// Get the section named `section_name`, if such a section exists.
     ///
     /// If `section_name` starts with a '.' then it is treated as a system section name,
@@ -87,6 +97,9 @@ pub trait Object<'data: 'file, 'file>: read::private::Sealed {
     /// name. In this case, the first matching section will be used.
     ///
     /// This method skips over sections with invalid names.
+    ///
+    /// This searches all of the sections. Use [`read::SectionNameIndex`] instead
+    /// if many lookups are needed.
     fn section_by_name(&'file self, section_name: &str) -> Option<Self::Section> {
         self.section_by_name_bytes(section_name.as_bytes())
     }
@@ -126,6 +139,21 @@ pub trait Object<'data: 'file, 'file>: read::private::Sealed {
     /// For Mach-O files, this does not include STAB entries.
     fn symbols(&'file self) -> Self::SymbolIterator;
 
+    /// Get the first symbol in the symbol table with the given name.
+    ///
+    /// For formats with a symbol table, and with the `std` feature, this uses a
+    /// [`read::SymbolNameIndex`] that is constructed on the first call. Otherwise,
+    /// this searches the whole symbol table.
+    fn symbol_by_name(&'file self, symbol_name: &str) -> Option<Self::Symbol> {
+        self.symbol_by_name_bytes(symbol_name.as_bytes())
+    }
+
+    /// Like [`Self::symbol_by_name`], but allows names that are not UTF-8.
+    fn symbol_by_name_bytes(&'file self, symbol_name: &[u8]) -> Option<Self::Symbol> {
+        self.symbols()
+            .find(|symbol| symbol.name_bytes() == Ok(symbol_name))
+    }
+
     /// Get the dynamic linking symbol table, if any.
     ///
     /// Only ELF has a separate dynamic linking symbol table.
@@ -143,28 +171,121 @@ pub trait Object<'data: 'file, 'file>: read::private::Sealed {
     ///
     /// Symbol indices in these relocations refer to the dynamic symbol table.
     ///
-    /// Only ELF has dynamic relocations.
+    /// The relocation addresses are virtual addresses.
+    ///
+    /// For ELF, this includes the relocations in `SHT_REL`, `SHT_RELA` and `SHT_RELR`
+    /// sections. For PE, this is the base relocations. For Mach-O, this is the rebases
+    /// from either the dyld info or the chained fixups; binds are not included.
+    /// Relocations that only adjust for the load address of the image use
+    /// `RelocationKind::Rebase`.
     fn dynamic_relocations(&'file self) -> Option<Self::DynamicRelocationIterator>;
 
     /// Construct a map from addresses to symbol names.
     ///
     /// The map will only contain defined text and data symbols.
     /// The dynamic symbol table will only be used if there are no debugging symbols.
+    ///
+    /// This is equivalent to `symbol_map_from(SymbolMapTables::Preferred)`.
     fn symbol_map(&'file self) -> SymbolMap<SymbolMapName<'data>> {
+        self.symbol_map_from(SymbolMapTables::Preferred)
+    }
+
+    /// Construct a map from addresses to symbol names, using the given symbol tables.
+    ///
+    /// The map will only contain defined text and data symbols.
+    fn symbol_map_from(&'file self, tables: SymbolMapTables) -> SymbolMap<SymbolMapName<'data>> {
+        let symbol_table = self.symbol_table();
+        let dynamic_symbol_table = self.dynamic_symbol_table();
+        let all = tables == SymbolMapTables::All;
+        let tables = match tables {
+            SymbolMapTables::Preferred => [symbol_table.or(dynamic_symbol_table), None],
+            SymbolMapTables::Largest => {
+                let len = |table: &Option<Self::SymbolTable>| {
+                    table.as_ref().map_or(0, |table| table.symbols().count())
+                };
+                if len(&dynamic_symbol_table) > len(&symbol_table) {
+                    [dynamic_symbol_table, None]
+                } else {
+                    [symbol_table, None]
+                }
+            }
+            SymbolMapTables::All => [symbol_table, dynamic_symbol_table],
+        };
         let mut symbols = Vec::new();
-        if let Some(table) = self.symbol_table().or_else(|| self.dynamic_symbol_table()) {
+        for table in tables.iter().flatten() {
             for symbol in table.symbols() {
                 if !symbol.is_definition() {
                     continue;
                 }
                 if let Ok(name) = symbol.name() {
-                    symbols.push(SymbolMapName::new(symbol.address(), name));
+                    symbols.push(SymbolMapName::with_size(
+                        symbol.address(),
+                        symbol.size(),
+                        name,
+                    ));
                 }
             }
         }
+        if all {
+            // Remove symbols that are in both tables.
+            symbols.sort_unstable_by_key(|symbol| (symbol.address(), symbol.name()));
+            symbols.dedup();
+        }
         SymbolMap::new(symbols)
     }
 
+    /// Construct a map from addresses to function names and sizes.
+    ///
+    /// The map will only contain defined text symbols.
+    /// The dynamic symbol table will only be used if there are no debugging symbols.
+    ///
+    /// Symbols with a size of 0 (which is common for Mach-O and hand written assembly)
+    /// have their size inferred from the address of the next function, clamped to
+    /// the end of the containing section.
+    fn function_map(&'file self) -> SymbolMap<FunctionMapEntry<'data>> {
+        let mut functions = Vec::new();
+        if let Some(table) = self.symbol_table().or_else(|| self.dynamic_symbol_table()) {
+            for symbol in table.symbols() {
+                if !symbol.is_definition() || symbol.kind() != SymbolKind::Text {
+                    continue;
+                }
+                let name = match symbol.name() {
+                    Ok(name) => name,
+                    Err(_) => continue,
+                };
+                let section_end = symbol
+                    .section_index()
+                    .and_then(|index| self.section_by_index(index).ok())
+                    .map(|section| section.address().wrapping_add(section.size()));
+                functions.push((
+                    FunctionMapEntry::new(symbol.address(), symbol.size(), name),
+                    section_end,
+                ));
+            }
+        }
+        SymbolMap::with_inferred_sizes(functions)
+    }
+
+    /// Get the address ranges of the functions in the file.
+    ///
+    /// This combines the best available sources for the file format, so that
+    /// function boundaries are available even if the file has no symbols.
+    /// These sources are:
+    ///
+    /// - the symbol table, or the dynamic symbol table, for all formats
+    /// - the FDEs in `.eh_frame` for ELF executables and shared objects
+    /// - `LC_FUNCTION_STARTS` for Mach-O
+    /// - the exception directory for x86-64, ARM and AArch64 PE files
+    ///
+    /// Functions without a known size have their size inferred from the next
+    /// function or the end of the section.
+    ///
+    /// The ranges are sorted by address, and there is at most one range
+    /// for each address.
+    fn function_ranges(&'file self) -> Result<Vec<FunctionRange>> {
+        Ok(read::function_ranges(self, Vec::new()))
+    }
+
     /// Construct a map from addresses to symbol names and object file names.
     ///
     /// This is derived from Mach-O STAB entries.
@@ -172,6 +293,25 @@ pub trait Object<'data: 'file, 'file>: read::private::Sealed {
         ObjectMap::default()
     }
 
+    /// Get the names of the source files that the file was compiled from.
+    ///
+    /// These are the ELF `STT_FILE` symbols, the COFF and XCOFF `C_FILE` symbols,
+    /// or the Mach-O `N_SO` STAB entries and the `N_OSO` entries that follow them.
+    /// This does not parse debugging information.
+    /// The source files are in the order they appear in the symbol table.
+    fn source_files(&'file self) -> Result<Vec<SourceFile<'data>>> {
+        let mut files = Vec::new();
+        for symbol in self.symbols() {
+            if symbol.kind() == SymbolKind::File {
+                let name = symbol.name_bytes()?;
+                if !name.is_empty() {
+                    files.push(SourceFile::new(&[], name));
+                }
+            }
+        }
+        Ok(files)
+    }
+
     /// Get the imported symbols.
     fn imports(&self) -> Result<Vec<Import<'data>>>;
 
@@ -179,8 +319,20 @@ pub trait Object<'data: 'file, 'file>: read::private::Sealed {
     ///
     /// Some file formats may provide other kinds of symbols, that can be retrieved using
     /// the lower-level API.
+    ///
+    /// For PE files, exports with a malformed forwarder string are skipped.
     fn exports(&self) -> Result<Vec<Export<'data>>>;
 
+    /// Get the names of the shared libraries that this file depends on.
+    ///
+    /// These are the ELF `DT_NEEDED` entries, the PE import and delay-load
+    /// import DLL names, or the Mach-O `LC_LOAD_DYLIB` (and similar) paths.
+    /// The names are in the order they appear in the file.
+    #[inline]
+    fn needed_libraries(&self) -> Result<Vec<&'data [u8]>> {
+        Ok(Vec::new())
+    }
+
     /// Return true if the file contains debug information sections, false if not.
     fn has_debug_symbols(&self) -> bool;
 
@@ -214,14 +366,121 @@ pub trait Object<'data: 'file, 'file>: read::private::Sealed {
         Ok(None)
     }
 
+    /// The embedded code signing data.
+    ///
+    /// This is the PE attribute certificate table, or the Mach-O code signature.
+    /// Returns `Ok(None)` if the file is not signed, or the file format does not
+    /// support code signing.
+    #[inline]
+    fn code_signature(&self) -> Result<Option<CodeSignature<'data>>> {
+        Ok(None)
+    }
+
     /// Get the base address used for relative virtual addresses.
     ///
     /// Currently this is only non-zero for PE.
     fn relative_address_base(&'file self) -> u64;
 
-    /// Get the virtual address of the entry point of the binary
+    /// Return the file offset of the data at the given virtual address.
+    ///
+    /// This uses the segments if there are any, otherwise it uses the sections.
+    ///
+    /// Returns `None` if the address is not within the file data of a segment
+    /// or section. This includes uninitialized data, such as ELF `SHT_NOBITS`
+    /// sections and the virtual part of PE sections.
+    ///
+    /// Always returns `None` for relocatable files without segments, because their
+    /// section addresses are usually all zero.
+    fn address_to_file_offset(&'file self, address: u64) -> Option<u64> {
+        let mut has_segments = false;
+        for segment in self.segments() {
+            has_segments = true;
+            let (file_offset, file_size) = segment.file_range();
+            if let Some(offset) = address.checked_sub(segment.address()) {
+                if offset < file_size && offset < segment.size() {
+                    return file_offset.checked_add(offset);
+                }
+            }
+        }
+        if has_segments || self.kind() == ObjectKind::Relocatable {
+            return None;
+        }
+        for section in self.sections() {
+            if let Some((file_offset, file_size)) = section.file_range() {
+                if let Some(offset) = address.checked_sub(section.address()) {
+                    if offset < file_size && offset < section.size() {
+                        return file_offset.checked_add(offset);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Return the virtual address of the data at the given file offset.
+    ///
+    /// This uses the segments if there are any, otherwise it uses the sections.
+    ///
+    /// Returns `None` if the offset is not within the file data of a segment
+    /// or section.
+    ///
+    /// Always returns `None` for relocatable files without segments, because their
+    /// section addresses are usually all zero.
+    fn file_offset_to_address(&'file self, offset: u64) -> Option<u64> {
+        let mut has_segments = false;
+        for segment in self.segments() {
+            has_segments = true;
+            let (file_offset, file_size) = segment.file_range();
+            if let Some(offset) = offset.checked_sub(file_offset) {
+                if offset < file_size && offset < segment.size() {
+                    return segment.address().checked_add(offset);
+                }
+            }
+        }
+        if has_segments || self.kind() == ObjectKind::Relocatable {
+            return None;
+        }
+        for section in self.sections() {
+            if let Some((file_offset, file_size)) = section.file_range() {
+                if let Some(offset) = offset.checked_sub(file_offset) {
+                    if offset < file_size && offset < section.size() {
+                        return section.address().checked_add(offset);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Get the virtual address of the entry point of the binary.
+    ///
+    /// Returns 0 if there is no entry point.
     fn entry(&'file self) -> u64;
 
+    /// Return true if the file can be loaded at any address.
+    ///
+    /// This is true for ELF `ET_DYN` files, PE files with `IMAGE_DLLCHARACTERISTICS_DYNAMIC_BASE`,
+    /// and Mach-O dynamic libraries, bundles, and files with `MH_PIE`.
+    #[inline]
+    fn is_position_independent(&self) -> bool {
+        false
+    }
+
+    /// Return true if the file does not contain a symbol table or debug information.
+    ///
+    /// Dynamic symbols are not considered. For Mach-O, this checks that there are
+    /// no local symbols.
+    fn is_stripped(&'file self) -> bool {
+        self.symbols().next().is_none() && !self.has_debug_symbols()
+    }
+
+    /// Get the hardening features that are enabled for the file.
+    ///
+    /// Features that are not applicable to the file format are `None`.
+    fn security_features(&'file self) -> SecurityFeatures {
+        SecurityFeatures::default()
+    }
+
     /// File flags that are specific to each file format.
     fn flags(&self) -> FileFlags;
 }
@@ -234,6 +493,14 @@ pub trait ObjectSegment<'data>: read::private::Sealed {
     /// Returns the virtual address of the segment.
     fn address(&self) -> u64;
 
+    /// Returns the physical address of the segment.
+    ///
+    /// For ELF files, this is `p_paddr`, which is the load address of segments that
+    /// are stored at a different address than they execute at, such as data that is
+    /// copied from flash to RAM at startup. For other file formats, this is the same
+    /// as the virtual address.
+    fn physical_address(&self) -> u64;
+
     /// Returns the size of the segment in memory.
     fn size(&self) -> u64;
 
@@ -262,6 +529,12 @@ pub trait ObjectSegment<'data>: read::private::Sealed {
     /// Returns an error if the name is not UTF-8.
     fn name(&self) -> Result<Option<&str>>;
 
+    /// Return the kind of segment.
+    fn kind(&self) -> SegmentKind;
+
+    /// Return the memory access permissions of the segment.
+    fn permissions(&self) -> Permissions;
+
     /// Return the flags of segment.
     fn flags(&self) -> SegmentFlags;
 }
@@ -406,6 +679,16 @@ pub trait ObjectSymbol<'data>: read::private::Sealed {
     /// Returns an error if the name is not UTF-8.
     fn name(&self) -> Result<&'data str>;
 
+    /// The demangled name of the symbol.
+    ///
+    /// The mangling scheme is detected from the name, as described for [`demangle`](crate::read::demangle).
+    ///
+    /// Returns `None` if the name is invalid or is not mangled using a supported scheme.
+    #[cfg(feature = "demangle")]
+    fn demangled_name(&self) -> Option<alloc::string::String> {
+        crate::read::demangle(self.name_bytes().ok()?)
+    }
+
     /// The address of the symbol. May be zero if the address is unknown.
     fn address(&self) -> u64;
 
@@ -467,3 +750,260 @@ impl Iterator for NoDynamicRelocationIterator {
         None
     }
 }
+
+/// A segment for files that don't have segments.
+///
+/// This type has no values, so its methods can never be called.
+#[derive(Debug, Clone, Copy)]
+pub enum NoSegment {}
+
+impl read::private::Sealed for NoSegment {}
+
+impl<'data> ObjectSegment<'data> for NoSegment {
+    fn address(&self) -> u64 {
+        match *self {}
+    }
+
+    fn physical_address(&self) -> u64 {
+        match *self {}
+    }
+
+    fn size(&self) -> u64 {
+        match *self {}
+    }
+
+    fn align(&self) -> u64 {
+        match *self {}
+    }
+
+    fn file_range(&self) -> (u64, u64) {
+        match *self {}
+    }
+
+    fn data(&self) -> Result<&'data [u8]> {
+        match *self {}
+    }
+
+    fn data_range(&self, _address: u64, _size: u64) -> Result<Option<&'data [u8]>> {
+        match *self {}
+    }
+
+    fn name_bytes(&self) -> Result<Option<&[u8]>> {
+        match *self {}
+    }
+
+    fn name(&self) -> Result<Option<&str>> {
+        match *self {}
+    }
+
+    fn kind(&self) -> SegmentKind {
+        match *self {}
+    }
+
+    fn permissions(&self) -> Permissions {
+        match *self {}
+    }
+
+    fn flags(&self) -> SegmentFlags {
+        match *self {}
+    }
+}
+
+/// A section for files that don't have sections.
+///
+/// This type has no values, so its methods can never be called.
+#[derive(Debug, Clone, Copy)]
+pub enum NoSection {}
+
+impl read::private::Sealed for NoSection {}
+
+impl<'data> ObjectSection<'data> for NoSection {
+    type RelocationIterator = core::iter::Empty<(u64, Relocation)>;
+
+    fn index(&self) -> SectionIndex {
+        match *self {}
+    }
+
+    fn address(&self) -> u64 {
+        match *self {}
+    }
+
+    fn size(&self) -> u64 {
+        match *self {}
+    }
+
+    fn align(&self) -> u64 {
+        match *self {}
+    }
+
+    fn file_range(&self) -> Option<(u64, u64)> {
+        match *self {}
+    }
+
+    fn data(&self) -> Result<&'data [u8]> {
+        match *self {}
+    }
+
+    fn data_range(&self, _address: u64, _size: u64) -> Result<Option<&'data [u8]>> {
+        match *self {}
+    }
+
+    fn compressed_file_range(&self) -> Result<CompressedFileRange> {
+        match *self {}
+    }
+
+    fn compressed_data(&self) -> Result<CompressedData<'data>> {
+        match *self {}
+    }
+
+    fn name_bytes(&self) -> Result<&[u8]> {
+        match *self {}
+    }
+
+    fn name(&self) -> Result<&str> {
+        match *self {}
+    }
+
+    fn segment_name_bytes(&self) -> Result<Option<&[u8]>> {
+        match *self {}
+    }
+
+    fn segment_name(&self) -> Result<Option<&str>> {
+        match *self {}
+    }
+
+    fn kind(&self) -> SectionKind {
+        match *self {}
+    }
+
+    fn relocations(&self) -> Self::RelocationIterator {
+        match *self {}
+    }
+
+    fn flags(&self) -> SectionFlags {
+        match *self {}
+    }
+}
+
+/// A COMDAT section group for files that don't have COMDAT section groups.
+///
+/// This type has no values, so its methods can never be called.
+#[derive(Debug, Clone, Copy)]
+pub enum NoComdat {}
+
+impl read::private::Sealed for NoComdat {}
+
+impl<'data> ObjectComdat<'data> for NoComdat {
+    type SectionIterator = core::iter::Empty<SectionIndex>;
+
+    fn kind(&self) -> ComdatKind {
+        match *self {}
+    }
+
+    fn symbol(&self) -> SymbolIndex {
+        match *self {}
+    }
+
+    fn name_bytes(&self) -> Result<&[u8]> {
+        match *self {}
+    }
+
+    fn name(&self) -> Result<&str> {
+        match *self {}
+    }
+
+    fn sections(&self) -> Self::SectionIterator {
+        match *self {}
+    }
+}
+
+/// A symbol table for files that don't have symbol tables.
+///
+/// This type has no values, so its methods can never be called.
+#[derive(Debug, Clone, Copy)]
+pub enum NoSymbolTable {}
+
+impl read::private::Sealed for NoSymbolTable {}
+
+impl<'data> ObjectSymbolTable<'data> for NoSymbolTable {
+    type Symbol = NoSymbol;
+    type SymbolIterator = core::iter::Empty<NoSymbol>;
+
+    fn symbols(&self) -> Self::SymbolIterator {
+        match *self {}
+    }
+
+    fn symbol_by_index(&self, _index: SymbolIndex) -> Result<Self::Symbol> {
+        match *self {}
+    }
+}
+
+/// A symbol for files that don't have symbols.
+///
+/// This type has no values, so its methods can never be called.
+#[derive(Debug, Clone, Copy)]
+pub enum NoSymbol {}
+
+impl read::private::Sealed for NoSymbol {}
+
+impl<'data> ObjectSymbol<'data> for NoSymbol {
+    fn index(&self) -> SymbolIndex {
+        match *self {}
+    }
+
+    fn name_bytes(&self) -> Result<&'data [u8]> {
+        match *self {}
+    }
+
+    fn name(&self) -> Result<&'data str> {
+        match *self {}
+    }
+
+    fn address(&self) -> u64 {
+        match *self {}
+    }
+
+    fn size(&self) -> u64 {
+        match *self {}
+    }
+
+    fn kind(&self) -> SymbolKind {
+        match *self {}
+    }
+
+    fn section(&self) -> SymbolSection {
+        match *self {}
+    }
+
+    fn is_undefined(&self) -> bool {
+        match *self {}
+    }
+
+    fn is_definition(&self) -> bool {
+        match *self {}
+    }
+
+    fn is_common(&self) -> bool {
+        match *self {}
+    }
+
+    fn is_weak(&self) -> bool {
+        match *self {}
+    }
+
+    fn scope(&self) -> SymbolScope {
+        match *self {}
+    }
+
+    fn is_global(&self) -> bool {
+        match *self {}
+    }
+
+    fn is_local(&self) -> bool {
+        match *self {}
+    }
+
+    fn flags(&self) -> SymbolFlags<SectionIndex, SymbolIndex> {
+        match *self {}
+    }
+}
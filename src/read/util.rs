@@ -1,4 +1,5 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 use core::convert::TryInto;
 use core::fmt;
 use core::marker::PhantomData;
@@ -249,6 +250,15 @@ impl<'data> fmt::Debug for ByteString<'data> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'data> serde::Serialize for ByteString<'data> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Always serialize as a string so that the output has the same shape
+        // for every value. Invalid UTF-8 is replaced, the same as for `Debug`.
+        serializer.serialize_str(&String::from_utf8_lossy(self.0))
+    }
+}
+
 #[allow(dead_code)]
 #[inline]
 pub(crate) fn align(offset: usize, size: usize) -> usize {
@@ -267,6 +277,105 @@ pub(crate) fn data_range(
         .get(..size.try_into().ok()?)
 }
 
+/// Remove leading and trailing ASCII whitespace.
+#[allow(dead_code)]
+pub(crate) fn trim_ascii(mut bytes: &[u8]) -> &[u8] {
+    while let [first, rest @ ..] = bytes {
+        if !first.is_ascii_whitespace() {
+            break;
+        }
+        bytes = rest;
+    }
+    while let [rest @ .., last] = bytes {
+        if !last.is_ascii_whitespace() {
+            break;
+        }
+        bytes = rest;
+    }
+    bytes
+}
+
+/// Decode pairs of ASCII hex digits into bytes.
+///
+/// Returns `None` if there is an odd number of digits or a digit is invalid.
+#[allow(dead_code)]
+pub(crate) fn decode_hex(digits: &[u8]) -> Option<Vec<u8>> {
+    fn hex_digit(digit: u8) -> Option<u8> {
+        match digit {
+            b'0'..=b'9' => Some(digit - b'0'),
+            b'a'..=b'f' => Some(digit - b'a' + 10),
+            b'A'..=b'F' => Some(digit - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    if digits.len() % 2 != 0 {
+        return None;
+    }
+    digits
+        .chunks(2)
+        .map(|digits| Some(hex_digit(digits[0])? << 4 | hex_digit(digits[1])?))
+        .collect()
+}
+
+/// A block of contiguous data at an address.
+///
+/// This is used for the data decoded from the records of text formats.
+#[derive(Debug, Clone)]
+pub(crate) struct DataBlock {
+    pub address: u64,
+    pub data: Vec<u8>,
+}
+
+impl DataBlock {
+    #[allow(dead_code)]
+    #[inline]
+    pub fn end(&self) -> u64 {
+        self.address + self.data.len() as u64
+    }
+}
+
+/// Add data to the last block if it follows on from it, otherwise start a new block.
+#[allow(dead_code)]
+pub(crate) fn add_data_block(blocks: &mut Vec<DataBlock>, address: u64, data: &[u8]) {
+    if data.is_empty() {
+        return;
+    }
+    if let Some(last) = blocks.last_mut() {
+        if last.end() == address {
+            last.data.extend_from_slice(data);
+            return;
+        }
+    }
+    blocks.push(DataBlock {
+        address,
+        data: data.to_vec(),
+    });
+}
+
+/// Sort blocks by address and combine adjacent blocks.
+///
+/// Returns an error if any blocks overlap.
+#[allow(dead_code)]
+pub(crate) fn merge_data_blocks(mut blocks: Vec<DataBlock>) -> Result<Vec<DataBlock>, ()> {
+    // Records are usually in address order, but this is not required.
+    blocks.sort_by_key(|block| block.address);
+    let mut merged: Vec<DataBlock> = Vec::with_capacity(blocks.len());
+    for block in blocks {
+        if let Some(last) = merged.last_mut() {
+            if block.address < last.end() {
+                return Err(());
+            }
+            if block.address == last.end() {
+                last.data.extend_from_slice(&block.data);
+                continue;
+            }
+        }
+        merged.push(block);
+    }
+    Ok(merged)
+}
+
 /// A table of zero-terminated strings.
 ///
 /// This is used for most file formats.
@@ -3,8 +3,11 @@
 //! Provides `WasmFile` and related types which implement the `Object` trait.
 //!
 //! Currently implements the minimum required to access DWARF debugging information.
+//!
+//! Addresses of function symbols are offsets relative to the start of the code section
+//! contents, which matches the addresses used by DWARF for WebAssembly.
 use alloc::boxed::Box;
-use alloc::vec::Vec;
+use alloc::vec::{self, Vec};
 use core::marker::PhantomData;
 use core::ops::Range;
 use core::{slice, str};
@@ -12,10 +15,10 @@ use wasmparser as wp;
 
 use crate::read::{
     self, Architecture, ComdatKind, CompressedData, CompressedFileRange, Error, Export, FileFlags,
-    Import, NoDynamicRelocationIterator, Object, ObjectComdat, ObjectKind, ObjectSection,
-    ObjectSegment, ObjectSymbol, ObjectSymbolTable, ReadError, ReadRef, Relocation, Result,
-    SectionFlags, SectionIndex, SectionKind, SegmentFlags, SymbolFlags, SymbolIndex, SymbolKind,
-    SymbolScope, SymbolSection,
+    FunctionRange, Import, NoDynamicRelocationIterator, Object, ObjectComdat, ObjectKind,
+    ObjectSection, ObjectSegment, ObjectSymbol, ObjectSymbolTable, Permissions, ReadError, ReadRef,
+    Relocation, Result, SectionFlags, SectionIndex, SectionKind, SegmentFlags, SegmentKind,
+    SourceFile, SymbolFlags, SymbolIndex, SymbolKind, SymbolScope, SymbolSection,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -49,10 +52,10 @@ pub struct WasmFile<'data, R = &'data [u8]> {
     id_sections: Box<[Option<usize>; MAX_SECTION_ID + 1]>,
     // Whether the file has DWARF information.
     has_debug_symbols: bool,
-    // Symbols collected from imports, exports, code and name sections.
-    symbols: Vec<WasmSymbolInternal<'data>>,
-    // Address of the function body for the entry point.
-    entry: u64,
+    // The function index given by the start section.
+    start_function: Option<u32>,
+    // The number of imported functions, which precede the local functions in the index space.
+    imported_function_count: u32,
     marker: PhantomData<R>,
 }
 
@@ -78,10 +81,12 @@ impl<T> ReadError<T> for wasmparser::Result<T> {
 
 impl<'data, R: ReadRef<'data>> WasmFile<'data, R> {
     /// Parse the raw wasm data.
+    ///
+    /// This only reads the section headers, and the import and memory sections.
+    /// Symbols and function bodies are decoded when they are accessed.
     pub fn parse(data: R) -> Result<Self> {
         let len = data.len().read_error("Unknown Wasm file size")?;
         let data = data.read_bytes_at(0, len).read_error("Wasm read failed")?;
-        let parser = wp::Parser::new(0).parse_all(data);
 
         let mut file = WasmFile {
             data,
@@ -89,11 +94,101 @@ impl<'data, R: ReadRef<'data>> WasmFile<'data, R> {
             sections: Vec::new(),
             id_sections: Default::default(),
             has_debug_symbols: false,
-            symbols: Vec::new(),
-            entry: 0,
+            start_function: None,
+            imported_function_count: 0,
             marker: PhantomData,
         };
 
+        let mut parser = wp::Parser::new(0);
+        let mut offset = 0;
+        loop {
+            let remaining = data.get(offset..).read_error("Invalid Wasm section size")?;
+            let payload = match parser
+                .parse(remaining, true)
+                .read_error("Invalid Wasm section header")?
+            {
+                wp::Chunk::Parsed { consumed, payload } => {
+                    offset += consumed;
+                    payload
+                }
+                // Only returned if `eof` is false.
+                wp::Chunk::NeedMoreData(_) => return Err(Error("Invalid Wasm section header")),
+            };
+
+            match payload {
+                wp::Payload::TypeSection(section) => {
+                    file.add_section(SectionId::Type, section.range(), "");
+                }
+                wp::Payload::ImportSection(section) => {
+                    file.add_section(SectionId::Import, section.range(), "");
+                    for import in section {
+                        let import = import.read_error("Couldn't read an import item")?;
+                        match import.ty {
+                            wp::TypeRef::Func(_) => file.imported_function_count += 1,
+                            wp::TypeRef::Memory(memory) => file.has_memory64 |= memory.memory64,
+                            _ => {}
+                        }
+                    }
+                }
+                wp::Payload::FunctionSection(section) => {
+                    file.add_section(SectionId::Function, section.range(), "");
+                }
+                wp::Payload::TableSection(section) => {
+                    file.add_section(SectionId::Table, section.range(), "");
+                }
+                wp::Payload::MemorySection(section) => {
+                    file.add_section(SectionId::Memory, section.range(), "");
+                    for memory in section {
+                        let memory = memory.read_error("Couldn't read a memory item")?;
+                        file.has_memory64 |= memory.memory64;
+                    }
+                }
+                wp::Payload::GlobalSection(section) => {
+                    file.add_section(SectionId::Global, section.range(), "");
+                }
+                wp::Payload::ExportSection(section) => {
+                    file.add_section(SectionId::Export, section.range(), "");
+                }
+                wp::Payload::StartSection { func, range, .. } => {
+                    file.add_section(SectionId::Start, range, "");
+                    file.start_function = Some(func);
+                }
+                wp::Payload::ElementSection(section) => {
+                    file.add_section(SectionId::Element, section.range(), "");
+                }
+                wp::Payload::CodeSectionStart { range, size, .. } => {
+                    file.add_section(SectionId::Code, range, "");
+                    // The function bodies are read by `function_bodies`.
+                    parser.skip_section();
+                    offset += size as usize;
+                }
+                wp::Payload::DataSection(section) => {
+                    file.add_section(SectionId::Data, section.range(), "");
+                }
+                wp::Payload::DataCountSection { range, .. } => {
+                    file.add_section(SectionId::DataCount, range, "");
+                }
+                wp::Payload::CustomSection(section) => {
+                    let name = section.name();
+                    let size = section.data().len();
+                    let mut range = section.range();
+                    range.start = range.end - size;
+                    file.add_section(SectionId::Custom, range, name);
+                    if name.starts_with(".debug_") {
+                        file.has_debug_symbols = true;
+                    }
+                }
+                wp::Payload::End(_) => break,
+                _ => {}
+            }
+        }
+
+        Ok(file)
+    }
+
+    /// Decode the symbols from the import, export, code and name sections.
+    fn parse_symbols(&self) -> Result<Vec<WasmSymbolInternal<'data>>> {
+        let mut symbols = Vec::new();
         let mut main_file_symbol = Some(WasmSymbolInternal {
             name: "",
             address: 0,
@@ -103,23 +198,21 @@ impl<'data, R: ReadRef<'data>> WasmFile<'data, R> {
             scope: SymbolScope::Compilation,
         });
 
-        let mut imported_funcs_count = 0;
+        let imported_funcs_count = self.imported_function_count;
         let mut local_func_kinds = Vec::new();
-        let mut entry_func_id = None;
-        let mut code_range_start = 0;
-        let mut code_func_index = 0;
         // One-to-one mapping of globals to their value (if the global is a constant integer).
         let mut global_values = Vec::new();
 
-        for payload in parser {
-            let payload = payload.read_error("Invalid Wasm section header")?;
-
-            match payload {
-                wp::Payload::TypeSection(section) => {
-                    file.add_section(SectionId::Type, section.range(), "");
-                }
-                wp::Payload::ImportSection(section) => {
-                    file.add_section(SectionId::Import, section.range(), "");
+        for section in &self.sections {
+            let offset = section.range.start;
+            let data = self
+                .data
+                .get(section.range.clone())
+                .read_error("Invalid Wasm section size")?;
+            match section.id {
+                SectionId::Import => {
+                    let section = wp::ImportSectionReader::new(data, offset)
+                        .read_error("Couldn't read the import section")?;
                     let mut last_module_name = None;
 
                     for import in section {
@@ -127,7 +220,7 @@ impl<'data, R: ReadRef<'data>> WasmFile<'data, R> {
                         let module_name = import.module;
 
                         if last_module_name != Some(module_name) {
-                            file.symbols.push(WasmSymbolInternal {
+                            symbols.push(WasmSymbolInternal {
                                 name: module_name,
                                 address: 0,
                                 size: 0,
@@ -139,19 +232,14 @@ impl<'data, R: ReadRef<'data>> WasmFile<'data, R> {
                         }
 
                         let kind = match import.ty {
-                            wp::TypeRef::Func(_) => {
-                                imported_funcs_count += 1;
-                                SymbolKind::Text
-                            }
-                            wp::TypeRef::Memory(memory) => {
-                                file.has_memory64 |= memory.memory64;
-                                SymbolKind::Data
-                            }
-                            wp::TypeRef::Table(_) | wp::TypeRef::Global(_) => SymbolKind::Data,
+                            wp::TypeRef::Func(_) => SymbolKind::Text,
+                            wp::TypeRef::Memory(_)
+                            | wp::TypeRef::Table(_)
+                            | wp::TypeRef::Global(_) => SymbolKind::Data,
                             wp::TypeRef::Tag(_) => SymbolKind::Unknown,
                         };
 
-                        file.symbols.push(WasmSymbolInternal {
+                        symbols.push(WasmSymbolInternal {
                             name: import.name,
                             address: 0,
                             size: 0,
@@ -161,23 +249,14 @@ impl<'data, R: ReadRef<'data>> WasmFile<'data, R> {
                         });
                     }
                 }
-                wp::Payload::FunctionSection(section) => {
-                    file.add_section(SectionId::Function, section.range(), "");
-                    local_func_kinds =
-                        vec![LocalFunctionKind::Unknown; section.into_iter().count()];
-                }
-                wp::Payload::TableSection(section) => {
-                    file.add_section(SectionId::Table, section.range(), "");
-                }
-                wp::Payload::MemorySection(section) => {
-                    file.add_section(SectionId::Memory, section.range(), "");
-                    for memory in section {
-                        let memory = memory.read_error("Couldn't read a memory item")?;
-                        file.has_memory64 |= memory.memory64;
-                    }
+                SectionId::Function => {
+                    let section = wp::FunctionSectionReader::new(data, offset)
+                        .read_error("Couldn't read the function section")?;
+                    local_func_kinds = vec![LocalFunctionKind::Unknown; section.count() as usize];
                 }
-                wp::Payload::GlobalSection(section) => {
-                    file.add_section(SectionId::Global, section.range(), "");
+                SectionId::Global => {
+                    let section = wp::GlobalSectionReader::new(data, offset)
+                        .read_error("Couldn't read the global section")?;
                     for global in section {
                         let global = global.read_error("Couldn't read a global item")?;
                         let mut address = None;
@@ -193,10 +272,11 @@ impl<'data, R: ReadRef<'data>> WasmFile<'data, R> {
                         global_values.push(address);
                     }
                 }
-                wp::Payload::ExportSection(section) => {
-                    file.add_section(SectionId::Export, section.range(), "");
+                SectionId::Export => {
+                    let section = wp::ExportSectionReader::new(data, offset)
+                        .read_error("Couldn't read the export section")?;
                     if let Some(main_file_symbol) = main_file_symbol.take() {
-                        file.symbols.push(main_file_symbol);
+                        symbols.push(main_file_symbol);
                     }
 
                     for export in section {
@@ -207,8 +287,9 @@ impl<'data, R: ReadRef<'data>> WasmFile<'data, R> {
                                 if let Some(local_func_id) =
                                     export.index.checked_sub(imported_funcs_count)
                                 {
-                                    let local_func_kind =
-                                        &mut local_func_kinds[local_func_id as usize];
+                                    let local_func_kind = local_func_kinds
+                                        .get_mut(local_func_id as usize)
+                                        .read_error("Invalid Wasm export function index")?;
                                     if let LocalFunctionKind::Unknown = local_func_kind {
                                         *local_func_kind = LocalFunctionKind::Exported {
                                             symbol_ids: Vec::new(),
@@ -218,7 +299,7 @@ impl<'data, R: ReadRef<'data>> WasmFile<'data, R> {
                                         LocalFunctionKind::Exported { symbol_ids } => symbol_ids,
                                         _ => unreachable!(),
                                     };
-                                    symbol_ids.push(file.symbols.len() as u32);
+                                    symbol_ids.push(symbols.len() as u32);
                                 }
                                 (SymbolKind::Text, SectionId::Code)
                             }
@@ -238,7 +319,7 @@ impl<'data, R: ReadRef<'data>> WasmFile<'data, R> {
                             }
                         }
 
-                        file.symbols.push(WasmSymbolInternal {
+                        symbols.push(WasmSymbolInternal {
                             name: export.name,
                             address,
                             size: 0,
@@ -248,109 +329,137 @@ impl<'data, R: ReadRef<'data>> WasmFile<'data, R> {
                         });
                     }
                 }
-                wp::Payload::StartSection { func, range, .. } => {
-                    file.add_section(SectionId::Start, range, "");
-                    entry_func_id = Some(func);
-                }
-                wp::Payload::ElementSection(section) => {
-                    file.add_section(SectionId::Element, section.range(), "");
-                }
-                wp::Payload::CodeSectionStart { range, .. } => {
-                    code_range_start = range.start;
-                    file.add_section(SectionId::Code, range, "");
+                SectionId::Code => {
                     if let Some(main_file_symbol) = main_file_symbol.take() {
-                        file.symbols.push(main_file_symbol);
+                        symbols.push(main_file_symbol);
                     }
-                }
-                wp::Payload::CodeSectionEntry(body) => {
-                    let i = code_func_index;
-                    code_func_index += 1;
-
-                    let range = body.range();
-
-                    let address = range.start as u64 - code_range_start as u64;
-                    let size = (range.end - range.start) as u64;
 
-                    if entry_func_id == Some(i as u32) {
-                        file.entry = address;
-                    }
-
-                    let local_func_kind = &mut local_func_kinds[i];
-                    match local_func_kind {
-                        LocalFunctionKind::Unknown => {
-                            *local_func_kind = LocalFunctionKind::Local {
-                                symbol_id: file.symbols.len() as u32,
-                            };
-                            file.symbols.push(WasmSymbolInternal {
-                                name: "",
-                                address,
-                                size,
-                                kind: SymbolKind::Text,
-                                section: SymbolSection::Section(SectionIndex(
-                                    SectionId::Code as usize,
-                                )),
-                                scope: SymbolScope::Compilation,
-                            });
-                        }
-                        LocalFunctionKind::Exported { symbol_ids } => {
-                            for symbol_id in core::mem::take(symbol_ids) {
-                                let export_symbol = &mut file.symbols[symbol_id as usize];
-                                export_symbol.address = address;
-                                export_symbol.size = size;
+                    for (i, body) in self.function_bodies()?.enumerate() {
+                        let body = body?;
+                        let address = body.start;
+                        let size = body.end - body.start;
+
+                        let local_func_kind = local_func_kinds
+                            .get_mut(i)
+                            .read_error("Invalid Wasm function body count")?;
+                        match local_func_kind {
+                            LocalFunctionKind::Unknown => {
+                                *local_func_kind = LocalFunctionKind::Local {
+                                    symbol_id: symbols.len() as u32,
+                                };
+                                symbols.push(WasmSymbolInternal {
+                                    name: "",
+                                    address,
+                                    size,
+                                    kind: SymbolKind::Text,
+                                    section: SymbolSection::Section(SectionIndex(
+                                        SectionId::Code as usize,
+                                    )),
+                                    scope: SymbolScope::Compilation,
+                                });
                             }
+                            LocalFunctionKind::Exported { symbol_ids } => {
+                                for symbol_id in core::mem::take(symbol_ids) {
+                                    let export_symbol = &mut symbols[symbol_id as usize];
+                                    export_symbol.address = address;
+                                    export_symbol.size = size;
+                                }
+                            }
+                            _ => unreachable!(),
                         }
-                        _ => unreachable!(),
                     }
                 }
-                wp::Payload::DataSection(section) => {
-                    file.add_section(SectionId::Data, section.range(), "");
-                }
-                wp::Payload::DataCountSection { range, .. } => {
-                    file.add_section(SectionId::DataCount, range, "");
-                }
-                wp::Payload::CustomSection(section) => {
-                    let name = section.name();
-                    let size = section.data().len();
-                    let mut range = section.range();
-                    range.start = range.end - size;
-                    file.add_section(SectionId::Custom, range, name);
-                    if name == "name" {
-                        for name in
-                            wp::NameSectionReader::new(section.data(), section.data_offset())
-                        {
-                            // TODO: Right now, ill-formed name subsections
-                            // are silently ignored in order to maintain
-                            // compatibility with extended name sections, which
-                            // are not yet supported by the version of
-                            // `wasmparser` currently used.
-                            // A better fix would be to update `wasmparser` to
-                            // the newest version, but this requires
-                            // a major rewrite of this file.
-                            if let Ok(wp::Name::Function(name_map)) = name {
-                                for naming in name_map {
-                                    let naming =
-                                        naming.read_error("Couldn't read a function name")?;
-                                    if let Some(local_index) =
-                                        naming.index.checked_sub(imported_funcs_count)
+                SectionId::Custom if section.name == "name" => {
+                    for name in wp::NameSectionReader::new(data, offset) {
+                        // TODO: Right now, ill-formed name subsections
+                        // are silently ignored in order to maintain
+                        // compatibility with extended name sections, which
+                        // are not yet supported by the version of
+                        // `wasmparser` currently used.
+                        // A better fix would be to update `wasmparser` to
+                        // the newest version, but this requires
+                        // a major rewrite of this file.
+                        if let Ok(wp::Name::Function(name_map)) = name {
+                            for naming in name_map {
+                                let naming = naming.read_error("Couldn't read a function name")?;
+                                if let Some(local_index) =
+                                    naming.index.checked_sub(imported_funcs_count)
+                                {
+                                    if let Some(LocalFunctionKind::Local { symbol_id }) =
+                                        local_func_kinds.get(local_index as usize)
                                     {
-                                        if let LocalFunctionKind::Local { symbol_id } =
-                                            local_func_kinds[local_index as usize]
-                                        {
-                                            file.symbols[symbol_id as usize].name = naming.name;
-                                        }
+                                        symbols[*symbol_id as usize].name = naming.name;
                                     }
                                 }
                             }
                         }
-                    } else if name.starts_with(".debug_") {
-                        file.has_debug_symbols = true;
                     }
                 }
                 _ => {}
             }
         }
 
-        Ok(file)
+        Ok(symbols)
+    }
+
+    /// Iterate over the code section relative ranges of the bodies of local functions.
+    fn function_bodies(&self) -> Result<impl Iterator<Item = Result<Range<u64>>> + 'data> {
+        let mut reader = None;
+        if let Some(index) = self.id_sections[SectionId::Code as usize] {
+            let range = self.sections[index].range.clone();
+            let data = self
+                .data
+                .get(range.clone())
+                .read_error("Invalid Wasm section size")?;
+            let section = wp::CodeSectionReader::new(data, range.start)
+                .read_error("Couldn't read the code section")?;
+            reader = Some((section, range.start));
+        }
+        Ok(reader.into_iter().flat_map(|(section, start)| {
+            section.into_iter().map(move |body| {
+                let range = body.read_error("Couldn't read a function body")?.range();
+                Ok((range.start - start) as u64..(range.end - start) as u64)
+            })
+        }))
+    }
+
+    /// Return the number of imported functions.
+    ///
+    /// Imported functions precede the functions defined in the code section
+    /// in the function index space.
+    #[inline]
+    pub fn imported_function_count(&self) -> u32 {
+        self.imported_function_count
+    }
+
+    /// Return the range of the body of the function with the given index.
+    ///
+    /// The range is relative to the start of the code section contents,
+    /// which is the address space used by symbols and DWARF for WebAssembly.
+    /// The body starts with the local declarations, after its size prefix.
+    ///
+    /// This reads the code section up to the function body.
+    /// Returns `None` for imported functions and invalid indices.
+    pub fn function_code_range(&self, index: u32) -> Option<Range<u64>> {
+        let local_index = index.checked_sub(self.imported_function_count)?;
+        self.function_bodies().ok()?.nth(local_index as usize)?.ok()
+    }
+
+    /// Return the index of the function whose body contains the given code offset.
+    ///
+    /// The offset is relative to the start of the code section contents.
+    /// This reads the code section up to the function body.
+    pub fn function_index_at_code_offset(&self, offset: u64) -> Option<u32> {
+        for (local_index, body) in self.function_bodies().ok()?.enumerate() {
+            let body = body.ok()?;
+            if offset < body.start {
+                break;
+            }
+            if offset < body.end {
+                return Some(self.imported_function_count + local_index as u32);
+            }
+        }
+        None
     }
 
     fn add_section(&mut self, id: SectionId, range: Range<usize>, name: &'data str) {
@@ -439,31 +548,33 @@ where
         WasmComdatIterator { file: self }
     }
 
-    #[inline]
     fn symbol_by_index(&'file self, index: SymbolIndex) -> Result<WasmSymbol<'data, 'file>> {
-        let symbol = self
-            .symbols
+        let symbols = self.parse_symbols()?;
+        let symbol = *symbols
             .get(index.0)
             .read_error("Invalid Wasm symbol index")?;
-        Ok(WasmSymbol { index, symbol })
+        Ok(WasmSymbol {
+            index,
+            symbol,
+            marker: PhantomData,
+        })
     }
 
+    /// Returns no symbols if the symbols can't be decoded.
     fn symbols(&'file self) -> Self::SymbolIterator {
-        WasmSymbolIterator {
-            symbols: self.symbols.iter().enumerate(),
-        }
+        WasmSymbolIterator::new(self.parse_symbols().unwrap_or_default())
     }
 
+    /// Returns `None` if the symbols can't be decoded.
     fn symbol_table(&'file self) -> Option<WasmSymbolTable<'data, 'file>> {
         Some(WasmSymbolTable {
-            symbols: &self.symbols,
+            symbols: self.parse_symbols().ok()?,
+            marker: PhantomData,
         })
     }
 
     fn dynamic_symbols(&'file self) -> Self::SymbolIterator {
-        WasmSymbolIterator {
-            symbols: [].iter().enumerate(),
-        }
+        WasmSymbolIterator::new(Vec::new())
     }
 
     #[inline]
@@ -490,13 +601,27 @@ where
         self.has_debug_symbols
     }
 
+    fn source_files(&'file self) -> Result<Vec<SourceFile<'data>>> {
+        // The file symbols are the names of imported modules.
+        Ok(Vec::new())
+    }
+
+    fn function_ranges(&'file self) -> Result<Vec<FunctionRange>> {
+        let ranges = self
+            .function_bodies()?
+            .map(|body| body.map(|body| FunctionRange::new(body.start, body.end - body.start)))
+            .collect::<Result<_>>()?;
+        Ok(read::function_ranges(self, ranges))
+    }
+
     fn relative_address_base(&self) -> u64 {
         0
     }
 
-    #[inline]
     fn entry(&'file self) -> u64 {
-        self.entry
+        self.start_function
+            .and_then(|index| self.function_bodies().ok()?.nth(index as usize)?.ok())
+            .map_or(0, |body| body.start)
     }
 
     #[inline]
@@ -536,6 +661,11 @@ impl<'data, 'file, R> ObjectSegment<'data> for WasmSegment<'data, 'file, R> {
         unreachable!()
     }
 
+    #[inline]
+    fn physical_address(&self) -> u64 {
+        unreachable!()
+    }
+
     #[inline]
     fn size(&self) -> u64 {
         unreachable!()
@@ -569,6 +699,16 @@ impl<'data, 'file, R> ObjectSegment<'data> for WasmSegment<'data, 'file, R> {
         unreachable!()
     }
 
+    #[inline]
+    fn kind(&self) -> SegmentKind {
+        unreachable!()
+    }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        unreachable!()
+    }
+
     #[inline]
     fn flags(&self) -> SegmentFlags {
         unreachable!()
@@ -644,8 +784,8 @@ impl<'data, 'file, R: ReadRef<'data>> ObjectSection<'data> for WasmSection<'data
             .read_error("Invalid Wasm section size or offset")
     }
 
-    fn data_range(&self, _address: u64, _size: u64) -> Result<Option<&'data [u8]>> {
-        unimplemented!()
+    fn data_range(&self, address: u64, size: u64) -> Result<Option<&'data [u8]>> {
+        Ok(read::util::data_range(self.data()?, 0, address, size))
     }
 
     #[inline]
@@ -797,7 +937,8 @@ impl<'data, 'file, R> Iterator for WasmComdatSectionIterator<'data, 'file, R> {
 /// A symbol table of a `WasmFile`.
 #[derive(Debug)]
 pub struct WasmSymbolTable<'data, 'file> {
-    symbols: &'file [WasmSymbolInternal<'data>],
+    symbols: Vec<WasmSymbolInternal<'data>>,
+    marker: PhantomData<&'file ()>,
 }
 
 impl<'data, 'file> read::private::Sealed for WasmSymbolTable<'data, 'file> {}
@@ -807,24 +948,36 @@ impl<'data, 'file> ObjectSymbolTable<'data> for WasmSymbolTable<'data, 'file> {
     type SymbolIterator = WasmSymbolIterator<'data, 'file>;
 
     fn symbols(&self) -> Self::SymbolIterator {
-        WasmSymbolIterator {
-            symbols: self.symbols.iter().enumerate(),
-        }
+        WasmSymbolIterator::new(self.symbols.clone())
     }
 
     fn symbol_by_index(&self, index: SymbolIndex) -> Result<Self::Symbol> {
-        let symbol = self
+        let symbol = *self
             .symbols
             .get(index.0)
             .read_error("Invalid Wasm symbol index")?;
-        Ok(WasmSymbol { index, symbol })
+        Ok(WasmSymbol {
+            index,
+            symbol,
+            marker: PhantomData,
+        })
     }
 }
 
 /// An iterator over the symbols of a `WasmFile`.
 #[derive(Debug)]
 pub struct WasmSymbolIterator<'data, 'file> {
-    symbols: core::iter::Enumerate<slice::Iter<'file, WasmSymbolInternal<'data>>>,
+    symbols: core::iter::Enumerate<vec::IntoIter<WasmSymbolInternal<'data>>>,
+    marker: PhantomData<&'file ()>,
+}
+
+impl<'data, 'file> WasmSymbolIterator<'data, 'file> {
+    fn new(symbols: Vec<WasmSymbolInternal<'data>>) -> Self {
+        WasmSymbolIterator {
+            symbols: symbols.into_iter().enumerate(),
+            marker: PhantomData,
+        }
+    }
 }
 
 impl<'data, 'file> Iterator for WasmSymbolIterator<'data, 'file> {
@@ -835,6 +988,7 @@ impl<'data, 'file> Iterator for WasmSymbolIterator<'data, 'file> {
         Some(WasmSymbol {
             index: SymbolIndex(index),
             symbol,
+            marker: PhantomData,
         })
     }
 }
@@ -843,10 +997,11 @@ impl<'data, 'file> Iterator for WasmSymbolIterator<'data, 'file> {
 #[derive(Clone, Copy, Debug)]
 pub struct WasmSymbol<'data, 'file> {
     index: SymbolIndex,
-    symbol: &'file WasmSymbolInternal<'data>,
+    symbol: WasmSymbolInternal<'data>,
+    marker: PhantomData<&'file ()>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug)]
 struct WasmSymbolInternal<'data> {
     name: &'data str,
     address: u64,
@@ -3,7 +3,10 @@ use core::mem;
 
 use alloc::vec::Vec;
 
-use crate::read::{self, Error, NoDynamicRelocationIterator, Object, ReadError, ReadRef, Result};
+use crate::read::{
+    self, Error, NoDynamicRelocationIterator, Object, ParseOptions, ReadError, ReadRef, Result,
+    SymbolNameCache,
+};
 
 use crate::{
     xcoff, Architecture, BigEndian as BE, FileFlags, ObjectKind, ObjectSection, Pod, SectionIndex,
@@ -35,6 +38,7 @@ where
     pub(super) aux_header: Option<&'data Xcoff::AuxHeader>,
     pub(super) sections: SectionTable<'data, Xcoff>,
     pub(super) symbols: SymbolTable<'data, Xcoff, R>,
+    pub(super) symbol_names: SymbolNameCache<'data>,
 }
 
 impl<'data, Xcoff, R> XcoffFile<'data, Xcoff, R>
@@ -44,10 +48,21 @@ where
 {
     /// Parse the raw XCOFF file data.
     pub fn parse(data: R) -> Result<Self> {
+        Self::parse_with_options(data, &ParseOptions::default())
+    }
+
+    /// Parse the raw XCOFF file data, with limits on parsing.
+    ///
+    /// Returns an error if the number of sections or symbols in the header exceeds
+    /// [`ParseOptions::max_table_entries`]. These counts are checked before the
+    /// tables are read.
+    pub fn parse_with_options(data: R, options: &ParseOptions) -> Result<Self> {
         let mut offset = 0;
         let header = Xcoff::parse(data, &mut offset)?;
         let aux_header = header.aux_header(data, &mut offset)?;
+        options.check_table_entries(header.f_nscns().into())?;
         let sections = header.sections(data, &mut offset)?;
+        options.check_table_entries(header.f_nsyms().into())?;
         let symbols = header.symbols(data)?;
 
         Ok(XcoffFile {
@@ -56,6 +71,7 @@ where
             aux_header,
             sections,
             symbols,
+            symbol_names: SymbolNameCache::default(),
         })
     }
 
@@ -168,6 +184,11 @@ where
         })
     }
 
+    fn symbol_by_name_bytes(&'file self, symbol_name: &[u8]) -> Option<Self::Symbol> {
+        let index = self.symbol_names.get(&self.symbol_table()?, symbol_name)?;
+        self.symbol_by_index(index).ok()
+    }
+
     fn symbol_by_index(
         &'file self,
         index: SymbolIndex,
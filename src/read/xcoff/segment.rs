@@ -79,6 +79,10 @@ where
         unreachable!();
     }
 
+    fn physical_address(&self) -> u64 {
+        unreachable!();
+    }
+
     fn size(&self) -> u64 {
         unreachable!();
     }
@@ -107,6 +111,14 @@ where
         unreachable!();
     }
 
+    fn kind(&self) -> crate::SegmentKind {
+        unreachable!();
+    }
+
+    fn permissions(&self) -> crate::Permissions {
+        unreachable!();
+    }
+
     fn flags(&self) -> crate::SegmentFlags {
         unreachable!();
     }
@@ -0,0 +1,248 @@
+use alloc::vec::Vec;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::endian::Endianness;
+use crate::write::{
+    Object, Relocation, SectionId, StandardSection, Symbol, SymbolId, SymbolSection,
+};
+use crate::{
+    AddressSize, Architecture, BinaryFormat, RelocationEncoding, RelocationKind, SectionKind,
+    SymbolFlags, SymbolKind, SymbolScope,
+};
+
+/// The file formats and architectures that arbitrary objects are generated for.
+///
+/// These are all combinations that the writer supports for the sections, symbols,
+/// and relocations that are generated below.
+const TARGETS: &[(BinaryFormat, Architecture, Endianness)] = &[
+    #[cfg(feature = "coff")]
+    (BinaryFormat::Coff, Architecture::I386, Endianness::Little),
+    #[cfg(feature = "coff")]
+    (BinaryFormat::Coff, Architecture::X86_64, Endianness::Little),
+    #[cfg(feature = "coff")]
+    (BinaryFormat::Coff, Architecture::Arm, Endianness::Little),
+    #[cfg(feature = "coff")]
+    (
+        BinaryFormat::Coff,
+        Architecture::Aarch64,
+        Endianness::Little,
+    ),
+    #[cfg(feature = "elf")]
+    (BinaryFormat::Elf, Architecture::I386, Endianness::Little),
+    #[cfg(feature = "elf")]
+    (BinaryFormat::Elf, Architecture::X86_64, Endianness::Little),
+    #[cfg(feature = "elf")]
+    (BinaryFormat::Elf, Architecture::Arm, Endianness::Little),
+    #[cfg(feature = "elf")]
+    (BinaryFormat::Elf, Architecture::Aarch64, Endianness::Little),
+    #[cfg(feature = "elf")]
+    (BinaryFormat::Elf, Architecture::Mips, Endianness::Big),
+    #[cfg(feature = "elf")]
+    (BinaryFormat::Elf, Architecture::PowerPc64, Endianness::Big),
+    #[cfg(feature = "elf")]
+    (BinaryFormat::Elf, Architecture::Riscv64, Endianness::Little),
+    #[cfg(feature = "elf")]
+    (BinaryFormat::Elf, Architecture::S390x, Endianness::Big),
+    #[cfg(feature = "macho")]
+    (BinaryFormat::MachO, Architecture::I386, Endianness::Little),
+    #[cfg(feature = "macho")]
+    (
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    ),
+    #[cfg(feature = "macho")]
+    (
+        BinaryFormat::MachO,
+        Architecture::Aarch64,
+        Endianness::Little,
+    ),
+    #[cfg(feature = "xcoff")]
+    (BinaryFormat::Xcoff, Architecture::PowerPc, Endianness::Big),
+    #[cfg(feature = "xcoff")]
+    (
+        BinaryFormat::Xcoff,
+        Architecture::PowerPc64,
+        Endianness::Big,
+    ),
+];
+
+/// The standard sections that are supported by all of the targets.
+const SECTIONS: &[StandardSection] = &[
+    StandardSection::Text,
+    StandardSection::Data,
+    StandardSection::ReadOnlyData,
+    StandardSection::UninitializedData,
+];
+
+const MAX_SECTIONS: usize = 8;
+const MAX_SYMBOLS: usize = 16;
+const MAX_RELOCATIONS: usize = 16;
+const MAX_BSS_SIZE: u64 = 0x1000;
+
+/// Generate an object file for structure-aware fuzzing.
+///
+/// The generated object contains sections, symbols and relocations that satisfy the
+/// constraints of the writer, so that `Object::write` is expected to succeed, and the
+/// result is expected to be readable.
+///
+/// The section data is copied from the unstructured input.
+impl<'a> Arbitrary<'a> for Object<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let (format, architecture, endian) = *u.choose(TARGETS)?;
+        let mut object = Object::new(format, architecture, endian);
+        object.deterministic = u.arbitrary()?;
+
+        let mut sections = Vec::new();
+        for _ in 0..u.int_in_range(0..=MAX_SECTIONS)? {
+            let standard = *u.choose(SECTIONS)?;
+            let is_bss = standard == StandardSection::UninitializedData;
+            // Subsections are only supported for sections that have data, and
+            // Mach-O and XCOFF use the standard section instead.
+            let section = if !is_bss
+                && matches!(format, BinaryFormat::Coff | BinaryFormat::Elf)
+                && u.arbitrary()?
+            {
+                let name = arbitrary_name(u)?;
+                object.add_subsection(standard, &name, &[], 1).0
+            } else {
+                object.section_id(standard)
+            };
+            let align = 1 << u.int_in_range(0..=4)?;
+            if is_bss {
+                let size = u.int_in_range(0..=MAX_BSS_SIZE)?;
+                object.append_section_bss(section, size, align);
+            } else {
+                let data: &[u8] = u.arbitrary()?;
+                object.append_section_data(section, data, align);
+            }
+            if !sections.contains(&section) {
+                sections.push(section);
+            }
+        }
+
+        let mut symbols = Vec::new();
+        for _ in 0..u.int_in_range(0..=MAX_SYMBOLS)? {
+            let symbol = arbitrary_symbol(u, &object, &sections)?;
+            symbols.push(object.add_symbol(symbol));
+        }
+
+        for _ in 0..u.int_in_range(0..=MAX_RELOCATIONS)? {
+            if let Some((section, relocation)) =
+                arbitrary_relocation(u, &mut object, &sections, &symbols)?
+            {
+                // The relocation is constructed to be valid, so this can't fail.
+                object.add_relocation(section, relocation).unwrap();
+            }
+        }
+
+        Ok(object)
+    }
+}
+
+/// Generate a non-empty name that does not contain any null bytes.
+fn arbitrary_name(u: &mut Unstructured<'_>) -> Result<Vec<u8>> {
+    let mut name: Vec<u8> = u.arbitrary()?;
+    name.retain(|&c| c != 0);
+    if name.is_empty() {
+        name.push(b'a' + u.int_in_range(0..=25)?);
+    }
+    Ok(name)
+}
+
+fn arbitrary_symbol(
+    u: &mut Unstructured<'_>,
+    object: &Object<'_>,
+    sections: &[SectionId],
+) -> Result<Symbol> {
+    let name = arbitrary_name(u)?;
+    let (section, value, size, kind) = if sections.is_empty() || u.arbitrary()? {
+        let kind = *u.choose(&[SymbolKind::Text, SymbolKind::Data])?;
+        (SymbolSection::Undefined, 0, 0, kind)
+    } else {
+        let id = *u.choose(sections)?;
+        let section = object.section(id);
+        let value = u.int_in_range(0..=section.size)?;
+        let size = u.int_in_range(0..=section.size - value)?;
+        let kind = match section.kind {
+            SectionKind::Text => SymbolKind::Text,
+            _ => SymbolKind::Data,
+        };
+        (SymbolSection::Section(id), value, size, kind)
+    };
+    let scope = if section == SymbolSection::Undefined {
+        SymbolScope::Unknown
+    } else {
+        *u.choose(&[
+            SymbolScope::Compilation,
+            SymbolScope::Linkage,
+            SymbolScope::Dynamic,
+        ])?
+    };
+    // Weak COFF symbols require a default symbol.
+    let weak = object.format != BinaryFormat::Coff && u.arbitrary()?;
+    Ok(Symbol {
+        name,
+        value,
+        size,
+        kind,
+        scope,
+        weak,
+        section,
+        flags: SymbolFlags::None,
+    })
+}
+
+fn arbitrary_relocation(
+    u: &mut Unstructured<'_>,
+    object: &mut Object<'_>,
+    sections: &[SectionId],
+    symbols: &[SymbolId],
+) -> Result<Option<(SectionId, Relocation)>> {
+    let address_size = match object.architecture.address_size() {
+        Some(AddressSize::U64) if object.format != BinaryFormat::Coff => 64,
+        _ => 32,
+    };
+    let (kind, size, addend) = if object.architecture == Architecture::X86_64 && u.arbitrary()? {
+        (RelocationKind::Relative, 32, -4)
+    } else {
+        (RelocationKind::Absolute, address_size, 0)
+    };
+
+    // Relocations can only be applied to sections with enough data.
+    let bytes = u64::from(size / 8);
+    let candidates: Vec<SectionId> = sections
+        .iter()
+        .copied()
+        .filter(|&id| {
+            let section = object.section(id);
+            !section.is_bss() && section.data().len() as u64 >= bytes
+        })
+        .collect();
+    if candidates.is_empty() || (symbols.is_empty() && object.format == BinaryFormat::Xcoff) {
+        return Ok(None);
+    }
+    let section = *u.choose(&candidates)?;
+    let offset = u.int_in_range(0..=object.section(section).data().len() as u64 - bytes)?;
+
+    // XCOFF doesn't support section symbols.
+    let symbol = if symbols.is_empty() || (object.format != BinaryFormat::Xcoff && u.arbitrary()?) {
+        let target = *u.choose(sections)?;
+        object.section_symbol(target)
+    } else {
+        *u.choose(symbols)?
+    };
+
+    Ok(Some((
+        section,
+        Relocation {
+            offset,
+            size,
+            kind,
+            encoding: RelocationEncoding::Generic,
+            symbol,
+            addend,
+        },
+    )))
+}
@@ -0,0 +1,757 @@
+//! Support for writing archive files.
+//!
+//! This module provides [`Archive`], which collects members and their symbols
+//! and then writes a Unix archive containing a symbol table and, if required,
+//! an extended names table.
+
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[cfg(feature = "std")]
+use std::{boxed::Box, error, io};
+
+use crate::archive;
+#[cfg(all(feature = "read_core", feature = "elf"))]
+use crate::elf;
+#[cfg(all(feature = "read_core", feature = "macho"))]
+use crate::macho;
+#[cfg(feature = "read_core")]
+use crate::read::{self, FileKind, Object, ObjectSymbol};
+use crate::write::util::align;
+#[cfg(feature = "read_core")]
+use crate::write::util::read_error;
+#[cfg(feature = "std")]
+use crate::write::StreamingBuffer;
+use crate::write::{Error, Result, WritableBuffer};
+#[cfg(all(feature = "read_core", any(feature = "elf", feature = "macho")))]
+use crate::Endianness;
+#[cfg(feature = "read_core")]
+use crate::SymbolKind;
+
+/// The default file mode used for archive members.
+const DEFAULT_MODE: u64 = 0o644;
+
+/// The kind of archive format to write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ArchiveKind {
+    /// The GNU (or System V) archive format.
+    ///
+    /// The 64-bit symbol table is used if a member offset is too large
+    /// for the 32-bit symbol table.
+    Gnu,
+    /// The GNU (or System V) archive format with 64-bit symbol table.
+    Gnu64,
+    /// The BSD archive format.
+    ///
+    /// Long member names are stored using the `#1/len` convention.
+    Bsd,
+    /// The BSD archive format with 64-bit symbol table.
+    ///
+    /// This is used for Darwin.
+    Bsd64,
+}
+
+/// The way in which the metadata of archive members is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MemberMetadata {
+    /// Write zero timestamps, user IDs and group IDs, and a fixed file mode.
+    ///
+    /// This produces byte-for-byte reproducible archives, and matches the
+    /// behaviour of `ar D`.
+    Deterministic,
+    /// Write the timestamp, user ID, group ID, and file mode given for each member.
+    Preserve,
+}
+
+/// A member to be written to an archive.
+#[derive(Debug, Clone)]
+pub struct ArchiveMember<'a> {
+    /// The file name of the member.
+    pub name: Vec<u8>,
+    /// The file data of the member.
+    pub data: Cow<'a, [u8]>,
+    /// The file modification timestamp.
+    ///
+    /// Only written if the archive uses [`MemberMetadata::Preserve`].
+    pub date: u64,
+    /// The user ID.
+    ///
+    /// Only written if the archive uses [`MemberMetadata::Preserve`].
+    pub uid: u64,
+    /// The group ID.
+    ///
+    /// Only written if the archive uses [`MemberMetadata::Preserve`].
+    pub gid: u64,
+    /// The file mode.
+    ///
+    /// Only written if the archive uses [`MemberMetadata::Preserve`].
+    pub mode: u64,
+    /// The names of the symbols defined by the member.
+    ///
+    /// These are added to the archive symbol table.
+    pub symbols: Vec<Vec<u8>>,
+}
+
+impl<'a> ArchiveMember<'a> {
+    /// Create a member with the given name and data.
+    ///
+    /// The metadata is zero, the mode is `0o644`, and there are no symbols.
+    pub fn new<T>(name: Vec<u8>, data: T) -> Self
+    where
+        T: Into<Cow<'a, [u8]>>,
+    {
+        ArchiveMember {
+            name,
+            data: data.into(),
+            date: 0,
+            uid: 0,
+            gid: 0,
+            mode: DEFAULT_MODE,
+            symbols: Vec::new(),
+        }
+    }
+
+    /// Add the symbols that are defined by the member data to [`Self::symbols`].
+    ///
+    /// See [`visit_symbols`] for the symbols that are added.
+    #[cfg(feature = "read_core")]
+    pub fn add_symbols(&mut self) -> Result<()> {
+        let symbols = &mut self.symbols;
+        visit_symbols(&self.data, |name| symbols.push(name.to_vec()))
+    }
+}
+
+/// Call `f` with the name of each symbol in an archive member that should be
+/// added to the archive symbol table.
+///
+/// These are the global and weak symbols that are defined or common. The symbols
+/// are visited in symbol table order, without collecting them first. For ELF
+/// and Mach-O members, only the file header and the symbol table are read;
+/// other members are parsed with [`read::File::parse`].
+///
+/// For LLVM bitcode members, these are the global symbols in the bitcode symbol
+/// table that are defined and not format specific.
+///
+/// Members that are not object files have no symbols. Returns an error if the
+/// member is an object file that cannot be parsed.
+#[cfg(feature = "read_core")]
+pub fn visit_symbols<F: FnMut(&[u8])>(data: &[u8], mut f: F) -> Result<()> {
+    match FileKind::parse(data) {
+        #[cfg(feature = "bitcode")]
+        Ok(FileKind::LlvmBitcode) => {
+            let file =
+                read::bitcode::BitcodeFile::parse(data).map_err(|e| Error(format!("{}", e)))?;
+            let symbols = file.symbols().map_err(|e| Error(format!("{}", e)))?;
+            for symbol in symbols {
+                let symbol = symbol.map_err(|e| Error(format!("{}", e)))?;
+                if symbol.is_global() && !symbol.is_undefined() && !symbol.is_format_specific() {
+                    f(symbol.name());
+                }
+            }
+            return Ok(());
+        }
+        #[cfg(feature = "coff")]
+        Ok(FileKind::Coff) | Ok(FileKind::CoffBig) => {}
+        #[cfg(feature = "elf")]
+        Ok(FileKind::Elf32) => {
+            return visit_elf_symbols::<elf::FileHeader32<Endianness>, _>(data, f)
+                .map_err(read_error);
+        }
+        #[cfg(feature = "elf")]
+        Ok(FileKind::Elf64) => {
+            return visit_elf_symbols::<elf::FileHeader64<Endianness>, _>(data, f)
+                .map_err(read_error);
+        }
+        #[cfg(feature = "macho")]
+        Ok(FileKind::MachO32) => {
+            return visit_macho_symbols::<macho::MachHeader32<Endianness>, _>(data, f)
+                .map_err(read_error);
+        }
+        #[cfg(feature = "macho")]
+        Ok(FileKind::MachO64) => {
+            return visit_macho_symbols::<macho::MachHeader64<Endianness>, _>(data, f)
+                .map_err(read_error);
+        }
+        #[cfg(feature = "wasm")]
+        Ok(FileKind::Wasm) => {}
+        #[cfg(feature = "xcoff")]
+        Ok(FileKind::Xcoff32) | Ok(FileKind::Xcoff64) => {}
+        _ => return Ok(()),
+    }
+    let file = read::File::parse(data).map_err(|e| Error(format!("{}", e)))?;
+    for symbol in file.symbols() {
+        if symbol.is_undefined()
+            || symbol.is_local()
+            || matches!(symbol.kind(), SymbolKind::File | SymbolKind::Section)
+        {
+            continue;
+        }
+        if let Ok(name) = symbol.name_bytes() {
+            if !name.is_empty() {
+                f(name);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Visit the archive symbols of an ELF file using only its symbol table.
+///
+/// This matches the symbols chosen from [`read::File::symbols`].
+#[cfg(all(feature = "read_core", feature = "elf"))]
+fn visit_elf_symbols<Elf, F>(data: &[u8], mut f: F) -> read::Result<()>
+where
+    Elf: read::elf::FileHeader<Endian = Endianness>,
+    F: FnMut(&[u8]),
+{
+    use read::elf::Sym;
+
+    let header = Elf::parse(data)?;
+    let endian = header.endian()?;
+    let sections = header.sections(endian, data)?;
+    let symbols = sections.symbols(endian, data, elf::SHT_SYMTAB)?;
+    for symbol in symbols.iter() {
+        if symbol.st_bind() == elf::STB_LOCAL
+            || symbol.is_undefined(endian)
+            || matches!(symbol.st_type(), elf::STT_FILE | elf::STT_SECTION)
+        {
+            continue;
+        }
+        if let Ok(name) = symbols.symbol_name(endian, symbol) {
+            if !name.is_empty() {
+                f(name);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Visit the archive symbols of a Mach-O file using only its symbol table.
+///
+/// This matches the symbols chosen from [`read::File::symbols`].
+#[cfg(all(feature = "read_core", feature = "macho"))]
+fn visit_macho_symbols<Mach, F>(data: &[u8], mut f: F) -> read::Result<()>
+where
+    Mach: read::macho::MachHeader<Endian = Endianness>,
+    F: FnMut(&[u8]),
+{
+    use read::macho::Nlist;
+
+    let header = Mach::parse(data, 0)?;
+    let endian = header.endian()?;
+    let mut commands = header.load_commands(endian, data, 0)?;
+    while let Some(command) = commands.next()? {
+        let symtab = match command.symtab()? {
+            Some(symtab) => symtab,
+            None => continue,
+        };
+        let symbols = symtab.symbols::<Mach, _>(endian, data)?;
+        for nlist in symbols.iter() {
+            let n_type = nlist.n_type();
+            // Common symbols are undefined external symbols with a non-zero value.
+            let is_common = n_type & (macho::N_TYPE | macho::N_EXT) == macho::N_UNDF | macho::N_EXT
+                && nlist.n_value(endian).into() != 0u64;
+            if n_type & macho::N_STAB != 0
+                || n_type & macho::N_EXT == 0
+                || (nlist.is_undefined() && !is_common)
+            {
+                continue;
+            }
+            if let Ok(name) = nlist.name(endian, symbols.strings()) {
+                if !name.is_empty() {
+                    f(name);
+                }
+            }
+        }
+        break;
+    }
+    Ok(())
+}
+
+/// An identifier used to reference an archive member.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MemberId(usize);
+
+/// A writable archive file.
+#[derive(Debug)]
+pub struct Archive<'a> {
+    kind: ArchiveKind,
+    members: Vec<ArchiveMember<'a>>,
+    /// The way in which member metadata is written.
+    ///
+    /// Defaults to [`MemberMetadata::Deterministic`].
+    pub metadata: MemberMetadata,
+}
+
+impl<'a> Archive<'a> {
+    /// Create an empty archive.
+    pub fn new(kind: ArchiveKind) -> Self {
+        Archive {
+            kind,
+            members: Vec::new(),
+            metadata: MemberMetadata::Deterministic,
+        }
+    }
+
+    /// Return the archive format.
+    #[inline]
+    pub fn kind(&self) -> ArchiveKind {
+        self.kind
+    }
+
+    /// Specify the archive format.
+    #[inline]
+    pub fn set_kind(&mut self, kind: ArchiveKind) {
+        self.kind = kind;
+    }
+
+    /// Specify the way in which member metadata is written.
+    #[inline]
+    pub fn set_metadata(&mut self, metadata: MemberMetadata) {
+        self.metadata = metadata;
+    }
+
+    /// Add a member and return its `MemberId`.
+    pub fn add_member(&mut self, member: ArchiveMember<'a>) -> MemberId {
+        let id = MemberId(self.members.len());
+        self.members.push(member);
+        id
+    }
+
+    /// Get the member with the given `MemberId`.
+    #[inline]
+    pub fn member(&self, member: MemberId) -> &ArchiveMember<'a> {
+        &self.members[member.0]
+    }
+
+    /// Mutably get the member with the given `MemberId`.
+    #[inline]
+    pub fn member_mut(&mut self, member: MemberId) -> &mut ArchiveMember<'a> {
+        &mut self.members[member.0]
+    }
+
+    /// Mutably get all of the members.
+    ///
+    /// The members are independent, so this may be used to process them in
+    /// parallel, such as calling [`ArchiveMember::add_symbols`] for each member
+    /// from a thread pool.
+    #[inline]
+    pub fn members_mut(&mut self) -> &mut [ArchiveMember<'a>] {
+        &mut self.members
+    }
+
+    /// Call [`ArchiveMember::add_symbols`] for each member.
+    ///
+    /// The members are divided between scoped threads, up to the available
+    /// parallelism. Returns the first error in member order.
+    #[cfg(all(feature = "std", feature = "read_core"))]
+    // Scoped threads need Rust 1.63, which is within the MSRV for writing.
+    #[allow(clippy::incompatible_msrv)]
+    pub fn add_symbols(&mut self) -> Result<()> {
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_size = (self.members.len() + threads - 1) / threads;
+        if threads <= 1 || chunk_size <= 1 {
+            return self.members.iter_mut().try_for_each(|m| m.add_symbols());
+        }
+        std::thread::scope(|scope| {
+            let handles = self
+                .members
+                .chunks_mut(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || chunk.iter_mut().try_for_each(|m| m.add_symbols()))
+                })
+                .collect::<Vec<_>>();
+            for handle in handles {
+                handle.join().expect("archive symbol thread panicked")?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Write the archive to a `Vec`.
+    pub fn write(&self) -> Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        self.emit(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Write the archive to a `Write` implementation.
+    ///
+    /// Also flushes the writer.
+    ///
+    /// It is advisable to use a buffered writer like [`BufWriter`](std::io::BufWriter)
+    /// instead of an unbuffered writer like [`File`](std::fs::File).
+    #[cfg(feature = "std")]
+    pub fn write_stream<W: io::Write>(
+        &self,
+        w: W,
+    ) -> core::result::Result<(), Box<dyn error::Error>> {
+        let mut stream = StreamingBuffer::new(w);
+        self.emit(&mut stream)?;
+        stream.result()?;
+        stream.into_inner().flush()?;
+        Ok(())
+    }
+
+    /// Write the archive to a `WritableBuffer`.
+    pub fn emit(&self, buffer: &mut dyn WritableBuffer) -> Result<()> {
+        match self.kind {
+            ArchiveKind::Gnu | ArchiveKind::Gnu64 => self.gnu_write(buffer),
+            ArchiveKind::Bsd | ArchiveKind::Bsd64 => self.bsd_write(buffer),
+        }
+    }
+
+    fn gnu_write(&self, buffer: &mut dyn WritableBuffer) -> Result<()> {
+        let header_len = core::mem::size_of::<archive::Header>();
+
+        // Calculate the extended names table.
+        // Names that are too long, or that contain a '/', are stored in the table.
+        let mut names = Vec::new();
+        let mut name_offsets = Vec::with_capacity(self.members.len());
+        for member in &self.members {
+            if member.name.len() > 15 || member.name.contains(&b'/') {
+                name_offsets.push(Some(names.len()));
+                names.extend_from_slice(&member.name);
+                names.extend_from_slice(b"/\n");
+            } else {
+                name_offsets.push(None);
+            }
+        }
+
+        let (symbol_count, symbol_names_len) = self.symbol_table_size()?;
+
+        // Calculate the member offsets.
+        // The GNU format switches to the 64-bit symbol table if the offsets
+        // don't fit in 32 bits.
+        let mut is_64 = self.kind == ArchiveKind::Gnu64;
+        let (symtab_len, member_offsets, total_len) = loop {
+            let word_size = if is_64 { 8 } else { 4 };
+            let symtab_len = if symbol_count != 0 || is_64 {
+                word_size * (1 + symbol_count) + symbol_names_len
+            } else {
+                0
+            };
+            let mut offset = archive::MAGIC.len() as u64;
+            if symtab_len != 0 {
+                offset += (header_len + align_even(symtab_len)) as u64;
+            }
+            if !names.is_empty() {
+                offset += (header_len + align_even(names.len())) as u64;
+            }
+            let mut member_offsets = Vec::with_capacity(self.members.len());
+            let mut symbol_offset_max = 0;
+            for member in &self.members {
+                if !member.symbols.is_empty() {
+                    symbol_offset_max = offset;
+                }
+                member_offsets.push(offset);
+                offset += (header_len + align_even(member.data.len())) as u64;
+            }
+            if !is_64 && symbol_offset_max > u64::from(u32::MAX) {
+                is_64 = true;
+                continue;
+            }
+            break (symtab_len, member_offsets, offset);
+        };
+        let total_len = usize::try_from(total_len)
+            .map_err(|_| Error(format!("archive size {:#x} is too large", total_len)))?;
+
+        buffer
+            .reserve(total_len)
+            .map_err(|_| Error(String::from("Cannot allocate buffer")))?;
+
+        buffer.write_bytes(&archive::MAGIC);
+
+        // Write the symbol table.
+        if symtab_len != 0 {
+            if is_64 {
+                self.write_special_header(buffer, b"/SYM64/", symtab_len)?;
+                buffer.write_bytes(&(symbol_count as u64).to_be_bytes());
+            } else {
+                self.write_special_header(buffer, b"/", symtab_len)?;
+                buffer.write_bytes(&(symbol_count as u32).to_be_bytes());
+            }
+            for (member, member_offset) in self.members.iter().zip(&member_offsets) {
+                for _ in &member.symbols {
+                    if is_64 {
+                        buffer.write_bytes(&member_offset.to_be_bytes());
+                    } else {
+                        buffer.write_bytes(&(*member_offset as u32).to_be_bytes());
+                    }
+                }
+            }
+            for member in &self.members {
+                for symbol in &member.symbols {
+                    buffer.write_bytes(symbol);
+                    buffer.write_bytes(&[0]);
+                }
+            }
+            write_pad(buffer, symtab_len);
+        }
+
+        // Write the extended names table.
+        if !names.is_empty() {
+            self.write_special_header(buffer, b"//", names.len())?;
+            buffer.write_bytes(&names);
+            write_pad(buffer, names.len());
+        }
+
+        // Write the members.
+        for ((member, name_offset), member_offset) in
+            self.members.iter().zip(&name_offsets).zip(&member_offsets)
+        {
+            debug_assert_eq!(buffer.len() as u64, *member_offset);
+            let mut name = Vec::with_capacity(16);
+            if let Some(name_offset) = name_offset {
+                name.push(b'/');
+                name.extend_from_slice(name_offset.to_string().as_bytes());
+            } else {
+                name.extend_from_slice(&member.name);
+                name.push(b'/');
+            }
+            self.write_member_header(buffer, &name, member, 0)?;
+            buffer.write_bytes(&member.data);
+            write_pad(buffer, member.data.len());
+        }
+
+        debug_assert_eq!(buffer.len(), total_len);
+        Ok(())
+    }
+
+    fn bsd_write(&self, buffer: &mut dyn WritableBuffer) -> Result<()> {
+        let header_len = core::mem::size_of::<archive::Header>();
+        let is_64 = self.kind == ArchiveKind::Bsd64;
+        let word_size = if is_64 { 8 } else { 4 };
+
+        // Calculate the symbol table size.
+        // The table contains a size, pairs of string and member offsets, another size,
+        // and then the strings. The strings are padded to the word size.
+        let (symbol_count, symbol_names_len) = self.symbol_table_size()?;
+        let strtab_len = align(symbol_names_len, word_size);
+        let symtab_len = if symbol_count != 0 {
+            word_size * (2 + 2 * symbol_count) + strtab_len
+        } else {
+            0
+        };
+
+        // Calculate the member offsets.
+        // Names that are too long, or that contain a space or '/', are stored after the header.
+        // These names are padded so that the member data is 8-byte aligned.
+        // The symbol table always uses an extended name, since that is what
+        // other tools expect.
+        let symtab_name: &[u8] = if is_64 { b"__.SYMDEF_64" } else { b"__.SYMDEF" };
+        let mut offset = archive::MAGIC.len() as u64;
+        let symtab_name_len = bsd_name_len(offset + header_len as u64, symtab_name);
+        if symtab_len != 0 {
+            offset += (header_len + align_even(symtab_name_len + symtab_len)) as u64;
+        }
+        let mut member_offsets = Vec::with_capacity(self.members.len());
+        let mut name_lens = Vec::with_capacity(self.members.len());
+        for member in &self.members {
+            member_offsets.push(offset);
+            offset += header_len as u64;
+            let name_len = if member.name.len() > 16
+                || member.name.contains(&b' ')
+                || member.name.contains(&b'/')
+            {
+                Some(bsd_name_len(offset, &member.name))
+            } else {
+                None
+            };
+            name_lens.push(name_len);
+            let size = name_len.unwrap_or(0) + member.data.len();
+            offset += align_even(size) as u64;
+        }
+        if !is_64 {
+            if let Some(member_offset) = member_offsets.last() {
+                if symbol_count != 0 && *member_offset > u64::from(u32::MAX) {
+                    return Err(Error(format!(
+                        "archive member offset {:#x} is too large for a 32-bit symbol table",
+                        member_offset
+                    )));
+                }
+            }
+        }
+        let total_len = usize::try_from(offset)
+            .map_err(|_| Error(format!("archive size {:#x} is too large", offset)))?;
+
+        buffer
+            .reserve(total_len)
+            .map_err(|_| Error(String::from("Cannot allocate buffer")))?;
+
+        buffer.write_bytes(&archive::MAGIC);
+
+        // Write the symbol table.
+        if symtab_len != 0 {
+            let write_word = |buffer: &mut dyn WritableBuffer, val: u64| {
+                if is_64 {
+                    buffer.write_bytes(&val.to_le_bytes());
+                } else {
+                    buffer.write_bytes(&(val as u32).to_le_bytes());
+                }
+            };
+            let name = format!("#1/{}", symtab_name_len);
+            self.write_special_header(buffer, name.as_bytes(), symtab_name_len + symtab_len)?;
+            buffer.write_bytes(symtab_name);
+            buffer.resize(buffer.len() + symtab_name_len - symtab_name.len());
+            write_word(buffer, (2 * word_size * symbol_count) as u64);
+            let mut string_offset = 0;
+            for (member, member_offset) in self.members.iter().zip(&member_offsets) {
+                for symbol in &member.symbols {
+                    write_word(buffer, string_offset as u64);
+                    write_word(buffer, *member_offset);
+                    string_offset += symbol.len() + 1;
+                }
+            }
+            write_word(buffer, strtab_len as u64);
+            for member in &self.members {
+                for symbol in &member.symbols {
+                    buffer.write_bytes(symbol);
+                    buffer.write_bytes(&[0]);
+                }
+            }
+            buffer.resize(buffer.len() + strtab_len - symbol_names_len);
+            write_pad(buffer, symtab_name_len + symtab_len);
+        }
+
+        // Write the members.
+        for ((member, name_len), member_offset) in
+            self.members.iter().zip(&name_lens).zip(&member_offsets)
+        {
+            debug_assert_eq!(buffer.len() as u64, *member_offset);
+            if let Some(name_len) = *name_len {
+                let mut name = Vec::with_capacity(16);
+                name.extend_from_slice(b"#1/");
+                name.extend_from_slice(name_len.to_string().as_bytes());
+                self.write_member_header(buffer, &name, member, name_len)?;
+                buffer.write_bytes(&member.name);
+                buffer.resize(buffer.len() + name_len - member.name.len());
+                buffer.write_bytes(&member.data);
+                write_pad(buffer, name_len + member.data.len());
+            } else {
+                self.write_member_header(buffer, &member.name, member, 0)?;
+                buffer.write_bytes(&member.data);
+                write_pad(buffer, member.data.len());
+            }
+        }
+
+        debug_assert_eq!(buffer.len(), total_len);
+        Ok(())
+    }
+
+    /// Return the number of symbols and the total length of their null-terminated names.
+    fn symbol_table_size(&self) -> Result<(usize, usize)> {
+        let mut symbol_count = 0;
+        let mut symbol_names_len = 0;
+        for member in &self.members {
+            for symbol in &member.symbols {
+                if symbol.contains(&0) {
+                    return Err(Error(format!(
+                        "archive symbol name contains a null byte: {:?}",
+                        symbol
+                    )));
+                }
+                symbol_count += 1;
+                symbol_names_len += symbol.len() + 1;
+            }
+        }
+        Ok((symbol_count, symbol_names_len))
+    }
+
+    fn write_member_header(
+        &self,
+        buffer: &mut dyn WritableBuffer,
+        name: &[u8],
+        member: &ArchiveMember<'_>,
+        name_len: usize,
+    ) -> Result<()> {
+        let (date, uid, gid, mode) = match self.metadata {
+            MemberMetadata::Deterministic => (0, 0, 0, DEFAULT_MODE),
+            MemberMetadata::Preserve => (member.date, member.uid, member.gid, member.mode),
+        };
+        write_header(
+            buffer,
+            name,
+            date,
+            uid,
+            gid,
+            mode,
+            name_len + member.data.len(),
+        )
+    }
+
+    fn write_special_header(
+        &self,
+        buffer: &mut dyn WritableBuffer,
+        name: &[u8],
+        size: usize,
+    ) -> Result<()> {
+        // Special members never have meaningful metadata.
+        write_header(buffer, name, 0, 0, 0, 0, size)
+    }
+}
+
+fn write_header(
+    buffer: &mut dyn WritableBuffer,
+    name: &[u8],
+    date: u64,
+    uid: u64,
+    gid: u64,
+    mode: u64,
+    size: usize,
+) -> Result<()> {
+    let mut header = archive::Header {
+        name: [b' '; 16],
+        date: [b' '; 12],
+        uid: [b' '; 6],
+        gid: [b' '; 6],
+        mode: [b' '; 8],
+        size: [b' '; 10],
+        terminator: archive::TERMINATOR,
+    };
+    write_field(&mut header.name, name, "name")?;
+    write_field(&mut header.date, format!("{}", date).as_bytes(), "date")?;
+    write_field(&mut header.uid, format!("{}", uid).as_bytes(), "uid")?;
+    write_field(&mut header.gid, format!("{}", gid).as_bytes(), "gid")?;
+    write_field(&mut header.mode, format!("{:o}", mode).as_bytes(), "mode")?;
+    write_field(&mut header.size, format!("{}", size).as_bytes(), "size")?;
+    buffer.write(&header);
+    Ok(())
+}
+
+fn write_field(field: &mut [u8], value: &[u8], desc: &str) -> Result<()> {
+    field
+        .get_mut(..value.len())
+        .ok_or_else(|| {
+            Error(format!(
+                "archive member {} {:?} is too long",
+                desc,
+                String::from_utf8_lossy(value)
+            ))
+        })?
+        .copy_from_slice(value);
+    Ok(())
+}
+
+/// Return the padded length of a BSD extended name.
+///
+/// The name is padded with null bytes so that the member data is 8-byte aligned.
+fn bsd_name_len(offset: u64, name: &[u8]) -> usize {
+    let name_end = offset + name.len() as u64;
+    name.len() + (name_end.wrapping_neg() & 7) as usize
+}
+
+/// Members are padded to an even number of bytes.
+fn write_pad(buffer: &mut dyn WritableBuffer, len: usize) {
+    if len & 1 != 0 {
+        buffer.write_bytes(b"\n");
+    }
+}
+
+#[inline]
+fn align_even(len: usize) -> usize {
+    len + (len & 1)
+}
@@ -33,7 +33,53 @@ pub enum CoffExportStyle {
     Gnu,
 }
 
+/// The way in which COFF section names that are longer than 8 bytes are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CoffLongSectionNames {
+    /// Store the name in the string table, and write a `/offset` reference in the
+    /// section header.
+    ///
+    /// This is the default.
+    StringTable,
+    /// Truncate the name to 8 bytes.
+    ///
+    /// The name of the section symbol is also truncated.
+    Truncate,
+    /// Return an error when writing the file.
+    Error,
+}
+
 impl<'a> Object<'a> {
+    /// Specify the way in which COFF section names that are longer than 8 bytes are written.
+    ///
+    /// Requires `feature = "coff"`.
+    #[inline]
+    pub fn set_coff_long_section_names(&mut self, long_section_names: CoffLongSectionNames) {
+        self.coff_long_section_names = long_section_names;
+    }
+
+    /// Return the name of a COFF section as it will be written.
+    ///
+    /// This is the name that readers will see after resolving any string table
+    /// reference, which depends on the `CoffLongSectionNames` setting.
+    ///
+    /// Requires `feature = "coff"`.
+    pub fn coff_section_name(&self, section: SectionId) -> Result<&[u8]> {
+        let name = &self.sections[section.0].name[..];
+        if name.len() <= 8 {
+            return Ok(name);
+        }
+        match self.coff_long_section_names {
+            CoffLongSectionNames::StringTable => Ok(name),
+            CoffLongSectionNames::Truncate => Ok(&name[..8]),
+            CoffLongSectionNames::Error => Err(Error(format!(
+                "section name `{}` is too long",
+                self.sections[section.0].name().unwrap_or("")
+            ))),
+        }
+    }
+
     pub(crate) fn coff_section_info(
         &self,
         section: StandardSection,
@@ -201,8 +247,9 @@ impl<'a> Object<'a> {
         // Calculate size of section data and add section strings to strtab.
         let mut section_offsets = vec![SectionOffsets::default(); self.sections.len()];
         for (index, section) in self.sections.iter().enumerate() {
-            if section.name.len() > 8 {
-                section_offsets[index].str_id = Some(strtab.add(&section.name));
+            let name = self.coff_section_name(SectionId(index))?;
+            if name.len() > 8 {
+                section_offsets[index].str_id = Some(strtab.add(name));
             }
 
             let len = section.data.len();
@@ -226,6 +273,22 @@ impl<'a> Object<'a> {
             }
         }
 
+        // Set COMDAT flags from the section symbols, such as when copying from
+        // another file. These are replaced by any COMDATs.
+        for (index, section) in self.sections.iter().enumerate() {
+            if let Some(symbol) = section.symbol {
+                if let SymbolFlags::CoffSection {
+                    selection,
+                    associative_section,
+                } = self.symbols[symbol.0].flags
+                {
+                    section_offsets[index].selection = selection;
+                    section_offsets[index].associative_section =
+                        associative_section.map(|id| id.0 as u16 + 1).unwrap_or(0);
+                }
+            }
+        }
+
         // Set COMDAT flags.
         for comdat in &self.comdats {
             let symbol = &self.symbols[comdat.symbol.0];
@@ -270,9 +333,10 @@ impl<'a> Object<'a> {
         }
 
         // Calculate size of symbols and add symbol strings to strtab.
+        let symbols = self.ordered_symbols();
         let mut symbol_offsets = vec![SymbolOffsets::default(); self.symbols.len()];
         let mut symtab_count = 0;
-        for (index, symbol) in self.symbols.iter().enumerate() {
+        for &(index, symbol) in &symbols {
             symbol_offsets[index].index = symtab_count;
             symtab_count += 1;
             match symbol.kind {
@@ -289,10 +353,24 @@ impl<'a> Object<'a> {
                     symbol_offsets[index].aux_count = 1;
                     symtab_count += 1;
                 }
-                _ => {}
+                _ => {
+                    if let SymbolFlags::CoffSymbol {
+                        storage_class: coff::IMAGE_SYM_CLASS_WEAK_EXTERNAL,
+                        weak_default_symbol: Some(_),
+                        ..
+                    } = symbol.flags
+                    {
+                        symbol_offsets[index].aux_count = 1;
+                        symtab_count += 1;
+                    }
+                }
             }
-            if symbol.name.len() > 8 {
-                symbol_offsets[index].str_id = Some(strtab.add(&symbol.name));
+            let name = match (symbol.kind, symbol.section) {
+                (SymbolKind::Section, SymbolSection::Section(id)) => self.coff_section_name(id)?,
+                _ => &symbol.name,
+            };
+            if name.len() > 8 {
+                symbol_offsets[index].str_id = Some(strtab.add(name));
             }
         }
 
@@ -369,7 +447,10 @@ impl<'a> Object<'a> {
                     }
                     SectionKind::ReadOnlyData
                     | SectionKind::ReadOnlyDataWithRel
-                    | SectionKind::ReadOnlyString => {
+                    | SectionKind::ReadOnlyString
+                    | SectionKind::InitArray
+                    | SectionKind::FiniArray
+                    | SectionKind::Unwind => {
                         coff::IMAGE_SCN_CNT_INITIALIZED_DATA | coff::IMAGE_SCN_MEM_READ
                     }
                     SectionKind::Debug | SectionKind::Other | SectionKind::OtherString => {
@@ -385,6 +466,9 @@ impl<'a> Object<'a> {
                     | SectionKind::Note
                     | SectionKind::Unknown
                     | SectionKind::Metadata
+                    | SectionKind::Got
+                    | SectionKind::Plt
+                    | SectionKind::Dynamic
                     | SectionKind::Elf(_) => {
                         return Err(Error(format!(
                             "unimplemented section `{}` kind {:?}",
@@ -400,7 +484,9 @@ impl<'a> Object<'a> {
             if section.relocations.len() > 0xffff {
                 characteristics |= coff::IMAGE_SCN_LNK_NRELOC_OVFL;
             }
+            // Keep any alignment in explicit characteristics.
             characteristics |= match section.align {
+                _ if characteristics & coff::IMAGE_SCN_ALIGN_MASK != 0 => 0,
                 1 => coff::IMAGE_SCN_ALIGN_1BYTES,
                 2 => coff::IMAGE_SCN_ALIGN_2BYTES,
                 4 => coff::IMAGE_SCN_ALIGN_4BYTES,
@@ -439,8 +525,9 @@ impl<'a> Object<'a> {
                 number_of_linenumbers: U16::default(),
                 characteristics: U32::new(LE, characteristics),
             };
-            if section.name.len() <= 8 {
-                coff_section.name[..section.name.len()].copy_from_slice(&section.name);
+            let name = self.coff_section_name(SectionId(index))?;
+            if name.len() <= 8 {
+                coff_section.name[..name.len()].copy_from_slice(name);
             } else {
                 let mut str_offset = strtab.get_offset(section_offsets[index].str_id.unwrap());
                 if str_offset <= 9_999_999 {
@@ -502,7 +589,7 @@ impl<'a> Object<'a> {
                     };
                     buffer.write(&coff_relocation);
                 }
-                for reloc in &section.relocations {
+                for reloc in self.ordered_relocations(section) {
                     //assert!(reloc.implicit_addend);
                     let typ = match self.architecture {
                         Architecture::I386 => match (reloc.kind, reloc.size, reloc.addend) {
@@ -582,8 +669,11 @@ impl<'a> Object<'a> {
 
         // Write symbols.
         debug_assert_eq!(symtab_offset, buffer.len());
-        for (index, symbol) in self.symbols.iter().enumerate() {
-            let mut name = &symbol.name[..];
+        for &(index, symbol) in &symbols {
+            let mut name = match (symbol.kind, symbol.section) {
+                (SymbolKind::Section, SymbolSection::Section(id)) => self.coff_section_name(id)?,
+                _ => &symbol.name[..],
+            };
             let section_number = match symbol.section {
                 SymbolSection::None => {
                     debug_assert_eq!(symbol.kind, SymbolKind::File);
@@ -594,12 +684,12 @@ impl<'a> Object<'a> {
                 SymbolSection::Common => coff::IMAGE_SYM_UNDEFINED as u16,
                 SymbolSection::Section(id) => id.0 as u16 + 1,
             };
-            let typ = if symbol.kind == SymbolKind::Text {
+            let mut typ = if symbol.kind == SymbolKind::Text {
                 coff::IMAGE_SYM_DTYPE_FUNCTION << coff::IMAGE_SYM_DTYPE_SHIFT
             } else {
                 coff::IMAGE_SYM_TYPE_NULL
             };
-            let storage_class = match symbol.kind {
+            let mut storage_class = match symbol.kind {
                 SymbolKind::File => {
                     // Name goes in auxiliary symbol records.
                     name = b".file";
@@ -645,6 +735,18 @@ impl<'a> Object<'a> {
                     )));
                 }
             };
+            // Explicit flags override the values derived from the symbol kind.
+            if let SymbolFlags::CoffSymbol {
+                typ: flags_typ,
+                storage_class: flags_storage_class,
+                ..
+            } = symbol.flags
+            {
+                if symbol.kind != SymbolKind::File {
+                    typ = flags_typ;
+                    storage_class = flags_storage_class;
+                }
+            }
             let number_of_aux_symbols = symbol_offsets[index].aux_count;
             let value = if symbol.section == SymbolSection::Common {
                 symbol.size as u32
@@ -688,7 +790,7 @@ impl<'a> Object<'a> {
                             U16Bytes::new(LE, section.relocations.len() as u16)
                         },
                         number_of_linenumbers: U16Bytes::default(),
-                        check_sum: U32Bytes::new(LE, checksum(section.data())),
+                        check_sum: U32Bytes::new(LE, checksum(&section.data)),
                         number: U16Bytes::new(
                             LE,
                             section_offsets[section_index].associative_section,
@@ -701,7 +803,29 @@ impl<'a> Object<'a> {
                     buffer.write(&aux);
                 }
                 _ => {
-                    debug_assert_eq!(number_of_aux_symbols, 0);
+                    if let SymbolFlags::CoffSymbol {
+                        storage_class: coff::IMAGE_SYM_CLASS_WEAK_EXTERNAL,
+                        weak_default_symbol: Some(default_symbol),
+                        weak_search_type,
+                        ..
+                    } = symbol.flags
+                    {
+                        debug_assert_eq!(number_of_aux_symbols, 1);
+                        let aux = coff::ImageAuxSymbolWeak {
+                            weak_default_sym_index: U32Bytes::new(
+                                LE,
+                                symbol_offsets[default_symbol.0].index as u32,
+                            ),
+                            weak_search_type: U32Bytes::new(LE, weak_search_type),
+                        };
+                        buffer.write(&aux);
+                        buffer.write_bytes(
+                            &[0; coff::IMAGE_SIZEOF_SYMBOL
+                                - mem::size_of::<coff::ImageAuxSymbolWeak>()],
+                        );
+                    } else {
+                        debug_assert_eq!(number_of_aux_symbols, 0);
+                    }
                 }
             }
         }
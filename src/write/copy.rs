@@ -0,0 +1,277 @@
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use hashbrown::HashMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::read::{
+    self, Object as _, ObjectComdat, ObjectSection, ObjectSymbol, ReadRef, RelocationTarget,
+    SectionIndex, SymbolIndex,
+};
+use crate::write::util::read_error;
+use crate::write::{
+    Comdat, Error, Mangling, Object, Relocation, Result, SectionId, Symbol, SymbolId, SymbolSection,
+};
+use crate::{ObjectKind, SectionKind, SymbolFlags, SymbolKind};
+
+/// Hooks for customizing the conversion of a `read::File` into an `Object`.
+///
+/// The default implementations keep everything unchanged.
+pub trait CopyHooks<'data, R: ReadRef<'data>> {
+    /// Return the name to use for a section, or `None` to remove the section.
+    ///
+    /// Removing a section also removes its relocations and the symbols defined in it.
+    fn section_name(
+        &mut self,
+        section: &read::Section<'data, '_, R>,
+        name: &[u8],
+    ) -> Option<Vec<u8>> {
+        let _ = section;
+        Some(name.to_vec())
+    }
+
+    /// Return the name to use for a symbol, or `None` to remove the symbol.
+    ///
+    /// It is an error to remove a symbol that is the target of a relocation.
+    fn symbol_name(&mut self, symbol: &read::Symbol<'data, '_, R>, name: &[u8]) -> Option<Vec<u8>> {
+        let _ = symbol;
+        Some(name.to_vec())
+    }
+
+    /// Modify a symbol before it is added to the object.
+    ///
+    /// This is called after `symbol_name`. It may be used to change the scope or
+    /// weakness of the symbol with [`Symbol::set_scope`] and [`Symbol::set_weak`].
+    fn update_symbol(&mut self, symbol: &read::Symbol<'data, '_, R>, out: &mut Symbol) {
+        let _ = (symbol, out);
+    }
+}
+
+/// The `CopyHooks` that keep everything unchanged.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultCopyHooks;
+
+impl<'data, R: ReadRef<'data>> CopyHooks<'data, R> for DefaultCopyHooks {}
+
+impl<'a> Object<'a> {
+    /// Create an object containing the sections, symbols, relocations and COMDATs
+    /// of a relocatable object file.
+    ///
+    /// The section data is borrowed from the file.
+    pub fn from_file<R: ReadRef<'a>>(file: &read::File<'a, R>) -> Result<Self> {
+        Self::from_file_with_hooks(file, &mut DefaultCopyHooks)
+    }
+
+    /// Create an object containing the sections, symbols, relocations and COMDATs
+    /// of a relocatable object file, using the given hooks to filter and rename them.
+    ///
+    /// The section data is borrowed from the file.
+    pub fn from_file_with_hooks<R: ReadRef<'a>, H: CopyHooks<'a, R>>(
+        file: &read::File<'a, R>,
+        hooks: &mut H,
+    ) -> Result<Self> {
+        let mut object = Object::new(file.format(), file.architecture(), file.endianness());
+        // Symbol names in the file are already mangled.
+        object.mangling = Mangling::None;
+        object.flags = file.flags();
+        object.append_file(file, hooks)?;
+        Ok(object)
+    }
+
+    /// Append the sections, symbols, relocations and COMDATs of a relocatable
+    /// object file to this object.
+    ///
+    /// The file may use a different format to this object, but format specific
+    /// flags are copied unchanged, and may be ignored when writing.
+    /// You may need to set `mangling` to `Mangling::None` before calling this.
+    pub fn append_file<R: ReadRef<'a>, H: CopyHooks<'a, R>>(
+        &mut self,
+        file: &read::File<'a, R>,
+        hooks: &mut H,
+    ) -> Result<()> {
+        if file.kind() != ObjectKind::Relocatable {
+            return Err(Error(format!("unsupported object kind {:?}", file.kind())));
+        }
+
+        let mut sections: HashMap<SectionIndex, SectionId> = HashMap::new();
+        for in_section in file.sections() {
+            // These are generated when writing.
+            if in_section.kind() == SectionKind::Metadata
+                || in_section.kind() == SectionKind::Dynamic
+            {
+                continue;
+            }
+            let name = in_section.name_bytes().map_err(read_error)?;
+            let name = match hooks.section_name(&in_section, name) {
+                Some(name) => name,
+                None => continue,
+            };
+            let segment = in_section
+                .segment_name_bytes()
+                .map_err(read_error)?
+                .unwrap_or(&[])
+                .to_vec();
+            let section_id = self.add_section(segment, name, in_section.kind());
+            let out_section = self.section_mut(section_id);
+            if out_section.is_bss() {
+                out_section.append_bss(in_section.size(), in_section.align());
+            } else {
+                out_section.set_data(in_section.data().map_err(read_error)?, in_section.align());
+            }
+            out_section.flags = in_section.flags();
+            sections.insert(in_section.index(), section_id);
+        }
+
+        let mut symbols: HashMap<SymbolIndex, SymbolId> = HashMap::new();
+        for in_symbol in file.symbols() {
+            if in_symbol.kind() == SymbolKind::Null {
+                continue;
+            }
+            let (section, value) = match in_symbol.section() {
+                read::SymbolSection::None => (SymbolSection::None, in_symbol.address()),
+                read::SymbolSection::Undefined => (SymbolSection::Undefined, in_symbol.address()),
+                read::SymbolSection::Absolute => (SymbolSection::Absolute, in_symbol.address()),
+                read::SymbolSection::Common => (SymbolSection::Common, in_symbol.address()),
+                read::SymbolSection::Section(index) => match sections.get(&index) {
+                    Some(section_id) => {
+                        let in_section = file.section_by_index(index).map_err(read_error)?;
+                        (
+                            SymbolSection::Section(*section_id),
+                            in_symbol.address() - in_section.address(),
+                        )
+                    }
+                    // The section was removed.
+                    None => continue,
+                },
+                _ => {
+                    return Err(Error(format!(
+                        "unsupported section for symbol `{}`",
+                        in_symbol.name().unwrap_or("")
+                    )))
+                }
+            };
+            let name = in_symbol.name_bytes().map_err(read_error)?;
+            let name = match hooks.symbol_name(&in_symbol, name) {
+                Some(name) => name,
+                None => continue,
+            };
+            let flags = match in_symbol.flags() {
+                SymbolFlags::None => SymbolFlags::None,
+                SymbolFlags::Elf { st_info, st_other } => SymbolFlags::Elf { st_info, st_other },
+                SymbolFlags::MachO { n_desc } => SymbolFlags::MachO { n_desc },
+                SymbolFlags::CoffSection {
+                    selection,
+                    associative_section,
+                } => SymbolFlags::CoffSection {
+                    selection,
+                    associative_section: associative_section
+                        .and_then(|index| sections.get(&index).copied()),
+                },
+                SymbolFlags::CoffSymbol {
+                    typ,
+                    storage_class,
+                    weak_default_symbol,
+                    weak_search_type,
+                } => SymbolFlags::CoffSymbol {
+                    typ,
+                    storage_class,
+                    // The default symbol must precede the weak external.
+                    weak_default_symbol: weak_default_symbol
+                        .and_then(|index| symbols.get(&index).copied()),
+                    weak_search_type,
+                },
+                SymbolFlags::Xcoff {
+                    n_sclass,
+                    x_smtyp,
+                    x_smclas,
+                    containing_csect,
+                } => SymbolFlags::Xcoff {
+                    n_sclass,
+                    x_smtyp,
+                    x_smclas,
+                    containing_csect: containing_csect
+                        .and_then(|index| symbols.get(&index).copied()),
+                },
+            };
+            let mut out_symbol = Symbol {
+                name,
+                value,
+                size: in_symbol.size(),
+                kind: in_symbol.kind(),
+                scope: in_symbol.scope(),
+                weak: in_symbol.is_weak(),
+                section,
+                flags,
+            };
+            hooks.update_symbol(&in_symbol, &mut out_symbol);
+            let symbol_id = self.add_symbol(out_symbol);
+            symbols.insert(in_symbol.index(), symbol_id);
+        }
+
+        for in_section in file.sections() {
+            let section_id = match sections.get(&in_section.index()) {
+                Some(section_id) => *section_id,
+                None => continue,
+            };
+            for (offset, in_relocation) in in_section.relocations() {
+                let symbol = match in_relocation.target() {
+                    RelocationTarget::Symbol(index) => match symbols.get(&index) {
+                        Some(symbol) => *symbol,
+                        None => {
+                            return Err(Error(format!(
+                                "relocation at offset {:#x} in section `{}` refers to a removed symbol",
+                                offset,
+                                in_section.name().unwrap_or("")
+                            )))
+                        }
+                    },
+                    RelocationTarget::Section(index) => match sections.get(&index) {
+                        Some(section) => self.section_symbol(*section),
+                        None => {
+                            return Err(Error(format!(
+                                "relocation at offset {:#x} in section `{}` refers to a removed section",
+                                offset,
+                                in_section.name().unwrap_or("")
+                            )))
+                        }
+                    },
+                    _ => {
+                        return Err(Error(format!(
+                            "unsupported target for relocation at offset {:#x} in section `{}`",
+                            offset,
+                            in_section.name().unwrap_or("")
+                        )))
+                    }
+                };
+                self.add_relocation(
+                    section_id,
+                    Relocation {
+                        offset,
+                        size: in_relocation.size(),
+                        kind: in_relocation.kind(),
+                        encoding: in_relocation.encoding(),
+                        symbol,
+                        addend: in_relocation.addend(),
+                    },
+                )?;
+            }
+        }
+
+        for in_comdat in file.comdats() {
+            let symbol = match symbols.get(&in_comdat.symbol()) {
+                Some(symbol) => *symbol,
+                None => continue,
+            };
+            let sections = in_comdat
+                .sections()
+                .filter_map(|index| sections.get(&index).copied())
+                .collect();
+            self.add_comdat(Comdat {
+                kind: in_comdat.kind(),
+                symbol,
+                sections,
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+use alloc::vec::Vec;
+
+use crate::endian::{Endianness, U16, U32, U64};
+use crate::write::{Error, Object, Relocation, Result, SectionId, WritableBuffer};
+use crate::{BinaryFormat, RelocationEncoding, RelocationKind, SectionKind};
+
+impl<'a> Object<'a> {
+    /// Return the `SectionId` of a DWARF section, creating the section if it doesn't exist.
+    ///
+    /// `name` uses the ELF naming convention, such as `.debug_info`, and is converted
+    /// to the naming convention of the file format. For Mach-O, the section is placed
+    /// in the `__DWARF` segment, and long names are truncated in the same way as
+    /// other Mach-O tools. For XCOFF, the name is not converted. Names with a `.dwo`
+    /// suffix are also supported for split DWARF and DWARF package files.
+    pub fn add_dwarf_section(&mut self, name: &[u8]) -> SectionId {
+        let segment;
+        let mut section_name;
+        let mut kind = SectionKind::Debug;
+        match self.format {
+            BinaryFormat::MachO => {
+                segment = b"__DWARF".to_vec();
+                section_name = b"__".to_vec();
+                section_name.extend_from_slice(name.strip_prefix(b".").unwrap_or(name));
+                section_name.truncate(16);
+            }
+            _ => {
+                segment = Vec::new();
+                section_name = name.to_vec();
+                if self.format == BinaryFormat::Elf
+                    && matches!(name, b".debug_str" | b".debug_line_str" | b".debug_str.dwo")
+                {
+                    kind = SectionKind::OtherString;
+                }
+            }
+        }
+        let existing = self
+            .sections
+            .iter()
+            .position(|section| section.segment == segment && section.name == section_name);
+        match existing {
+            Some(index) => SectionId(index),
+            None => self.add_section(segment, section_name, kind),
+        }
+    }
+
+    /// Add a reference to an offset within a DWARF section.
+    ///
+    /// This is used for attributes such as `DW_FORM_sec_offset` and `DW_FORM_strp`.
+    /// `offset` is the location of the reference within `section`, and must already
+    /// contain `size` bits of data. `target_offset` is an offset within `target`.
+    ///
+    /// For ELF, this adds an absolute relocation against the target section symbol.
+    /// For COFF, this adds a section offset relocation. For Mach-O, the linker does
+    /// not relocate DWARF sections, so the offset is written directly.
+    pub fn add_dwarf_section_offset(
+        &mut self,
+        section: SectionId,
+        offset: u64,
+        size: u8,
+        target: SectionId,
+        target_offset: u64,
+    ) -> Result<()> {
+        let kind = match self.format {
+            BinaryFormat::MachO => {
+                let relocation = Relocation {
+                    offset,
+                    size,
+                    kind: RelocationKind::Absolute,
+                    encoding: RelocationEncoding::Generic,
+                    symbol: self.section_symbol(target),
+                    addend: 0,
+                };
+                return self.write_relocation_addend(section, &relocation, target_offset as i64);
+            }
+            BinaryFormat::Coff => RelocationKind::SectionOffset,
+            _ => RelocationKind::Absolute,
+        };
+        let symbol = self.section_symbol(target);
+        self.add_relocation(
+            section,
+            Relocation {
+                offset,
+                size,
+                kind,
+                encoding: RelocationEncoding::Generic,
+                symbol,
+                addend: target_offset as i64,
+            },
+        )
+    }
+}
+
+/// A contribution of a unit to a section in a DWARF package file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DwarfContribution {
+    /// The section identifier, such as `DW_SECT_INFO`.
+    ///
+    /// The values depend on the version of the index.
+    pub section: u32,
+    /// The offset of the contribution within the section.
+    pub offset: u32,
+    /// The size of the contribution.
+    pub size: u32,
+}
+
+/// A builder for the `.debug_cu_index` and `.debug_tu_index` sections of a DWARF package file.
+///
+/// The units and their contributions are added with [`DwarfPackageIndex::add_unit`],
+/// and then [`DwarfPackageIndex::write`] returns the section data, which can be
+/// added to the section returned by [`Object::add_dwarf_section`].
+#[derive(Debug, Clone)]
+pub struct DwarfPackageIndex {
+    version: u16,
+    units: Vec<(u64, Vec<DwarfContribution>)>,
+}
+
+impl DwarfPackageIndex {
+    /// Create an empty index.
+    ///
+    /// `version` is 5 for DWARF 5 packages, or 2 for the GNU extension used with DWARF 4.
+    pub fn new(version: u16) -> Self {
+        DwarfPackageIndex {
+            version,
+            units: Vec::new(),
+        }
+    }
+
+    /// Add a unit with the given signature or DWO ID.
+    ///
+    /// Sections that the unit does not contribute to are written with a zero offset and size.
+    pub fn add_unit(&mut self, signature: u64, contributions: &[DwarfContribution]) {
+        self.units.push((signature, contributions.to_vec()));
+    }
+
+    /// Write the index.
+    ///
+    /// Returns an error if there are duplicate signatures.
+    pub fn write(&self, endian: Endianness) -> Result<Vec<u8>> {
+        let mut sections: Vec<u32> = self
+            .units
+            .iter()
+            .flat_map(|(_, contributions)| contributions.iter().map(|c| c.section))
+            .collect();
+        sections.sort_unstable();
+        sections.dedup();
+
+        let unit_count = self.units.len() as u32;
+        let mut slot_count = 0;
+        if unit_count != 0 {
+            // The table must be less than 2/3 full so that probing terminates quickly.
+            slot_count = 1;
+            while slot_count * 2 <= unit_count * 3 {
+                slot_count *= 2;
+            }
+        }
+        let mask = u64::from(slot_count).wrapping_sub(1);
+        let mut signatures = vec![0u64; slot_count as usize];
+        let mut indices = vec![0u32; slot_count as usize];
+        for (row, (signature, _)) in self.units.iter().enumerate() {
+            let mut slot = signature & mask;
+            let step = ((signature >> 32) & mask) | 1;
+            while indices[slot as usize] != 0 {
+                if signatures[slot as usize] == *signature {
+                    return Err(Error(format!(
+                        "duplicate DWARF package unit signature {:#x}",
+                        signature
+                    )));
+                }
+                slot = (slot + step) & mask;
+            }
+            signatures[slot as usize] = *signature;
+            indices[slot as usize] = row as u32 + 1;
+        }
+
+        let len = 16 + slot_count as usize * 12 + sections.len() * 4 * (1 + 2 * self.units.len());
+        let mut buffer = Vec::with_capacity(len);
+        if self.version >= 5 {
+            buffer.write_pod(&U16::new(endian, self.version));
+            buffer.write_pod(&U16::new(endian, 0));
+        } else {
+            buffer.write_pod(&U32::new(endian, u32::from(self.version)));
+        }
+        buffer.write_pod(&U32::new(endian, sections.len() as u32));
+        buffer.write_pod(&U32::new(endian, unit_count));
+        buffer.write_pod(&U32::new(endian, slot_count));
+        for signature in &signatures {
+            buffer.write_pod(&U64::new(endian, *signature));
+        }
+        for index in &indices {
+            buffer.write_pod(&U32::new(endian, *index));
+        }
+        for section in &sections {
+            buffer.write_pod(&U32::new(endian, *section));
+        }
+        for (_, contributions) in &self.units {
+            for section in &sections {
+                let offset = contributions
+                    .iter()
+                    .find(|c| c.section == *section)
+                    .map_or(0, |c| c.offset);
+                buffer.write_pod(&U32::new(endian, offset));
+            }
+        }
+        for (_, contributions) in &self.units {
+            for section in &sections {
+                let size = contributions
+                    .iter()
+                    .find(|c| c.section == *section)
+                    .map_or(0, |c| c.size);
+                buffer.write_pod(&U32::new(endian, size));
+            }
+        }
+        Ok(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::TryInto;
+
+    #[test]
+    fn package_index() {
+        let mut index = DwarfPackageIndex::new(5);
+        let info = |offset, size| DwarfContribution {
+            section: 1,
+            offset,
+            size,
+        };
+        let abbrev = DwarfContribution {
+            section: 3,
+            offset: 0,
+            size: 0x10,
+        };
+        index.add_unit(0x1_0000_0001, &[info(0, 0x20), abbrev]);
+        index.add_unit(0x2_0000_0001, &[info(0x20, 0x30)]);
+        let data = index.write(Endianness::Little).unwrap();
+
+        let words: Vec<u32> = data
+            .chunks(4)
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        // Header: version, section count, unit count, slot count.
+        assert_eq!(words[..4], [5, 2, 2, 4]);
+        // Both signatures hash to slot 1, and the second probes to slot (1 + 3) % 4.
+        assert_eq!(words[4..12], [1, 2, 1, 1, 0, 0, 0, 0]);
+        assert_eq!(words[12..16], [2, 1, 0, 0]);
+        // Section identifiers, offsets and sizes.
+        assert_eq!(words[16..18], [1, 3]);
+        assert_eq!(words[18..22], [0, 0, 0x20, 0]);
+        assert_eq!(words[22..26], [0x20, 0x10, 0x30, 0]);
+
+        let mut index = DwarfPackageIndex::new(5);
+        index.add_unit(1, &[]);
+        index.add_unit(1, &[]);
+        assert!(index.write(Endianness::Little).is_err());
+    }
+}
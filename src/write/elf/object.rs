@@ -256,8 +256,16 @@ impl<'a> Object<'a> {
             let str_id = writer.add_section_name(b".group");
             comdat_offsets.push(ComdatOffsets { offset, str_id });
         }
+        let max_align = if self.elf_is_64() { 1 << 63 } else { 1 << 31 };
         let mut section_offsets = Vec::with_capacity(self.sections.len());
         for (section, reloc_name) in self.sections.iter().zip(reloc_names.iter()) {
+            if !section.align.is_power_of_two() || section.align > max_align {
+                return Err(Error(format!(
+                    "unimplemented section `{}` align {}",
+                    section.name().unwrap_or(""),
+                    section.align
+                )));
+            }
             let index = writer.reserve_section_index();
             let offset = writer.reserve(section.data.len(), section.align as usize);
             let str_id = writer.add_section_name(&section.name);
@@ -277,17 +285,18 @@ impl<'a> Object<'a> {
         }
 
         // Calculate index of symbols and add symbol strings to strtab.
+        let symbols = self.ordered_symbols();
         let mut symbol_offsets = vec![SymbolOffsets::default(); self.symbols.len()];
         writer.reserve_null_symbol_index();
         // Local symbols must come before global.
-        for (index, symbol) in self.symbols.iter().enumerate() {
+        for &(index, symbol) in &symbols {
             if symbol.is_local() {
                 let section_index = symbol.section.id().map(|s| section_offsets[s.0].index);
                 symbol_offsets[index].index = writer.reserve_symbol_index(section_index);
             }
         }
         let symtab_num_local = writer.symbol_count();
-        for (index, symbol) in self.symbols.iter().enumerate() {
+        for &(index, symbol) in &symbols {
             if !symbol.is_local() {
                 let section_index = symbol.section.id().map(|s| section_offsets[s.0].index);
                 symbol_offsets[index].index = writer.reserve_symbol_index(section_index);
@@ -465,12 +474,12 @@ impl<'a> Object<'a> {
             });
             Ok(())
         };
-        for (index, symbol) in self.symbols.iter().enumerate() {
+        for &(index, symbol) in &symbols {
             if symbol.is_local() {
                 write_symbol(index, symbol)?;
             }
         }
-        for (index, symbol) in self.symbols.iter().enumerate() {
+        for &(index, symbol) in &symbols {
             if !symbol.is_local() {
                 write_symbol(index, symbol)?;
             }
@@ -483,7 +492,7 @@ impl<'a> Object<'a> {
             if !section.relocations.is_empty() {
                 writer.write_align_relocation();
                 debug_assert_eq!(section_offsets[index].reloc_offset, writer.len());
-                for reloc in &section.relocations {
+                for reloc in self.ordered_relocations(section) {
                     let r_type = match self.architecture {
                         Architecture::Aarch64 => match (reloc.kind, reloc.encoding, reloc.size) {
                             (RelocationKind::Absolute, RelocationEncoding::Generic, 64) => {
@@ -826,6 +835,8 @@ impl<'a> Object<'a> {
             let sh_type = match section.kind {
                 SectionKind::UninitializedData | SectionKind::UninitializedTls => elf::SHT_NOBITS,
                 SectionKind::Note => elf::SHT_NOTE,
+                SectionKind::InitArray => elf::SHT_INIT_ARRAY,
+                SectionKind::FiniArray => elf::SHT_FINI_ARRAY,
                 SectionKind::Elf(sh_type) => sh_type,
                 _ => elf::SHT_PROGBITS,
             };
@@ -833,14 +844,16 @@ impl<'a> Object<'a> {
                 sh_flags
             } else {
                 match section.kind {
-                    SectionKind::Text => elf::SHF_ALLOC | elf::SHF_EXECINSTR,
-                    SectionKind::Data | SectionKind::ReadOnlyDataWithRel => {
-                        elf::SHF_ALLOC | elf::SHF_WRITE
-                    }
+                    SectionKind::Text | SectionKind::Plt => elf::SHF_ALLOC | elf::SHF_EXECINSTR,
+                    SectionKind::Data
+                    | SectionKind::ReadOnlyDataWithRel
+                    | SectionKind::Got
+                    | SectionKind::InitArray
+                    | SectionKind::FiniArray => elf::SHF_ALLOC | elf::SHF_WRITE,
                     SectionKind::Tls => elf::SHF_ALLOC | elf::SHF_WRITE | elf::SHF_TLS,
                     SectionKind::UninitializedData => elf::SHF_ALLOC | elf::SHF_WRITE,
                     SectionKind::UninitializedTls => elf::SHF_ALLOC | elf::SHF_WRITE | elf::SHF_TLS,
-                    SectionKind::ReadOnlyData => elf::SHF_ALLOC,
+                    SectionKind::ReadOnlyData | SectionKind::Unwind => elf::SHF_ALLOC,
                     SectionKind::ReadOnlyString => {
                         elf::SHF_ALLOC | elf::SHF_STRINGS | elf::SHF_MERGE
                     }
@@ -851,7 +864,10 @@ impl<'a> Object<'a> {
                     | SectionKind::Linker
                     | SectionKind::Note
                     | SectionKind::Elf(_) => 0,
-                    SectionKind::Unknown | SectionKind::Common | SectionKind::TlsVariables => {
+                    SectionKind::Unknown
+                    | SectionKind::Common
+                    | SectionKind::TlsVariables
+                    | SectionKind::Dynamic => {
                         return Err(Error(format!(
                             "unimplemented section `{}` kind {:?}",
                             section.name().unwrap_or(""),
@@ -861,11 +877,7 @@ impl<'a> Object<'a> {
                 }
                 .into()
             };
-            // TODO: not sure if this is correct, maybe user should determine this
-            let sh_entsize = match section.kind {
-                SectionKind::ReadOnlyString | SectionKind::OtherString => 1,
-                _ => 0,
-            };
+            let sh_entsize = section.entsize;
             writer.write_section_header(&SectionHeader {
                 name: Some(section_offsets[index].str_id),
                 sh_type,
@@ -118,6 +118,18 @@ pub struct Writer<'a> {
     gnu_attributes_str_id: Option<StringId>,
     gnu_attributes_offset: usize,
     gnu_attributes_size: usize,
+
+    plt_machine: u16,
+    plt_count: usize,
+    plt_str_id: Option<StringId>,
+    plt_index: SectionIndex,
+    plt_offset: usize,
+
+    got_plt_str_id: Option<StringId>,
+    got_plt_offset: usize,
+
+    rela_plt_str_id: Option<StringId>,
+    rela_plt_offset: usize,
 }
 
 impl<'a> Writer<'a> {
@@ -207,6 +219,18 @@ impl<'a> Writer<'a> {
             gnu_attributes_str_id: None,
             gnu_attributes_offset: 0,
             gnu_attributes_size: 0,
+
+            plt_machine: 0,
+            plt_count: 0,
+            plt_str_id: None,
+            plt_index: SectionIndex(0),
+            plt_offset: 0,
+
+            got_plt_str_id: None,
+            got_plt_offset: 0,
+
+            rela_plt_str_id: None,
+            rela_plt_offset: 0,
         }
     }
 
@@ -1916,12 +1940,332 @@ impl<'a> Writer<'a> {
         });
     }
 
+    /// Set the machine and the number of functions for the PLT.
+    ///
+    /// This must be called before reserving the `.plt`, `.got.plt` or `.rela.plt`
+    /// sections. Each function is given a PLT stub, a GOT slot, and a jump slot
+    /// relocation, in the order of the symbols passed to [`Self::write_rela_plt`].
+    ///
+    /// Supported machines are `EM_X86_64` and `EM_AARCH64`, for 64-bit files only.
+    pub fn set_plt(&mut self, machine: u16, count: usize) -> Result<()> {
+        debug_assert_eq!(self.plt_offset, 0);
+        if !self.is_64 || (machine != elf::EM_X86_64 && machine != elf::EM_AARCH64) {
+            return Err(Error(format!(
+                "unsupported machine {} for ELF PLT synthesis",
+                machine
+            )));
+        }
+        self.plt_machine = machine;
+        self.plt_count = count;
+        Ok(())
+    }
+
+    fn plt_header_size(&self) -> usize {
+        if self.plt_machine == elf::EM_AARCH64 {
+            32
+        } else {
+            16
+        }
+    }
+
+    fn plt_size(&self) -> usize {
+        self.plt_header_size() + self.plt_count * 16
+    }
+
+    /// Return the address of the PLT stub for the function with the given index.
+    ///
+    /// `plt_address` is the address of the `.plt` section.
+    pub fn plt_entry_address(&self, plt_address: u64, index: usize) -> u64 {
+        plt_address + (self.plt_header_size() + index * 16) as u64
+    }
+
+    /// Return the address of the GOT slot for the function with the given index.
+    ///
+    /// `got_plt_address` is the address of the `.got.plt` section.
+    pub fn got_plt_entry_address(&self, got_plt_address: u64, index: usize) -> u64 {
+        got_plt_address + (3 + index as u64) * 8
+    }
+
+    /// Reserve the range for the `.plt` section.
+    ///
+    /// Returns the offset of the range.
+    ///
+    /// This function does nothing and returns 0 if `set_plt` was not called.
+    pub fn reserve_plt(&mut self) -> usize {
+        debug_assert_eq!(self.plt_offset, 0);
+        if self.plt_machine == 0 {
+            return 0;
+        }
+        self.plt_offset = self.reserve(self.plt_size(), 16);
+        self.plt_offset
+    }
+
+    /// Write the `.plt` section.
+    ///
+    /// The first entry calls the dynamic linker's lazy resolver using the
+    /// reserved GOT slots, and each following entry jumps via its GOT slot.
+    ///
+    /// This function does nothing if the section was not reserved.
+    pub fn write_plt(&mut self, plt_address: u64, got_plt_address: u64) {
+        if self.plt_offset == 0 {
+            return;
+        }
+        util::write_align(self.buffer, 16);
+        debug_assert_eq!(self.plt_offset, self.buffer.len());
+        let mut data = Vec::with_capacity(self.plt_size());
+        if self.plt_machine == elf::EM_AARCH64 {
+            // stp x16, x30, [sp, #-16]!
+            data.extend_from_slice(&0xa9bf_7bf0u32.to_le_bytes());
+            aarch64_plt_load(&mut data, plt_address + 4, got_plt_address + 16);
+            // nop
+            data.extend_from_slice(&0xd503_201fu32.to_le_bytes());
+            data.extend_from_slice(&0xd503_201fu32.to_le_bytes());
+            data.extend_from_slice(&0xd503_201fu32.to_le_bytes());
+            for i in 0..self.plt_count {
+                let entry = self.plt_entry_address(plt_address, i);
+                let slot = self.got_plt_entry_address(got_plt_address, i);
+                aarch64_plt_load(&mut data, entry, slot);
+            }
+        } else {
+            // push GOT+8(%rip)
+            data.extend_from_slice(&[0xff, 0x35]);
+            x86_64_rel32(&mut data, plt_address + 6, got_plt_address + 8);
+            // jmp *GOT+16(%rip)
+            data.extend_from_slice(&[0xff, 0x25]);
+            x86_64_rel32(&mut data, plt_address + 12, got_plt_address + 16);
+            // nopl 0(%rax)
+            data.extend_from_slice(&[0x0f, 0x1f, 0x40, 0x00]);
+            for i in 0..self.plt_count {
+                let entry = self.plt_entry_address(plt_address, i);
+                let slot = self.got_plt_entry_address(got_plt_address, i);
+                // jmp *slot(%rip)
+                data.extend_from_slice(&[0xff, 0x25]);
+                x86_64_rel32(&mut data, entry + 6, slot);
+                // push $i
+                data.push(0x68);
+                data.extend_from_slice(&(i as u32).to_le_bytes());
+                // jmp PLT0
+                data.push(0xe9);
+                x86_64_rel32(&mut data, entry + 16, plt_address);
+            }
+        }
+        self.buffer.write_bytes(&data);
+    }
+
+    /// Reserve the section index for the `.plt` section.
+    pub fn reserve_plt_section_index(&mut self) -> SectionIndex {
+        debug_assert!(self.plt_str_id.is_none());
+        self.plt_str_id = Some(self.add_section_name(&b".plt"[..]));
+        self.plt_index = self.reserve_section_index();
+        self.plt_index
+    }
+
+    /// Write the section header for the `.plt` section.
+    ///
+    /// This function does nothing if the section index was not reserved.
+    pub fn write_plt_section_header(&mut self, sh_addr: u64) {
+        if self.plt_str_id.is_none() {
+            return;
+        }
+        self.write_section_header(&SectionHeader {
+            name: self.plt_str_id,
+            sh_type: elf::SHT_PROGBITS,
+            sh_flags: (elf::SHF_ALLOC | elf::SHF_EXECINSTR).into(),
+            sh_addr,
+            sh_offset: self.plt_offset as u64,
+            sh_size: self.plt_size() as u64,
+            sh_link: 0,
+            sh_info: 0,
+            sh_addralign: 16,
+            sh_entsize: 16,
+        });
+    }
+
+    /// Reserve the range for the `.got.plt` section.
+    ///
+    /// Returns the offset of the range.
+    ///
+    /// This function does nothing and returns 0 if `set_plt` was not called.
+    pub fn reserve_got_plt(&mut self) -> usize {
+        debug_assert_eq!(self.got_plt_offset, 0);
+        if self.plt_machine == 0 {
+            return 0;
+        }
+        self.got_plt_offset = self.reserve((3 + self.plt_count) * 8, 8);
+        self.got_plt_offset
+    }
+
+    /// Write the `.got.plt` section.
+    ///
+    /// The first slot contains the address of the `.dynamic` section, and the
+    /// next two slots are reserved for the dynamic linker. Each function slot
+    /// initially points to the code in the PLT that calls the lazy resolver.
+    ///
+    /// This function does nothing if the section was not reserved.
+    pub fn write_got_plt(&mut self, dynamic_address: u64, plt_address: u64) {
+        if self.got_plt_offset == 0 {
+            return;
+        }
+        util::write_align(self.buffer, 8);
+        debug_assert_eq!(self.got_plt_offset, self.buffer.len());
+        let endian = self.endian;
+        self.buffer.write(&U64::new(endian, dynamic_address));
+        self.buffer.write(&U64::new(endian, 0));
+        self.buffer.write(&U64::new(endian, 0));
+        for i in 0..self.plt_count {
+            let resolve = if self.plt_machine == elf::EM_AARCH64 {
+                plt_address
+            } else {
+                self.plt_entry_address(plt_address, i) + 6
+            };
+            self.buffer.write(&U64::new(endian, resolve));
+        }
+    }
+
+    /// Reserve the section index for the `.got.plt` section.
+    pub fn reserve_got_plt_section_index(&mut self) -> SectionIndex {
+        debug_assert!(self.got_plt_str_id.is_none());
+        self.got_plt_str_id = Some(self.add_section_name(&b".got.plt"[..]));
+        self.reserve_section_index()
+    }
+
+    /// Write the section header for the `.got.plt` section.
+    ///
+    /// This function does nothing if the section index was not reserved.
+    pub fn write_got_plt_section_header(&mut self, sh_addr: u64) {
+        if self.got_plt_str_id.is_none() {
+            return;
+        }
+        self.write_section_header(&SectionHeader {
+            name: self.got_plt_str_id,
+            sh_type: elf::SHT_PROGBITS,
+            sh_flags: (elf::SHF_WRITE | elf::SHF_ALLOC).into(),
+            sh_addr,
+            sh_offset: self.got_plt_offset as u64,
+            sh_size: ((3 + self.plt_count) * 8) as u64,
+            sh_link: 0,
+            sh_info: 0,
+            sh_addralign: 8,
+            sh_entsize: 8,
+        });
+    }
+
+    /// Reserve the range for the `.rela.plt` section.
+    ///
+    /// Returns the offset of the range.
+    ///
+    /// This function does nothing and returns 0 if `set_plt` was not called.
+    pub fn reserve_rela_plt(&mut self) -> usize {
+        debug_assert_eq!(self.rela_plt_offset, 0);
+        if self.plt_machine == 0 {
+            return 0;
+        }
+        self.rela_plt_offset = self.reserve_relocations(self.plt_count, true);
+        self.rela_plt_offset
+    }
+
+    /// Write the `.rela.plt` section.
+    ///
+    /// `symbols` are the dynamic symbols of the functions, in PLT order.
+    ///
+    /// This function does nothing if the section was not reserved.
+    pub fn write_rela_plt(&mut self, got_plt_address: u64, symbols: &[SymbolIndex]) {
+        if self.rela_plt_offset == 0 {
+            return;
+        }
+        debug_assert_eq!(symbols.len(), self.plt_count);
+        util::write_align(self.buffer, self.elf_align);
+        debug_assert_eq!(self.rela_plt_offset, self.buffer.len());
+        let r_type = if self.plt_machine == elf::EM_AARCH64 {
+            elf::R_AARCH64_JUMP_SLOT
+        } else {
+            elf::R_X86_64_JUMP_SLOT
+        };
+        for (i, symbol) in symbols.iter().enumerate() {
+            self.write_relocation(
+                true,
+                &Rel {
+                    r_offset: self.got_plt_entry_address(got_plt_address, i),
+                    r_sym: symbol.0,
+                    r_type,
+                    r_addend: 0,
+                },
+            );
+        }
+    }
+
+    /// Reserve the section index for the `.rela.plt` section.
+    pub fn reserve_rela_plt_section_index(&mut self) -> SectionIndex {
+        debug_assert!(self.rela_plt_str_id.is_none());
+        self.rela_plt_str_id = Some(self.add_section_name(&b".rela.plt"[..]));
+        self.reserve_section_index()
+    }
+
+    /// Write the section header for the `.rela.plt` section.
+    ///
+    /// This function does nothing if the section index was not reserved.
+    pub fn write_rela_plt_section_header(&mut self, sh_addr: u64) {
+        if self.rela_plt_str_id.is_none() {
+            return;
+        }
+        self.write_section_header(&SectionHeader {
+            name: self.rela_plt_str_id,
+            sh_type: elf::SHT_RELA,
+            sh_flags: (elf::SHF_ALLOC | elf::SHF_INFO_LINK).into(),
+            sh_addr,
+            sh_offset: self.rela_plt_offset as u64,
+            sh_size: (self.plt_count * self.rel_size(true)) as u64,
+            sh_link: self.dynsym_index.0,
+            sh_info: self.plt_index.0,
+            sh_addralign: self.elf_align as u64,
+            sh_entsize: self.rel_size(true) as u64,
+        });
+    }
+
+    /// Write the dynamic entries for the PLT.
+    ///
+    /// This writes 4 entries: `DT_PLTGOT`, `DT_PLTRELSZ`, `DT_PLTREL` and `DT_JMPREL`.
+    pub fn write_plt_dynamic(&mut self, got_plt_address: u64, rela_plt_address: u64) {
+        let size = (self.plt_count * self.rel_size(true)) as u64;
+        self.write_dynamic(elf::DT_PLTGOT, got_plt_address);
+        self.write_dynamic(elf::DT_PLTRELSZ, size);
+        self.write_dynamic(elf::DT_PLTREL, elf::DT_RELA.into());
+        self.write_dynamic(elf::DT_JMPREL, rela_plt_address);
+    }
+
     /// Return a helper for writing an attributes section.
     pub fn attributes_writer(&self) -> AttributesWriter {
         AttributesWriter::new(self.endian)
     }
 }
 
+/// Write a 32-bit displacement from `next` to `target`.
+///
+/// `next` is the address of the following instruction.
+fn x86_64_rel32(data: &mut Vec<u8>, next: u64, target: u64) {
+    data.extend_from_slice(&(target.wrapping_sub(next) as u32).to_le_bytes());
+}
+
+/// Write the `adrp`, `ldr`, `add` and `br` instructions that jump via the GOT slot
+/// at `slot`, leaving the slot address in `x16`.
+///
+/// `address` is the address of the first instruction.
+fn aarch64_plt_load(data: &mut Vec<u8>, address: u64, slot: u64) {
+    let page = ((slot >> 12).wrapping_sub(address >> 12) & 0x1f_ffff) as u32;
+    let offset = (slot & 0xfff) as u32;
+    // adrp x16, slot
+    let adrp = 0x9000_0010 | (page & 0x3) << 29 | (page >> 2) << 5;
+    // ldr x17, [x16, #:lo12:slot]
+    let ldr = 0xf940_0211 | (offset / 8) << 10;
+    // add x16, x16, #:lo12:slot
+    let add = 0x9100_0210 | offset << 10;
+    // br x17
+    let br = 0xd61f_0220;
+    for insn in [adrp, ldr, add, br] {
+        data.extend_from_slice(&insn.to_le_bytes());
+    }
+}
+
 /// A helper for writing an attributes section.
 ///
 /// Attributes have a variable length encoding, so it is awkward to write them in a
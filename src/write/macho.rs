@@ -1,4 +1,4 @@
-use core::mem;
+use core::{cmp, mem};
 
 use crate::endian::*;
 use crate::macho;
@@ -308,6 +308,14 @@ impl<'a> Object<'a> {
         let mut section_offsets = vec![SectionOffsets::default(); self.sections.len()];
         let mut address = 0;
         for (index, section) in self.sections.iter().enumerate() {
+            // The linker limits the alignment to 2^15.
+            if !section.align.is_power_of_two() || section.align > 1 << 15 {
+                return Err(Error(format!(
+                    "unimplemented section `{}` align {}",
+                    section.name().unwrap_or(""),
+                    section.align
+                )));
+            }
             section_offsets[index].index = 1 + index;
             if !section.is_bss() {
                 address = align_u64(address, section.align);
@@ -329,16 +337,20 @@ impl<'a> Object<'a> {
 
         // Count symbols and add symbol strings to strtab.
         let mut strtab = StringTable::default();
+        let symbols = self.ordered_symbols();
         let mut symbol_offsets = vec![SymbolOffsets::default(); self.symbols.len()];
         let mut nsyms = 0;
-        for (index, symbol) in self.symbols.iter().enumerate() {
+        let mut has_file_symbol = false;
+        for &(index, symbol) in &symbols {
             // The unified API allows creating symbols that we don't emit, so filter
             // them out here.
             //
             // Since we don't actually emit the symbol kind, we validate it here too.
             match symbol.kind {
                 SymbolKind::Text | SymbolKind::Data | SymbolKind::Tls | SymbolKind::Unknown => {}
-                SymbolKind::File | SymbolKind::Section => continue,
+                // File symbols are emitted as `N_SO` stabs.
+                SymbolKind::File => has_file_symbol = true,
+                SymbolKind::Section => continue,
                 SymbolKind::Null | SymbolKind::Label => {
                     return Err(Error(format!(
                         "unimplemented symbol `{}` kind {:?}",
@@ -354,6 +366,10 @@ impl<'a> Object<'a> {
                 symbol_offsets[index].str_id = Some(strtab.add(&symbol.name));
             }
         }
+        if has_file_symbol {
+            // An empty `N_SO` stab terminates the last source file.
+            nsyms += 1;
+        }
 
         // Calculate size of symtab.
         offset = align(offset, pointer_align);
@@ -487,8 +503,19 @@ impl<'a> Object<'a> {
                     SectionKind::TlsVariables => macho::S_THREAD_LOCAL_VARIABLES,
                     SectionKind::Debug => macho::S_ATTR_DEBUG,
                     SectionKind::OtherString => macho::S_CSTRING_LITERALS,
-                    SectionKind::Other | SectionKind::Linker | SectionKind::Metadata => 0,
-                    SectionKind::Note | SectionKind::Unknown | SectionKind::Elf(_) => {
+                    // `S_NON_LAZY_SYMBOL_POINTERS` requires indirect symbol table entries.
+                    SectionKind::Got => 0,
+                    SectionKind::InitArray => macho::S_MOD_INIT_FUNC_POINTERS,
+                    SectionKind::FiniArray => macho::S_MOD_TERM_FUNC_POINTERS,
+                    SectionKind::Other
+                    | SectionKind::Linker
+                    | SectionKind::Metadata
+                    | SectionKind::Unwind => 0,
+                    SectionKind::Note
+                    | SectionKind::Unknown
+                    | SectionKind::Plt
+                    | SectionKind::Dynamic
+                    | SectionKind::Elf(_) => {
                         return Err(Error(format!(
                             "unimplemented section `{}` kind {:?}",
                             section.name().unwrap_or(""),
@@ -537,16 +564,36 @@ impl<'a> Object<'a> {
         // Write symtab.
         write_align(buffer, pointer_align);
         debug_assert_eq!(symtab_offset, buffer.len());
-        for (index, symbol) in self.symbols.iter().enumerate() {
+        for &(index, symbol) in &symbols {
             if !symbol_offsets[index].emit {
                 continue;
             }
-            // TODO: N_STAB
+            let n_strx = symbol_offsets[index]
+                .str_id
+                .map(|id| strtab.get_offset(id))
+                .unwrap_or(0);
+
+            if symbol.kind == SymbolKind::File {
+                macho.write_nlist(
+                    buffer,
+                    Nlist {
+                        n_strx: n_strx as u32,
+                        n_type: macho::N_SO,
+                        n_sect: 0,
+                        n_desc: 0,
+                        n_value: 0,
+                    },
+                );
+                continue;
+            }
+
             let (mut n_type, n_sect) = match symbol.section {
-                SymbolSection::Undefined => (macho::N_UNDF | macho::N_EXT, 0),
+                SymbolSection::Undefined | SymbolSection::Common => {
+                    (macho::N_UNDF | macho::N_EXT, 0)
+                }
                 SymbolSection::Absolute => (macho::N_ABS, 0),
                 SymbolSection::Section(id) => (macho::N_SECT, id.0 + 1),
-                SymbolSection::None | SymbolSection::Common => {
+                SymbolSection::None => {
                     return Err(Error(format!(
                         "unimplemented symbol `{}` section {:?}",
                         symbol.name().unwrap_or(""),
@@ -575,19 +622,22 @@ impl<'a> Object<'a> {
                         n_desc |= macho::N_WEAK_DEF;
                     }
                 }
+                if symbol.is_common() && symbol.value > 1 {
+                    let align = cmp::min(symbol.value.next_power_of_two().trailing_zeros(), 15);
+                    n_desc = macho::set_comm_align(n_desc, align as u16);
+                }
                 n_desc
             };
 
-            let n_value = match symbol.section.id() {
-                Some(section) => section_offsets[section.0].address + symbol.value,
-                None => symbol.value,
+            let n_value = match symbol.section {
+                SymbolSection::Section(section) => {
+                    section_offsets[section.0].address + symbol.value
+                }
+                // The value of a common symbol is its size.
+                SymbolSection::Common => symbol.size,
+                _ => symbol.value,
             };
 
-            let n_strx = symbol_offsets[index]
-                .str_id
-                .map(|id| strtab.get_offset(id))
-                .unwrap_or(0);
-
             macho.write_nlist(
                 buffer,
                 Nlist {
@@ -599,6 +649,18 @@ impl<'a> Object<'a> {
                 },
             );
         }
+        if has_file_symbol {
+            macho.write_nlist(
+                buffer,
+                Nlist {
+                    n_strx: 0,
+                    n_type: macho::N_SO,
+                    n_sect: 0,
+                    n_desc: 0,
+                    n_value: 0,
+                },
+            );
+        }
 
         // Write strtab.
         debug_assert_eq!(strtab_offset, buffer.len());
@@ -609,7 +671,7 @@ impl<'a> Object<'a> {
             if !section.relocations.is_empty() {
                 write_align(buffer, 4);
                 debug_assert_eq!(section_offsets[index].reloc_offset, buffer.len());
-                for reloc in &section.relocations {
+                for reloc in self.ordered_relocations(section) {
                     let r_extern;
                     let mut r_symbolnum;
                     let symbol = &self.symbols[reloc.symbol.0];
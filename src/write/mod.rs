@@ -1,9 +1,10 @@
 //! Interface for writing object files.
 
 use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
 use alloc::string::String;
 use alloc::vec::Vec;
-use core::{fmt, result, str};
+use core::{cmp, fmt, result, str};
 #[cfg(not(feature = "std"))]
 use hashbrown::HashMap;
 #[cfg(feature = "std")]
@@ -15,10 +16,24 @@ use crate::{
     SectionFlags, SectionKind, SymbolFlags, SymbolKind, SymbolScope,
 };
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "archive")]
+pub mod archive;
+
+mod dwarf;
+pub use dwarf::{DwarfContribution, DwarfPackageIndex};
+
+#[cfg(feature = "read_core")]
+mod copy;
+#[cfg(feature = "read_core")]
+pub use copy::{CopyHooks, DefaultCopyHooks};
+
 #[cfg(feature = "coff")]
 mod coff;
 #[cfg(feature = "coff")]
-pub use coff::CoffExportStyle;
+pub use coff::{CoffExportStyle, CoffLongSectionNames};
 
 #[cfg(feature = "elf")]
 pub mod elf;
@@ -31,9 +46,18 @@ pub use macho::MachOBuildVersion;
 #[cfg(feature = "pe")]
 pub mod pe;
 
+#[cfg(feature = "raw")]
+pub mod raw;
+
+#[cfg(feature = "srec")]
+pub mod srec;
+
 #[cfg(feature = "xcoff")]
 mod xcoff;
 
+mod patch;
+pub use patch::FilePatch;
+
 mod string;
 pub use string::StringId;
 
@@ -73,8 +97,21 @@ pub struct Object<'a> {
     pub flags: FileFlags,
     /// The symbol name mangling scheme.
     pub mangling: Mangling,
+    /// Write symbols and relocations in an order that does not depend on the order
+    /// in which they were added.
+    ///
+    /// File symbols are written first, followed by the other symbols ordered by
+    /// section, name, value and size. The relocations of each section are ordered
+    /// by offset. Nothing else is changed.
+    ///
+    /// This is only supported for COFF, ELF and Mach-O.
+    ///
+    /// Defaults to false.
+    pub deterministic: bool,
     /// Mach-O "_tlv_bootstrap" symbol.
     tlv_bootstrap: Option<SymbolId>,
+    #[cfg(feature = "coff")]
+    coff_long_section_names: CoffLongSectionNames,
     #[cfg(feature = "macho")]
     macho_build_version: Option<MachOBuildVersion>,
 }
@@ -94,7 +131,10 @@ impl<'a> Object<'a> {
             comdats: Vec::new(),
             flags: FileFlags::None,
             mangling: Mangling::default(format, architecture),
+            deterministic: false,
             tlv_bootstrap: None,
+            #[cfg(feature = "coff")]
+            coff_long_section_names: CoffLongSectionNames::StringTable,
             #[cfg(feature = "macho")]
             macho_build_version: None,
         }
@@ -167,6 +207,21 @@ impl<'a> Object<'a> {
         self.sections[section.0].append_data(data, align)
     }
 
+    /// Append a string to an existing mergeable string section.
+    ///
+    /// See [`Section::append_string`] for details.
+    ///
+    /// For ELF, [`StandardSection::ReadOnlyString`] is `.rodata`, which is not mergeable.
+    /// Use [`Self::add_section`] with [`SectionKind::ReadOnlyString`] and a name such as
+    /// `.rodata.str1.1` instead.
+    ///
+    /// Returns the section symbol and the section offset of the string, which can be
+    /// used as the symbol and addend of a relocation that refers to the string.
+    pub fn add_section_string(&mut self, section: SectionId, string: &[u8]) -> (SymbolId, u64) {
+        let offset = self.sections[section.0].append_string(string);
+        (self.section_symbol(section), offset)
+    }
+
     /// Append zero-initialized data to an existing section. Returns the section offset of the data.
     pub fn append_section_bss(&mut self, section: SectionId, size: u64, align: u64) -> u64 {
         self.sections[section.0].append_bss(size, align)
@@ -201,6 +256,11 @@ impl<'a> Object<'a> {
             data: Cow::Borrowed(&[]),
             relocations: Vec::new(),
             symbol: None,
+            entsize: match kind {
+                SectionKind::ReadOnlyString | SectionKind::OtherString => 1,
+                _ => 0,
+            },
+            strings: HashMap::new(),
             flags: SectionFlags::None,
         });
 
@@ -330,6 +390,40 @@ impl<'a> Object<'a> {
         &mut self.symbols[symbol.0]
     }
 
+    /// Return the symbols and their indices in the order that they should be written.
+    ///
+    /// This is insertion order, unless `deterministic` is set.
+    #[cfg(any(feature = "coff", feature = "elf", feature = "macho"))]
+    fn ordered_symbols(&self) -> Vec<(usize, &Symbol)> {
+        let mut symbols: Vec<_> = self.symbols.iter().enumerate().collect();
+        if self.deterministic {
+            symbols.sort_by_key(|(_, symbol)| {
+                (
+                    symbol.kind != SymbolKind::File,
+                    symbol.section.id().map(|id| id.0),
+                    &symbol.name,
+                    symbol.value,
+                    symbol.size,
+                )
+            });
+        }
+        symbols
+    }
+
+    /// Return the relocations of a section in the order that they should be written.
+    ///
+    /// This is insertion order, unless `deterministic` is set, in which case the
+    /// relocations are sorted by offset. Relocations with the same offset keep their
+    /// relative order, since some formats require relocation pairs.
+    #[cfg(any(feature = "coff", feature = "elf", feature = "macho"))]
+    fn ordered_relocations<'b>(&self, section: &'b Section<'_>) -> Vec<&'b Relocation> {
+        let mut relocations: Vec<_> = section.relocations.iter().collect();
+        if self.deterministic {
+            relocations.sort_by_key(|relocation| relocation.offset);
+        }
+        relocations
+    }
+
     /// Add a new symbol and return its `SymbolId`.
     pub fn add_symbol(&mut self, mut symbol: Symbol) -> SymbolId {
         // Defined symbols must have a scope.
@@ -380,21 +474,51 @@ impl<'a> Object<'a> {
 
     /// Add a new common symbol and return its `SymbolId`.
     ///
-    /// For Mach-O, this appends the symbol to the `__common` section.
+    /// This sets the symbol section to `SymbolSection::Common`, the symbol size to `size`,
+    /// and the symbol value to `align`.
+    ///
+    /// The symbol is written as an ELF `SHN_COMMON` symbol, a COFF undefined symbol
+    /// with a non-zero value, a Mach-O undefined external symbol with a non-zero value,
+    /// or an XCOFF undefined `XTY_CM` csect.
+    /// The alignment is written in the ELF `st_value`, the Mach-O `n_desc` and the
+    /// XCOFF `x_smtyp`; COFF cannot represent it. Mach-O and XCOFF store the log2 of
+    /// the alignment, so an `align` that is not a power of two is rounded up.
+    ///
+    /// This is supported for all formats, independent of [`Self::has_common`],
+    /// which only applies to `StandardSection::Common`.
+    ///
+    /// Use [`Self::define_common_symbols`] to write the symbols as definitions instead.
     pub fn add_common_symbol(&mut self, mut symbol: Symbol, size: u64, align: u64) -> SymbolId {
-        if self.has_common() {
-            let symbol_id = self.add_symbol(symbol);
-            let section = self.section_id(StandardSection::Common);
-            self.add_symbol_bss(symbol_id, section, size, align);
-            symbol_id
-        } else {
-            symbol.section = SymbolSection::Common;
-            symbol.size = size;
-            self.add_symbol(symbol)
+        symbol.section = SymbolSection::Common;
+        symbol.value = align;
+        symbol.size = size;
+        self.add_symbol(symbol)
+    }
+
+    /// Convert all common symbols into definitions of zero-initialized data.
+    ///
+    /// The data for each symbol is appended to `StandardSection::UninitializedData`,
+    /// using the symbol size and the alignment in the symbol value.
+    /// This is equivalent to compiling with `-fno-common`, and should be called
+    /// after all symbols have been added and before writing the file.
+    pub fn define_common_symbols(&mut self) {
+        let mut section = None;
+        for index in 0..self.symbols.len() {
+            let symbol = &self.symbols[index];
+            if !symbol.is_common() {
+                continue;
+            }
+            let (size, align) = (symbol.size, symbol.value);
+            let section =
+                *section.get_or_insert_with(|| self.section_id(StandardSection::UninitializedData));
+            self.add_symbol_bss(SymbolId(index), section, size, cmp::max(align, 1));
         }
     }
 
     /// Add a new file symbol and return its `SymbolId`.
+    ///
+    /// This is an ELF `STT_FILE` symbol, a COFF or XCOFF `.file` symbol,
+    /// or a Mach-O `N_SO` stab.
     pub fn add_file_symbol(&mut self, name: Vec<u8>) -> SymbolId {
         self.add_raw_symbol(Symbol {
             name,
@@ -560,6 +684,113 @@ impl<'a> Object<'a> {
         })
     }
 
+    /// Reorder the sections by the key returned by the given function.
+    ///
+    /// The sort is stable. All references to the sections within the object are
+    /// updated, including symbols and COMDATs. Previously returned `SectionId`s are
+    /// invalidated; the returned map contains the new `SectionId` for each old `SectionId`.
+    pub fn sort_sections_by_key<K, F>(&mut self, mut f: F) -> BTreeMap<SectionId, SectionId>
+    where
+        K: Ord,
+        F: FnMut(SectionId, &Section<'a>) -> K,
+    {
+        let mut order: Vec<usize> = (0..self.sections.len()).collect();
+        order.sort_by_cached_key(|&index| f(SectionId(index), &self.sections[index]));
+        permute(&mut self.sections, &order);
+        let mut map = vec![SectionId(0); order.len()];
+        for (new, old) in order.into_iter().enumerate() {
+            map[old] = SectionId(new);
+        }
+
+        for id in self.standard_sections.values_mut() {
+            *id = map[id.0];
+        }
+        for symbol in &mut self.symbols {
+            if let SymbolSection::Section(ref mut id) = symbol.section {
+                *id = map[id.0];
+            }
+            if let SymbolFlags::CoffSection {
+                associative_section: Some(ref mut id),
+                ..
+            } = symbol.flags
+            {
+                *id = map[id.0];
+            }
+        }
+        for comdat in &mut self.comdats {
+            for id in &mut comdat.sections {
+                *id = map[id.0];
+            }
+        }
+        map.into_iter()
+            .enumerate()
+            .map(|(old, new)| (SectionId(old), new))
+            .collect()
+    }
+
+    /// Reorder the symbols by the key returned by the given function.
+    ///
+    /// The sort is stable. All references to the symbols within the object are
+    /// updated, including relocations and COMDATs. Previously returned `SymbolId`s are
+    /// invalidated; the returned map contains the new `SymbolId` for each old `SymbolId`.
+    ///
+    /// The file format may impose additional ordering when writing. For example, ELF
+    /// local symbols are always written before global symbols. The order is also
+    /// overridden if `deterministic` is set.
+    pub fn sort_symbols_by_key<K, F>(&mut self, mut f: F) -> BTreeMap<SymbolId, SymbolId>
+    where
+        K: Ord,
+        F: FnMut(SymbolId, &Symbol) -> K,
+    {
+        let mut order: Vec<usize> = (0..self.symbols.len()).collect();
+        order.sort_by_cached_key(|&index| f(SymbolId(index), &self.symbols[index]));
+        permute(&mut self.symbols, &order);
+        let mut map = vec![SymbolId(0); order.len()];
+        for (new, old) in order.into_iter().enumerate() {
+            map[old] = SymbolId(new);
+        }
+
+        for section in &mut self.sections {
+            if let Some(ref mut id) = section.symbol {
+                *id = map[id.0];
+            }
+            for relocation in &mut section.relocations {
+                relocation.symbol = map[relocation.symbol.0];
+            }
+        }
+        for symbol in &mut self.symbols {
+            match symbol.flags {
+                SymbolFlags::CoffSymbol {
+                    weak_default_symbol: Some(ref mut id),
+                    ..
+                }
+                | SymbolFlags::Xcoff {
+                    containing_csect: Some(ref mut id),
+                    ..
+                } => *id = map[id.0],
+                _ => {}
+            }
+        }
+        for id in self.symbol_map.values_mut() {
+            *id = map[id.0];
+        }
+        self.stub_symbols = self
+            .stub_symbols
+            .iter()
+            .map(|(symbol, stub)| (map[symbol.0], map[stub.0]))
+            .collect();
+        if let Some(ref mut id) = self.tlv_bootstrap {
+            *id = map[id.0];
+        }
+        for comdat in &mut self.comdats {
+            comdat.symbol = map[comdat.symbol.0];
+        }
+        map.into_iter()
+            .enumerate()
+            .map(|(old, new)| (SymbolId(old), new))
+            .collect()
+    }
+
     /// Write the object to a `Vec`.
     pub fn write(&self) -> Result<Vec<u8>> {
         let mut buffer = Vec::new();
@@ -569,7 +800,8 @@ impl<'a> Object<'a> {
 
     /// Write the object to a `Write` implementation.
     ///
-    /// Also flushes the writer.
+    /// The data is written in file order as it is generated, without holding the
+    /// whole output in memory. Also flushes the writer.
     ///
     /// It is advisable to use a buffered writer like [`BufWriter`](std::io::BufWriter)
     /// instead of an unbuffered writer like [`File`](std::fs::File).
@@ -582,6 +814,21 @@ impl<'a> Object<'a> {
         Ok(())
     }
 
+    /// Return the size in bytes of the output that will be written.
+    ///
+    /// This calculates the layout of the file without writing any data, so it can
+    /// be used before [`Self::write_stream`] to determine the output size without
+    /// holding the whole output in memory.
+    pub fn write_size(&self) -> Result<usize> {
+        let mut buffer = SizeBuffer::default();
+        let result = self.emit(&mut buffer);
+        match (buffer.size, result) {
+            (Some(size), _) => Ok(size),
+            (None, Err(e)) => Err(e),
+            (None, Ok(())) => Err(Error(String::from("output size was not reserved"))),
+        }
+    }
+
     /// Write the object to a `WritableBuffer`.
     pub fn emit(&self, buffer: &mut dyn WritableBuffer) -> Result<()> {
         match self.format {
@@ -598,6 +845,12 @@ impl<'a> Object<'a> {
     }
 }
 
+/// Reorder the items so that the new item at each position is the old item at `order[position]`.
+fn permute<T>(items: &mut Vec<T>, order: &[usize]) {
+    let mut old: Vec<Option<T>> = items.drain(..).map(Some).collect();
+    items.extend(order.iter().map(|&index| old[index].take().unwrap()));
+}
+
 /// A standard segment kind.
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -671,6 +924,9 @@ impl StandardSection {
 pub struct SectionId(usize);
 
 /// A section in an object file.
+///
+/// The maximum section alignment depends on the file format: 8192 for COFF, 2^15 for
+/// Mach-O, and 2^31 or 2^63 for 32-bit or 64-bit ELF. Writing fails if this is exceeded.
 #[derive(Debug)]
 pub struct Section<'a> {
     segment: Vec<u8>,
@@ -681,7 +937,16 @@ pub struct Section<'a> {
     data: Cow<'a, [u8]>,
     relocations: Vec<Relocation>,
     symbol: Option<SymbolId>,
+    entsize: u64,
+    strings: HashMap<Vec<u8>, u64>,
     /// Section flags that are specific to each file format.
+    ///
+    /// If set, these are written instead of the flags derived from the section kind.
+    /// This allows setting the ELF `sh_flags`, the Mach-O section type and attributes,
+    /// and the COFF characteristics, including for section kinds that are otherwise
+    /// unsupported by the format. The ELF `sh_type` can be set using `SectionKind::Elf`.
+    ///
+    /// For COFF, the alignment and COMDAT characteristics are still added if needed.
     pub flags: SectionFlags,
 }
 
@@ -740,6 +1005,36 @@ impl<'a> Section<'a> {
         offset as u64
     }
 
+    /// Set the size of each entry in a section that contains fixed size entries.
+    ///
+    /// This is written as the ELF `sh_entsize`, and is the character size used by
+    /// [`Section::append_string`]. The default is 1 for string sections and 0 otherwise.
+    pub fn set_entsize(&mut self, entsize: u64) {
+        debug_assert_eq!(entsize & entsize.wrapping_sub(1), 0);
+        self.entsize = entsize;
+    }
+
+    /// Append a null-terminated string to a mergeable string section.
+    ///
+    /// `string` must not include the null terminator, and its length must be a multiple
+    /// of the section entry size. If the same string was previously appended using this
+    /// method, then the offset of the existing string is returned instead.
+    ///
+    /// Returns the section offset of the string.
+    pub fn append_string(&mut self, string: &[u8]) -> u64 {
+        let entsize = cmp::max(self.entsize, 1);
+        debug_assert_eq!(string.len() as u64 % entsize, 0);
+        if let Some(offset) = self.strings.get(string) {
+            return *offset;
+        }
+        let mut data = Vec::with_capacity(string.len() + entsize as usize);
+        data.extend_from_slice(string);
+        data.resize(string.len() + entsize as usize, 0);
+        let offset = self.append_data(&data, entsize);
+        self.strings.insert(string.to_vec(), offset);
+        offset
+    }
+
     /// Append uninitialized data to a section.
     ///
     /// Must not be called for sections that contain initialized data.
@@ -817,6 +1112,7 @@ pub struct Symbol {
     /// The value of the symbol.
     ///
     /// If the symbol defined in a section, then this is the section offset of the symbol.
+    /// If the symbol is common, then this is the alignment of the symbol.
     pub value: u64,
     /// The size of the symbol.
     pub size: u64,
@@ -829,6 +1125,10 @@ pub struct Symbol {
     /// The section containing the symbol.
     pub section: SymbolSection,
     /// Symbol flags that are specific to each file format.
+    ///
+    /// If set, these are written instead of the values derived from the symbol kind,
+    /// scope and weakness. For example, this sets the ELF `st_info` and `st_other`,
+    /// or the Mach-O `n_desc`.
     pub flags: SymbolFlags<SectionId, SymbolId>,
 }
 
@@ -858,6 +1158,49 @@ impl Symbol {
     pub fn is_local(&self) -> bool {
         self.scope == SymbolScope::Compilation
     }
+
+    /// Set the scope of the symbol.
+    ///
+    /// Local symbols cannot be weak, so this clears `weak` for `SymbolScope::Compilation`.
+    ///
+    /// ELF symbol flags are written instead of the scope, so this also updates the
+    /// binding and visibility in the flags.
+    pub fn set_scope(&mut self, scope: SymbolScope) {
+        self.scope = scope;
+        if scope == SymbolScope::Compilation {
+            self.weak = false;
+        }
+        if let SymbolFlags::Elf { st_info, st_other } = &mut self.flags {
+            let st_visibility = match scope {
+                SymbolScope::Unknown => return,
+                SymbolScope::Linkage => crate::elf::STV_HIDDEN,
+                SymbolScope::Compilation | SymbolScope::Dynamic => crate::elf::STV_DEFAULT,
+            };
+            *st_info = (Self::elf_bind(scope, self.weak) << 4) | (*st_info & 0xf);
+            *st_other = (*st_other & !0x3) | st_visibility;
+        }
+    }
+
+    /// Set whether the symbol has weak binding.
+    ///
+    /// ELF symbol flags are written instead of the weakness, so this also updates the
+    /// binding in the flags.
+    pub fn set_weak(&mut self, weak: bool) {
+        self.weak = weak;
+        if let SymbolFlags::Elf { st_info, .. } = &mut self.flags {
+            *st_info = (Self::elf_bind(self.scope, weak) << 4) | (*st_info & 0xf);
+        }
+    }
+
+    fn elf_bind(scope: SymbolScope, weak: bool) -> u8 {
+        if weak {
+            crate::elf::STB_WEAK
+        } else if scope == SymbolScope::Compilation {
+            crate::elf::STB_LOCAL
+        } else {
+            crate::elf::STB_GLOBAL
+        }
+    }
 }
 
 /// A relocation in an object file.
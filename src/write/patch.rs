@@ -0,0 +1,210 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+#[cfg(feature = "read_core")]
+use crate::read::{CompressionFormat, ObjectSection};
+use crate::write::{Error, Result, WritableBuffer};
+use crate::{bytes_of, Pod};
+
+/// A set of edits to apply to the data of an existing file.
+///
+/// This is used for changes that don't require rewriting the file, such as
+/// stamping a build ID, filling in a signature slot, or updating a header field.
+/// The edits replace bytes without changing the size of the file, and all
+/// other bytes are preserved.
+///
+/// Most edits are specified using references into the file data that were
+/// obtained from the read API, such as a header field or a section name. The
+/// patch does not borrow the file data, so the edits can be applied in place
+/// once those references are no longer needed.
+#[derive(Debug, Clone)]
+pub struct FilePatch {
+    address: usize,
+    len: usize,
+    edits: BTreeMap<usize, Vec<u8>>,
+}
+
+impl FilePatch {
+    /// Create an empty patch for the given file data.
+    pub fn new(data: &[u8]) -> Self {
+        FilePatch {
+            address: data.as_ptr() as usize,
+            len: data.len(),
+            edits: BTreeMap::new(),
+        }
+    }
+
+    /// Return true if the patch contains no edits.
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    /// Replace the bytes at the given file offset.
+    ///
+    /// Returns an error if the bytes are beyond the end of the file, or if they
+    /// overlap a previous edit.
+    pub fn write_at(&mut self, offset: u64, bytes: &[u8]) -> Result<()> {
+        let offset = usize::try_from(offset)
+            .ok()
+            .filter(|offset| {
+                offset
+                    .checked_add(bytes.len())
+                    .map_or(false, |end| end <= self.len)
+            })
+            .ok_or_else(|| Error(format!("invalid patch offset {:#x}", offset)))?;
+        if bytes.is_empty() {
+            return Ok(());
+        }
+        let overlaps_previous = self
+            .edits
+            .range(..=offset)
+            .next_back()
+            .map_or(false, |(start, edit)| start + edit.len() > offset);
+        let overlaps_next = self
+            .edits
+            .range(offset..)
+            .next()
+            .map_or(false, |(start, _)| *start < offset + bytes.len());
+        if overlaps_previous || overlaps_next {
+            return Err(Error(format!("overlapping patch at offset {:#x}", offset)));
+        }
+        self.edits.insert(offset, bytes.to_vec());
+        Ok(())
+    }
+
+    /// Replace a range of bytes in the file with bytes of the same length.
+    ///
+    /// `old` must be a reference into the file data that was given to [`Self::new`].
+    pub fn replace_bytes(&mut self, old: &[u8], new: &[u8]) -> Result<()> {
+        if old.len() != new.len() {
+            return Err(Error(format!(
+                "patch size mismatch: {} bytes replaced with {} bytes",
+                old.len(),
+                new.len()
+            )));
+        }
+        let offset = self.offset_of(old)?;
+        self.write_at(offset, new)
+    }
+
+    /// Replace a value in the file, such as a header field.
+    ///
+    /// `old` must be a reference into the file data that was given to [`Self::new`].
+    pub fn replace<T: Pod>(&mut self, old: &T, new: &T) -> Result<()> {
+        self.replace_bytes(bytes_of(old), bytes_of(new))
+    }
+
+    /// Replace a null-terminated string in the file, such as a section or symbol name.
+    ///
+    /// `old` must be a reference into the file data that was given to [`Self::new`],
+    /// and must not include the null terminator. The new string must not be longer
+    /// than the old string, and the remaining bytes are filled with nulls.
+    ///
+    /// String tables may share the storage for a string with the end of a longer
+    /// string. For example, the name `.text` may be stored within `.rela.text`, and
+    /// replacing it with `.code` changes the other name to `.rela.code`. This can't
+    /// be detected from `old` alone, so check the other names that end with `old` if
+    /// this matters.
+    pub fn replace_string(&mut self, old: &[u8], new: &[u8]) -> Result<()> {
+        if new.len() > old.len() {
+            return Err(Error(format!(
+                "patch string is too long: {} bytes replaced with {} bytes",
+                old.len(),
+                new.len()
+            )));
+        }
+        let mut bytes = new.to_vec();
+        bytes.resize(old.len(), 0);
+        self.replace_bytes(old, &bytes)
+    }
+
+    /// Replace the contents of a section with data of the same size.
+    ///
+    /// Returns an error if the section has no file data or is compressed.
+    #[cfg(feature = "read_core")]
+    pub fn replace_section_data<'data, S: ObjectSection<'data>>(
+        &mut self,
+        section: &S,
+        data: &[u8],
+    ) -> Result<()> {
+        let name = section.name().unwrap_or("");
+        let compressed = section
+            .compressed_file_range()
+            .map_err(|_| Error(format!("invalid file range for section `{}`", name)))?;
+        if compressed.format != CompressionFormat::None {
+            return Err(Error(format!("unsupported compressed section `{}`", name)));
+        }
+        let (offset, size) = section
+            .file_range()
+            .ok_or_else(|| Error(format!("section `{}` has no file data", name)))?;
+        if size != data.len() as u64 {
+            return Err(Error(format!(
+                "patch size mismatch for section `{}`: {} bytes replaced with {} bytes",
+                name,
+                size,
+                data.len()
+            )));
+        }
+        self.write_at(offset, data)
+    }
+
+    fn offset_of(&self, bytes: &[u8]) -> Result<u64> {
+        let offset = (bytes.as_ptr() as usize).wrapping_sub(self.address);
+        if offset > self.len || bytes.len() > self.len - offset {
+            return Err(Error(String::from("patch data is not within the file")));
+        }
+        Ok(offset as u64)
+    }
+
+    /// Apply the edits to the file data in place.
+    ///
+    /// `data` must have the same length as the data given to [`Self::new`].
+    pub fn apply(&self, data: &mut [u8]) -> Result<()> {
+        if data.len() != self.len {
+            return Err(Error(format!(
+                "patch file size mismatch: expected {} bytes, found {} bytes",
+                self.len,
+                data.len()
+            )));
+        }
+        for (offset, edit) in &self.edits {
+            data[*offset..][..edit.len()].copy_from_slice(edit);
+        }
+        Ok(())
+    }
+
+    /// Write a copy of the file data with the edits applied to a `Vec`.
+    ///
+    /// `data` must have the same length as the data given to [`Self::new`].
+    pub fn write(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        self.emit(data, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Write a copy of the file data with the edits applied to a `WritableBuffer`.
+    ///
+    /// `data` must have the same length as the data given to [`Self::new`].
+    pub fn emit(&self, data: &[u8], buffer: &mut dyn WritableBuffer) -> Result<()> {
+        if data.len() != self.len {
+            return Err(Error(format!(
+                "patch file size mismatch: expected {} bytes, found {} bytes",
+                self.len,
+                data.len()
+            )));
+        }
+        buffer
+            .reserve(data.len())
+            .map_err(|_| Error(String::from("Cannot allocate buffer")))?;
+        let mut offset = 0;
+        for (start, edit) in &self.edits {
+            buffer.write_bytes(&data[offset..*start]);
+            buffer.write_bytes(edit);
+            offset = start + edit.len();
+        }
+        buffer.write_bytes(&data[offset..]);
+        Ok(())
+    }
+}
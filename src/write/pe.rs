@@ -1,10 +1,14 @@
 //! Helper for writing PE files.
+use alloc::collections::BTreeMap;
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::mem;
 
 use crate::endian::{LittleEndian as LE, *};
 use crate::pe;
+use crate::pod;
+#[cfg(feature = "read_core")]
+use crate::read::{self, ReadRef};
 use crate::write::util;
 use crate::write::{Error, Result, WritableBuffer};
 
@@ -845,3 +849,463 @@ impl RelocBlock {
         mem::size_of::<pe::ImageBaseRelocation>() as u32 + self.count * mem::size_of::<u16>() as u32
     }
 }
+
+/// A resource type or name in a [`ResourceSection`].
+///
+/// Names are ordered before IDs, as required for resource directory tables.
+/// Names are compared by their UTF-16 code units, so they should be upper case.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResourceId {
+    /// A name, encoded as UTF-16.
+    Name(Vec<u16>),
+    /// A numeric ID, such as one of the `pe::RT_*` constants for resource types.
+    Id(u16),
+}
+
+impl ResourceId {
+    /// Create a resource name from a string.
+    pub fn name(name: &str) -> Self {
+        ResourceId::Name(name.encode_utf16().collect())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Resource {
+    data: Vec<u8>,
+    code_page: u32,
+}
+
+/// A builder for the resource directory in a `.rsrc` section.
+///
+/// Resources are identified by their type, name and language, which are the
+/// three levels of the resource directory.
+///
+/// Use [`ResourceSection::size`] to reserve the section with
+/// [`Writer::reserve_rsrc_section`], and then [`ResourceSection::write`] to
+/// get the section data for its virtual address.
+#[derive(Debug, Default, Clone)]
+pub struct ResourceSection {
+    resources: BTreeMap<(ResourceId, ResourceId, u16), Resource>,
+}
+
+impl ResourceSection {
+    /// Create an empty resource section.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a resource section containing the resources of an existing file.
+    #[cfg(feature = "read_core")]
+    pub fn from_directory<'data, R: ReadRef<'data>>(
+        directory: read::pe::ResourceDirectory<'data>,
+        sections: &read::pe::SectionTable<'data>,
+        data: R,
+    ) -> Result<Self> {
+        fn table<'data>(
+            parent: &read::pe::ResourceDirectoryTable<'data>,
+            entry: &pe::ImageResourceDirectoryEntry,
+            directory: read::pe::ResourceDirectory<'data>,
+        ) -> Result<read::pe::ResourceDirectoryTable<'data>> {
+            parent
+                .entry_data(entry, directory)
+                .map_err(util::read_error)?
+                .table()
+                .ok_or_else(|| Error(String::from("Invalid resource directory table")))
+        }
+
+        fn id(
+            entry: &pe::ImageResourceDirectoryEntry,
+            directory: read::pe::ResourceDirectory<'_>,
+        ) -> Result<ResourceId> {
+            Ok(match entry.name_or_id() {
+                read::pe::ResourceNameOrId::Name(name) => ResourceId::Name(
+                    name.data(directory)
+                        .map_err(util::read_error)?
+                        .iter()
+                        .map(|c| c.get(LE))
+                        .collect(),
+                ),
+                read::pe::ResourceNameOrId::Id(id) => ResourceId::Id(id),
+            })
+        }
+
+        let mut resources = ResourceSection::new();
+        let root = directory.root().map_err(util::read_error)?;
+        for type_entry in root.entries {
+            let type_table = table(&root, type_entry, directory)?;
+            for name_entry in type_table.entries {
+                let name_table = table(&type_table, name_entry, directory)?;
+                for language_entry in name_table.entries {
+                    let language = language_entry
+                        .name_or_id()
+                        .id()
+                        .ok_or_else(|| Error(String::from("Unsupported resource language name")))?;
+                    let entry = name_table
+                        .entry_data(language_entry, directory)
+                        .map_err(util::read_error)?
+                        .data()
+                        .ok_or_else(|| Error(String::from("Invalid resource data entry")))?;
+                    let resource_data = sections
+                        .pe_data_at(data, entry.offset_to_data.get(LE))
+                        .and_then(|resource_data| resource_data.get(..entry.size.get(LE) as usize))
+                        .ok_or_else(|| {
+                            Error(String::from("Invalid resource data address or size"))
+                        })?;
+                    resources.resources.insert(
+                        (
+                            id(type_entry, directory)?,
+                            id(name_entry, directory)?,
+                            language,
+                        ),
+                        Resource {
+                            data: resource_data.to_vec(),
+                            code_page: entry.code_page.get(LE),
+                        },
+                    );
+                }
+            }
+        }
+        Ok(resources)
+    }
+
+    /// Return the data of a resource.
+    pub fn get(&self, typ: &ResourceId, name: &ResourceId, language: u16) -> Option<&[u8]> {
+        self.resources
+            .get(&(typ.clone(), name.clone(), language))
+            .map(|resource| &*resource.data)
+    }
+
+    /// Add a resource, replacing any existing resource with the same type, name and language.
+    pub fn set(&mut self, typ: ResourceId, name: ResourceId, language: u16, data: Vec<u8>) {
+        self.resources
+            .insert((typ, name, language), Resource { data, code_page: 0 });
+    }
+
+    /// Remove a resource, and return its data.
+    pub fn remove(
+        &mut self,
+        typ: &ResourceId,
+        name: &ResourceId,
+        language: u16,
+    ) -> Option<Vec<u8>> {
+        self.resources
+            .remove(&(typ.clone(), name.clone(), language))
+            .map(|resource| resource.data)
+    }
+
+    /// Set the `VS_VERSIONINFO` resource.
+    ///
+    /// This replaces the version resources for all languages.
+    pub fn set_version_info(&mut self, language: u16, version: &VersionInfo) {
+        let typ = ResourceId::Id(pe::RT_VERSION);
+        let name = ResourceId::Id(pe::VS_VERSION_INFO);
+        self.resources
+            .retain(|(t, n, _), _| *t != typ || *n != name);
+        self.set(typ, name, language, version.data());
+    }
+
+    /// Return the size of the section data.
+    pub fn size(&self) -> u32 {
+        self.layout().size
+    }
+
+    /// Return the section data for a section at the given virtual address.
+    pub fn write(&self, virtual_address: u32) -> Vec<u8> {
+        let layout = self.layout();
+        let mut data = Vec::with_capacity(layout.size as usize);
+        let name_or_id = |id: &ResourceId| match id {
+            ResourceId::Name(name) => layout.strings[&**name] | pe::IMAGE_RESOURCE_NAME_IS_STRING,
+            ResourceId::Id(id) => u32::from(*id),
+        };
+
+        // The root table, with an entry for each type.
+        write_resource_table(&mut data, named_count(&layout.types), layout.types.len());
+        let mut table_offset = resource_table_size(layout.types.len());
+        for (typ, names) in &layout.types {
+            write_resource_entry(&mut data, name_or_id(typ), table_offset);
+            table_offset += resource_table_size(names.len());
+        }
+        // The type tables, with an entry for each name.
+        for (_, names) in &layout.types {
+            write_resource_table(&mut data, named_count(names), names.len());
+            for (name, languages) in names {
+                write_resource_entry(&mut data, name_or_id(name), table_offset);
+                table_offset += resource_table_size(languages.len());
+            }
+        }
+        // The name tables, with an entry for each language.
+        let mut entry_offset = table_offset;
+        for (_, names) in &layout.types {
+            for (_, languages) in names {
+                write_resource_table(&mut data, 0, languages.len());
+                for (language, _) in languages {
+                    data.extend_from_slice(pod::bytes_of(&pe::ImageResourceDirectoryEntry {
+                        name_or_id: U32::new(LE, u32::from(*language)),
+                        offset_to_data_or_directory: U32::new(LE, entry_offset),
+                    }));
+                    entry_offset += mem::size_of::<pe::ImageResourceDataEntry>() as u32;
+                }
+            }
+        }
+        // The data entries.
+        let mut data_offset = layout.data_offset;
+        for resource in layout.resources() {
+            data_offset = util::align_u32(data_offset, 8);
+            data.extend_from_slice(pod::bytes_of(&pe::ImageResourceDataEntry {
+                offset_to_data: U32::new(LE, virtual_address + data_offset),
+                size: U32::new(LE, resource.data.len() as u32),
+                code_page: U32::new(LE, resource.code_page),
+                reserved: U32::new(LE, 0),
+            }));
+            data_offset += resource.data.len() as u32;
+        }
+        // The names.
+        for name in layout.strings.keys() {
+            data.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            for c in name.iter() {
+                data.extend_from_slice(&c.to_le_bytes());
+            }
+        }
+        // The resource data.
+        for resource in layout.resources() {
+            data.resize(util::align(data.len(), 8), 0);
+            data.extend_from_slice(&resource.data);
+        }
+        debug_assert_eq!(data.len(), layout.size as usize);
+        data
+    }
+
+    fn layout(&self) -> ResourceLayout<'_> {
+        let mut types: Vec<(&ResourceId, Vec<(&ResourceId, Vec<(u16, &Resource)>)>)> = Vec::new();
+        for ((typ, name, language), resource) in &self.resources {
+            if types.last().map(|(t, _)| *t) != Some(typ) {
+                types.push((typ, Vec::new()));
+            }
+            let names = &mut types.last_mut().unwrap().1;
+            if names.last().map(|(n, _)| *n) != Some(name) {
+                names.push((name, Vec::new()));
+            }
+            names.last_mut().unwrap().1.push((*language, resource));
+        }
+
+        let mut offset = resource_table_size(types.len());
+        for (_, names) in &types {
+            offset += resource_table_size(names.len());
+            for (_, languages) in names {
+                offset += resource_table_size(languages.len());
+            }
+        }
+        offset += (self.resources.len() * mem::size_of::<pe::ImageResourceDataEntry>()) as u32;
+
+        let mut strings = BTreeMap::new();
+        for (typ, names) in &types {
+            for id in core::iter::once(typ).chain(names.iter().map(|(name, _)| name)) {
+                if let ResourceId::Name(name) = id {
+                    strings.insert(&**name, 0);
+                }
+            }
+        }
+        for string_offset in strings.iter_mut() {
+            *string_offset.1 = offset;
+            offset += 2 + 2 * string_offset.0.len() as u32;
+        }
+
+        let data_offset = offset;
+        for resource in self.resources.values() {
+            offset = util::align_u32(offset, 8) + resource.data.len() as u32;
+        }
+        ResourceLayout {
+            types,
+            strings,
+            data_offset,
+            size: offset,
+        }
+    }
+}
+
+struct ResourceLayout<'a> {
+    types: Vec<(
+        &'a ResourceId,
+        Vec<(&'a ResourceId, Vec<(u16, &'a Resource)>)>,
+    )>,
+    /// The section offset of each name.
+    strings: BTreeMap<&'a [u16], u32>,
+    /// The section offset of the resource data.
+    data_offset: u32,
+    size: u32,
+}
+
+impl<'a> ResourceLayout<'a> {
+    /// Return the resources in the order that their data entries are written.
+    fn resources(&self) -> impl Iterator<Item = &'a Resource> + '_ {
+        self.types
+            .iter()
+            .flat_map(|(_, names)| names.iter())
+            .flat_map(|(_, languages)| languages.iter().map(|(_, resource)| *resource))
+    }
+}
+
+fn resource_table_size(len: usize) -> u32 {
+    (mem::size_of::<pe::ImageResourceDirectory>()
+        + len * mem::size_of::<pe::ImageResourceDirectoryEntry>()) as u32
+}
+
+fn named_count<T>(entries: &[(&ResourceId, T)]) -> usize {
+    entries
+        .iter()
+        .filter(|(id, _)| matches!(id, ResourceId::Name(_)))
+        .count()
+}
+
+/// Write the header of a resource directory table.
+fn write_resource_table(data: &mut Vec<u8>, named: usize, len: usize) {
+    let header = pe::ImageResourceDirectory {
+        characteristics: U32::new(LE, 0),
+        time_date_stamp: U32::new(LE, 0),
+        major_version: U16::new(LE, 0),
+        minor_version: U16::new(LE, 0),
+        number_of_named_entries: U16::new(LE, named as u16),
+        number_of_id_entries: U16::new(LE, (len - named) as u16),
+    };
+    data.extend_from_slice(pod::bytes_of(&header));
+}
+
+/// Write a resource directory entry for a subtable.
+fn write_resource_entry(data: &mut Vec<u8>, name_or_id: u32, table_offset: u32) {
+    data.extend_from_slice(pod::bytes_of(&pe::ImageResourceDirectoryEntry {
+        name_or_id: U32::new(LE, name_or_id),
+        offset_to_data_or_directory: U32::new(
+            LE,
+            table_offset | pe::IMAGE_RESOURCE_DATA_IS_DIRECTORY,
+        ),
+    }));
+}
+
+/// The data for a `VS_VERSIONINFO` resource.
+///
+/// Use [`ResourceSection::set_version_info`] to add this to a resource section.
+#[derive(Debug, Default, Clone)]
+pub struct VersionInfo {
+    /// The binary version number of the file, from the most significant part.
+    pub file_version: [u16; 4],
+    /// The binary version number of the product, from the most significant part.
+    pub product_version: [u16; 4],
+    /// The `VS_FF_*` flags that are valid in `file_flags`.
+    pub file_flags_mask: u32,
+    /// `VS_FF_*`
+    pub file_flags: u32,
+    /// `VOS_*`
+    pub file_os: u32,
+    /// `VFT_*`
+    pub file_type: u32,
+    /// `VFT2_*`
+    pub file_subtype: u32,
+    /// The binary creation date and time stamp of the file.
+    pub file_date: u64,
+    /// The tables in the `StringFileInfo` block.
+    pub string_tables: Vec<VersionStringTable>,
+    /// The language and code page pairs in the `Translation` value of the `VarFileInfo` block.
+    ///
+    /// The block is omitted if this is empty.
+    pub translations: Vec<(u16, u16)>,
+}
+
+/// A `StringTable` block in a `VS_VERSIONINFO` resource.
+#[derive(Debug, Default, Clone)]
+pub struct VersionStringTable {
+    /// The language ID.
+    pub language: u16,
+    /// The code page.
+    pub code_page: u16,
+    /// The keys and values, such as `("FileDescription", "My program")`.
+    pub strings: Vec<(String, String)>,
+}
+
+impl VersionInfo {
+    /// Return the data of the resource.
+    pub fn data(&self) -> Vec<u8> {
+        let version = |v: [u16; 4]| {
+            (
+                u32::from(v[0]) << 16 | u32::from(v[1]),
+                u32::from(v[2]) << 16 | u32::from(v[3]),
+            )
+        };
+        let (file_version_ms, file_version_ls) = version(self.file_version);
+        let (product_version_ms, product_version_ls) = version(self.product_version);
+        let fixed = pe::VsFixedFileInfo {
+            signature: U32::new(LE, pe::VS_FFI_SIGNATURE),
+            struc_version: U32::new(LE, pe::VS_FFI_STRUCVERSION),
+            file_version_ms: U32::new(LE, file_version_ms),
+            file_version_ls: U32::new(LE, file_version_ls),
+            product_version_ms: U32::new(LE, product_version_ms),
+            product_version_ls: U32::new(LE, product_version_ls),
+            file_flags_mask: U32::new(LE, self.file_flags_mask),
+            file_flags: U32::new(LE, self.file_flags),
+            file_os: U32::new(LE, self.file_os),
+            file_type: U32::new(LE, self.file_type),
+            file_subtype: U32::new(LE, self.file_subtype),
+            file_date_ms: U32::new(LE, (self.file_date >> 32) as u32),
+            file_date_ls: U32::new(LE, self.file_date as u32),
+        };
+
+        let mut data = Vec::new();
+        let fixed = pod::bytes_of(&fixed);
+        let root = begin_version_block(&mut data, "VS_VERSION_INFO", 0, fixed.len() as u16);
+        data.extend_from_slice(fixed);
+        if !self.string_tables.is_empty() {
+            let info = begin_version_block(&mut data, "StringFileInfo", 1, 0);
+            for table in &self.string_tables {
+                let key = format!("{:04x}{:04x}", table.language, table.code_page);
+                let block = begin_version_block(&mut data, &key, 1, 0);
+                for (key, value) in &table.strings {
+                    let value = value.encode_utf16().chain(Some(0)).collect::<Vec<_>>();
+                    let string = begin_version_block(&mut data, key, 1, value.len() as u16);
+                    for c in value {
+                        data.extend_from_slice(&c.to_le_bytes());
+                    }
+                    end_version_block(&mut data, string);
+                }
+                end_version_block(&mut data, block);
+            }
+            end_version_block(&mut data, info);
+        }
+        if !self.translations.is_empty() {
+            let info = begin_version_block(&mut data, "VarFileInfo", 1, 0);
+            let len = self.translations.len() * 4;
+            let var = begin_version_block(&mut data, "Translation", 0, len as u16);
+            for (language, code_page) in &self.translations {
+                data.extend_from_slice(&language.to_le_bytes());
+                data.extend_from_slice(&code_page.to_le_bytes());
+            }
+            end_version_block(&mut data, var);
+            end_version_block(&mut data, info);
+        }
+        end_version_block(&mut data, root);
+        data
+    }
+}
+
+/// Write the header and key of a block in a `VS_VERSIONINFO` resource,
+/// and return the offset of the block.
+///
+/// `value_type` is 1 for text values and 0 for binary values. `value_len` is
+/// in 16-bit words for text values, and bytes for binary values.
+fn begin_version_block(data: &mut Vec<u8>, key: &str, value_type: u16, value_len: u16) -> usize {
+    data.resize(util::align(data.len(), 4), 0);
+    let offset = data.len();
+    // The length is set by `end_version_block`.
+    data.extend_from_slice(&0u16.to_le_bytes());
+    data.extend_from_slice(&value_len.to_le_bytes());
+    data.extend_from_slice(&value_type.to_le_bytes());
+    for c in key.encode_utf16().chain(Some(0)) {
+        data.extend_from_slice(&c.to_le_bytes());
+    }
+    data.resize(util::align(data.len(), 4), 0);
+    offset
+}
+
+/// Set the length of a block in a `VS_VERSIONINFO` resource.
+fn end_version_block(data: &mut [u8], offset: usize) {
+    let len = (data.len() - offset) as u16;
+    data[offset..][..2].copy_from_slice(&len.to_le_bytes());
+}
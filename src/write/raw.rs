@@ -0,0 +1,175 @@
+//! Support for writing raw binary images.
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+#[cfg(all(feature = "read_core", feature = "elf"))]
+use crate::elf;
+#[cfg(feature = "read_core")]
+use crate::read::{self, ObjectSection, ObjectSegment};
+use crate::write::{Error, Result, WritableBuffer};
+#[cfg(feature = "read_core")]
+use crate::{SectionFlags, SectionKind};
+
+/// A writer for raw binary images.
+///
+/// This is equivalent to `objcopy -O binary`. The data of the loadable
+/// sections is written at offsets relative to a base address, and the gaps
+/// between sections are filled with a fill byte.
+#[derive(Debug, Default, Clone)]
+pub struct Writer {
+    base_address: Option<u64>,
+    fill: u8,
+}
+
+impl Writer {
+    /// Create a new `Writer` with the default settings.
+    ///
+    /// By default, the base address is the lowest address of the data, and the
+    /// fill byte is 0.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the address of the first byte of the image.
+    ///
+    /// This must not be greater than the lowest address of the data. Any space
+    /// before the data is filled with the fill byte.
+    pub fn set_base_address(&mut self, base_address: u64) {
+        self.base_address = Some(base_address);
+    }
+
+    /// Set the byte used to fill gaps between sections.
+    pub fn set_fill(&mut self, fill: u8) {
+        self.fill = fill;
+    }
+
+    /// Write the data of the loadable sections of an object file.
+    ///
+    /// For ELF files, loadable sections are those with the `SHF_ALLOC` flag and
+    /// file data. For other file formats, sections that contain code or
+    /// initialized data are loadable. Uninitialized data is not written unless
+    /// it is in a gap between other sections.
+    ///
+    /// Sections are placed at their load address, which is found using the
+    /// physical address of the segment containing the section's file data.
+    /// Sections that are not in a segment are placed at their address.
+    ///
+    /// Returns the base address of the image.
+    #[cfg(feature = "read_core")]
+    pub fn write_object<'data: 'file, 'file, O>(
+        &self,
+        file: &'file O,
+        buffer: &mut dyn WritableBuffer,
+    ) -> Result<u64>
+    where
+        O: read::Object<'data, 'file>,
+    {
+        let segments = file
+            .segments()
+            .map(|segment| (segment.file_range(), segment.physical_address()))
+            .collect::<Vec<_>>();
+        let mut sections = Vec::new();
+        for section in file.sections() {
+            if !is_loadable(&section) {
+                continue;
+            }
+            let data = section.data().map_err(|_| {
+                Error(format!(
+                    "invalid data for section `{}`",
+                    section.name().unwrap_or("")
+                ))
+            })?;
+            if !data.is_empty() {
+                let address = section
+                    .file_range()
+                    .and_then(|(offset, size)| {
+                        segments
+                            .iter()
+                            .find_map(|((segment_offset, segment_size), address)| {
+                                let start = offset.checked_sub(*segment_offset)?;
+                                if start.checked_add(size)? > *segment_size {
+                                    return None;
+                                }
+                                address.checked_add(start)
+                            })
+                    })
+                    .unwrap_or_else(|| section.address());
+                sections.push((address, data));
+            }
+        }
+        self.write(&sections, buffer)
+    }
+
+    /// Write the given blocks of data, which are pairs of an address and the data
+    /// at that address.
+    ///
+    /// Returns an error if the blocks overlap.
+    ///
+    /// Returns the base address of the image.
+    pub fn write(&self, blocks: &[(u64, &[u8])], buffer: &mut dyn WritableBuffer) -> Result<u64> {
+        let mut blocks = blocks.to_vec();
+        blocks.sort_by_key(|(address, _)| *address);
+        let base_address = match (self.base_address, blocks.first()) {
+            (Some(base_address), _) => base_address,
+            (None, Some((address, _))) => *address,
+            (None, None) => 0,
+        };
+
+        let mut end = base_address;
+        for (address, data) in &blocks {
+            if *address < end {
+                return Err(Error(format!(
+                    "data at address {:#x} overlaps previous data or base address {:#x}",
+                    address, base_address
+                )));
+            }
+            end = address
+                .checked_add(data.len() as u64)
+                .ok_or_else(|| Error(format!("invalid data address {:#x}", address)))?;
+        }
+        let len = usize::try_from(end - base_address)
+            .map_err(|_| Error(format!("image size {:#x} is too large", end - base_address)))?;
+        buffer
+            .reserve(len)
+            .map_err(|_| Error(String::from("Cannot allocate buffer")))?;
+
+        let fill = [self.fill; 256];
+        let mut offset = base_address;
+        for (address, data) in &blocks {
+            let mut gap = (address - offset) as usize;
+            while gap != 0 {
+                let len = gap.min(fill.len());
+                buffer.write_bytes(&fill[..len]);
+                gap -= len;
+            }
+            buffer.write_bytes(data);
+            offset = address + data.len() as u64;
+        }
+        Ok(base_address)
+    }
+}
+
+#[cfg(feature = "read_core")]
+fn is_loadable<'data, S: ObjectSection<'data>>(section: &S) -> bool {
+    match section.flags() {
+        #[cfg(feature = "elf")]
+        SectionFlags::Elf { sh_flags } => {
+            sh_flags & u64::from(elf::SHF_ALLOC) != 0 && !section.kind().is_bss()
+        }
+        _ => matches!(
+            section.kind(),
+            SectionKind::Text
+                | SectionKind::Data
+                | SectionKind::ReadOnlyData
+                | SectionKind::ReadOnlyDataWithRel
+                | SectionKind::ReadOnlyString
+                | SectionKind::Tls
+                | SectionKind::Got
+                | SectionKind::Plt
+                | SectionKind::InitArray
+                | SectionKind::FiniArray
+                | SectionKind::Unwind
+        ),
+    }
+}
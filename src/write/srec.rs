@@ -0,0 +1,190 @@
+//! Support for writing Motorola S-record files.
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "read_core")]
+use crate::read::{self, ObjectSegment};
+use crate::write::{Error, Result, WritableBuffer};
+#[cfg(feature = "read_core")]
+use crate::SegmentKind;
+
+/// A writer for Motorola S-record files.
+///
+/// The address size of the records is chosen automatically, so the output is
+/// an S19, S28 or S37 file depending on the highest address that is written.
+#[derive(Debug, Clone)]
+pub struct Writer {
+    record_length: usize,
+    header: Vec<u8>,
+}
+
+impl Default for Writer {
+    fn default() -> Self {
+        Writer {
+            record_length: 16,
+            header: Vec::new(),
+        }
+    }
+}
+
+impl Writer {
+    /// Create a new `Writer` with the default settings.
+    ///
+    /// The default record length is 16 bytes, and the header record is empty.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of data bytes in each data record.
+    ///
+    /// The length must be at least 1, and small enough that the record fits
+    /// in the 255 byte record limit after adding the address and checksum.
+    pub fn set_record_length(&mut self, record_length: usize) {
+        self.record_length = record_length;
+    }
+
+    /// Set the data of the header record.
+    ///
+    /// This is usually a module name or description.
+    pub fn set_header(&mut self, header: &[u8]) {
+        self.header = header.to_vec();
+    }
+
+    /// Write the file data of the loadable segments of an object file.
+    ///
+    /// Segments without file data are skipped. The entry point of the file is
+    /// written to the termination record.
+    #[cfg(feature = "read_core")]
+    pub fn write_object<'data: 'file, 'file, O>(
+        &self,
+        file: &'file O,
+        buffer: &mut dyn WritableBuffer,
+    ) -> Result<()>
+    where
+        O: read::Object<'data, 'file>,
+    {
+        let mut segments = Vec::new();
+        for segment in file.segments() {
+            if segment.kind() != SegmentKind::Load {
+                continue;
+            }
+            let data = segment.data().map_err(|_| {
+                Error(format!(
+                    "invalid data for segment at {:#x}",
+                    segment.address()
+                ))
+            })?;
+            if !data.is_empty() {
+                segments.push((segment.address(), data));
+            }
+        }
+        self.write(&segments, file.entry(), buffer)
+    }
+
+    /// Write the given blocks of data, which are pairs of an address and the data
+    /// at that address.
+    ///
+    /// `entry` is written to the termination record.
+    pub fn write(
+        &self,
+        segments: &[(u64, &[u8])],
+        entry: u64,
+        buffer: &mut dyn WritableBuffer,
+    ) -> Result<()> {
+        let mut max_address = entry;
+        for (address, data) in segments {
+            let end = address
+                .checked_add(data.len() as u64)
+                .ok_or_else(|| Error(format!("invalid S-record address {:#x}", address)))?;
+            max_address = max_address.max(end.saturating_sub(1));
+        }
+        let address_size = match max_address {
+            0..=0xffff => 2,
+            0x1_0000..=0xff_ffff => 3,
+            0x100_0000..=0xffff_ffff => 4,
+            _ => {
+                return Err(Error(format!(
+                    "S-record address {:#x} is too large",
+                    max_address
+                )))
+            }
+        };
+        if self.record_length == 0 || self.record_length > 0xff - address_size - 1 {
+            return Err(Error(format!(
+                "invalid S-record length {}",
+                self.record_length
+            )));
+        }
+        if self.header.len() > 0xff - 2 - 1 {
+            return Err(Error(format!(
+                "S-record header is too long: {} bytes",
+                self.header.len()
+            )));
+        }
+
+        // Each record has a type, and the hex digits for the count, address,
+        // data and checksum, followed by a newline.
+        let record_size = |address_size: usize, len: usize| 2 + 2 * (address_size + len + 2) + 1;
+        let mut len = record_size(2, self.header.len()) + record_size(address_size, 0);
+        for (_, data) in segments {
+            let full = data.len() / self.record_length;
+            let partial = data.len() % self.record_length;
+            len += full * record_size(address_size, self.record_length);
+            if partial != 0 {
+                len += record_size(address_size, partial);
+            }
+        }
+        buffer
+            .reserve(len)
+            .map_err(|_| Error(String::from("Cannot allocate buffer")))?;
+
+        let (data_type, end_type) = match address_size {
+            2 => (b'1', b'9'),
+            3 => (b'2', b'8'),
+            _ => (b'3', b'7'),
+        };
+        let mut record = Vec::with_capacity(record_size(4, 0xff));
+        write_record(buffer, &mut record, b'0', 2, 0, &self.header);
+        for (address, data) in segments {
+            for (i, chunk) in data.chunks(self.record_length).enumerate() {
+                let address = address + (i * self.record_length) as u64;
+                write_record(buffer, &mut record, data_type, address_size, address, chunk);
+            }
+        }
+        write_record(buffer, &mut record, end_type, address_size, entry, &[]);
+        Ok(())
+    }
+}
+
+fn write_record(
+    buffer: &mut dyn WritableBuffer,
+    record: &mut Vec<u8>,
+    kind: u8,
+    address_size: usize,
+    address: u64,
+    data: &[u8],
+) {
+    const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+    let mut checksum = 0u8;
+    let mut push = |record: &mut Vec<u8>, byte: u8| {
+        checksum = checksum.wrapping_add(byte);
+        record.push(DIGITS[usize::from(byte >> 4)]);
+        record.push(DIGITS[usize::from(byte & 0xf)]);
+    };
+
+    record.clear();
+    record.push(b'S');
+    record.push(kind);
+    push(record, (address_size + data.len() + 1) as u8);
+    for i in (0..address_size).rev() {
+        push(record, (address >> (i * 8)) as u8);
+    }
+    for byte in data {
+        push(record, *byte);
+    }
+    let checksum = !checksum;
+    record.push(DIGITS[usize::from(checksum >> 4)]);
+    record.push(DIGITS[usize::from(checksum & 0xf)]);
+    record.push(b'\n');
+    buffer.write_bytes(record);
+}
@@ -1,4 +1,6 @@
 use alloc::vec::Vec;
+#[cfg(feature = "mmap")]
+use std::fs;
 #[cfg(feature = "std")]
 use std::{io, mem};
 
@@ -80,6 +82,33 @@ impl WritableBuffer for Vec<u8> {
     }
 }
 
+/// A [`WritableBuffer`] that only records the total size of the output.
+///
+/// This fails the reservation so that writing stops after the layout is complete.
+#[derive(Debug, Default)]
+pub(crate) struct SizeBuffer {
+    pub size: Option<usize>,
+}
+
+impl WritableBuffer for SizeBuffer {
+    #[inline]
+    fn len(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn reserve(&mut self, size: usize) -> Result<(), ()> {
+        self.size = Some(size);
+        Err(())
+    }
+
+    #[inline]
+    fn resize(&mut self, _new_len: usize) {}
+
+    #[inline]
+    fn write_bytes(&mut self, _val: &[u8]) {}
+}
+
 /// A [`WritableBuffer`] that streams data to a [`Write`](std::io::Write) implementation.
 ///
 /// [`Self::result`] must be called to determine if an I/O error occurred during writing.
@@ -146,6 +175,114 @@ impl<W: io::Write> WritableBuffer for StreamingBuffer<W> {
     }
 }
 
+/// A [`WritableBuffer`] that writes to a memory mapped file.
+///
+/// The file is resized to the total size of the output when writing starts,
+/// and the data is written directly to the mapping.
+///
+/// [`Self::finish`] must be called to flush the data and to determine if an I/O
+/// error occurred during writing.
+#[cfg(feature = "mmap")]
+#[derive(Debug)]
+pub struct MmapBuffer {
+    file: fs::File,
+    mmap: Option<memmap2::MmapMut>,
+    len: usize,
+    result: Result<(), io::Error>,
+}
+
+#[cfg(feature = "mmap")]
+impl MmapBuffer {
+    /// Create a new `MmapBuffer` that writes to the given file.
+    ///
+    /// The file must be opened for reading and writing.
+    pub fn new(file: fs::File) -> Self {
+        MmapBuffer {
+            file,
+            mmap: None,
+            len: 0,
+            result: Ok(()),
+        }
+    }
+
+    /// Flush the data to the file, and return the file.
+    ///
+    /// Returns any error that occurred during writing.
+    pub fn finish(self) -> Result<fs::File, io::Error> {
+        self.result?;
+        if let Some(mmap) = self.mmap {
+            mmap.flush()?;
+        }
+        Ok(self.file)
+    }
+
+    /// Return the mapped data for the next `size` bytes.
+    ///
+    /// Sets the result to an error if the data is beyond the end of the mapping.
+    fn slice_mut(&mut self, size: usize) -> Option<&mut [u8]> {
+        if size == 0 {
+            return None;
+        }
+        let start = self.len;
+        let slice = self
+            .mmap
+            .as_mut()
+            .and_then(|mmap| mmap.get_mut(start..start.checked_add(size)?));
+        if slice.is_none() && self.result.is_ok() {
+            self.result = Err(io::Error::new(
+                io::ErrorKind::Other,
+                "write beyond reserved size",
+            ));
+        }
+        slice
+    }
+
+    fn map(&mut self, size: usize) -> Result<(), io::Error> {
+        self.file.set_len(size as u64)?;
+        if size != 0 {
+            // Safety: the file is owned by this buffer, but other processes may
+            // still modify it while it is mapped.
+            self.mmap = Some(unsafe { memmap2::MmapMut::map_mut(&self.file)? });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl WritableBuffer for MmapBuffer {
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn reserve(&mut self, size: usize) -> Result<(), ()> {
+        debug_assert!(self.mmap.is_none());
+        if let Err(e) = self.map(size) {
+            self.result = Err(e);
+            return Err(());
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn resize(&mut self, new_len: usize) {
+        debug_assert!(self.len <= new_len);
+        // The file may have existing data, so this can't skip writing the zeros.
+        if let Some(dest) = self.slice_mut(new_len - self.len) {
+            dest.fill(0);
+        }
+        self.len = new_len;
+    }
+
+    #[inline]
+    fn write_bytes(&mut self, val: &[u8]) {
+        if let Some(dest) = self.slice_mut(val.len()) {
+            dest.copy_from_slice(val);
+        }
+        self.len += val.len();
+    }
+}
+
 /// A trait for mutable byte slices.
 ///
 /// It provides convenience methods for `Pod` types.
@@ -214,6 +351,12 @@ pub(crate) fn write_sleb128<W>(buf: &mut Vec<u8>, mut val: i64) -> usize {
     }
 }
 
+/// Convert a read error into a write error.
+#[cfg(feature = "read_core")]
+pub(crate) fn read_error(error: crate::read::Error) -> super::Error {
+    super::Error(format!("{}", error))
+}
+
 pub(crate) fn align(offset: usize, size: usize) -> usize {
     (offset + (size - 1)) & !(size - 1)
 }
@@ -257,4 +400,35 @@ mod tests {
         assert_eq!(bytes.write_at(4, &u16::to_be(0x89ab)), Err(()));
         assert_eq!(vec![].write_at(0, &u32::to_be(0x89ab)), Err(()));
     }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn mmap_buffer() {
+        let path = std::env::temp_dir().join(format!("object-mmap-{}", std::process::id()));
+        // Existing data must be overwritten.
+        fs::write(&path, [0xff; 16]).unwrap();
+        let open = || {
+            fs::OpenOptions::new()
+                .read(true)
+                .write(true)
+                .open(&path)
+                .unwrap()
+        };
+
+        let mut buffer = MmapBuffer::new(open());
+        assert_eq!(WritableBuffer::reserve(&mut buffer, 8), Ok(()));
+        buffer.write_bytes(&[1, 2, 3]);
+        WritableBuffer::resize(&mut buffer, 6);
+        buffer.write_bytes(&[4, 5]);
+        assert_eq!(WritableBuffer::len(&buffer), 8);
+        buffer.finish().unwrap();
+        assert_eq!(fs::read(&path).unwrap(), [1, 2, 3, 0, 0, 0, 4, 5]);
+
+        let mut buffer = MmapBuffer::new(open());
+        assert_eq!(WritableBuffer::reserve(&mut buffer, 2), Ok(()));
+        buffer.write_bytes(&[1, 2, 3]);
+        assert!(buffer.finish().is_err());
+
+        fs::remove_file(&path).unwrap();
+    }
 }
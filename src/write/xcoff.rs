@@ -1,4 +1,4 @@
-use core::mem;
+use core::{cmp, mem};
 
 use crate::endian::{BigEndian as BE, I16, U16, U32};
 use crate::write::string::*;
@@ -284,6 +284,12 @@ impl<'a> Object<'a> {
                     | SectionKind::Common
                     | SectionKind::Unknown
                     | SectionKind::TlsVariables
+                    | SectionKind::Got
+                    | SectionKind::Plt
+                    | SectionKind::InitArray
+                    | SectionKind::FiniArray
+                    | SectionKind::Unwind
+                    | SectionKind::Dynamic
                     | SectionKind::Elf(_) => {
                         return Err(Error(format!(
                             "unimplemented section `{}` kind {:?}",
@@ -385,6 +391,9 @@ impl<'a> Object<'a> {
                     section_offsets[id.0].address + symbol.value,
                     self.sections[id.0].kind,
                 )
+            } else if symbol.section == SymbolSection::Common {
+                // The size and alignment are written in the csect auxiliary entry.
+                (0, SectionKind::Unknown)
             } else {
                 (symbol.value, SectionKind::Unknown)
             };
@@ -481,6 +490,10 @@ impl<'a> Object<'a> {
                 } = symbol.flags
                 {
                     (x_smtyp, x_smclas)
+                } else if symbol.section == SymbolSection::Common {
+                    // The log2 of the alignment is stored in the upper 5 bits of `x_smtyp`.
+                    let align = cmp::min(symbol.value.next_power_of_two().trailing_zeros(), 31);
+                    ((align as u8) << 3 | xcoff::XTY_CM, xcoff::XMC_RW)
                 } else {
                     match symbol.kind {
                         SymbolKind::Text => (xcoff::XTY_SD, xcoff::XMC_PR),
@@ -10,8 +10,12 @@
 use crate::endian::{BigEndian as BE, I16, U16, U32, U64};
 use crate::pod::Pod;
 
+mod names;
+pub use names::*;
+
 /// The header at the start of every 32-bit XCOFF file.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct FileHeader32 {
     /// Magic number. Must be 0x01DF.
@@ -32,6 +36,7 @@ pub struct FileHeader32 {
 
 /// The header at the start of every 64-bit XCOFF file.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct FileHeader64 {
     /// Magic number. Must be 0x01F7.
@@ -91,6 +96,7 @@ pub const F_LOADONLY: u16 = 0x4000;
 /// The auxiliary header immediately following file header. If the value of the
 /// f_opthdr field in the file header is 0, the auxiliary header does not exist.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct AuxHeader32 {
     /// Flags.
@@ -156,6 +162,7 @@ pub struct AuxHeader32 {
 /// The auxiliary header immediately following file header. If the value of the
 /// f_opthdr field in the file header is 0, the auxiliary header does not exist.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct AuxHeader64 {
     /// Flags.
@@ -230,6 +237,7 @@ pub const AOUTHSZ_SHORT: u16 = 28;
 
 /// Section header.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct SectionHeader32 {
     /// Section name.
@@ -256,6 +264,7 @@ pub struct SectionHeader32 {
 
 /// Section header.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(C)]
 pub struct SectionHeader64 {
     /// Section name.
@@ -0,0 +1,46 @@
+//! Functions for finding the names of XCOFF constants.
+
+use crate::common::{value_name, FlagName, FlagNames};
+use crate::xcoff::*;
+
+/// Return the name of a relocation type in a relocation's `r_rtype` field.
+pub fn r_rtype_name(r_rtype: u8) -> Option<&'static str> {
+    value_name(r_rtype, NAMES_R)
+}
+
+/// Return the names of the flags in a section header's `s_flags` field.
+pub fn s_flags_names(s_flags: u32) -> FlagNames {
+    FlagNames::new(s_flags.into(), NAMES_STYP, &[])
+}
+
+/// Return the name of a symbol's storage class.
+pub fn n_sclass_name(n_sclass: u8) -> Option<&'static str> {
+    value_name(n_sclass, NAMES_C)
+}
+
+const NAMES_R: &[(u8, &str)] = &value_names!(
+    R_POS, R_RL, R_RLA, R_NEG, R_REL, R_TOC, R_TRL, R_TRLA, R_GL, R_TCL, R_REF, R_BA, R_BR, R_RBA,
+    R_RBR, R_TLS, R_TLS_IE, R_TLS_LD, R_TLS_LE, R_TLSM, R_TLSML, R_TOCU, R_TOCL
+);
+const NAMES_STYP: &[FlagName] = &flag_names!(
+    STYP_PAD,
+    STYP_DWARF,
+    STYP_TEXT,
+    STYP_DATA,
+    STYP_BSS,
+    STYP_EXCEPT,
+    STYP_INFO,
+    STYP_TDATA,
+    STYP_TBSS,
+    STYP_LOADER,
+    STYP_DEBUG,
+    STYP_TYPCHK,
+    STYP_OVRFLO
+);
+const NAMES_C: &[(u8, &str)] = &value_names!(
+    C_FILE, C_BINCL, C_EINCL, C_GSYM, C_STSYM, C_BCOMM, C_ECOMM, C_ENTRY, C_BSTAT, C_ESTAT, C_GTLS,
+    C_STTLS, C_DWARF, C_LSYM, C_PSYM, C_RSYM, C_RPSYM, C_ECOML, C_FUN, C_EXT, C_WEAKEXT, C_NULL,
+    C_STAT, C_BLOCK, C_FCN, C_HIDEXT, C_INFO, C_DECL, C_AUTO, C_REG, C_EXTDEF, C_LABEL, C_ULABEL,
+    C_MOS, C_ARG, C_STRTAG, C_MOU, C_UNTAG, C_TPDEF, C_USTATIC, C_ENTAG, C_MOE, C_REGPARM, C_FIELD,
+    C_EOS, C_ALIAS, C_HIDDEN, C_EFCN, C_TCSYM
+);
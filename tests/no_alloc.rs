@@ -0,0 +1,71 @@
+#![cfg(feature = "read")]
+//! Check that parsing, section and symbol iteration, and section name lookup don't allocate.
+//!
+//! This covers ELF and PE, which are the formats used by bootloaders and kernels.
+//! Other formats and the remaining `Object` methods may allocate. In particular,
+//! `Object::symbol_by_name` builds an index on first use when the `std` feature
+//! is enabled.
+
+use object::{File, FileKind, Object, ObjectSection, ObjectSymbol};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::{env, fs};
+
+struct CountingAllocator;
+
+thread_local! {
+    // Only count allocations on the test thread, not the test harness.
+    static COUNT: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        COUNT.with(|count| {
+            if let Some(n) = count.get() {
+                count.set(Some(n + 1));
+            }
+        });
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn count_allocations<F: FnOnce()>(f: F) -> usize {
+    COUNT.with(|count| count.set(Some(0)));
+    f();
+    COUNT.with(|count| count.replace(None)).unwrap()
+}
+
+#[test]
+fn parse_self_no_alloc() {
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    match FileKind::parse(&*data).unwrap() {
+        FileKind::Elf32 | FileKind::Elf64 | FileKind::Pe32 | FileKind::Pe64 => {}
+        _ => return,
+    }
+
+    let allocations = count_allocations(|| {
+        let object = File::parse(&*data).unwrap();
+        assert!(object.entry() != 0);
+        let mut sections = 0;
+        for section in object.sections() {
+            section.name().unwrap();
+            sections += 1;
+        }
+        assert!(sections != 0);
+        for symbol in object.symbols() {
+            let _ = symbol.name();
+        }
+        assert!(object.section_by_name(".text").is_some());
+        // This also looks for `.zdebug_info`.
+        let _ = object.section_by_name(".debug_info");
+    });
+    assert_eq!(allocations, 0);
+}
@@ -23,3 +23,14 @@ fn parse_self_cache() {
     assert!(object.entry() != 0);
     assert!(object.sections().count() != 0);
 }
+
+#[cfg(feature = "mmap")]
+#[test]
+fn parse_self_mmap() {
+    use object::read::MappedFile;
+    let exe = env::current_exe().unwrap();
+    let mapped = MappedFile::open(exe).unwrap();
+    let object = mapped.parse().unwrap();
+    assert!(object.entry() != 0);
+    assert!(object.sections().count() != 0);
+}
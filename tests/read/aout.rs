@@ -0,0 +1,190 @@
+use object::read::aout::AoutFile;
+use object::read::{
+    Object, ObjectSection, ObjectSegment, ObjectSymbol, RelocationTarget, SectionIndex,
+    SymbolIndex, SymbolSection,
+};
+use object::{aout, ObjectKind, RelocationKind, SectionKind, SymbolKind};
+
+// Build a little endian a.out file from its parts.
+fn build(
+    magic: u16,
+    text: &[u8],
+    data: &[u8],
+    bss: u32,
+    text_relocs: &[(u32, u32)],
+    symbols: &[(&str, u8, u32)],
+) -> Vec<u8> {
+    let mut strtab = vec![0; 4];
+    let mut symtab = Vec::new();
+    for (name, n_type, n_value) in symbols {
+        symtab.extend_from_slice(&(strtab.len() as u32).to_le_bytes());
+        symtab.push(*n_type);
+        symtab.extend_from_slice(&[0, 0, 0]);
+        symtab.extend_from_slice(&n_value.to_le_bytes());
+        strtab.extend_from_slice(name.as_bytes());
+        strtab.push(0);
+    }
+    let strtab_len = strtab.len() as u32;
+    strtab[..4].copy_from_slice(&strtab_len.to_le_bytes());
+
+    let mut out = Vec::new();
+    let a_info = u32::from(magic) | u32::from(aout::M_386) << 16;
+    for field in [
+        a_info,
+        text.len() as u32,
+        data.len() as u32,
+        bss,
+        symtab.len() as u32,
+        0,
+        (text_relocs.len() * 8) as u32,
+        0,
+    ] {
+        out.extend_from_slice(&field.to_le_bytes());
+    }
+    if magic == aout::ZMAGIC {
+        out.resize(aout::ZMAGIC_TEXT_OFFSET as usize, 0);
+    }
+    out.extend_from_slice(text);
+    out.extend_from_slice(data);
+    for (r_address, r_info) in text_relocs {
+        out.extend_from_slice(&r_address.to_le_bytes());
+        out.extend_from_slice(&r_info.to_le_bytes());
+    }
+    out.extend_from_slice(&symtab);
+    out.extend_from_slice(&strtab);
+    out
+}
+
+#[test]
+fn aout_object() {
+    // A pc-relative 32-bit relocation against symbol 2, and an absolute 32-bit
+    // relocation against the data section.
+    let relocs = [
+        (1, 2 | 1 << 24 | 2 << 25 | 1 << 27),
+        (6, aout::N_DATA as u32 | 2 << 25),
+    ];
+    let data = build(
+        aout::OMAGIC,
+        &[0xe8, 0, 0, 0, 0, 0xa1, 8, 0, 0, 0, 0xc3, 0x90],
+        &[1, 2, 3, 4],
+        16,
+        &relocs,
+        &[
+            ("_main", aout::N_TEXT | aout::N_EXT, 0),
+            ("_var", aout::N_DATA, 12),
+            ("_bar", aout::N_UNDF | aout::N_EXT, 0),
+            ("_common", aout::N_UNDF | aout::N_EXT, 32),
+            ("_abs", aout::N_ABS | aout::N_EXT, 0x1234),
+        ],
+    );
+    assert_eq!(
+        object::FileKind::parse(&*data).unwrap(),
+        object::FileKind::Aout
+    );
+    let file = AoutFile::<&[u8]>::parse(&*data).unwrap();
+    assert_eq!(file.kind(), ObjectKind::Relocatable);
+    assert_eq!(file.architecture(), object::Architecture::I386);
+    assert!(file.is_little_endian());
+
+    let sections: Vec<_> = file.sections().collect();
+    assert_eq!(sections.len(), 3);
+    assert_eq!(sections[0].name(), Ok(".text"));
+    assert_eq!(sections[0].kind(), SectionKind::Text);
+    assert_eq!(sections[0].file_range(), Some((32, 12)));
+    assert_eq!(sections[1].name(), Ok(".data"));
+    assert_eq!(sections[1].address(), 12);
+    assert_eq!(sections[1].data(), Ok(&[1, 2, 3, 4][..]));
+    assert_eq!(sections[2].name(), Ok(".bss"));
+    assert_eq!(sections[2].kind(), SectionKind::UninitializedData);
+    assert_eq!(sections[2].address(), 16);
+    assert_eq!(sections[2].size(), 16);
+    assert_eq!(sections[2].file_range(), None);
+    assert_eq!(
+        file.section_by_name(".data").unwrap().index(),
+        SectionIndex(2)
+    );
+
+    let relocations: Vec<_> = sections[0].relocations().collect();
+    assert_eq!(relocations.len(), 2);
+    let (offset, ref relocation) = relocations[0];
+    assert_eq!(offset, 1);
+    assert_eq!(relocation.kind(), RelocationKind::Relative);
+    assert_eq!(relocation.size(), 32);
+    assert_eq!(
+        relocation.target(),
+        RelocationTarget::Symbol(SymbolIndex(2))
+    );
+    assert!(relocation.has_implicit_addend());
+    let (offset, ref relocation) = relocations[1];
+    assert_eq!(offset, 6);
+    assert_eq!(relocation.kind(), RelocationKind::Absolute);
+    assert_eq!(
+        relocation.target(),
+        RelocationTarget::Section(SectionIndex(2))
+    );
+    assert_eq!(sections[1].relocations().count(), 0);
+
+    let symbols: Vec<_> = file.symbols().collect();
+    assert_eq!(symbols.len(), 5);
+    assert_eq!(symbols[0].name(), Ok("_main"));
+    assert_eq!(symbols[0].kind(), SymbolKind::Text);
+    assert_eq!(
+        symbols[0].section(),
+        SymbolSection::Section(SectionIndex(1))
+    );
+    assert!(symbols[0].is_global());
+    assert_eq!(symbols[1].name(), Ok("_var"));
+    assert_eq!(symbols[1].kind(), SymbolKind::Data);
+    assert_eq!(symbols[1].address(), 12);
+    assert!(symbols[1].is_local());
+    assert_eq!(symbols[2].name(), Ok("_bar"));
+    assert!(symbols[2].is_undefined());
+    assert_eq!(symbols[3].name(), Ok("_common"));
+    assert!(symbols[3].is_common());
+    assert_eq!(symbols[3].size(), 32);
+    assert_eq!(symbols[4].section(), SymbolSection::Absolute);
+    assert_eq!(symbols[4].address(), 0x1234);
+    assert_eq!(
+        file.symbol_by_index(SymbolIndex(2)).unwrap().name(),
+        Ok("_bar")
+    );
+    assert!(!file.has_debug_symbols());
+}
+
+#[test]
+fn aout_zmagic() {
+    let data = build(aout::ZMAGIC, &[0x90; 0x10], &[1, 2], 4, &[], &[]);
+    let file = AoutFile::<&[u8]>::parse(&*data).unwrap();
+    assert_eq!(file.kind(), ObjectKind::Executable);
+    assert!(file.symbol_table().is_none());
+
+    let text = file.section_by_name(".text").unwrap();
+    assert_eq!(text.file_range(), Some((1024, 0x10)));
+    let data_section = file.section_by_name(".data").unwrap();
+    assert_eq!(data_section.address(), 1024);
+    assert_eq!(data_section.data(), Ok(&[1, 2][..]));
+
+    let segments: Vec<_> = file.segments().collect();
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments[0].address(), 0);
+    assert_eq!(segments[0].size(), 0x10);
+    assert_eq!(segments[1].address(), 1024);
+    assert_eq!(segments[1].size(), 6);
+    assert_eq!(segments[1].data(), Ok(&[1, 2][..]));
+}
+
+#[test]
+fn aout_big_endian() {
+    let mut data = vec![0; 32];
+    data[..4].copy_from_slice(&(u32::from(aout::M_68020) << 16 | 0o407).to_be_bytes());
+    data[4..8].copy_from_slice(&4u32.to_be_bytes());
+    data.extend_from_slice(&[0x4e, 0x75, 0x4e, 0x71]);
+    assert_eq!(
+        object::FileKind::parse(&*data).unwrap(),
+        object::FileKind::Aout
+    );
+    let file = AoutFile::<&[u8]>::parse(&*data).unwrap();
+    assert!(!file.is_little_endian());
+    let text = file.section_by_name(".text").unwrap();
+    assert_eq!(text.data(), Ok(&[0x4e, 0x75, 0x4e, 0x71][..]));
+}
@@ -0,0 +1,225 @@
+use object::read::bitcode::BitcodeFile;
+use object::{FileKind, SymbolScope};
+
+#[derive(Default)]
+struct BitWriter {
+    data: Vec<u8>,
+    pos: usize,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u64, width: u32) {
+        for i in 0..width {
+            if self.pos % 8 == 0 {
+                self.data.push(0);
+            }
+            let bit = ((value >> i) & 1) as u8;
+            *self.data.last_mut().unwrap() |= bit << (self.pos % 8);
+            self.pos += 1;
+        }
+    }
+
+    fn write_vbr(&mut self, mut value: u64, width: u32) {
+        let high = 1 << (width - 1);
+        while value >= high {
+            self.write((value & (high - 1)) | high, width);
+            value >>= width - 1;
+        }
+        self.write(value, width);
+    }
+
+    fn align32(&mut self) {
+        while self.pos % 32 != 0 {
+            self.write(0, 1);
+        }
+    }
+
+    /// Write a block containing an abbreviation and a record with a blob.
+    fn write_blob_block(&mut self, block_id: u64, blob: &[u8]) {
+        // ENTER_SUBBLOCK with an abbreviation width of 3.
+        self.write(1, 2);
+        self.write_vbr(block_id, 8);
+        self.write_vbr(3, 4);
+        self.align32();
+        let len_offset = self.data.len();
+        self.write(0, 32);
+        let start = self.pos;
+
+        // DEFINE_ABBREV with a literal record code of 1 and a blob.
+        self.write(2, 3);
+        self.write_vbr(2, 5);
+        self.write(1, 1);
+        self.write_vbr(1, 8);
+        self.write(0, 1);
+        self.write(5, 3);
+
+        // The record using the abbreviation.
+        self.write(4, 3);
+        self.write_vbr(blob.len() as u64, 6);
+        self.align32();
+        for byte in blob {
+            self.write(u64::from(*byte), 8);
+        }
+        self.align32();
+
+        // END_BLOCK
+        self.write(0, 3);
+        self.align32();
+        let words = ((self.pos - start) / 32) as u32;
+        self.data[len_offset..][..4].copy_from_slice(&words.to_le_bytes());
+    }
+
+    /// Write a block containing an abbreviation and a record with an array.
+    ///
+    /// `element` writes the abbreviation operand for the array elements.
+    fn write_array_block(&mut self, block_id: u64, element: impl Fn(&mut Self), count: u64) {
+        // ENTER_SUBBLOCK with an abbreviation width of 3.
+        self.write(1, 2);
+        self.write_vbr(block_id, 8);
+        self.write_vbr(3, 4);
+        self.align32();
+        let len_offset = self.data.len();
+        self.write(0, 32);
+        let start = self.pos;
+
+        // DEFINE_ABBREV with a literal record code of 1 and an array.
+        self.write(2, 3);
+        self.write_vbr(3, 5);
+        self.write(1, 1);
+        self.write_vbr(1, 8);
+        self.write(0, 1);
+        self.write(3, 3);
+        element(self);
+
+        // The record using the abbreviation, with no element data.
+        self.write(4, 3);
+        self.write_vbr(count, 6);
+
+        // END_BLOCK
+        self.write(0, 3);
+        self.align32();
+        let words = ((self.pos - start) / 32) as u32;
+        self.data[len_offset..][..4].copy_from_slice(&words.to_le_bytes());
+    }
+}
+
+fn bitcode() -> Vec<u8> {
+    let strtab = b"x86_64-unknown-linux-gnua.cfoobarbaz";
+    let str_ref = |symtab: &mut Vec<u8>, offset: u32, size: u32| {
+        symtab.extend_from_slice(&offset.to_le_bytes());
+        symtab.extend_from_slice(&size.to_le_bytes());
+    };
+
+    let mut symtab = Vec::new();
+    // Version and producer.
+    symtab.extend_from_slice(&3u32.to_le_bytes());
+    str_ref(&mut symtab, 0, 0);
+    // Modules, comdats, symbols and uncommons.
+    str_ref(&mut symtab, 0, 0);
+    str_ref(&mut symtab, 0, 0);
+    str_ref(&mut symtab, 76, 3);
+    str_ref(&mut symtab, 0, 0);
+    // Target triple, source file name, linker options and dependent libraries.
+    str_ref(&mut symtab, 0, 24);
+    str_ref(&mut symtab, 24, 3);
+    str_ref(&mut symtab, 0, 0);
+    str_ref(&mut symtab, 0, 0);
+    assert_eq!(symtab.len(), 76);
+    // Symbols: name, IR name, comdat index and flags.
+    for (offset, flags) in [
+        // Defined global function.
+        (27, (1 << 10) | (1 << 13)),
+        // Undefined global.
+        (30, (1 << 10) | (1 << 3)),
+        // Defined local.
+        (33, 0),
+    ] {
+        str_ref(&mut symtab, offset, 3);
+        str_ref(&mut symtab, offset, 3);
+        symtab.extend_from_slice(&u32::MAX.to_le_bytes());
+        symtab.extend_from_slice(&(flags as u32).to_le_bytes());
+    }
+
+    let mut writer = BitWriter::default();
+    for byte in b"BC\xc0\xde" {
+        writer.write(u64::from(*byte), 8);
+    }
+    // An empty module block, which is skipped.
+    writer.write_blob_block(8, &[]);
+    writer.write_blob_block(25, &symtab);
+    writer.write_blob_block(23, strtab);
+    writer.data
+}
+
+#[test]
+fn bitcode_symbols() {
+    let data = bitcode();
+    assert_eq!(FileKind::parse(&*data).unwrap(), FileKind::LlvmBitcode);
+
+    let file = BitcodeFile::parse(&*data).unwrap();
+    assert_eq!(file.target_triple().unwrap(), b"x86_64-unknown-linux-gnu");
+    assert_eq!(file.source_filename().unwrap(), b"a.c");
+
+    let symbols = file
+        .symbols()
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(symbols.len(), 3);
+    assert_eq!(symbols[0].name(), b"foo");
+    assert!(symbols[0].is_executable());
+    assert!(!symbols[0].is_undefined());
+    assert_eq!(symbols[0].scope(), SymbolScope::Dynamic);
+    assert_eq!(symbols[1].name(), b"bar");
+    assert!(symbols[1].is_undefined());
+    assert_eq!(symbols[2].name(), b"baz");
+    assert_eq!(symbols[2].scope(), SymbolScope::Compilation);
+
+    #[cfg(feature = "write_core")]
+    {
+        let mut names = Vec::new();
+        object::write::archive::visit_symbols(&data, |name| names.push(name.to_vec())).unwrap();
+        assert_eq!(names, [b"foo"]);
+    }
+}
+
+#[test]
+fn bitcode_missing_symtab() {
+    let mut writer = BitWriter::default();
+    for byte in b"BC\xc0\xde" {
+        writer.write(u64::from(*byte), 8);
+    }
+    writer.write_blob_block(8, &[]);
+    assert!(BitcodeFile::parse(&*writer.data).is_err());
+}
+
+#[test]
+fn bitcode_array_length() {
+    let literal = |writer: &mut BitWriter| {
+        writer.write(1, 1);
+        writer.write_vbr(0, 8);
+    };
+    let fixed0 = |writer: &mut BitWriter| {
+        writer.write(0, 1);
+        writer.write(1, 3);
+        writer.write_vbr(0, 5);
+    };
+    let fixed8 = |writer: &mut BitWriter| {
+        writer.write(0, 1);
+        writer.write(1, 3);
+        writer.write_vbr(8, 5);
+    };
+    // Elements that read no bits, and a count that exceeds the data.
+    for (element, count) in [
+        (&literal as &dyn Fn(&mut BitWriter), u64::MAX),
+        (&fixed0, u64::MAX),
+        (&fixed8, u64::MAX),
+    ] {
+        let mut writer = BitWriter::default();
+        for byte in b"BC\xc0\xde" {
+            writer.write(u64::from(*byte), 8);
+        }
+        writer.write_array_block(25, element, count);
+        assert!(BitcodeFile::parse(&*writer.data).is_err());
+    }
+}
@@ -21,3 +21,52 @@ fn coff_extended_relocations() {
     let relocations = code_section.relocations().collect::<Vec<_>>();
     assert_eq!(relocations.len(), 65536);
 }
+
+#[cfg(all(feature = "coff", feature = "write"))]
+#[test]
+fn coff_line_numbers() {
+    use object::read::coff::CoffLineNumber;
+    use object::write;
+    use object::{Architecture, BinaryFormat, Endianness, SectionKind, SymbolIndex};
+
+    let mut object =
+        write::Object::new(BinaryFormat::Coff, Architecture::X86_64, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0x90; 16], 1);
+    let mut data = object.write().unwrap();
+
+    // Append a line number table and point the .text section header at it.
+    let pointer = data.len() as u32;
+    for (value, line) in [(1u32, 0u16), (0, 1), (4, 3), (8, 7)] {
+        data.extend_from_slice(&value.to_le_bytes());
+        data.extend_from_slice(&line.to_le_bytes());
+    }
+    let header = 20;
+    data[header + 28..][..4].copy_from_slice(&pointer.to_le_bytes());
+    data[header + 34..][..2].copy_from_slice(&4u16.to_le_bytes());
+
+    let file = read::coff::CoffFile::<_>::parse(&data[..]).unwrap();
+    let section = file.section_by_name(".text").unwrap();
+    assert_eq!(section.kind(), SectionKind::Text);
+    let line_numbers = section.line_numbers().unwrap().collect::<Vec<_>>();
+    assert_eq!(
+        line_numbers,
+        [
+            CoffLineNumber::Function {
+                symbol: SymbolIndex(1)
+            },
+            CoffLineNumber::Line {
+                address: 0,
+                line: 1
+            },
+            CoffLineNumber::Line {
+                address: 4,
+                line: 3
+            },
+            CoffLineNumber::Line {
+                address: 8,
+                line: 7
+            },
+        ]
+    );
+}
@@ -0,0 +1,96 @@
+use object::FileKind;
+
+fn kind(magic: &[u8]) -> Option<FileKind> {
+    let mut data = magic.to_vec();
+    data.resize(0x400, 0);
+    FileKind::parse(&*data).ok()
+}
+
+#[test]
+fn file_kind_magic() {
+    assert_eq!(kind(b"BC\xc0\xde"), Some(FileKind::LlvmBitcode));
+    assert_eq!(kind(b"\xde\xc0\x17\x0b"), Some(FileKind::LlvmBitcode));
+    assert_eq!(kind(b"\x1f\x8b\x08"), Some(FileKind::Gzip));
+    assert_eq!(kind(b"\xfd7zXZ\0"), Some(FileKind::Xz));
+    assert_eq!(kind(b"\x28\xb5\x2f\xfd"), Some(FileKind::Zstandard));
+    #[cfg(feature = "aout")]
+    {
+        assert_eq!(kind(b"\x07\x01\x64\0"), Some(FileKind::Aout));
+        assert_eq!(kind(b"\0\x02\x01\x0b"), Some(FileKind::Aout));
+    }
+    #[cfg(feature = "ihex")]
+    assert_eq!(kind(b":020000040800F2\n"), Some(FileKind::Ihex));
+    #[cfg(feature = "omf")]
+    {
+        assert_eq!(kind(b"\x80\x09\0\x07hello.c"), Some(FileKind::Omf));
+        assert_eq!(
+            kind(b"\xf0\x0d\0\0\x02\0\0\x01\0"),
+            Some(FileKind::OmfLibrary)
+        );
+    }
+    #[cfg(feature = "srec")]
+    assert_eq!(kind(b"S00600004844521B\n"), Some(FileKind::Srec));
+    #[cfg(feature = "pe")]
+    assert_eq!(kind(b"VZ\x64\x86"), Some(FileKind::Te));
+    #[cfg(feature = "wasm")]
+    {
+        assert_eq!(kind(b"\0asm\x01\0\0\0"), Some(FileKind::Wasm));
+        assert_eq!(kind(b"\0asm\x0d\0\x01\0"), Some(FileKind::WasmComponent));
+    }
+    assert_eq!(kind(b"\0\0\0\0"), None);
+}
+
+#[test]
+fn file_kind_short() {
+    assert!(FileKind::parse(&b"\x7fELF\x02"[..]).is_err());
+    #[cfg(feature = "omf")]
+    {
+        // The dictionary extends past the end of the file.
+        let mut data = b"\xf0\x0d\0\0\x02\0\0\x01\0".to_vec();
+        data.resize(0x3ff, 0);
+        assert!(FileKind::parse(&*data).is_err());
+    }
+    #[cfg(feature = "aout")]
+    {
+        // The text extends past the end of the file.
+        assert!(FileKind::parse(&b"\x07\x01\x64\0\0\x04"[..]).is_err());
+        let mut data = b"\x07\x01\x64\0\0\x04".to_vec();
+        data.resize(0x420, 0);
+        assert_eq!(FileKind::parse(&*data).ok(), Some(FileKind::Aout));
+        data.truncate(0x41f);
+        assert!(FileKind::parse(&*data).is_err());
+        // ZMAGIC text starts at 0x400.
+        assert!(FileKind::parse(&b"\0\x02\x01\x0b"[..]).is_err());
+        // The symbol table size is not a multiple of the symbol size.
+        let mut data = b"\x07\x01\x64\0".to_vec();
+        data.resize(16, 0);
+        data.push(5);
+        data.resize(0x400, 0);
+        assert!(FileKind::parse(&*data).is_err());
+    }
+    #[cfg(feature = "ihex")]
+    assert_eq!(
+        FileKind::parse(&b":00000001FF\n"[..]).ok(),
+        Some(FileKind::Ihex)
+    );
+    #[cfg(feature = "srec")]
+    assert_eq!(
+        FileKind::parse(&b"S9030000FC\n"[..]).ok(),
+        Some(FileKind::Srec)
+    );
+}
+
+#[cfg(feature = "macho")]
+#[test]
+fn file_kind_dyld_subcache() {
+    let mut data = b"dyld_v1  arm64e\0".to_vec();
+    data.resize(0x400, 0);
+    // mapping_offset
+    data[0x10..0x14].copy_from_slice(&0x200u32.to_le_bytes());
+    assert_eq!(FileKind::parse(&*data).ok(), Some(FileKind::DyldSubCache));
+
+    // images_across_all_subcaches_offset and images_across_all_subcaches_count
+    data[0x1c0..0x1c4].copy_from_slice(&0x200u32.to_le_bytes());
+    data[0x1c4..0x1c8].copy_from_slice(&1u32.to_le_bytes());
+    assert_eq!(FileKind::parse(&*data).ok(), Some(FileKind::DyldCache));
+}
@@ -0,0 +1,41 @@
+use object::read::ihex::IhexImage;
+use object::read::{Object, ObjectSegment};
+
+#[test]
+fn ihex_segments() {
+    let data = b"\
+:020000040800F2\r
+:0400000001020304F2\r
+:0400040005060708DE\r
+:020000040801F1\r
+:02000000AABB99\r
+:0400000508000101ED\r
+:00000001FF\r
+";
+    let image = IhexImage::parse(data).unwrap();
+    let file = image.file();
+    assert_eq!(file.entry(), 0x0800_0101);
+
+    let segments: Vec<_> = file.segments().collect();
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments[0].address(), 0x0800_0000);
+    assert_eq!(segments[0].data().unwrap(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(
+        segments[0].data_range(0x0800_0002, 3).unwrap(),
+        Some(&[3, 4, 5][..])
+    );
+    assert_eq!(segments[1].address(), 0x0801_0000);
+    assert_eq!(segments[1].data().unwrap(), &[0xaa, 0xbb]);
+    assert_eq!(file.sections().count(), 0);
+    assert_eq!(file.symbols().count(), 0);
+}
+
+#[test]
+fn ihex_errors() {
+    // Bad checksum.
+    assert!(IhexImage::parse(b":0400000001020304F3\n:00000001FF\n").is_err());
+    // Missing end of file record.
+    assert!(IhexImage::parse(b":0400000001020304F2\n").is_err());
+    // Overlapping data.
+    assert!(IhexImage::parse(b":0400000001020304F2\n:010002009964\n:00000001FF\n").is_err());
+}
@@ -0,0 +1,712 @@
+use object::read::ImportKind;
+use object::read::ObjectSegment;
+use object::{macho, Object, RelocationKind, RelocationTarget, SymbolKind};
+
+fn push_u32(data: &mut Vec<u8>, value: u32) {
+    data.extend_from_slice(&value.to_le_bytes());
+}
+
+fn push_u64(data: &mut Vec<u8>, value: u64) {
+    data.extend_from_slice(&value.to_le_bytes());
+}
+
+fn push_segment(data: &mut Vec<u8>, name: &[u8], vmaddr: u64, fileoff: u64, filesize: u64) {
+    push_u32(data, macho::LC_SEGMENT_64);
+    push_u32(data, 72);
+    let mut segname = [0; 16];
+    segname[..name.len()].copy_from_slice(name);
+    data.extend_from_slice(&segname);
+    push_u64(data, vmaddr);
+    push_u64(data, 0x4000);
+    push_u64(data, fileoff);
+    push_u64(data, filesize);
+    push_u32(data, 3);
+    push_u32(data, 3);
+    push_u32(data, 0);
+    push_u32(data, 0);
+}
+
+/// Build a Mach-O file with `__TEXT` and `__DATA` segments, and a final load command.
+///
+/// The `__DATA` segment is at file offset 0x100, and `linkedit` is at file offset 0x200.
+fn macho_file(command: &[u8], data: &[u8], linkedit: &[u8]) -> Vec<u8> {
+    let mut file = Vec::new();
+    push_u32(&mut file, macho::MH_MAGIC_64);
+    push_u32(&mut file, macho::CPU_TYPE_X86_64);
+    push_u32(&mut file, macho::CPU_SUBTYPE_X86_64_ALL);
+    push_u32(&mut file, macho::MH_EXECUTE);
+    push_u32(&mut file, 3);
+    push_u32(&mut file, 72 * 2 + command.len() as u32);
+    push_u32(&mut file, 0);
+    push_u32(&mut file, 0);
+    push_segment(&mut file, b"__TEXT", 0x1_0000_0000, 0, 0x100);
+    push_segment(&mut file, b"__DATA", 0x1_0000_4000, 0x100, 0x100);
+    file.extend_from_slice(command);
+    file.resize(0x100, 0);
+    file.extend_from_slice(data);
+    file.resize(0x200, 0);
+    file.extend_from_slice(linkedit);
+    file
+}
+
+#[test]
+fn macho_rebase_opcodes() {
+    let opcodes = [
+        macho::REBASE_OPCODE_SET_TYPE_IMM | macho::REBASE_TYPE_POINTER,
+        macho::REBASE_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1,
+        0x10,
+        macho::REBASE_OPCODE_DO_REBASE_IMM_TIMES | 2,
+        macho::REBASE_OPCODE_DO_REBASE_ADD_ADDR_ULEB,
+        0x8,
+        macho::REBASE_OPCODE_DO_REBASE_ULEB_TIMES_SKIPPING_ULEB,
+        2,
+        0,
+        macho::REBASE_OPCODE_DONE,
+    ];
+    let mut command = Vec::new();
+    push_u32(&mut command, macho::LC_DYLD_INFO_ONLY);
+    push_u32(&mut command, 48);
+    push_u32(&mut command, 0x200);
+    push_u32(&mut command, opcodes.len() as u32);
+    command.resize(48, 0);
+    let data = macho_file(&command, &[], &opcodes);
+
+    let file = object::File::parse(&*data).unwrap();
+    let relocations: Vec<_> = file.dynamic_relocations().unwrap().collect();
+    let addresses: Vec<_> = relocations.iter().map(|(address, _)| *address).collect();
+    assert_eq!(
+        addresses,
+        [
+            0x1_0000_4010,
+            0x1_0000_4018,
+            0x1_0000_4020,
+            0x1_0000_4030,
+            0x1_0000_4038
+        ]
+    );
+    for (_, relocation) in relocations {
+        assert_eq!(relocation.kind(), RelocationKind::Rebase);
+        assert_eq!(relocation.size(), 64);
+        assert_eq!(relocation.target(), RelocationTarget::Absolute);
+        assert!(relocation.has_implicit_addend());
+    }
+}
+
+#[test]
+fn macho_chained_fixups() {
+    let mut fixups = Vec::new();
+    // DyldChainedFixupsHeader
+    push_u32(&mut fixups, 0);
+    push_u32(&mut fixups, 28);
+    fixups.resize(28, 0);
+    // DyldChainedStartsInImage
+    push_u32(&mut fixups, 2);
+    push_u32(&mut fixups, 0);
+    push_u32(&mut fixups, 12);
+    // DyldChainedStartsInSegment
+    push_u32(&mut fixups, 24);
+    fixups.extend_from_slice(&0x4000u16.to_le_bytes());
+    fixups.extend_from_slice(&macho::DYLD_CHAINED_PTR_64_OFFSET.to_le_bytes());
+    push_u64(&mut fixups, 0x4000);
+    push_u32(&mut fixups, 0);
+    fixups.extend_from_slice(&1u16.to_le_bytes());
+    fixups.extend_from_slice(&8u16.to_le_bytes());
+
+    let mut chain = vec![0; 8];
+    // Rebase to 0x3f00, next is 8 bytes later.
+    push_u64(&mut chain, 0x3f00 | (2 << 51));
+    // Bind, next is 8 bytes later.
+    push_u64(&mut chain, (1 << 63) | (2 << 51));
+    // Rebase to 0x10 with a high byte, end of chain.
+    push_u64(&mut chain, 0x10 | (0x12 << 36));
+
+    let mut command = Vec::new();
+    push_u32(&mut command, macho::LC_DYLD_CHAINED_FIXUPS);
+    push_u32(&mut command, 16);
+    push_u32(&mut command, 0x200);
+    push_u32(&mut command, fixups.len() as u32);
+    let data = macho_file(&command, &chain, &fixups);
+
+    let file = object::File::parse(&*data).unwrap();
+    let relocations: Vec<_> = file
+        .dynamic_relocations()
+        .unwrap()
+        .map(|(address, relocation)| (address, relocation.size(), relocation.addend()))
+        .collect();
+    assert_eq!(
+        relocations,
+        [
+            (0x1_0000_4008, 64, 0x3f00),
+            (0x1_0000_4018, 64, 0x1200_0000_0000_0010),
+        ]
+    );
+}
+
+#[test]
+fn macho_entry() {
+    let mut command = Vec::new();
+    push_u32(&mut command, macho::LC_MAIN);
+    push_u32(&mut command, 24);
+    push_u64(&mut command, 0x80);
+    push_u64(&mut command, 0);
+    let data = macho_file(&command, &[], &[]);
+
+    let file = object::File::parse(&*data).unwrap();
+    assert_eq!(file.entry(), 0x1_0000_0080);
+    assert!(!file.is_position_independent());
+    assert!(file.is_stripped());
+}
+
+#[test]
+fn macho_code_signature() {
+    let signature = [0xfa, 0xde, 0x0c, 0xc0, 0, 0, 0, 12, 0, 0, 0, 0];
+    let mut command = Vec::new();
+    push_u32(&mut command, macho::LC_CODE_SIGNATURE);
+    push_u32(&mut command, 16);
+    push_u32(&mut command, 0x200);
+    push_u32(&mut command, signature.len() as u32);
+    let data = macho_file(&command, &[], &signature);
+
+    let file = object::File::parse(&*data).unwrap();
+    let code_signature = file.code_signature().unwrap().unwrap();
+    assert_eq!(
+        code_signature,
+        object::read::CodeSignature::MachO(&signature)
+    );
+    assert_eq!(code_signature.data(), &signature);
+
+    let mut command = Vec::new();
+    push_u32(&mut command, macho::LC_MAIN);
+    push_u32(&mut command, 24);
+    command.resize(24, 0);
+    let data = macho_file(&command, &[], &[]);
+    let file = object::File::parse(&*data).unwrap();
+    assert_eq!(file.code_signature(), Ok(None));
+}
+
+#[test]
+fn macho_entitlements() {
+    let plist = b"<plist><dict/></plist>";
+    let mut signature = Vec::new();
+    for value in [
+        macho::CSMAGIC_EMBEDDED_SIGNATURE,
+        44 + plist.len() as u32,
+        2,
+        macho::CSSLOT_CODEDIRECTORY,
+        28,
+        macho::CSSLOT_ENTITLEMENTS,
+        36,
+        macho::CSMAGIC_CODEDIRECTORY,
+        8,
+        macho::CSMAGIC_EMBEDDED_ENTITLEMENTS,
+        8 + plist.len() as u32,
+    ] {
+        signature.extend_from_slice(&value.to_be_bytes());
+    }
+    signature.extend_from_slice(plist);
+    let mut command = Vec::new();
+    push_u32(&mut command, macho::LC_CODE_SIGNATURE);
+    push_u32(&mut command, 16);
+    push_u32(&mut command, 0x200);
+    push_u32(&mut command, signature.len() as u32);
+    let data = macho_file(&command, &[], &signature);
+
+    let file = object::read::macho::MachOFile64::<object::Endianness>::parse(&*data).unwrap();
+    assert_eq!(file.entitlements(), Ok(Some((0x200 + 44, &plist[..]))));
+    assert_eq!(file.info_plist(), Ok(None));
+
+    // A signature without entitlements.
+    signature[8..12].copy_from_slice(&1u32.to_be_bytes());
+    let data = macho_file(&command, &[], &signature);
+    let file = object::read::macho::MachOFile64::<object::Endianness>::parse(&*data).unwrap();
+    assert_eq!(file.entitlements(), Ok(None));
+
+    // A blob with the wrong magic.
+    signature[8..12].copy_from_slice(&2u32.to_be_bytes());
+    signature[36..40].copy_from_slice(&macho::CSMAGIC_REQUIREMENTS.to_be_bytes());
+    let data = macho_file(&command, &[], &signature);
+    let file = object::read::macho::MachOFile64::<object::Endianness>::parse(&*data).unwrap();
+    assert!(file.entitlements().is_err());
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn macho_info_plist() {
+    use object::write;
+    use object::{Architecture, BinaryFormat, Endianness, SectionKind};
+
+    let plist = b"<plist><dict/></plist>";
+    let mut object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+    let text = object.add_section(b"__TEXT".to_vec(), b"__text".to_vec(), SectionKind::Text);
+    object.append_section_data(text, &[0xc3], 1);
+    let section = object.add_section(
+        b"__TEXT".to_vec(),
+        b"__info_plist".to_vec(),
+        SectionKind::ReadOnlyData,
+    );
+    object.append_section_data(section, plist, 1);
+    let data = object.write().unwrap();
+
+    let file = object::read::macho::MachOFile64::<Endianness>::parse(&*data).unwrap();
+    let (offset, contents) = file.info_plist().unwrap().unwrap();
+    assert_eq!(contents, plist);
+    assert_eq!(&data[offset as usize..][..plist.len()], plist);
+    assert_eq!(file.entitlements(), Ok(None));
+}
+
+#[test]
+fn macho_segment_permissions() {
+    let mut command = Vec::new();
+    push_u32(&mut command, macho::LC_MAIN);
+    push_u32(&mut command, 24);
+    command.resize(24, 0);
+    let data = macho_file(&command, &[], &[]);
+
+    let file = object::File::parse(&*data).unwrap();
+    let segment = file.segments().next().unwrap();
+    assert_eq!(segment.kind(), object::SegmentKind::Load);
+    assert_eq!(
+        segment.permissions(),
+        object::Permissions::new(true, true, false)
+    );
+    assert_eq!(segment.align(), 0x1000);
+    assert_eq!(file.all_segments().count(), 2);
+}
+
+#[test]
+fn macho_function_starts() {
+    let starts = [0x10, 0x20, 0x30, 0];
+    let mut command = Vec::new();
+    push_u32(&mut command, macho::LC_FUNCTION_STARTS);
+    push_u32(&mut command, 16);
+    push_u32(&mut command, 0x200);
+    push_u32(&mut command, starts.len() as u32);
+    let data = macho_file(&command, &[], &starts);
+
+    let file = object::File::parse(&*data).unwrap();
+    let ranges: Vec<_> = file
+        .function_ranges()
+        .unwrap()
+        .iter()
+        .map(|range| (range.address(), range.size(), range.is_size_inferred()))
+        .collect();
+    assert_eq!(
+        ranges,
+        [
+            (0x1_0000_0010, 0x20, true),
+            (0x1_0000_0030, 0x30, true),
+            (0x1_0000_0060, 0, false),
+        ]
+    );
+}
+
+/// Create a file that imports `_puts`, `_exit` and `_weak`, with the given
+/// lazy binding opcodes.
+fn macho_imports_file(lazy_bind: &[u8]) -> Vec<u8> {
+    let mut file = Vec::new();
+    push_u32(&mut file, macho::MH_MAGIC_64);
+    push_u32(&mut file, macho::CPU_TYPE_X86_64);
+    push_u32(&mut file, macho::CPU_SUBTYPE_X86_64_ALL);
+    push_u32(&mut file, macho::MH_EXECUTE);
+    push_u32(&mut file, 3);
+    push_u32(&mut file, 48 + 24 + 80);
+    push_u32(&mut file, 0);
+    push_u32(&mut file, 0);
+
+    let strings = b"\0_puts\0_exit\0_weak\0";
+
+    // The lazy binding opcodes are at 0x200, the symbols at 0x210,
+    // and the strings at 0x240.
+    push_u32(&mut file, macho::LC_DYLD_INFO_ONLY);
+    push_u32(&mut file, 48);
+    file.resize(file.len() + 24, 0);
+    push_u32(&mut file, 0x200);
+    push_u32(&mut file, lazy_bind.len() as u32);
+    file.resize(file.len() + 8, 0);
+    push_u32(&mut file, macho::LC_SYMTAB);
+    push_u32(&mut file, 24);
+    push_u32(&mut file, 0x210);
+    push_u32(&mut file, 3);
+    push_u32(&mut file, 0x240);
+    push_u32(&mut file, strings.len() as u32);
+    push_u32(&mut file, macho::LC_DYSYMTAB);
+    push_u32(&mut file, 80);
+    file.resize(file.len() + 16, 0);
+    push_u32(&mut file, 0);
+    push_u32(&mut file, 3);
+    file.resize(file.len() + 48, 0);
+
+    file.resize(0x200, 0);
+    file.extend_from_slice(lazy_bind);
+    file.resize(0x210, 0);
+    for (strx, n_desc) in [(1, 0), (7, 0), (13, macho::N_WEAK_REF)] {
+        push_u32(&mut file, strx);
+        file.push(macho::N_EXT);
+        file.push(0);
+        file.extend_from_slice(&n_desc.to_le_bytes());
+        push_u64(&mut file, 0);
+    }
+    file.extend_from_slice(strings);
+    file
+}
+
+fn macho_import_kinds(file: &[u8]) -> Vec<(&[u8], ImportKind)> {
+    let file = object::File::parse(file).unwrap();
+    file.imports()
+        .unwrap()
+        .iter()
+        .map(|import| (import.name(), import.kind()))
+        .collect()
+}
+
+fn lazy_bind_puts() -> Vec<u8> {
+    let mut lazy_bind = vec![
+        macho::BIND_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | 1,
+        0,
+        macho::BIND_OPCODE_SET_DYLIB_ORDINAL_IMM | 1,
+        macho::BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM,
+    ];
+    lazy_bind.extend_from_slice(b"_puts\0");
+    lazy_bind.push(macho::BIND_OPCODE_DO_BIND);
+    lazy_bind.push(macho::BIND_OPCODE_DONE);
+    lazy_bind
+}
+
+#[test]
+fn macho_lazy_imports() {
+    let file = macho_imports_file(&lazy_bind_puts());
+    assert_eq!(
+        macho_import_kinds(&file),
+        [
+            (&b"_puts"[..], ImportKind::Lazy),
+            (&b"_exit"[..], ImportKind::Eager),
+            (&b"_weak"[..], ImportKind::Weak),
+        ]
+    );
+}
+
+#[test]
+fn macho_lazy_imports_invalid_opcode() {
+    // Decoding stops at the invalid opcode, so `_exit` is not lazy.
+    let mut lazy_bind = lazy_bind_puts();
+    lazy_bind.push(0xe0);
+    lazy_bind.push(macho::BIND_OPCODE_SET_SYMBOL_TRAILING_FLAGS_IMM);
+    lazy_bind.extend_from_slice(b"_exit\0");
+    lazy_bind.push(macho::BIND_OPCODE_DO_BIND);
+    lazy_bind.push(macho::BIND_OPCODE_DONE);
+    let file = macho_imports_file(&lazy_bind);
+    assert_eq!(
+        macho_import_kinds(&file),
+        [
+            (&b"_puts"[..], ImportKind::Lazy),
+            (&b"_exit"[..], ImportKind::Eager),
+            (&b"_weak"[..], ImportKind::Weak),
+        ]
+    );
+}
+
+#[test]
+fn macho_address_to_file_offset() {
+    let mut command = Vec::new();
+    push_u32(&mut command, macho::LC_MAIN);
+    push_u32(&mut command, 24);
+    push_u64(&mut command, 0x80);
+    push_u64(&mut command, 0);
+    let data = macho_file(&command, &[], &[]);
+
+    let file = object::File::parse(&*data).unwrap();
+    assert_eq!(file.address_to_file_offset(0x1_0000_0010), Some(0x10));
+    assert_eq!(file.address_to_file_offset(0x1_0000_4020), Some(0x120));
+    // Beyond the file data of the segment.
+    assert_eq!(file.address_to_file_offset(0x1_0000_4100), None);
+    assert_eq!(file.address_to_file_offset(0x2_0000_0000), None);
+
+    assert_eq!(file.file_offset_to_address(0x10), Some(0x1_0000_0010));
+    assert_eq!(file.file_offset_to_address(0x120), Some(0x1_0000_4020));
+    assert_eq!(file.file_offset_to_address(0x200), None);
+}
+
+#[test]
+fn macho_object_map() {
+    let symbols: &[(&[u8], u8, u8, u64)] = &[
+        (b"/src/", macho::N_SO, 0, 0),
+        (b"a.c", macho::N_SO, 0, 0),
+        (b"/build/libfoo.a(a.o)", macho::N_OSO, 0, 0x6500_0000),
+        (b"", macho::N_BNSYM, 1, 0x1_0000_0010),
+        (b"_f", macho::N_FUN, 1, 0x1_0000_0010),
+        (b"", macho::N_FUN, 0, 0x20),
+        (b"", macho::N_ENSYM, 1, 0x20),
+        (b"_s", macho::N_STSYM, 2, 0x1_0000_4010),
+        (b"_g", macho::N_GSYM, 0, 0),
+        (b"", macho::N_SO, 1, 0),
+        (b"b.c", macho::N_SO, 0, 0),
+        (b"/build/b.o", macho::N_OSO, 0, 0x6600_0000),
+        (b"_h", macho::N_FUN, 1, 0x1_0000_0040),
+        (b"", macho::N_FUN, 0, 0x8),
+        (b"", macho::N_SO, 1, 0),
+        (b"_g", macho::N_SECT | macho::N_EXT, 2, 0x1_0000_4020),
+    ];
+    let mut linkedit = Vec::new();
+    let mut strings = vec![b' ', 0];
+    for (name, n_type, n_sect, n_value) in symbols {
+        if name.is_empty() {
+            // The string table starts with " \0", so this is an empty string.
+            push_u32(&mut linkedit, 1);
+        } else {
+            push_u32(&mut linkedit, strings.len() as u32);
+            strings.extend_from_slice(name);
+            strings.push(0);
+        }
+        linkedit.push(*n_type);
+        linkedit.push(*n_sect);
+        linkedit.extend_from_slice(&[0, 0]);
+        push_u64(&mut linkedit, *n_value);
+    }
+    let stroff = 0x200 + linkedit.len() as u32;
+    linkedit.extend_from_slice(&strings);
+
+    let mut command = Vec::new();
+    push_u32(&mut command, macho::LC_SYMTAB);
+    push_u32(&mut command, 24);
+    push_u32(&mut command, 0x200);
+    push_u32(&mut command, symbols.len() as u32);
+    push_u32(&mut command, stroff);
+    push_u32(&mut command, strings.len() as u32);
+    let data = macho_file(&command, &[], &linkedit);
+
+    let file = object::File::parse(&*data).unwrap();
+    let map = file.object_map();
+
+    assert_eq!(
+        map.objects(),
+        [&b"/build/libfoo.a(a.o)"[..], &b"/build/b.o"[..]]
+    );
+    let objects = map.object_files();
+    assert_eq!(objects.len(), 2);
+    assert_eq!(objects[0].path(), b"/build/libfoo.a");
+    assert_eq!(objects[0].member(), Some(&b"a.o"[..]));
+    assert_eq!(objects[0].timestamp(), 0x6500_0000);
+    assert_eq!(objects[0].source_directory(), b"/src/");
+    assert_eq!(objects[0].source_name(), b"a.c");
+    assert_eq!(objects[1].path(), b"/build/b.o");
+    assert_eq!(objects[1].member(), None);
+    assert_eq!(objects[1].timestamp(), 0x6600_0000);
+    assert_eq!(objects[1].source_directory(), b"");
+    assert_eq!(objects[1].source_name(), b"b.c");
+
+    let symbols: Vec<_> = map
+        .symbols()
+        .iter()
+        .map(|s| (s.name(), s.address(), s.size(), s.kind(), s.object_index()))
+        .collect();
+    assert_eq!(
+        symbols,
+        [
+            (&b"_f"[..], 0x1_0000_0010, 0x20, SymbolKind::Text, 0),
+            (&b"_h"[..], 0x1_0000_0040, 0x8, SymbolKind::Text, 1),
+            (&b"_s"[..], 0x1_0000_4010, 0, SymbolKind::Data, 0),
+            (&b"_g"[..], 0x1_0000_4020, 0, SymbolKind::Data, 0),
+        ]
+    );
+
+    let entry = map.get(0x1_0000_0018).unwrap();
+    assert_eq!(entry.name(), b"_f");
+    assert_eq!(entry.object(&map), b"/build/libfoo.a(a.o)");
+    assert_eq!(entry.object_file(&map).member(), Some(&b"a.o"[..]));
+    assert!(map.get(0x1_0000_0030).is_none());
+
+    let files = file.source_files().unwrap();
+    let files: Vec<_> = files
+        .iter()
+        .map(|f| (f.directory(), f.name(), f.object()))
+        .collect();
+    assert_eq!(
+        files,
+        [
+            (
+                &b"/src/"[..],
+                &b"a.c"[..],
+                Some(&b"/build/libfoo.a(a.o)"[..])
+            ),
+            (&b""[..], &b"b.c"[..], Some(&b"/build/b.o"[..])),
+        ]
+    );
+}
+
+#[test]
+fn macho_notes() {
+    use object::read::macho::{
+        AddressingBits, LoadBinary, MachHeader, MachONotePayload, MainBinSpec,
+    };
+    use object::LittleEndian as LE;
+
+    // `padding` is added before the payload to check unaligned payloads.
+    fn check_note(owner: &[u8], padding: usize, payload: &[u8], expected: MachONotePayload<'_>) {
+        let mut command = Vec::new();
+        push_u32(&mut command, macho::LC_NOTE);
+        push_u32(&mut command, 40);
+        let mut data_owner = [0; 16];
+        data_owner[..owner.len()].copy_from_slice(owner);
+        command.extend_from_slice(&data_owner);
+        push_u64(&mut command, 0x100 + padding as u64);
+        push_u64(&mut command, payload.len() as u64);
+        let mut padded = vec![0; padding];
+        padded.extend_from_slice(payload);
+        let data = &*macho_file(&command, &padded, &[]);
+
+        let header = macho::MachHeader64::<LE>::parse(data, 0).unwrap();
+        let mut commands = header.load_commands(LE, data, 0).unwrap();
+        while let Some(command) = commands.next().unwrap() {
+            if let object::read::macho::LoadCommandVariant::Note(note) = command.variant().unwrap()
+            {
+                assert_eq!(note.owner(), owner);
+                assert_eq!(note.payload(LE, data).unwrap(), expected);
+                return;
+            }
+        }
+        panic!("missing LC_NOTE");
+    }
+
+    let mut payload = Vec::new();
+    push_u32(&mut payload, 2);
+    push_u32(&mut payload, macho::MAIN_BIN_SPEC_TYPE_KERNEL);
+    push_u64(&mut payload, 0xffff_fe00_0000_4000);
+    push_u64(&mut payload, u64::MAX);
+    payload.extend_from_slice(&[0x11; 16]);
+    push_u32(&mut payload, 14);
+    push_u32(&mut payload, 0);
+    for padding in [0, 4] {
+        check_note(
+            macho::NOTE_MAIN_BIN_SPEC,
+            padding,
+            &payload,
+            MachONotePayload::MainBinSpec(MainBinSpec {
+                version: 2,
+                bin_type: macho::MAIN_BIN_SPEC_TYPE_KERNEL,
+                address: Some(0xffff_fe00_0000_4000),
+                slide: None,
+                uuid: Some([0x11; 16]),
+                log2_page_size: Some(14),
+                platform: None,
+            }),
+        );
+    }
+
+    let mut payload = Vec::new();
+    push_u32(&mut payload, 1);
+    payload.extend_from_slice(&[0; 16]);
+    push_u64(&mut payload, 0x1_0000_0000);
+    push_u64(&mut payload, 0x4000);
+    payload.extend_from_slice(b"dyld\0");
+    check_note(
+        macho::NOTE_LOAD_BINARY,
+        0,
+        &payload,
+        MachONotePayload::LoadBinary(LoadBinary {
+            version: 1,
+            uuid: None,
+            load_address: Some(0x1_0000_0000),
+            slide: 0x4000,
+            name: Some(b"dyld"),
+        }),
+    );
+
+    let mut payload = Vec::new();
+    push_u32(&mut payload, 3);
+    push_u32(&mut payload, 47);
+    push_u32(&mut payload, 39);
+    push_u32(&mut payload, 0);
+    check_note(
+        macho::NOTE_ADDRABLE_BITS,
+        0,
+        &payload,
+        MachONotePayload::AddressingBits(AddressingBits {
+            version: 3,
+            low: Some(47),
+            high: Some(39),
+        }),
+    );
+
+    check_note(
+        b"kern ver str",
+        0,
+        b"\x01\0\0\0Darwin\0",
+        MachONotePayload::Unknown(b"\x01\0\0\0Darwin\0"),
+    );
+}
+
+#[test]
+fn macho_needed_libraries() {
+    let mut commands = Vec::new();
+    for (cmd, name) in [
+        (macho::LC_LOAD_DYLIB, &b"libc.dylib\0"[..]),
+        (macho::LC_LOAD_WEAK_DYLIB, b"libw.dylib\0"),
+    ] {
+        let cmdsize = (24 + name.len() + 7) & !7;
+        push_u32(&mut commands, cmd);
+        push_u32(&mut commands, cmdsize as u32);
+        push_u32(&mut commands, 24);
+        commands.resize(commands.len() + 12, 0);
+        commands.extend_from_slice(name);
+        commands.resize((commands.len() + 7) & !7, 0);
+    }
+    let mut file = macho_file(&commands, &[], &[]);
+    // Two dylib commands instead of one final command.
+    file[16..20].copy_from_slice(&4u32.to_le_bytes());
+
+    let file = object::File::parse(&*file).unwrap();
+    assert_eq!(
+        file.needed_libraries().unwrap(),
+        [&b"libc.dylib"[..], &b"libw.dylib"[..]]
+    );
+}
+
+#[test]
+fn macho_export_trie() {
+    #[rustfmt::skip]
+    let trie = [
+        // Root: no terminal, one child.
+        0, 1, b'_', 0, 5,
+        // "_": no terminal, two children.
+        0, 2, b'f', b'o', b'o', 0, 17, b'b', b'a', b'r', 0, 21,
+        // "_foo": regular symbol at 0x10.
+        2, 0, 0x10, 0,
+        // "_bar": re-export of "_baz" from library 1.
+        7, macho::EXPORT_SYMBOL_FLAGS_REEXPORT as u8, 1, b'_', b'b', b'a', b'z', 0, 0,
+    ];
+    let mut command = Vec::new();
+    push_u32(&mut command, macho::LC_DYLD_EXPORTS_TRIE);
+    push_u32(&mut command, 16);
+    push_u32(&mut command, 0x200);
+    push_u32(&mut command, trie.len() as u32);
+    let data = macho_file(&command, &[], &trie);
+
+    let file = object::read::macho::MachOFile64::<object::Endianness>::parse(&*data).unwrap();
+    let export_trie = file.export_trie().unwrap().unwrap();
+    let entries = export_trie.entries().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].name(), b"_foo");
+    assert_eq!(entries[0].flags(), 0);
+    assert_eq!(entries[0].address(), 0x10);
+    assert_eq!(entries[1].name(), b"_bar");
+    assert_eq!(entries[1].other(), 1);
+    assert_eq!(entries[1].import_name(), b"_baz");
+
+    let mut options = object::read::ParseOptions::default();
+    options.max_depth = 1;
+    assert_eq!(
+        export_trie
+            .entries_with_options(&options)
+            .unwrap_err()
+            .to_string(),
+        "Nesting depth exceeds parse limit"
+    );
+
+    // A child that refers back to the root.
+    let trie = object::read::macho::ExportTrie::new(&[0, 1, b'_', 0, 0]);
+    assert!(trie.entries().is_err());
+}
@@ -1,3 +1,23 @@
 #![cfg(feature = "read")]
 
+#[cfg(feature = "aout")]
+mod aout;
+#[cfg(feature = "bitcode")]
+mod bitcode;
 mod coff;
+mod file_kind;
+#[cfg(feature = "ihex")]
+mod ihex;
+#[cfg(feature = "macho")]
+mod macho;
+#[cfg(feature = "pe")]
+mod ne;
+#[cfg(feature = "omf")]
+mod omf;
+#[cfg(feature = "pe")]
+mod pe;
+#[cfg(feature = "srec")]
+mod srec;
+mod symbol_map;
+#[cfg(feature = "wasm")]
+mod wasm;
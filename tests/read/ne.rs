@@ -0,0 +1,139 @@
+use object::pe;
+use object::read::pe::{LxFile, NeFile};
+use object::FileKind;
+
+fn put_u16(data: &mut [u8], offset: usize, value: u16) {
+    data[offset..offset + 2].copy_from_slice(&value.to_le_bytes());
+}
+
+fn put_u32(data: &mut [u8], offset: usize, value: u32) {
+    data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+}
+
+// Create a DOS header with the new header at 0x40.
+fn dos_header(size: usize) -> Vec<u8> {
+    let mut data = vec![0; size];
+    data[..2].copy_from_slice(b"MZ");
+    put_u32(&mut data, 0x3c, 0x40);
+    data
+}
+
+#[test]
+fn ne_file() {
+    let mut data = dos_header(0x200);
+    let ne = 0x40;
+    data[ne..ne + 2].copy_from_slice(b"NE");
+    // Entry table: ordinal 1 in fixed segment 1, ordinal 2 unused,
+    // ordinal 3 in movable segment 2.
+    let entries = [
+        1, 1, 3, 0x10, 0, 1, 0, 1, 0xff, 1, 0xcd, 0x3f, 2, 0x20, 0, 0,
+    ];
+    put_u16(&mut data, ne + 4, 0x60);
+    put_u16(&mut data, ne + 6, entries.len() as u16);
+    data[ne + 0x60..ne + 0x60 + entries.len()].copy_from_slice(&entries);
+    // Segment table.
+    put_u16(&mut data, ne + 28, 2);
+    put_u16(&mut data, ne + 34, 0x40);
+    put_u16(&mut data, ne + 0x40, 0x12);
+    put_u16(&mut data, ne + 0x42, 4);
+    put_u16(&mut data, ne + 0x4a, 0);
+    put_u16(&mut data, ne + 0x4c, pe::NSDATA | pe::NSMOVE);
+    put_u16(&mut data, ne + 0x4e, 0x100);
+    put_u16(&mut data, ne + 50, 4);
+    data[0x120..0x124].copy_from_slice(&[0xcb, 0x90, 0x90, 0x90]);
+    // Resident name table.
+    let names = b"\x04TEST\x00\x00\x03FOO\x01\x00\x00";
+    put_u16(&mut data, ne + 38, 0x50);
+    put_u16(&mut data, ne + 40, 0x50 + names.len() as u16);
+    data[ne + 0x50..ne + 0x50 + names.len()].copy_from_slice(names);
+    // Non-resident name table.
+    let names = b"\x04desc\x00\x00\x03BAR\x03\x00\x00";
+    put_u16(&mut data, ne + 32, names.len() as u16);
+    put_u32(&mut data, ne + 44, 0x180);
+    data[0x180..0x180 + names.len()].copy_from_slice(names);
+
+    assert_eq!(FileKind::parse(&*data), Ok(FileKind::Ne));
+    let file = NeFile::<&[u8]>::parse(&*data).unwrap();
+    assert_eq!(file.module_name(), Ok(&b"TEST"[..]));
+
+    let segments = file.segments().unwrap();
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments[0].file_range(file.align_shift()), Some((0x120, 4)));
+    assert_eq!(
+        file.segment_data(&segments[0]),
+        Ok(&[0xcb, 0x90, 0x90, 0x90][..])
+    );
+    assert!(!segments[0].is_data());
+    assert_eq!(segments[1].file_range(file.align_shift()), None);
+    assert_eq!(segments[1].min_alloc(), 0x100);
+    assert!(segments[1].is_data());
+
+    let exports = file.exports().unwrap();
+    assert_eq!(exports.len(), 2);
+    assert_eq!(exports[0].ordinal, 1);
+    assert_eq!(exports[0].name, Some(&b"FOO"[..]));
+    assert_eq!(exports[0].segment, 1);
+    assert_eq!(exports[0].offset, 0x10);
+    assert_eq!(exports[1].ordinal, 3);
+    assert_eq!(exports[1].name, Some(&b"BAR"[..]));
+    assert_eq!(exports[1].segment, 2);
+    assert_eq!(exports[1].offset, 0x20);
+}
+
+#[test]
+fn lx_file() {
+    let mut data = dos_header(0x200);
+    let lx = 0x40;
+    data[lx..lx + 2].copy_from_slice(b"LX");
+    // Object table.
+    let objtab = 0xc4;
+    put_u32(&mut data, lx + 64, objtab as u32);
+    put_u32(&mut data, lx + 68, 2);
+    put_u32(&mut data, lx + objtab, 0x2000);
+    put_u32(&mut data, lx + objtab + 4, 0x1_0000);
+    put_u32(&mut data, lx + objtab + 8, pe::OBJREAD | pe::OBJEXEC);
+    put_u32(&mut data, lx + objtab + 24, 0x100);
+    put_u32(&mut data, lx + objtab + 28, 0x2_0000);
+    put_u32(&mut data, lx + objtab + 32, pe::OBJREAD | pe::OBJWRITE);
+    // Resident name table.
+    let restab = objtab + 48;
+    let names = b"\x04TEST\x00\x00\x04MAIN\x01\x00\x00";
+    put_u32(&mut data, lx + 88, restab as u32);
+    data[lx + restab..lx + restab + names.len()].copy_from_slice(names);
+    // Entry table: ordinal 1 is a 32-bit entry, ordinal 2 is a forwarder.
+    let enttab = restab + names.len();
+    let entries = [
+        1, 3, 1, 0, 1, 0, 0x10, 0, 0, 1, 4, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0,
+    ];
+    put_u32(&mut data, lx + 92, enttab as u32);
+    data[lx + enttab..lx + enttab + entries.len()].copy_from_slice(&entries);
+    put_u32(&mut data, lx + 56, (enttab + entries.len() - objtab) as u32);
+
+    assert_eq!(FileKind::parse(&*data), Ok(FileKind::Lx));
+    let file = LxFile::<&[u8]>::parse(&*data).unwrap();
+    assert!(!file.is_le());
+    assert_eq!(file.module_name(), Ok(&b"TEST"[..]));
+
+    let objects = file.objects().unwrap();
+    assert_eq!(objects.len(), 2);
+    assert_eq!(objects[0].o32_base.get(object::LittleEndian), 0x1_0000);
+    assert_eq!(
+        objects[1].o32_flags.get(object::LittleEndian),
+        pe::OBJREAD | pe::OBJWRITE
+    );
+
+    let mut entries = file.entries().unwrap();
+    let entry = entries.next().unwrap().unwrap();
+    assert_eq!(entry.kind, pe::ENTRY32);
+    assert_eq!((entry.ordinal, entry.object, entry.offset), (1, 1, 0x1000));
+    let entry = entries.next().unwrap().unwrap();
+    assert_eq!(entry.kind, pe::ENTRYFWD);
+    assert_eq!((entry.ordinal, entry.object, entry.offset), (2, 1, 5));
+    assert!(entries.next().unwrap().is_none());
+
+    let exports = file.exports().unwrap();
+    assert_eq!(exports.len(), 1);
+    assert_eq!(exports[0].name, Some(&b"MAIN"[..]));
+    assert_eq!(exports[0].object, 1);
+    assert_eq!(exports[0].offset, 0x1000);
+}
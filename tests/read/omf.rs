@@ -0,0 +1,224 @@
+use object::read::omf::{OmfImage, OmfLibrary};
+use object::read::{
+    Object, ObjectSection, ObjectSymbol, ParseOptions, RelocationTarget, SectionIndex, SymbolIndex,
+    SymbolSection,
+};
+use object::{
+    omf, Architecture, BinaryFormat, FileKind, ObjectKind, RelocationKind, SectionKind, SymbolKind,
+};
+
+// Build a record with a valid checksum.
+fn record(record_type: u8, contents: &[u8]) -> Vec<u8> {
+    let mut out = vec![record_type];
+    out.extend_from_slice(&(contents.len() as u16 + 1).to_le_bytes());
+    out.extend_from_slice(contents);
+    let sum = out.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+    out.push(sum.wrapping_neg());
+    out
+}
+
+fn name(name: &str) -> Vec<u8> {
+    let mut out = vec![name.len() as u8];
+    out.extend_from_slice(name.as_bytes());
+    out
+}
+
+fn module(module_name: &str) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend(record(omf::THEADR, &name(module_name)));
+    out.extend(record(
+        omf::LNAMES,
+        &[
+            name(""),
+            name("_TEXT"),
+            name("CODE"),
+            name("_DATA"),
+            name("DATA"),
+        ]
+        .concat(),
+    ));
+    // Byte aligned public 16-bit code segment of length 8.
+    out.extend(record(omf::SEGDEF, &[0x28, 8, 0, 2, 3, 1]));
+    // Paragraph aligned public 16-bit data segment of length 6.
+    out.extend(record(omf::SEGDEF, &[0x68, 6, 0, 4, 5, 1]));
+    out.extend(record(omf::EXTDEF, &[name("_puts"), vec![0]].concat()));
+    out.extend(record(
+        omf::PUBDEF,
+        &[vec![0, 1], name("_main"), vec![2, 0, 0]].concat(),
+    ));
+    out.extend(record(
+        omf::LEDATA,
+        &[1, 0, 0, 0x55, 0xb8, 0, 0, 0xe8, 0, 0, 0x5d],
+    ));
+    out.extend(record(
+        omf::FIXUPP,
+        &[
+            // Target thread 0 for segment 2.
+            0x00, 2,
+            // Segment relative 16-bit offset at 2, target thread 0, frame location.
+            0xc4, 0x02, 0x4c,
+            // Self relative 16-bit offset at 5, frame and target external 1.
+            0x84, 0x05, 0x26, 1, 1,
+        ],
+    ));
+    // Iterated data: 3 repetitions of "ab".
+    out.extend(record(omf::LIDATA, &[2, 0, 0, 3, 0, 0, 0, 2, b'a', b'b']));
+    out.extend(record(omf::MODEND, &[0]));
+    out
+}
+
+#[test]
+fn omf_object() {
+    let data = module("hello.c");
+    assert_eq!(FileKind::parse(&*data).unwrap(), FileKind::Omf);
+    let image = OmfImage::parse(&data).unwrap();
+    assert_eq!(image.name(), b"hello.c");
+    let file = image.file();
+    assert_eq!(file.kind(), ObjectKind::Relocatable);
+    assert_eq!(file.architecture(), object::Architecture::I386);
+
+    let text = file.section_by_name("_TEXT").unwrap();
+    assert_eq!(text.index(), SectionIndex(1));
+    assert_eq!(text.kind(), SectionKind::Text);
+    assert_eq!(text.class_name(), b"CODE");
+    assert_eq!(text.size(), 8);
+    assert_eq!(text.align(), 1);
+    assert_eq!(
+        text.data().unwrap(),
+        &[0x55, 0xb8, 0, 0, 0xe8, 0, 0, 0x5d][..]
+    );
+
+    let relocations = text.relocations().collect::<Vec<_>>();
+    assert_eq!(relocations.len(), 2);
+    let (offset, relocation) = &relocations[0];
+    assert_eq!(*offset, 2);
+    assert_eq!(relocation.kind(), RelocationKind::Absolute);
+    assert_eq!(relocation.size(), 16);
+    assert_eq!(
+        relocation.target(),
+        RelocationTarget::Section(SectionIndex(2))
+    );
+    assert_eq!(relocation.addend(), 0);
+    let (offset, relocation) = &relocations[1];
+    assert_eq!(*offset, 5);
+    assert_eq!(relocation.kind(), RelocationKind::Relative);
+    assert_eq!(relocation.size(), 16);
+    assert_eq!(
+        relocation.target(),
+        RelocationTarget::Symbol(SymbolIndex(0))
+    );
+    assert_eq!(relocation.addend(), -2);
+    assert_eq!(text.fixups()[1].frame_method, omf::FRAME_EXTERNAL);
+
+    let data_section = file.section_by_index(SectionIndex(2)).unwrap();
+    assert_eq!(data_section.name(), Ok("_DATA"));
+    assert_eq!(data_section.kind(), SectionKind::Data);
+    assert_eq!(data_section.align(), 16);
+    assert_eq!(data_section.data().unwrap(), b"ababab");
+
+    let symbols = file.symbols().collect::<Vec<_>>();
+    assert_eq!(symbols.len(), 2);
+    assert_eq!(symbols[0].name(), Ok("_puts"));
+    assert!(symbols[0].is_undefined());
+    assert!(symbols[0].is_global());
+    assert_eq!(symbols[1].name(), Ok("_main"));
+    assert_eq!(
+        symbols[1].section(),
+        SymbolSection::Section(SectionIndex(1))
+    );
+    assert_eq!(symbols[1].kind(), SymbolKind::Text);
+    assert_eq!(symbols[1].address(), 2);
+    assert!(symbols[1].is_definition());
+}
+
+#[test]
+fn omf_library() {
+    let page_size = 16;
+    let mut data = record(omf::LIBHDR, &[0; 12]);
+    assert_eq!(data.len(), page_size);
+    for module_name in ["a.c", "b.c"] {
+        data.extend(module(module_name));
+        data.resize((data.len() + page_size - 1) / page_size * page_size, 0);
+    }
+    data.extend(record(omf::LIBEND, &[0; 12]));
+    // One empty dictionary block.
+    let dictionary_offset = (data.len() + 511) / 512 * 512;
+    data.resize(dictionary_offset + 512, 0);
+    let mut header = [0; 12];
+    header[..4].copy_from_slice(&(dictionary_offset as u32).to_le_bytes());
+    header[4..6].copy_from_slice(&1u16.to_le_bytes());
+    data[..page_size].copy_from_slice(&record(omf::LIBHDR, &header));
+    assert_eq!(FileKind::parse(&*data).unwrap(), FileKind::OmfLibrary);
+    assert!(OmfLibrary::parse(&data[..data.len() - 1]).is_err());
+
+    let library = OmfLibrary::parse(&data).unwrap();
+    assert_eq!(library.page_size(), page_size as u64);
+    assert_eq!(library.dictionary_offset(), dictionary_offset as u64);
+    assert_eq!(library.dictionary_blocks(), 1);
+    let members = library.members().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0].name(), b"a.c");
+    assert_eq!(members[0].offset(), page_size as u64);
+    assert_eq!(members[1].name(), b"b.c");
+    let image = OmfImage::parse(members[1].data()).unwrap();
+    assert_eq!(image.name(), b"b.c");
+    assert_eq!(image.file().sections().count(), 2);
+}
+
+#[test]
+fn omf_limits() {
+    let data = module("hello.c");
+    let mut options = ParseOptions::default();
+    options.max_allocation_size = 8;
+    assert!(OmfImage::parse_with_options(&data, &options).is_ok());
+    options.max_allocation_size = 7;
+    assert!(OmfImage::parse_with_options(&data, &options).is_err());
+}
+
+#[test]
+fn omf_invalid() {
+    let mut data = module("hello.c");
+    // Missing MODEND.
+    let len = data.len();
+    assert!(OmfImage::parse(&data[..len - 5]).is_err());
+    // Invalid checksum.
+    data[len - 1] ^= 1;
+    assert!(OmfImage::parse(&data).is_err());
+}
+
+#[test]
+fn omf_iterated_empty_blocks() {
+    let mut data = module("hello.c");
+    let modend = data.split_off(data.len() - 5);
+    // Many blocks with a large repeat count and no content.
+    let mut contents = vec![2, 0, 0, 0, 0];
+    for _ in 0..9000 {
+        contents.extend_from_slice(&[0xff, 0xff, 0xff, 0xff, 0, 0, 0]);
+    }
+    data.extend(record(omf::LIDATA32, &contents));
+    data.extend(modend);
+
+    let image = OmfImage::parse(&data).unwrap();
+    let file = image.file();
+    let data_section = file.section_by_index(SectionIndex(2)).unwrap();
+    assert_eq!(data_section.data().unwrap(), b"ababab");
+}
+
+#[test]
+fn omf_any_file() {
+    let data = module("hello.c");
+    assert!(object::File::parse(&*data).is_err());
+
+    let image = OmfImage::parse(&data).unwrap();
+    let file = object::File::<&[u8]>::Omf(image.file());
+    assert_eq!(file.format(), BinaryFormat::Omf);
+    assert_eq!(file.architecture(), Architecture::I386);
+    assert_eq!(file.segments().count(), 0);
+    assert_eq!(file.comdats().count(), 0);
+    let sections = file
+        .sections()
+        .map(|section| section.name().unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(sections, ["_TEXT", "_DATA"]);
+    assert_eq!(file.symbols().count(), image.file().symbols().count());
+}
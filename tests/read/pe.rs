@@ -0,0 +1,1163 @@
+use object::pe;
+use object::read::pe::{DigestAlgorithm, PeFile64, ResourceDirectory, ResourceDirectoryEntryData};
+use object::read::ParseOptions;
+use object::LittleEndian as LE;
+
+fn put_u16(data: &mut [u8], offset: usize, value: u16) {
+    data[offset..offset + 2].copy_from_slice(&value.to_le_bytes());
+}
+
+fn put_u32(data: &mut [u8], offset: usize, value: u32) {
+    data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+}
+
+/// Encode a DER element with a short or long form length.
+fn der(tag: u8, contents: &[&[u8]]) -> Vec<u8> {
+    let contents = contents.concat();
+    let mut data = vec![tag];
+    if contents.len() < 0x80 {
+        data.push(contents.len() as u8);
+    } else {
+        data.push(0x82);
+        data.extend_from_slice(&(contents.len() as u16).to_be_bytes());
+    }
+    data.extend_from_slice(&contents);
+    data
+}
+
+fn oid(value: &[u8]) -> Vec<u8> {
+    der(0x06, &[value])
+}
+
+fn authenticode_signature(digest: &[u8]) -> Vec<u8> {
+    let sha256 = oid(&[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01]);
+    let algorithm = der(0x30, &[&sha256, &der(0x05, &[])]);
+    let indirect_data = der(
+        0x30,
+        &[
+            &der(
+                0x30,
+                &[&oid(&[
+                    0x2b, 0x06, 0x01, 0x04, 0x01, 0x82, 0x37, 0x02, 0x01, 0x0f,
+                ])],
+            ),
+            &der(0x30, &[&algorithm, &der(0x04, &[digest])]),
+        ],
+    );
+    let content = der(
+        0x30,
+        &[
+            &oid(&[0x2b, 0x06, 0x01, 0x04, 0x01, 0x82, 0x37, 0x02, 0x01, 0x04]),
+            &der(0xa0, &[&indirect_data]),
+        ],
+    );
+    // Dummy certificates. Only the outer element is parsed.
+    let certificates = der(
+        0xa0,
+        &[&der(0x30, &[&[0xaa; 200]]), &der(0x30, &[&[0xbb; 4]])],
+    );
+    let timestamp = der(
+        0x30,
+        &[
+            &oid(&[0x2b, 0x06, 0x01, 0x04, 0x01, 0x82, 0x37, 0x03, 0x03, 0x01]),
+            &der(0x31, &[&der(0x30, &[])]),
+        ],
+    );
+    let signer_info = der(
+        0x30,
+        &[
+            &der(0x02, &[&[1]]),
+            &der(0x30, &[&der(0x30, &[]), &der(0x02, &[&[1]])]),
+            &algorithm,
+            &der(0x30, &[]),
+            &der(0x04, &[&[0xcc; 16]]),
+            &der(0xa1, &[&timestamp]),
+        ],
+    );
+    let signed_data = der(
+        0x30,
+        &[
+            &der(0x02, &[&[1]]),
+            &der(0x31, &[&algorithm]),
+            &content,
+            &certificates,
+            &der(0x31, &[&signer_info]),
+        ],
+    );
+    der(
+        0x30,
+        &[
+            &oid(&[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x07, 0x02]),
+            &der(0xa0, &[&signed_data]),
+        ],
+    )
+}
+
+#[test]
+fn pe_authenticode() {
+    let signature = authenticode_signature(&[0x55; 32]);
+    let mut data = vec![0; 0x200];
+    data[..2].copy_from_slice(b"MZ");
+    put_u32(&mut data, 0x3c, 0x40);
+    data[0x40..0x44].copy_from_slice(b"PE\0\0");
+    put_u16(&mut data, 0x44, pe::IMAGE_FILE_MACHINE_AMD64);
+    put_u16(&mut data, 0x54, 240);
+    let optional = 0x58;
+    put_u16(&mut data, optional, pe::IMAGE_NT_OPTIONAL_HDR64_MAGIC);
+    put_u32(&mut data, optional + 32, 0x1000);
+    put_u32(&mut data, optional + 36, 0x200);
+    put_u32(&mut data, optional + 60, 0x200);
+    put_u32(&mut data, optional + 108, 16);
+    // The certificate table directory entry.
+    let security = optional + 112 + 4 * 8;
+    let table = data.len();
+    let length = 8 + signature.len();
+    put_u32(&mut data, security, table as u32);
+    put_u32(&mut data, security + 4, ((length + 7) & !7) as u32);
+    data.extend_from_slice(&(length as u32).to_le_bytes());
+    data.extend_from_slice(&pe::WIN_CERT_REVISION_2_0.to_le_bytes());
+    data.extend_from_slice(&pe::WIN_CERT_TYPE_PKCS_SIGNED_DATA.to_le_bytes());
+    data.extend_from_slice(&signature);
+    data.resize((data.len() + 7) & !7, 0);
+
+    let file = PeFile64::parse(&*data).unwrap();
+    let mut certificates = file.certificates().unwrap();
+    let certificate = certificates.next().unwrap().unwrap();
+    assert!(certificates.next().unwrap().is_none());
+    assert_eq!(certificate.revision(), pe::WIN_CERT_REVISION_2_0);
+    assert_eq!(certificate.data(), &signature[..]);
+
+    let authenticode = certificate.authenticode().unwrap();
+    assert_eq!(authenticode.digest_algorithm(), DigestAlgorithm::Sha256);
+    assert_eq!(authenticode.digest(), &[0x55; 32]);
+    assert!(authenticode.matches_digest(&[0x55; 32]));
+    assert!(!authenticode.matches_digest(&[0x55; 20]));
+    assert!(authenticode.has_timestamp());
+    let mut blobs = authenticode.certificates();
+    let blob = blobs.next().unwrap().unwrap();
+    assert_eq!(blob.len(), 204);
+    assert_eq!(&blob[..4], &[0x30, 0x82, 0x00, 0xc8]);
+    assert_eq!(
+        blobs.next().unwrap(),
+        Some(&[0x30, 4, 0xbb, 0xbb, 0xbb, 0xbb][..])
+    );
+    assert_eq!(blobs.next().unwrap(), None);
+
+    assert_eq!(
+        file.authenticode_ranges().unwrap(),
+        [
+            (0, optional as u64 + 64),
+            (optional as u64 + 68, security as u64 - optional as u64 - 68),
+            (security as u64 + 8, table as u64 - security as u64 - 8),
+        ]
+    );
+    let mut hashed = Vec::new();
+    file.authenticode_hash(|data| hashed.extend_from_slice(data))
+        .unwrap();
+    let expected = [
+        &data[..optional + 64],
+        &data[optional + 68..security],
+        &data[security + 8..table],
+    ]
+    .concat();
+    assert_eq!(hashed, expected);
+
+    let mut certificates = file
+        .data_directories()
+        .security_directory(&*data)
+        .unwrap()
+        .unwrap();
+    assert_eq!(certificates.next().unwrap().unwrap().data(), &signature[..]);
+    assert!(certificates.next().unwrap().is_none());
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn pe_authenticode_pages() {
+    use object::read::pe::AuthenticodePage;
+    use object::write::pe::{NtHeaders, Writer};
+    use std::convert::TryInto;
+
+    let mut data = Vec::new();
+    let mut writer = Writer::new(true, 0x1000, 0x200, &mut data);
+    writer.reserve_dos_header_and_stub();
+    writer.reserve_nt_headers(16);
+    writer.reserve_section_headers(1);
+    let text = writer.reserve_text_section(0x1800);
+    writer.write_dos_header_and_stub().unwrap();
+    writer.write_nt_headers(NtHeaders {
+        machine: pe::IMAGE_FILE_MACHINE_AMD64,
+        time_date_stamp: 0,
+        characteristics: pe::IMAGE_FILE_EXECUTABLE_IMAGE,
+        major_linker_version: 0,
+        minor_linker_version: 0,
+        address_of_entry_point: 0x1000,
+        image_base: 0x1_4000_0000,
+        major_operating_system_version: 6,
+        minor_operating_system_version: 0,
+        major_image_version: 0,
+        minor_image_version: 0,
+        major_subsystem_version: 6,
+        minor_subsystem_version: 0,
+        subsystem: pe::IMAGE_SUBSYSTEM_WINDOWS_CUI,
+        dll_characteristics: 0,
+        size_of_stack_reserve: 0x10_0000,
+        size_of_stack_commit: 0x1000,
+        size_of_heap_reserve: 0x10_0000,
+        size_of_heap_commit: 0x1000,
+    });
+    writer.write_section_headers();
+    writer.write_section(text.file_offset, &[0xcc; 0x1800]);
+
+    let file = PeFile64::parse(&*data).unwrap();
+    let optional = u64::from(file.dos_header().nt_headers_offset()) + 24;
+    let security = optional + 112 + 4 * 8;
+    let text_offset = u64::from(text.file_offset);
+    assert_eq!(
+        file.authenticode_pages().unwrap(),
+        [
+            AuthenticodePage {
+                offset: 0,
+                ranges: vec![
+                    (0, optional + 64),
+                    (optional + 68, security - optional - 68),
+                    (security + 8, 0x200 - security - 8),
+                ],
+                padding: 0xe00,
+            },
+            AuthenticodePage {
+                offset: text_offset,
+                ranges: vec![(text_offset, 0x1000)],
+                padding: 0,
+            },
+            AuthenticodePage {
+                offset: text_offset + 0x1000,
+                ranges: vec![(text_offset + 0x1000, 0x800)],
+                padding: 0x800,
+            },
+        ]
+    );
+
+    // Use the total hashed length as the digest.
+    let table = file
+        .authenticode_page_hashes(|data, padding| {
+            let len = data.iter().map(|data| data.len() as u64).sum::<u64>() + padding;
+            (len as u32).to_le_bytes().to_vec()
+        })
+        .unwrap();
+    let entries = table
+        .chunks(8)
+        .map(|entry| {
+            (
+                u32::from_le_bytes(entry[..4].try_into().unwrap()),
+                u32::from_le_bytes(entry[4..].try_into().unwrap()),
+            )
+        })
+        .collect::<Vec<_>>();
+    let text_offset = text.file_offset;
+    assert_eq!(
+        entries,
+        [
+            // The checksum and certificate table entry are excluded.
+            (0, 0x1000 - 12),
+            (text_offset, 0x1000),
+            (text_offset + 0x1000, 0x1000),
+            (text_offset + 0x1800, 0),
+        ]
+    );
+}
+
+#[cfg(feature = "write")]
+fn runtime_function(begin: u32, end: u32, unwind_info: u32) -> Vec<u8> {
+    [begin, end, unwind_info]
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect()
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn pe_exception_table() {
+    use object::write::pe::{NtHeaders, Writer};
+
+    // The primary unwind info, followed by unwind info with a single unwind
+    // code that is chained to the first function entry.
+    let mut xdata = vec![1, 0, 0, 0];
+    xdata.resize(0x10, 0);
+    xdata.extend_from_slice(&[1 | (pe::UNW_FLAG_CHAININFO << 3), 0, 1, 0, 0, 0, 0, 0]);
+    xdata.extend(runtime_function(0x1000, 0x1010, 0x2000));
+    let pdata = [
+        runtime_function(0x1000, 0x1010, 0x2000),
+        runtime_function(0x1020, 0x1030, 0x2010),
+        runtime_function(0x1030, 0x1040, 0x3000 | pe::RUNTIME_FUNCTION_INDIRECT),
+    ]
+    .concat();
+
+    let mut data = Vec::new();
+    let mut writer = Writer::new(true, 0x1000, 0x200, &mut data);
+    writer.reserve_dos_header_and_stub();
+    writer.reserve_nt_headers(16);
+    writer.reserve_section_headers(3);
+    let text = writer.reserve_text_section(0x40);
+    let xdata_range = writer.reserve_xdata_section(xdata.len() as u32);
+    let pdata_range = writer.reserve_pdata_section(pdata.len() as u32);
+    assert_eq!(pdata_range.virtual_address, 0x3000);
+    writer.write_dos_header_and_stub().unwrap();
+    writer.write_nt_headers(NtHeaders {
+        machine: pe::IMAGE_FILE_MACHINE_AMD64,
+        time_date_stamp: 0,
+        characteristics: pe::IMAGE_FILE_EXECUTABLE_IMAGE,
+        major_linker_version: 0,
+        minor_linker_version: 0,
+        address_of_entry_point: 0x1000,
+        image_base: 0x1_4000_0000,
+        major_operating_system_version: 6,
+        minor_operating_system_version: 0,
+        major_image_version: 0,
+        minor_image_version: 0,
+        major_subsystem_version: 6,
+        minor_subsystem_version: 0,
+        subsystem: pe::IMAGE_SUBSYSTEM_WINDOWS_CUI,
+        dll_characteristics: 0,
+        size_of_stack_reserve: 0x10_0000,
+        size_of_stack_commit: 0x1000,
+        size_of_heap_reserve: 0x10_0000,
+        size_of_heap_commit: 0x1000,
+    });
+    writer.write_section_headers();
+    writer.write_section(text.file_offset, &[0xcc; 0x40]);
+    writer.write_section(xdata_range.file_offset, &xdata);
+    writer.write_section(pdata_range.file_offset, &pdata);
+
+    let file = PeFile64::parse(&*data).unwrap();
+    let sections = file.section_table();
+    let directories = file.data_directories();
+    // Only x64 entries are supported.
+    assert!(directories
+        .exception_table(&*data, &sections, pe::IMAGE_FILE_MACHINE_ARM64)
+        .is_err());
+    let table = directories
+        .exception_table(&*data, &sections, pe::IMAGE_FILE_MACHINE_AMD64)
+        .unwrap()
+        .unwrap();
+    assert_eq!(table.entries().len(), 3);
+
+    let begin = |rva| {
+        table
+            .find_function(&*data, &sections, rva)
+            .unwrap()
+            .map(|entry| entry.begin_address.get(object::LittleEndian))
+    };
+    assert_eq!(begin(0xfff), None);
+    assert_eq!(begin(0x1000), Some(0x1000));
+    assert_eq!(begin(0x100f), Some(0x1000));
+    assert_eq!(begin(0x1010), None);
+    // Chained unwind info.
+    assert_eq!(begin(0x1024), Some(0x1000));
+    // Indirect unwind info.
+    assert_eq!(begin(0x1030), Some(0x1000));
+    assert_eq!(begin(0x1040), None);
+    assert_eq!(
+        table
+            .find_entry(0x1024)
+            .map(|entry| entry.begin_address.get(object::LittleEndian)),
+        Some(0x1020)
+    );
+
+    let mut options = ParseOptions::default();
+    options.max_depth = 0;
+    let table = object::read::pe::ExceptionTable::parse_with_options(&pdata, &options).unwrap();
+    assert!(table.find_function(&*data, &sections, 0x1000).is_ok());
+    assert!(table.find_function(&*data, &sections, 0x1024).is_err());
+    options.max_table_entries = 2;
+    assert!(object::read::pe::ExceptionTable::parse_with_options(&pdata, &options).is_err());
+}
+
+#[test]
+fn pe_resource_limits() {
+    // A root table with a single entry that is a subtable referring to the root table.
+    let mut data = vec![0; 24];
+    put_u16(&mut data, 14, 1);
+    put_u32(&mut data, 16, 1);
+    put_u32(&mut data, 20, pe::IMAGE_RESOURCE_DATA_IS_DIRECTORY);
+
+    let mut options = ParseOptions::default();
+    options.max_depth = 4;
+    let directory = ResourceDirectory::new_with_options(&data, &options);
+    let mut table = directory.root().unwrap();
+    let mut depth = 0;
+    let error = loop {
+        match table.entry_data(&table.entries[0], directory) {
+            Ok(ResourceDirectoryEntryData::Table(subtable)) => table = subtable,
+            Ok(ResourceDirectoryEntryData::Data(_)) => unreachable!(),
+            Err(error) => break error,
+        }
+        depth += 1;
+    };
+    assert_eq!(depth, 4);
+    assert_eq!(error.to_string(), "Nesting depth exceeds parse limit");
+
+    // Without the table, the depth is not limited.
+    let entry = &directory.root().unwrap().entries[0];
+    assert!(entry.data(directory).unwrap().table().is_some());
+
+    options.max_table_entries = 0;
+    let directory = ResourceDirectory::new_with_options(&data, &options);
+    assert!(directory.root().is_err());
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn pe_load_config() {
+    use object::read::pe::{GuardFunction, LoadConfigDirectory};
+    use object::read::Object;
+    use object::write::pe::{NtHeaders, Writer};
+    use object::{LittleEndian as LE, U32, U64};
+
+    let image_base = 0x1_4000_0000;
+    let rdata_address = 0x2000;
+    let cf_table = 0x140;
+    let eh_table = 0x160;
+    let guard_flags = pe::IMAGE_GUARD_CF_INSTRUMENTED
+        | pe::IMAGE_GUARD_CF_FUNCTION_TABLE_PRESENT
+        | pe::IMAGE_GUARD_EH_CONTINUATION_TABLE_PRESENT
+        | 1 << pe::IMAGE_GUARD_CF_FUNCTION_TABLE_SIZE_SHIFT;
+    let mut config: pe::ImageLoadConfigDirectory64 = object::pod::zeroed();
+    config.size = U32::new(LE, std::mem::size_of_val(&config) as u32);
+    config.guard_flags = U32::new(LE, guard_flags);
+    config.guard_cf_function_table = U64::new(LE, image_base + rdata_address + cf_table);
+    config.guard_cf_function_count = U64::new(LE, 3);
+    config.guard_eh_continuation_table = U64::new(LE, image_base + rdata_address + eh_table);
+    config.guard_eh_continuation_count = U64::new(LE, 2);
+
+    let mut rdata = object::pod::bytes_of(&config).to_vec();
+    assert_eq!(rdata.len(), cf_table as usize);
+    for (address, flags) in [
+        (0x1000u32, 0),
+        (0x1010, pe::IMAGE_GUARD_FLAG_FID_SUPPRESSED),
+        (0x1020, 0),
+    ] {
+        rdata.extend_from_slice(&address.to_le_bytes());
+        rdata.push(flags as u8);
+    }
+    rdata.resize(eh_table as usize, 0);
+    for address in [0x1008u32, 0x1018] {
+        rdata.extend_from_slice(&address.to_le_bytes());
+        rdata.push(0);
+    }
+
+    let mut data = Vec::new();
+    let mut writer = Writer::new(true, 0x1000, 0x200, &mut data);
+    writer.reserve_dos_header_and_stub();
+    writer.reserve_nt_headers(16);
+    writer.reserve_section_headers(2);
+    let text = writer.reserve_text_section(0x40);
+    let rdata_range = writer.reserve_rdata_section(rdata.len() as u32);
+    assert_eq!(u64::from(rdata_range.virtual_address), rdata_address);
+    writer.set_data_directory(
+        pe::IMAGE_DIRECTORY_ENTRY_LOAD_CONFIG,
+        rdata_range.virtual_address,
+        config.size.get(LE),
+    );
+    writer.write_dos_header_and_stub().unwrap();
+    writer.write_nt_headers(NtHeaders {
+        machine: pe::IMAGE_FILE_MACHINE_AMD64,
+        time_date_stamp: 0,
+        characteristics: pe::IMAGE_FILE_EXECUTABLE_IMAGE,
+        major_linker_version: 0,
+        minor_linker_version: 0,
+        address_of_entry_point: 0x1000,
+        image_base,
+        major_operating_system_version: 6,
+        minor_operating_system_version: 0,
+        major_image_version: 0,
+        minor_image_version: 0,
+        major_subsystem_version: 6,
+        minor_subsystem_version: 0,
+        subsystem: pe::IMAGE_SUBSYSTEM_WINDOWS_CUI,
+        dll_characteristics: pe::IMAGE_DLLCHARACTERISTICS_GUARD_CF,
+        size_of_stack_reserve: 0x10_0000,
+        size_of_stack_commit: 0x1000,
+        size_of_heap_reserve: 0x10_0000,
+        size_of_heap_commit: 0x1000,
+    });
+    writer.write_section_headers();
+    writer.write_section(text.file_offset, &[0xcc; 0x40]);
+    writer.write_section(rdata_range.file_offset, &rdata);
+
+    let file = PeFile64::parse(&*data).unwrap();
+    let sections = file.section_table();
+    let load_config = file.load_config_directory().unwrap().unwrap();
+    assert_eq!(load_config.size(), 0x140);
+    assert_eq!(load_config.guard_flags(), guard_flags);
+    let image_base = file.relative_address_base();
+    let functions = load_config
+        .guard_cf_function_table(&*data, &sections, image_base)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        functions,
+        [
+            GuardFunction {
+                address: 0x1000,
+                flags: 0
+            },
+            GuardFunction {
+                address: 0x1010,
+                flags: pe::IMAGE_GUARD_FLAG_FID_SUPPRESSED
+            },
+            GuardFunction {
+                address: 0x1020,
+                flags: 0
+            },
+        ]
+    );
+    let targets = load_config
+        .guard_eh_continuation_table(&*data, &sections, image_base)
+        .unwrap()
+        .map(|target| target.address)
+        .collect::<Vec<_>>();
+    assert_eq!(targets, [0x1008, 0x1018]);
+    assert!(load_config
+        .sehandler_table(&*data, &sections, image_base)
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        load_config
+            .guard_long_jump_target_table(&*data, &sections, image_base)
+            .unwrap()
+            .count(),
+        0
+    );
+
+    // An older directory that ends after the guard flags.
+    let size = 0x94u32;
+    rdata[..4].copy_from_slice(&size.to_le_bytes());
+    let old = LoadConfigDirectory::<pe::ImageLoadConfigDirectory64>::parse(&rdata).unwrap();
+    assert_eq!(old.size(), size);
+    assert_eq!(old.guard_flags(), guard_flags);
+    assert_eq!(old.directory().guard_eh_continuation_count.get(LE), 0);
+    assert_eq!(
+        old.guard_eh_continuation_table(&*data, &sections, image_base)
+            .unwrap()
+            .count(),
+        0
+    );
+    assert_eq!(
+        old.guard_cf_function_table(&*data, &sections, image_base)
+            .unwrap()
+            .count(),
+        3
+    );
+
+    rdata[..4].copy_from_slice(&0x1000u32.to_le_bytes());
+    assert!(LoadConfigDirectory::<pe::ImageLoadConfigDirectory64>::parse(&rdata).is_err());
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn pe_imports_hint_name_section() {
+    use object::read::Object;
+    use object::write::pe::{NtHeaders, Writer};
+
+    // The import descriptors and thunks are in `.idata`, but the hint/name
+    // table is in `.rdata`.
+    let idata_address = 0x1000u32;
+    let rdata_address = 0x2000u32;
+    let mut rdata = Vec::new();
+    for (hint, name) in [(1u16, &b"alpha"[..]), (2, b"beta")] {
+        rdata.extend_from_slice(&hint.to_le_bytes());
+        rdata.extend_from_slice(name);
+        rdata.push(0);
+        rdata.resize((rdata.len() + 1) & !1, 0);
+    }
+    let thunks = [
+        u64::from(rdata_address),
+        pe::IMAGE_ORDINAL_FLAG64 | 5,
+        u64::from(rdata_address) + 8,
+        0,
+    ];
+    let thunks_offset = 40;
+    let name_offset = thunks_offset + 8 * thunks.len() as u32;
+    let mut idata = vec![0; thunks_offset as usize];
+    put_u32(&mut idata, 0, idata_address + thunks_offset);
+    put_u32(&mut idata, 12, idata_address + name_offset);
+    put_u32(&mut idata, 16, idata_address + thunks_offset);
+    for thunk in thunks {
+        idata.extend_from_slice(&thunk.to_le_bytes());
+    }
+    idata.extend_from_slice(b"foo.dll\0");
+
+    let mut data = Vec::new();
+    let mut writer = Writer::new(true, 0x1000, 0x200, &mut data);
+    writer.reserve_dos_header_and_stub();
+    writer.reserve_nt_headers(16);
+    writer.reserve_section_headers(2);
+    let idata_range = writer.reserve_idata_section(idata.len() as u32);
+    assert_eq!(idata_range.virtual_address, idata_address);
+    let rdata_range = writer.reserve_rdata_section(rdata.len() as u32);
+    assert_eq!(rdata_range.virtual_address, rdata_address);
+    writer.write_dos_header_and_stub().unwrap();
+    writer.write_nt_headers(NtHeaders {
+        machine: pe::IMAGE_FILE_MACHINE_AMD64,
+        time_date_stamp: 0,
+        characteristics: pe::IMAGE_FILE_EXECUTABLE_IMAGE,
+        major_linker_version: 0,
+        minor_linker_version: 0,
+        address_of_entry_point: 0,
+        image_base: 0x1_4000_0000,
+        major_operating_system_version: 6,
+        minor_operating_system_version: 0,
+        major_image_version: 0,
+        minor_image_version: 0,
+        major_subsystem_version: 6,
+        minor_subsystem_version: 0,
+        subsystem: pe::IMAGE_SUBSYSTEM_WINDOWS_CUI,
+        dll_characteristics: 0,
+        size_of_stack_reserve: 0x10_0000,
+        size_of_stack_commit: 0x1000,
+        size_of_heap_reserve: 0x10_0000,
+        size_of_heap_commit: 0x1000,
+    });
+    writer.write_section_headers();
+    writer.write_section(idata_range.file_offset, &idata);
+    writer.write_section(rdata_range.file_offset, &rdata);
+
+    let file = PeFile64::parse(&*data).unwrap();
+    let imports = file.imports().unwrap();
+    let imports = imports
+        .iter()
+        .map(|import| (import.library(), import.name(), import.ordinal()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        imports,
+        [
+            (&b"foo.dll"[..], &b"alpha"[..], None),
+            (b"foo.dll", b"", Some(5)),
+            (b"foo.dll", b"beta", None),
+        ]
+    );
+}
+
+#[cfg(feature = "write")]
+fn write_resource_file(resources: &object::write::pe::ResourceSection) -> Vec<u8> {
+    use object::write::pe::{NtHeaders, Writer};
+
+    let mut data = Vec::new();
+    let mut writer = Writer::new(true, 0x1000, 0x200, &mut data);
+    writer.reserve_dos_header_and_stub();
+    writer.reserve_nt_headers(16);
+    writer.reserve_section_headers(2);
+    let text = writer.reserve_text_section(0x10);
+    let rsrc = writer.reserve_rsrc_section(resources.size());
+    writer.write_dos_header_and_stub().unwrap();
+    writer.write_nt_headers(NtHeaders {
+        machine: pe::IMAGE_FILE_MACHINE_AMD64,
+        time_date_stamp: 0,
+        characteristics: pe::IMAGE_FILE_EXECUTABLE_IMAGE,
+        major_linker_version: 0,
+        minor_linker_version: 0,
+        address_of_entry_point: 0x1000,
+        image_base: 0x1_4000_0000,
+        major_operating_system_version: 6,
+        minor_operating_system_version: 0,
+        major_image_version: 0,
+        minor_image_version: 0,
+        major_subsystem_version: 6,
+        minor_subsystem_version: 0,
+        subsystem: pe::IMAGE_SUBSYSTEM_WINDOWS_CUI,
+        dll_characteristics: 0,
+        size_of_stack_reserve: 0x10_0000,
+        size_of_stack_commit: 0x1000,
+        size_of_heap_reserve: 0x10_0000,
+        size_of_heap_commit: 0x1000,
+    });
+    writer.write_section_headers();
+    writer.write_section(text.file_offset, &[0xcc; 0x10]);
+    writer.write_section(rsrc.file_offset, &resources.write(rsrc.virtual_address));
+    data
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn pe_version_resource() {
+    use object::write::pe::{ResourceId, ResourceSection, VersionInfo, VersionStringTable};
+
+    let utf16 = |s: &str| -> Vec<u8> { s.encode_utf16().flat_map(u16::to_le_bytes).collect() };
+    let icon = ResourceId::Id(pe::RT_ICON);
+    let version = ResourceId::Id(pe::RT_VERSION);
+    let version_name = ResourceId::Id(pe::VS_VERSION_INFO);
+
+    let old_version = VersionInfo {
+        file_version: [1, 0, 0, 0],
+        ..Default::default()
+    };
+    let mut resources = ResourceSection::new();
+    resources.set(icon.clone(), ResourceId::Id(1), 0x409, vec![1, 2, 3]);
+    resources.set(
+        ResourceId::name("DATA"),
+        ResourceId::name("CONFIG"),
+        0,
+        b"config".to_vec(),
+    );
+    resources.set_version_info(0x409, &old_version);
+    let data = write_resource_file(&resources);
+
+    // Read the resources of the file, and replace the version.
+    let file = PeFile64::parse(&*data).unwrap();
+    let sections = file.section_table();
+    let directory = file
+        .data_directories()
+        .resource_directory(&*data, &sections)
+        .unwrap()
+        .unwrap();
+    let mut resources = ResourceSection::from_directory(directory, &sections, &*data).unwrap();
+    assert_eq!(
+        resources.get(&icon, &ResourceId::Id(1), 0x409),
+        Some(&[1, 2, 3][..])
+    );
+    assert_eq!(
+        resources.get(&version, &version_name, 0x409),
+        Some(&*old_version.data())
+    );
+
+    let new_version = VersionInfo {
+        file_version: [2, 1, 0, 5],
+        product_version: [2, 1, 0, 0],
+        file_flags_mask: pe::VS_FFI_FILEFLAGSMASK,
+        file_os: pe::VOS_NT_WINDOWS32,
+        file_type: pe::VFT_APP,
+        string_tables: vec![VersionStringTable {
+            language: 0x409,
+            code_page: 0x4b0,
+            strings: vec![
+                ("FileDescription".into(), "Test program".into()),
+                ("ProductName".into(), "Test".into()),
+            ],
+        }],
+        translations: vec![(0x409, 0x4b0)],
+        ..Default::default()
+    };
+    resources.set_version_info(0, &new_version);
+    assert_eq!(resources.get(&version, &version_name, 0x409), None);
+    let data = write_resource_file(&resources);
+
+    let file = PeFile64::parse(&*data).unwrap();
+    let sections = file.section_table();
+    let directory = file
+        .data_directories()
+        .resource_directory(&*data, &sections)
+        .unwrap()
+        .unwrap();
+    let root = directory.root().unwrap();
+    assert_eq!(root.header.number_of_named_entries.get(LE), 1);
+    assert_eq!(root.header.number_of_id_entries.get(LE), 2);
+    let name = root.entries[0].name_or_id().name().unwrap();
+    assert_eq!(name.to_string_lossy(directory).unwrap(), "DATA");
+    assert_eq!(root.entries[1].name_or_id().id(), Some(pe::RT_ICON));
+    assert_eq!(root.entries[2].name_or_id().id(), Some(pe::RT_VERSION));
+
+    let resources = ResourceSection::from_directory(directory, &sections, &*data).unwrap();
+    assert_eq!(
+        resources.get(&ResourceId::name("DATA"), &ResourceId::name("CONFIG"), 0),
+        Some(&b"config"[..])
+    );
+    let version_data = resources.get(&version, &version_name, 0).unwrap();
+    assert_eq!(version_data, &*new_version.data());
+
+    // Check the VS_VERSIONINFO layout.
+    let u16_at =
+        |offset: usize| u16::from_le_bytes([version_data[offset], version_data[offset + 1]]);
+    let u32_at = |offset: usize| u32::from(u16_at(offset)) | u32::from(u16_at(offset + 2)) << 16;
+    assert_eq!(usize::from(u16_at(0)), version_data.len());
+    assert_eq!(u16_at(2), 52);
+    assert_eq!(&version_data[6..38], &*utf16("VS_VERSION_INFO\0"));
+    assert_eq!(u32_at(40), pe::VS_FFI_SIGNATURE);
+    assert_eq!(u32_at(48), 0x0002_0001);
+    assert_eq!(u32_at(52), 0x0000_0005);
+    let contains = |needle: &[u8]| version_data.windows(needle.len()).any(|w| w == needle);
+    assert!(contains(&utf16("StringFileInfo\0")));
+    assert!(contains(&utf16("040904b0\0")));
+    assert!(contains(&utf16("ProductName\0")));
+    assert!(contains(&utf16("Test program\0")));
+    assert!(version_data.ends_with(&[0x09, 0x04, 0xb0, 0x04]));
+}
+
+/// Write a PE file with a single `.rdata` section at address 0x1000.
+///
+/// `directories` is a list of data directory index, offset in `.rdata`, and size.
+#[cfg(feature = "write")]
+fn write_rdata_file(rdata: &[u8], directories: &[(usize, u32, u32)]) -> Vec<u8> {
+    use object::write::pe::{NtHeaders, Writer};
+
+    let mut data = Vec::new();
+    let mut writer = Writer::new(true, 0x1000, 0x200, &mut data);
+    writer.reserve_dos_header_and_stub();
+    writer.reserve_nt_headers(16);
+    writer.reserve_section_headers(1);
+    let range = writer.reserve_rdata_section(rdata.len() as u32);
+    assert_eq!(range.virtual_address, 0x1000);
+    for &(index, offset, size) in directories {
+        writer.set_data_directory(index, range.virtual_address + offset, size);
+    }
+    writer.write_dos_header_and_stub().unwrap();
+    writer.write_nt_headers(NtHeaders {
+        machine: pe::IMAGE_FILE_MACHINE_AMD64,
+        time_date_stamp: 0,
+        characteristics: pe::IMAGE_FILE_EXECUTABLE_IMAGE | pe::IMAGE_FILE_DLL,
+        major_linker_version: 0,
+        minor_linker_version: 0,
+        address_of_entry_point: 0,
+        image_base: 0x1_4000_0000,
+        major_operating_system_version: 6,
+        minor_operating_system_version: 0,
+        major_image_version: 0,
+        minor_image_version: 0,
+        major_subsystem_version: 6,
+        minor_subsystem_version: 0,
+        subsystem: pe::IMAGE_SUBSYSTEM_WINDOWS_CUI,
+        dll_characteristics: 0,
+        size_of_stack_reserve: 0x10_0000,
+        size_of_stack_commit: 0x1000,
+        size_of_heap_reserve: 0x10_0000,
+        size_of_heap_commit: 0x1000,
+    });
+    writer.write_section_headers();
+    writer.write_section(range.file_offset, rdata);
+    data
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn pe_certificate_table() {
+    use object::read::{CodeSignature, Object};
+
+    let mut data = write_rdata_file(&[0; 16], &[]);
+    let file = PeFile64::parse(&*data).unwrap();
+    assert_eq!(file.certificate_table().unwrap(), None);
+    assert!(file.code_signature().unwrap().is_none());
+
+    // Append a certificate table with one entry, and point the directory entry at it.
+    let dos_header = pe::ImageDosHeader::parse(&*data).unwrap();
+    let nt_headers = dos_header.nt_headers_offset() as usize;
+    let security = nt_headers + 24 + 112 + pe::IMAGE_DIRECTORY_ENTRY_SECURITY * 8;
+    let table = data.len();
+    data.extend_from_slice(&13u32.to_le_bytes());
+    data.extend_from_slice(&pe::WIN_CERT_REVISION_2_0.to_le_bytes());
+    data.extend_from_slice(&pe::WIN_CERT_TYPE_X509.to_le_bytes());
+    data.extend_from_slice(b"x509\0");
+    data.resize(table + 16, 0);
+    put_u32(&mut data, security, table as u32);
+    put_u32(&mut data, security + 4, 16);
+
+    let file = PeFile64::parse(&*data).unwrap();
+    assert_eq!(file.certificate_table().unwrap(), Some(&data[table..]));
+    let mut certificates = file.certificates().unwrap();
+    let certificate = certificates.next().unwrap().unwrap();
+    assert_eq!(certificate.revision(), pe::WIN_CERT_REVISION_2_0);
+    assert_eq!(certificate.certificate_type(), pe::WIN_CERT_TYPE_X509);
+    assert_eq!(certificate.data(), b"x509\0");
+    assert!(certificates.next().unwrap().is_none());
+    match file.code_signature().unwrap() {
+        Some(CodeSignature::Pe(signature)) => assert_eq!(signature, &data[table..]),
+        signature => panic!("unexpected code signature {:?}", signature),
+    }
+
+    // The table is outside the file.
+    put_u32(&mut data, security, (table + 8) as u32);
+    let file = PeFile64::parse(&*data).unwrap();
+    assert!(file.certificate_table().is_err());
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn pe_delay_load_imports() {
+    use object::read::{ImportKind, Object};
+
+    // Layout of `.rdata`, which is at address 0x1000:
+    //   0: import descriptor and null descriptor
+    //  40: delay-load descriptor and null descriptor
+    // 104: import thunks
+    // 120: delay-load thunks
+    // 144: hint/name entry
+    // 152: library names
+    let mut rdata = vec![0; 144];
+    put_u32(&mut rdata, 0, 0x1000 + 104);
+    put_u32(&mut rdata, 12, 0x1000 + 152);
+    put_u32(&mut rdata, 16, 0x1000 + 104);
+    put_u32(&mut rdata, 40, 1);
+    put_u32(&mut rdata, 44, 0x1000 + 160);
+    put_u32(&mut rdata, 52, 0x1000 + 120);
+    put_u32(&mut rdata, 56, 0x1000 + 120);
+    for (offset, thunk) in [
+        (104, pe::IMAGE_ORDINAL_FLAG64 | 5),
+        (120, 0x1000 + 144),
+        (128, pe::IMAGE_ORDINAL_FLAG64 | 7),
+    ] {
+        rdata[offset..offset + 8].copy_from_slice(&u64::to_le_bytes(thunk));
+    }
+    rdata.extend_from_slice(b"\x03\0gamma\0");
+    rdata.extend_from_slice(b"foo.dll\0bar.dll\0");
+    let data = write_rdata_file(
+        &rdata,
+        &[
+            (pe::IMAGE_DIRECTORY_ENTRY_IMPORT, 0, 40),
+            (pe::IMAGE_DIRECTORY_ENTRY_DELAY_IMPORT, 40, 64),
+        ],
+    );
+
+    let file = PeFile64::parse(&*data).unwrap();
+    let imports = file.imports().unwrap();
+    let iter = file.import_iter().unwrap();
+    assert_eq!(iter.collect::<Result<Vec<_>, _>>().unwrap(), imports);
+    let imports = imports
+        .iter()
+        .map(|import| {
+            assert_eq!(import.version(), None);
+            (
+                import.library(),
+                import.name(),
+                import.ordinal(),
+                import.kind(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        imports,
+        [
+            (&b"foo.dll"[..], &b""[..], Some(5), ImportKind::Eager),
+            (b"bar.dll", b"gamma", None, ImportKind::Delay),
+            (b"bar.dll", b"", Some(7), ImportKind::Delay),
+        ]
+    );
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn pe_needed_libraries() {
+    use object::read::Object;
+
+    // Layout of `.rdata`, which is at address 0x1000:
+    //   0: two import descriptors and null descriptor
+    //  60: delay-load descriptor and null descriptor
+    // 124: library names
+    let mut rdata = vec![0; 124];
+    put_u32(&mut rdata, 12, 0x1000 + 124);
+    put_u32(&mut rdata, 20 + 12, 0x1000 + 130);
+    put_u32(&mut rdata, 60, 1);
+    put_u32(&mut rdata, 60 + 4, 0x1000 + 136);
+    rdata.extend_from_slice(b"a.dll\0b.dll\0c.dll\0");
+    let data = write_rdata_file(
+        &rdata,
+        &[
+            (pe::IMAGE_DIRECTORY_ENTRY_IMPORT, 0, 60),
+            (pe::IMAGE_DIRECTORY_ENTRY_DELAY_IMPORT, 60, 64),
+        ],
+    );
+
+    // Delay-load libraries follow the eagerly loaded libraries.
+    let file = PeFile64::parse(&*data).unwrap();
+    assert_eq!(
+        file.needed_libraries().unwrap(),
+        [&b"a.dll"[..], b"b.dll", b"c.dll"]
+    );
+
+    // Files without an import directory don't need any libraries.
+    let data = write_rdata_file(&rdata, &[]);
+    let file = PeFile64::parse(&*data).unwrap();
+    assert!(file.needed_libraries().unwrap().is_empty());
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn pe_exports_malformed_forwarder() {
+    use object::read::Object;
+
+    // Layout of the export directory at the start of `.rdata`:
+    //   0: export directory
+    //  40: export address table
+    //  56: name pointer table
+    //  72: ordinal table
+    //  80: names and forwarder strings
+    let mut rdata = vec![0; 80];
+    let mut strings = Vec::new();
+    let mut string = |s: &[u8]| {
+        let address = 0x1000 + 80 + strings.len() as u32;
+        strings.extend_from_slice(s);
+        strings.push(0);
+        address
+    };
+    let names = [string(b"a"), string(b"b"), string(b"c"), string(b"d")];
+    let addresses = [
+        0x2000,
+        string(b"lib.func"),
+        string(b"lib.#3"),
+        string(b"nodot"),
+    ];
+    let dll_name = string(b"x.dll");
+    put_u32(&mut rdata, 12, dll_name);
+    put_u32(&mut rdata, 16, 1);
+    put_u32(&mut rdata, 20, 4);
+    put_u32(&mut rdata, 24, 4);
+    put_u32(&mut rdata, 28, 0x1000 + 40);
+    put_u32(&mut rdata, 32, 0x1000 + 56);
+    put_u32(&mut rdata, 36, 0x1000 + 72);
+    for i in 0..4 {
+        put_u32(&mut rdata, 40 + 4 * i, addresses[i]);
+        put_u32(&mut rdata, 56 + 4 * i, names[i]);
+        put_u16(&mut rdata, 72 + 2 * i, i as u16);
+    }
+    rdata.extend_from_slice(&strings);
+    let size = rdata.len() as u32;
+    let data = write_rdata_file(&rdata, &[(pe::IMAGE_DIRECTORY_ENTRY_EXPORT, 0, size)]);
+
+    let file = PeFile64::parse(&*data).unwrap();
+    let exports = file.exports().unwrap();
+    let iter = file.export_iter().unwrap();
+    assert_eq!(iter.size_hint(), (0, Some(4)));
+    assert_eq!(iter.collect::<Result<Vec<_>, _>>().unwrap(), exports);
+    let exports = exports
+        .iter()
+        .map(|export| {
+            let forward = export
+                .forward()
+                .map(|forward| (forward.library(), forward.name(), forward.ordinal()));
+            (export.name(), export.address(), forward)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        exports,
+        [
+            (&b"a"[..], 0x1_4000_2000, None),
+            (b"b", 0, Some((&b"lib"[..], &b"func"[..], None))),
+            (b"c", 0, Some((b"lib", b"", Some(3)))),
+        ]
+    );
+}
+
+#[cfg(all(feature = "serde", feature = "write"))]
+#[test]
+fn pe_serialize_import() {
+    use object::read::Object;
+    use serde_test::{assert_ser_tokens, Token};
+
+    // Layout of `.rdata`, which is at address 0x1000:
+    //   0: import descriptor and null descriptor
+    //  40: import thunks
+    //  56: hint/name entry
+    //  62: library name
+    let mut rdata = vec![0; 56];
+    put_u32(&mut rdata, 0, 0x1000 + 40);
+    put_u32(&mut rdata, 12, 0x1000 + 62);
+    put_u32(&mut rdata, 16, 0x1000 + 40);
+    put_u32(&mut rdata, 40, 0x1000 + 56);
+    rdata.extend_from_slice(b"\0\0g\xff\0\0");
+    rdata.extend_from_slice(b"foo.dll\0");
+    let data = write_rdata_file(&rdata, &[(pe::IMAGE_DIRECTORY_ENTRY_IMPORT, 0, 40)]);
+
+    // Names are always serialized as strings, even if they aren't valid UTF-8.
+    let file = PeFile64::parse(&*data).unwrap();
+    let imports = file.imports().unwrap();
+    assert_eq!(imports.len(), 1);
+    assert_ser_tokens(
+        &imports[0],
+        &[
+            Token::Struct {
+                name: "Import",
+                len: 5,
+            },
+            Token::Str("library"),
+            Token::Str("foo.dll"),
+            Token::Str("name"),
+            Token::Str("g\u{fffd}"),
+            Token::Str("ordinal"),
+            Token::None,
+            Token::Str("version"),
+            Token::None,
+            Token::Str("kind"),
+            Token::UnitVariant {
+                name: "ImportKind",
+                variant: "Eager",
+            },
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn pe_dump_imports_exports() {
+    use object::read::dump::{self, DumpNode, DumpValue};
+
+    fn string<'data>(node: &DumpNode<'data>, name: &str) -> Option<DumpValue<'data>> {
+        let string = node.child(name)?.child("String")?;
+        Some(string.value().clone())
+    }
+
+    // Layout of `.rdata`, which is at address 0x1000:
+    //   0: import descriptor and null descriptor
+    //  40: import thunks
+    //  64: hint/name entry
+    //  72: library name
+    //  80: export directory
+    // 120: export address table
+    // 124: name pointer table
+    // 128: ordinal table
+    // 130: export name and library name
+    let mut rdata = vec![0; 130];
+    put_u32(&mut rdata, 0, 0x1000 + 40);
+    put_u32(&mut rdata, 12, 0x1000 + 72);
+    put_u32(&mut rdata, 16, 0x1000 + 40);
+    rdata[40..48].copy_from_slice(&u64::to_le_bytes(0x1000 + 64));
+    rdata[48..56].copy_from_slice(&u64::to_le_bytes(pe::IMAGE_ORDINAL_FLAG64 | 5));
+    rdata[64..71].copy_from_slice(b"\0\0func\0");
+    rdata[72..80].copy_from_slice(b"foo.dll\0");
+    put_u32(&mut rdata, 80 + 12, 0x1000 + 134);
+    put_u32(&mut rdata, 80 + 16, 1);
+    put_u32(&mut rdata, 80 + 20, 1);
+    put_u32(&mut rdata, 80 + 24, 1);
+    put_u32(&mut rdata, 80 + 28, 0x1000 + 120);
+    put_u32(&mut rdata, 80 + 32, 0x1000 + 124);
+    put_u32(&mut rdata, 80 + 36, 0x1000 + 128);
+    put_u32(&mut rdata, 120, 0x2000);
+    put_u32(&mut rdata, 124, 0x1000 + 130);
+    rdata.extend_from_slice(b"bar\0x.dll\0");
+    let data = write_rdata_file(
+        &rdata,
+        &[
+            (pe::IMAGE_DIRECTORY_ENTRY_EXPORT, 80, 60),
+            (pe::IMAGE_DIRECTORY_ENTRY_IMPORT, 0, 40),
+        ],
+    );
+
+    let root = dump::parse(&data).unwrap();
+
+    let exports = root.child("ExportDirectory").unwrap();
+    assert_eq!(string(exports, "Name"), Some(DumpValue::String(b"x.dll")));
+    let addresses = root.child("ExportAddressTable").unwrap();
+    assert_eq!(
+        addresses.child("Address").unwrap().value(),
+        &DumpValue::Hex(0x2000)
+    );
+    let names = root.child("NamePointerTable").unwrap();
+    assert_eq!(string(names, "Name"), Some(DumpValue::String(b"bar")));
+    let ordinals = root.child("OrdinalTable").unwrap();
+    assert_eq!(
+        ordinals.child("Index").unwrap().value(),
+        &DumpValue::Decimal(0)
+    );
+
+    let descriptors = root.child("ImportDirectory").unwrap();
+    assert_eq!(descriptors.children().len(), 1);
+    let descriptor = descriptors.child("ImportDescriptor").unwrap();
+    assert_eq!(descriptor.value(), &DumpValue::String(b"foo.dll"));
+    assert_eq!(descriptor.raw(), &rdata[..20]);
+    let thunks = root.child("ImportLookupTable").unwrap();
+    assert_eq!(thunks.value(), &DumpValue::String(b"foo.dll"));
+    assert_eq!(thunks.raw(), &rdata[40..56]);
+    assert_eq!(string(thunks, "HintName"), Some(DumpValue::String(b"func")));
+    assert_eq!(
+        thunks.child("Ordinal").unwrap().value(),
+        &DumpValue::Decimal(5)
+    );
+}
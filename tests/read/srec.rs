@@ -0,0 +1,37 @@
+use object::read::srec::SrecImage;
+use object::read::{Object, ObjectSegment};
+
+#[test]
+fn srec_segments() {
+    let data = b"\
+S00600004844521B\r
+S107100001020304DE\r
+S10510040506DB\r
+S206020000AABB92\r
+S5030003F9\r
+S804001004E7\r
+";
+    let image = SrecImage::parse(data).unwrap();
+    assert_eq!(image.header(), b"HDR");
+    let file = image.file();
+    assert_eq!(file.entry(), 0x1004);
+
+    let segments: Vec<_> = file.segments().collect();
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments[0].address(), 0x1000);
+    assert_eq!(segments[0].data().unwrap(), &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(segments[1].address(), 0x2_0000);
+    assert_eq!(segments[1].data().unwrap(), &[0xaa, 0xbb]);
+}
+
+#[test]
+fn srec_errors() {
+    // Bad checksum.
+    assert!(SrecImage::parse(b"S107100001020304DF\nS9031000EC\n").is_err());
+    // Missing termination record.
+    assert!(SrecImage::parse(b"S107100001020304DE\n").is_err());
+    // Overlapping data.
+    assert!(SrecImage::parse(b"S107100001020304DE\nS1041002FFEA\nS9031000EC\n").is_err());
+    // Wrong record count.
+    assert!(SrecImage::parse(b"S107100001020304DE\nS5030002FA\nS9031000EC\n").is_err());
+}
@@ -0,0 +1,38 @@
+use object::read::{SymbolMap, SymbolMapName};
+
+#[test]
+fn symbol_map_get() {
+    let map = SymbolMap::new(vec![
+        SymbolMapName::with_size(0x1010, 0x10, "b"),
+        SymbolMapName::new(0x1000, "a"),
+        SymbolMapName::with_size(0x1030, 0x10, "c"),
+    ]);
+    let a = SymbolMapName::new(0x1000, "a");
+    let b = SymbolMapName::new(0x1010, "b");
+    let c = SymbolMapName::new(0x1030, "c");
+
+    assert_eq!(map.get(0xfff), None);
+    assert_eq!(map.get(0x1000), Some(&a));
+    assert_eq!(map.get(0x1020), Some(&b));
+
+    // Sized symbols only contain addresses within their size.
+    assert_eq!(map.get_within(0x101f, 0), Some(&b));
+    assert_eq!(map.get_within(0x1020, 0x100), None);
+    // Unsized symbols contain addresses within the maximum gap.
+    assert_eq!(map.get_within(0x1004, 4), Some(&a));
+    assert_eq!(map.get_within(0x1005, 4), None);
+
+    assert_eq!(map.get_next(0xfff), Some(&a));
+    assert_eq!(map.get_next(0x1010), Some(&c));
+    assert_eq!(map.get_next(0x1030), None);
+}
+
+#[test]
+fn symbol_map_name_eq() {
+    // The size is not used for equality.
+    let sized = SymbolMapName::with_size(0x1000, 0x10, "a");
+    assert_eq!(sized, SymbolMapName::new(0x1000, "a"));
+    assert_eq!(sized.size(), 0x10);
+    assert_ne!(sized, SymbolMapName::with_size(0x1000, 0x10, "b"));
+    assert_ne!(sized, SymbolMapName::with_size(0x1001, 0x10, "a"));
+}
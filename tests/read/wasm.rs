@@ -0,0 +1,74 @@
+use object::read::wasm::WasmFile;
+use object::read::{Object, ObjectSection, ObjectSymbol};
+
+fn section(id: u8, contents: &[u8]) -> Vec<u8> {
+    let mut out = vec![id, contents.len() as u8];
+    out.extend_from_slice(contents);
+    out
+}
+
+#[test]
+fn wasm_function_code_ranges() {
+    let mut data = b"\0asm\x01\0\0\0".to_vec();
+    // One type: no parameters or results.
+    data.extend(section(1, &[1, 0x60, 0, 0]));
+    // One imported function: "env" "f".
+    data.extend(section(2, &[1, 3, b'e', b'n', b'v', 1, b'f', 0, 0]));
+    // Two local functions.
+    data.extend(section(3, &[2, 0, 0]));
+    // Export the second local function as "main".
+    data.extend(section(7, &[1, 4, b'm', b'a', b'i', b'n', 0, 2]));
+    // The first body is at offset 2, and the second body is at offset 5.
+    data.extend(section(10, &[2, 2, 0, 0x0b, 4, 0, 0x01, 0x01, 0x0b]));
+
+    let file = WasmFile::<&[u8]>::parse(&*data).unwrap();
+    assert_eq!(file.imported_function_count(), 1);
+    assert_eq!(file.function_code_range(0), None);
+    assert_eq!(file.function_code_range(1), Some(2..4));
+    assert_eq!(file.function_code_range(2), Some(5..9));
+    assert_eq!(file.function_code_range(3), None);
+    assert_eq!(file.function_index_at_code_offset(1), None);
+    assert_eq!(file.function_index_at_code_offset(3), Some(1));
+    assert_eq!(file.function_index_at_code_offset(4), None);
+    assert_eq!(file.function_index_at_code_offset(8), Some(2));
+    assert_eq!(file.function_index_at_code_offset(9), None);
+
+    let main = file.symbol_by_name("main").unwrap();
+    assert_eq!(main.address(), 5);
+    assert_eq!(main.size(), 4);
+    let code = main.section_index().unwrap();
+    let code = file.section_by_index(code).unwrap();
+    assert_eq!(
+        code.data_range(main.address(), main.size()).unwrap(),
+        Some(&[0, 0x01, 0x01, 0x0b][..])
+    );
+
+    let ranges = file.function_ranges().unwrap();
+    let ranges = ranges
+        .iter()
+        .map(|range| (range.address(), range.size()))
+        .collect::<Vec<_>>();
+    assert_eq!(ranges, [(2, 2), (5, 4)]);
+}
+
+#[test]
+fn wasm_lazy_symbols() {
+    let mut data = b"\0asm\x01\0\0\0".to_vec();
+    // One type: no parameters or results.
+    data.extend(section(1, &[1, 0x60, 0, 0]));
+    // One local function.
+    data.extend(section(3, &[1, 0]));
+    // An export of an invalid function index.
+    data.extend(section(7, &[1, 4, b'm', b'a', b'i', b'n', 0, 5]));
+    // A body that is not checked by `parse`.
+    data.extend(section(10, &[1, 9, 0]));
+
+    let file = WasmFile::<&[u8]>::parse(&*data).unwrap();
+    assert_eq!(file.sections().count(), 4);
+    assert_eq!(file.imported_function_count(), 0);
+    assert!(file.symbol_by_index(object::SymbolIndex(0)).is_err());
+    assert_eq!(file.symbols().count(), 0);
+    assert!(file.symbol_table().is_none());
+    assert!(file.function_ranges().is_err());
+    assert_eq!(file.function_code_range(0), None);
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use object::read::{Object, ObjectSection};
+use object::{read, write};
+
+#[test]
+fn arbitrary_round_trip() {
+    // A simple xorshift generator, so that the inputs are deterministic.
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut data = vec![0; 4096];
+    for _ in 0..256 {
+        for byte in &mut data {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            *byte = state as u8;
+        }
+        let mut u = Unstructured::new(&data);
+        let object = write::Object::arbitrary(&mut u).unwrap();
+        let bytes = object.write().unwrap();
+        let file = read::File::parse(&*bytes).unwrap();
+        assert_eq!(file.format(), object.format());
+        assert_eq!(file.architecture(), object.architecture());
+        for section in file.sections() {
+            section.data().unwrap();
+            for (_, relocation) in section.relocations() {
+                if let read::RelocationTarget::Symbol(index) = relocation.target() {
+                    file.symbol_by_index(index).unwrap();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use object::read::archive::ArchiveFile;
+use object::write::archive::{Archive, ArchiveMember, MemberMetadata};
+use object::{read, write};
+
+fn archive() -> Archive<'static> {
+    let mut archive = Archive::new(write::archive::ArchiveKind::Gnu);
+    let mut member = ArchiveMember::new(b"a.o".to_vec(), &b"odd"[..]);
+    member.date = 1_600_000_000;
+    member.uid = 1000;
+    member.gid = 100;
+    member.mode = 0o600;
+    member.symbols.push(b"a".to_vec());
+    archive.add_member(member);
+    let mut member = ArchiveMember::new(b"a_long_file_name.o".to_vec(), &b"even"[..]);
+    member.symbols.push(b"b".to_vec());
+    member.symbols.push(b"c".to_vec());
+    archive.add_member(member);
+    archive
+}
+
+#[test]
+fn archive_gnu_deterministic() {
+    let archive = archive();
+    assert_eq!(archive.metadata, MemberMetadata::Deterministic);
+    let bytes = archive.write().unwrap();
+    assert_eq!(bytes, archive.write().unwrap());
+
+    let data = &*bytes;
+    let file = ArchiveFile::parse(data).unwrap();
+    assert_eq!(file.kind(), read::archive::ArchiveKind::Gnu);
+    let mut members = file.members();
+
+    let member = members.next().unwrap().unwrap();
+    assert_eq!(member.name(), b"a.o");
+    assert_eq!(member.data(data).unwrap(), b"odd");
+    assert_eq!(member.date(), Some(0));
+    assert_eq!(member.uid(), Some(0));
+    assert_eq!(member.gid(), Some(0));
+    assert_eq!(member.mode(), Some(0o644));
+
+    let member = members.next().unwrap().unwrap();
+    assert_eq!(member.name(), b"a_long_file_name.o");
+    assert_eq!(member.data(data).unwrap(), b"even");
+    assert_eq!(member.date(), Some(0));
+
+    assert!(members.next().is_none());
+}
+
+#[test]
+fn archive_gnu_preserve() {
+    let mut archive = archive();
+    archive.set_metadata(MemberMetadata::Preserve);
+    let bytes = archive.write().unwrap();
+
+    let data = &*bytes;
+    let file = ArchiveFile::parse(data).unwrap();
+    let mut members = file.members();
+
+    let member = members.next().unwrap().unwrap();
+    assert_eq!(member.name(), b"a.o");
+    assert_eq!(member.date(), Some(1_600_000_000));
+    assert_eq!(member.uid(), Some(1000));
+    assert_eq!(member.gid(), Some(100));
+    assert_eq!(member.mode(), Some(0o600));
+
+    let member = members.next().unwrap().unwrap();
+    assert_eq!(member.name(), b"a_long_file_name.o");
+    assert_eq!(member.date(), Some(0));
+    assert_eq!(member.mode(), Some(0o644));
+}
+
+fn check_symbols(data: &[u8], kind: read::archive::ArchiveKind) {
+    let file = ArchiveFile::parse(data).unwrap();
+    assert_eq!(file.kind(), kind);
+    let symbols = file
+        .symbols()
+        .unwrap()
+        .unwrap()
+        .map(|symbol| {
+            let symbol = symbol.unwrap();
+            let member = file.member(symbol.offset()).unwrap();
+            (symbol.name(), member.name())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        symbols,
+        [
+            (&b"a"[..], &b"a.o"[..]),
+            (&b"b"[..], &b"a_long_file_name.o"[..]),
+            (&b"c"[..], &b"a_long_file_name.o"[..]),
+        ]
+    );
+}
+
+#[test]
+fn archive_gnu_symbols() {
+    let archive = archive();
+    let bytes = archive.write().unwrap();
+    check_symbols(&bytes, read::archive::ArchiveKind::Gnu);
+}
+
+#[test]
+fn archive_gnu64_symbols() {
+    let mut archive = archive();
+    archive.set_kind(write::archive::ArchiveKind::Gnu64);
+    let bytes = archive.write().unwrap();
+    check_symbols(&bytes, read::archive::ArchiveKind::Gnu64);
+
+    let file = ArchiveFile::parse(&*bytes).unwrap();
+    let mut members = file.members();
+    let member = members.next().unwrap().unwrap();
+    assert_eq!(member.name(), b"a.o");
+    let member = members.next().unwrap().unwrap();
+    assert_eq!(member.name(), b"a_long_file_name.o");
+    assert!(members.next().is_none());
+}
+
+#[test]
+fn archive_bsd_names() {
+    let mut archive = archive();
+    archive.set_kind(write::archive::ArchiveKind::Bsd);
+    archive.add_member(ArchiveMember::new(b"s p a c e".to_vec(), &b"x"[..]));
+    archive.add_member(ArchiveMember::new(b"0123456789abcdef".to_vec(), &b"y"[..]));
+    let bytes = archive.write().unwrap();
+    check_symbols(&bytes, read::archive::ArchiveKind::Bsd);
+
+    let data = &*bytes;
+    let file = ArchiveFile::parse(data).unwrap();
+    let mut members = file.members();
+
+    let member = members.next().unwrap().unwrap();
+    assert_eq!(member.name(), b"a.o");
+    assert_eq!(member.data(data).unwrap(), b"odd");
+    assert_eq!(&member.header().unwrap().name, b"a.o             ");
+
+    let member = members.next().unwrap().unwrap();
+    assert_eq!(member.name(), b"a_long_file_name.o");
+    assert_eq!(member.data(data).unwrap(), b"even");
+    assert_eq!(member.file_range().0 % 8, 0);
+    assert_eq!(&member.header().unwrap().name[..3], b"#1/");
+
+    let member = members.next().unwrap().unwrap();
+    assert_eq!(member.name(), b"s p a c e");
+    assert_eq!(member.data(data).unwrap(), b"x");
+
+    let member = members.next().unwrap().unwrap();
+    assert_eq!(member.name(), b"0123456789abcdef");
+    assert_eq!(member.data(data).unwrap(), b"y");
+
+    assert!(members.next().is_none());
+}
+
+#[test]
+fn archive_bsd64_symbols() {
+    let mut archive = archive();
+    archive.set_kind(write::archive::ArchiveKind::Bsd64);
+    let bytes = archive.write().unwrap();
+    check_symbols(&bytes, read::archive::ArchiveKind::Bsd64);
+}
+
+fn member_object(format: object::BinaryFormat) -> Vec<u8> {
+    use object::{Architecture, Endianness, SymbolFlags, SymbolKind, SymbolScope};
+
+    let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+    object.set_mangling(write::Mangling::None);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3; 4], 1);
+    for (name, scope, weak, section) in [
+        (
+            &b"global"[..],
+            SymbolScope::Linkage,
+            false,
+            write::SymbolSection::Section(text),
+        ),
+        (
+            b"local",
+            SymbolScope::Compilation,
+            false,
+            write::SymbolSection::Section(text),
+        ),
+        (
+            b"weak",
+            SymbolScope::Linkage,
+            true,
+            write::SymbolSection::Section(text),
+        ),
+        (
+            b"undefined",
+            SymbolScope::Unknown,
+            false,
+            write::SymbolSection::Undefined,
+        ),
+    ] {
+        object.add_symbol(write::Symbol {
+            name: name.to_vec(),
+            value: 0,
+            size: 0,
+            kind: SymbolKind::Text,
+            scope,
+            weak,
+            section,
+            flags: SymbolFlags::None,
+        });
+    }
+    object.add_common_symbol(
+        write::Symbol {
+            name: b"common".to_vec(),
+            value: 0,
+            size: 0,
+            kind: SymbolKind::Data,
+            scope: SymbolScope::Linkage,
+            weak: false,
+            section: write::SymbolSection::Undefined,
+            flags: SymbolFlags::None,
+        },
+        4,
+        4,
+    );
+    object.write().unwrap()
+}
+
+fn check_member_symbols(archive: &Archive, objects: usize) {
+    let bytes = archive.write().unwrap();
+    let file = ArchiveFile::parse(&*bytes).unwrap();
+    let symbols = file
+        .symbols()
+        .unwrap()
+        .unwrap()
+        .map(|symbol| symbol.unwrap().name())
+        .collect::<Vec<_>>();
+    let expected = [&b"global"[..], b"weak", b"common"];
+    assert_eq!(symbols.len(), expected.len() * objects);
+    for chunk in symbols.chunks(expected.len()) {
+        assert_eq!(chunk, expected);
+    }
+}
+
+#[test]
+fn archive_member_symbols() {
+    for format in [object::BinaryFormat::Elf, object::BinaryFormat::MachO] {
+        let mut archive = Archive::new(write::archive::ArchiveKind::Gnu);
+        archive.add_member(ArchiveMember::new(b"a.o".to_vec(), member_object(format)));
+        archive.add_member(ArchiveMember::new(b"README".to_vec(), &b"text"[..]));
+        for member in archive.members_mut() {
+            member.add_symbols().unwrap();
+        }
+        check_member_symbols(&archive, 1);
+    }
+
+    let mut archive = Archive::new(write::archive::ArchiveKind::Gnu);
+    let mut elf = vec![0; 64];
+    elf[..6].copy_from_slice(b"\x7fELF\x02\x01");
+    let id = archive.add_member(ArchiveMember::new(b"bad.o".to_vec(), elf));
+    assert!(archive.member_mut(id).add_symbols().is_err());
+}
+
+#[test]
+fn archive_add_symbols() {
+    let mut archive = Archive::new(write::archive::ArchiveKind::Gnu);
+    for i in 0..32 {
+        let format = if i % 2 == 0 {
+            object::BinaryFormat::Elf
+        } else {
+            object::BinaryFormat::MachO
+        };
+        let name = format!("{}.o", i).into_bytes();
+        archive.add_member(ArchiveMember::new(name, member_object(format)));
+        archive.add_member(ArchiveMember::new(b"README".to_vec(), &b"text"[..]));
+    }
+    archive.add_symbols().unwrap();
+    check_member_symbols(&archive, 32);
+
+    let mut elf = vec![0; 64];
+    elf[..6].copy_from_slice(b"\x7fELF\x02\x01");
+    archive.add_member(ArchiveMember::new(b"bad.o".to_vec(), elf));
+    assert!(archive.add_symbols().is_err());
+}
@@ -1,5 +1,5 @@
-use object::read::{Object, ObjectSection};
-use object::{read, write};
+use object::read::{Object, ObjectSection, ObjectSymbol};
+use object::{pe, read, write};
 use object::{
     Architecture, BinaryFormat, Endianness, RelocationEncoding, RelocationKind, SymbolFlags,
     SymbolKind, SymbolScope,
@@ -54,3 +54,135 @@ fn reloc_overflow() {
     }
     assert_eq!(i, 0x10000);
 }
+
+#[test]
+fn weak_external() {
+    let mut object =
+        write::Object::new(BinaryFormat::Coff, Architecture::X86_64, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3], 1);
+    let default_symbol = object.add_symbol(write::Symbol {
+        name: b"f_default".to_vec(),
+        value: 0,
+        size: 1,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(text),
+        flags: SymbolFlags::None,
+    });
+    object.add_symbol(write::Symbol {
+        name: b"f".to_vec(),
+        value: 0,
+        size: 0,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: true,
+        section: write::SymbolSection::Undefined,
+        flags: SymbolFlags::CoffSymbol {
+            typ: pe::IMAGE_SYM_DTYPE_FUNCTION << pe::IMAGE_SYM_DTYPE_SHIFT,
+            storage_class: pe::IMAGE_SYM_CLASS_WEAK_EXTERNAL,
+            weak_default_symbol: Some(default_symbol),
+            weak_search_type: pe::IMAGE_WEAK_EXTERN_SEARCH_ALIAS.into(),
+        },
+    });
+    let bytes = object.write().unwrap();
+
+    let object = read::File::parse(&*bytes).unwrap();
+    let default_symbol = object
+        .symbols()
+        .find(|symbol| symbol.name() == Ok("f_default"))
+        .unwrap();
+    let weak_symbol = object
+        .symbols()
+        .find(|symbol| symbol.name() == Ok("f"))
+        .unwrap();
+    assert!(weak_symbol.is_weak());
+    assert_eq!(
+        weak_symbol.flags(),
+        SymbolFlags::CoffSymbol {
+            typ: pe::IMAGE_SYM_DTYPE_FUNCTION << pe::IMAGE_SYM_DTYPE_SHIFT,
+            storage_class: pe::IMAGE_SYM_CLASS_WEAK_EXTERNAL,
+            weak_default_symbol: Some(default_symbol.index()),
+            weak_search_type: pe::IMAGE_WEAK_EXTERN_SEARCH_ALIAS.into(),
+        }
+    );
+}
+
+#[test]
+fn long_section_names() {
+    let build = |long_section_names| {
+        let mut object =
+            write::Object::new(BinaryFormat::Coff, Architecture::X86_64, Endianness::Little);
+        object.set_coff_long_section_names(long_section_names);
+        let section = object.add_section(
+            Vec::new(),
+            b".debug_info".to_vec(),
+            object::SectionKind::Debug,
+        );
+        object.append_section_data(section, &[1; 4], 1);
+        object.section_symbol(section);
+        (object, section)
+    };
+
+    let (object, section) = build(write::CoffLongSectionNames::StringTable);
+    assert_eq!(object.coff_section_name(section), Ok(&b".debug_info"[..]));
+    let bytes = object.write().unwrap();
+    let file = read::File::parse(&*bytes).unwrap();
+    assert!(file.section_by_name(".debug_info").is_some());
+
+    let (object, section) = build(write::CoffLongSectionNames::Truncate);
+    assert_eq!(object.coff_section_name(section), Ok(&b".debug_i"[..]));
+    let bytes = object.write().unwrap();
+    let file = read::File::parse(&*bytes).unwrap();
+    assert!(file.section_by_name(".debug_i").is_some());
+    let symbol = file
+        .symbols()
+        .find(|symbol| symbol.kind() == SymbolKind::Section)
+        .unwrap();
+    assert_eq!(symbol.name(), Ok(".debug_i"));
+
+    let (object, section) = build(write::CoffLongSectionNames::Error);
+    assert!(object.coff_section_name(section).is_err());
+    assert!(object.write().is_err());
+}
+
+#[test]
+fn section_symbol_checksum() {
+    use object::read::coff::CoffHeader;
+    use object::LittleEndian as LE;
+
+    let mut object =
+        write::Object::new(BinaryFormat::Coff, Architecture::X86_64, Endianness::Little);
+
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[1, 2, 3, 4], 4);
+    object.section_symbol(text);
+
+    // The section data of a bss section must not be accessed.
+    let bss = object.section_id(write::StandardSection::UninitializedData);
+    object.append_section_bss(bss, 16, 4);
+    object.section_symbol(bss);
+
+    let bytes = object.write().unwrap();
+
+    let mut offset = 0;
+    let header = pe::ImageFileHeader::parse(&*bytes, &mut offset).unwrap();
+    let symbols = header.symbols(&*bytes).unwrap();
+    let mut symbol_indices = object::read::File::parse(&*bytes)
+        .unwrap()
+        .symbols()
+        .filter(|symbol| symbol.kind() == SymbolKind::Section)
+        .map(|symbol| symbol.index().0)
+        .collect::<Vec<_>>();
+    assert_eq!(symbol_indices.len(), 2);
+    let bss_index = symbol_indices.pop().unwrap();
+    let text_index = symbol_indices.pop().unwrap();
+
+    let aux = symbols.aux_section(text_index).unwrap();
+    assert_eq!(aux.length.get(LE), 4);
+    assert_ne!(aux.check_sum.get(LE), 0);
+    let aux = symbols.aux_section(bss_index).unwrap();
+    assert_eq!(aux.length.get(LE), 16);
+    assert_eq!(aux.check_sum.get(LE), 0);
+}
@@ -1,11 +1,11 @@
 #![cfg(all(feature = "read", feature = "write"))]
 
-use object::pe;
 use object::read::{Object, ObjectComdat, ObjectSection, ObjectSymbol};
+use object::{macho, pe};
 use object::{read, write};
 use object::{
-    Architecture, BinaryFormat, ComdatKind, Endianness, SectionKind, SymbolFlags, SymbolKind,
-    SymbolScope,
+    Architecture, BinaryFormat, ComdatKind, Endianness, SectionFlags, SectionKind, SymbolFlags,
+    SymbolKind, SymbolScope,
 };
 
 #[test]
@@ -223,3 +223,129 @@ fn elf_x86_64_comdat() {
     assert_eq!(comdat_sections.next(), Some(section2_index));
     assert_eq!(comdat_sections.next(), None);
 }
+
+#[test]
+fn coff_x86_64_comdat_associative_chain() {
+    let mut object =
+        write::Object::new(BinaryFormat::Coff, Architecture::X86_64, Endianness::Little);
+
+    let (section1, offset) =
+        object.add_subsection(write::StandardSection::Text, b"s1", &[0, 1, 2, 3], 4);
+    object.section_symbol(section1);
+    let (section2, _) =
+        object.add_subsection(write::StandardSection::Data, b"s1", &[0, 1, 2, 3], 4);
+    let section2_symbol = object.section_symbol(section2);
+    object.symbol_mut(section2_symbol).flags = SymbolFlags::CoffSection {
+        selection: pe::IMAGE_COMDAT_SELECT_ASSOCIATIVE,
+        associative_section: Some(section1),
+    };
+    let (section3, _) = object.add_subsection(
+        write::StandardSection::ReadOnlyData,
+        b"s1",
+        &[0, 1, 2, 3],
+        4,
+    );
+    let section3_symbol = object.section_symbol(section3);
+    object.symbol_mut(section3_symbol).flags = SymbolFlags::CoffSection {
+        selection: pe::IMAGE_COMDAT_SELECT_ASSOCIATIVE,
+        associative_section: Some(section2),
+    };
+
+    let symbol = object.add_symbol(write::Symbol {
+        name: b"s1".to_vec(),
+        value: offset,
+        size: 4,
+        kind: SymbolKind::Data,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(section1),
+        flags: SymbolFlags::None,
+    });
+
+    object.add_comdat(write::Comdat {
+        kind: ComdatKind::Largest,
+        symbol,
+        sections: vec![section1],
+    });
+
+    let bytes = object.write().unwrap();
+
+    let object = read::coff::CoffFile::<&[u8]>::parse(&*bytes).unwrap();
+    let section_indices: Vec<_> = object.sections().map(|section| section.index()).collect();
+
+    let mut comdats = object.comdats();
+    let comdat = comdats.next().unwrap();
+    assert_eq!(comdat.kind(), ComdatKind::Largest);
+    assert_eq!(comdat.selection(), pe::IMAGE_COMDAT_SELECT_LARGEST);
+    assert_eq!(comdat.section(), section_indices[0]);
+    assert_eq!(comdat.name(), Ok("s1"));
+    assert!(comdat.sections().eq(section_indices));
+    assert!(comdats.next().is_none());
+}
+
+#[test]
+fn macho_x86_64_comdat() {
+    let mut object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3, 0xc3], 1);
+    object.add_symbol(write::Symbol {
+        name: b"f".to_vec(),
+        value: 0,
+        size: 1,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(text),
+        flags: SymbolFlags::None,
+    });
+    object.add_symbol(write::Symbol {
+        name: b"g".to_vec(),
+        value: 1,
+        size: 1,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: true,
+        section: write::SymbolSection::Section(text),
+        flags: SymbolFlags::None,
+    });
+
+    let coal = object.add_section(
+        b"__TEXT".to_vec(),
+        b"__textcoal_nt".to_vec(),
+        SectionKind::Text,
+    );
+    object.section_mut(coal).flags = SectionFlags::MachO {
+        flags: macho::S_COALESCED | macho::S_ATTR_PURE_INSTRUCTIONS,
+    };
+    object.append_section_data(coal, &[0xc3], 1);
+    object.add_symbol(write::Symbol {
+        name: b"h".to_vec(),
+        value: 0,
+        size: 1,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: true,
+        section: write::SymbolSection::Section(coal),
+        flags: SymbolFlags::None,
+    });
+
+    let bytes = object.write().unwrap();
+
+    let object = read::File::parse(&*bytes).unwrap();
+    let coal_index = object.section_by_name("__textcoal_nt").unwrap().index();
+
+    // The weak definition in `__text` is not a COMDAT.
+    let mut comdats = object.comdats();
+    let comdat = comdats.next().unwrap();
+    assert_eq!(comdat.kind(), ComdatKind::Any);
+    assert_eq!(comdat.name(), Ok("_h"));
+    let symbol = object.symbol_by_index(comdat.symbol()).unwrap();
+    assert_eq!(symbol.name(), Ok("_h"));
+    assert!(comdat.sections().eq([coal_index]));
+    assert!(comdats.next().is_none());
+}
@@ -1,7 +1,7 @@
 #![cfg(all(feature = "read", feature = "write"))]
 
 use object::read::{Object, ObjectSection, ObjectSymbol};
-use object::{read, write};
+use object::{macho, read, write, xcoff};
 use object::{
     Architecture, BinaryFormat, Endianness, SectionKind, SymbolFlags, SymbolKind, SymbolScope,
 };
@@ -146,7 +146,7 @@ fn elf_x86_64_common() {
     assert_eq!(symbol.scope(), SymbolScope::Linkage);
     assert_eq!(symbol.is_weak(), false);
     assert_eq!(symbol.is_undefined(), false);
-    assert_eq!(symbol.address(), 0);
+    assert_eq!(symbol.address(), 4);
     assert_eq!(symbol.size(), 4);
 
     let symbol = symbols.next().unwrap();
@@ -157,7 +157,7 @@ fn elf_x86_64_common() {
     assert_eq!(symbol.scope(), SymbolScope::Linkage);
     assert_eq!(symbol.is_weak(), false);
     assert_eq!(symbol.is_undefined(), false);
-    assert_eq!(symbol.address(), 0);
+    assert_eq!(symbol.address(), 8);
     assert_eq!(symbol.size(), 8);
 
     let symbol = symbols.next();
@@ -204,18 +204,7 @@ fn macho_x86_64_common() {
     assert_eq!(object.format(), BinaryFormat::MachO);
     assert_eq!(object.architecture(), Architecture::X86_64);
 
-    let mut sections = object.sections();
-
-    let common = sections.next().unwrap();
-    println!("{:?}", common);
-    let common_index = common.index();
-    assert_eq!(common.name(), Ok("__common"));
-    assert_eq!(common.segment_name(), Ok(Some("__DATA")));
-    assert_eq!(common.kind(), SectionKind::Common);
-    assert_eq!(common.size(), 16);
-    assert_eq!(common.data(), Ok(&[][..]));
-
-    let section = sections.next();
+    let section = object.sections().next();
     assert!(section.is_none(), "unexpected section {:?}", section);
 
     let mut symbols = object.symbols();
@@ -224,21 +213,138 @@ fn macho_x86_64_common() {
     println!("{:?}", symbol);
     assert_eq!(symbol.name(), Ok("_v1"));
     assert_eq!(symbol.kind(), SymbolKind::Data);
-    assert_eq!(symbol.section_index(), Some(common_index));
+    assert_eq!(symbol.section(), read::SymbolSection::Common);
     assert_eq!(symbol.scope(), SymbolScope::Linkage);
     assert_eq!(symbol.is_weak(), false);
     assert_eq!(symbol.is_undefined(), false);
     assert_eq!(symbol.address(), 0);
+    assert_eq!(symbol.size(), 4);
+    assert_eq!(
+        symbol.flags(),
+        SymbolFlags::MachO {
+            n_desc: macho::set_comm_align(0, 2)
+        }
+    );
 
     let symbol = symbols.next().unwrap();
     println!("{:?}", symbol);
     assert_eq!(symbol.name(), Ok("_v2"));
     assert_eq!(symbol.kind(), SymbolKind::Data);
-    assert_eq!(symbol.section_index(), Some(common_index));
+    assert_eq!(symbol.section(), read::SymbolSection::Common);
     assert_eq!(symbol.scope(), SymbolScope::Linkage);
     assert_eq!(symbol.is_weak(), false);
     assert_eq!(symbol.is_undefined(), false);
-    assert_eq!(symbol.address(), 8);
+    assert_eq!(symbol.address(), 0);
+    assert_eq!(symbol.size(), 8);
+    assert_eq!(
+        symbol.flags(),
+        SymbolFlags::MachO {
+            n_desc: macho::set_comm_align(0, 3)
+        }
+    );
+
+    let symbol = symbols.next();
+    assert!(symbol.is_none(), "unexpected symbol {:?}", symbol);
+}
+
+#[test]
+fn define_common_symbols() {
+    for (format, arch, endian) in [
+        (BinaryFormat::Coff, Architecture::X86_64, Endianness::Little),
+        (BinaryFormat::Elf, Architecture::X86_64, Endianness::Little),
+        (
+            BinaryFormat::MachO,
+            Architecture::X86_64,
+            Endianness::Little,
+        ),
+    ] {
+        let mut object = write::Object::new(format, arch, endian);
+        for (name, size, align) in [(&b"v1"[..], 4, 4), (b"v2", 8, 8)] {
+            let symbol = write::Symbol {
+                name: name.to_vec(),
+                value: 0,
+                size: 0,
+                kind: SymbolKind::Data,
+                scope: SymbolScope::Linkage,
+                weak: false,
+                section: write::SymbolSection::Undefined,
+                flags: SymbolFlags::None,
+            };
+            object.add_common_symbol(symbol, size, align);
+        }
+        object.define_common_symbols();
+        let bytes = object.write().unwrap();
+
+        let object = read::File::parse(&*bytes).unwrap();
+        let bss = object
+            .sections()
+            .find(|section| section.kind() == SectionKind::UninitializedData)
+            .unwrap();
+        assert_eq!(bss.size(), 16, "{:?}", format);
+
+        let symbols = object
+            .symbols()
+            .filter(|symbol| symbol.kind() == SymbolKind::Data)
+            .collect::<Vec<_>>();
+        assert_eq!(symbols.len(), 2, "{:?}", format);
+        for (symbol, offset) in symbols.iter().zip([0, 8]) {
+            assert_eq!(symbol.section_index(), Some(bss.index()), "{:?}", format);
+            assert_eq!(symbol.address(), bss.address() + offset, "{:?}", format);
+            assert!(!symbol.is_common());
+        }
+    }
+}
+
+#[test]
+fn xcoff_powerpc64_common() {
+    let mut object = write::Object::new(
+        BinaryFormat::Xcoff,
+        Architecture::PowerPc64,
+        Endianness::Big,
+    );
+
+    // An alignment that isn't a power of two is rounded up.
+    for (name, size, align) in [(&b"v1"[..], 4, 4), (b"v2", 12, 6)] {
+        let symbol = write::Symbol {
+            name: name.to_vec(),
+            value: 0,
+            size: 0,
+            kind: SymbolKind::Data,
+            scope: SymbolScope::Dynamic,
+            weak: false,
+            section: write::SymbolSection::Undefined,
+            flags: SymbolFlags::None,
+        };
+        object.add_common_symbol(symbol, size, align);
+    }
+
+    let bytes = object.write().unwrap();
+
+    //std::fs::write(&"common.o", &bytes).unwrap();
+
+    let object = read::File::parse(&*bytes).unwrap();
+    assert_eq!(object.format(), BinaryFormat::Xcoff);
+    assert_eq!(object.architecture(), Architecture::PowerPc64);
+
+    let mut symbols = object.symbols();
+
+    for (name, size, align) in [("v1", 4, 2), ("v2", 12, 3)] {
+        let symbol = symbols.next().unwrap();
+        println!("{:?}", symbol);
+        assert_eq!(symbol.name(), Ok(name));
+        assert_eq!(symbol.kind(), SymbolKind::Data);
+        assert_eq!(symbol.address(), 0);
+        assert_eq!(symbol.size(), size);
+        assert_eq!(
+            symbol.flags(),
+            SymbolFlags::Xcoff {
+                n_sclass: xcoff::C_EXT,
+                x_smtyp: align << 3 | xcoff::XTY_CM,
+                x_smclas: xcoff::XMC_RW,
+                containing_csect: None,
+            }
+        );
+    }
 
     let symbol = symbols.next();
     assert!(symbol.is_none(), "unexpected symbol {:?}", symbol);
@@ -0,0 +1,177 @@
+use object::read::{Object, ObjectSection, ObjectSymbol};
+use object::{read, write};
+use object::{
+    Architecture, BinaryFormat, Endianness, RelocationEncoding, RelocationKind, RelocationTarget,
+    SymbolFlags, SymbolKind, SymbolScope,
+};
+
+struct Hooks;
+
+impl<'data> write::CopyHooks<'data, &'data [u8]> for Hooks {
+    fn section_name(
+        &mut self,
+        _section: &read::Section<'data, '_>,
+        name: &[u8],
+    ) -> Option<Vec<u8>> {
+        if name == b".comment" {
+            None
+        } else {
+            Some(name.to_vec())
+        }
+    }
+
+    fn symbol_name(&mut self, _symbol: &read::Symbol<'data, '_>, name: &[u8]) -> Option<Vec<u8>> {
+        let mut name = name.to_vec();
+        if name == b"f" {
+            name.extend_from_slice(b"_renamed");
+        }
+        Some(name)
+    }
+}
+
+#[test]
+fn elf_x86_64_copy() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0; 8], 4);
+    let comment = object.add_section(Vec::new(), b".comment".to_vec(), object::SectionKind::Other);
+    object.append_section_data(comment, b"compiler\0", 1);
+    object.add_symbol(write::Symbol {
+        name: b"f".to_vec(),
+        value: 0,
+        size: 8,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(text),
+        flags: SymbolFlags::None,
+    });
+    let g = object.add_symbol(write::Symbol {
+        name: b"g".to_vec(),
+        value: 0,
+        size: 0,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Undefined,
+        flags: SymbolFlags::None,
+    });
+    object
+        .add_relocation(
+            text,
+            write::Relocation {
+                offset: 4,
+                size: 32,
+                kind: RelocationKind::Relative,
+                encoding: RelocationEncoding::Generic,
+                symbol: g,
+                addend: -4,
+            },
+        )
+        .unwrap();
+    let bytes = object.write().unwrap();
+
+    let in_object = read::File::parse(&*bytes).unwrap();
+    let out_object = write::Object::from_file_with_hooks(&in_object, &mut Hooks).unwrap();
+    let bytes = out_object.write().unwrap();
+
+    let object = read::File::parse(&*bytes).unwrap();
+    assert_eq!(object.format(), BinaryFormat::Elf);
+    assert_eq!(object.architecture(), Architecture::X86_64);
+    assert!(object.section_by_name(".comment").is_none());
+
+    let text = object.section_by_name(".text").unwrap();
+    assert_eq!(text.size(), 8);
+    let symbol = object
+        .symbols()
+        .find(|symbol| symbol.name() == Ok("f_renamed"))
+        .unwrap();
+    assert_eq!(symbol.kind(), SymbolKind::Text);
+    assert_eq!(symbol.address(), text.address());
+    assert_eq!(symbol.size(), 8);
+
+    let mut relocations = text.relocations();
+    let (offset, relocation) = relocations.next().unwrap();
+    assert!(relocations.next().is_none());
+    assert_eq!(offset, 4);
+    assert_eq!(relocation.kind(), RelocationKind::Relative);
+    assert_eq!(relocation.addend(), -4);
+    let target = match relocation.target() {
+        RelocationTarget::Symbol(index) => object.symbol_by_index(index).unwrap(),
+        target => panic!("unexpected target {:?}", target),
+    };
+    assert_eq!(target.name(), Ok("g"));
+    assert!(target.is_undefined());
+}
+
+struct RebindHooks;
+
+impl<'data> write::CopyHooks<'data, &'data [u8]> for RebindHooks {
+    fn update_symbol(&mut self, _symbol: &read::Symbol<'data, '_>, out: &mut write::Symbol) {
+        match &*out.name {
+            b"local" => out.set_scope(SymbolScope::Compilation),
+            b"hidden" => out.set_scope(SymbolScope::Linkage),
+            b"weak" => out.set_weak(true),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn elf_x86_64_copy_rebind() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0; 12], 4);
+    for (i, name) in ["local", "hidden", "weak"].iter().enumerate() {
+        object.add_symbol(write::Symbol {
+            name: name.as_bytes().to_vec(),
+            value: i as u64 * 4,
+            size: 4,
+            kind: SymbolKind::Text,
+            scope: SymbolScope::Dynamic,
+            weak: false,
+            section: write::SymbolSection::Section(text),
+            flags: SymbolFlags::None,
+        });
+    }
+    let bytes = object.write().unwrap();
+
+    let in_object = read::File::parse(&*bytes).unwrap();
+    let out_object = write::Object::from_file_with_hooks(&in_object, &mut RebindHooks).unwrap();
+    let bytes = out_object.write().unwrap();
+
+    let object = read::File::parse(&*bytes).unwrap();
+    let symbol = |name| {
+        object
+            .symbols()
+            .find(|symbol| symbol.name() == Ok(name))
+            .unwrap()
+    };
+
+    let local = symbol("local");
+    assert_eq!(local.scope(), SymbolScope::Compilation);
+    assert!(local.is_local());
+    assert!(!local.is_weak());
+    assert_eq!(local.address(), 0);
+
+    let hidden = symbol("hidden");
+    assert_eq!(hidden.scope(), SymbolScope::Linkage);
+    assert!(hidden.is_global());
+    assert!(!hidden.is_weak());
+
+    let weak = symbol("weak");
+    assert_eq!(weak.scope(), SymbolScope::Dynamic);
+    assert!(weak.is_weak());
+    assert_eq!(weak.address(), 8);
+
+    // Local symbols must precede global symbols.
+    let first_global = object
+        .symbols()
+        .position(|symbol| symbol.is_global())
+        .unwrap();
+    assert!(object.symbols().skip(first_global).all(|s| s.is_global()));
+}
@@ -0,0 +1,86 @@
+use object::read::{Object, ObjectSymbol};
+use object::{read, write};
+use object::{
+    Architecture, BinaryFormat, Endianness, RelocationEncoding, RelocationKind, SymbolFlags,
+    SymbolKind, SymbolScope,
+};
+
+fn object(format: BinaryFormat, reverse: bool) -> Vec<u8> {
+    let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+    object.deterministic = true;
+
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0; 32], 4);
+
+    let mut names: Vec<&[u8]> = vec![b"func1", b"func2_long_name", b"local"];
+    if reverse {
+        names.reverse();
+    }
+    for name in names {
+        let scope = if name == b"local" {
+            SymbolScope::Compilation
+        } else {
+            SymbolScope::Linkage
+        };
+        object.add_symbol(write::Symbol {
+            name: name.to_vec(),
+            value: name.len() as u64,
+            size: 0,
+            kind: SymbolKind::Text,
+            scope,
+            weak: false,
+            section: write::SymbolSection::Section(text),
+            flags: SymbolFlags::None,
+        });
+    }
+
+    let mut imports: Vec<&[u8]> = vec![b"import1", b"import2"];
+    if reverse {
+        imports.reverse();
+    }
+    for name in imports {
+        let symbol = object.add_symbol(write::Symbol {
+            name: name.to_vec(),
+            value: 0,
+            size: 0,
+            kind: SymbolKind::Text,
+            scope: SymbolScope::Dynamic,
+            weak: false,
+            section: write::SymbolSection::Undefined,
+            flags: SymbolFlags::None,
+        });
+        let offset = if name == b"import1" { 0 } else { 8 };
+        object
+            .add_relocation(
+                text,
+                write::Relocation {
+                    offset,
+                    size: 32,
+                    kind: RelocationKind::Relative,
+                    encoding: RelocationEncoding::Generic,
+                    symbol,
+                    addend: -4,
+                },
+            )
+            .unwrap();
+    }
+
+    object.write().unwrap()
+}
+
+#[test]
+fn deterministic_symbols() {
+    for format in [BinaryFormat::Coff, BinaryFormat::Elf, BinaryFormat::MachO] {
+        let bytes = object(format, false);
+        assert_eq!(bytes, object(format, true), "{:?}", format);
+
+        let object = read::File::parse(&*bytes).unwrap();
+        let names: Vec<_> = object
+            .symbols()
+            .filter(|symbol| symbol.kind() != SymbolKind::Section)
+            .filter_map(|symbol| symbol.name().ok())
+            .filter(|name| !name.is_empty())
+            .collect();
+        assert_eq!(names.len(), 5, "{:?}", format);
+    }
+}
@@ -0,0 +1,179 @@
+use object::read::dump::{self, DumpNode, DumpValue};
+use object::write;
+use object::{Architecture, BinaryFormat, Endianness, SymbolFlags, SymbolKind, SymbolScope};
+
+fn object(format: BinaryFormat, architecture: Architecture) -> Vec<u8> {
+    let mut object = write::Object::new(format, architecture, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3; 16], 16);
+    object.add_symbol(write::Symbol {
+        name: b"a_function_with_a_long_name".to_vec(),
+        value: 4,
+        size: 12,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(text),
+        flags: SymbolFlags::None,
+    });
+    object.write().unwrap()
+}
+
+/// Check that the raw bytes of every node match its offset and value.
+fn check_raw(data: &[u8], node: &DumpNode<'_>) {
+    let offset = node.offset() as usize;
+    let raw = node.raw();
+    assert_eq!(&data[offset..][..raw.len()], raw, "{}", node.name());
+    let value = match node.value() {
+        DumpValue::Decimal(value)
+        | DumpValue::Hex(value)
+        | DumpValue::Enum(value, _)
+        | DumpValue::Flags(value, _) => Some(*value),
+        _ => None,
+    };
+    // Fields that reference a string table may be stored as text.
+    if let (Some(value), true) = (value, node.children().is_empty()) {
+        let mut bytes = [0; 8];
+        bytes[..raw.len()].copy_from_slice(raw);
+        let le = u64::from_le_bytes(bytes);
+        bytes[..raw.len()].reverse();
+        let be = u64::from_le_bytes(bytes);
+        // The Mach-O magic is always read as big endian.
+        assert!(value == le || value == be, "{}", node.name());
+    }
+    for child in node.children() {
+        check_raw(data, child);
+    }
+}
+
+fn find<'a, 'data>(node: &'a DumpNode<'data>, path: &[&str]) -> &'a DumpNode<'data> {
+    path.iter().fold(node, |node, name| {
+        node.child(name)
+            .unwrap_or_else(|| panic!("missing {}", name))
+    })
+}
+
+#[test]
+fn dump_elf() {
+    let data = object(BinaryFormat::Elf, Architecture::X86_64);
+    let root = dump::parse(&data).unwrap();
+    check_raw(&data, &root);
+    assert_eq!(root.raw().len(), data.len());
+
+    let header = root.child("FileHeader").unwrap();
+    assert_eq!(header.offset(), 0);
+    assert_eq!(header.raw().len(), 64);
+    let magic = find(header, &["Ident", "Magic"]);
+    assert_eq!(magic.value(), &DumpValue::Bytes(b"\x7fELF"));
+    let machine = header.child("Machine").unwrap();
+    assert_eq!(machine.offset(), 18);
+    assert_eq!(machine.raw(), &[62, 0]);
+    assert_eq!(machine.value(), &DumpValue::Enum(62, "EM_X86_64"));
+    let count = header.child("SectionHeaderStringTableIndex").unwrap();
+    assert_eq!(count.offset(), 62);
+
+    let text = root
+        .child("SectionHeaders")
+        .unwrap()
+        .children()
+        .iter()
+        .find(|section| section.value() == &DumpValue::String(b".text"))
+        .unwrap();
+    let name = find(text, &["Name", "String"]);
+    assert_eq!(name.raw(), b".text");
+    match text.child("Flags").unwrap().value() {
+        DumpValue::Flags(_, names) => assert_eq!(names, &["SHF_ALLOC", "SHF_EXECINSTR"]),
+        value => panic!("unexpected flags {:?}", value),
+    }
+
+    let symbols = root.child("SymbolTable").unwrap();
+    assert_eq!(symbols.value(), &DumpValue::String(b".symtab"));
+    assert!(symbols
+        .children()
+        .iter()
+        .any(|symbol| symbol.value() == &DumpValue::String(b"a_function_with_a_long_name")));
+
+    let text = root.to_string();
+    assert!(text.starts_with("File {\n    FileHeader {\n        Ident {\n"));
+    assert!(text.contains("        Machine: EM_X86_64 (0x3E)\n"));
+}
+
+#[test]
+fn dump_elf32() {
+    let data = object(BinaryFormat::Elf, Architecture::I386);
+    let root = dump::parse(&data).unwrap();
+    check_raw(&data, &root);
+
+    let header = root.child("FileHeader").unwrap();
+    assert_eq!(header.raw().len(), 52);
+    let section = find(&root, &["SectionHeaders", "SectionHeader"]);
+    assert_eq!(section.raw().len(), 40);
+    let symbol = find(&root, &["SymbolTable", "Symbol"]);
+    assert_eq!(symbol.raw().len(), 16);
+    assert_eq!(symbol.child("Size").unwrap().offset(), symbol.offset() + 8);
+}
+
+#[test]
+fn dump_macho() {
+    let data = object(BinaryFormat::MachO, Architecture::X86_64);
+    let root = dump::parse(&data).unwrap();
+    check_raw(&data, &root);
+
+    let header = root.child("MachHeader").unwrap();
+    assert_eq!(header.raw().len(), 32);
+    assert_eq!(
+        header.child("Reserved").unwrap().value(),
+        &DumpValue::Bytes(&[0; 4])
+    );
+    let segment = root
+        .children()
+        .iter()
+        .find(|command| command.value() == &DumpValue::Enum(0x19, "LC_SEGMENT_64"))
+        .unwrap();
+    let section = segment.child("Section").unwrap();
+    assert_eq!(section.value(), &DumpValue::String(b"__text"));
+    assert_eq!(section.raw().len(), 80);
+    assert_eq!(
+        section.child("SegmentName").unwrap().value(),
+        &DumpValue::String(b"__TEXT")
+    );
+
+    let symbols = root.child("SymbolTable").unwrap();
+    assert!(symbols
+        .children()
+        .iter()
+        .any(|symbol| symbol.value() == &DumpValue::String(b"_a_function_with_a_long_name")));
+}
+
+#[test]
+fn dump_coff() {
+    let data = object(BinaryFormat::Coff, Architecture::X86_64);
+    let root = dump::parse(&data).unwrap();
+    check_raw(&data, &root);
+
+    let header = root.child("FileHeader").unwrap();
+    assert_eq!(
+        header.child("Machine").unwrap().value(),
+        &DumpValue::Enum(0x8664, "IMAGE_FILE_MACHINE_AMD64")
+    );
+    let section = find(&root, &["SectionHeaders", "SectionHeader"]);
+    assert_eq!(section.value(), &DumpValue::String(b".text"));
+
+    // Long symbol names are stored in the string table.
+    let symbol = root
+        .child("SymbolTable")
+        .unwrap()
+        .children()
+        .iter()
+        .find(|symbol| symbol.value() == &DumpValue::String(b"a_function_with_a_long_name"))
+        .unwrap();
+    assert_eq!(symbol.child("Zeroes").unwrap().raw(), &[0; 4]);
+    let name = find(symbol, &["Name", "String"]);
+    assert_eq!(name.raw(), b"a_function_with_a_long_name");
+}
+
+#[test]
+fn dump_unsupported() {
+    let data = object(BinaryFormat::Xcoff, Architecture::PowerPc64);
+    assert!(dump::parse(&data).is_err());
+}
@@ -0,0 +1,65 @@
+use object::read::{Object, ObjectSection};
+use object::{read, write, Architecture, BinaryFormat, Endianness, SectionKind};
+
+#[test]
+fn dwarf_section_offset() {
+    for format in [BinaryFormat::Coff, BinaryFormat::Elf, BinaryFormat::MachO] {
+        let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+
+        let debug_str = object.add_dwarf_section(b".debug_str");
+        object.append_section_data(debug_str, b"hello\0world\0", 1);
+        let debug_info = object.add_dwarf_section(b".debug_info");
+        object.append_section_data(debug_info, &[0; 8], 1);
+        assert_eq!(object.add_dwarf_section(b".debug_info"), debug_info);
+        object
+            .add_dwarf_section_offset(debug_info, 4, 32, debug_str, 6)
+            .unwrap();
+
+        let bytes = object.write().unwrap();
+        let object = read::File::parse(&*bytes).unwrap();
+        let dwarf = read::DwarfSections::load(&object).unwrap();
+        assert_eq!(
+            dwarf.get(".debug_info"),
+            &[0, 0, 0, 0, 6, 0, 0, 0],
+            "{:?}",
+            format
+        );
+        assert_eq!(dwarf.get(".debug_str"), b"hello\0world\0", "{:?}", format);
+
+        if format == BinaryFormat::MachO {
+            let section = object.section_by_name(".debug_info").unwrap();
+            assert_eq!(section.kind(), SectionKind::Debug);
+            assert_eq!(section.segment_name(), Ok(Some("__DWARF")));
+            assert_eq!(section.relocations().count(), 0);
+        }
+    }
+}
+
+#[test]
+fn dwarf_package_index() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+    let info = object.add_dwarf_section(b".debug_info.dwo");
+    object.append_section_data(info, &[0; 0x20], 1);
+    let mut index = write::DwarfPackageIndex::new(5);
+    index.add_unit(
+        0x1234,
+        &[write::DwarfContribution {
+            section: 1,
+            offset: 0,
+            size: 0x20,
+        }],
+    );
+    let cu_index = object.add_dwarf_section(b".debug_cu_index");
+    object.set_section_data(cu_index, index.write(Endianness::Little).unwrap(), 4);
+
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    let section = object.section_by_name(".debug_cu_index").unwrap();
+    let data = section.data().unwrap();
+    // Header, one slot of signature and index, section identifiers, offsets and sizes.
+    assert_eq!(data.len(), 16 + 2 * 12 + 3 * 4);
+    assert_eq!(&data[..4], &[5, 0, 0, 0]);
+    assert_eq!(&data[16..24], &0x1234u64.to_le_bytes());
+}
@@ -4,6 +4,7 @@ use object::{
     elf, read, write, Architecture, BinaryFormat, Endianness, LittleEndian, SectionIndex,
     SectionKind, SymbolFlags, SymbolKind, SymbolScope, SymbolSection, U32,
 };
+use std::convert::TryInto;
 use std::io::Write;
 
 #[test]
@@ -73,6 +74,53 @@ fn aligned_sections() {
     assert_eq!(section.file_range(), Some((4096, 4)));
 }
 
+#[test]
+fn merge_strings() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+    // The standard section isn't mergeable, for compatibility.
+    let rodata = object.section_id(write::StandardSection::ReadOnlyString);
+    assert_eq!(object.section(rodata).name(), Some(".rodata"));
+
+    let rodata = object.add_section(
+        Vec::new(),
+        b".rodata.str1.1".to_vec(),
+        SectionKind::ReadOnlyString,
+    );
+    let (symbol, hello) = object.add_section_string(rodata, b"hello");
+    assert_eq!(object.add_section_string(rodata, b"world"), (symbol, 6));
+    assert_eq!(object.add_section_string(rodata, b"hello"), (symbol, hello));
+
+    let wide = object.add_section(
+        Vec::new(),
+        b".rodata.str2.2".to_vec(),
+        SectionKind::ReadOnlyString,
+    );
+    object.section_mut(wide).set_entsize(2);
+    object.add_section_string(wide, b"h\0i\0");
+    assert_eq!(object.add_section_string(wide, b"h\0i\0").1, 0);
+
+    let bytes = &*object.write().unwrap();
+
+    let header = elf::FileHeader64::parse(bytes).unwrap();
+    let endian: LittleEndian = header.endian().unwrap();
+    let sections = header.sections(endian, bytes).unwrap();
+
+    let (_, section) = sections.section_by_name(endian, b".rodata.str1.1").unwrap();
+    assert_eq!(
+        section.sh_flags(endian),
+        u64::from(elf::SHF_ALLOC | elf::SHF_MERGE | elf::SHF_STRINGS)
+    );
+    assert_eq!(section.sh_entsize(endian), 1);
+    assert_eq!(section.data(endian, bytes), Ok(&b"hello\0world\0"[..]));
+
+    let (_, section) = sections.section_by_name(endian, b".rodata.str2.2").unwrap();
+    assert_eq!(section.sh_addralign(endian), 2);
+    assert_eq!(section.sh_entsize(endian), 2);
+    assert_eq!(section.data(endian, bytes), Ok(&b"h\0i\0\0\0"[..]));
+}
+
 #[cfg(feature = "compression")]
 #[test]
 fn compression_zlib() {
@@ -248,6 +296,81 @@ fn note() {
     assert!(notes.next().unwrap().is_none());
 }
 
+#[test]
+fn os_notes() {
+    use object::read::elf::OsNote;
+
+    let endian = Endianness::Little;
+    let mut object = write::Object::new(BinaryFormat::Elf, Architecture::X86_64, endian);
+
+    let mut buffer = Vec::new();
+    let mut add_note = |name: &[u8], n_type: u32, desc: &[u8]| {
+        buffer.extend_from_slice(object::bytes_of(&elf::NoteHeader32 {
+            n_namesz: U32::new(endian, name.len() as u32 + 1),
+            n_descsz: U32::new(endian, desc.len() as u32),
+            n_type: U32::new(endian, n_type),
+        }));
+        buffer.extend_from_slice(name);
+        buffer.push(0);
+        buffer.resize((buffer.len() + 3) & !3, 0);
+        buffer.extend_from_slice(desc);
+        buffer.resize((buffer.len() + 3) & !3, 0);
+    };
+    add_note(
+        elf::ELF_NOTE_FREEBSD,
+        elf::NT_FREEBSD_ABI_TAG,
+        &1_400_000u32.to_le_bytes(),
+    );
+    add_note(elf::ELF_NOTE_FREEBSD, elf::NT_FREEBSD_ARCH_TAG, b"amd64\0");
+    add_note(
+        elf::ELF_NOTE_FREEBSD,
+        elf::NT_FREEBSD_FEATURE_CTL,
+        &elf::NT_FREEBSD_FCTL_WXNEEDED.to_le_bytes(),
+    );
+    add_note(elf::ELF_NOTE_NETBSD, elf::NT_NETBSD_MARCH, b"earmv7hf\0");
+    add_note(
+        elf::ELF_NOTE_PAX,
+        elf::NT_NETBSD_PAX,
+        &elf::NT_NETBSD_PAX_NOMPROTECT.to_le_bytes(),
+    );
+    add_note(
+        elf::ELF_NOTE_OPENBSD,
+        elf::NT_OPENBSD_IDENT,
+        &0u32.to_le_bytes(),
+    );
+    add_note(elf::ELF_NOTE_GNU, elf::NT_GNU_BUILD_ID, &[1; 20]);
+    add_note(elf::ELF_NOTE_NETBSD, elf::NT_NETBSD_IDENT, &[]);
+
+    let section = object.add_section(Vec::new(), b".note".to_vec(), SectionKind::Note);
+    object.section_mut(section).set_data(buffer, 4);
+    let bytes = &*object.write().unwrap();
+
+    let header = elf::FileHeader64::parse(bytes).unwrap();
+    let endian: LittleEndian = header.endian().unwrap();
+    let sections = header.sections(endian, bytes).unwrap();
+    let section = sections.section(SectionIndex(1)).unwrap();
+    let mut notes = section.notes(endian, bytes).unwrap().unwrap();
+    let mut os_notes = Vec::new();
+    while let Some(note) = notes.next().unwrap() {
+        os_notes.push(note.os_note(endian).ok());
+    }
+    assert_eq!(
+        os_notes,
+        [
+            Some(Some(OsNote::FreeBsdAbiTag(1_400_000))),
+            Some(Some(OsNote::FreeBsdArch(b"amd64"))),
+            Some(Some(OsNote::FreeBsdFeatureControl(
+                elf::NT_FREEBSD_FCTL_WXNEEDED
+            ))),
+            Some(Some(OsNote::NetBsdMarch(b"earmv7hf"))),
+            Some(Some(OsNote::NetBsdPax(elf::NT_NETBSD_PAX_NOMPROTECT))),
+            Some(Some(OsNote::OpenBsdIdent(0))),
+            Some(None),
+            None,
+        ]
+    );
+}
+
 #[test]
 fn gnu_property() {
     gnu_property_inner::<elf::FileHeader32<Endianness>>(Architecture::I386);
@@ -287,3 +410,773 @@ fn gnu_property_inner<Elf: FileHeader<Endian = Endianness>>(architecture: Archit
     assert!(props.next().unwrap().is_none());
     assert!(notes.next().unwrap().is_none());
 }
+
+#[test]
+fn dwarf_sections_relocated() {
+    for arch in [Architecture::X86_64, Architecture::I386] {
+        let mut object = write::Object::new(BinaryFormat::Elf, arch, Endianness::Little);
+
+        let debug_str = object.add_section(vec![], b".debug_str".to_vec(), SectionKind::Debug);
+        object.append_section_data(debug_str, b"hello\0world\0", 1);
+        let debug_info = object.add_section(vec![], b".debug_info".to_vec(), SectionKind::Debug);
+        object.append_section_data(debug_info, &[0; 8], 1);
+        let symbol = object.section_symbol(debug_str);
+        object
+            .add_relocation(
+                debug_info,
+                write::Relocation {
+                    offset: 4,
+                    size: 32,
+                    kind: object::RelocationKind::Absolute,
+                    encoding: object::RelocationEncoding::Generic,
+                    symbol,
+                    addend: 6,
+                },
+            )
+            .unwrap();
+
+        let bytes = object.write().unwrap();
+        let object = read::File::parse(&*bytes).unwrap();
+        let dwarf = read::DwarfSections::load(&object).unwrap();
+        assert_eq!(dwarf.get(".debug_info"), &[0, 0, 0, 0, 6, 0, 0, 0]);
+        assert_eq!(dwarf.get(".debug_str"), b"hello\0world\0");
+        assert_eq!(dwarf.get(".debug_line"), &[]);
+    }
+}
+
+#[test]
+fn section_kinds() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+    let kinds = [
+        (&b".init_array"[..], SectionKind::InitArray),
+        (b".fini_array", SectionKind::FiniArray),
+        (b".eh_frame", SectionKind::Unwind),
+    ];
+    for (name, kind) in kinds {
+        let section = object.add_section(vec![], name.to_vec(), kind);
+        object.append_section_data(section, &[0; 8], 8);
+    }
+
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    for (name, kind) in kinds {
+        let section = object.section_by_name_bytes(name).unwrap();
+        assert_eq!(section.kind(), kind);
+    }
+}
+
+#[test]
+fn security_features() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::Aarch64, Endianness::Little);
+    object.add_elf_gnu_property_u32(
+        elf::GNU_PROPERTY_AARCH64_FEATURE_1_AND,
+        elf::GNU_PROPERTY_AARCH64_FEATURE_1_BTI,
+    );
+    object.add_symbol(write::Symbol {
+        name: b"__stack_chk_fail".to_vec(),
+        value: 0,
+        size: 0,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Dynamic,
+        weak: false,
+        section: write::SymbolSection::Undefined,
+        flags: SymbolFlags::None,
+    });
+
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    let features = object.security_features();
+    assert_eq!(features.branch_protection(), Some(true));
+    assert_eq!(features.pointer_authentication(), Some(false));
+    assert_eq!(features.shadow_stack(), None);
+    assert_eq!(features.stack_canary(), Some(true));
+    // Not applicable to relocatable files.
+    assert_eq!(features.nx(), None);
+    assert_eq!(features.relro(), None);
+}
+
+#[test]
+fn large_alignment() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+    let text = object.add_section(vec![], b".text.huge".to_vec(), SectionKind::Text);
+    object.append_section_data(text, &[0xcc; 16], 0x20_0000);
+
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    let section = object.section_by_name(".text.huge").unwrap();
+    assert_eq!(section.align(), 0x20_0000);
+    assert_eq!(section.data(), Ok(&[0xcc; 16][..]));
+
+    let mut object = write::Object::new(BinaryFormat::Elf, Architecture::I386, Endianness::Little);
+    let text = object.add_section(vec![], b".text".to_vec(), SectionKind::Text);
+    object.section_mut(text).set_data(&[][..], 1 << 32);
+    assert!(object.write().is_err());
+}
+
+#[test]
+fn sort_sections_and_symbols() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+    let text_b = object.add_section(vec![], b".text.b".to_vec(), SectionKind::Text);
+    object.append_section_data(text_b, &[0; 8], 4);
+    let text_a = object.add_section(vec![], b".text.a".to_vec(), SectionKind::Text);
+    object.append_section_data(text_a, &[0; 8], 4);
+
+    let mut add_symbol = |name: &[u8], section| {
+        object.add_symbol(write::Symbol {
+            name: name.to_vec(),
+            value: 0,
+            size: 8,
+            kind: SymbolKind::Text,
+            scope: SymbolScope::Linkage,
+            weak: false,
+            section: write::SymbolSection::Section(section),
+            flags: SymbolFlags::None,
+        })
+    };
+    let func_b = add_symbol(b"func_b", text_b);
+    let func_a = add_symbol(b"func_a", text_a);
+    object
+        .add_relocation(
+            text_a,
+            write::Relocation {
+                offset: 0,
+                size: 32,
+                kind: object::RelocationKind::Relative,
+                encoding: object::RelocationEncoding::Generic,
+                symbol: func_b,
+                addend: -4,
+            },
+        )
+        .unwrap();
+
+    let sections = object.sort_sections_by_key(|_, section| section.name().map(String::from));
+    assert_eq!(sections[&text_a], text_b);
+    assert_eq!(sections[&text_b], text_a);
+    let symbols = object.sort_symbols_by_key(|_, symbol| symbol.name().map(String::from));
+    assert_eq!(symbols[&func_a], func_b);
+    assert_eq!(object.symbol_id(b"func_b"), Some(symbols[&func_b]));
+
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    let section_names: Vec<_> = object
+        .sections()
+        .filter_map(|section| section.name().ok().map(String::from))
+        .filter(|name| name.starts_with(".text"))
+        .collect();
+    assert_eq!(section_names, [".text.a", ".text.b"]);
+    let symbols: Vec<_> = object
+        .symbols()
+        .filter(|symbol| symbol.kind() == SymbolKind::Text)
+        .collect();
+    assert_eq!(symbols[0].name(), Ok("func_a"));
+    assert_eq!(symbols[1].name(), Ok("func_b"));
+    for (symbol, section_name) in symbols.iter().zip([".text.a", ".text.b"]) {
+        let index = symbol.section_index().unwrap();
+        let section = object.section_by_index(index).unwrap();
+        assert_eq!(section.name(), Ok(section_name));
+    }
+
+    let section = object.section_by_name(".text.a").unwrap();
+    let (_, relocation) = section.relocations().next().unwrap();
+    let target = match relocation.target() {
+        read::RelocationTarget::Symbol(index) => object.symbol_by_index(index).unwrap(),
+        target => panic!("unexpected relocation target {:?}", target),
+    };
+    assert_eq!(target.name(), Ok("func_b"));
+}
+
+#[test]
+fn plt_x86_64() {
+    let mut buffer = Vec::new();
+    let mut writer = write::elf::Writer::new(Endianness::Little, true, &mut buffer);
+    writer.set_plt(elf::EM_X86_64, 2).unwrap();
+    let puts_name = writer.add_dynamic_string(b"puts");
+    let exit_name = writer.add_dynamic_string(b"exit");
+
+    writer.reserve_file_header();
+    writer.reserve_null_section_index();
+    let plt_index = writer.reserve_plt_section_index();
+    writer.reserve_got_plt_section_index();
+    writer.reserve_rela_plt_section_index();
+    writer.reserve_dynsym_section_index();
+    writer.reserve_dynstr_section_index();
+    writer.reserve_shstrtab_section_index();
+    writer.reserve_null_dynamic_symbol_index();
+    let puts = writer.reserve_dynamic_symbol_index();
+    let exit = writer.reserve_dynamic_symbol_index();
+    // Use the file offsets as addresses.
+    let plt = writer.reserve_plt() as u64;
+    let got_plt = writer.reserve_got_plt() as u64;
+    let rela_plt = writer.reserve_rela_plt() as u64;
+    writer.reserve_dynsym();
+    writer.reserve_dynstr();
+    writer.reserve_shstrtab();
+    writer.reserve_section_headers();
+
+    writer
+        .write_file_header(&write::elf::FileHeader {
+            os_abi: elf::ELFOSABI_NONE,
+            abi_version: 0,
+            e_type: elf::ET_DYN,
+            e_machine: elf::EM_X86_64,
+            e_entry: 0,
+            e_flags: 0,
+        })
+        .unwrap();
+    writer.write_plt(plt, got_plt);
+    writer.write_got_plt(0, plt);
+    writer.write_rela_plt(got_plt, &[puts, exit]);
+    writer.write_null_dynamic_symbol();
+    for name in [puts_name, exit_name] {
+        writer.write_dynamic_symbol(&write::elf::Sym {
+            name: Some(name),
+            section: None,
+            st_info: (elf::STB_GLOBAL << 4) | elf::STT_FUNC,
+            st_other: 0,
+            st_shndx: 0,
+            st_value: 0,
+            st_size: 0,
+        });
+    }
+    writer.write_dynstr();
+    writer.write_shstrtab();
+    writer.write_null_section_header();
+    writer.write_plt_section_header(plt);
+    writer.write_got_plt_section_header(got_plt);
+    writer.write_rela_plt_section_header(rela_plt);
+    writer.write_dynsym_section_header(0, 1);
+    writer.write_dynstr_section_header(0);
+    writer.write_shstrtab_section_header();
+    assert_eq!(writer.reserved_len(), writer.len());
+
+    let file = read::elf::ElfFile64::<Endianness>::parse(&*buffer).unwrap();
+    let section = file.section_by_name(".plt").unwrap();
+    assert_eq!(section.index(), SectionIndex(plt_index.0 as usize));
+    assert_eq!(section.address(), plt);
+    let data = section.data().unwrap();
+    assert_eq!(data.len(), 48);
+    // The second stub jumps via the fifth GOT slot, pushes its index, and jumps to PLT0.
+    let stub = &data[32..];
+    let slot = i64::from(i32::from_le_bytes(stub[2..6].try_into().unwrap()));
+    assert_eq!((plt as i64 + 32 + 6 + slot) as u64, got_plt + 32);
+    assert_eq!(stub[6..11], [0x68, 1, 0, 0, 0]);
+    let resolver = i64::from(i32::from_le_bytes(stub[12..16].try_into().unwrap()));
+    assert_eq!(plt as i64 + 48 + resolver, plt as i64);
+
+    let got = file.section_by_name(".got.plt").unwrap().data().unwrap();
+    assert_eq!(got.len(), 40);
+    assert_eq!(got[32..], (plt + 32 + 6).to_le_bytes());
+
+    let relocations = file.dynamic_relocations().unwrap().collect::<Vec<_>>();
+    assert_eq!(relocations.len(), 2);
+    assert_eq!(relocations[1].0, got_plt + 32);
+    assert_eq!(
+        relocations[1].1.target(),
+        read::RelocationTarget::Symbol(read::SymbolIndex(exit.0 as usize))
+    );
+}
+
+#[test]
+fn plt_aarch64() {
+    let mut buffer = Vec::new();
+    let mut writer = write::elf::Writer::new(Endianness::Little, true, &mut buffer);
+    writer.set_plt(elf::EM_AARCH64, 2).unwrap();
+    let puts_name = writer.add_dynamic_string(b"puts");
+    let exit_name = writer.add_dynamic_string(b"exit");
+
+    writer.reserve_file_header();
+    writer.reserve_null_section_index();
+    writer.reserve_plt_section_index();
+    writer.reserve_got_plt_section_index();
+    writer.reserve_rela_plt_section_index();
+    writer.reserve_dynamic_section_index();
+    writer.reserve_dynsym_section_index();
+    writer.reserve_dynstr_section_index();
+    writer.reserve_shstrtab_section_index();
+    writer.reserve_null_dynamic_symbol_index();
+    let puts = writer.reserve_dynamic_symbol_index();
+    let exit = writer.reserve_dynamic_symbol_index();
+    writer.reserve_plt();
+    writer.reserve_got_plt();
+    writer.reserve_rela_plt();
+    writer.reserve_dynamic(5);
+    writer.reserve_dynsym();
+    writer.reserve_dynstr();
+    writer.reserve_shstrtab();
+    writer.reserve_section_headers();
+
+    // Place the GOT so that the slots are in a different page to the start of the GOT.
+    let plt = 0x1_0000;
+    let got_plt = 0x2_0ff0;
+    let rela_plt = 0x3_0000;
+    writer
+        .write_file_header(&write::elf::FileHeader {
+            os_abi: elf::ELFOSABI_NONE,
+            abi_version: 0,
+            e_type: elf::ET_DYN,
+            e_machine: elf::EM_AARCH64,
+            e_entry: 0,
+            e_flags: 0,
+        })
+        .unwrap();
+    writer.write_plt(plt, got_plt);
+    writer.write_got_plt(0, plt);
+    writer.write_rela_plt(got_plt, &[puts, exit]);
+    writer.write_align_dynamic();
+    writer.write_plt_dynamic(got_plt, rela_plt);
+    writer.write_dynamic(elf::DT_NULL, 0);
+    writer.write_null_dynamic_symbol();
+    for name in [puts_name, exit_name] {
+        writer.write_dynamic_symbol(&write::elf::Sym {
+            name: Some(name),
+            section: None,
+            st_info: (elf::STB_GLOBAL << 4) | elf::STT_FUNC,
+            st_other: 0,
+            st_shndx: 0,
+            st_value: 0,
+            st_size: 0,
+        });
+    }
+    writer.write_dynstr();
+    writer.write_shstrtab();
+    writer.write_null_section_header();
+    writer.write_plt_section_header(plt);
+    writer.write_got_plt_section_header(got_plt);
+    writer.write_rela_plt_section_header(rela_plt);
+    writer.write_dynamic_section_header(0);
+    writer.write_dynsym_section_header(0, 1);
+    writer.write_dynstr_section_header(0);
+    writer.write_shstrtab_section_header();
+    assert_eq!(writer.reserved_len(), writer.len());
+
+    let file = read::elf::ElfFile64::<Endianness>::parse(&*buffer).unwrap();
+    let data = file.section_by_name(".plt").unwrap().data().unwrap();
+    let insns = data
+        .chunks_exact(4)
+        .map(|insn| u32::from_le_bytes(insn.try_into().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(insns.len(), 16);
+
+    // Return the address of the GOT slot that is loaded by the `adrp` and `ldr` at `index`.
+    let slot = |index: usize| {
+        let address = plt + index as u64 * 4;
+        let adrp = insns[index];
+        let ldr = insns[index + 1];
+        let add = insns[index + 2];
+        assert_eq!(adrp & 0x9f00_001f, 0x9000_0010, "adrp x16");
+        assert_eq!(ldr & 0xffc0_03ff, 0xf940_0211, "ldr x17, [x16]");
+        assert_eq!(add & 0xffc0_03ff, 0x9100_0210, "add x16, x16");
+        assert_eq!(insns[index + 3], 0xd61f_0220, "br x17");
+        let imm = u64::from((adrp >> 29) & 0x3 | ((adrp >> 5) & 0x7_ffff) << 2);
+        // Sign extend the 21 bit page offset.
+        let page = ((imm << 43) as i64 >> 43) as u64;
+        let base = (address & !0xfff).wrapping_add(page << 12);
+        let offset = u64::from((ldr >> 10) & 0xfff) * 8;
+        assert_eq!(u64::from((add >> 10) & 0xfff), offset);
+        base + offset
+    };
+    // PLT0 saves the registers and jumps to the resolver in the third GOT slot.
+    assert_eq!(insns[0], 0xa9bf_7bf0);
+    assert_eq!(slot(1), got_plt + 16);
+    assert_eq!(insns[5..8], [0xd503_201f; 3]);
+    // Each stub jumps via its own GOT slot.
+    assert_eq!(slot(8), got_plt + 24);
+    assert_eq!(slot(12), got_plt + 32);
+
+    // Unresolved slots point to PLT0.
+    let got = file.section_by_name(".got.plt").unwrap().data().unwrap();
+    assert_eq!(got.len(), 40);
+    assert_eq!(got[24..32], plt.to_le_bytes());
+    assert_eq!(got[32..], plt.to_le_bytes());
+
+    let relocations = file.dynamic_relocations().unwrap().collect::<Vec<_>>();
+    assert_eq!(relocations.len(), 2);
+    assert_eq!(relocations[1].0, got_plt + 32);
+    assert_eq!(
+        relocations[1].1.kind(),
+        object::RelocationKind::Elf(elf::R_AARCH64_JUMP_SLOT)
+    );
+    assert_eq!(
+        relocations[1].1.target(),
+        read::RelocationTarget::Symbol(read::SymbolIndex(exit.0 as usize))
+    );
+
+    let endian = file.endian();
+    let sections = file.raw_header().sections(endian, &*buffer).unwrap();
+    let (dynamic, _) = sections.dynamic(endian, &*buffer).unwrap().unwrap();
+    let dynamic = dynamic
+        .iter()
+        .map(|d| (d.d_tag.get(endian) as u32, d.d_val.get(endian)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        dynamic,
+        [
+            (elf::DT_PLTGOT, got_plt),
+            (elf::DT_PLTRELSZ, 48),
+            (elf::DT_PLTREL, elf::DT_RELA.into()),
+            (elf::DT_JMPREL, rela_plt),
+            (elf::DT_NULL, 0),
+        ]
+    );
+}
+
+#[test]
+fn symbol_map_tables() {
+    let symtab_symbols: &[(&[u8], u64)] = &[(b"a", 0x10), (b"b", 0x20)];
+    let dynsym_symbols: &[(&[u8], u64)] = &[(b"a", 0x10), (b"c", 0x30), (b"d", 0x40)];
+
+    let mut buffer = Vec::new();
+    let mut writer = write::elf::Writer::new(Endianness::Little, true, &mut buffer);
+    let symtab_names: Vec<_> = symtab_symbols
+        .iter()
+        .map(|(name, _)| writer.add_string(name))
+        .collect();
+    let dynsym_names: Vec<_> = dynsym_symbols
+        .iter()
+        .map(|(name, _)| writer.add_dynamic_string(name))
+        .collect();
+
+    writer.reserve_file_header();
+    writer.reserve_null_section_index();
+    writer.reserve_symtab_section_index();
+    writer.reserve_strtab_section_index();
+    writer.reserve_dynsym_section_index();
+    writer.reserve_dynstr_section_index();
+    writer.reserve_shstrtab_section_index();
+    writer.reserve_null_symbol_index();
+    for _ in symtab_symbols {
+        writer.reserve_symbol_index(None);
+    }
+    writer.reserve_null_dynamic_symbol_index();
+    for _ in dynsym_symbols {
+        writer.reserve_dynamic_symbol_index();
+    }
+    writer.reserve_symtab();
+    writer.reserve_strtab();
+    writer.reserve_dynsym();
+    writer.reserve_dynstr();
+    writer.reserve_shstrtab();
+    writer.reserve_section_headers();
+
+    writer
+        .write_file_header(&write::elf::FileHeader {
+            os_abi: elf::ELFOSABI_NONE,
+            abi_version: 0,
+            e_type: elf::ET_DYN,
+            e_machine: elf::EM_X86_64,
+            e_entry: 0,
+            e_flags: 0,
+        })
+        .unwrap();
+    let sym = |name, st_value| write::elf::Sym {
+        name: Some(name),
+        section: None,
+        st_info: (elf::STB_GLOBAL << 4) | elf::STT_FUNC,
+        st_other: 0,
+        st_shndx: elf::SHN_ABS,
+        st_value,
+        st_size: 4,
+    };
+    writer.write_null_symbol();
+    for (name, (_, address)) in symtab_names.iter().zip(symtab_symbols) {
+        writer.write_symbol(&sym(*name, *address));
+    }
+    writer.write_strtab();
+    writer.write_null_dynamic_symbol();
+    for (name, (_, address)) in dynsym_names.iter().zip(dynsym_symbols) {
+        writer.write_dynamic_symbol(&sym(*name, *address));
+    }
+    writer.write_dynstr();
+    writer.write_shstrtab();
+    writer.write_null_section_header();
+    writer.write_symtab_section_header(1);
+    writer.write_strtab_section_header();
+    writer.write_dynsym_section_header(0, 1);
+    writer.write_dynstr_section_header(0);
+    writer.write_shstrtab_section_header();
+    assert_eq!(writer.reserved_len(), writer.len());
+
+    let file = read::File::parse(&*buffer).unwrap();
+    let names = |tables| {
+        file.symbol_map_from(tables)
+            .symbols()
+            .iter()
+            .map(|symbol| (symbol.name(), symbol.address()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        names(read::SymbolMapTables::Preferred),
+        [("a", 0x10), ("b", 0x20)]
+    );
+    assert_eq!(
+        names(read::SymbolMapTables::Largest),
+        [("a", 0x10), ("c", 0x30), ("d", 0x40)]
+    );
+    assert_eq!(
+        names(read::SymbolMapTables::All),
+        [("a", 0x10), ("b", 0x20), ("c", 0x30), ("d", 0x40)]
+    );
+    assert_eq!(
+        file.symbol_map().symbols(),
+        file.symbol_map_from(read::SymbolMapTables::Preferred)
+            .symbols()
+    );
+}
+
+#[test]
+fn imports_version() {
+    let mut buffer = Vec::new();
+    let mut writer = write::elf::Writer::new(Endianness::Little, true, &mut buffer);
+    let puts_name = writer.add_dynamic_string(b"puts");
+    let weak_name = writer.add_dynamic_string(b"weak");
+    let libc_name = writer.add_dynamic_string(b"libc.so.6");
+    let version_name = writer.add_dynamic_string(b"GLIBC_2.2.5");
+
+    writer.reserve_file_header();
+    writer.reserve_null_section_index();
+    writer.reserve_dynsym_section_index();
+    writer.reserve_dynstr_section_index();
+    writer.reserve_gnu_versym_section_index();
+    writer.reserve_gnu_verneed_section_index();
+    writer.reserve_shstrtab_section_index();
+    writer.reserve_null_dynamic_symbol_index();
+    writer.reserve_dynamic_symbol_index();
+    writer.reserve_dynamic_symbol_index();
+    writer.reserve_dynsym();
+    writer.reserve_dynstr();
+    writer.reserve_gnu_versym();
+    writer.reserve_gnu_verneed(1, 1);
+    writer.reserve_shstrtab();
+    writer.reserve_section_headers();
+
+    writer
+        .write_file_header(&write::elf::FileHeader {
+            os_abi: elf::ELFOSABI_NONE,
+            abi_version: 0,
+            e_type: elf::ET_DYN,
+            e_machine: elf::EM_X86_64,
+            e_entry: 0,
+            e_flags: 0,
+        })
+        .unwrap();
+    writer.write_null_dynamic_symbol();
+    for (name, st_bind) in [(puts_name, elf::STB_GLOBAL), (weak_name, elf::STB_WEAK)] {
+        writer.write_dynamic_symbol(&write::elf::Sym {
+            name: Some(name),
+            section: None,
+            st_info: (st_bind << 4) | elf::STT_FUNC,
+            st_other: 0,
+            st_shndx: 0,
+            st_value: 0,
+            st_size: 0,
+        });
+    }
+    writer.write_dynstr();
+    writer.write_null_gnu_versym();
+    writer.write_gnu_versym(2);
+    writer.write_gnu_versym(1);
+    writer.write_align_gnu_verneed();
+    writer.write_gnu_verneed(&write::elf::Verneed {
+        version: elf::VER_NEED_CURRENT,
+        aux_count: 1,
+        file: libc_name,
+    });
+    writer.write_gnu_vernaux(&write::elf::Vernaux {
+        flags: 0,
+        index: 2,
+        name: version_name,
+    });
+    writer.write_shstrtab();
+    writer.write_null_section_header();
+    writer.write_dynsym_section_header(0, 1);
+    writer.write_dynstr_section_header(0);
+    writer.write_gnu_versym_section_header(0);
+    writer.write_gnu_verneed_section_header(0);
+    writer.write_shstrtab_section_header();
+    assert_eq!(writer.reserved_len(), writer.len());
+
+    let file = read::File::parse(&*buffer).unwrap();
+    let imports = file.imports().unwrap();
+    let imports = imports
+        .iter()
+        .map(|import| {
+            assert_eq!(import.ordinal(), None);
+            (
+                import.name(),
+                import.library(),
+                import.version(),
+                import.kind(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        imports,
+        [
+            (
+                &b"puts"[..],
+                &b"libc.so.6"[..],
+                Some(&b"GLIBC_2.2.5"[..]),
+                read::ImportKind::Eager
+            ),
+            (b"weak", b"", None, read::ImportKind::Weak),
+        ]
+    );
+}
+
+#[test]
+fn imports_lazy() {
+    for bind_now in [false, true] {
+        let mut buffer = Vec::new();
+        let mut writer = write::elf::Writer::new(Endianness::Little, true, &mut buffer);
+        writer.set_plt(elf::EM_X86_64, 1).unwrap();
+        let puts_name = writer.add_dynamic_string(b"puts");
+        let exit_name = writer.add_dynamic_string(b"exit");
+
+        writer.reserve_file_header();
+        writer.reserve_null_section_index();
+        writer.reserve_plt_section_index();
+        writer.reserve_got_plt_section_index();
+        writer.reserve_rela_plt_section_index();
+        writer.reserve_dynamic_section_index();
+        writer.reserve_dynsym_section_index();
+        writer.reserve_dynstr_section_index();
+        writer.reserve_shstrtab_section_index();
+        writer.reserve_null_dynamic_symbol_index();
+        let puts = writer.reserve_dynamic_symbol_index();
+        writer.reserve_dynamic_symbol_index();
+        // Use the file offsets as addresses.
+        let plt = writer.reserve_plt() as u64;
+        let got_plt = writer.reserve_got_plt() as u64;
+        let rela_plt = writer.reserve_rela_plt() as u64;
+        writer.reserve_dynamic(3);
+        writer.reserve_dynsym();
+        writer.reserve_dynstr();
+        writer.reserve_shstrtab();
+        writer.reserve_section_headers();
+
+        writer
+            .write_file_header(&write::elf::FileHeader {
+                os_abi: elf::ELFOSABI_NONE,
+                abi_version: 0,
+                e_type: elf::ET_DYN,
+                e_machine: elf::EM_X86_64,
+                e_entry: 0,
+                e_flags: 0,
+            })
+            .unwrap();
+        writer.write_plt(plt, got_plt);
+        writer.write_got_plt(0, plt);
+        // Only `puts` has a PLT relocation.
+        writer.write_rela_plt(got_plt, &[puts]);
+        writer.write_align_dynamic();
+        writer.write_dynamic(elf::DT_JMPREL, rela_plt);
+        let flags = if bind_now { elf::DF_BIND_NOW } else { 0 };
+        writer.write_dynamic(elf::DT_FLAGS, flags.into());
+        writer.write_dynamic(elf::DT_NULL, 0);
+        writer.write_null_dynamic_symbol();
+        for name in [puts_name, exit_name] {
+            writer.write_dynamic_symbol(&write::elf::Sym {
+                name: Some(name),
+                section: None,
+                st_info: (elf::STB_GLOBAL << 4) | elf::STT_FUNC,
+                st_other: 0,
+                st_shndx: 0,
+                st_value: 0,
+                st_size: 0,
+            });
+        }
+        writer.write_dynstr();
+        writer.write_shstrtab();
+        writer.write_null_section_header();
+        writer.write_plt_section_header(plt);
+        writer.write_got_plt_section_header(got_plt);
+        writer.write_rela_plt_section_header(rela_plt);
+        writer.write_dynamic_section_header(0);
+        writer.write_dynsym_section_header(0, 1);
+        writer.write_dynstr_section_header(0);
+        writer.write_shstrtab_section_header();
+        assert_eq!(writer.reserved_len(), writer.len());
+
+        let file = read::File::parse(&*buffer).unwrap();
+        let imports = file.imports().unwrap();
+        let imports = imports
+            .iter()
+            .map(|import| (import.name(), import.kind()))
+            .collect::<Vec<_>>();
+        let puts_kind = if bind_now {
+            read::ImportKind::Eager
+        } else {
+            read::ImportKind::Lazy
+        };
+        assert_eq!(
+            imports,
+            [
+                (&b"puts"[..], puts_kind),
+                (b"exit", read::ImportKind::Eager)
+            ]
+        );
+    }
+}
+
+#[test]
+fn needed_libraries() {
+    let mut buffer = Vec::new();
+    let mut writer = write::elf::Writer::new(Endianness::Little, true, &mut buffer);
+    let libc = writer.add_dynamic_string(b"libc.so.6");
+    let libm = writer.add_dynamic_string(b"libm.so.6");
+    let soname = writer.add_dynamic_string(b"libfoo.so");
+
+    writer.reserve_file_header();
+    writer.reserve_null_section_index();
+    writer.reserve_dynamic_section_index();
+    writer.reserve_dynstr_section_index();
+    writer.reserve_shstrtab_section_index();
+    writer.reserve_dynamic(5);
+    writer.reserve_dynstr();
+    writer.reserve_shstrtab();
+    writer.reserve_section_headers();
+
+    writer
+        .write_file_header(&write::elf::FileHeader {
+            os_abi: elf::ELFOSABI_NONE,
+            abi_version: 0,
+            e_type: elf::ET_DYN,
+            e_machine: elf::EM_X86_64,
+            e_entry: 0,
+            e_flags: 0,
+        })
+        .unwrap();
+    writer.write_align_dynamic();
+    writer.write_dynamic_string(elf::DT_NEEDED, libc);
+    writer.write_dynamic_string(elf::DT_SONAME, soname);
+    writer.write_dynamic_string(elf::DT_NEEDED, libm);
+    writer.write_dynamic(elf::DT_NULL, 0);
+    // Entries after the null entry are ignored.
+    writer.write_dynamic_string(elf::DT_NEEDED, soname);
+    writer.write_dynstr();
+    writer.write_shstrtab();
+    writer.write_null_section_header();
+    writer.write_dynamic_section_header(0);
+    writer.write_dynstr_section_header(0);
+    writer.write_shstrtab_section_header();
+    assert_eq!(writer.reserved_len(), writer.len());
+
+    let file = read::File::parse(&*buffer).unwrap();
+    assert_eq!(
+        file.needed_libraries().unwrap(),
+        [&b"libc.so.6"[..], b"libm.so.6"]
+    );
+
+    // Relocatable files don't have a dynamic section.
+    let object = write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let buffer = object.write().unwrap();
+    let file = read::File::parse(&*buffer).unwrap();
+    assert!(file.needed_libraries().unwrap().is_empty());
+}
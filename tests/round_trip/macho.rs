@@ -1,6 +1,9 @@
-use object::read::macho::MachHeader;
-use object::read::{Object, ObjectSection};
-use object::{macho, read, write, Architecture, BinaryFormat, Endianness};
+use object::read::macho::{MachHeader, Nlist};
+use object::read::{Object, ObjectSection, ObjectSymbol};
+use object::{
+    macho, read, write, Architecture, BinaryFormat, Endianness, SectionFlags, SectionKind,
+    SymbolFlags, SymbolKind, SymbolScope,
+};
 
 // Test that segment size is valid when the first section needs alignment.
 #[test]
@@ -61,3 +64,251 @@ fn issue_552_section_file_alignment() {
     assert_eq!(section.address(), 32);
     assert_eq!(section.size(), 1);
 }
+
+#[test]
+fn function_map_inferred_sizes() {
+    let mut object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xcc; 48], 16);
+    for (name, value) in [("f1", 0), ("f2", 16), ("f2_alias", 16), ("f3", 32)] {
+        object.add_symbol(write::Symbol {
+            name: name.as_bytes().to_vec(),
+            value,
+            size: 0,
+            kind: object::SymbolKind::Text,
+            scope: object::SymbolScope::Linkage,
+            weak: false,
+            section: write::SymbolSection::Section(text),
+            flags: object::SymbolFlags::None,
+        });
+    }
+
+    let bytes = &*object.write().unwrap();
+    let object = read::File::parse(bytes).unwrap();
+    let map = object.function_map();
+    let functions = map
+        .symbols()
+        .iter()
+        .map(|f| (f.address(), f.size(), f.is_size_inferred()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        functions,
+        [
+            (0, 16, true),
+            (16, 16, true),
+            (16, 16, true),
+            (32, 16, true)
+        ]
+    );
+
+    let function = map.get(40).unwrap();
+    assert_eq!(function.name(), "_f3");
+    assert!(function.contains(40));
+    assert!(!function.contains(48));
+}
+
+#[test]
+fn cstring_literals() {
+    let mut object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+
+    let cstring = object.section_id(write::StandardSection::ReadOnlyString);
+    let (_, hello) = object.add_section_string(cstring, b"hello");
+    let (_, world) = object.add_section_string(cstring, b"world");
+    assert_eq!(object.add_section_string(cstring, b"hello").1, hello);
+    assert_eq!((hello, world), (0, 6));
+
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    let section = object.section_by_name("__cstring").unwrap();
+    assert_eq!(section.kind(), object::SectionKind::ReadOnlyString);
+    assert_eq!(section.data(), Ok(&b"hello\0world\0"[..]));
+}
+
+#[test]
+fn section_kinds() {
+    use object::{SectionFlags, SectionKind};
+
+    let mut object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+    let kinds = [
+        (&b"__got"[..], SectionKind::Got, macho::S_REGULAR),
+        (
+            b"__mod_init_func",
+            SectionKind::InitArray,
+            macho::S_MOD_INIT_FUNC_POINTERS,
+        ),
+        (
+            b"__mod_term_func",
+            SectionKind::FiniArray,
+            macho::S_MOD_TERM_FUNC_POINTERS,
+        ),
+    ];
+    for (name, kind, _) in kinds {
+        let section = object.add_section(b"__DATA".to_vec(), name.to_vec(), kind);
+        object.append_section_data(section, &[0; 8], 8);
+    }
+
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    for (name, kind, section_type) in kinds {
+        let section = object.section_by_name_bytes(name).unwrap();
+        assert_eq!(section.kind(), kind);
+        let flags = match section.flags() {
+            SectionFlags::MachO { flags } => flags,
+            _ => unreachable!(),
+        };
+        assert_eq!(flags & macho::SECTION_TYPE, section_type);
+    }
+}
+
+#[test]
+fn large_alignment() {
+    let mut object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xcc; 16], 1 << 15);
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    let section = object.section_by_name("__text").unwrap();
+    assert_eq!(section.align(), 1 << 15);
+
+    let mut object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xcc; 16], 1 << 16);
+    assert!(object.write().is_err());
+}
+
+#[test]
+fn file_symbols() {
+    let mut object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+    object.add_file_symbol(b"/src/".to_vec());
+    object.add_file_symbol(b"main.c".to_vec());
+    let text = object.section_id(write::StandardSection::Text);
+    let offset = object.append_section_data(text, &[0xc3], 1);
+    object.add_symbol(write::Symbol {
+        name: b"main".to_vec(),
+        value: offset,
+        size: 1,
+        kind: object::SymbolKind::Text,
+        scope: object::SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(text),
+        flags: object::SymbolFlags::None,
+    });
+
+    let bytes = &*object.write().unwrap();
+    let header = macho::MachHeader64::parse(bytes, 0).unwrap();
+    let endian: Endianness = header.endian().unwrap();
+    let mut commands = header.load_commands(endian, bytes, 0).unwrap();
+    let symtab = loop {
+        if let Some(symtab) = commands.next().unwrap().unwrap().symtab().unwrap() {
+            break symtab;
+        }
+    };
+    let symbols = symtab
+        .symbols::<macho::MachHeader64<Endianness>, _>(endian, bytes)
+        .unwrap();
+    let nlists = symbols
+        .iter()
+        .map(|nlist| (nlist.name(endian, symbols.strings()).unwrap(), nlist.n_type))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        nlists,
+        [
+            (&b"/src/"[..], macho::N_SO),
+            (&b"main.c"[..], macho::N_SO),
+            (&b"_main"[..], macho::N_SECT | macho::N_EXT | macho::N_PEXT),
+            (&b""[..], macho::N_SO),
+        ]
+    );
+
+    // Stabs are not included in the unified symbol API.
+    let object = read::File::parse(bytes).unwrap();
+    let names = object
+        .symbols()
+        .map(|symbol| symbol.name().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["_main"]);
+}
+
+#[test]
+fn symbol_kind_stubs_and_got() {
+    let mut object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+
+    let stubs = object.add_section(b"__TEXT".to_vec(), b"__stubs".to_vec(), SectionKind::Text);
+    object.section_mut(stubs).flags = SectionFlags::MachO {
+        flags: macho::S_SYMBOL_STUBS | macho::S_ATTR_PURE_INSTRUCTIONS,
+    };
+    object.append_section_data(stubs, &[0xcc; 6], 2);
+    let got = object.add_section(
+        b"__DATA_CONST".to_vec(),
+        b"__got".to_vec(),
+        SectionKind::Got,
+    );
+    object.append_section_data(got, &[0; 8], 8);
+    let init = object.add_section(
+        b"__DATA".to_vec(),
+        b"__mod_init_func".to_vec(),
+        SectionKind::InitArray,
+    );
+    object.append_section_data(init, &[0; 8], 8);
+    for (name, section) in [(&b"stub"[..], stubs), (b"got", got), (b"init", init)] {
+        object.add_symbol(write::Symbol {
+            name: name.to_vec(),
+            value: 0,
+            size: 0,
+            kind: SymbolKind::Unknown,
+            scope: SymbolScope::Compilation,
+            weak: false,
+            section: write::SymbolSection::Section(section),
+            flags: SymbolFlags::None,
+        });
+    }
+
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    let kinds = object
+        .symbols()
+        .map(|symbol| {
+            let section = object
+                .section_by_index(symbol.section_index().unwrap())
+                .unwrap();
+            (symbol.name().unwrap(), section.kind(), symbol.kind())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        [
+            ("stub", SectionKind::Plt, SymbolKind::Text),
+            ("got", SectionKind::Got, SymbolKind::Data),
+            ("init", SectionKind::InitArray, SymbolKind::Data),
+        ]
+    );
+}
@@ -7,13 +7,23 @@ use object::{
     SymbolFlags, SymbolKind, SymbolScope, SymbolSection,
 };
 
+mod arbitrary;
+mod archive;
 mod bss;
 mod coff;
 mod comdat;
 mod common;
+mod copy;
+mod deterministic;
+mod dump;
+mod dwarf;
 mod elf;
 mod macho;
+mod patch;
+mod raw;
 mod section_flags;
+mod srec;
+mod summary;
 mod tls;
 
 #[test]
@@ -635,3 +645,244 @@ fn xcoff_powerpc() {
         assert_eq!(relocation.addend(), 0);
     }
 }
+
+#[test]
+fn write_size() {
+    for (format, arch, endian) in [
+        (BinaryFormat::Coff, Architecture::X86_64, Endianness::Little),
+        (BinaryFormat::Elf, Architecture::X86_64, Endianness::Little),
+        (
+            BinaryFormat::MachO,
+            Architecture::Aarch64,
+            Endianness::Little,
+        ),
+        (
+            BinaryFormat::Xcoff,
+            Architecture::PowerPc64,
+            Endianness::Big,
+        ),
+    ] {
+        let mut object = write::Object::new(format, arch, endian);
+        object.add_file_symbol(b"file.c".to_vec());
+
+        let text = object.section_id(write::StandardSection::Text);
+        let offset = object.append_section_data(text, &[1; 30], 4);
+        let symbol = object.add_symbol(write::Symbol {
+            name: b"func1_with_a_long_name".to_vec(),
+            value: offset,
+            size: 30,
+            kind: SymbolKind::Text,
+            scope: SymbolScope::Linkage,
+            weak: false,
+            section: write::SymbolSection::Section(text),
+            flags: SymbolFlags::None,
+        });
+        let data = object.section_id(write::StandardSection::Data);
+        object.append_section_data(data, &[0; 8], 8);
+        object
+            .add_relocation(
+                data,
+                write::Relocation {
+                    offset: 0,
+                    size: 64,
+                    kind: RelocationKind::Absolute,
+                    encoding: RelocationEncoding::Generic,
+                    symbol,
+                    addend: 0,
+                },
+            )
+            .unwrap();
+        let bss = object.section_id(write::StandardSection::UninitializedData);
+        object.append_section_bss(bss, 0x1000, 16);
+
+        let bytes = object.write().unwrap();
+        assert_eq!(object.write_size(), Ok(bytes.len()), "{:?}", format);
+
+        let mut stream = Vec::new();
+        object.write_stream(&mut stream).unwrap();
+        assert_eq!(stream, bytes, "{:?}", format);
+    }
+}
+
+#[test]
+fn source_files() {
+    for (format, arch, endian) in [
+        (BinaryFormat::Coff, Architecture::X86_64, Endianness::Little),
+        (BinaryFormat::Elf, Architecture::X86_64, Endianness::Little),
+        (
+            BinaryFormat::MachO,
+            Architecture::X86_64,
+            Endianness::Little,
+        ),
+        (
+            BinaryFormat::Xcoff,
+            Architecture::PowerPc64,
+            Endianness::Big,
+        ),
+    ] {
+        let mut object = write::Object::new(format, arch, endian);
+        object.add_file_symbol(b"a.c".to_vec());
+        let text = object.section_id(write::StandardSection::Text);
+        object.append_section_data(text, &[1; 4], 4);
+        object.add_file_symbol(b"b.c".to_vec());
+
+        let bytes = object.write().unwrap();
+        let object = read::File::parse(&*bytes).unwrap();
+        let files = object.source_files().unwrap();
+        let names = files.iter().map(|file| file.name()).collect::<Vec<_>>();
+        assert_eq!(names, [&b"a.c"[..], b"b.c"], "{:?}", format);
+        assert!(files.iter().all(|file| file.directory().is_empty()));
+    }
+}
+
+#[test]
+fn parse_options() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[1; 4], 4);
+    object.add_file_symbol(b"a.c".to_vec());
+    let bytes = object.write().unwrap();
+
+    let file = read::File::parse(&*bytes).unwrap();
+    let sections = file.sections().count();
+    let symbols = file.symbols().count();
+    let mut options = read::ParseOptions::default();
+    options.max_table_entries = sections.max(symbols);
+    assert!(read::File::parse_with_options(&*bytes, &options).is_ok());
+    options.max_table_entries -= 1;
+    assert!(read::File::parse_with_options(&*bytes, &options).is_err());
+
+    // The counts in the file headers are checked before the tables are read.
+    for format in [BinaryFormat::Coff, BinaryFormat::MachO] {
+        let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+        let text = object.section_id(write::StandardSection::Text);
+        object.append_section_data(text, &[1; 4], 4);
+        object.add_file_symbol(b"a.c".to_vec());
+        let bytes = object.write().unwrap();
+
+        let mut options = read::ParseOptions::default();
+        assert!(read::File::parse_with_options(&*bytes, &options).is_ok());
+        options.max_table_entries = 1;
+        assert_eq!(
+            read::File::parse_with_options(&*bytes, &options)
+                .unwrap_err()
+                .to_string(),
+            "Table entry count exceeds parse limit"
+        );
+    }
+
+    let compressed = read::CompressedData {
+        format: object::CompressionFormat::Zlib,
+        data: &[],
+        uncompressed_size: 1 << 40,
+    };
+    options.max_allocation_size = 1 << 20;
+    assert_eq!(
+        compressed
+            .decompress_with_options(&options)
+            .unwrap_err()
+            .to_string(),
+        "Allocation size exceeds parse limit"
+    );
+    assert!(read::CompressedData::none(&bytes)
+        .decompress_with_options(&options)
+        .is_ok());
+}
+
+#[test]
+fn symbol_name_index() {
+    for (format, architecture) in [
+        (BinaryFormat::Coff, Architecture::X86_64),
+        (BinaryFormat::Elf, Architecture::X86_64),
+        (BinaryFormat::MachO, Architecture::X86_64),
+        (BinaryFormat::Xcoff, Architecture::PowerPc64),
+    ] {
+        symbol_name_index_format(format, architecture);
+    }
+}
+
+fn symbol_name_index_format(format: BinaryFormat, architecture: Architecture) {
+    let mut object = write::Object::new(format, architecture, Endianness::Little);
+    object.set_mangling(write::Mangling::None);
+    let text = object.section_id(write::StandardSection::Text);
+    for (name, scope) in [
+        (&b"func2"[..], SymbolScope::Linkage),
+        (b"func1", SymbolScope::Linkage),
+        (b"local", SymbolScope::Compilation),
+        (b"local", SymbolScope::Compilation),
+    ] {
+        let offset = object.append_section_data(text, &[0xc3], 1);
+        object.add_symbol(write::Symbol {
+            name: name.to_vec(),
+            value: offset,
+            size: 1,
+            kind: SymbolKind::Text,
+            scope,
+            weak: false,
+            section: write::SymbolSection::Section(text),
+            flags: SymbolFlags::None,
+        });
+    }
+    let bytes = object.write().unwrap();
+
+    let object = read::File::parse(&*bytes).unwrap();
+    let symbol = object.symbol_by_name("func1").unwrap();
+    assert_eq!(symbol.address(), 1, "{:?}", format);
+    assert!(object.symbol_by_name("func3").is_none());
+    // Repeated lookups use the index built by the first lookup.
+    for _ in 0..2 {
+        let local = object.symbol_by_name("local").unwrap();
+        assert_eq!(local.address(), 2, "{:?}", format);
+        let func2 = object.symbol_by_name_bytes(b"func2").unwrap();
+        assert_eq!(func2.address(), 0, "{:?}", format);
+    }
+
+    let index = read::SymbolNameIndex::new(&object.symbol_table().unwrap());
+    assert_eq!(index.get(b"func1"), Some(symbol.index()));
+    let func2 = object
+        .symbol_by_index(index.get(b"func2").unwrap())
+        .unwrap();
+    assert_eq!(func2.address(), 0);
+    assert_eq!(index.get(b"func3"), None);
+    let locals: Vec<_> = index
+        .get_all(b"local")
+        .map(|index| object.symbol_by_index(index).unwrap().address())
+        .collect();
+    assert_eq!(locals, [2, 3]);
+}
+
+#[test]
+fn section_name_index() {
+    for format in [BinaryFormat::Coff, BinaryFormat::Elf, BinaryFormat::MachO] {
+        let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+        let text = object.section_id(write::StandardSection::Text);
+        object.append_section_data(text, &[0xc3], 1);
+        for name in [&b".debug_info"[..], b".debug_str_offsets"] {
+            let section = object.add_dwarf_section(name);
+            object.append_section_data(section, &[0; 4], 1);
+        }
+        let bytes = object.write().unwrap();
+
+        let object = read::File::parse(&*bytes).unwrap();
+        let index = read::SectionNameIndex::new(&object);
+        for name in [
+            ".text",
+            "__text",
+            ".debug_info",
+            ".debug_str_offsets",
+            ".debug_line",
+        ] {
+            assert_eq!(
+                index.get(name.as_bytes()),
+                object.section_by_name(name).map(|section| section.index()),
+                "{:?} {}",
+                format,
+                name
+            );
+        }
+        let section = index.section(&object, b".debug_info").unwrap();
+        assert_eq!(section.data().unwrap(), &[0; 4]);
+        assert!(index.get(b".debug_str_offsets").is_some(), "{:?}", format);
+    }
+}
@@ -0,0 +1,95 @@
+use object::read::{Object, ObjectSection};
+use object::write::FilePatch;
+use object::{elf, read, write, Architecture, BinaryFormat, Endianness, SectionKind, U32};
+
+#[test]
+fn patch_elf() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let note = object.add_section(Vec::new(), b".note.build-id".to_vec(), SectionKind::Note);
+    object.append_section_data(note, &[0; 20], 4);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3], 1);
+    let mut bytes = object.write().unwrap();
+
+    let mut patch;
+    let build_id = [0xab; 20];
+    {
+        let file = read::elf::ElfFile64::<Endianness>::parse(&*bytes).unwrap();
+        patch = FilePatch::new(&bytes);
+        let header = file.raw_header();
+        patch
+            .replace(&header.e_flags, &U32::new(Endianness::Little, 0x1234))
+            .unwrap();
+        let note = file.section_by_name(".note.build-id").unwrap();
+        patch.replace_section_data(&note, &build_id).unwrap();
+        assert!(patch.replace_section_data(&note, &[0; 4]).is_err());
+        let text = file.section_by_name(".text").unwrap();
+        let name = text.name_bytes().unwrap();
+        patch.replace_string(name, b".code").unwrap();
+        assert!(patch.replace_string(name, b".overlong").is_err());
+        assert!(patch.replace_bytes(&[0; 4], &[1; 4]).is_err());
+    }
+    assert!(patch.write_at(bytes.len() as u64, &[0]).is_err());
+
+    let copy = patch.write(&bytes).unwrap();
+    patch.apply(&mut bytes).unwrap();
+    assert_eq!(copy, bytes);
+
+    let file = read::elf::ElfFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert_eq!(file.raw_header().e_flags.get(Endianness::Little), 0x1234);
+    assert_eq!(
+        file.raw_header().e_type.get(Endianness::Little),
+        elf::ET_REL
+    );
+    let note = file.section_by_name(".note.build-id").unwrap();
+    assert_eq!(note.data().unwrap(), &build_id);
+    assert!(file.section_by_name(".text").is_none());
+    let code = file.section_by_name(".code").unwrap();
+    assert_eq!(code.data().unwrap(), &[0xc3]);
+}
+
+#[test]
+fn patch_elf_shared_string() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0; 8], 1);
+    let symbol = object.section_symbol(text);
+    object
+        .add_relocation(
+            text,
+            write::Relocation {
+                offset: 0,
+                size: 64,
+                kind: object::RelocationKind::Absolute,
+                encoding: object::RelocationEncoding::Generic,
+                symbol,
+                addend: 0,
+            },
+        )
+        .unwrap();
+    let mut bytes = object.write().unwrap();
+
+    let mut patch;
+    {
+        let file = read::elf::ElfFile64::<Endianness>::parse(&*bytes).unwrap();
+        let text = file.section_by_name(".text").unwrap();
+        let text = text.name_bytes().unwrap();
+        let rela = file.section_by_name(".rela.text").unwrap();
+        let rela = rela.name_bytes().unwrap();
+        // The section name string table stores `.text` at the end of `.rela.text`.
+        assert_eq!(
+            text.as_ptr() as usize,
+            rela.as_ptr() as usize + b".rela".len()
+        );
+        patch = FilePatch::new(&bytes);
+        patch.replace_string(text, b".code").unwrap();
+    }
+    patch.apply(&mut bytes).unwrap();
+
+    let file = read::elf::ElfFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert!(file.section_by_name(".code").is_some());
+    assert!(file.section_by_name(".rela.code").is_some());
+    assert!(file.section_by_name(".rela.text").is_none());
+}
@@ -0,0 +1,150 @@
+use object::read::raw::RawFile;
+use object::read::{Object, ObjectSection, ObjectSegment};
+use object::{write, Architecture, Endianness, SectionKind};
+
+#[test]
+fn raw_write() {
+    let mut writer = write::raw::Writer::new();
+    writer.set_fill(0xff);
+    let mut buffer = Vec::new();
+    let base = writer
+        .write(&[(0x1004, &[3, 4]), (0x1000, &[1, 2])], &mut buffer)
+        .unwrap();
+    assert_eq!(base, 0x1000);
+    assert_eq!(buffer, [1, 2, 0xff, 0xff, 3, 4]);
+
+    writer.set_base_address(0xffe);
+    let mut buffer = Vec::new();
+    writer.write(&[(0x1000, &[1])], &mut buffer).unwrap();
+    assert_eq!(buffer, [0xff, 0xff, 1]);
+
+    // Overlapping data.
+    let mut buffer = Vec::new();
+    assert!(writer
+        .write(&[(0x1000, &[1, 2]), (0x1001, &[3])], &mut buffer)
+        .is_err());
+}
+
+#[test]
+fn raw_file() {
+    let data = [0x90, 0x90, 0xc3];
+    let file = RawFile::new(&data, Architecture::X86_64, Endianness::Little, 0x8000);
+    assert!(file.is_64());
+    assert_eq!(file.entry(), 0x8000);
+
+    let segment = file.segments().next().unwrap();
+    assert_eq!(segment.address(), 0x8000);
+    assert_eq!(segment.data_range(0x8001, 2).unwrap(), Some(&data[1..]));
+
+    let section = file.section_by_name(".text").unwrap();
+    assert_eq!(section.kind(), SectionKind::Text);
+    assert_eq!(section.address(), 0x8000);
+    assert_eq!(file.section_by_index(section.index()).unwrap().size(), 3);
+
+    let mut buffer = Vec::new();
+    let base = write::raw::Writer::new()
+        .write_object(&file, &mut buffer)
+        .unwrap();
+    assert_eq!(base, 0x8000);
+    assert_eq!(buffer, data);
+}
+
+#[cfg(feature = "elf")]
+#[test]
+fn raw_elf_load_address() {
+    use object::elf;
+
+    // Code in flash at 0, and data that is stored in flash after the code but
+    // runs in RAM at 0x2000_0000.
+    let text = [1, 2, 3, 4];
+    let data = [5, 6];
+    let mut buffer = Vec::new();
+    let mut writer = write::elf::Writer::new(Endianness::Little, false, &mut buffer);
+    writer.reserve_file_header();
+    writer.reserve_program_headers(2);
+    writer.reserve_null_section_index();
+    let text_name = writer.add_section_name(b".text");
+    writer.reserve_section_index();
+    let data_name = writer.add_section_name(b".data");
+    writer.reserve_section_index();
+    writer.reserve_shstrtab_section_index();
+    let text_offset = writer.reserve(text.len(), 4) as u64;
+    let data_offset = writer.reserve(data.len(), 1) as u64;
+    writer.reserve_shstrtab();
+    writer.reserve_section_headers();
+
+    writer
+        .write_file_header(&write::elf::FileHeader {
+            os_abi: elf::ELFOSABI_NONE,
+            abi_version: 0,
+            e_type: elf::ET_EXEC,
+            e_machine: elf::EM_ARM,
+            e_entry: 0,
+            e_flags: 0,
+        })
+        .unwrap();
+    writer.write_align_program_headers();
+    for (offset, size, vaddr, paddr) in [
+        (text_offset, text.len(), 0, 0),
+        (data_offset, data.len(), 0x2000_0000, text.len() as u64),
+    ] {
+        writer.write_program_header(&write::elf::ProgramHeader {
+            p_type: elf::PT_LOAD,
+            p_flags: elf::PF_R,
+            p_offset: offset,
+            p_vaddr: vaddr,
+            p_paddr: paddr,
+            p_filesz: size as u64,
+            p_memsz: size as u64,
+            p_align: 1,
+        });
+    }
+    writer.write_align(4);
+    writer.write(&text);
+    writer.write(&data);
+    writer.write_shstrtab();
+    writer.write_null_section_header();
+    for (name, flags, address, offset, size) in [
+        (
+            text_name,
+            elf::SHF_ALLOC | elf::SHF_EXECINSTR,
+            0,
+            text_offset,
+            text.len(),
+        ),
+        (
+            data_name,
+            elf::SHF_ALLOC | elf::SHF_WRITE,
+            0x2000_0000,
+            data_offset,
+            data.len(),
+        ),
+    ] {
+        writer.write_section_header(&write::elf::SectionHeader {
+            name: Some(name),
+            sh_type: elf::SHT_PROGBITS,
+            sh_flags: flags.into(),
+            sh_addr: address,
+            sh_offset: offset,
+            sh_size: size as u64,
+            sh_link: 0,
+            sh_info: 0,
+            sh_addralign: 1,
+            sh_entsize: 0,
+        });
+    }
+    writer.write_shstrtab_section_header();
+    assert_eq!(writer.reserved_len(), writer.len());
+
+    let file = object::File::parse(&*buffer).unwrap();
+    let segment = file.segments().nth(1).unwrap();
+    assert_eq!(segment.address(), 0x2000_0000);
+    assert_eq!(segment.physical_address(), 4);
+
+    let mut image = Vec::new();
+    let base = write::raw::Writer::new()
+        .write_object(&file, &mut image)
+        .unwrap();
+    assert_eq!(base, 0);
+    assert_eq!(image, [1, 2, 3, 4, 5, 6]);
+}
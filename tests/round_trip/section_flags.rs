@@ -88,3 +88,62 @@ fn macho_x86_64_section_flags() {
         }
     );
 }
+
+#[test]
+fn coff_x86_64_section_flags_align() {
+    let mut object =
+        write::Object::new(BinaryFormat::Coff, Architecture::X86_64, Endianness::Little);
+
+    let section = object.add_section(Vec::new(), b".drectve".to_vec(), SectionKind::Note);
+    object.append_section_data(section, b" /DEFAULTLIB:foo", 1);
+    object.section_mut(section).flags = SectionFlags::Coff {
+        characteristics: object::pe::IMAGE_SCN_LNK_INFO
+            | object::pe::IMAGE_SCN_LNK_REMOVE
+            | object::pe::IMAGE_SCN_ALIGN_16BYTES,
+    };
+
+    let bytes = object.write().unwrap();
+
+    let object = read::File::parse(&*bytes).unwrap();
+    let section = object.sections().next().unwrap();
+    assert_eq!(section.name(), Ok(".drectve"));
+    assert_eq!(
+        section.flags(),
+        SectionFlags::Coff {
+            characteristics: object::pe::IMAGE_SCN_LNK_INFO
+                | object::pe::IMAGE_SCN_LNK_REMOVE
+                | object::pe::IMAGE_SCN_ALIGN_16BYTES,
+        }
+    );
+}
+
+#[test]
+fn elf_x86_64_section_type() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+    let section = object.add_section(
+        Vec::new(),
+        b".gnu.attributes".to_vec(),
+        SectionKind::Elf(object::elf::SHT_GNU_ATTRIBUTES),
+    );
+    object.append_section_data(section, &[b'A', 0, 0, 0, 0], 1);
+    object.section_mut(section).flags = SectionFlags::Elf {
+        sh_flags: object::elf::SHF_ALLOC.into(),
+    };
+
+    let bytes = object.write().unwrap();
+
+    let object = read::File::parse(&*bytes).unwrap();
+    let section = object.section_by_name(".gnu.attributes").unwrap();
+    assert_eq!(
+        section.kind(),
+        SectionKind::Elf(object::elf::SHT_GNU_ATTRIBUTES)
+    );
+    assert_eq!(
+        section.flags(),
+        SectionFlags::Elf {
+            sh_flags: object::elf::SHF_ALLOC.into(),
+        }
+    );
+}
@@ -0,0 +1,48 @@
+use object::read::ihex::IhexImage;
+use object::read::srec::SrecImage;
+use object::read::{Object, ObjectSegment};
+use object::write;
+
+#[test]
+fn srec_write() {
+    let data: Vec<u8> = (0..40).collect();
+    let mut writer = write::srec::Writer::new();
+    writer.set_header(b"test");
+    writer.set_record_length(16);
+    let mut buffer = Vec::new();
+    writer
+        .write(&[(0x1000, &data), (0x1_0000, &[0xaa])], 0x1000, &mut buffer)
+        .unwrap();
+
+    let lines: Vec<&[u8]> = buffer.split(|c| *c == b'\n').collect();
+    // Header, 3 + 1 data records, termination, and the empty string after the last newline.
+    assert_eq!(lines.len(), 7);
+    assert_eq!(lines[0], b"S00700007465737438");
+    assert!(lines[1].starts_with(b"S2140010000001"));
+    assert_eq!(lines[5], b"S804001000EB");
+
+    let image = SrecImage::parse(&buffer).unwrap();
+    assert_eq!(image.header(), b"test");
+    let file = image.file();
+    assert_eq!(file.entry(), 0x1000);
+    let segments: Vec<_> = file.segments().collect();
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments[0].address(), 0x1000);
+    assert_eq!(segments[0].data().unwrap(), &data[..]);
+    assert_eq!(segments[1].address(), 0x1_0000);
+    assert_eq!(segments[1].data().unwrap(), &[0xaa]);
+
+    writer.set_record_length(0);
+    assert!(writer.write(&[], 0, &mut Vec::new()).is_err());
+}
+
+#[test]
+fn srec_write_object() {
+    let hex = b":0400000001020304F2\n:0400000500000002F5\n:00000001FF\n";
+    let image = IhexImage::parse(hex).unwrap();
+    let mut buffer = Vec::new();
+    write::srec::Writer::new()
+        .write_object(&image.file(), &mut buffer)
+        .unwrap();
+    assert_eq!(buffer, b"S0030000FC\nS107000001020304EE\nS9030002FA\n");
+}
@@ -0,0 +1,182 @@
+use object::read::{
+    Object, ObjectSection, ObjectSymbol, SectionSummary, SegmentSummary, SymbolSummary,
+};
+use object::{read, write};
+use object::{
+    Architecture, BinaryFormat, Endianness, SectionKind, SymbolFlags, SymbolKind, SymbolScope,
+    SymbolSection,
+};
+
+#[test]
+fn elf_x86_64_summary() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3; 16], 16);
+    object.add_symbol(write::Symbol {
+        name: b"func".to_vec(),
+        value: 4,
+        size: 12,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(text),
+        flags: SymbolFlags::None,
+    });
+
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+
+    let section = object.section_by_name(".text").unwrap();
+    let summary = SectionSummary::new(&section);
+    assert_eq!(summary.index(), section.index());
+    assert_eq!(summary.name(), ".text");
+    assert_eq!(summary.segment_name(), None);
+    assert_eq!(summary.size(), 16);
+    assert_eq!(summary.align(), 16);
+    assert_eq!(summary.file_range(), section.file_range());
+    assert_eq!(summary.kind(), SectionKind::Text);
+    assert_eq!(summary.flags(), section.flags());
+
+    let symbol = object.symbol_by_name("func").unwrap();
+    let summary = SymbolSummary::new(&symbol);
+    assert_eq!(summary.index(), symbol.index());
+    assert_eq!(summary.name(), "func");
+    assert_eq!(summary.address(), 4);
+    assert_eq!(summary.size(), 12);
+    assert_eq!(summary.kind(), SymbolKind::Text);
+    assert_eq!(summary.section(), SymbolSection::Section(section.index()));
+    assert_eq!(summary.scope(), SymbolScope::Linkage);
+    assert!(!summary.is_weak());
+
+    // Relocatable ELF files have no segments.
+    let segments: Vec<_> = object.segments().map(|s| SegmentSummary::new(&s)).collect();
+    assert!(segments.is_empty());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialize_summary() {
+    use object::elf;
+    use serde_test::{assert_ser_tokens, Token};
+
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3], 1);
+    object.add_symbol(write::Symbol {
+        name: b"func".to_vec(),
+        value: 0,
+        size: 1,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(text),
+        flags: SymbolFlags::None,
+    });
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+
+    let symbol = object.symbol_by_name("func").unwrap();
+    let section_index = symbol.section_index().unwrap();
+    let summary = SymbolSummary::new(&symbol);
+    assert_ser_tokens(
+        &summary,
+        &[
+            Token::Struct {
+                name: "SymbolSummary",
+                len: 9,
+            },
+            Token::Str("index"),
+            Token::NewtypeStruct {
+                name: "SymbolIndex",
+            },
+            Token::U64(symbol.index().0 as u64),
+            Token::Str("name"),
+            Token::Str("func"),
+            Token::Str("address"),
+            Token::U64(0),
+            Token::Str("size"),
+            Token::U64(1),
+            Token::Str("kind"),
+            Token::UnitVariant {
+                name: "SymbolKind",
+                variant: "Text",
+            },
+            Token::Str("section"),
+            Token::NewtypeVariant {
+                name: "SymbolSection",
+                variant: "Section",
+            },
+            Token::NewtypeStruct {
+                name: "SectionIndex",
+            },
+            Token::U64(section_index.0 as u64),
+            Token::Str("scope"),
+            Token::UnitVariant {
+                name: "SymbolScope",
+                variant: "Linkage",
+            },
+            Token::Str("weak"),
+            Token::Bool(false),
+            Token::Str("flags"),
+            Token::StructVariant {
+                name: "SymbolFlags",
+                variant: "Elf",
+                len: 2,
+            },
+            Token::Str("st_info"),
+            Token::U8(0x12),
+            Token::Str("st_other"),
+            Token::U8(elf::STV_HIDDEN),
+            Token::StructVariantEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialize_headers() {
+    use object::elf;
+    use object::LittleEndian as LE;
+    use serde_test::{assert_ser_tokens, Token};
+
+    let header = elf::ProgramHeader32::<LE> {
+        p_type: object::U32::new(LE, elf::PT_LOAD),
+        p_offset: object::U32::new(LE, 0x100),
+        p_vaddr: object::U32::new(LE, 0x1000),
+        p_paddr: object::U32::new(LE, 0x1000),
+        p_filesz: object::U32::new(LE, 0x20),
+        p_memsz: object::U32::new(LE, 0x40),
+        p_flags: object::U32::new(LE, elf::PF_R),
+        p_align: object::U32::new(LE, 0x1000),
+    };
+    assert_ser_tokens(
+        &header,
+        &[
+            Token::Struct {
+                name: "ProgramHeader32",
+                len: 8,
+            },
+            Token::Str("p_type"),
+            Token::U32(elf::PT_LOAD),
+            Token::Str("p_offset"),
+            Token::U32(0x100),
+            Token::Str("p_vaddr"),
+            Token::U32(0x1000),
+            Token::Str("p_paddr"),
+            Token::U32(0x1000),
+            Token::Str("p_filesz"),
+            Token::U32(0x20),
+            Token::Str("p_memsz"),
+            Token::U32(0x40),
+            Token::Str("p_flags"),
+            Token::U32(elf::PF_R),
+            Token::Str("p_align"),
+            Token::U32(0x1000),
+            Token::StructEnd,
+        ],
+    );
+}